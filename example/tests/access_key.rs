@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use near_openapi_client::types::{
+    AccessKeyInfoView, AccessKeyList, AccessKeyView, NearToken, PublicKey,
+};
+
+const FULL: &str = "ed25519:11111111111111111111111111111111";
+const APP: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const DRAINED: &str = "ed25519:4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+const UNLIMITED: &str = "ed25519:US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx";
+
+fn keys() -> AccessKeyList {
+    serde_json::from_value(serde_json::json!({"keys": [
+        {"public_key": FULL, "access_key": {"nonce": 1, "permission": "FullAccess"}},
+        {"public_key": APP, "access_key": {"nonce": 2, "permission": {"FunctionCall": {
+            "allowance": "250000000000000000000000",
+            "method_names": ["add_message"],
+            "receiver_id": "guestbook.near",
+        }}}},
+        {"public_key": DRAINED, "access_key": {"nonce": 3, "permission": {"FunctionCall": {
+            "allowance": "1000",
+            "method_names": [],
+            "receiver_id": "game.near",
+        }}}},
+        {"public_key": UNLIMITED, "access_key": {"nonce": 4, "permission": {"FunctionCall": {
+            "method_names": [],
+            "receiver_id": "game.near",
+        }}}},
+    ]}))
+    .unwrap()
+}
+
+fn public_keys<'a>(keys: impl Iterator<Item = &'a AccessKeyInfoView>) -> Vec<String> {
+    keys.map(|key| key.public_key.to_string()).collect()
+}
+
+#[test]
+fn test_filters() {
+    let keys = keys();
+    assert_eq!(public_keys(keys.full_access()), [FULL]);
+    assert_eq!(public_keys(keys.function_call()), [APP, DRAINED, UNLIMITED]);
+    assert_eq!(
+        public_keys(keys.for_receiver("game.near")),
+        [DRAINED, UNLIMITED]
+    );
+    assert_eq!(
+        public_keys(keys.allowance_below(NearToken::from_millinear(1))),
+        [DRAINED]
+    );
+    assert_eq!(public_keys(keys.iter()), [FULL, APP, DRAINED, UNLIMITED]);
+}
+
+#[test]
+fn test_permission_allows() {
+    let keys = keys();
+    let permission = |key: &str| &keys.get(&key.parse().unwrap()).unwrap().permission;
+    assert!(permission(FULL).allows("anything.near", "delete"));
+    assert!(permission(APP).allows("guestbook.near", "add_message"));
+    assert!(!permission(APP).allows("guestbook.near", "clear"));
+    assert!(!permission(APP).allows("game.near", "add_message"));
+    assert!(permission(DRAINED).allows("game.near", "play"));
+    assert_eq!(permission(APP).receiver_id(), Some("guestbook.near"));
+    assert_eq!(permission(FULL).receiver_id(), None);
+}
+
+#[test]
+fn test_into_map() {
+    let map: HashMap<PublicKey, AccessKeyView> = keys().into_map();
+    assert_eq!(map.len(), 4);
+    assert_eq!(map[&UNLIMITED.parse::<PublicKey>().unwrap()].nonce, 4);
+    assert!(keys().get(&PublicKey::Ed25519([1; 32])).is_none());
+    assert_eq!(keys().into_iter().count(), 4);
+}
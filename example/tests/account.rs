@@ -0,0 +1,177 @@
+mod common;
+
+use near_openapi_client::account::{AccountDeletion, AccountExistence};
+use near_openapi_client::rpc::RpcClient;
+
+const HASH: &str = "11111111111111111111111111111111";
+const RECEIPT_HASH: &str = "US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx";
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{}},"code":-32000,"message":"Server error"}}"#;
+const UNKNOWN_RECEIPT: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_RECEIPT","info":{"receipt_id":"11111111111111111111111111111111"}},"code":-32000,"message":"Server error"}}"#;
+
+fn unknown_account(block_height: u64) -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {
+                "name": "UNKNOWN_ACCOUNT",
+                "info": {
+                    "block_hash": HASH,
+                    "block_height": block_height,
+                    "requested_account_id": "alice.near",
+                },
+            },
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    (200, response.to_string())
+}
+
+fn account_changes(changes: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {"block_hash": HASH, "changes": changes},
+    });
+    (200, response.to_string())
+}
+
+fn deletion() -> (u16, String) {
+    account_changes(serde_json::json!([{
+        "cause": {"type": "receipt_processing", "receipt_hash": RECEIPT_HASH},
+        "type": "account_deletion",
+        "change": {"account_id": "alice.near"},
+    }]))
+}
+
+fn delete_account_receipt() -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "predecessor_id": "alice.near",
+            "receipt": {
+                "Action": {
+                    "actions": [{"DeleteAccount": {"beneficiary_id": "bob.near"}}],
+                    "gas_price": "100000000",
+                    "input_data_ids": [],
+                    "output_data_receivers": [],
+                    "signer_id": "alice.near",
+                    "signer_public_key": "ed25519:11111111111111111111111111111111",
+                },
+            },
+            "receipt_id": RECEIPT_HASH,
+            "receiver_id": "alice.near",
+        },
+    });
+    (200, response.to_string())
+}
+
+#[tokio::test]
+async fn test_existing_account() {
+    let url = common::serve(vec![(
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"amount":"5","block_hash":"{HASH}","block_height":10,"code_hash":"{HASH}","locked":"0","storage_usage":100}}}}"#
+        ),
+    )]);
+
+    let existence = RpcClient::new(&url)
+        .account_exists(&"alice.near".parse().unwrap())
+        .await
+        .unwrap();
+    assert!(
+        matches!(&existence, AccountExistence::Exists(account) if account.storage_usage == 100),
+        "{:?}",
+        existence
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_account_without_lookback() {
+    let url = common::serve(vec![unknown_account(10)]);
+
+    let existence = RpcClient::new(&url)
+        .account_exists(&"alice.near".parse().unwrap())
+        .await
+        .unwrap();
+    assert!(
+        matches!(existence, AccountExistence::NeverExisted),
+        "{:?}",
+        existence
+    );
+}
+
+#[tokio::test]
+async fn test_finds_deletion_and_beneficiary() {
+    let url = common::serve(vec![
+        unknown_account(10),
+        // Nothing at height 10, no block at height 9.
+        account_changes(serde_json::json!([])),
+        (200, UNKNOWN_BLOCK.to_string()),
+        deletion(),
+        delete_account_receipt(),
+    ]);
+
+    let existence = RpcClient::new(&url)
+        .account_exists_within(&"alice.near".parse().unwrap(), 5)
+        .await
+        .unwrap();
+    let AccountExistence::Deleted(deletion) = existence else {
+        panic!("expected a deletion, got {:?}", existence);
+    };
+    assert_eq!(
+        deletion,
+        AccountDeletion {
+            block_hash: HASH.parse().unwrap(),
+            block_height: 8,
+            receipt_id: Some(RECEIPT_HASH.parse().unwrap()),
+            beneficiary_id: Some("bob.near".parse().unwrap()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_deletion_of_garbage_collected_receipt() {
+    let url = common::serve(vec![
+        unknown_account(10),
+        deletion(),
+        (200, UNKNOWN_RECEIPT.to_string()),
+    ]);
+
+    let existence = RpcClient::new(&url)
+        .account_exists_within(&"alice.near".parse().unwrap(), 1)
+        .await
+        .unwrap();
+    assert!(
+        matches!(
+            &existence,
+            AccountExistence::Deleted(deletion)
+                if deletion.block_height == 10 && deletion.beneficiary_id.is_none()
+        ),
+        "{:?}",
+        existence
+    );
+}
+
+#[tokio::test]
+async fn test_never_existed_within_lookback() {
+    let url = common::serve(vec![
+        unknown_account(1),
+        account_changes(serde_json::json!([])),
+        account_changes(serde_json::json!([])),
+    ]);
+
+    // Only heights 1 and 0 exist below the final block.
+    let existence = RpcClient::new(&url)
+        .account_exists_within(&"alice.near".parse().unwrap(), 100)
+        .await
+        .unwrap();
+    assert!(
+        matches!(existence, AccountExistence::NeverExisted),
+        "{:?}",
+        existence
+    );
+}
@@ -0,0 +1,59 @@
+use near_openapi_client::types::{ActionError, ActionErrorKind, lenient};
+
+fn kind(json: serde_json::Value) -> ActionErrorKind {
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn test_messages_interpolate_fields() {
+    let err = kind(serde_json::json!({"LackBalanceForState": {
+        "account_id": "alice.near",
+        "amount": "1000",
+    }}));
+    assert_eq!(
+        err.to_string(),
+        "The account alice.near wouldn't have enough balance to cover storage, required to have 1000 yoctoNEAR more"
+    );
+
+    let err = kind(serde_json::json!({"AccountDoesNotExist": {"account_id": "bob.near"}}));
+    assert_eq!(
+        err.to_string(),
+        "Can't complete the action because account bob.near doesn't exist"
+    );
+
+    let err = kind(serde_json::json!({"FunctionCallError": {
+        "ExecutionError": "Smart contract panicked: out of tokens",
+    }}));
+    assert_eq!(err.to_string(), "Smart contract panicked: out of tokens");
+
+    assert_eq!(
+        kind(serde_json::json!("DelegateActionExpired")).to_string(),
+        "DelegateAction has expired"
+    );
+}
+
+#[test]
+fn test_action_error_names_the_action() {
+    let err: ActionError = serde_json::from_value(serde_json::json!({
+        "index": 1,
+        "kind": {"TriesToUnstake": {"account_id": "alice.near"}},
+    }))
+    .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Action #1: Account alice.near is not yet staked, but tries to unstake"
+    );
+    let err: &dyn std::error::Error = &err;
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_unknown_kind() {
+    let (err, _) = lenient::decode_leniently(|| {
+        kind(serde_json::json!({"SomeFutureError": {"account_id": "alice.near"}}))
+    });
+    assert_eq!(
+        err.to_string(),
+        r#"Unknown action error: {"SomeFutureError":{"account_id":"alice.near"}}"#
+    );
+}
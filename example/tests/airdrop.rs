@@ -0,0 +1,241 @@
+mod common;
+
+use std::path::PathBuf;
+
+use near_openapi_client::airdrop::{Airdrop, AirdropAsset, AirdropError, TransferStatus};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::tx::Transaction;
+use near_openapi_client::types::{self, AccountId, NearGas};
+
+const HASH: &str = "11111111111111111111111111111111";
+const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const SIGNATURE: &str = "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj";
+
+fn access_key(nonce: u64) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"{HASH}","block_height":10,"nonce":{nonce},"permission":"FullAccess"}}}}"#
+        ),
+    )
+}
+
+fn block() -> (u16, String) {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": HASH,
+        "challenges_result": [],
+        "challenges_root": HASH,
+        "chunk_headers_root": HASH,
+        "chunk_mask": [],
+        "chunk_receipts_root": HASH,
+        "chunk_tx_root": HASH,
+        "chunks_included": 0,
+        "epoch_id": HASH,
+        "gas_price": "100000000",
+        "hash": HASH,
+        "height": 10,
+        "last_ds_final_block": HASH,
+        "last_final_block": HASH,
+        "latest_protocol_version": 80,
+        "next_bp_hash": HASH,
+        "next_epoch_id": HASH,
+        "outcome_root": HASH,
+        "prev_hash": HASH,
+        "prev_state_root": HASH,
+        "random_value": HASH,
+        "rent_paid": "0",
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {"author": "node.near", "chunks": [], "header": header},
+    });
+    (200, response.to_string())
+}
+
+/// Response to `send_tx` of a transaction that ended with `status`.
+fn executed(status: &str) -> (u16, String) {
+    let outcome = format!(
+        r#"{{"block_hash":"{HASH}","id":"{HASH}","proof":[],"outcome":{{"executor_id":"alice.near","gas_burnt":1,"logs":[],"metadata":{{"version":1}},"receipt_ids":[],"status":{{"SuccessValue":""}},"tokens_burnt":"0"}}}}"#
+    );
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"final_execution_status":"EXECUTED_OPTIMISTIC","receipts_outcome":[],"status":{status},"transaction":{{"actions":[],"hash":"{HASH}","nonce":6,"priority_fee":0,"public_key":"{PUBLIC_KEY}","receiver_id":"token.near","signature":"{SIGNATURE}","signer_id":"alice.near"}},"transaction_outcome":{outcome}}}}}"#
+        ),
+    )
+}
+
+fn recipients(count: usize) -> Vec<(AccountId, u128)> {
+    (0..count)
+        .map(|i| (format!("user{}.near", i).parse().unwrap(), 100 + i as u128))
+        .collect()
+}
+
+fn token() -> AirdropAsset {
+    AirdropAsset::FungibleToken {
+        contract_id: "token.near".parse().unwrap(),
+    }
+}
+
+/// Signs with a fixed signature; sent transactions must decode, the
+/// signature is not checked.
+async fn sign(tx: Transaction) -> types::tx::SignedTransaction {
+    tx.with_signature(SIGNATURE.parse().unwrap())
+}
+
+async fn run(
+    url: &str,
+    airdrop: &Airdrop,
+) -> Result<near_openapi_client::airdrop::AirdropReport, AirdropError> {
+    airdrop
+        .run(
+            &RpcClient::new(url),
+            &"alice.near".parse().unwrap(),
+            &PUBLIC_KEY.parse().unwrap(),
+            sign,
+        )
+        .await
+}
+
+fn checkpoint(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "near-openapi-airdrop-{}-{}.json",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn test_batches_within_action_and_gas_limits() {
+    let sizes = |airdrop: Airdrop| -> Vec<usize> {
+        airdrop
+            .batches()
+            .iter()
+            .map(|batch| batch.transfers.len())
+            .collect()
+    };
+
+    let airdrop = Airdrop::new(token(), recipients(25)).gas_per_transfer(NearGas::from_tgas(30));
+    assert_eq!(sizes(airdrop.clone()), vec![10, 10, 5]);
+    assert_eq!(sizes(airdrop.max_actions(4)), vec![4, 4, 4, 4, 4, 4, 1]);
+
+    let batches = Airdrop::new(AirdropAsset::Near, recipients(2)).batches();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(
+        batches[1].receiver_id,
+        "user1.near".parse::<AccountId>().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_reports_outcome_of_each_recipient() {
+    let url = common::serve(vec![
+        access_key(5),
+        block(),
+        executed(r#"{"SuccessValue":""}"#),
+        block(),
+        executed(r#"{"Failure":{"InvalidTxError":"Expired"}}"#),
+    ]);
+    let airdrop = Airdrop::new(token(), recipients(3))
+        .max_actions(2)
+        .concurrency(1);
+
+    let report = run(&url, &airdrop).await.unwrap();
+    let statuses: Vec<_> = report
+        .outcomes
+        .iter()
+        .map(|outcome| {
+            (
+                outcome.account_id.to_string(),
+                outcome.batch,
+                &outcome.status,
+            )
+        })
+        .collect();
+    assert!(
+        matches!(
+            statuses.as_slice(),
+            [
+                (_, 0, TransferStatus::Transferred { .. }),
+                (_, 0, TransferStatus::Transferred { .. }),
+                (last, 1, TransferStatus::Failed { tx_hash: Some(_), .. }),
+            ] if last == "user2.near"
+        ),
+        "{:?}",
+        statuses
+    );
+    assert_eq!(report.failed().count(), 1);
+}
+
+#[tokio::test]
+async fn test_resumes_from_checkpoint() {
+    let path = checkpoint("resume");
+    let airdrop = Airdrop::new(token(), recipients(3))
+        .max_actions(2)
+        .concurrency(1)
+        .checkpoint(&path);
+    let url = common::serve(vec![
+        access_key(5),
+        block(),
+        executed(r#"{"SuccessValue":""}"#),
+        block(),
+        (503, String::new()),
+    ]);
+
+    let report = run(&url, &airdrop).await.unwrap();
+    assert!(
+        matches!(report.outcomes[2].status, TransferStatus::Unknown { .. }),
+        "{:?}",
+        report
+    );
+
+    // Only the batch with an unknown outcome is sent again, unchanged.
+    let url = common::serve(vec![executed(r#"{"SuccessValue":""}"#)]);
+    let resumed = run(&url, &airdrop).await.unwrap();
+    assert_eq!(resumed.failed().count(), 0, "{:?}", resumed);
+    let (TransferStatus::Unknown { tx_hash }, TransferStatus::Transferred { tx_hash: resent }) =
+        (&report.outcomes[2].status, &resumed.outcomes[2].status)
+    else {
+        panic!("{:?}", resumed);
+    };
+    assert_eq!(tx_hash, resent);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_refuses_checkpoint_of_another_airdrop() {
+    let path = checkpoint("mismatch");
+    let url = common::serve(vec![
+        access_key(5),
+        block(),
+        executed(r#"{"SuccessValue":""}"#),
+    ]);
+    run(
+        &url,
+        &Airdrop::new(token(), recipients(1)).checkpoint(&path),
+    )
+    .await
+    .unwrap();
+
+    let result = run(
+        &url,
+        &Airdrop::new(token(), recipients(2)).checkpoint(&path),
+    )
+    .await;
+    assert!(
+        matches!(result, Err(AirdropError::CheckpointMismatch(_))),
+        "{:?}",
+        result
+    );
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,55 @@
+use arbitrary::{Arbitrary, Unstructured};
+use near_openapi_client::types;
+
+/// Deterministic pseudo-random input for `Unstructured`.
+fn input(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+/// Checks that arbitrary values of `T` survive a JSON round trip.
+fn round_trip<T>(samples: u64)
+where
+    T: for<'a> Arbitrary<'a>
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + PartialEq
+        + std::fmt::Debug,
+{
+    for seed in 0..samples {
+        let data = input(seed, 4096);
+        let Ok(value) = T::arbitrary(&mut Unstructured::new(&data)) else {
+            continue;
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        let decoded: T = serde_json::from_value(json.clone())
+            .unwrap_or_else(|err| panic!("{} does not decode: {}", json, err));
+        assert_eq!(decoded, value, "{}", json);
+    }
+}
+
+#[test]
+fn test_round_trips() {
+    round_trip::<types::ActionView>(500);
+    round_trip::<types::ActionErrorKind>(500);
+    round_trip::<types::InvalidTxError>(500);
+    round_trip::<types::ExecutionOutcomeView>(200);
+    round_trip::<types::RpcBlockError>(200);
+}
+
+#[test]
+fn test_unknown_variants_are_not_generated() {
+    for seed in 0..500 {
+        let data = input(seed, 256);
+        if let Ok(action) = types::ActionView::arbitrary(&mut Unstructured::new(&data)) {
+            assert!(!matches!(action, types::ActionView::Unknown(_)));
+        }
+    }
+}
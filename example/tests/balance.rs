@@ -0,0 +1,97 @@
+use near_openapi_client::types::balance::{
+    self, AccountBalance, BalanceError, STORAGE_AMOUNT_PER_BYTE,
+};
+use near_openapi_client::types::{AccountView, NearToken};
+
+fn account(amount: NearToken, locked: NearToken, storage_usage: u64) -> AccountView {
+    serde_json::from_value(serde_json::json!({
+        "amount": amount,
+        "locked": locked,
+        "code_hash": "11111111111111111111111111111111",
+        "storage_usage": storage_usage,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_available_balance_keeps_storage_cost() {
+    // 1 NEAR of storage, 100000 bytes.
+    let view = account(NearToken::from_near(5), NearToken::from_near(0), 100_000);
+    assert_eq!(
+        view.balance(STORAGE_AMOUNT_PER_BYTE).unwrap(),
+        AccountBalance {
+            total: NearToken::from_near(5),
+            staked: NearToken::from_near(0),
+            state_staked: NearToken::from_near(1),
+            available: NearToken::from_near(4),
+        }
+    );
+}
+
+#[test]
+fn test_available_balance_with_stake_covering_storage() {
+    let view = account(NearToken::from_near(5), NearToken::from_near(2), 100_000);
+    let balance = view.balance(STORAGE_AMOUNT_PER_BYTE).unwrap();
+    assert_eq!(balance.total, NearToken::from_near(7));
+    assert_eq!(balance.available, NearToken::from_near(5), "{:?}", balance);
+
+    let view = account(
+        NearToken::from_millinear(500),
+        NearToken::from_near(0),
+        100_000,
+    );
+    assert_eq!(
+        balance::available_balance(&view, STORAGE_AMOUNT_PER_BYTE),
+        NearToken::from_near(0)
+    );
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let five = NearToken::from_near(5);
+    let two = NearToken::from_near(2);
+    assert_eq!(balance::sub(five, two), Ok(NearToken::from_near(3)));
+    assert_eq!(
+        balance::sub(two, five),
+        Err(BalanceError::Insufficient {
+            required: five,
+            available: two,
+        })
+    );
+    assert_eq!(
+        balance::add(NearToken::from_yoctonear(u128::MAX), two),
+        Err(BalanceError::Overflow)
+    );
+    assert_eq!(
+        balance::percent(five, 10),
+        Ok(NearToken::from_millinear(500))
+    );
+    assert_eq!(
+        balance::fraction(NearToken::from_yoctonear(u128::MAX), 2, 3),
+        Ok(NearToken::from_yoctonear(u128::MAX / 3 * 2))
+    );
+    assert_eq!(
+        balance::fraction(five, 1, 0),
+        Err(BalanceError::DivisionByZero)
+    );
+}
+
+#[test]
+fn test_format_rounds_down() {
+    let amount = NearToken::from_yoctonear(1_239_999_999_999_999_999_999_999);
+    assert_eq!(balance::format_near(amount, 2), "1.23 NEAR");
+    assert_eq!(balance::format_near(amount, 0), "1 NEAR");
+    assert_eq!(balance::format_millinear(amount, 1), "1239.9 mNEAR");
+    assert_eq!(
+        balance::format_near(NearToken::from_millinear(1500), 5),
+        "1.5 NEAR"
+    );
+    assert_eq!(
+        balance::format_near(NearToken::from_yoctonear(1), 30),
+        "0.000000000000000000000001 NEAR"
+    );
+    assert_eq!(
+        balance::format_near(NearToken::from_yoctonear(1), 3),
+        "0 NEAR"
+    );
+}
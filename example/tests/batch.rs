@@ -0,0 +1,85 @@
+mod common;
+
+use near_openapi_client::batch::BatchRequest;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+
+const HASH: &str = "11111111111111111111111111111111";
+
+fn view_account(account_id: &str) -> types::RpcQueryRequest {
+    types::RpcQueryRequest::ViewAccountByFinality {
+        account_id: account_id.parse().unwrap(),
+        finality: types::Finality::Final,
+        request_type: types::ViewAccountByFinalityRequestType::ViewAccount,
+    }
+}
+
+fn account(id: usize, amount: u128) -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":"{id}","result":{{"amount":"{amount}","block_hash":"{HASH}","block_height":10,"code_hash":"{HASH}","locked":"0","storage_usage":100}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn test_batch_returns_results_in_call_order() {
+    let unknown_account = r#"{"jsonrpc":"2.0","id":"1","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_ACCOUNT","info":{"requested_account_id":"bob.near","block_hash":"11111111111111111111111111111111","block_height":10}},"code":-32000,"message":"Server error"}}"#;
+    let gas_price = r#"{"jsonrpc":"2.0","id":"3","result":{"gas_price":"100000000"}}"#;
+    let url = common::serve(vec![(
+        200,
+        format!(
+            "[{},{},{},{}]",
+            gas_price,
+            account(2, 7),
+            unknown_account,
+            account(0, 5)
+        ),
+    )]);
+    let client = RpcClient::new(&url);
+
+    let mut batch = BatchRequest::new();
+    let accounts: Vec<_> = ["alice.near", "bob.near", "carol.near"]
+        .into_iter()
+        .map(|account_id| batch.add::<methods::Query>(&view_account(account_id)))
+        .collect();
+    let price = batch.add::<methods::GasPrice>(&types::RpcGasPriceRequest { block_id: None });
+    assert_eq!(batch.len(), 4);
+    let response = batch.send(&client).await.unwrap();
+
+    let amounts: Vec<_> = accounts
+        .into_iter()
+        .map(|call| match response.get(call) {
+            Ok(types::RpcQueryResponse::Variant0 { amount, .. }) => Some(amount.as_yoctonear()),
+            Ok(other) => panic!("unexpected response: {:?}", other),
+            Err(RpcError::Rpc(_)) => None,
+            Err(err) => panic!("unexpected error: {}", err),
+        })
+        .collect();
+    assert_eq!(amounts, vec![Some(5), None, Some(7)]);
+    assert_eq!(
+        response.get(price).unwrap().gas_price.as_yoctonear(),
+        100_000_000
+    );
+}
+
+#[tokio::test]
+async fn test_batch_missing_response() {
+    let url = common::serve(vec![(200, format!("[{}]", account(0, 5)))]);
+    let client = RpcClient::new(&url);
+
+    let mut batch = BatchRequest::new();
+    let first = batch.add::<methods::Query>(&view_account("alice.near"));
+    let second = batch.add::<methods::Query>(&view_account("bob.near"));
+    let response = batch.send(&client).await.unwrap();
+
+    assert!(response.get(first).is_ok());
+    assert!(matches!(response.get(second), Err(RpcError::Client(_))));
+}
+
+#[tokio::test]
+async fn test_empty_batch_sends_nothing() {
+    let client = RpcClient::new("http://127.0.0.1:1");
+    let response = BatchRequest::new().send(&client).await;
+    assert!(response.is_ok());
+}
@@ -0,0 +1,354 @@
+mod common;
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use near_openapi_client::block::{BlockStream, IngestionConfig};
+use near_openapi_client::methods;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types::{
+    self, BlockId, BlockReference, Finality, RpcBlockRequest, RpcBlockResponse,
+};
+
+const HASH: &str = "11111111111111111111111111111111";
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{}},"code":-32000,"message":"Server error"}}"#;
+
+fn block(height: u64) -> (u16, String) {
+    block_with_chunk_heights(height, &[])
+}
+
+/// Block at `height` with chunks of the given `height_included`, one per
+/// shard.
+fn block_with_chunk_heights(height: u64, chunk_heights: &[u64]) -> (u16, String) {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": HASH,
+        "challenges_result": [],
+        "challenges_root": HASH,
+        "chunk_headers_root": HASH,
+        "chunk_mask": chunk_heights.iter().map(|&h| h == height).collect::<Vec<_>>(),
+        "chunk_receipts_root": HASH,
+        "chunk_tx_root": HASH,
+        "chunks_included": 0,
+        "epoch_id": HASH,
+        "gas_price": "100000000",
+        "hash": HASH,
+        "height": height,
+        "last_ds_final_block": HASH,
+        "last_final_block": HASH,
+        "latest_protocol_version": 80,
+        "next_bp_hash": HASH,
+        "next_epoch_id": HASH,
+        "outcome_root": HASH,
+        "prev_hash": HASH,
+        "prev_state_root": HASH,
+        "random_value": HASH,
+        "rent_paid": "0",
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "author": "node.near",
+            "chunks": chunk_heights
+                .iter()
+                .enumerate()
+                .map(|(shard_id, &height_included)| chunk_header(shard_id as u64, height_included))
+                .collect::<Vec<_>>(),
+            "header": header,
+        },
+    });
+    (200, response.to_string())
+}
+
+fn chunk_header(shard_id: u64, height_included: u64) -> serde_json::Value {
+    serde_json::json!({
+        "balance_burnt": "0",
+        "chunk_hash": HASH,
+        "encoded_length": 0,
+        "encoded_merkle_root": HASH,
+        "gas_limit": 1000,
+        "gas_used": 0,
+        "height_created": height_included,
+        "height_included": height_included,
+        "outcome_root": HASH,
+        "outgoing_receipts_root": HASH,
+        "prev_block_hash": HASH,
+        "prev_state_root": HASH,
+        "shard_id": shard_id,
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "tx_root": HASH,
+        "validator_proposals": [],
+    })
+}
+
+fn chunk(shard_id: u64, height: u64) -> (u16, String) {
+    chunk_with(shard_id, height, &[], &[])
+}
+
+/// Chunk of `shard_id` with a transfer transaction per nonce and a transfer
+/// receipt per receipt id.
+fn chunk_with(shard_id: u64, height: u64, nonces: &[u64], receipt_ids: &[&str]) -> (u16, String) {
+    let transactions: Vec<_> = nonces
+        .iter()
+        .map(|nonce| {
+            serde_json::json!({
+                "actions": [{"Transfer": {"deposit": "1"}}],
+                "hash": HASH,
+                "nonce": nonce,
+                "public_key": "ed25519:11111111111111111111111111111111",
+                "receiver_id": "bob.near",
+                "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+                "signer_id": "alice.near",
+            })
+        })
+        .collect();
+    let receipts: Vec<_> = receipt_ids
+        .iter()
+        .map(|receipt_id| {
+            serde_json::json!({
+                "predecessor_id": "alice.near",
+                "receipt": {"Action": {
+                    "actions": [{"Transfer": {"deposit": "1"}}],
+                    "gas_price": "100000000",
+                    "input_data_ids": [],
+                    "output_data_receivers": [],
+                    "signer_id": "alice.near",
+                    "signer_public_key": "ed25519:11111111111111111111111111111111",
+                }},
+                "receipt_id": receipt_id,
+                "receiver_id": "bob.near",
+            })
+        })
+        .collect();
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "author": "node.near",
+            "header": chunk_header(shard_id, height),
+            "receipts": receipts,
+            "transactions": transactions,
+        },
+    });
+    (200, response.to_string())
+}
+
+#[tokio::test]
+async fn test_skips_missing_heights() {
+    let url = common::serve(vec![
+        // The final head.
+        block(12),
+        // The starting block.
+        block(10),
+        // Height 11 was skipped.
+        (200, UNKNOWN_BLOCK.to_string()),
+        block(12),
+    ]);
+    let stream = BlockStream::new(
+        RpcClient::new(&url),
+        BlockId::BlockHeight(10),
+        Finality::Final,
+    );
+
+    let heights: Vec<u64> = stream
+        .into_stream()
+        .take(2)
+        .map(|block| block.unwrap().header.height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![10, 12]);
+}
+
+#[tokio::test]
+async fn test_waits_for_finality() {
+    let url = common::serve(vec![
+        // The starting block, by hash.
+        block(5),
+        // The final head is the starting block.
+        block(5),
+        block(5),
+        // The final head is still the starting block.
+        (500, String::new()),
+        block(6),
+        block(6),
+    ]);
+    let stream = BlockStream::new(
+        RpcClient::new(&url),
+        BlockId::CryptoHash(HASH.parse().unwrap()),
+        Finality::Final,
+    )
+    .poll_interval(Duration::from_millis(10));
+
+    let blocks: Vec<_> = stream.into_stream().take(3).collect().await;
+    assert_eq!(blocks[0].as_ref().unwrap().header.height, 5);
+    assert!(blocks[1].is_err());
+    assert_eq!(blocks[2].as_ref().unwrap().header.height, 6);
+}
+
+#[tokio::test]
+async fn test_waits_for_start_to_be_final() {
+    let url = common::serve(vec![
+        // The final head is below the starting block.
+        block(8),
+        block(10),
+        block(10),
+    ]);
+    let stream = BlockStream::new(
+        RpcClient::new(&url),
+        BlockId::BlockHeight(10),
+        Finality::Final,
+    )
+    .poll_interval(Duration::from_millis(10));
+
+    let blocks: Vec<_> = stream.into_stream().take(1).collect().await;
+    assert_eq!(blocks[0].as_ref().unwrap().header.height, 10);
+}
+
+#[tokio::test]
+async fn test_skips_missing_start_height() {
+    let url = common::serve(vec![
+        block(12),
+        // The starting height 10 was skipped.
+        (200, UNKNOWN_BLOCK.to_string()),
+        block(11),
+    ]);
+    let stream = BlockStream::new(
+        RpcClient::new(&url),
+        BlockId::BlockHeight(10),
+        Finality::Final,
+    );
+
+    let heights: Vec<u64> = stream
+        .into_stream()
+        .take(1)
+        .map(|block| block.unwrap().header.height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![11]);
+}
+
+#[tokio::test]
+async fn test_block_with_chunks() {
+    // Shard 2 missed its chunk, so the block carries an older chunk header.
+    let url = common::serve(vec![
+        block_with_chunk_heights(10, &[10, 10, 9]),
+        chunk(0, 10),
+        chunk(1, 10),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let block = client
+        .block_with_chunks(BlockReference::BlockId(BlockId::BlockHeight(10)))
+        .await
+        .unwrap();
+    assert_eq!(block.header.height, 10);
+    let mut shard_ids: Vec<u64> = block
+        .chunks
+        .iter()
+        .map(|chunk| chunk.header.shard_id.0)
+        .collect();
+    shard_ids.sort();
+    assert_eq!(shard_ids, vec![0, 1]);
+}
+
+#[tokio::test]
+async fn test_block_with_chunks_flattens_transactions_and_receipts() {
+    const RECEIPT: &str = "US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx";
+    let url = common::serve(vec![
+        block_with_chunk_heights(10, &[10, 10]),
+        chunk_with(0, 10, &[1, 2], &[]),
+        chunk_with(1, 10, &[3], &[HASH, RECEIPT]),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let block = client
+        .block_with_chunks(BlockReference::BlockId(BlockId::BlockHeight(10)))
+        .await
+        .unwrap();
+    let transactions: Vec<(u64, u64)> = block
+        .transactions()
+        .map(|(shard_id, tx)| (shard_id.0, tx.nonce))
+        .collect();
+    assert_eq!(transactions, vec![(0, 1), (0, 2), (1, 3)]);
+    let receipts: Vec<(u64, String)> = block
+        .receipts()
+        .map(|(shard_id, receipt)| (shard_id.0, receipt.receipt_id.to_string()))
+        .collect();
+    assert_eq!(
+        receipts,
+        vec![(1, HASH.to_string()), (1, RECEIPT.to_string())]
+    );
+}
+
+fn typed_block(height: u64) -> RpcBlockResponse {
+    let response: serde_json::Value = serde_json::from_str(&block(height).1).unwrap();
+    serde_json::from_value(response["result"].clone()).unwrap()
+}
+
+/// Expects the start block 10, the final head 14 and the heights up to it,
+/// of which 12 was skipped.
+fn mock_chain() -> MockRpc {
+    let mock = MockRpc::start();
+    for height in [10, 11, 13, 14] {
+        mock.expect::<methods::Block>()
+            .with_params(&RpcBlockRequest::BlockId(BlockId::BlockHeight(height)))
+            .return_ok(typed_block(height));
+    }
+    mock.expect::<methods::Block>()
+        .with_params(&RpcBlockRequest::BlockId(BlockId::BlockHeight(12)))
+        .return_error(types::ErrorWrapperForRpcBlockError::HandlerError(
+            types::RpcBlockError::UnknownBlock(serde_json::Map::new()),
+        ));
+    mock.expect::<methods::Block>()
+        .with_params(&RpcBlockRequest::Finality(Finality::Final))
+        .return_ok(typed_block(14));
+    mock
+}
+
+#[tokio::test]
+async fn test_concurrent_ingestion_yields_blocks_in_order() {
+    let mock = mock_chain();
+    let stream = BlockStream::new(mock.client(), BlockId::BlockHeight(10), Finality::Final).config(
+        IngestionConfig {
+            concurrency: 3,
+            cache_size: 4,
+            ..Default::default()
+        },
+    );
+
+    let heights: Vec<u64> = stream
+        .into_stream()
+        .take(4)
+        .map(|block| block.unwrap().header.height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![10, 11, 13, 14]);
+}
+
+#[tokio::test]
+async fn test_header_stream() {
+    let mock = mock_chain();
+    let stream = BlockStream::new(mock.client(), BlockId::BlockHeight(10), Finality::Final).config(
+        IngestionConfig {
+            concurrency: 2,
+            cache_size: 0,
+            ..Default::default()
+        },
+    );
+
+    let heights: Vec<u64> = stream
+        .into_header_stream()
+        .take(4)
+        .map(|header| header.unwrap().height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![10, 11, 13, 14]);
+}
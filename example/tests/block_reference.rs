@@ -0,0 +1,111 @@
+use near_openapi_client::methods;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types::{self, BlockId, BlockReference, CryptoHash, Finality};
+use serde_json::json;
+
+fn golden_block() -> types::RpcBlockResponse {
+    serde_json::from_str(include_str!("golden/block.json")).unwrap()
+}
+
+#[test]
+fn test_shorthands() {
+    let hash: CryptoHash = "AmDsC4pxnE1FxJyZvTYLZR448e3yz2qHWCFFuHu3MiUu"
+        .parse()
+        .unwrap();
+    assert_eq!(BlockReference::latest(), BlockReference::optimistic());
+    assert_eq!(
+        BlockReference::optimistic(),
+        BlockReference::Finality(Finality::Optimistic)
+    );
+    assert_eq!(
+        BlockReference::near_final(),
+        BlockReference::Finality(Finality::NearFinal)
+    );
+    assert_eq!(
+        BlockReference::at_hash(hash),
+        BlockReference::BlockId(BlockId::CryptoHash(hash))
+    );
+    assert_eq!(
+        serde_json::to_value(BlockReference::final_()).unwrap(),
+        json!({"finality": "final"})
+    );
+    assert_eq!(
+        serde_json::to_value(BlockReference::at_height(10)).unwrap(),
+        json!({"block_id": 10})
+    );
+}
+
+#[tokio::test]
+async fn test_default_finality_fills_in_omitted_blocks() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::Block>()
+        .with_params(&types::RpcBlockRequest::Finality(Finality::Optimistic))
+        .return_ok(golden_block());
+    mock.expect::<methods::Block>()
+        .with_params(&types::RpcBlockRequest::BlockId(BlockId::BlockHeight(10)))
+        .return_ok(golden_block());
+    mock.expect::<methods::GasPrice>()
+        .return_ok(types::RpcGasPriceResponse {
+            gas_price: types::NearToken::from_yoctonear(1),
+        });
+    let client = mock.client().with_default_finality(Finality::Optimistic);
+
+    client
+        .call::<_, serde_json::Value, serde_json::Value>("block", &json!({}))
+        .await
+        .unwrap();
+    // Params with a block keep it, and methods without blocks are left alone.
+    client
+        .block(&types::RpcBlockRequest::BlockId(BlockId::BlockHeight(10)))
+        .await
+        .unwrap();
+    client
+        .gas_price(&types::RpcGasPriceRequest { block_id: None })
+        .await
+        .unwrap();
+    let received = mock.received();
+    assert_eq!(received[0].1, json!({"finality": "optimistic"}));
+    assert_eq!(received[1].1, json!({"block_id": 10}));
+    assert_eq!(received[2].1, json!({}));
+}
+
+#[tokio::test]
+async fn test_default_finality_does_not_override_explicit_blocks() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::Block>()
+        .with_params(&types::RpcBlockRequest::Finality(Finality::Final))
+        .return_ok(golden_block());
+    mock.expect::<methods::Block>()
+        .with_params(&types::RpcBlockRequest::SyncCheckpoint(
+            types::SyncCheckpoint::Genesis,
+        ))
+        .return_ok(golden_block());
+    let client = mock.client().with_default_finality(Finality::Optimistic);
+
+    client
+        .call::<_, serde_json::Value, serde_json::Value>("block", &json!({"finality": "final"}))
+        .await
+        .unwrap();
+    client
+        .block(&types::RpcBlockRequest::SyncCheckpoint(
+            types::SyncCheckpoint::Genesis,
+        ))
+        .await
+        .unwrap();
+    let received = mock.received();
+    assert_eq!(received[0].1, json!({"finality": "final"}));
+    assert_eq!(received[1].1, json!({"sync_checkpoint": "genesis"}));
+}
+
+#[tokio::test]
+async fn test_omitted_blocks_are_sent_as_is_by_default() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::Block>().return_ok(golden_block());
+    let client = mock.client();
+
+    client
+        .call::<_, serde_json::Value, serde_json::Value>("block", &json!({}))
+        .await
+        .unwrap();
+    assert_eq!(mock.received()[0].1, json!({}));
+}
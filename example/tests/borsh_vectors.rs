@@ -0,0 +1,157 @@
+//! Test vectors of nearcore and near-api-js, which the borsh encoding and
+//! the hashes of `types::tx` must reproduce byte for byte for transactions,
+//! delegate actions and receipts made with it to be accepted by the network.
+use near_openapi_client::types::tx::{
+    Action, SecretKey, SignedTransaction, Transaction, TransactionBuilder, child_receipt_id,
+};
+use near_openapi_client::types::{
+    AccessKeyPermission, CreateAccountAction, CryptoHash, DelegateAction, FunctionArgs,
+    FunctionCallPermission, NearGas, NearToken, NonDelegateAction, PublicKey, Signature,
+    SignedDelegateAction, StakeAction,
+};
+
+// `test_serialize_transaction` of nearcore's `core/primitives/src/transaction.rs`,
+// a transaction with one action of each kind of protocol version 1.
+const NEARCORE_PUBLIC_KEY: &str = "ed25519:22skMptHjFWNyuEWY22ftn2AbLPSYpmYwGJRGwpNHbTV";
+const NEARCORE_TX_HASH: &str = "4GXvjMFN6wSxnU9jEVT8HbXP5Yk6yELX9faRSKp6n9fX";
+
+// `DELEGATE_ACTION_HEX` of nearcore's `core/primitives/src/action/delegate.rs`,
+// a delegate action from "aaa" to "bbb" creating an account, with an empty
+// key and signature. The hash is its `get_nep461_hash()`.
+const NEARCORE_DELEGATE_ACTION: &str = concat!(
+    "0803000000616161030000006262620100000000010000000000000002000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000"
+);
+const NEARCORE_DELEGATE_ACTION_HASH: &str = "Hk4KS6bWuycytBdnm4kZ5hAFZBmGYMwCXPBLYNJJmgwe";
+
+// `create_receipt_id_from_transaction(NEARCORE_TX_HASH, 100)` and
+// `create_receipt_id_from_receipt_id(that id, 101, 1)` of nearcore's
+// `core/primitives/src/utils.rs`.
+const NEARCORE_RECEIPT_ID: &str = "DUvaowsP2BZEKyvMhZsWKokNC95ifeQ35yre7VwsQhvv";
+const NEARCORE_CHILD_RECEIPT_ID: &str = "9WnXKFw1V23gYPW8R3EJRbs4fTZFsUTr11HhJ46khMbM";
+
+// `serialize and sign transfer tx` of near-api-js' transaction tests.
+const NEAR_API_JS_SECRET_KEY: &str = "ed25519:3hoMW1HvnRLSFCLZnvPzWeoGwtdHzke34B2cTHM8rhcbG3TbuLKtShTv3DvyejnXKXKBiV7YPkLeqUHN1ghnqpFv";
+const NEAR_API_JS_PUBLIC_KEY: &str = "ed25519:Anu7LYDfpLtkP7E16LT9imXF694BdQaa9ufVkQiwTQxC";
+const NEAR_API_JS_BLOCK_HASH: &str = "244ZQ9cgj3CQ6bWBdytfrJMuMQ1jdXLFGnr4HhvtCTnM";
+const NEAR_API_JS_TX: &str = "09000000746573742e6e65617200917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d01000000000000000d00000077686174657665722e6e6561720fa473fd26901df296be6adc4cc4df34d040efa2435224b6986910e630c2fef6010000000301000000000000000000000000000000";
+// The test checks the signature in base64, `lpqDMyGG7pdV5IOTJVJYBuGJo9LS...`.
+const NEAR_API_JS_SIGNATURE: &str = "ed25519:41eAXToSZSqFqHCDZKnK7QnYANgRY5mSgHz2P19NaLYqphwsdk4HA6QSd11kdKgqL4wa6hg7wvwt1V3wE6kbRxWt";
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn nearcore_transaction() -> Transaction {
+    let public_key: PublicKey = NEARCORE_PUBLIC_KEY.parse().unwrap();
+    TransactionBuilder::new(
+        "test.near".parse().unwrap(),
+        public_key,
+        "123".parse().unwrap(),
+    )
+    .create_account()
+    .deploy_contract(&[1, 2, 3])
+    .function_call(
+        "qqq",
+        FunctionArgs::from_bytes(&[1, 2, 3]),
+        NearGas::from_gas(1_000),
+        NearToken::from_yoctonear(1_000_000),
+    )
+    .transfer(NearToken::from_yoctonear(123))
+    .action(NonDelegateAction::Stake(StakeAction {
+        public_key,
+        stake: NearToken::from_yoctonear(1_000_000),
+    }))
+    .add_key(
+        public_key,
+        AccessKeyPermission::FunctionCall(FunctionCallPermission {
+            allowance: None,
+            method_names: vec!["www".to_string()],
+            receiver_id: "zzz".to_string(),
+        }),
+    )
+    .delete_key(public_key)
+    .delete_account("123".parse().unwrap())
+    .build(1, CryptoHash::default())
+}
+
+fn nearcore_delegate_action() -> SignedDelegateAction {
+    SignedDelegateAction {
+        delegate_action: DelegateAction {
+            sender_id: "aaa".parse().unwrap(),
+            receiver_id: "bbb".parse().unwrap(),
+            actions: vec![NonDelegateAction::CreateAccount(CreateAccountAction(
+                serde_json::Map::new(),
+            ))],
+            nonce: 1,
+            max_block_height: 2,
+            public_key: PublicKey::Ed25519([0; 32]),
+        },
+        signature: Signature::Ed25519([0; 64]),
+    }
+}
+
+fn near_api_js_transaction() -> Transaction {
+    TransactionBuilder::new(
+        "test.near".parse().unwrap(),
+        NEAR_API_JS_PUBLIC_KEY.parse().unwrap(),
+        "whatever.near".parse().unwrap(),
+    )
+    .transfer(NearToken::from_yoctonear(1))
+    .build(1, NEAR_API_JS_BLOCK_HASH.parse().unwrap())
+}
+
+#[test]
+fn test_nearcore_transaction_hash() {
+    let tx = nearcore_transaction();
+    assert_eq!(tx.hash().unwrap().to_string(), NEARCORE_TX_HASH);
+
+    let decoded = Transaction::decode(&tx.encode().unwrap()).unwrap();
+    assert_eq!(decoded, tx);
+    assert_eq!(decoded.hash().unwrap().to_string(), NEARCORE_TX_HASH);
+}
+
+#[test]
+fn test_nearcore_delegate_action() {
+    let signed = nearcore_delegate_action();
+    let action = Action::from(signed.clone());
+    assert_eq!(
+        hex(&borsh::to_vec(&action).unwrap()),
+        NEARCORE_DELEGATE_ACTION
+    );
+    assert_eq!(
+        signed.delegate_action.hash().unwrap().to_string(),
+        NEARCORE_DELEGATE_ACTION_HASH
+    );
+}
+
+#[test]
+fn test_nearcore_receipt_ids() {
+    let signed = nearcore_transaction().with_signature(Signature::Ed25519([0; 64]));
+    let receipt_id = signed.receipt_id(100).unwrap();
+    assert_eq!(receipt_id.to_string(), NEARCORE_RECEIPT_ID);
+    assert_eq!(
+        child_receipt_id(&receipt_id, 101, 1).to_string(),
+        NEARCORE_CHILD_RECEIPT_ID
+    );
+}
+
+#[test]
+fn test_near_api_js_transaction_encoding() {
+    let tx = near_api_js_transaction();
+    assert_eq!(hex(&tx.encode().unwrap()), NEAR_API_JS_TX);
+}
+
+#[test]
+fn test_near_api_js_transaction_signature() {
+    let secret_key: SecretKey = NEAR_API_JS_SECRET_KEY.parse().unwrap();
+    assert_eq!(secret_key.public_key().to_string(), NEAR_API_JS_PUBLIC_KEY);
+
+    let signed = near_api_js_transaction().sign(&secret_key).unwrap();
+    assert_eq!(signed.signature.to_string(), NEAR_API_JS_SIGNATURE);
+    let encoded = signed.encode().unwrap();
+    assert_eq!(hex(&encoded[..NEAR_API_JS_TX.len() / 2]), NEAR_API_JS_TX);
+    assert_eq!(SignedTransaction::decode(&encoded).unwrap(), signed);
+}
@@ -0,0 +1,119 @@
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use near_openapi_client::builder::{ClientBuilderError, HeaderMap, HeaderName, HeaderValue};
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{RpcStatusRequest, RpcStatusResponse};
+use near_openapi_client::{Client, Error};
+
+const SYNCING: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"NODE_SYNCING"},"code":-32000,"message":"Server error"}}"#;
+
+/// Answers one request with a JSON-RPC error, sending the request it got.
+fn serve_one() -> (String, mpsc::Receiver<String>) {
+    serve_one_after(Duration::ZERO)
+}
+
+/// Answers one request with a JSON-RPC error `delay` after reading it.
+fn serve_one_after(delay: Duration) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        std::thread::sleep(delay);
+        let mut request = [0; 4096];
+        let len = stream.read(&mut request).unwrap();
+        sender
+            .send(String::from_utf8_lossy(&request[..len]).to_lowercase())
+            .unwrap();
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            SYNCING.len(),
+            SYNCING
+        );
+    });
+    (url, receiver)
+}
+
+#[tokio::test]
+async fn test_client_builder_sends_headers() {
+    let (url, requests) = serve_one();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("x-team"),
+        HeaderValue::from_static("indexer"),
+    );
+    let client = Client::builder(&url)
+        .default_headers(headers)
+        .with_api_key("secret-key")
+        .with_bearer_token("secret-token")
+        .build()
+        .unwrap();
+
+    let _ = RpcClient::from(client)
+        .call::<_, RpcStatusResponse, serde_json::Value>("status", &RpcStatusRequest(()))
+        .await;
+    let request = requests.recv().unwrap();
+    assert!(request.contains("x-team: indexer\r\n"));
+    assert!(request.contains("x-api-key: secret-key\r\n"));
+    assert!(request.contains("authorization: bearer secret-token\r\n"));
+}
+
+#[test]
+fn test_client_builder_rejects_invalid_header() {
+    let err = Client::builder("http://localhost:3030")
+        .with_api_key("line\nbreak")
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, ClientBuilderError::InvalidHeader(_)));
+}
+
+#[test]
+fn test_client_builder_hides_secrets_from_debug() {
+    let builder = Client::builder("http://localhost:3030").with_api_key("secret-key");
+    assert!(!format!("{:?}", builder).contains("secret-key"));
+}
+
+async fn status(client: &RpcClient) -> Result<RpcStatusResponse, RpcError<serde_json::Value>> {
+    client.call("status", &RpcStatusRequest(())).await
+}
+
+#[tokio::test]
+async fn test_method_timeout_shortens_timeout() {
+    let (url, _requests) = serve_one_after(Duration::from_secs(5));
+    let client = Client::builder(&url)
+        .connect_timeout(Some(Duration::from_secs(1)))
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(Some(Duration::from_secs(30)))
+        .tcp_keepalive(Some(Duration::from_secs(30)))
+        .method_timeout("status", Duration::from_millis(200))
+        .build_rpc()
+        .unwrap();
+
+    let err = status(&client).await.unwrap_err();
+    assert!(
+        matches!(&err, RpcError::Client(Error::CommunicationError(err)) if err.is_timeout()),
+        "{:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_method_timeout_lengthens_timeout() {
+    let (url, _requests) = serve_one_after(Duration::from_millis(500));
+    let client = RpcClient::from(
+        Client::builder(&url)
+            .timeout(Some(Duration::from_millis(100)))
+            .connect_timeout(Some(Duration::from_secs(1)))
+            .build()
+            .unwrap(),
+    )
+    .with_method_timeout("status", Duration::from_secs(5));
+
+    let err = status(&client).await.unwrap_err();
+    assert!(matches!(err, RpcError::Rpc(_)), "{:?}", err);
+}
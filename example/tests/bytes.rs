@@ -0,0 +1,53 @@
+use near_openapi_client::types::{
+    CallResult, ContractCodeView, CryptoHash, ExecutionStatusView, FinalExecutionStatus,
+    FunctionArgs, StoreKey,
+};
+
+#[test]
+fn test_decode_base64_fields() {
+    let code = ContractCodeView {
+        code_base64: "AGFzbQEAAAA=".to_string(),
+        hash: CryptoHash::default(),
+    };
+    assert_eq!(code.code_bytes().unwrap(), b"\0asm\x01\0\0\0");
+    assert_eq!(
+        FunctionArgs::from_bytes(b"{}").to_bytes().unwrap(),
+        b"{}".to_vec()
+    );
+    assert!(StoreKey("not base64!".to_string()).to_bytes().is_err());
+}
+
+#[test]
+fn test_success_value_bytes() {
+    let status = FinalExecutionStatus::SuccessValue("IjUi".to_string());
+    assert_eq!(
+        status.success_value_bytes().unwrap().unwrap(),
+        br#""5""#.to_vec()
+    );
+    assert!(
+        FinalExecutionStatus::NotStarted
+            .success_value_bytes()
+            .is_none()
+    );
+    assert_eq!(
+        ExecutionStatusView::SuccessValue(String::new())
+            .success_value_bytes()
+            .unwrap()
+            .unwrap(),
+        Vec::<u8>::new()
+    );
+}
+
+#[test]
+fn test_json_args_and_results() {
+    let args = FunctionArgs::from_json(&serde_json::json!({"account_id": "alice.near"})).unwrap();
+    let decoded: serde_json::Value = args.to_json().unwrap();
+    assert_eq!(decoded["account_id"], "alice.near");
+    assert!(args.to_json::<u64>().is_err());
+
+    let result = CallResult {
+        logs: vec![],
+        result: br#"["1", "2"]"#.to_vec(),
+    };
+    assert_eq!(result.json::<Vec<String>>().unwrap(), ["1", "2"]);
+}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use near_openapi_client::cache::ResponseCache;
+use near_openapi_client::methods;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types;
+
+fn at_height(height: u64) -> types::RpcGasPriceRequest {
+    types::RpcGasPriceRequest {
+        block_id: Some(types::BlockId::BlockHeight(height)),
+    }
+}
+
+fn at_hash(hash: &str) -> types::RpcGasPriceRequest {
+    types::RpcGasPriceRequest {
+        block_id: Some(types::BlockId::CryptoHash(hash.parse().unwrap())),
+    }
+}
+
+fn gas_price(yocto: u128) -> types::RpcGasPriceResponse {
+    types::RpcGasPriceResponse {
+        gas_price: types::NearToken::from_yoctonear(yocto),
+    }
+}
+
+fn golden_block() -> types::RpcBlockResponse {
+    serde_json::from_str(include_str!("golden/block.json")).unwrap()
+}
+
+const HASH: &str = "AmDsC4pxnE1FxJyZvTYLZR448e3yz2qHWCFFuHu3MiUu";
+const OTHER_HASH: &str = "9rsXAb1Rc2tgtXRZV5DKhbwYyVj2Z7ufd5Pu3FmvSpVw";
+
+#[tokio::test]
+async fn test_caches_requests_at_block_hash() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_hash(HASH))
+        .return_ok(gas_price(100));
+    let cache = ResponseCache::new(10);
+    let client = mock.client().with_cache(cache.clone());
+
+    for _ in 0..3 {
+        let response = client.gas_price(&at_hash(HASH)).await.unwrap();
+        assert_eq!(response, gas_price(100));
+    }
+    assert_eq!(mock.received().len(), 1);
+    assert_eq!(cache.len(), 1);
+
+    // Clones of the client share the cache.
+    let response = client.clone().gas_price(&at_hash(HASH)).await.unwrap();
+    assert_eq!(response, gas_price(100));
+    assert_eq!(mock.received().len(), 1);
+}
+
+#[tokio::test]
+async fn test_caches_heights_once_final() {
+    let mock = MockRpc::start();
+    let block = golden_block();
+    let final_height = block.header.height;
+    mock.expect::<methods::Block>()
+        .times(2)
+        .return_ok(block.clone());
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_height(final_height + 1))
+        .times(2)
+        .return_ok(gas_price(1));
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_height(final_height))
+        .return_ok(gas_price(2));
+    let cache = ResponseCache::new(10);
+    let client = mock.client().with_cache(cache.clone());
+
+    let final_block = types::RpcBlockRequest::Finality(types::Finality::Final);
+    for _ in 0..2 {
+        client.block(&final_block).await.unwrap();
+    }
+    assert_eq!(cache.final_height(), Some(final_height));
+    assert!(cache.is_empty());
+
+    for _ in 0..2 {
+        let response = client
+            .gas_price(&at_height(final_height + 1))
+            .await
+            .unwrap();
+        assert_eq!(response, gas_price(1));
+        let response = client.gas_price(&at_height(final_height)).await.unwrap();
+        assert_eq!(response, gas_price(2));
+    }
+    assert_eq!(cache.len(), 1);
+}
+
+#[tokio::test]
+async fn test_does_not_cache_optimistic_blocks_and_errors() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::Block>()
+        .times(2)
+        .return_ok(golden_block());
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_hash(HASH))
+        .times(2)
+        .return_error(types::ErrorWrapperForRpcGasPriceError::HandlerError(
+            types::RpcGasPriceError::UnknownBlock(serde_json::Map::new()),
+        ));
+    let cache = ResponseCache::new(10);
+    cache.set_final_height(u64::MAX);
+    let client = mock.client().with_cache(cache.clone());
+
+    let optimistic = types::RpcBlockRequest::Finality(types::Finality::Optimistic);
+    for _ in 0..2 {
+        client.block(&optimistic).await.unwrap();
+        client.gas_price(&at_hash(HASH)).await.unwrap_err();
+    }
+    assert!(cache.is_empty());
+}
+
+#[tokio::test]
+async fn test_evicts_least_recently_used() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_hash(HASH))
+        .return_ok(gas_price(1));
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_hash(OTHER_HASH))
+        .times(2)
+        .return_ok(gas_price(2));
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_height(1))
+        .return_ok(gas_price(3));
+    let cache = ResponseCache::new(2);
+    cache.set_final_height(1);
+    let client = mock.client().with_cache(cache.clone());
+
+    client.gas_price(&at_hash(HASH)).await.unwrap();
+    client.gas_price(&at_hash(OTHER_HASH)).await.unwrap();
+    // Using the first response makes the second the least recently used.
+    client.gas_price(&at_hash(HASH)).await.unwrap();
+    client.gas_price(&at_height(1)).await.unwrap();
+    assert_eq!(cache.len(), 2);
+
+    client.gas_price(&at_hash(HASH)).await.unwrap();
+    client.gas_price(&at_height(1)).await.unwrap();
+    let response = client.gas_price(&at_hash(OTHER_HASH)).await.unwrap();
+    assert_eq!(response, gas_price(2));
+    assert_eq!(mock.received().len(), 4);
+}
+
+#[tokio::test]
+async fn test_expires_after_ttl() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GasPrice>()
+        .times(2)
+        .return_ok(gas_price(1));
+    let cache = ResponseCache::new(10).with_ttl(Duration::from_millis(100));
+    let client = mock.client().with_cache(cache.clone());
+
+    client.gas_price(&at_hash(HASH)).await.unwrap();
+    client.gas_price(&at_hash(HASH)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    client.gas_price(&at_hash(HASH)).await.unwrap();
+    assert_eq!(mock.received().len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use near_openapi_client::chain_signatures;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::chain_signatures::{
+    MAINNET_CONTRACT, SIGN_DEPOSIT, SIGN_GAS, SignRequest, SignatureResponse, derive_epsilon,
+    derive_public_key,
+};
+use near_openapi_client::types::tx::{Action, TransactionBuilder};
+use near_openapi_client::types::{
+    self, CryptoHash, FinalExecutionStatus, FunctionArgs, NonDelegateAction,
+};
+
+// Public key of the secp256k1 secret key 1, the generator point.
+const GENERATOR: &str = "secp256k1:3SB8tA9Kbn7FBtT6GWR6AJk73QceudisHaGThPoLCDgC9tan7d3cwZFiDZtrmhSAf8aTynEdQ3N7KXhMm3nWhekP";
+
+#[test]
+fn test_sign_request() {
+    let request = SignRequest::new([7; 32], "ethereum-1");
+    assert_eq!(
+        request.args().to_json::<serde_json::Value>().unwrap(),
+        serde_json::json!({
+            "request": {"payload": vec![7; 32], "path": "ethereum-1", "key_version": 0}
+        })
+    );
+
+    let tx = TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        GENERATOR.parse().unwrap(),
+        MAINNET_CONTRACT.parse().unwrap(),
+    )
+    .mpc_sign(&request)
+    .build(1, CryptoHash::default());
+    let [Action::NonDelegate(NonDelegateAction::FunctionCall(call))] = tx.actions.as_slice() else {
+        panic!("expected a single function call");
+    };
+    assert_eq!(call.method_name, "sign");
+    assert_eq!(FunctionArgs(call.args.clone()), request.args());
+    assert_eq!((call.gas, call.deposit), (SIGN_GAS, SIGN_DEPOSIT));
+}
+
+#[test]
+fn test_signature_response() {
+    let r = "a".repeat(64);
+    let s = "b".repeat(64);
+    let json = format!(
+        r#"{{"scheme":"Secp256k1","big_r":{{"affine_point":"03{r}"}},"s":{{"scalar":"{s}"}},"recovery_id":1}}"#
+    );
+    let status = FinalExecutionStatus::SuccessValue(FunctionArgs::from_bytes(json.as_bytes()).0);
+    let response = SignatureResponse::from_status(&status).unwrap();
+    assert_eq!(response.recovery_id, 1);
+
+    let bytes = response.to_bytes().unwrap();
+    assert_eq!(bytes[..32], [0xaa; 32]);
+    assert_eq!(bytes[32..64], [0xbb; 32]);
+    assert_eq!(bytes[64], 1);
+
+    assert!(SignatureResponse::from_status(&FinalExecutionStatus::Started).is_err());
+}
+
+#[test]
+fn test_derive_public_key() {
+    let predecessor_id = "alice.near".parse().unwrap();
+    assert_eq!(
+        derive_epsilon(&predecessor_id, "ethereum-1")[..4],
+        [0xe4, 0xc9, 0xcc, 0x34]
+    );
+    // The derived key is root + epsilon * G, with root = G here.
+    assert_eq!(
+        derive_public_key(&GENERATOR.parse().unwrap(), &predecessor_id, "ethereum-1")
+            .unwrap()
+            .to_string(),
+        "secp256k1:4tpvug6FdpUa5kN1oQiKzfXqvk9868tRQx5TjA5gKQWVzHkM5ka8V7zFYLCdsKjtDbMM9Be5HGJM6hAR2c9T57ey"
+    );
+    assert!(
+        derive_public_key(
+            &"ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+                .parse()
+                .unwrap(),
+            &predecessor_id,
+            "ethereum-1"
+        )
+        .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_mpc_public_key() {
+    let result = format!("{:?}", GENERATOR);
+    let url = common::serve(vec![(
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"11111111111111111111111111111111","block_height":10,"logs":[],"result":{:?}}}}}"#,
+            result.as_bytes()
+        ),
+    )]);
+    let client = RpcClient::new(&url);
+
+    let root = chain_signatures::mpc_public_key(
+        &client,
+        &MAINNET_CONTRACT.parse().unwrap(),
+        types::BlockReference::Finality(types::Finality::Final),
+    )
+    .await
+    .unwrap();
+    assert_eq!(root.to_string(), GENERATOR);
+}
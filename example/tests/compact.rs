@@ -0,0 +1,61 @@
+use near_openapi_client::types::compact::{CompactTransaction, TextEncoding};
+use near_openapi_client::types::tx::{SecretKey, TransactionBuilder};
+use near_openapi_client::types::{CryptoHash, NearToken};
+
+const SECRET_KEY: &str = "ed25519:49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+
+fn unsigned() -> CompactTransaction {
+    let secret_key: SecretKey = SECRET_KEY.parse().unwrap();
+    TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        secret_key.public_key(),
+        "bob.near".parse().unwrap(),
+    )
+    .transfer(NearToken::from_near(1))
+    .build(7, CryptoHash([1; 32]))
+    .into()
+}
+
+#[test]
+fn test_round_trip() {
+    let CompactTransaction::Unsigned(tx) = unsigned() else {
+        unreachable!()
+    };
+    let signed: CompactTransaction = tx.sign(&SECRET_KEY.parse().unwrap()).unwrap().into();
+
+    for message in [unsigned(), signed] {
+        for encoding in [TextEncoding::Base45, TextEncoding::Base64Url] {
+            let text = message.encode(encoding).unwrap();
+            assert_eq!(
+                CompactTransaction::decode(&text, encoding).unwrap(),
+                message
+            );
+        }
+    }
+}
+
+#[test]
+fn test_base45_alphabet() {
+    let text = unsigned().encode(TextEncoding::Base45).unwrap();
+    assert!(
+        text.bytes()
+            .all(|c| b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:".contains(&c))
+    );
+    // 3 characters for every 2 bytes.
+    let bytes = unsigned().to_bytes().unwrap();
+    assert_eq!(text.len(), bytes.len() / 2 * 3 + bytes.len() % 2 * 2);
+    assert!(CompactTransaction::decode(&text.to_lowercase(), TextEncoding::Base45).is_err());
+}
+
+#[test]
+fn test_envelope_is_checked() {
+    let bytes = unsigned().to_bytes().unwrap();
+    assert_eq!(bytes[0], 1);
+    assert_eq!(CompactTransaction::from_bytes(&bytes).unwrap(), unsigned());
+
+    let mut corrupted = bytes.clone();
+    corrupted[10] ^= 1;
+    assert!(CompactTransaction::from_bytes(&corrupted).is_err());
+    assert!(CompactTransaction::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(CompactTransaction::from_bytes(&[1, 0]).is_err());
+}
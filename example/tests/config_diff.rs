@@ -0,0 +1,76 @@
+use near_openapi_client::types::{
+    ActionCreationConfigView, ExtCostsConfigView, Fee, NearGas, NearToken, RuntimeConfigView,
+    RuntimeFeesConfigView, VmConfigView,
+};
+
+fn fee(execution: u64) -> Fee {
+    Fee {
+        execution: NearGas::from_gas(execution),
+        send_not_sir: NearGas::from_gas(2),
+        send_sir: NearGas::from_gas(1),
+    }
+}
+
+fn config(transfer_execution: u64, storage_write_base: Option<u64>) -> RuntimeConfigView {
+    RuntimeConfigView {
+        storage_amount_per_byte: Some(NearToken::from_yoctonear(10u128.pow(19))),
+        transaction_costs: Some(RuntimeFeesConfigView {
+            action_creation_config: Some(ActionCreationConfigView {
+                transfer_cost: Some(fee(transfer_execution)),
+                create_account_cost: Some(fee(5)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        wasm_config: Some(VmConfigView {
+            ext_costs: Some(ExtCostsConfigView {
+                base: Some(NearGas::from_gas(100)),
+                storage_write_base: storage_write_base.map(NearGas::from_gas),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_same_config_has_no_changes() {
+    let diff = config(3, Some(7)).diff(&config(3, Some(7)));
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+fn test_changed_fee_tables() {
+    let diff = config(3, None).diff(&config(4, Some(7)));
+    let paths: Vec<_> = diff
+        .changes
+        .iter()
+        .map(|change| change.path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "transaction_costs.action_creation_config.transfer_cost.execution",
+            "wasm_config.ext_costs.storage_write_base",
+        ]
+    );
+
+    let transfer = diff
+        .get("transaction_costs.action_creation_config.transfer_cost.execution")
+        .unwrap();
+    assert_eq!(transfer.old, Some(serde_json::json!("3")));
+    assert_eq!(transfer.new, Some(serde_json::json!("4")));
+    let write = diff
+        .get("wasm_config.ext_costs.storage_write_base")
+        .unwrap();
+    assert_eq!(write.old, None);
+    assert_eq!(
+        write.to_string(),
+        "wasm_config.ext_costs.storage_write_base: (none) -> \"7\""
+    );
+
+    assert_eq!(diff.under("wasm_config").count(), 1);
+    assert_eq!(diff.under("wasm_config.ext").count(), 0);
+    assert_eq!(diff.under("transaction_costs").count(), 1);
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use near_openapi_client::contract::{ContractCall, DEFAULT_GAS};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::tx::Action;
+use near_openapi_client::types::{
+    AccountId, BlockReference, Finality, FunctionCallAction, NearGas, NearToken, NonDelegateAction,
+    PublicKey,
+};
+
+near_openapi_client::near_contract! {
+    /// A NEP-141 fungible token.
+    pub struct FungibleToken {
+        view fn ft_balance_of(account_id: AccountId) -> String;
+        view fn ft_total_supply() -> String;
+        call fn ft_transfer(receiver_id: AccountId, amount: String, memo: Option<String>,);
+    }
+}
+
+fn call_result(result: &str) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"11111111111111111111111111111111","block_height":10,"logs":[],"result":{:?}}}}}"#,
+            result.as_bytes()
+        ),
+    )
+}
+
+fn token(url: &str) -> FungibleToken {
+    FungibleToken::new(
+        RpcClient::new(url),
+        "usdt.tether-token.near".parse().unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_view_method() {
+    let url = common::serve(vec![call_result(r#""1000""#), call_result(r#""5""#)]);
+    let token = token(&url);
+
+    let balance = token
+        .ft_balance_of(
+            "alice.near".parse().unwrap(),
+            BlockReference::Finality(Finality::Final),
+        )
+        .await
+        .unwrap();
+    assert_eq!(balance, "1000");
+    let supply = token
+        .ft_total_supply(BlockReference::Finality(Finality::Final))
+        .await
+        .unwrap();
+    assert_eq!(supply, "5");
+}
+
+#[test]
+fn test_call_method() {
+    let token = token("http://localhost:3030");
+    let call = token
+        .ft_transfer("bob.near".parse().unwrap(), "10".to_string(), None)
+        .unwrap()
+        .deposit(NearToken::from_yoctonear(1));
+    assert_eq!(call.contract_id, *token.contract_id());
+    assert_eq!(call.method_name, "ft_transfer");
+    assert_eq!(call.gas, DEFAULT_GAS);
+    let args: serde_json::Value = call.args.to_json().unwrap();
+    assert_eq!(
+        args,
+        serde_json::json!({"receiver_id": "bob.near", "amount": "10", "memo": null}),
+        "{:?}",
+        call
+    );
+
+    let expected = ContractCall::new(
+        token.contract_id().clone(),
+        "ft_transfer",
+        &serde_json::json!({"receiver_id": "bob.near", "amount": "10", "memo": null}),
+    )
+    .unwrap()
+    .gas(NearGas::from_tgas(30))
+    .deposit(NearToken::from_yoctonear(1));
+    assert_eq!(call, expected);
+
+    let action = Action::from(call.clone());
+    assert_eq!(
+        action,
+        Action::NonDelegate(NonDelegateAction::FunctionCall(FunctionCallAction {
+            args: call.args.0.clone(),
+            deposit: NearToken::from_yoctonear(1),
+            gas: DEFAULT_GAS,
+            method_name: "ft_transfer".to_string(),
+        }))
+    );
+    let public_key: PublicKey = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+        .parse()
+        .unwrap();
+    let _ = call.transaction("alice.near".parse().unwrap(), public_key);
+}
@@ -0,0 +1,80 @@
+use std::collections::{BTreeSet, HashSet};
+
+use near_openapi_client::types::CryptoHash;
+
+const ONES: &str = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+#[test]
+fn test_constructors_and_bytes() {
+    const ZERO: CryptoHash = CryptoHash::from_bytes([0; 32]);
+    assert_eq!(ZERO, CryptoHash::ZERO);
+    assert_eq!(CryptoHash::ZERO, CryptoHash::default());
+    assert!(CryptoHash::ZERO.is_zero());
+    assert_eq!(
+        CryptoHash::ZERO.to_string(),
+        "11111111111111111111111111111111"
+    );
+
+    let hash = CryptoHash::from([1; 32]);
+    assert!(!hash.is_zero());
+    assert_eq!(hash.as_bytes(), &[1; 32]);
+    assert_eq!(<[u8; 32]>::from(hash), [1; 32]);
+    assert_eq!(hash.as_ref(), &[1; 32][..]);
+    assert_eq!(CryptoHash::try_from(&[1; 32][..]).unwrap(), hash);
+    assert!(CryptoHash::try_from(&[1; 31][..]).is_err());
+
+    // Copy, Hash and Ord, for use as map keys without cloning.
+    let copy = hash;
+    assert_eq!(copy, hash);
+    assert_eq!(HashSet::from([hash, copy]).len(), 1);
+    let sorted: Vec<_> = BTreeSet::from([hash, CryptoHash::ZERO])
+        .into_iter()
+        .collect();
+    assert_eq!(sorted, [CryptoHash::ZERO, hash]);
+}
+
+#[test]
+fn test_parse() {
+    let hash: CryptoHash = ONES.parse().unwrap();
+    assert_eq!(hash, CryptoHash([1; 32]));
+    assert_eq!(hash.to_string(), ONES);
+
+    for invalid in [
+        "",
+        "1111",
+        // Too large for 32 bytes, and a leading zero byte too many.
+        &format!("{}zz", ONES),
+        &format!("1{}", ONES),
+        "0OIl",
+    ] {
+        let result = invalid.parse::<CryptoHash>();
+        assert!(result.is_err(), "{:?} parsed as {:?}", invalid, result);
+    }
+    let error = "1111".parse::<CryptoHash>().unwrap_err();
+    assert_eq!(error.to_string(), "length for CryptoHash is not 32");
+}
+
+#[test]
+fn test_serde() {
+    let hash = CryptoHash([7; 32]);
+    let json = serde_json::to_string(&hash).unwrap();
+    assert_eq!(json, format!("\"{}\"", hash));
+    assert_eq!(serde_json::from_str::<CryptoHash>(&json).unwrap(), hash);
+    // Strings with escapes and from readers are not borrowed from the input.
+    assert_eq!(
+        serde_json::from_reader::<_, CryptoHash>(json.as_bytes()).unwrap(),
+        hash
+    );
+    assert_eq!(
+        serde_json::from_value::<CryptoHash>(serde_json::json!(hash.to_string())).unwrap(),
+        hash
+    );
+
+    let error = serde_json::from_str::<CryptoHash>("42").unwrap_err();
+    assert!(
+        error.to_string().contains("base58-encoded 32-byte hash"),
+        "{}",
+        error
+    );
+    assert!(serde_json::from_str::<CryptoHash>("\"1111\"").is_err());
+}
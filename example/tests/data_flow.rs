@@ -0,0 +1,138 @@
+use near_openapi_client::types::data_flow::DataFlow;
+use near_openapi_client::types::{ActionView, CryptoHash, ReceiptView};
+
+fn hash(n: u8) -> CryptoHash {
+    CryptoHash([n; 32])
+}
+
+fn action_receipt(
+    id: u8,
+    predecessor_id: &str,
+    receiver_id: &str,
+    method_name: &str,
+    input_data_ids: &[u8],
+    output_data_receivers: &[(u8, &str)],
+) -> ReceiptView {
+    serde_json::from_value(serde_json::json!({
+        "predecessor_id": predecessor_id,
+        "receipt": {"Action": {
+            "actions": [{"FunctionCall": {
+                "args": "e30=",
+                "deposit": "0",
+                "gas": 1000,
+                "method_name": method_name,
+            }}],
+            "gas_price": "100000000",
+            "input_data_ids": input_data_ids.iter().map(|&n| hash(n).to_string()).collect::<Vec<_>>(),
+            "output_data_receivers": output_data_receivers
+                .iter()
+                .map(|&(n, receiver_id)| serde_json::json!({
+                    "data_id": hash(n).to_string(),
+                    "receiver_id": receiver_id,
+                }))
+                .collect::<Vec<_>>(),
+            "signer_id": "alice.near",
+            "signer_public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+        }},
+        "receipt_id": hash(id).to_string(),
+        "receiver_id": receiver_id,
+    }))
+    .unwrap()
+}
+
+fn data_receipt(id: u8, predecessor_id: &str, receiver_id: &str, data_id: u8) -> ReceiptView {
+    serde_json::from_value(serde_json::json!({
+        "predecessor_id": predecessor_id,
+        "receipt": {"Data": {"data": "MQ==", "data_id": hash(data_id).to_string()}},
+        "receipt_id": hash(id).to_string(),
+        "receiver_id": receiver_id,
+    }))
+    .unwrap()
+}
+
+/// `dex.near` calls `ft_transfer` of `token.near` with the callback
+/// `on_transfer`, and the result of the call is sent back as data `hash(10)`.
+fn swap() -> Vec<ReceiptView> {
+    vec![
+        action_receipt(
+            1,
+            "dex.near",
+            "token.near",
+            "ft_transfer",
+            &[],
+            &[(10, "dex.near")],
+        ),
+        action_receipt(2, "dex.near", "dex.near", "on_transfer", &[10], &[]),
+        data_receipt(3, "token.near", "dex.near", 10),
+    ]
+}
+
+#[test]
+fn test_pairs_call_data_and_callback() {
+    let receipts = swap();
+    let flow = DataFlow::new(&receipts);
+
+    let dependency = flow.get(&hash(10)).unwrap();
+    assert!(dependency.is_complete(), "{:?}", dependency);
+    assert_eq!(dependency.producer, Some(&receipts[0]));
+    assert_eq!(dependency.data, Some(&receipts[2]));
+    assert_eq!(dependency.consumer, Some(&receipts[1]));
+    assert!(
+        receipts[0].output_data_receivers()[0].is_fulfilled_by(&receipts[2]),
+        "{:?}",
+        receipts
+    );
+
+    let consumers: Vec<_> = flow.consumers(&receipts[0]).collect();
+    assert_eq!(consumers, [&receipts[1]]);
+    let producers: Vec<_> = flow.producers(&receipts[1]).collect();
+    assert_eq!(producers, [&receipts[0]]);
+    assert_eq!(flow.consumers(&receipts[1]).count(), 0);
+    assert_eq!(flow.iter().count(), 1);
+}
+
+#[test]
+fn test_incomplete_and_misdirected_data() {
+    let mut receipts = swap();
+    // The data is sent to another account than the one the call promised.
+    receipts[2] = data_receipt(3, "token.near", "eve.near", 10);
+    // A second callback whose data was not seen.
+    receipts.push(action_receipt(
+        4,
+        "dex.near",
+        "dex.near",
+        "on_refund",
+        &[11],
+        &[],
+    ));
+    let flow = DataFlow::new(&receipts);
+
+    let misdirected = flow.get(&hash(10)).unwrap();
+    assert!(!misdirected.is_complete(), "{:?}", misdirected);
+    let missing = flow.get(&hash(11)).unwrap();
+    assert!(
+        missing.producer.is_none() && missing.data.is_none(),
+        "{:?}",
+        missing
+    );
+    assert_eq!(missing.consumer, Some(&receipts[3]));
+    assert!(flow.get(&hash(12)).is_none());
+}
+
+#[test]
+fn test_receipt_accessors() {
+    let receipts = swap();
+    let actions = receipts[0].actions().unwrap();
+    assert!(
+        matches!(&actions[0], ActionView::FunctionCall { method_name, .. } if method_name == "ft_transfer"),
+        "{:?}",
+        actions
+    );
+    assert_eq!(receipts[1].input_data_ids(), [hash(10)]);
+    assert_eq!(receipts[0].data_id(), None);
+
+    assert_eq!(receipts[2].actions(), None);
+    assert_eq!(receipts[2].data_id(), Some(&hash(10)));
+    assert!(receipts[2].input_data_ids().is_empty());
+    assert!(receipts[2].output_data_receivers().is_empty());
+}
@@ -0,0 +1,39 @@
+use near_openapi_client::types::descriptions::descriptions;
+
+#[test]
+fn test_descriptions_of_types_and_members() {
+    let descriptions = descriptions();
+    assert_eq!(
+        descriptions.type_description("BlockHeaderView"),
+        Some("Contains main info about the block.")
+    );
+    assert_eq!(
+        descriptions.member("FinalExecutionStatus", "NotStarted"),
+        Some("The execution has not yet started.")
+    );
+    assert!(
+        descriptions
+            .member("AccessKey", "nonce")
+            .unwrap()
+            .starts_with("Nonce for this access key")
+    );
+    assert_eq!(descriptions.member("BlockHeaderView", "height"), None);
+    assert_eq!(descriptions.type_description("NoSuchType"), None);
+}
+
+#[test]
+fn test_variant_fields_and_repo_docs() {
+    let descriptions = descriptions();
+    assert!(
+        descriptions
+            .member("ExternalStorageLocation", "S3.bucket")
+            .is_some()
+    );
+    // Docs added to the generated code, not taken from the spec, are left out.
+    assert!(descriptions.get("ActionErrorKind").is_some());
+    assert_eq!(descriptions.member("ActionErrorKind", "Unknown"), None);
+    assert_eq!(
+        descriptions.member("ExecutionStatusView", "Unknown"),
+        Some("The execution is pending or unknown.")
+    );
+}
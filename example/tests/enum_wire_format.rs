@@ -0,0 +1,76 @@
+use near_openapi_client::types;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Deserializes `json` as the node returns it and checks that serializing the
+/// value back produces the same JSON.
+fn assert_roundtrip<T: Serialize + DeserializeOwned + std::fmt::Debug>(json: &str) {
+    let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+    let value: T = serde_json::from_str(json)
+        .unwrap_or_else(|err| panic!("failed to deserialize {}: {}", json, err));
+    assert_eq!(
+        serde_json::to_value(&value).unwrap(),
+        expected,
+        "{:?}",
+        value
+    );
+}
+
+#[test]
+fn test_access_key_permission_view_wire_format() {
+    assert_roundtrip::<types::AccessKeyPermissionView>(r#""FullAccess""#);
+    assert_roundtrip::<types::AccessKeyPermissionView>(
+        r#"{"FunctionCall":{"allowance":"1000","method_names":["get"],"receiver_id":"app.near"}}"#,
+    );
+    assert_roundtrip::<types::AccessKeyPermissionView>(
+        r#"{"FunctionCall":{"method_names":[],"receiver_id":"app.near"}}"#,
+    );
+}
+
+#[test]
+fn test_action_error_kind_wire_format() {
+    for json in [
+        r#"{"AccountAlreadyExists":{"account_id":"alice.near"}}"#,
+        r#"{"AccountDoesNotExist":{"account_id":"alice.near"}}"#,
+        r#"{"CreateAccountOnlyByRegistrar":{"account_id":"alice","predecessor_id":"bob.near","registrar_account_id":"registrar"}}"#,
+        r#"{"CreateAccountNotAllowed":{"account_id":"alice.bob.near","predecessor_id":"carol.near"}}"#,
+        r#"{"ActorNoPermission":{"account_id":"alice.near","actor_id":"bob.near"}}"#,
+        r#"{"DeleteKeyDoesNotExist":{"account_id":"alice.near","public_key":"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"}}"#,
+        r#"{"AddKeyAlreadyExists":{"account_id":"alice.near","public_key":"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"}}"#,
+        r#"{"DeleteAccountStaking":{"account_id":"alice.near"}}"#,
+        r#"{"LackBalanceForState":{"account_id":"alice.near","amount":"182000000000000000000000"}}"#,
+        r#"{"TriesToUnstake":{"account_id":"alice.near"}}"#,
+        r#"{"TriesToStake":{"account_id":"alice.near","balance":"1","locked":"2","stake":"3"}}"#,
+        r#"{"InsufficientStake":{"account_id":"alice.near","minimum_stake":"10","stake":"1"}}"#,
+        r#"{"FunctionCallError":{"ExecutionError":"Smart contract panicked: oops"}}"#,
+        r#"{"FunctionCallError":"WasmUnknownError"}"#,
+        r#"{"FunctionCallError":"_EVMError"}"#,
+        r#"{"NewReceiptValidationError":{"InvalidReceiverId":{"account_id":"Alice"}}}"#,
+        r#"{"OnlyImplicitAccountCreationAllowed":{"account_id":"alice.near"}}"#,
+        r#"{"DeleteAccountWithLargeState":{"account_id":"alice.near"}}"#,
+        r#""DelegateActionInvalidSignature""#,
+        r#"{"DelegateActionSenderDoesNotMatchTxReceiver":{"receiver_id":"relayer.near","sender_id":"alice.near"}}"#,
+        r#""DelegateActionExpired""#,
+        r#"{"DelegateActionAccessKeyError":"RequiresFullAccess"}"#,
+        r#"{"DelegateActionAccessKeyError":{"MethodNameMismatch":{"method_name":"transfer"}}}"#,
+        r#"{"DelegateActionInvalidNonce":{"ak_nonce":5,"delegate_nonce":4}}"#,
+        r#"{"DelegateActionNonceTooLarge":{"delegate_nonce":100,"upper_bound":10}}"#,
+        r#"{"GlobalContractDoesNotExist":{"identifier":{"AccountId":"code.near"}}}"#,
+        r#"{"GlobalContractDoesNotExist":{"identifier":{"CodeHash":"11111111111111111111111111111111"}}}"#,
+        r#"{"GasKeyDoesNotExist":{"account_id":"alice.near","public_key":"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"}}"#,
+        r#"{"GasKeyAlreadyExists":{"account_id":"alice.near","public_key":"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"}}"#,
+    ] {
+        assert_roundtrip::<types::ActionErrorKind>(json);
+    }
+}
+
+#[test]
+fn test_unit_variant_is_a_bare_string() {
+    assert_eq!(
+        serde_json::to_string(&types::ActionErrorKind::DelegateActionExpired).unwrap(),
+        r#""DelegateActionExpired""#
+    );
+    assert_eq!(
+        serde_json::to_string(&types::AccessKeyPermissionView::FullAccess).unwrap(),
+        r#""FullAccess""#
+    );
+}
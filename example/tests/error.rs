@@ -0,0 +1,131 @@
+mod common;
+
+use near_openapi_client::error::{self, ErrorClass, FailureReason};
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{
+    ErrorWrapperForRpcQueryError, ErrorWrapperForRpcTransactionError, Finality, RpcBlockRequest,
+};
+
+fn tx_error(cause: serde_json::Value) -> RpcError<ErrorWrapperForRpcTransactionError> {
+    let error = serde_json::json!({"name": "HANDLER_ERROR", "cause": cause});
+    RpcError::Rpc(serde_json::from_value(error).unwrap())
+}
+
+fn invalid_transaction(err: serde_json::Value) -> RpcError<ErrorWrapperForRpcTransactionError> {
+    tx_error(serde_json::json!({
+        "name": "INVALID_TRANSACTION",
+        "info": {"TxExecutionError": err},
+    }))
+}
+
+fn query_error(cause: serde_json::Value) -> RpcError<ErrorWrapperForRpcQueryError> {
+    let error = serde_json::json!({"name": "HANDLER_ERROR", "cause": cause});
+    RpcError::Rpc(serde_json::from_value(error).unwrap())
+}
+
+#[test]
+fn test_classifies_handler_errors() {
+    let err = tx_error(serde_json::json!({"name": "TIMEOUT_ERROR"}));
+    assert_eq!(error::classify(&err), ErrorClass::Retryable);
+
+    let err = query_error(serde_json::json!({
+        "name": "UNKNOWN_ACCOUNT",
+        "info": {
+            "block_hash": "11111111111111111111111111111111",
+            "block_height": 1,
+            "requested_account_id": "alice.near",
+        },
+    }));
+    assert_eq!(error::classify(&err), ErrorClass::NotFound);
+
+    let err = query_error(serde_json::json!({
+        "name": "CONTRACT_EXECUTION_ERROR",
+        "info": {
+            "block_hash": "11111111111111111111111111111111",
+            "block_height": 1,
+            "vm_error": "wasm execution failed",
+        },
+    }));
+    assert_eq!(
+        error::classify(&err),
+        ErrorClass::ExecutionFailure {
+            reason: FailureReason::Contract("wasm execution failed".to_string()),
+        }
+    );
+
+    let err = tx_error(serde_json::json!({
+        "name": "INTERNAL_ERROR",
+        "info": {"debug_info": "oops"},
+    }));
+    assert_eq!(error::classify(&err), ErrorClass::Internal);
+}
+
+#[test]
+fn test_nonce_and_balance_predicates() {
+    let err = invalid_transaction(serde_json::json!({
+        "InvalidTxError": {"InvalidNonce": {"tx_nonce": 5, "ak_nonce": 7}},
+    }));
+    assert!(
+        matches!(
+            error::classify(&err),
+            ErrorClass::ExecutionFailure {
+                reason: FailureReason::Transaction(_)
+            }
+        ),
+        "{:?}",
+        err
+    );
+    assert!(error::is_nonce_error(&err));
+    assert!(!error::is_balance_error(&err));
+
+    let err = invalid_transaction(serde_json::json!({
+        "InvalidTxError": {"NotEnoughBalance": {
+            "signer_id": "alice.near",
+            "balance": "1",
+            "cost": "2",
+        }},
+    }));
+    assert!(error::is_balance_error(&err));
+    assert!(!error::is_nonce_error(&err));
+
+    let err = invalid_transaction(serde_json::json!({
+        "ActionError": {"index": 0, "kind": {"LackBalanceForState": {
+            "account_id": "alice.near",
+            "amount": "1",
+        }}},
+    }));
+    assert!(error::is_balance_error(&err));
+
+    let err = invalid_transaction(serde_json::json!({"InvalidTxError": "Expired"}));
+    assert!(!error::is_nonce_error(&err));
+    assert!(!error::is_balance_error(&err));
+}
+
+#[tokio::test]
+async fn test_classifies_client_errors() {
+    let validation = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "REQUEST_VALIDATION_ERROR",
+            "cause": {"name": "PARSE_ERROR", "info": {"error_message": "invalid params"}},
+            "code": -32700,
+            "message": "Parse error",
+        },
+    });
+    let url = common::serve(vec![
+        (503, "unavailable".to_string()),
+        (200, validation.to_string()),
+        (200, "not json".to_string()),
+    ]);
+    let client = RpcClient::new(&url);
+    let request = RpcBlockRequest::Finality(Finality::Final);
+
+    let err = client.block(&request).await.unwrap_err();
+    assert_eq!(error::classify(&err), ErrorClass::Retryable);
+    let err = client.block(&request).await.unwrap_err();
+    assert_eq!(error::classify(&err), ErrorClass::InvalidRequest);
+    let err = client.block(&request).await.unwrap_err();
+    assert_eq!(error::classify(&err), ErrorClass::Internal);
+}
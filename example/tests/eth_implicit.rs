@@ -0,0 +1,87 @@
+use near_openapi_client::types::eth_implicit::EvmAddress;
+use near_openapi_client::types::tx::{Action, TransactionBuilder};
+use near_openapi_client::types::{AccountId, CryptoHash, NearToken, NonDelegateAction};
+
+// Public key of the secp256k1 secret key 1, the generator point.
+const GENERATOR: &str = "secp256k1:3SB8tA9Kbn7FBtT6GWR6AJk73QceudisHaGThPoLCDgC9tan7d3cwZFiDZtrmhSAf8aTynEdQ3N7KXhMm3nWhekP";
+
+#[test]
+fn test_address_of_public_key() {
+    let address = EvmAddress::from_public_key(&GENERATOR.parse().unwrap()).unwrap();
+    assert_eq!(
+        address.to_string(),
+        "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+    );
+    assert_eq!(
+        address.account_id().as_str(),
+        "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+    );
+    assert_eq!(
+        EvmAddress::from_account_id(&address.account_id()),
+        Some(address)
+    );
+    assert_eq!(
+        EvmAddress::from_account_id(&"alice.near".parse().unwrap()),
+        None
+    );
+    assert!(
+        EvmAddress::from_public_key(
+            &"ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+                .parse()
+                .unwrap()
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_eip55_checksum() {
+    // Test vectors of EIP-55.
+    for checksummed in [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+        let address: EvmAddress = checksummed.parse().unwrap();
+        assert_eq!(address.to_checksum_string(), checksummed);
+        assert_eq!(
+            checksummed.to_lowercase().parse::<EvmAddress>().unwrap(),
+            address
+        );
+    }
+    assert!(
+        "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            .parse::<EvmAddress>()
+            .is_err()
+    );
+    assert!(
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea"
+            .parse::<EvmAddress>()
+            .is_err()
+    );
+    assert!(
+        "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+            .parse::<EvmAddress>()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_transfer_to_evm_address() {
+    let address: EvmAddress = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        .parse()
+        .unwrap();
+    let tx = TransactionBuilder::transfer_to_evm_address(
+        "alice.near".parse().unwrap(),
+        GENERATOR.parse().unwrap(),
+        address,
+        NearToken::from_near(1),
+    )
+    .build(1, CryptoHash::default());
+    assert_eq!(tx.receiver_id, AccountId::from(address));
+    assert!(matches!(
+        tx.actions.as_slice(),
+        [Action::NonDelegate(NonDelegateAction::Transfer(_))]
+    ));
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use near_openapi_client::fees::{FeeError, FeeEstimator};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::tx::TransactionBuilder;
+use near_openapi_client::types::{
+    ActionCreationConfigView, BlockReference, DelegateAction, Fee, Finality, FunctionArgs, NearGas,
+    NearToken, NonDelegateAction, RpcProtocolConfigResponse, RuntimeConfigView,
+    RuntimeFeesConfigView, SignedDelegateAction, TransferAction,
+};
+
+const HASH: &str = "11111111111111111111111111111111";
+const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const SIGNATURE: &str = "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj";
+
+fn fee(send_sir: u64, send_not_sir: u64, execution: u64) -> Fee {
+    Fee {
+        execution: NearGas::from_gas(execution),
+        send_not_sir: NearGas::from_gas(send_not_sir),
+        send_sir: NearGas::from_gas(send_sir),
+    }
+}
+
+/// Fees of receipts, transfers, function calls and delegate actions only.
+fn fees() -> RuntimeFeesConfigView {
+    RuntimeFeesConfigView {
+        action_creation_config: Some(ActionCreationConfigView {
+            function_call_cost: Some(fee(100, 200, 300)),
+            function_call_cost_per_byte: Some(fee(1, 2, 3)),
+            transfer_cost: Some(fee(10, 20, 30)),
+            delegate_cost: Some(fee(1000, 2000, 3000)),
+            ..Default::default()
+        }),
+        action_receipt_creation_config: Some(fee(1, 2, 3)),
+        ..Default::default()
+    }
+}
+
+fn builder(receiver_id: &str) -> TransactionBuilder {
+    TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        PUBLIC_KEY.parse().unwrap(),
+        receiver_id.parse().unwrap(),
+    )
+}
+
+#[test]
+fn test_transfer_to_self() {
+    let estimator = FeeEstimator::new(fees(), NearToken::from_yoctonear(100));
+    let transaction = builder("alice.near")
+        .transfer(NearToken::from_yoctonear(5))
+        .build(1, HASH.parse().unwrap());
+
+    let estimate = estimator.estimate_transaction(&transaction).unwrap();
+    // Receipt 1 + 3, transfer 10 + 30, at the cheaper send_sir fees.
+    assert_eq!(estimate.gas, NearGas::from_gas(44));
+    assert_eq!(estimate.gas_cost, NearToken::from_yoctonear(4400));
+    assert_eq!(estimate.deposit, NearToken::from_yoctonear(5));
+    assert_eq!(estimate.total, NearToken::from_yoctonear(4405));
+}
+
+#[test]
+fn test_function_call_includes_attached_gas() {
+    let estimator = FeeEstimator::new(fees(), NearToken::from_yoctonear(1));
+    let transaction = builder("token.near")
+        .function_call(
+            "ft",
+            FunctionArgs::from_bytes(b"{}"),
+            NearGas::from_gas(1000),
+            NearToken::from_yoctonear(1),
+        )
+        .build(1, HASH.parse().unwrap());
+
+    let estimate = estimator.estimate_total_cost(&transaction.actions).unwrap();
+    // Receipt 2 + 3, call 200 + 300, 4 bytes of method name and arguments at
+    // 2 + 3 each, and the attached gas.
+    assert_eq!(estimate.gas, NearGas::from_gas(1525));
+    assert_eq!(estimate.total, NearToken::from_yoctonear(1526));
+}
+
+#[test]
+fn test_delegate_leaves_out_inner_deposits() {
+    let estimator = FeeEstimator::new(fees(), NearToken::from_yoctonear(1));
+    let delegate_action = DelegateAction {
+        actions: vec![NonDelegateAction::Transfer(TransferAction {
+            deposit: NearToken::from_near(1),
+        })],
+        max_block_height: 100,
+        nonce: 7,
+        public_key: PUBLIC_KEY.parse().unwrap(),
+        receiver_id: "bob.near".parse().unwrap(),
+        sender_id: "carol.near".parse().unwrap(),
+    };
+    let transaction = builder("carol.near")
+        .action(SignedDelegateAction {
+            signature: SIGNATURE.parse().unwrap(),
+            delegate_action,
+        })
+        .build(1, HASH.parse().unwrap());
+
+    let estimate = estimator.estimate_transaction(&transaction).unwrap();
+    // Receipt 2 + 3, delegate 2000 + 3000, inner receipt 2 + 3 and transfer
+    // 20 + 30. The transferred token is paid by carol.near.
+    assert_eq!(estimate.gas, NearGas::from_gas(5060));
+    assert_eq!(estimate.deposit, NearToken::from_yoctonear(0));
+    assert_eq!(estimate.total, NearToken::from_yoctonear(5060));
+}
+
+#[test]
+fn test_missing_fee() {
+    let estimator = FeeEstimator::new(fees(), NearToken::from_yoctonear(1));
+    let transaction = builder("alice.near")
+        .delete_key(PUBLIC_KEY.parse().unwrap())
+        .build(1, HASH.parse().unwrap());
+
+    let err = estimator.estimate_transaction(&transaction).unwrap_err();
+    assert!(
+        matches!(err, FeeError::MissingFee("delete_key")),
+        "{:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_fee_estimator_uses_gas_price_of_block() {
+    let block = format!(
+        r#"{{"jsonrpc":"2.0","id":"dontcare","result":{}}}"#,
+        include_str!("golden/block.json")
+    );
+    let config = RpcProtocolConfigResponse {
+        runtime_config: Some(RuntimeConfigView {
+            transaction_costs: Some(fees()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let config = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": config});
+    let url = common::serve(vec![(200, block), (200, config.to_string())]);
+    let client = RpcClient::new(&url);
+
+    let estimator = client
+        .fee_estimator(BlockReference::Finality(Finality::Final))
+        .await
+        .unwrap();
+    assert_eq!(
+        estimator.gas_price(),
+        NearToken::from_yoctonear(100_000_000)
+    );
+    let transaction = builder("alice.near")
+        .transfer(NearToken::from_yoctonear(5))
+        .build(1, HASH.parse().unwrap());
+    assert_eq!(
+        estimator.estimate_transaction(&transaction).unwrap().gas,
+        NearGas::from_gas(44)
+    );
+}
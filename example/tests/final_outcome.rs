@@ -0,0 +1,119 @@
+mod common;
+
+use near_openapi_client::final_outcome::FinalOutcomeError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{CryptoHash, FinalExecutionStatus};
+
+const TX_HASH: &str = "EUTKRZyR6mX9JhM6uBadWD5yAVVM4XPu1CpL1kroau1B";
+const CALL: &str = "B56tSDsbENib2QN9FnhD3mqhxvpMCTq2uRb8LVJS4mrL";
+const REFUND: &str = "8tfaSwTSpRCbm9WPfxb87HmkhCFs1oDSP9ZXX6STcxaK";
+
+fn golden() -> serde_json::Value {
+    serde_json::from_str(include_str!("golden/tx.json")).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": result});
+    (200, response.to_string())
+}
+
+fn hash(hash: &str) -> CryptoHash {
+    hash.parse().unwrap()
+}
+
+#[tokio::test]
+async fn test_resolves_receipt_tree() {
+    let url = common::serve(vec![envelope(golden())]);
+    let client = RpcClient::new(&url);
+
+    let outcome = client
+        .final_outcome(hash(TX_HASH), "alice.near".parse().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome
+            .receipts_outcome
+            .iter()
+            .map(|outcome| outcome.id)
+            .collect::<Vec<_>>(),
+        [hash(CALL), hash(REFUND)]
+    );
+    assert_eq!(
+        outcome.status,
+        FinalExecutionStatus::SuccessValue(String::new())
+    );
+    assert!(outcome.is_complete());
+    let children: Vec<_> = outcome.children(&hash(CALL)).collect();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].outcome.executor_id.as_str(), "alice.near");
+}
+
+#[tokio::test]
+async fn test_fetches_pending_receipts() {
+    let mut tx = golden();
+    tx["receipts_outcome"].as_array_mut().unwrap().pop();
+    let mut refund = golden()["receipts"][0].clone();
+    refund["receipt_id"] = REFUND.into();
+    refund["predecessor_id"] = "system".into();
+    let url = common::serve(vec![envelope(tx), envelope(refund)]);
+    let client = RpcClient::new(&url);
+
+    let outcome = client
+        .final_outcome(hash(TX_HASH), "alice.near".parse().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(outcome.receipts_outcome.len(), 1);
+    assert!(!outcome.is_complete());
+    assert_eq!(outcome.pending_receipts[0].receipt_id, hash(REFUND));
+    assert_eq!(
+        outcome.pending_receipts[0].predecessor_id.as_str(),
+        "system"
+    );
+    // The value of the call does not depend on the refund.
+    assert_eq!(
+        outcome.status,
+        FinalExecutionStatus::SuccessValue(String::new())
+    );
+}
+
+#[tokio::test]
+async fn test_failure_of_any_receipt_fails_the_tree() {
+    let mut tx = golden();
+    tx["receipts_outcome"][1]["outcome"]["status"] = serde_json::json!({
+        "Failure": {"ActionError": {"index": 0, "kind": {"AccountDoesNotExist": {"account_id": "alice.near"}}}},
+    });
+    let url = common::serve(vec![envelope(tx)]);
+    let client = RpcClient::new(&url);
+
+    let outcome = client
+        .final_outcome(hash(TX_HASH), "alice.near".parse().unwrap())
+        .await
+        .unwrap();
+    assert!(
+        matches!(outcome.status, FinalExecutionStatus::Failure(_)),
+        "{:?}",
+        outcome.status
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_transaction() {
+    let error = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "UNKNOWN_TRANSACTION", "info": {"requested_transaction_hash": TX_HASH}},
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    let url = common::serve(vec![(200, error.to_string())]);
+    let client = RpcClient::new(&url);
+
+    let err = client
+        .final_outcome(hash(TX_HASH), "alice.near".parse().unwrap())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FinalOutcomeError::Tx(_)), "{:?}", err);
+}
@@ -0,0 +1,174 @@
+use futures_util::StreamExt;
+use near_openapi_client::finality::{FinalityEvent, FinalityStatus, FinalityTracker};
+use near_openapi_client::methods;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types::{
+    self, BlockId, CryptoHash, Finality, RpcBlockRequest, RpcBlockResponse,
+};
+
+const HASH: &str = "11111111111111111111111111111111";
+
+fn hash(n: u8) -> CryptoHash {
+    CryptoHash([n; 32])
+}
+
+/// Block `hash(n)` at `height`.
+fn block(height: u64, n: u8) -> RpcBlockResponse {
+    serde_json::from_value(serde_json::json!({
+        "author": "node.near",
+        "chunks": [],
+        "header": {
+            "approvals": [],
+            "block_merkle_root": HASH,
+            "challenges_result": [],
+            "challenges_root": HASH,
+            "chunk_headers_root": HASH,
+            "chunk_mask": [],
+            "chunk_receipts_root": HASH,
+            "chunk_tx_root": HASH,
+            "chunks_included": 0,
+            "epoch_id": HASH,
+            "gas_price": "100000000",
+            "hash": hash(n),
+            "height": height,
+            "last_ds_final_block": HASH,
+            "last_final_block": HASH,
+            "latest_protocol_version": 80,
+            "next_bp_hash": HASH,
+            "next_epoch_id": HASH,
+            "outcome_root": HASH,
+            "prev_hash": HASH,
+            "prev_state_root": HASH,
+            "random_value": HASH,
+            "rent_paid": "0",
+            "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+            "timestamp": 1,
+            "timestamp_nanosec": "1",
+            "total_supply": "0",
+            "validator_proposals": [],
+            "validator_reward": "0",
+        },
+    }))
+    .unwrap()
+}
+
+fn heads(mock: &MockRpc, optimistic: RpcBlockResponse, head: RpcBlockResponse) {
+    mock.expect::<methods::Block>()
+        .with_params(&RpcBlockRequest::Finality(Finality::Optimistic))
+        .return_ok(optimistic);
+    mock.expect::<methods::Block>()
+        .with_params(&RpcBlockRequest::Finality(Finality::Final))
+        .return_ok(head);
+}
+
+#[tokio::test]
+async fn test_tracker_finalizes_and_orphans_blocks() {
+    let mock = MockRpc::start();
+    // Poll 1: block 1 at 11 is optimistic, 9 is final.
+    heads(&mock, block(11, 1), block(9, 9));
+    // Poll 2: block 2 replaced block 1 at 11 and is final.
+    heads(&mock, block(13, 3), block(11, 2));
+    mock.expect::<methods::Block>()
+        .with_params(&RpcBlockRequest::BlockId(BlockId::BlockHeight(10)))
+        .return_ok(block(10, 10));
+    // Poll 3: height 12 was skipped.
+    heads(&mock, block(13, 3), block(13, 3));
+    mock.expect::<methods::Block>()
+        .with_params(&RpcBlockRequest::BlockId(BlockId::BlockHeight(12)))
+        .return_error(types::ErrorWrapperForRpcBlockError::HandlerError(
+            types::RpcBlockError::UnknownBlock(serde_json::Map::new()),
+        ));
+    let tracker = FinalityTracker::new(mock.client());
+
+    let ingested = tracker.tag("from 10", 10, hash(10));
+    let events = tracker.poll().await.unwrap();
+    assert_eq!(
+        events,
+        [FinalityEvent::NewHead {
+            height: 11,
+            hash: hash(1)
+        }],
+        "{:?}",
+        events
+    );
+    let orphaned = tracker.tag("from 11", 11, hash(1));
+    assert_eq!(ingested.status(), FinalityStatus::Optimistic);
+    assert_eq!(tracker.final_height(), Some(9));
+
+    let events = tracker.poll().await.unwrap();
+    assert_eq!(
+        events,
+        [
+            FinalityEvent::Finalized {
+                height: 10,
+                hash: hash(10)
+            },
+            FinalityEvent::Orphaned {
+                height: 11,
+                hash: hash(1)
+            },
+            FinalityEvent::NewHead {
+                height: 13,
+                hash: hash(3)
+            },
+        ],
+        "{:?}",
+        events
+    );
+    assert_eq!(ingested.status(), FinalityStatus::Final);
+    assert_eq!(orphaned.status(), FinalityStatus::Reverted);
+    assert_eq!(orphaned.value, "from 11");
+
+    let skipped = tracker.tag((), 12, hash(12));
+    let events = tracker.poll().await.unwrap();
+    assert_eq!(
+        events,
+        [
+            FinalityEvent::Orphaned {
+                height: 12,
+                hash: hash(12)
+            },
+            FinalityEvent::Finalized {
+                height: 13,
+                hash: hash(3)
+            },
+        ],
+        "{:?}",
+        events
+    );
+    assert_eq!(skipped.status(), FinalityStatus::Reverted);
+}
+
+#[tokio::test]
+async fn test_tracker_stream() {
+    let mock = MockRpc::start();
+    heads(&mock, block(11, 1), block(10, 10));
+    heads(&mock, block(12, 2), block(11, 1));
+    let tracker = FinalityTracker::new(mock.client()).poll_interval(std::time::Duration::ZERO);
+
+    let events: Vec<_> = tracker
+        .into_stream()
+        .take(3)
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+    assert_eq!(
+        events,
+        [
+            FinalityEvent::NewHead {
+                height: 11,
+                hash: hash(1)
+            },
+            FinalityEvent::Finalized {
+                height: 11,
+                hash: hash(1)
+            },
+            FinalityEvent::NewHead {
+                height: 12,
+                hash: hash(2)
+            },
+        ],
+        "{:?}",
+        events
+    );
+}
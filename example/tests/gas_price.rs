@@ -0,0 +1,96 @@
+mod common;
+
+use near_openapi_client::gas_price::GasPriceStats;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::NearToken;
+
+const GENESIS_HASH: &str = "11111111111111111111111111111111";
+const HASH: &str = "US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx";
+
+/// Block at `height` with `gas_price`, whose parent is the genesis block if
+/// `prev_hash` is [`GENESIS_HASH`].
+fn block(height: u64, gas_price: u128, prev_hash: &str) -> (u16, String) {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": HASH,
+        "challenges_result": [],
+        "challenges_root": HASH,
+        "chunk_headers_root": HASH,
+        "chunk_mask": [],
+        "chunk_receipts_root": HASH,
+        "chunk_tx_root": HASH,
+        "chunks_included": 0,
+        "epoch_id": HASH,
+        "gas_price": gas_price.to_string(),
+        "hash": HASH,
+        "height": height,
+        "last_ds_final_block": HASH,
+        "last_final_block": HASH,
+        "latest_protocol_version": 80,
+        "next_bp_hash": HASH,
+        "next_epoch_id": HASH,
+        "outcome_root": HASH,
+        "prev_hash": prev_hash,
+        "prev_state_root": HASH,
+        "random_value": HASH,
+        "rent_paid": "0",
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {"author": "node.near", "chunks": [], "header": header},
+    });
+    (200, response.to_string())
+}
+
+fn samples(prices: &[(u64, u128)]) -> Vec<(u64, NearToken)> {
+    prices
+        .iter()
+        .map(|&(height, price)| (height, NearToken::from_yoctonear(price)))
+        .collect()
+}
+
+#[test]
+fn test_stats_of_samples() {
+    let stats = GasPriceStats::from_samples(samples(&[(1, 100), (2, 400), (3, 200)])).unwrap();
+    assert_eq!(stats.min, NearToken::from_yoctonear(100));
+    assert_eq!(stats.max, NearToken::from_yoctonear(400));
+    // Smoothing factor 2 / 4: 100, then 250, then 225.
+    assert_eq!(stats.ema, NearToken::from_yoctonear(225));
+
+    assert_eq!(GasPriceStats::from_samples(Vec::new()), None);
+}
+
+#[tokio::test]
+async fn test_walks_back_from_the_final_block() {
+    let url = common::serve(vec![
+        block(12, 300, HASH),
+        block(11, 200, HASH),
+        block(10, 100, HASH),
+    ]);
+
+    let stats = RpcClient::new(&url).gas_price_smoothed(3).await.unwrap();
+    assert_eq!(
+        stats.samples,
+        samples(&[(10, 100), (11, 200), (12, 300)]),
+        "{:?}",
+        stats
+    );
+    assert_eq!(stats.min, NearToken::from_yoctonear(100));
+    assert_eq!(stats.max, NearToken::from_yoctonear(300));
+    assert_eq!(stats.ema, NearToken::from_yoctonear(225));
+}
+
+#[tokio::test]
+async fn test_stops_at_genesis() {
+    let url = common::serve(vec![block(1, 300, HASH), block(0, 100, GENESIS_HASH)]);
+
+    let stats = RpcClient::new(&url).gas_price_smoothed(10).await.unwrap();
+    assert_eq!(stats.samples, samples(&[(0, 100), (1, 300)]), "{:?}", stats);
+}
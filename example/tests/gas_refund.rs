@@ -0,0 +1,54 @@
+use near_openapi_client::types::gas_refund::{
+    GasRefund, REFUND_PENALTY_PROTOCOL_VERSION, RefundPenaltyConfig,
+};
+use near_openapi_client::types::{NearGas, NearToken};
+
+#[test]
+fn test_penalty_by_protocol_version() {
+    let before = RefundPenaltyConfig::for_protocol_version(REFUND_PENALTY_PROTOCOL_VERSION - 1);
+    assert_eq!(
+        before.refund(NearGas::from_tgas(300), NearGas::from_tgas(10)),
+        GasRefund {
+            gross: NearGas::from_tgas(290),
+            penalty: NearGas::from_gas(0),
+            net: NearGas::from_tgas(290),
+        }
+    );
+
+    let after = RefundPenaltyConfig::for_protocol_version(REFUND_PENALTY_PROTOCOL_VERSION);
+    // 5% of the refund.
+    assert_eq!(
+        after.penalty(NearGas::from_tgas(290)),
+        NearGas::from_gas(14_500_000_000_000)
+    );
+    // At least 1 Tgas.
+    assert_eq!(after.penalty(NearGas::from_tgas(10)), NearGas::from_tgas(1));
+    // At most the whole refund.
+    let refund = after.refund(
+        NearGas::from_tgas(30),
+        NearGas::from_gas(29_500_000_000_000),
+    );
+    assert_eq!(refund.penalty, NearGas::from_gas(500_000_000_000));
+    assert_eq!(refund.net, NearGas::from_gas(0));
+    assert_eq!(
+        after
+            .refund(NearGas::from_tgas(30), NearGas::from_tgas(30))
+            .gross,
+        NearGas::from_gas(0)
+    );
+}
+
+#[test]
+fn test_refund_tokens() {
+    let refund =
+        RefundPenaltyConfig::NEP_536.refund(NearGas::from_tgas(100), NearGas::from_tgas(20));
+    let gas_price = NearToken::from_yoctonear(100_000_000);
+    assert_eq!(
+        refund.net_tokens(gas_price),
+        NearToken::from_yoctonear(76_000_000_000_000 * 100_000_000)
+    );
+    assert_eq!(
+        refund.penalty_tokens(gas_price),
+        NearToken::from_yoctonear(4_000_000_000_000 * 100_000_000)
+    );
+}
@@ -0,0 +1,59 @@
+use arbitrary::{Arbitrary, Unstructured};
+use near_openapi_client::methods;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types::{self, ChainId};
+
+fn genesis_config(chain_id: &str) -> types::GenesisConfig {
+    let data = [0; 4096];
+    let mut genesis = types::GenesisConfig::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    genesis.chain_id = chain_id.to_string();
+    genesis
+}
+
+#[tokio::test]
+async fn test_genesis_config_is_fetched_once() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GenesisConfig>()
+        .return_ok(genesis_config("mainnet"));
+    let client = mock.client();
+
+    let genesis = client.cached_genesis_config().await.unwrap();
+    assert_eq!(genesis.chain_id, "mainnet");
+    // Clones share the cache.
+    let clone = client.clone();
+    let again = clone.cached_genesis_config().await.unwrap();
+    assert!(std::sync::Arc::ptr_eq(&genesis, &again));
+    assert_eq!(client.chain_id().await.unwrap(), ChainId::Mainnet);
+    assert!(clone.is_mainnet().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_chain_id_of_other_networks() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GenesisConfig>()
+        .return_ok(genesis_config("testnet"));
+    let client = mock.client();
+    assert_eq!(client.chain_id().await.unwrap(), ChainId::Testnet);
+    assert!(!client.is_mainnet().await.unwrap());
+
+    let mock = MockRpc::start();
+    mock.expect::<methods::GenesisConfig>()
+        .return_ok(genesis_config("localnet"));
+    let client = mock.client();
+    assert_eq!(
+        client.chain_id().await.unwrap(),
+        ChainId::Custom("localnet".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_failures_are_not_cached() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GenesisConfig>().return_status(503);
+    mock.expect::<methods::GenesisConfig>()
+        .return_ok(genesis_config("mainnet"));
+    let client = mock.client();
+
+    assert!(client.cached_genesis_config().await.is_err());
+    assert!(client.is_mainnet().await.unwrap());
+}
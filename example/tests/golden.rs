@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use near_openapi_client::types;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Decodes the golden file `name`, a response of the node as the types encode
+/// it, and checks that the value encodes back to the same bytes, so that any
+/// change of the wire format of the types, e.g. after regenerating them from
+/// a newer spec, fails here. Run with `UPDATE_GOLDEN=1` to rewrite the files
+/// after an intended change.
+fn assert_golden<T: Serialize + DeserializeOwned>(name: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+    let golden = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+    let value: T = serde_json::from_str(&golden)
+        .unwrap_or_else(|err| panic!("failed to decode {}: {}", path.display(), err));
+    let encoded = serde_json::to_string_pretty(&value).unwrap() + "\n";
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, encoded).unwrap();
+        return;
+    }
+    if let Some((line, (golden, encoded))) = golden
+        .lines()
+        .zip(encoded.lines())
+        .enumerate()
+        .find(|(_, (golden, encoded))| golden != encoded)
+    {
+        panic!(
+            "{} changed at line {}: {:?} is now {:?}; rerun with UPDATE_GOLDEN=1 if intended",
+            name,
+            line + 1,
+            golden,
+            encoded
+        );
+    }
+    assert_eq!(golden.len(), encoded.len(), "{} changed length", name);
+}
+
+#[test]
+fn test_block_golden() {
+    assert_golden::<types::RpcBlockResponse>("block.json");
+}
+
+#[test]
+fn test_chunk_golden() {
+    assert_golden::<types::RpcChunkResponse>("chunk.json");
+}
+
+#[test]
+fn test_tx_golden() {
+    assert_golden::<types::RpcTransactionResponse>("tx.json");
+    assert_golden::<types::RpcTransactionResponse>("tx_failure.json");
+}
+
+#[test]
+fn test_query_golden() {
+    assert_golden::<types::RpcQueryResponse>("view_account.json");
+    assert_golden::<types::ErrorWrapperForRpcQueryError>("query_error.json");
+}
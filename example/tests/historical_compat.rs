@@ -0,0 +1,18 @@
+#![cfg(feature = "historical-compat")]
+
+use near_openapi_client::types;
+
+#[test]
+fn test_congestion_info_view_without_allowed_shard() {
+    // Congestion info as returned for chunks produced before `allowed_shard` was introduced
+    let json = r#"{"buffered_receipts_gas":"0","delayed_receipts_gas":"0","receipt_bytes":0}"#;
+
+    let result: Result<types::CongestionInfoView, _> = serde_json::from_str(json);
+
+    assert!(
+        result.is_ok(),
+        "Failed to deserialize CongestionInfoView without allowed_shard: {:?}",
+        result.err()
+    );
+    assert_eq!(result.unwrap().allowed_shard, 0);
+}
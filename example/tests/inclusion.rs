@@ -0,0 +1,200 @@
+mod common;
+
+use near_openapi_client::inclusion::InclusionError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{CryptoHash, RpcTransactionResponse};
+
+const SIGNATURE: &str = "ed25519:1111111111111111111111111111111111111111111111111111111111111111";
+
+fn hash(n: u8) -> String {
+    CryptoHash([n; 32]).to_string()
+}
+
+/// Block `hash(n)` at `height` after `hash(prev)`, with a new chunk
+/// `hash(100 + n)` of `shard_id`.
+fn block(n: u8, height: u64, prev: u8, shard_id: u64) -> (u16, String) {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": hash(0),
+        "challenges_result": [],
+        "challenges_root": hash(0),
+        "chunk_headers_root": hash(0),
+        "chunk_mask": [true],
+        "chunk_receipts_root": hash(0),
+        "chunk_tx_root": hash(0),
+        "chunks_included": 1,
+        "epoch_id": hash(0),
+        "gas_price": "100000000",
+        "hash": hash(n),
+        "height": height,
+        "last_ds_final_block": hash(0),
+        "last_final_block": hash(0),
+        "latest_protocol_version": 80,
+        "next_bp_hash": hash(0),
+        "next_epoch_id": hash(0),
+        "outcome_root": hash(0),
+        "prev_hash": hash(prev),
+        "prev_state_root": hash(0),
+        "random_value": hash(0),
+        "rent_paid": "0",
+        "signature": SIGNATURE,
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "author": "node.near",
+            "chunks": [chunk_header(n, height, shard_id)],
+            "header": header,
+        },
+    });
+    (200, response.to_string())
+}
+
+fn chunk_header(n: u8, height: u64, shard_id: u64) -> serde_json::Value {
+    serde_json::json!({
+        "balance_burnt": "0",
+        "chunk_hash": hash(100 + n),
+        "encoded_length": 0,
+        "encoded_merkle_root": hash(0),
+        "gas_limit": 1000,
+        "gas_used": 0,
+        "height_created": height,
+        "height_included": height,
+        "outcome_root": hash(0),
+        "outgoing_receipts_root": hash(0),
+        "prev_block_hash": hash(0),
+        "prev_state_root": hash(0),
+        "shard_id": shard_id,
+        "signature": SIGNATURE,
+        "tx_root": hash(0),
+        "validator_proposals": [],
+    })
+}
+
+/// Chunk of block `hash(n)` listing the transactions and receipts with the
+/// given hashes.
+fn chunk(n: u8, height: u64, shard_id: u64, transactions: &[u8], receipts: &[u8]) -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "author": "node.near",
+            "header": chunk_header(n, height, shard_id),
+            "receipts": receipts.iter().map(|&id| serde_json::json!({
+                "predecessor_id": "alice.near",
+                "receipt": {"Data": {"data_id": hash(0)}},
+                "receipt_id": hash(id),
+                "receiver_id": "bob.near",
+            })).collect::<Vec<_>>(),
+            "transactions": transactions.iter().map(|&id| transaction(id, "bob.near")).collect::<Vec<_>>(),
+        },
+    });
+    (200, response.to_string())
+}
+
+fn transaction(id: u8, receiver_id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "actions": [],
+        "hash": hash(id),
+        "nonce": 1,
+        "public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+        "receiver_id": receiver_id,
+        "signature": SIGNATURE,
+        "signer_id": "alice.near",
+    })
+}
+
+fn outcome(id: u8, block: u8, receipt_ids: &[u8]) -> serde_json::Value {
+    serde_json::json!({
+        "block_hash": hash(block),
+        "id": hash(id),
+        "outcome": {
+            "executor_id": "bob.near",
+            "gas_burnt": 1,
+            "logs": [],
+            "receipt_ids": receipt_ids.iter().map(|&id| hash(id)).collect::<Vec<_>>(),
+            "status": {"SuccessValue": ""},
+            "tokens_burnt": "0",
+        },
+        "proof": [],
+    })
+}
+
+fn response(receiver_id: &str, receipts_outcome: Vec<serde_json::Value>) -> RpcTransactionResponse {
+    serde_json::from_value(serde_json::json!({
+        "final_execution_status": "FINAL",
+        "receipts_outcome": receipts_outcome,
+        "status": {"SuccessValue": ""},
+        "transaction": transaction(1, receiver_id),
+        "transaction_outcome": outcome(1, 10, &[2]),
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_tx_inclusion_follows_receipts_across_shards() {
+    // The transaction is in block 10 on shard 0, its receipt is carried to
+    // shard 1 in block 11, and the refund created there is carried in block
+    // 12 but executed in block 13 after being delayed.
+    let url = common::serve(vec![
+        block(10, 100, 9, 0),
+        chunk(10, 100, 0, &[1], &[]),
+        block(11, 101, 10, 1),
+        chunk(11, 101, 1, &[], &[2]),
+        block(13, 103, 12, 0),
+        chunk(13, 103, 0, &[], &[]),
+        block(12, 102, 11, 1),
+        chunk(12, 102, 1, &[], &[3]),
+    ]);
+    let response = response("bob.near", vec![outcome(2, 11, &[3]), outcome(3, 13, &[])]);
+
+    let inclusion = RpcClient::new(&url).tx_inclusion(&response).await.unwrap();
+    assert_eq!(inclusion.transaction.block_height, 100);
+    assert_eq!(inclusion.transaction.shard_id.0, 0);
+    assert_eq!(inclusion.transaction.chunk_hash.to_string(), hash(110));
+    let receipts: Vec<_> = inclusion
+        .receipts
+        .iter()
+        .map(|receipt| (receipt.block_height, receipt.shard_id.0, receipt.local))
+        .collect();
+    assert_eq!(receipts, vec![(101, 1, false), (102, 1, false)]);
+    assert_eq!(inclusion.receipts[1].chunk_hash.to_string(), hash(112));
+}
+
+#[tokio::test]
+async fn test_tx_inclusion_of_local_receipt() {
+    // A transaction to its own signer is executed right away in its chunk.
+    let url = common::serve(vec![block(10, 100, 9, 2), chunk(10, 100, 2, &[1], &[])]);
+    let response = response("alice.near", vec![outcome(2, 10, &[])]);
+
+    let inclusion = RpcClient::new(&url).tx_inclusion(&response).await.unwrap();
+    assert!(inclusion.receipts[0].local);
+    assert_eq!(inclusion.receipts[0].shard_id.0, 2);
+    assert_eq!(
+        inclusion.receipts[0].chunk_hash,
+        inclusion.transaction.chunk_hash
+    );
+}
+
+#[tokio::test]
+async fn test_tx_inclusion_receipt_not_found() {
+    let url = common::serve(vec![
+        block(10, 100, 9, 0),
+        chunk(10, 100, 0, &[1], &[]),
+        block(11, 101, 10, 1),
+        chunk(11, 101, 1, &[], &[]),
+    ]);
+    let response = response("bob.near", vec![outcome(2, 11, &[])]);
+
+    let err = RpcClient::new(&url)
+        .tx_inclusion(&response)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, InclusionError::NotFound(id) if id.to_string() == hash(2)));
+}
@@ -0,0 +1,44 @@
+use near_openapi_client::types::known::{self, KnownAccounts};
+use near_openapi_client::types::{AccountId, ChainId};
+
+#[test]
+fn test_known_accounts_by_chain() {
+    assert_eq!(
+        KnownAccounts::for_chain(&ChainId::Mainnet),
+        Some(&known::MAINNET)
+    );
+    assert_eq!(
+        KnownAccounts::for_chain(&ChainId::Testnet),
+        Some(&known::TESTNET)
+    );
+    assert_eq!(KnownAccounts::for_chain(&"localnet".into()), None);
+
+    let mpc_signer: AccountId = known::mainnet::MPC_SIGNER.to_owned();
+    assert_eq!(
+        mpc_signer.as_str(),
+        near_openapi_client::types::chain_signatures::MAINNET_CONTRACT
+    );
+    assert_eq!(
+        known::testnet::SOCIAL_DB.as_str(),
+        near_openapi_client::socialdb::TESTNET_CONTRACT
+    );
+}
+
+#[test]
+fn test_known_accounts_are_distinct_per_network() {
+    for accounts in [known::MAINNET, known::TESTNET] {
+        let mut ids = vec![
+            accounts.wrap_near,
+            accounts.usdc,
+            accounts.usdt,
+            accounts.staking_pool_factory,
+            accounts.linkdrop,
+            accounts.mpc_signer,
+            accounts.social_db,
+        ];
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 7);
+    }
+    assert!(known::testnet::WRAP_NEAR.is_sub_account_of(known::testnet::LINKDROP));
+}
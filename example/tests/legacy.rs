@@ -0,0 +1,64 @@
+use near_openapi_client::types::legacy::{self, LEGACY_FIELDS};
+use near_openapi_client::types::{BlockHeaderView, CryptoHash, RpcBlockResponse};
+
+fn golden_header() -> serde_json::Value {
+    let block: serde_json::Value = serde_json::from_str(include_str!("golden/block.json")).unwrap();
+    block["header"].clone()
+}
+
+#[test]
+fn test_current_headers_carry_no_challenges() {
+    let block: RpcBlockResponse = serde_json::from_str(include_str!("golden/block.json")).unwrap();
+    assert!(block.header.challenges_result.is_empty());
+    assert_eq!(block.header.challenges_root, Some(CryptoHash::default()));
+    assert!(!block.header.has_challenges());
+}
+
+#[test]
+fn test_missing_challenge_fields_default() {
+    let mut json = golden_header();
+    let object = json.as_object_mut().unwrap();
+    object.remove("challenges_result");
+    object.remove("challenges_root");
+
+    let header: BlockHeaderView = serde_json::from_value(json).unwrap();
+    assert!(header.challenges_result.is_empty());
+    assert_eq!(header.challenges_root, None);
+    assert!(!header.has_challenges());
+
+    let encoded = serde_json::to_value(&header).unwrap();
+    assert_eq!(encoded.get("challenges_root"), None);
+    assert_eq!(encoded["challenges_result"], serde_json::json!([]));
+}
+
+#[test]
+fn test_old_header_with_challenges() {
+    let mut json = golden_header();
+    json["challenges_result"] = serde_json::json!([
+        {"account_id": "validator.near", "is_double_sign": true},
+    ]);
+    json["challenges_root"] = serde_json::json!("4HnBbDNdfBN4cbzDXv4E5nf3fWKC8AeUkA3h3nYZzZq3");
+
+    let header: BlockHeaderView = serde_json::from_value(json.clone()).unwrap();
+    assert!(header.has_challenges());
+    assert_eq!(
+        header.challenges_result[0].account_id.as_str(),
+        "validator.near"
+    );
+    assert_eq!(
+        serde_json::to_value(&header).unwrap()["challenges_root"],
+        json["challenges_root"]
+    );
+}
+
+#[test]
+fn test_legacy_fields() {
+    let field = legacy::field("BlockHeaderView", "challenges_result").unwrap();
+    assert_eq!(field.unused_since, 69);
+    assert!(legacy::field("BlockHeaderView", "height").is_none());
+    assert!(
+        LEGACY_FIELDS
+            .iter()
+            .all(|field| field.type_name == "BlockHeaderView")
+    );
+}
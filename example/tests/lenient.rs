@@ -0,0 +1,73 @@
+use near_openapi_client::types::{self, lenient};
+
+const RECEIPT_WITH_NEW_ACTION: &str = r#"{
+    "Action": {
+        "actions": ["CreateAccount", {"NewAction": {"amount": "1"}}],
+        "gas_price": "100000000",
+        "input_data_ids": [],
+        "is_promise_yield": false,
+        "output_data_receivers": [],
+        "signer_id": "alice.near",
+        "signer_public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+    }
+}"#;
+
+#[test]
+fn test_unknown_variant_is_rejected_by_default() {
+    let result: Result<types::ActionView, _> =
+        serde_json::from_str(r#"{"NewAction": {"amount": "1"}}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unknown_variant_decoded_leniently() {
+    let (receipt, unknown) = lenient::decode_leniently(|| {
+        serde_json::from_str::<types::ReceiptEnumView>(RECEIPT_WITH_NEW_ACTION)
+    });
+    let receipt = receipt.unwrap();
+    assert_eq!(unknown, vec!["NewAction".to_string()]);
+
+    let types::ReceiptEnumView::Action { actions, .. } = &receipt else {
+        panic!("expected action receipt, got {:?}", receipt);
+    };
+    assert_eq!(actions[0], types::ActionView::CreateAccount);
+    let types::ActionView::Unknown(unknown) = &actions[1] else {
+        panic!("expected unknown action, got {:?}", actions[1]);
+    };
+    assert_eq!(unknown.discriminant(), Some("NewAction"));
+
+    // Unknown payloads serialize back unchanged
+    let expected: serde_json::Value = serde_json::from_str(RECEIPT_WITH_NEW_ACTION).unwrap();
+    assert_eq!(serde_json::to_value(&receipt).unwrap(), expected);
+}
+
+#[test]
+fn test_unknown_internally_tagged_variant() {
+    let (cause, unknown) = lenient::decode_leniently(|| {
+        serde_json::from_str::<types::StateChangeCauseView>(r#"{"type": "new_cause", "id": 1}"#)
+    });
+    assert!(matches!(cause, Ok(types::StateChangeCauseView::Unknown(_))));
+    assert_eq!(unknown, vec!["new_cause".to_string()]);
+}
+
+#[test]
+fn test_invalid_known_variant_is_not_unknown() {
+    // `account_id` is given twice, so this is not a valid `AccountAlreadyExists`
+    let (kind, unknown) = lenient::decode_leniently(|| {
+        serde_json::from_str::<types::ActionErrorKind>(
+            r#"{"AccountAlreadyExists": {"account_id": "a.near", "account_id": "b.near"}}"#,
+        )
+    });
+    assert!(kind.is_err());
+    assert!(unknown.is_empty());
+
+    let (cause, _) = lenient::decode_leniently(|| {
+        serde_json::from_str::<types::StateChangeCauseView>(
+            r#"{"type": "initial_state", "tx_hash": 1}"#,
+        )
+    });
+    assert!(matches!(
+        cause,
+        Ok(types::StateChangeCauseView::InitialState)
+    ));
+}
@@ -0,0 +1,62 @@
+use near_openapi_client::types::limits::{FieldLimit, FieldLimits, LimitError};
+use near_openapi_client::types::{BandwidthRequestBitmap, CongestionInfoView, SyncConcurrency};
+
+#[test]
+fn test_field_limits_of_generated_types() {
+    assert_eq!(
+        CongestionInfoView::field_limit("allowed_shard"),
+        Some(&FieldLimit::integer(
+            "allowed_shard",
+            "uint16",
+            Some(0),
+            Some(65535)
+        ))
+    );
+    assert_eq!(
+        BandwidthRequestBitmap::field_limit("data"),
+        Some(&FieldLimit::items("data", Some(5), Some(5)))
+    );
+    assert_eq!(
+        CongestionInfoView::field_limit("buffered_receipts_gas"),
+        None
+    );
+}
+
+#[test]
+fn test_field_limit_check() {
+    let limit = CongestionInfoView::field_limit("allowed_shard").unwrap();
+    assert!(limit.check(65535).is_ok());
+    let err = limit.check(65536).unwrap_err();
+    assert_eq!(
+        err,
+        LimitError::OutOfRange {
+            field: "allowed_shard",
+            value: 65536,
+            minimum: Some(0),
+            maximum: Some(65535),
+        }
+    );
+    assert_eq!(err.to_string(), "allowed_shard is 65536, out of [0, 65535]");
+    assert!(limit.check(-1).is_err());
+
+    let limit = BandwidthRequestBitmap::field_limit("data").unwrap();
+    assert!(limit.check_len(5).is_ok());
+    assert!(matches!(
+        limit.check_len(6),
+        Err(LimitError::ItemCount { len: 6, .. })
+    ));
+}
+
+#[test]
+fn test_validate_accepts_generated_values() {
+    let congestion_info: CongestionInfoView = serde_json::from_value(serde_json::json!({
+        "allowed_shard": 65535,
+        "buffered_receipts_gas": "0",
+        "delayed_receipts_gas": "0",
+        "receipt_bytes": 0,
+    }))
+    .unwrap();
+    assert!(congestion_info.validate().is_ok());
+    // Unset optional fields are not checked.
+    assert!(SyncConcurrency::default().validate().is_ok());
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use near_openapi_client::maintenance::MaintenanceError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::CryptoHash;
+
+const SIGNATURE: &str = "ed25519:1111111111111111111111111111111111111111111111111111111111111111";
+const SECOND: u64 = 1_000_000_000;
+
+fn hash(n: u8) -> CryptoHash {
+    CryptoHash([n; 32])
+}
+
+fn envelope(result: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": result});
+    (200, response.to_string())
+}
+
+fn validators(epoch_height: u64, start_height: u64) -> (u16, String) {
+    envelope(serde_json::json!({
+        "current_fishermen": [],
+        "current_proposals": [],
+        "current_validators": [],
+        "epoch_height": epoch_height,
+        "epoch_start_height": start_height,
+        "next_fishermen": [],
+        "next_validators": [],
+        "prev_epoch_kickout": [],
+    }))
+}
+
+/// Block at `height` produced `timestamp` nanoseconds after the Unix epoch.
+fn block(height: u64, timestamp: u64) -> (u16, String) {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": hash(0),
+        "challenges_result": [],
+        "challenges_root": hash(0),
+        "chunk_headers_root": hash(0),
+        "chunk_mask": [true],
+        "chunk_receipts_root": hash(0),
+        "chunk_tx_root": hash(0),
+        "chunks_included": 1,
+        "epoch_id": hash(0),
+        "gas_price": "100000000",
+        "hash": hash(height as u8),
+        "height": height,
+        "last_ds_final_block": hash(0),
+        "last_final_block": hash(0),
+        "latest_protocol_version": 80,
+        "next_bp_hash": hash(0),
+        "next_epoch_id": hash(0),
+        "outcome_root": hash(0),
+        "prev_hash": hash(height as u8 - 1),
+        "prev_state_root": hash(0),
+        "random_value": hash(0),
+        "rent_paid": "0",
+        "signature": SIGNATURE,
+        "timestamp": timestamp,
+        "timestamp_nanosec": timestamp.to_string(),
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    envelope(serde_json::json!({"author": "node.near", "chunks": [], "header": header}))
+}
+
+fn windows(ranges: &[(u64, u64)]) -> (u16, String) {
+    let ranges: Vec<_> = ranges
+        .iter()
+        .map(|(start, end)| serde_json::json!({"start": start, "end": end}))
+        .collect();
+    envelope(serde_json::json!(ranges))
+}
+
+#[tokio::test]
+async fn test_schedule_estimates_times_from_epoch_block_rate() {
+    let url = common::serve(vec![
+        block(150, 1000 * SECOND),
+        validators(7, 100),
+        windows(&[(170, 180), (140, 160)]),
+        // 50 heights in 100 seconds since the start of the epoch.
+        block(100, 900 * SECOND),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let account_id = "validator.near".parse().unwrap();
+    let schedule = client.maintenance_schedule(&account_id).await.unwrap();
+    assert_eq!(schedule.epoch_height, 7);
+    assert_eq!(schedule.head_height, 150);
+    assert_eq!(schedule.block_time, Duration::from_secs(2));
+
+    let heights: Vec<_> = schedule
+        .windows
+        .iter()
+        .map(|window| (window.start_height, window.end_height))
+        .collect();
+    assert_eq!(heights, [(140, 160), (170, 180)], "{:?}", heights);
+
+    // The window around the final block is in progress.
+    let next = schedule.next().unwrap();
+    assert_eq!(next.start_time, UNIX_EPOCH + Duration::from_secs(980));
+    assert_eq!(next.end_time, UNIX_EPOCH + Duration::from_secs(1020));
+    assert_eq!(
+        schedule.time_at(175),
+        UNIX_EPOCH + Duration::from_secs(1050)
+    );
+}
+
+#[tokio::test]
+async fn test_next_window_at_start_of_epoch() {
+    let url = common::serve(vec![
+        block(100, 1000 * SECOND),
+        validators(7, 100),
+        windows(&[(90, 95), (110, 120)]),
+        // The epoch just started, so the rate is taken from the block before.
+        block(99, 999 * SECOND),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let window = client
+        .next_maintenance_window(&"validator.near".parse().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((window.start_height, window.end_height), (110, 120));
+    assert_eq!(window.start_time, UNIX_EPOCH + Duration::from_secs(1010));
+    assert_eq!(window.end_time, UNIX_EPOCH + Duration::from_secs(1020));
+}
+
+#[tokio::test]
+async fn test_maintenance_windows_error() {
+    let error = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "INTERNAL_ERROR", "info": {"error_message": "boom"}},
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    let url = common::serve(vec![
+        block(150, 1000 * SECOND),
+        validators(7, 100),
+        (200, error.to_string()),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let result = client
+        .maintenance_schedule(&"validator.near".parse().unwrap())
+        .await;
+    assert!(
+        matches!(result, Err(MaintenanceError::MaintenanceWindows(_))),
+        "{:?}",
+        result
+    );
+}
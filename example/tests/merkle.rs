@@ -0,0 +1,118 @@
+use near_openapi_client::types::merkle::{combine_hash, compute_root, merklize, verify_path};
+use near_openapi_client::types::{
+    CryptoHash, Direction, ExecutionOutcomeWithIdView, MerklePathItem,
+    RpcLightClientExecutionProofResponse,
+};
+use serde_json::json;
+
+// Expected hashes were computed independently, from nearcore's definitions.
+const LEAF: &str = "4BDtd2XqkBuGsCkqL1fMfxbs9s7a6zFXCm483GdHtg4b";
+const CHUNK_OUTCOME_ROOT: &str = "6Judb1ahH1rrYyPYyBRJGmgjRs8jYbmuPXNdA4TB7kaY";
+const OUTCOME_ROOT: &str = "4udRMbkPrt3qcq9cTtMRwJEWWdP6PhGhsqs6EFb3KKAy";
+
+fn hash(byte: u8) -> CryptoHash {
+    CryptoHash([byte; 32])
+}
+
+fn outcome_json() -> serde_json::Value {
+    json!({
+        "block_hash": hash(7),
+        "id": hash(9),
+        "outcome": {
+            "executor_id": "alice.near",
+            "gas_burnt": 2428000000000u64,
+            "logs": ["hello"],
+            "receipt_ids": [hash(8)],
+            "status": {"SuccessValue": "Im9rIg=="},
+            "tokens_burnt": "242800000000000000000",
+        },
+        "proof": [{"direction": "Left", "hash": hash(5)}],
+    })
+}
+
+#[test]
+fn test_merklize() {
+    let leaves: Vec<_> = (1..=5).map(hash).collect();
+    let (root, paths) = merklize(&leaves);
+    assert_eq!(
+        root.to_string(),
+        "iP8HbWmpuxTTnFSzcMU1U4VeqzCFqkncSow4tkK2ad1"
+    );
+    for (leaf, path) in leaves.iter().zip(&paths) {
+        assert!(verify_path(&root, path, leaf));
+        assert!(!verify_path(&root, path, &hash(0)));
+    }
+    // The last leaf has no sibling until the top level.
+    assert_eq!(
+        paths[4],
+        vec![MerklePathItem {
+            direction: Direction::Left,
+            hash: combine_hash(
+                &combine_hash(&leaves[0], &leaves[1]),
+                &combine_hash(&leaves[2], &leaves[3])
+            ),
+        }]
+    );
+
+    assert_eq!(merklize(&[hash(1)]), (hash(1), vec![vec![]]));
+    assert_eq!(merklize(&[]), (CryptoHash::default(), vec![]));
+}
+
+#[test]
+fn test_path_directions() {
+    let left = MerklePathItem {
+        direction: Direction::Left,
+        hash: hash(1),
+    };
+    let right = MerklePathItem {
+        direction: Direction::Right,
+        hash: hash(3),
+    };
+    assert_eq!(left.combine(&hash(2)), combine_hash(&hash(1), &hash(2)));
+    assert_eq!(
+        compute_root(&hash(2), &[left, right]),
+        combine_hash(&combine_hash(&hash(1), &hash(2)), &hash(3))
+    );
+    assert_eq!(compute_root(&hash(2), &[]), hash(2));
+}
+
+#[test]
+fn test_outcome_proof() {
+    let outcome: ExecutionOutcomeWithIdView = serde_json::from_value(outcome_json()).unwrap();
+    assert_eq!(outcome.leaf_hash().unwrap().to_string(), LEAF);
+    assert_eq!(
+        outcome.chunk_outcome_root().unwrap().to_string(),
+        CHUNK_OUTCOME_ROOT
+    );
+
+    let mut response = json!({
+        "block_header_lite": {
+            "inner_lite": {
+                "block_merkle_root": hash(0),
+                "epoch_id": hash(0),
+                "height": 10,
+                "next_bp_hash": hash(0),
+                "next_epoch_id": hash(0),
+                "outcome_root": OUTCOME_ROOT,
+                "prev_state_root": hash(0),
+                "timestamp": 1,
+                "timestamp_nanosec": "1",
+            },
+            "inner_rest_hash": hash(0),
+            "prev_block_hash": hash(0),
+        },
+        "block_proof": [],
+        "outcome_proof": outcome_json(),
+        "outcome_root_proof": [{"direction": "Right", "hash": hash(6)}],
+    });
+    let proof: RpcLightClientExecutionProofResponse =
+        serde_json::from_value(response.clone()).unwrap();
+    assert_eq!(proof.outcome_root().unwrap().to_string(), OUTCOME_ROOT);
+    assert!(proof.verify_outcome().unwrap());
+
+    // Any change to the committed fields breaks the proof.
+    response["outcome_proof"]["outcome"]["logs"] = json!(["bye"]);
+    let proof: RpcLightClientExecutionProofResponse = serde_json::from_value(response).unwrap();
+    assert!(!proof.verify_outcome().unwrap());
+    assert_ne!(proof.outcome_proof.leaf_hash().unwrap().to_string(), LEAF);
+}
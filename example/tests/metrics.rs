@@ -0,0 +1,103 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use near_openapi_client::batch::BatchRequest;
+use near_openapi_client::methods;
+use near_openapi_client::metrics::prometheus::{self, Encoder};
+use near_openapi_client::metrics::{MetricsRecorder, Outcome, PrometheusRecorder};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":{"gas_price":"100000000"}}"#;
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{}},"code":-32000,"message":"Server error"}}"#;
+
+#[derive(Clone, Default)]
+struct Calls(Arc<Mutex<Vec<(String, Outcome)>>>);
+
+impl MetricsRecorder for Calls {
+    fn record_call(&self, method: &str, _latency: Duration, outcome: &Outcome) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((method.to_string(), outcome.clone()));
+    }
+}
+
+async fn gas_price(client: &RpcClient) {
+    let _ = client
+        .gas_price(&types::RpcGasPriceRequest { block_id: None })
+        .await;
+}
+
+#[tokio::test]
+async fn test_records_outcome_of_every_call() {
+    let url = common::serve(vec![
+        (200, GAS_PRICE.to_string()),
+        (200, UNKNOWN_BLOCK.to_string()),
+        (503, String::new()),
+        (200, format!("[{}]", GAS_PRICE.replace("dontcare", "0"))),
+    ]);
+    let calls = Calls::default();
+    let client = RpcClient::new(&url).with_metrics(calls.clone());
+
+    gas_price(&client).await;
+    gas_price(&client).await;
+    gas_price(&client).await;
+    let mut batch = BatchRequest::new();
+    batch.add::<methods::GasPrice>(&types::RpcGasPriceRequest { block_id: None });
+    batch.send(&client).await.unwrap();
+
+    assert_eq!(
+        *calls.0.lock().unwrap(),
+        vec![
+            ("gas_price".to_string(), Outcome::Success),
+            (
+                "gas_price".to_string(),
+                Outcome::Rpc {
+                    name: "HANDLER_ERROR".to_string(),
+                    cause: Some("UNKNOWN_BLOCK".to_string()),
+                }
+            ),
+            ("gas_price".to_string(), Outcome::Status(503)),
+            ("batch".to_string(), Outcome::Success),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_prometheus_recorder() {
+    let url = common::serve(vec![
+        (200, GAS_PRICE.to_string()),
+        (200, UNKNOWN_BLOCK.to_string()),
+    ]);
+    let registry = prometheus::Registry::new();
+    let client = RpcClient::new(&url).with_metrics(PrometheusRecorder::new(&registry).unwrap());
+
+    gas_price(&client).await;
+    gas_price(&client).await;
+
+    let mut text = Vec::new();
+    prometheus::TextEncoder::new()
+        .encode(&registry.gather(), &mut text)
+        .unwrap();
+    let text = String::from_utf8(text).unwrap();
+    assert!(
+        text.contains(r#"near_rpc_requests_total{method="gas_price"} 2"#),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains(
+            r#"near_rpc_errors_total{cause="UNKNOWN_BLOCK",error="HANDLER_ERROR",method="gas_price"} 1"#
+        ),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains(r#"near_rpc_request_duration_seconds_count{method="gas_price"} 2"#),
+        "{}",
+        text
+    );
+}
@@ -0,0 +1,86 @@
+use near_openapi_client::Error;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types;
+
+fn latest() -> types::RpcGasPriceRequest {
+    types::RpcGasPriceRequest { block_id: None }
+}
+
+fn at_height(height: u64) -> types::RpcGasPriceRequest {
+    types::RpcGasPriceRequest {
+        block_id: Some(types::BlockId::BlockHeight(height)),
+    }
+}
+
+fn gas_price(yocto: u128) -> types::RpcGasPriceResponse {
+    types::RpcGasPriceResponse {
+        gas_price: types::NearToken::from_yoctonear(yocto),
+    }
+}
+
+#[tokio::test]
+async fn test_return_ok_by_params() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_height(1))
+        .return_ok(gas_price(1));
+    mock.expect::<methods::GasPrice>()
+        .times(2)
+        .return_ok(gas_price(100));
+    let client = mock.client();
+
+    let response = client.gas_price(&latest()).await.unwrap();
+    assert_eq!(response, gas_price(100));
+    let response = client.gas_price(&at_height(1)).await.unwrap();
+    assert_eq!(response, gas_price(1));
+    let response = client.gas_price(&at_height(2)).await.unwrap();
+    assert_eq!(response, gas_price(100));
+
+    let received = mock.received();
+    assert_eq!(received.len(), 3, "{:?}", received);
+    assert!(
+        received.iter().all(|(method, _)| method == "gas_price"),
+        "{:?}",
+        received
+    );
+}
+
+#[tokio::test]
+async fn test_return_error_and_status() {
+    let mock = MockRpc::start();
+    let unknown_block = types::ErrorWrapperForRpcGasPriceError::HandlerError(
+        types::RpcGasPriceError::UnknownBlock(serde_json::Map::new()),
+    );
+    mock.expect::<methods::GasPrice>()
+        .with_params(&at_height(1))
+        .return_error(unknown_block.clone());
+    mock.expect::<methods::GasPrice>().return_status(503);
+    let client = mock.client();
+
+    match client.gas_price(&at_height(1)).await {
+        Err(RpcError::Rpc(err)) => assert_eq!(err, unknown_block),
+        other => panic!("unexpected response: {:?}", other),
+    }
+    match client.gas_price(&latest()).await {
+        Err(RpcError::Client(Error::UnexpectedResponse(response))) => {
+            assert_eq!(response.status(), 503)
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[tokio::test]
+#[should_panic(expected = "unmet mock expectations")]
+async fn test_uncalled_expectation_panics() {
+    let mock = MockRpc::start();
+    mock.expect::<methods::GasPrice>().return_ok(gas_price(100));
+}
+
+#[tokio::test]
+#[should_panic(expected = "unexpected call of gas_price")]
+async fn test_unexpected_call_panics() {
+    let mock = MockRpc::start();
+    let _ = mock.client().gas_price(&latest()).await;
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use near_openapi_client::multi_token;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::multi_token::{MtBatchTransferArgs, MtEvent, MtTransferLog};
+use near_openapi_client::types::{self, FunctionArgs};
+
+fn call_result(result: &str) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"11111111111111111111111111111111","block_height":10,"logs":[],"result":{:?}}}}}"#,
+            result.as_bytes()
+        ),
+    )
+}
+
+#[test]
+fn test_batch_transfer_args_encode_amounts_as_strings() {
+    let args = MtBatchTransferArgs::new(
+        "bob.near".parse().unwrap(),
+        [
+            ("sword".to_string(), 1),
+            (
+                "gold".to_string(),
+                340_282_366_920_938_463_463_374_607_431_768_211_455,
+            ),
+        ],
+    );
+    assert_eq!(
+        serde_json::to_value(&args).unwrap(),
+        serde_json::json!({
+            "receiver_id": "bob.near",
+            "token_ids": ["sword", "gold"],
+            "amounts": ["1", "340282366920938463463374607431768211455"],
+        })
+    );
+    assert_eq!(
+        FunctionArgs::from_json(&serde_json::json!({"a": 1})).unwrap(),
+        FunctionArgs("eyJhIjoxfQ==".to_string())
+    );
+}
+
+#[test]
+fn test_events_roundtrip_through_logs() {
+    let event = MtEvent::MtTransfer(vec![MtTransferLog {
+        authorized_id: None,
+        old_owner_id: "alice.near".parse().unwrap(),
+        new_owner_id: "bob.near".parse().unwrap(),
+        token_ids: vec!["sword".to_string()],
+        amounts: vec![2],
+        memo: None,
+    }]);
+    let log = event.to_log();
+    assert_eq!(
+        log,
+        r#"EVENT_JSON:{"standard":"nep245","version":"1.0.0","event":"mt_transfer","data":[{"old_owner_id":"alice.near","new_owner_id":"bob.near","token_ids":["sword"],"amounts":["2"]}]}"#
+    );
+    assert_eq!(MtEvent::from_log(&log), Some(event));
+    assert_eq!(
+        MtEvent::from_log(
+            r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_mint","data":[]}"#
+        ),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_batch_balance_of() {
+    let url = common::serve(vec![call_result(r#"["5","1000000000000000000000000"]"#)]);
+    let client = RpcClient::new(&url);
+
+    let balances = multi_token::mt_batch_balance_of(
+        &client,
+        &"game.near".parse().unwrap(),
+        &"alice.near".parse().unwrap(),
+        &["sword".to_string(), "gold".to_string()],
+        types::BlockReference::Finality(types::Finality::Final),
+    )
+    .await
+    .unwrap();
+    assert_eq!(balances, vec![5, 1_000_000_000_000_000_000_000_000]);
+}
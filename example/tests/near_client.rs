@@ -0,0 +1,63 @@
+mod common;
+
+use std::sync::Arc;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods;
+use near_openapi_client::near_client::{CallFuture, NearClient};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+use serde_json::{Value, json};
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":{"gas_price":"100000000"}}"#;
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{}},"code":-32000,"message":"Server error"}}"#;
+
+/// Answers every call with the same gas price.
+struct FixedGasPrice;
+
+impl NearClient for FixedGasPrice {
+    fn call_json<'a>(&'a self, method: &'a str, _params: Value) -> CallFuture<'a> {
+        assert_eq!(method, "gas_price");
+        Box::pin(async { Ok(json!({"gas_price": "1"})) })
+    }
+}
+
+async fn gas_price(
+    client: &dyn NearClient,
+) -> Result<types::NearToken, RpcError<types::ErrorWrapperForRpcGasPriceError>> {
+    let response = client
+        .request::<methods::GasPrice>(&types::RpcGasPriceRequest { block_id: None })
+        .await?;
+    Ok(response.gas_price)
+}
+
+#[tokio::test]
+async fn test_rpc_client_as_dyn_near_client() {
+    let url = common::serve(vec![
+        (200, GAS_PRICE.to_string()),
+        (200, UNKNOWN_BLOCK.to_string()),
+    ]);
+    let client: Arc<dyn NearClient> = Arc::new(RpcClient::new(&url));
+
+    let price = gas_price(client.as_ref()).await.unwrap();
+    assert_eq!(price.as_yoctonear(), 100_000_000);
+    let result = gas_price(client.as_ref()).await;
+    assert!(
+        matches!(
+            result,
+            Err(RpcError::Rpc(
+                types::ErrorWrapperForRpcGasPriceError::HandlerError(
+                    types::RpcGasPriceError::UnknownBlock(_)
+                )
+            ))
+        ),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_other_near_client() {
+    let price = gas_price(&FixedGasPrice).await.unwrap();
+    assert_eq!(price.as_yoctonear(), 1);
+}
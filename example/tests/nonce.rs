@@ -0,0 +1,73 @@
+mod common;
+
+use near_openapi_client::nonce::NonceManager;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{AccountId, PublicKey};
+
+fn access_key(nonce: u64) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"11111111111111111111111111111111","block_height":10,"nonce":{nonce},"permission":"FullAccess"}}}}"#
+        ),
+    )
+}
+
+fn key() -> (AccountId, PublicKey) {
+    (
+        "alice.near".parse().unwrap(),
+        "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+            .parse()
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_concurrent_nonces_are_unique_and_increasing() {
+    let url = common::serve(vec![access_key(5)]);
+    let manager = NonceManager::new(RpcClient::new(&url));
+    let (account_id, public_key) = key();
+    let first = manager.next_nonce(&account_id, &public_key).await.unwrap();
+    assert_eq!(first, 6);
+
+    let tasks: Vec<_> = (0..10)
+        .map(|_| {
+            let manager = manager.clone();
+            let (account_id, public_key) = key();
+            tokio::spawn(async move { manager.next_nonce(&account_id, &public_key).await })
+        })
+        .collect();
+    let mut nonces = Vec::new();
+    for task in tasks {
+        nonces.push(task.await.unwrap().unwrap());
+    }
+    nonces.sort();
+    assert_eq!(nonces, (7..17).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn test_refresh_keeps_higher_cached_nonce() {
+    let url = common::serve(vec![access_key(5), access_key(6), access_key(20)]);
+    let manager = NonceManager::new(RpcClient::new(&url));
+    let (account_id, public_key) = key();
+
+    assert_eq!(
+        manager.next_nonce(&account_id, &public_key).await.unwrap(),
+        6
+    );
+    assert_eq!(
+        manager.next_nonce(&account_id, &public_key).await.unwrap(),
+        7
+    );
+    manager.refresh(&account_id, &public_key).await.unwrap();
+    assert_eq!(
+        manager.next_nonce(&account_id, &public_key).await.unwrap(),
+        8
+    );
+
+    manager.invalidate(&account_id, &public_key);
+    assert_eq!(
+        manager.next_nonce(&account_id, &public_key).await.unwrap(),
+        21
+    );
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use near_openapi_client::Client;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+
+const HEALTHY: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":null}"#;
+const SYNCING: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"NODE_IS_SYNCING"},"code":-32000,"message":"Server error"}}"#;
+
+#[tokio::test]
+async fn test_typed_null_result() {
+    let url = common::serve(vec![
+        (200, HEALTHY.to_string()),
+        // Some providers leave out the `null` result.
+        (200, r#"{"jsonrpc":"2.0","id":"dontcare"}"#.to_string()),
+    ]);
+    let client = RpcClient::new(&url);
+
+    for _ in 0..2 {
+        let health = client.health(&types::RpcHealthRequest(())).await.unwrap();
+        assert_eq!(health, types::RpcHealthResponse(()));
+    }
+}
+
+#[tokio::test]
+async fn test_typed_null_result_error() {
+    let url = common::serve(vec![(200, SYNCING.to_string())]);
+    let client = RpcClient::new(&url);
+
+    let result = client.health(&types::RpcHealthRequest(())).await;
+    assert!(
+        matches!(
+            result,
+            Err(RpcError::Rpc(
+                types::ErrorWrapperForRpcStatusError::HandlerError(
+                    types::RpcStatusError::NodeIsSyncing
+                )
+            ))
+        ),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_generated_null_result_envelope() {
+    let url = common::serve(vec![(200, HEALTHY.to_string()), (200, SYNCING.to_string())]);
+    let client = Client::new(&url);
+    let request = types::JsonRpcRequestForHealth {
+        id: "dontcare".to_string(),
+        jsonrpc: "2.0".to_string(),
+        method: types::JsonRpcRequestForHealthMethod::Health,
+        params: types::RpcHealthRequest(()),
+    };
+
+    let healthy = client.health(&request).await.unwrap().into_inner();
+    assert!(matches!(
+        healthy,
+        types::JsonRpcResponseForNullableRpcHealthResponseAndRpcStatusError::Variant0 {
+            result: None,
+            ..
+        }
+    ));
+
+    // The error must not be taken for a success with a missing result.
+    let syncing = client.health(&request).await.unwrap().into_inner();
+    assert!(
+        matches!(
+            syncing,
+            types::JsonRpcResponseForNullableRpcHealthResponseAndRpcStatusError::Variant1 {
+                error: types::ErrorWrapperForRpcStatusError::HandlerError(
+                    types::RpcStatusError::NodeIsSyncing
+                ),
+                ..
+            }
+        ),
+        "{:?}",
+        syncing
+    );
+}
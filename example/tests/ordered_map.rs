@@ -0,0 +1,28 @@
+//! With `preserve-order`, maps of the generated types keep the order of the
+//! JSON they were decoded from.
+use near_openapi_client::types::{ActionView, OrderedMap};
+
+#[test]
+fn test_state_init_data_keeps_byte_order() {
+    // Nodes sort the data by its bytes, so `AA==` ([0x00]) comes before
+    // `fwE=` ([0x7f, 0x01]) and `/w==` ([0xff]), unlike sorted strings.
+    let json = r#"{"DeterministicStateInit":{"code":"token.near","data":{"AA==":"YQ==","fwE=":"","/w==":"Yg=="},"deposit":"0"}}"#;
+    let action: ActionView = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&action).unwrap(), json);
+
+    let ActionView::DeterministicStateInit { data, .. } = action else {
+        panic!("{:?}", action);
+    };
+    let keys: Vec<_> = data.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["AA==", "fwE=", "/w=="]);
+}
+
+#[test]
+fn test_maps_compare_regardless_of_order() {
+    let forward: OrderedMap<String, String> = [("a", "1"), ("b", "2")]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    let backward: OrderedMap<String, String> = forward.clone().into_iter().rev().collect();
+    assert_eq!(forward, backward);
+}
@@ -0,0 +1,50 @@
+use near_openapi_client::types::ActionsValidationError;
+use near_openapi_client::types::protocol_features::{
+    PROTOCOL_FEATURES, ProtocolFeature, protocol_features,
+};
+
+fn names(protocol_version: u32) -> Vec<&'static str> {
+    protocol_features(protocol_version)
+        .iter()
+        .map(|feature| feature.name)
+        .collect()
+}
+
+#[test]
+fn test_features_by_version() {
+    assert!(names(58).is_empty());
+    assert_eq!(names(59), ["DelegateAction", "ZeroBalanceAccount"]);
+    assert_eq!(names(76).last(), Some(&"EthImplicitAccounts"));
+    assert_eq!(protocol_features(u32::MAX), PROTOCOL_FEATURES);
+    assert!(
+        PROTOCOL_FEATURES
+            .windows(2)
+            .all(|pair| pair[0].protocol_version <= pair[1].protocol_version)
+    );
+}
+
+#[test]
+fn test_display() {
+    let feature = ProtocolFeature::by_name("GlobalContracts").unwrap();
+    assert_eq!(
+        feature.to_string(),
+        "GlobalContracts (protocol version 77): contract code deployed once and used by many accounts, see https://github.com/near/NEPs/blob/master/neps/nep-0591.md"
+    );
+}
+
+#[test]
+fn test_unsupported_protocol_feature() {
+    let error: ActionsValidationError = serde_json::from_value(serde_json::json!({
+        "UnsupportedProtocolFeature": {"protocol_feature": "GlobalContracts", "version": 76}
+    }))
+    .unwrap();
+    let feature = error.unsupported_protocol_feature().unwrap();
+    assert_eq!(feature.protocol_version, 77);
+    assert!(!feature.is_enabled(76));
+
+    let error = ActionsValidationError::UnsupportedProtocolFeature {
+        protocol_feature: "SomethingNew".to_string(),
+        version: 76,
+    };
+    assert_eq!(error.unsupported_protocol_feature(), None);
+}
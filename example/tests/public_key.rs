@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+use near_openapi_client::types::{
+    AccessKeyInfoView, CryptoHash, KeyType, PublicKey, tx::SecretKey,
+};
+
+const ED25519: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const SECP256K1: &str = "secp256k1:3SB8tA9Kbn7FBtT6GWR6AJk73QceudisHaGThPoLCDgC9tan7d3cwZFiDZtrmhSAf8aTynEdQ3N7KXhMm3nWhekP";
+/// The scalar `0x0101...01`.
+const SECP256K1_SECRET_KEY: &str = "secp256k1:4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+const ED25519_SECRET_KEY: &str = "ed25519:49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+
+#[test]
+fn test_parse() {
+    let key: PublicKey = ED25519.parse().unwrap();
+    assert_eq!(key.key_type(), KeyType::Ed25519);
+    assert_eq!(key.key_data().len(), 32);
+    assert_eq!(key.to_string(), ED25519);
+    assert_eq!(format!("{:?}", key), format!("PublicKey({:?})", ED25519));
+
+    let key: PublicKey = SECP256K1.parse().unwrap();
+    assert_eq!(key.key_type(), KeyType::Secp256k1);
+    assert_eq!(key.key_data().len(), 64);
+    assert_eq!(key.to_string(), SECP256K1);
+    assert_eq!(
+        PublicKey::from_parts(KeyType::Secp256k1, key.key_data()).unwrap(),
+        key
+    );
+
+    // Keys without a type are ed25519 keys.
+    let untyped = ED25519.strip_prefix("ed25519:").unwrap();
+    assert_eq!(
+        untyped.parse::<PublicKey>().unwrap(),
+        ED25519.parse().unwrap()
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    for invalid in [
+        "",
+        "ed25519:",
+        "ed25519:abc",
+        "ed25519:0OIl",
+        "rsa:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+        // A secp256k1 key with an ed25519 prefix and the other way around.
+        &SECP256K1.replace("secp256k1:", "ed25519:"),
+        &ED25519.replace("ed25519:", "secp256k1:"),
+    ] {
+        assert!(invalid.parse::<PublicKey>().is_err(), "{:?}", invalid);
+    }
+    assert!(PublicKey::from_parts(KeyType::Ed25519, &[0; 31]).is_err());
+    assert_eq!(
+        "ed25519:abc".parse::<PublicKey>().unwrap_err().to_string(),
+        "ed25519 public key is not 32 bytes long"
+    );
+}
+
+#[test]
+fn test_serde_keeps_string_wire_format() {
+    let json = serde_json::json!({
+        "public_key": SECP256K1,
+        "access_key": {"nonce": 1, "permission": "FullAccess"},
+    });
+    let info: AccessKeyInfoView = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(info.public_key, SECP256K1.parse().unwrap());
+    assert_eq!(serde_json::to_value(&info).unwrap(), json);
+
+    let key: PublicKey = serde_json::from_str(&format!("\"{}\"", ED25519)).unwrap();
+    assert_eq!(
+        serde_json::to_string(&key).unwrap(),
+        format!("\"{}\"", ED25519)
+    );
+    assert!(serde_json::from_str::<PublicKey>("\"ed25519:abc\"").is_err());
+}
+
+#[test]
+fn test_copy_hash_and_ord() {
+    let ed25519: PublicKey = ED25519.parse().unwrap();
+    let secp256k1: PublicKey = SECP256K1.parse().unwrap();
+    let copy = ed25519;
+    assert_eq!(HashSet::from([ed25519, copy, secp256k1]).len(), 2);
+    assert!(ed25519 < secp256k1);
+}
+
+#[test]
+fn test_verify_ed25519() {
+    let secret_key: SecretKey = ED25519_SECRET_KEY.parse().unwrap();
+    let public_key = secret_key.public_key();
+    let hash = CryptoHash([7; 32]);
+    let signature = secret_key.sign(&hash);
+    assert!(public_key.verify(&hash.0, &signature));
+    assert!(!public_key.verify(&[8; 32], &signature));
+
+    let other: PublicKey = ED25519.parse().unwrap();
+    assert!(!other.verify(&hash.0, &signature));
+}
+
+#[test]
+fn test_verify_secp256k1() {
+    let secret_key: SecretKey = SECP256K1_SECRET_KEY.parse().unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(public_key.key_type(), KeyType::Secp256k1);
+    let hash = CryptoHash([7; 32]);
+    let signature = secret_key.sign(&hash);
+    assert!(public_key.verify(&hash.0, &signature));
+    assert!(!public_key.verify(&[8; 32], &signature));
+    // Secp256k1 keys only sign 32-byte hashes.
+    assert!(!public_key.verify(&[7; 31], &signature));
+
+    let other: PublicKey = SECP256K1.parse().unwrap();
+    assert!(!other.verify(&hash.0, &signature));
+
+    // Signatures of another curve never verify.
+    let ed25519_signature = ED25519_SECRET_KEY.parse::<SecretKey>().unwrap().sign(&hash);
+    assert!(!public_key.verify(&hash.0, &ed25519_signature));
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::path::PathBuf;
+
+use near_openapi_client::Error;
+use near_openapi_client::batch::BatchRequest;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods;
+use near_openapi_client::replay::Fixtures;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":{"gas_price":"100000000"}}"#;
+
+/// Nothing listens here: replayed requests are never sent.
+const UNREACHABLE: &str = "http://127.0.0.1:9";
+
+fn fixtures_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "near-openapi-fixtures-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn at_height(height: u64) -> types::RpcGasPriceRequest {
+    types::RpcGasPriceRequest {
+        block_id: Some(types::BlockId::BlockHeight(height)),
+    }
+}
+
+#[tokio::test]
+async fn test_record_then_replay() {
+    let dir = fixtures_dir("calls");
+    let url = common::serve(vec![
+        (200, GAS_PRICE.to_string()),
+        (200, format!("[{}]", GAS_PRICE.replace("dontcare", "0"))),
+    ]);
+    let recording = RpcClient::new(&url).with_fixtures(Fixtures::record(&dir));
+    let recorded = recording.gas_price(&at_height(1)).await.unwrap();
+    let mut batch = BatchRequest::new();
+    let call = batch.add::<methods::GasPrice>(&at_height(2));
+    batch.send(&recording).await.unwrap();
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+    let replaying = RpcClient::new(UNREACHABLE).with_fixtures(Fixtures::replay(&dir));
+    let replayed = replaying.gas_price(&at_height(1)).await.unwrap();
+    assert_eq!(replayed, recorded);
+    let mut batch = BatchRequest::new();
+    batch.add::<methods::GasPrice>(&at_height(2));
+    let response = batch.send(&replaying).await.unwrap();
+    assert_eq!(response.get(call).unwrap(), recorded);
+
+    let result = replaying.gas_price(&at_height(3)).await;
+    match result {
+        Err(RpcError::Client(Error::InvalidRequest(message))) => {
+            assert!(message.starts_with("no fixture at"), "{}", message)
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_failed_calls_are_not_recorded() {
+    let dir = fixtures_dir("failures");
+    let url = common::serve(vec![(503, String::new())]);
+    let recording = RpcClient::new(&url).with_fixtures(Fixtures::record(&dir));
+    assert!(recording.gas_price(&at_height(1)).await.is_err());
+    assert!(!dir.exists());
+}
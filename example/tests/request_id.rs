@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use near_openapi_client::cache::ResponseCache;
+use near_openapi_client::methods;
+use near_openapi_client::request_id::{self, RequestIds};
+use near_openapi_client::retry::{Backoff, RetryPolicy};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::transport::{Bytes, Transport, TransportFuture, TransportResponse};
+use near_openapi_client::types;
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"svc-0","result":{"gas_price":"100000000"}}"#;
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"svc-0","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{"block_reference":{"block_id":1}}},"code":-32000,"message":"Server error"}}"#;
+const PARSE_ERROR: &str = r#"{"jsonrpc":"2.0","id":"svc-0","error":{"name":"REQUEST_VALIDATION_ERROR","cause":{"name":"PARSE_ERROR","info":{"error_message":"Failed parsing args: missing field `block_id`"}},"code":-32700,"message":"Parse error"}}"#;
+
+/// Transport answering from a queue and keeping the JSON-RPC ids of the
+/// requests.
+#[derive(Clone, Default)]
+struct Canned {
+    responses: Arc<Mutex<VecDeque<(u16, &'static str)>>>,
+    ids: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+impl Canned {
+    fn new(responses: Vec<(u16, &'static str)>) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(responses.into())),
+            ids: Arc::default(),
+        }
+    }
+
+    fn ids(&self) -> Vec<serde_json::Value> {
+        self.ids.lock().unwrap().clone()
+    }
+}
+
+impl Transport for Canned {
+    fn send<'a>(&'a self, _url: &'a str, body: Bytes) -> TransportFuture<'a> {
+        let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        self.ids.lock().unwrap().push(request["id"].clone());
+        let (status, body) = self.responses.lock().unwrap().pop_front().unwrap();
+        Box::pin(async move {
+            Ok(TransportResponse {
+                status,
+                body: Bytes::from(body),
+            })
+        })
+    }
+}
+
+fn at_height(height: u64) -> types::RpcGasPriceRequest {
+    types::RpcGasPriceRequest {
+        block_id: Some(types::BlockId::BlockHeight(height)),
+    }
+}
+
+#[tokio::test]
+async fn test_sends_calls_with_ids() {
+    let transport = Canned::new(vec![
+        (200, GAS_PRICE),
+        (200, UNKNOWN_BLOCK),
+        (200, GAS_PRICE),
+    ]);
+    let client = RpcClient::new("http://node.invalid")
+        .with_transport(transport.clone())
+        .with_request_ids(RequestIds::with_prefix("svc"))
+        .with_retry(RetryPolicy::new().backoff(Backoff::Fixed(Duration::from_millis(1))));
+
+    client.gas_price(&at_height(1)).await.unwrap();
+    // Retries keep the id of the call, clones share the numbering.
+    client.clone().gas_price(&at_height(1)).await.unwrap();
+    assert_eq!(transport.ids(), ["svc-0", "svc-1", "svc-1"]);
+}
+
+#[tokio::test]
+async fn test_sends_calls_without_ids_by_default() {
+    let transport = Canned::new(vec![(200, GAS_PRICE), (200, GAS_PRICE)]);
+    let client = RpcClient::new("http://node.invalid").with_transport(transport.clone());
+
+    client.gas_price(&at_height(1)).await.unwrap();
+    let response = client
+        .request_with_id::<methods::GasPrice>(&at_height(1))
+        .await
+        .unwrap();
+    assert_eq!(response.gas_price.as_yoctonear(), 100_000_000);
+    let ids = transport.ids();
+    assert_eq!(ids[0], "dontcare");
+    let id = ids[1].as_str().unwrap();
+    assert!(
+        id.len() > 9 && id.as_bytes()[8] == b'-' && id[..8].chars().all(|c| c.is_ascii_hexdigit()),
+        "{}",
+        id
+    );
+}
+
+#[tokio::test]
+async fn test_errors_tell_id_and_causes() {
+    let transport = Canned::new(vec![(200, UNKNOWN_BLOCK), (200, PARSE_ERROR), (503, "")]);
+    let client = RpcClient::new("http://node.invalid")
+        .with_transport(transport)
+        .with_request_ids(RequestIds::with_prefix("svc"));
+
+    let err = client
+        .request_with_id::<methods::GasPrice>(&at_height(1))
+        .await
+        .unwrap_err();
+    assert_eq!(err.request_id.as_str(), "svc-0");
+    assert_eq!(
+        err.to_string(),
+        r#"request svc-0 failed: HANDLER_ERROR: UNKNOWN_BLOCK: {"block_reference":{"block_id":1}}"#
+    );
+
+    let err = client
+        .request_with_id::<methods::GasPrice>(&at_height(1))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.cause_chain(),
+        [
+            "REQUEST_VALIDATION_ERROR",
+            "PARSE_ERROR",
+            "Failed parsing args: missing field `block_id`"
+        ]
+    );
+
+    let err = client.gas_price(&at_height(1)).await.unwrap_err();
+    let chain = request_id::cause_chain(&err);
+    assert!(chain[0].contains("503"), "{:?}", chain);
+}
+
+#[tokio::test]
+async fn test_cache_ignores_ids() {
+    const BY_HASH: &str = r#"{"jsonrpc":"2.0","id":"svc-0","result":{"gas_price":"1"}}"#;
+    let transport = Canned::new(vec![(200, BY_HASH)]);
+    let client = RpcClient::new("http://node.invalid")
+        .with_transport(transport.clone())
+        .with_request_ids(RequestIds::with_prefix("svc"))
+        .with_cache(ResponseCache::new(10));
+    let request = types::RpcGasPriceRequest {
+        block_id: Some(types::BlockId::CryptoHash(
+            "AmDsC4pxnE1FxJyZvTYLZR448e3yz2qHWCFFuHu3MiUu"
+                .parse()
+                .unwrap(),
+        )),
+    };
+
+    for _ in 0..2 {
+        let response = client.gas_price(&request).await.unwrap();
+        assert_eq!(response.gas_price.as_yoctonear(), 1);
+    }
+    assert_eq!(transport.ids(), ["svc-0"]);
+}
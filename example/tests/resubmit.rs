@@ -0,0 +1,134 @@
+mod common;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::resubmit::{self, ResubmitError, ResubmitPolicy, TransactionParams};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::tx::TransactionBuilder;
+use near_openapi_client::types::{self, NearGas};
+
+const HASH_1: &str = "11111111111111111111111111111111";
+const HASH_2: &str = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const SIGNATURE: &str = "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj";
+
+fn access_key(nonce: u64, block_hash: &str) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"{block_hash}","block_height":10,"nonce":{nonce},"permission":"FullAccess"}}}}"#
+        ),
+    )
+}
+
+fn invalid_tx(reason: &str) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","error":{{"name":"HANDLER_ERROR","cause":{{"name":"INVALID_TRANSACTION","info":{{"TxExecutionError":{{"InvalidTxError":{reason}}}}}}}}}}}"#
+        ),
+    )
+}
+
+fn executed(nonce: u64) -> (u16, String) {
+    let outcome = format!(
+        r#"{{"block_hash":"{HASH_1}","id":"{HASH_1}","proof":[],"outcome":{{"executor_id":"alice.near","gas_burnt":1,"logs":[],"metadata":{{"version":1}},"receipt_ids":[],"status":{{"SuccessValue":""}},"tokens_burnt":"0"}}}}"#
+    );
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"final_execution_status":"EXECUTED_OPTIMISTIC","receipts_outcome":[],"status":{{"SuccessValue":""}},"transaction":{{"actions":[],"hash":"{HASH_1}","nonce":{nonce},"priority_fee":0,"public_key":"ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp","receiver_id":"bob.near","signature":"ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj","signer_id":"alice.near"}},"transaction_outcome":{outcome}}}}}"#
+        ),
+    )
+}
+
+async fn send(
+    url: &str,
+    policy: &ResubmitPolicy,
+) -> (resubmit::Resubmission, Vec<TransactionParams>) {
+    let client = RpcClient::new(url);
+    let mut signed = Vec::new();
+    let resubmission = resubmit::send_with_resubmission(
+        &client,
+        &"alice.near".parse().unwrap(),
+        &PUBLIC_KEY.parse().unwrap(),
+        NearGas::from_tgas(10),
+        policy,
+        |params| {
+            // Sent transactions must decode, the signature is not checked.
+            let signed_tx = TransactionBuilder::new(
+                "alice.near".parse().unwrap(),
+                PUBLIC_KEY.parse().unwrap(),
+                "bob.near".parse().unwrap(),
+            )
+            .build(params.nonce, params.block_hash)
+            .with_signature(SIGNATURE.parse().unwrap())
+            .to_base64()
+            .unwrap();
+            signed.push(params);
+            async { signed_tx }
+        },
+    )
+    .await;
+    (resubmission, signed)
+}
+
+#[tokio::test]
+async fn test_resubmits_with_refreshed_params_and_escalated_gas() {
+    let url = common::serve(vec![
+        access_key(5, HASH_1),
+        invalid_tx(r#""Expired""#),
+        access_key(5, HASH_2),
+        invalid_tx(r#"{"ShardCongested":{"congestion_level":1.0,"shard_id":0}}"#),
+        access_key(6, HASH_2),
+        executed(7),
+    ]);
+    let policy = ResubmitPolicy::new().escalate_gas(2.0, NearGas::from_tgas(30));
+
+    let (resubmission, signed) = send(&url, &policy).await;
+
+    resubmission.result.unwrap();
+    let gas: Vec<_> = signed.iter().map(|params| params.gas.as_tgas()).collect();
+    assert_eq!(gas, vec![10, 20, 30]);
+    let nonces: Vec<_> = signed.iter().map(|params| params.nonce).collect();
+    assert_eq!(nonces, vec![6, 6, 7]);
+    assert_eq!(signed[1].block_hash.to_string(), HASH_2);
+    assert_eq!(resubmission.rejected.len(), 2);
+    assert_eq!(
+        resubmission.rejected[0].reason,
+        types::InvalidTxError::Expired
+    );
+    assert_eq!(resubmission.rejected[1].params, signed[1]);
+}
+
+#[tokio::test]
+async fn test_stops_after_max_attempts() {
+    let url = common::serve(vec![
+        access_key(5, HASH_1),
+        invalid_tx(r#""Expired""#),
+        access_key(5, HASH_1),
+        invalid_tx(r#""Expired""#),
+    ]);
+
+    let (resubmission, signed) = send(&url, &ResubmitPolicy::new().max_attempts(2)).await;
+
+    assert_eq!(signed.len(), 2);
+    assert_eq!(resubmission.rejected.len(), 1);
+    assert!(matches!(
+        resubmission.result,
+        Err(ResubmitError::Send(RpcError::Rpc(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_does_not_resubmit_other_errors() {
+    let url = common::serve(vec![
+        access_key(5, HASH_1),
+        invalid_tx(r#""InvalidSignature""#),
+    ]);
+
+    let (resubmission, signed) = send(&url, &ResubmitPolicy::new()).await;
+
+    assert_eq!(signed.len(), 1);
+    assert!(resubmission.rejected.is_empty());
+    assert!(resubmission.result.is_err());
+}
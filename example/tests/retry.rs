@@ -0,0 +1,181 @@
+mod common;
+
+use std::time::Duration;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods::ExperimentalChangesInBlock;
+use near_openapi_client::retry::{Backoff, FailedAttempt, RetryPolicy, TipRetry};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":{"gas_price":"100000000"}}"#;
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{}},"code":-32000,"message":"Server error"}}"#;
+
+const HASH: &str = "11111111111111111111111111111111";
+const PREV_HASH: &str = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .max_attempts(3)
+        .backoff(Backoff::Fixed(Duration::from_millis(1)))
+}
+
+async fn gas_price(
+    client: &RpcClient,
+) -> Result<types::RpcGasPriceResponse, RpcError<types::ErrorWrapperForRpcGasPriceError>> {
+    client
+        .gas_price(&types::RpcGasPriceRequest { block_id: None })
+        .await
+}
+
+#[tokio::test]
+async fn test_retries_transient_failures() {
+    let url = common::serve(vec![
+        (503, String::new()),
+        (200, UNKNOWN_BLOCK.to_string()),
+        (200, GAS_PRICE.to_string()),
+    ]);
+    let client = RpcClient::new(&url).with_retry(policy());
+
+    let response = gas_price(&client).await.unwrap();
+    assert_eq!(response.gas_price.as_yoctonear(), 100_000_000);
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let url = common::serve(vec![
+        (429, String::new()),
+        (429, String::new()),
+        (429, String::new()),
+    ]);
+    let client = RpcClient::new(&url).with_retry(policy());
+
+    let result = gas_price(&client).await;
+    assert!(matches!(result, Err(RpcError::Client(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_custom_predicate() {
+    let url = common::serve(vec![(200, UNKNOWN_BLOCK.to_string())]);
+    let client = RpcClient::new(&url).with_retry(policy().retry_on(|failure| {
+        !matches!(failure, FailedAttempt::Rpc { cause: Some(cause), .. } if cause == "UNKNOWN_BLOCK")
+    }));
+
+    let result = gas_price(&client).await;
+    assert!(matches!(result, Err(RpcError::Rpc(_))), "{:?}", result);
+}
+
+#[test]
+fn test_exponential_backoff() {
+    let backoff = Backoff::Exponential {
+        initial: Duration::from_millis(100),
+        max: Duration::from_secs(1),
+    };
+    assert_eq!(backoff.delay(0), Duration::from_millis(100));
+    assert_eq!(backoff.delay(2), Duration::from_millis(400));
+    assert_eq!(backoff.delay(10), Duration::from_secs(1));
+    assert_eq!(backoff.delay(100), Duration::from_secs(1));
+}
+
+fn header() -> types::BlockHeaderView {
+    serde_json::from_value(serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": HASH,
+        "challenges_result": [],
+        "challenges_root": HASH,
+        "chunk_headers_root": HASH,
+        "chunk_mask": [],
+        "chunk_receipts_root": HASH,
+        "chunk_tx_root": HASH,
+        "chunks_included": 0,
+        "epoch_id": HASH,
+        "gas_price": "100000000",
+        "hash": HASH,
+        "height": 10,
+        "last_ds_final_block": HASH,
+        "last_final_block": HASH,
+        "latest_protocol_version": 80,
+        "next_bp_hash": HASH,
+        "next_epoch_id": HASH,
+        "outcome_root": HASH,
+        "prev_hash": PREV_HASH,
+        "prev_state_root": HASH,
+        "random_value": HASH,
+        "rent_paid": "0",
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    }))
+    .unwrap()
+}
+
+fn changes_in_block(block_hash: &str) -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"{block_hash}","changes":[]}}}}"#
+    )
+}
+
+fn changes_request(block_hash: &types::CryptoHash) -> types::RpcStateChangesInBlockRequest {
+    types::RpcStateChangesInBlockRequest::BlockId(types::BlockId::CryptoHash(*block_hash))
+}
+
+#[tokio::test]
+async fn test_tip_retry_after_delay() {
+    let url = common::serve(vec![
+        (200, UNKNOWN_BLOCK.to_string()),
+        (200, changes_in_block(HASH)),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let tip = client
+        .request_at_tip::<ExperimentalChangesInBlock>(
+            &header(),
+            TipRetry::After(Duration::from_millis(1)),
+            changes_request,
+        )
+        .await
+        .unwrap();
+    assert_eq!(tip.block_hash.to_string(), HASH);
+    assert_eq!(tip.response.block_hash.to_string(), HASH);
+}
+
+#[tokio::test]
+async fn test_tip_retry_prev_block() {
+    let url = common::serve(vec![
+        (200, UNKNOWN_BLOCK.to_string()),
+        (200, changes_in_block(PREV_HASH)),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let tip = client
+        .request_at_tip::<ExperimentalChangesInBlock>(
+            &header(),
+            TipRetry::PrevBlock,
+            changes_request,
+        )
+        .await
+        .unwrap();
+    assert_eq!(tip.block_hash.to_string(), PREV_HASH);
+    assert_eq!(tip.response.block_hash.to_string(), PREV_HASH);
+}
+
+#[tokio::test]
+async fn test_tip_retry_once() {
+    let url = common::serve(vec![
+        (200, UNKNOWN_BLOCK.to_string()),
+        (200, UNKNOWN_BLOCK.to_string()),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let result = client
+        .request_at_tip::<ExperimentalChangesInBlock>(
+            &header(),
+            TipRetry::PrevBlock,
+            changes_request,
+        )
+        .await;
+    assert!(matches!(result, Err(RpcError::Rpc(_))), "{:?}", result);
+}
@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use near_openapi_client::types::round_trip::{
+    CanonicalJson, RoundTripError, canonical_json, check_canonical_json, check_json,
+};
+use near_openapi_client::types::{self, lenient};
+
+#[test]
+fn test_check_json() {
+    let kind = check_json::<types::ActionErrorKind>(
+        br#"{"AccountDoesNotExist": {"account_id": "alice.near"}}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        kind,
+        Some(types::ActionErrorKind::AccountDoesNotExist { .. })
+    ));
+    assert_eq!(
+        check_json::<types::ActionErrorKind>(b"not json").unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_large_numbers_round_trip() {
+    // Integers beyond u64 decode as floats, which must encode back to the
+    // same float.
+    let (result, _) = lenient::decode_leniently(|| {
+        check_json::<types::ActionErrorKind>(b"88888888888888888888888888888888888888888888")
+    });
+    assert!(matches!(
+        result,
+        Ok(Some(types::ActionErrorKind::Unknown(_)))
+    ));
+}
+
+/// Decodes `{"names": [..]}` as `Named`, which encodes as `{"keys": [..]}`
+/// and then decodes as `Keys`.
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum Listing {
+    Keys {
+        keys: Vec<u8>,
+    },
+    Named {
+        #[serde(rename(serialize = "keys"))]
+        names: Vec<u8>,
+    },
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Renamed {
+    #[serde(rename(serialize = "b"))]
+    a: u8,
+}
+
+#[test]
+fn test_unstable_values_are_reported() {
+    assert_eq!(
+        check_json::<Listing>(br#"{"names": [1]}"#),
+        Err(RoundTripError::Changed {
+            json: r#"{"keys":[1]}"#.to_string()
+        })
+    );
+    assert!(matches!(
+        check_json::<Renamed>(br#"{"a": 1}"#),
+        Err(RoundTripError::Undecodable { .. })
+    ));
+}
+
+fn golden(name: &str) -> Vec<u8> {
+    std::fs::read(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(name),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_golden_responses_are_canonical_stable() {
+    fn check<T>(name: &str)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + PartialEq,
+    {
+        let data = golden(name);
+        let canonical = check_canonical_json::<T>(&data).unwrap().unwrap();
+        // The JSON of the node, only encoded canonically.
+        let golden: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        assert_eq!(canonical, canonical_json(&golden).unwrap(), "{}", name);
+    }
+
+    check::<types::RpcBlockResponse>("block.json");
+    check::<types::RpcChunkResponse>("chunk.json");
+    check::<types::RpcTransactionResponse>("tx.json");
+    check::<types::RpcTransactionResponse>("tx_failure.json");
+}
+
+#[test]
+fn test_canonical_json_ignores_key_order() {
+    let sorted: types::AccountView = serde_json::from_str(
+        r#"{"amount":"1","code_hash":"11111111111111111111111111111111","locked":"0","storage_usage":182}"#,
+    )
+    .unwrap();
+    let shuffled: types::AccountView = serde_json::from_str(
+        r#"{"storage_usage": 182, "locked": "0", "code_hash": "11111111111111111111111111111111", "amount": "1"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        sorted.canonical_json().unwrap(),
+        shuffled.canonical_json().unwrap()
+    );
+}
+
+#[test]
+fn test_canonical_json_sorts_preserved_maps() {
+    // With `preserve-order`, the data keeps the order of the node, which
+    // canonical JSON sorts again.
+    let action: types::ActionView = serde_json::from_str(
+        r#"{"DeterministicStateInit":{"code":"token.near","data":{"AA==":"YQ==","/w==":"Yg=="},"deposit":"0"}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        canonical_json(&action).unwrap(),
+        r#"{"DeterministicStateInit":{"code":"token.near","data":{"/w==":"Yg==","AA==":"YQ=="},"deposit":"0"}}"#
+    );
+}
@@ -0,0 +1,45 @@
+use near_openapi_client::types;
+use schemars::schema_for;
+use serde_json::json;
+
+#[test]
+fn test_primitives_are_strings() {
+    for schema in [
+        schema_for!(types::CryptoHash),
+        schema_for!(types::PublicKey),
+        schema_for!(types::Signature),
+    ] {
+        assert_eq!(schema.get("type"), Some(&json!("string")));
+    }
+}
+
+#[test]
+fn test_struct_schema_follows_serde() {
+    let schema = schema_for!(types::AccountView);
+    let properties = schema.get("properties").unwrap();
+    assert_eq!(
+        properties["code_hash"],
+        json!({ "$ref": "#/$defs/CryptoHash" })
+    );
+    assert!(properties.get("storage_usage").is_some());
+    assert!(properties.get("global_contract_hash").is_some());
+    let required = schema.get("required").unwrap().as_array().unwrap();
+    assert!(required.contains(&json!("amount")));
+    assert!(!required.contains(&json!("global_contract_hash")));
+}
+
+#[test]
+fn test_open_enums_leave_out_unknown() {
+    let schema = schema_for!(types::ActionView);
+    let variants = schema.get("anyOf").unwrap().as_array().unwrap();
+    let names: Vec<String> = variants
+        .iter()
+        .map(|variant| match variant.get("enum") {
+            Some(names) => names[0].as_str().unwrap().to_string(),
+            None => variant["required"][0].as_str().unwrap().to_string(),
+        })
+        .collect();
+    assert!(names.contains(&"CreateAccount".to_string()));
+    assert!(names.contains(&"FunctionCall".to_string()));
+    assert!(!names.contains(&"Unknown".to_string()));
+}
@@ -0,0 +1,98 @@
+//! Shard assignment of `types::ShardLayout`, checked against nearcore's
+//! `ShardLayout::account_id_to_shard_id` on layouts encoded by nearcore.
+#![allow(deprecated)]
+
+use near_openapi_client::types::{AccountId, ShardId, ShardLayout};
+use near_primitives::shard_layout as nearcore;
+
+const ACCOUNTS: &[&str] = &[
+    "aa",
+    "alice.near",
+    "aurora",
+    "aurora-0",
+    "bob.testnet",
+    "game.hot.tg",
+    "kkuuue2akv_1630967379.near",
+    "relay.aurora",
+    "sweat",
+    "sweat_welcome.near",
+    "tge-lockup.sweat",
+    "token.sweat",
+    "usdt.tether-token.near",
+    "wrap.near",
+    "zz",
+];
+
+fn boundary_accounts() -> Vec<near_primitives::types::AccountId> {
+    [
+        "aurora",
+        "aurora-0",
+        "game.hot.tg",
+        "kkuuue2akv_1630967379.near",
+        "tge-lockup.sweat",
+    ]
+    .iter()
+    .map(|account_id| account_id.parse().unwrap())
+    .collect()
+}
+
+/// Decodes the JSON of `layout` and checks that every account goes to the
+/// same shard as with nearcore.
+fn check_layout(layout: nearcore::ShardLayout) -> ShardLayout {
+    let decoded: ShardLayout =
+        serde_json::from_value(serde_json::to_value(&layout).unwrap()).unwrap();
+    assert_eq!(decoded.shard_count(), layout.num_shards() as usize);
+    let shard_ids: Vec<_> = layout
+        .shard_ids()
+        .map(|shard_id| ShardId(shard_id.into()))
+        .collect();
+    assert_eq!(decoded.shard_ids(), shard_ids);
+    for account_id in ACCOUNTS {
+        let expected: u64 = layout
+            .account_id_to_shard_id(&account_id.parse().unwrap())
+            .into();
+        let account_id: AccountId = account_id.parse().unwrap();
+        assert_eq!(
+            decoded.account_to_shard(&account_id),
+            ShardId(expected),
+            "{}",
+            account_id
+        );
+    }
+    decoded
+}
+
+#[test]
+fn test_v0_hashes_account_ids() {
+    check_layout(nearcore::ShardLayout::v0(4, 0));
+    check_layout(nearcore::ShardLayout::v0(1, 0));
+}
+
+#[test]
+fn test_v1_splits_by_boundary_accounts() {
+    check_layout(nearcore::ShardLayout::v1(boundary_accounts(), None, 1));
+}
+
+#[test]
+fn test_v2_keeps_shard_ids() {
+    let shard_ids = [3, 8, 9, 11, 12, 13].map(near_primitives::types::ShardId::new);
+    let layout = check_layout(nearcore::ShardLayout::v2(
+        boundary_accounts(),
+        shard_ids.to_vec(),
+        None,
+    ));
+    assert_eq!(layout.shard_index(&ShardId(9)), Some(2));
+    assert_eq!(layout.validate_shard_id(&ShardId(13)), Ok(5));
+    let err = layout.validate_shard_id(&ShardId(2)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "shard 2 is not in the shard layout, which has shards [3, 8, 9, 11, 12, 13]"
+    );
+}
+
+#[test]
+fn test_validate_shard_id_of_numbered_layouts() {
+    let layout = check_layout(nearcore::ShardLayout::v0(4, 0));
+    assert_eq!(layout.validate_shard_id(&ShardId(3)), Ok(3));
+    assert!(layout.validate_shard_id(&ShardId(4)).is_err());
+}
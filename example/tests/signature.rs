@@ -0,0 +1,131 @@
+use near_openapi_client::types::tx::{SecretKey, SignedTransaction, TransactionBuilder};
+use near_openapi_client::types::{
+    CryptoHash, DelegateAction, KeyType, NearToken, NonDelegateAction, Signature,
+    SignedDelegateAction, TransferAction,
+};
+
+const ED25519_SECRET_KEY: &str = "ed25519:49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+/// The scalar `0x0101...01`.
+const SECP256K1_SECRET_KEY: &str = "secp256k1:4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+const SIGNATURE: &str = "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj";
+
+#[test]
+fn test_parse_and_serde() {
+    let signature: Signature = SIGNATURE.parse().unwrap();
+    assert_eq!(signature.key_type(), KeyType::Ed25519);
+    assert_eq!(signature.to_bytes().len(), 64);
+    assert_eq!(signature.to_string(), SIGNATURE);
+    assert_eq!(
+        format!("{:?}", signature),
+        format!("Signature({:?})", SIGNATURE)
+    );
+    assert_eq!(
+        Signature::from_parts(KeyType::Ed25519, &signature.to_bytes()).unwrap(),
+        signature
+    );
+
+    let json = serde_json::to_string(&signature).unwrap();
+    assert_eq!(json, format!("\"{}\"", SIGNATURE));
+    assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+
+    let secp256k1 = SECP256K1_SECRET_KEY
+        .parse::<SecretKey>()
+        .unwrap()
+        .sign(&CryptoHash([7; 32]));
+    assert_eq!(secp256k1.key_type(), KeyType::Secp256k1);
+    assert_eq!(secp256k1.to_bytes().len(), 65);
+    assert_eq!(
+        secp256k1.to_string().parse::<Signature>().unwrap(),
+        secp256k1
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    for invalid in [
+        "",
+        "ed25519:abc",
+        "ed25519:0OIl",
+        "rsa:abc",
+        // 64 bytes are not a secp256k1 signature.
+        &SIGNATURE.replace("ed25519:", "secp256k1:"),
+    ] {
+        assert!(invalid.parse::<Signature>().is_err(), "{:?}", invalid);
+    }
+    assert_eq!(
+        "secp256k1:abc"
+            .parse::<Signature>()
+            .unwrap_err()
+            .to_string(),
+        "secp256k1 signature is not 65 bytes long"
+    );
+}
+
+#[test]
+fn test_verify() {
+    for secret_key in [ED25519_SECRET_KEY, SECP256K1_SECRET_KEY] {
+        let secret_key: SecretKey = secret_key.parse().unwrap();
+        let hash = CryptoHash([7; 32]);
+        let signature = secret_key.sign(&hash);
+        assert!(
+            signature.verify(&hash.0, &secret_key.public_key()),
+            "{:?}",
+            secret_key
+        );
+        assert!(
+            !signature.verify(&[8; 32], &secret_key.public_key()),
+            "{:?}",
+            secret_key
+        );
+    }
+}
+
+#[test]
+fn test_verify_signed_transaction() {
+    for secret_key in [ED25519_SECRET_KEY, SECP256K1_SECRET_KEY] {
+        let secret_key: SecretKey = secret_key.parse().unwrap();
+        let signed = TransactionBuilder::new(
+            "alice.near".parse().unwrap(),
+            secret_key.public_key(),
+            "bob.near".parse().unwrap(),
+        )
+        .transfer(NearToken::from_near(1))
+        .build(7, CryptoHash::default())
+        .sign(&secret_key)
+        .unwrap();
+        assert!(signed.verify().unwrap(), "{:?}", signed);
+
+        // The signature survives the borsh encoding.
+        let decoded = SignedTransaction::decode(&signed.encode().unwrap()).unwrap();
+        assert_eq!(decoded, signed);
+
+        let mut tampered = signed.clone();
+        tampered.transaction.nonce += 1;
+        assert!(!tampered.verify().unwrap(), "{:?}", tampered);
+    }
+}
+
+#[test]
+fn test_verify_signed_delegate_action() {
+    let secret_key: SecretKey = ED25519_SECRET_KEY.parse().unwrap();
+    let delegate_action = DelegateAction {
+        actions: vec![NonDelegateAction::Transfer(TransferAction {
+            deposit: NearToken::from_near(1),
+        })],
+        max_block_height: 100,
+        nonce: 7,
+        public_key: secret_key.public_key(),
+        receiver_id: "bob.near".parse().unwrap(),
+        sender_id: "alice.near".parse().unwrap(),
+    };
+    let hash = delegate_action.hash().unwrap();
+    let signed = SignedDelegateAction {
+        signature: secret_key.sign(&hash),
+        delegate_action,
+    };
+    assert!(signed.verify().unwrap(), "{:?}", signed);
+
+    let mut tampered = signed.clone();
+    tampered.delegate_action.max_block_height += 1;
+    assert!(!tampered.verify().unwrap(), "{:?}", tampered);
+}
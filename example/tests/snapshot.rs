@@ -0,0 +1,232 @@
+mod common;
+
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::snapshot::{Snapshot, SnapshotError};
+use near_openapi_client::types;
+
+const HASH: &str = "11111111111111111111111111111111";
+const OTHER_HASH: &str = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+
+fn view_account() -> types::RpcQueryRequest {
+    types::RpcQueryRequest::ViewAccountByFinality {
+        account_id: "alice.near".parse().unwrap(),
+        finality: types::Finality::Final,
+        request_type: types::ViewAccountByFinalityRequestType::ViewAccount,
+    }
+}
+
+fn view_access_key_list() -> types::RpcQueryRequest {
+    types::RpcQueryRequest::ViewAccessKeyListByFinality {
+        account_id: "alice.near".parse().unwrap(),
+        finality: types::Finality::Final,
+        request_type: types::ViewAccessKeyListByFinalityRequestType::ViewAccessKeyList,
+    }
+}
+
+fn account(block_hash: &str) -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"amount":"5","block_hash":"{block_hash}","block_height":10,"code_hash":"{HASH}","locked":"0","storage_usage":100}}}}"#
+    )
+}
+
+fn access_key_list(block_hash: &str) -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"{block_hash}","block_height":10,"keys":[]}}}}"#
+    )
+}
+
+/// Block at height 10 with hash `OTHER_HASH`, without chunks.
+fn block() -> String {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": HASH,
+        "challenges_result": [],
+        "challenges_root": HASH,
+        "chunk_headers_root": HASH,
+        "chunk_mask": [],
+        "chunk_receipts_root": HASH,
+        "chunk_tx_root": HASH,
+        "chunks_included": 0,
+        "epoch_id": HASH,
+        "gas_price": "100000000",
+        "hash": OTHER_HASH,
+        "height": 10,
+        "last_ds_final_block": HASH,
+        "last_final_block": HASH,
+        "latest_protocol_version": 80,
+        "next_bp_hash": HASH,
+        "next_epoch_id": HASH,
+        "outcome_root": HASH,
+        "prev_hash": HASH,
+        "prev_state_root": HASH,
+        "random_value": HASH,
+        "rent_paid": "0",
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {"author": "node.near", "chunks": [], "header": header},
+    })
+    .to_string()
+}
+
+fn call_result(block_hash: &str, result: &[u8]) -> String {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "block_hash": block_hash,
+            "block_height": 10,
+            "logs": ["balance read"],
+            "result": result,
+        },
+    })
+    .to_string()
+}
+
+#[test]
+fn test_with_block_id() {
+    let block_id = types::BlockId::CryptoHash(HASH.parse().unwrap());
+    assert_eq!(
+        view_account().with_block_id(block_id.clone()),
+        types::RpcQueryRequest::ViewAccountByBlockId {
+            account_id: "alice.near".parse().unwrap(),
+            block_id: block_id.clone(),
+            request_type: types::ViewAccountByBlockIdRequestType::ViewAccount,
+        }
+    );
+
+    let call = types::RpcQueryRequest::call_function(
+        "app.near".parse().unwrap(),
+        "get",
+        types::FunctionArgs::from_bytes(b"{}"),
+        types::BlockReference::SyncCheckpoint(types::SyncCheckpoint::Genesis),
+    );
+    assert_eq!(
+        call.with_block_id(types::BlockId::BlockHeight(10)),
+        types::RpcQueryRequest::call_function(
+            "app.near".parse().unwrap(),
+            "get",
+            types::FunctionArgs::from_bytes(b"{}"),
+            types::BlockReference::BlockId(types::BlockId::BlockHeight(10)),
+        )
+    );
+}
+
+#[test]
+fn test_with_block() {
+    let at_height = view_account().with_block(types::BlockReference::BlockId(
+        types::BlockId::BlockHeight(10),
+    ));
+    assert_eq!(
+        at_height,
+        types::RpcQueryRequest::ViewAccountByBlockId {
+            account_id: "alice.near".parse().unwrap(),
+            block_id: types::BlockId::BlockHeight(10),
+            request_type: types::ViewAccountByBlockIdRequestType::ViewAccount,
+        }
+    );
+    assert_eq!(
+        at_height.with_block(types::BlockReference::Finality(types::Finality::Final)),
+        view_account()
+    );
+    assert_eq!(
+        view_account().with_block(types::BlockReference::SyncCheckpoint(
+            types::SyncCheckpoint::Genesis
+        )),
+        types::RpcQueryRequest::ViewAccountBySyncCheckpoint {
+            account_id: "alice.near".parse().unwrap(),
+            request_type: types::ViewAccountBySyncCheckpointRequestType::ViewAccount,
+            sync_checkpoint: types::SyncCheckpoint::Genesis,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_query_all_pins_block() {
+    let url = common::serve(vec![(200, account(HASH)), (200, access_key_list(HASH))]);
+    let snapshot = Snapshot::new(RpcClient::new(&url));
+    assert_eq!(snapshot.block_height(), None);
+
+    let responses = snapshot
+        .query_all(&[view_account(), view_access_key_list()])
+        .await
+        .unwrap();
+    assert!(matches!(
+        responses[..],
+        [
+            types::RpcQueryResponse::Variant0 { .. },
+            types::RpcQueryResponse::Variant5 { .. }
+        ]
+    ));
+    assert_eq!(snapshot.block_hash().unwrap().to_string(), HASH);
+    assert_eq!(snapshot.block_height(), Some(10));
+}
+
+#[tokio::test]
+async fn test_block_mismatch() {
+    let url = common::serve(vec![
+        (200, account(HASH)),
+        (200, access_key_list(OTHER_HASH)),
+    ]);
+    let snapshot = Snapshot::new(RpcClient::new(&url));
+
+    snapshot.query(&view_account()).await.unwrap();
+    let result = snapshot.query(&view_access_key_list()).await;
+    assert!(
+        matches!(
+            &result,
+            Err(SnapshotError::BlockMismatch { expected, found })
+                if expected.to_string() == HASH && found.to_string() == OTHER_HASH
+        ),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_client_snapshot_views() {
+    let url = common::serve(vec![
+        (200, block()),
+        (200, account(OTHER_HASH)),
+        (200, call_result(OTHER_HASH, br#""42""#)),
+    ]);
+    let client = RpcClient::new(&url);
+    let snapshot = client.snapshot(types::Finality::Final).await.unwrap();
+    assert_eq!(snapshot.block_hash().unwrap().to_string(), OTHER_HASH);
+    assert_eq!(snapshot.block_height(), Some(10));
+
+    let alice = "alice.near".parse().unwrap();
+    let account = snapshot.view_account(&alice).await.unwrap();
+    assert_eq!(account.storage_usage, 100);
+
+    let balance = snapshot
+        .view_function::<_, String>(
+            &"token.near".parse().unwrap(),
+            "ft_balance_of",
+            &serde_json::json!({"account_id": alice}),
+        )
+        .await
+        .unwrap();
+    assert_eq!(balance.result, "42");
+    assert_eq!(balance.logs, ["balance read"]);
+    assert_eq!(balance.block_height, 10);
+}
+
+#[tokio::test]
+async fn test_view_at_other_block() {
+    let url = common::serve(vec![(200, account(HASH))]);
+    let snapshot = Snapshot::at(RpcClient::new(&url), OTHER_HASH.parse().unwrap(), 10);
+    let result = snapshot.view_account(&"alice.near".parse().unwrap()).await;
+    assert!(
+        matches!(result, Err(SnapshotError::BlockMismatch { .. })),
+        "{:?}",
+        result
+    );
+}
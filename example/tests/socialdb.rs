@@ -0,0 +1,53 @@
+mod common;
+
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::socialdb::{GetOptions, SocialData, SocialDb};
+use near_openapi_client::types;
+
+fn call_result(result: &str) -> (u16, String) {
+    (
+        200,
+        format!(
+            r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"block_hash":"11111111111111111111111111111111","block_height":10,"logs":[],"result":{:?}}}}}"#,
+            result.as_bytes()
+        ),
+    )
+}
+
+#[tokio::test]
+async fn test_get_profile() {
+    let url = common::serve(vec![call_result(
+        r#"{"alice.near":{"profile":{"name":"Alice","linktree":{"github":"alice","twitter":"alice_near"}}}}"#,
+    )]);
+    let social = SocialDb::new(RpcClient::new(&url));
+
+    let data = social
+        .get(
+            &["alice.near/profile/**"],
+            GetOptions::default(),
+            types::BlockReference::Finality(types::Finality::Final),
+        )
+        .await
+        .unwrap();
+    assert_eq!(data.value("alice.near/profile/name"), Some("Alice"));
+    let links: Vec<_> = data.children("alice.near/profile/linktree").collect();
+    assert_eq!(links, vec!["github", "twitter"]);
+    assert_eq!(data.get("bob.near/profile"), None);
+}
+
+#[test]
+fn test_values_with_block_height() {
+    let data = SocialData(serde_json::json!({
+        "alice.near": {"graph": {"follow": {
+            "bob.near": {"": "", ":block": 100},
+            ":block": 100,
+        }}}
+    }));
+    let follows: Vec<_> = data.children("alice.near/graph/follow").collect();
+    assert_eq!(follows, vec!["bob.near"]);
+    assert_eq!(data.value("alice.near/graph/follow/bob.near"), Some(""));
+    assert_eq!(
+        data.block_height("alice.near/graph/follow/bob.near"),
+        Some(100)
+    );
+}
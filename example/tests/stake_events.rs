@@ -0,0 +1,218 @@
+mod common;
+
+use futures_util::StreamExt;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::stake_events::{StakeEvent, StakeEventError, StakeEventStream};
+use near_openapi_client::types::{
+    BlockId, CryptoHash, Finality, NearToken, ValidatorKickoutReason,
+};
+
+const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const SIGNATURE: &str = "ed25519:1111111111111111111111111111111111111111111111111111111111111111";
+
+fn hash(n: u8) -> CryptoHash {
+    CryptoHash([n; 32])
+}
+
+fn envelope(result: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": result});
+    (200, response.to_string())
+}
+
+/// Block at `height` of epoch `hash(epoch)` with the given stake proposals.
+fn block(height: u64, epoch: u8, proposals: &[(&str, u128)]) -> (u16, String) {
+    let proposals: Vec<_> = proposals
+        .iter()
+        .map(|(account_id, stake)| {
+            serde_json::json!({
+                "account_id": account_id,
+                "public_key": PUBLIC_KEY,
+                "stake": stake.to_string(),
+                "validator_stake_struct_version": "V1",
+            })
+        })
+        .collect();
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": hash(0),
+        "challenges_result": [],
+        "challenges_root": hash(0),
+        "chunk_headers_root": hash(0),
+        "chunk_mask": [true],
+        "chunk_receipts_root": hash(0),
+        "chunk_tx_root": hash(0),
+        "chunks_included": 1,
+        "epoch_id": hash(epoch),
+        "gas_price": "100000000",
+        "hash": hash(height as u8),
+        "height": height,
+        "last_ds_final_block": hash(0),
+        "last_final_block": hash(0),
+        "latest_protocol_version": 80,
+        "next_bp_hash": hash(0),
+        "next_epoch_id": hash(0),
+        "outcome_root": hash(0),
+        "prev_hash": hash(height as u8 - 1),
+        "prev_state_root": hash(0),
+        "random_value": hash(0),
+        "rent_paid": "0",
+        "signature": SIGNATURE,
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": proposals,
+        "validator_reward": "0",
+    });
+    envelope(serde_json::json!({"author": "node.near", "chunks": [], "header": header}))
+}
+
+/// Validators of epoch `epoch_height` with their stakes, and the accounts
+/// kicked out at its start for not producing enough blocks.
+fn validators(epoch_height: u64, stakes: &[(&str, u128)], kicked: &[&str]) -> (u16, String) {
+    let validators: Vec<_> = stakes
+        .iter()
+        .map(|(account_id, stake)| {
+            serde_json::json!({
+                "account_id": account_id,
+                "is_slashed": false,
+                "num_expected_blocks": 10,
+                "num_produced_blocks": 10,
+                "public_key": PUBLIC_KEY,
+                "shards": [0],
+                "stake": stake.to_string(),
+            })
+        })
+        .collect();
+    let kickouts: Vec<_> = kicked
+        .iter()
+        .map(|account_id| {
+            serde_json::json!({
+                "account_id": account_id,
+                "reason": {"NotEnoughBlocks": {"expected": 10, "produced": 1}},
+            })
+        })
+        .collect();
+    envelope(serde_json::json!({
+        "current_fishermen": [],
+        "current_proposals": [],
+        "current_validators": validators,
+        "epoch_height": epoch_height,
+        "epoch_start_height": 1,
+        "next_fishermen": [],
+        "next_validators": [],
+        "prev_epoch_kickout": kickouts,
+    }))
+}
+
+fn token(yocto: u128) -> NearToken {
+    NearToken::from_yoctonear(yocto)
+}
+
+#[tokio::test]
+async fn test_epoch_transition_events() {
+    let url = common::serve(vec![
+        // Head, then the starting block.
+        block(10, 1, &[]),
+        block(10, 1, &[("x.near", 7)]),
+        validators(
+            1,
+            &[("a.near", 10), ("b.near", 20), ("c.near", 5), ("e.near", 4)],
+            &[],
+        ),
+        // Head, then the first block of the next epoch.
+        block(11, 2, &[]),
+        block(11, 2, &[("b.near", 30)]),
+        validators(
+            2,
+            &[("a.near", 10), ("b.near", 25), ("d.near", 3)],
+            &["c.near"],
+        ),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let events: Vec<_> = StakeEventStream::new(client, BlockId::BlockHeight(10), Finality::Final)
+        .into_stream()
+        .take(6)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(
+        events,
+        [
+            StakeEvent::ProposalSubmitted {
+                account_id: "x.near".parse().unwrap(),
+                stake: token(7),
+                height: 10,
+            },
+            StakeEvent::StakeChanged {
+                account_id: "b.near".parse().unwrap(),
+                old_stake: token(20),
+                new_stake: token(25),
+                epoch_height: 2,
+            },
+            StakeEvent::Kicked {
+                account_id: "c.near".parse().unwrap(),
+                reason: ValidatorKickoutReason::NotEnoughBlocks {
+                    expected: 10,
+                    produced: 1,
+                },
+                epoch_height: 2,
+            },
+            StakeEvent::JoinedSet {
+                account_id: "d.near".parse().unwrap(),
+                stake: token(3),
+                epoch_height: 2,
+            },
+            StakeEvent::StakeChanged {
+                account_id: "e.near".parse().unwrap(),
+                old_stake: token(4),
+                new_stake: token(0),
+                epoch_height: 2,
+            },
+            StakeEvent::ProposalSubmitted {
+                account_id: "b.near".parse().unwrap(),
+                stake: token(30),
+                height: 11,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_retries_validators_of_block() {
+    let error = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "UNKNOWN_EPOCH"},
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    let url = common::serve(vec![
+        block(10, 1, &[]),
+        block(10, 1, &[("x.near", 7)]),
+        (200, error.to_string()),
+        validators(1, &[("x.near", 5)], &[]),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let events: Vec<_> = StakeEventStream::new(client, BlockId::BlockHeight(10), Finality::Final)
+        .into_stream()
+        .take(2)
+        .collect()
+        .await;
+    assert!(
+        matches!(events[0], Err(StakeEventError::Validators(_))),
+        "{:?}",
+        events
+    );
+    let event = events[1].as_ref().unwrap();
+    assert_eq!(event.account_id().as_str(), "x.near");
+    assert!(
+        matches!(event, StakeEvent::ProposalSubmitted { height: 10, .. }),
+        "{:?}",
+        event
+    );
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use futures_util::StreamExt;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::state::StateEntry;
+use near_openapi_client::types::{BlockReference, Finality, StoreKey};
+
+const HASH: &str = "11111111111111111111111111111111";
+
+fn state(values: &[(&[u8], &[u8])]) -> (u16, String) {
+    let encode = |bytes: &[u8]| StoreKey::from_bytes(bytes).0;
+    let values: Vec<_> = values
+        .iter()
+        .map(|(key, value)| serde_json::json!({"key": encode(key), "value": encode(value)}))
+        .collect();
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {"block_hash": HASH, "block_height": 10, "values": values},
+    });
+    (200, response.to_string())
+}
+
+fn query_error(name: &str, info: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {"name": name, "info": info},
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    (200, response.to_string())
+}
+
+fn entry(key: &[u8], value: &[u8]) -> StateEntry {
+    StateEntry {
+        key: key.to_vec(),
+        value: value.to_vec(),
+    }
+}
+
+#[tokio::test]
+async fn test_splits_prefix_of_too_large_state() {
+    let mut responses = vec![query_error(
+        "TOO_LARGE_CONTRACT_STATE",
+        serde_json::json!({
+            "block_hash": HASH,
+            "block_height": 10,
+            "contract_account_id": "app.near",
+        }),
+    )];
+    for byte in 0..=u8::MAX {
+        responses.push(match byte {
+            b'a' => state(&[(b"ab", b"2"), (b"aa", b"1")]),
+            b'z' => state(&[(b"z", b"3")]),
+            _ => state(&[]),
+        });
+    }
+    let url = common::serve(responses);
+    let client = RpcClient::new(&url);
+
+    let mut iterator = client.view_state_iter(
+        &"app.near".parse().unwrap(),
+        b"",
+        BlockReference::Finality(Finality::Final),
+    );
+    let mut entries = vec![];
+    while let Some(entry) = iterator.next().await {
+        entries.push(entry.unwrap());
+    }
+    assert_eq!(
+        entries,
+        [entry(b"aa", b"1"), entry(b"ab", b"2"), entry(b"z", b"3")]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_decodes_borsh_values() {
+    // A borsh-encoded u128 is its 16 little-endian bytes.
+    let balance = 1_000_000u128.to_le_bytes();
+    let url = common::serve(vec![state(&[(b"b:alice.near", &balance)])]);
+    let client = RpcClient::new(&url);
+
+    let entries: Vec<_> = client
+        .view_state_iter(
+            &"token.near".parse().unwrap(),
+            b"b:",
+            BlockReference::Finality(Finality::Final),
+        )
+        .into_stream()
+        .collect()
+        .await;
+    assert_eq!(entries.len(), 1, "{:?}", entries);
+    let entry = entries[0].as_ref().unwrap();
+    assert_eq!(entry.key, b"b:alice.near");
+    assert_eq!(entry.value_borsh::<u128>().unwrap(), 1_000_000);
+}
+
+#[tokio::test]
+async fn test_stream_ends_after_error() {
+    let url = common::serve(vec![query_error(
+        "UNKNOWN_ACCOUNT",
+        serde_json::json!({
+            "block_hash": HASH,
+            "block_height": 10,
+            "requested_account_id": "app.near",
+        }),
+    )]);
+    let client = RpcClient::new(&url);
+
+    let entries: Vec<_> = client
+        .view_state_iter(
+            &"app.near".parse().unwrap(),
+            b"",
+            BlockReference::Finality(Finality::Final),
+        )
+        .into_stream()
+        .collect()
+        .await;
+    assert_eq!(entries.len(), 1, "{:?}", entries);
+    assert!(matches!(entries[0], Err(RpcError::Rpc(_))), "{:?}", entries);
+}
@@ -0,0 +1,412 @@
+mod common;
+
+use futures_util::StreamExt;
+use near_openapi_client::methods;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::state_changes::{
+    ChangesType, StateChange, StateChangeKind, StateChangesRequest, StateChangesStream,
+};
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types;
+use near_openapi_client::types::state_changes::StateChangesRequestBuilder;
+
+const HASH: &str = "11111111111111111111111111111111";
+
+#[test]
+fn test_state_changes_builder_data_changes_by_block_id() {
+    let request = StateChangesRequestBuilder::new()
+        .block(types::BlockId::BlockHeight(42))
+        .data_changes(
+            vec!["alice.near".parse().unwrap()],
+            types::StoreKey(String::from("U1RBVEU=")),
+        );
+
+    assert!(matches!(
+        request,
+        types::RpcStateChangesInBlockByTypeRequest::DataChangesByBlockId { .. }
+    ));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "account_ids": ["alice.near"],
+            "block_id": 42,
+            "changes_type": "data_changes",
+            "key_prefix_base64": "U1RBVEU=",
+        })
+    );
+}
+
+#[test]
+fn test_state_changes_builder_defaults_to_final() {
+    let request =
+        StateChangesRequestBuilder::new().account_changes(vec!["alice.near".parse().unwrap()]);
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "account_ids": ["alice.near"],
+            "changes_type": "account_changes",
+            "finality": "final",
+        })
+    );
+}
+
+#[test]
+fn test_state_changes_request_maps_to_generated_variant() {
+    let request = StateChangesRequest::new(
+        types::BlockReference::SyncCheckpoint(types::SyncCheckpoint::Genesis),
+        ChangesType::ContractCodeChanges {
+            account_ids: vec!["alice.near".parse().unwrap()],
+        },
+    );
+
+    assert!(matches!(
+        types::RpcStateChangesInBlockByTypeRequest::from(request),
+        types::RpcStateChangesInBlockByTypeRequest::ContractCodeChangesBySyncCheckpoint { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_state_changes_decodes_unified_response() {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "block_hash": HASH,
+            "changes": [
+                {
+                    "cause": {"type": "receipt_processing", "receipt_hash": HASH},
+                    "type": "account_update",
+                    "change": {
+                        "account_id": "alice.near",
+                        "amount": "5",
+                        "code_hash": HASH,
+                        "locked": "0",
+                        "storage_usage": 100,
+                    },
+                },
+                {
+                    "cause": {"type": "transaction_processing", "tx_hash": HASH},
+                    "type": "data_update",
+                    "change": {
+                        "account_id": "alice.near",
+                        "key_base64": "U1RBVEU=",
+                        "value_base64": "AQ==",
+                    },
+                },
+                {
+                    "cause": {"type": "migration"},
+                    "type": "contract_code_deletion",
+                    "change": {"account_id": "bob.near"},
+                },
+            ],
+        },
+    });
+    let url = common::serve(vec![(200, body.to_string())]);
+    let request = StateChangesRequest::new(
+        types::BlockReference::Finality(types::Finality::Final),
+        ChangesType::AccountChanges {
+            account_ids: vec!["alice.near".parse().unwrap()],
+        },
+    );
+
+    let changes = RpcClient::new(&url).state_changes(&request).await.unwrap();
+    assert_eq!(changes.changes.len(), 3);
+    assert!(matches!(
+        &changes.changes[0].change,
+        StateChange::AccountUpdate { account, .. } if account.storage_usage == 100
+    ));
+    assert!(matches!(
+        &changes.changes[1].change,
+        StateChange::DataUpdate { value, .. } if value.0 == "AQ=="
+    ));
+    assert!(matches!(
+        changes.changes[1].cause,
+        types::StateChangeCauseView::TransactionProcessing { .. }
+    ));
+    assert_eq!(changes.changes[2].change.account_id().as_str(), "bob.near");
+}
+
+#[test]
+fn test_changes_type_markers_share_one_enum() {
+    let marker: types::DataChangesType = types::DataChangesByFinalityChangesType::DataChanges;
+    assert_eq!(
+        serde_json::to_value(marker).unwrap(),
+        serde_json::json!("data_changes")
+    );
+    assert_eq!(
+        serde_json::from_value::<types::DataChangesBySyncCheckpointChangesType>(serde_json::json!(
+            "data_changes"
+        ))
+        .unwrap(),
+        marker
+    );
+}
+
+fn hash(height: u64) -> types::CryptoHash {
+    types::CryptoHash([height as u8; 32])
+}
+
+fn block(height: u64) -> types::RpcBlockResponse {
+    serde_json::from_value(serde_json::json!({
+        "author": "node.near",
+        "chunks": [],
+        "header": {
+            "approvals": [],
+            "block_merkle_root": HASH,
+            "challenges_result": [],
+            "challenges_root": HASH,
+            "chunk_headers_root": HASH,
+            "chunk_mask": [],
+            "chunk_receipts_root": HASH,
+            "chunk_tx_root": HASH,
+            "chunks_included": 0,
+            "epoch_id": HASH,
+            "gas_price": "100000000",
+            "hash": hash(height),
+            "height": height,
+            "last_ds_final_block": HASH,
+            "last_final_block": HASH,
+            "latest_protocol_version": 80,
+            "next_bp_hash": HASH,
+            "next_epoch_id": HASH,
+            "outcome_root": HASH,
+            "prev_hash": hash(height - 1),
+            "prev_state_root": HASH,
+            "random_value": HASH,
+            "rent_paid": "0",
+            "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+            "timestamp": 1,
+            "timestamp_nanosec": "1",
+            "total_supply": "0",
+            "validator_proposals": [],
+            "validator_reward": "0",
+        },
+    }))
+    .unwrap()
+}
+
+/// Update of the balance of alice.near to `amount` by the receipt `hash(receipt)`.
+fn balance_change(receipt: u64, amount: u128) -> serde_json::Value {
+    serde_json::json!({
+        "cause": {"type": "receipt_processing", "receipt_hash": hash(receipt)},
+        "type": "account_update",
+        "change": {
+            "account_id": "alice.near",
+            "amount": amount.to_string(),
+            "code_hash": HASH,
+            "locked": "0",
+            "storage_usage": 100,
+        },
+    })
+}
+
+fn alice_changes() -> ChangesType {
+    ChangesType::AccountChanges {
+        account_ids: vec!["alice.near".parse().unwrap()],
+    }
+}
+
+/// Expects blocks 10 to 12 with the final head at 12, the kinds of changes
+/// `touched` of each and the changes of alice.near in those touching it.
+fn mock_changes(blocks: &[(u64, &[&str], Vec<serde_json::Value>)]) -> MockRpc {
+    let mock = MockRpc::start();
+    mock.expect::<methods::Block>()
+        .with_params(&types::RpcBlockRequest::Finality(types::Finality::Final))
+        .return_ok(block(12));
+    for (height, touched, changes) in blocks {
+        let block_id = types::BlockId::CryptoHash(hash(*height));
+        mock.expect::<methods::Block>()
+            .with_params(&types::RpcBlockRequest::BlockId(
+                types::BlockId::BlockHeight(*height),
+            ))
+            .return_ok(block(*height));
+        let kinds = touched
+            .iter()
+            .map(|account_id| serde_json::json!({"type": "account_touched", "account_id": account_id}))
+            .collect::<Vec<_>>();
+        mock.expect::<methods::ExperimentalChangesInBlock>()
+            .with_params(&types::RpcStateChangesInBlockRequest::BlockId(
+                block_id.clone(),
+            ))
+            .return_ok(
+                serde_json::from_value(serde_json::json!({
+                    "block_hash": hash(*height),
+                    "changes": kinds,
+                }))
+                .unwrap(),
+            );
+        if touched.contains(&"alice.near") {
+            let request =
+                StateChangesRequest::new(types::BlockReference::BlockId(block_id), alice_changes());
+            mock.expect::<methods::Changes>()
+                .with_params(&request.into())
+                .return_ok(
+                    serde_json::from_value(serde_json::json!({
+                        "block_hash": hash(*height),
+                        "changes": changes,
+                    }))
+                    .unwrap(),
+                );
+        }
+    }
+    mock
+}
+
+#[tokio::test]
+async fn test_stream_changes_skips_untouched_blocks() {
+    let mock = mock_changes(&[
+        (10, &["bob.near"], vec![]),
+        (11, &["alice.near"], vec![balance_change(1, 5)]),
+        (12, &["alice.near", "bob.near"], vec![balance_change(2, 7)]),
+    ]);
+
+    let events: Vec<_> = mock
+        .client()
+        .stream_changes(alice_changes(), types::BlockId::BlockHeight(10))
+        .take(2)
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+    let blocks: Vec<_> = events
+        .iter()
+        .map(|event| (event.block_height, event.block_hash))
+        .collect();
+    assert_eq!(blocks, [(11, hash(11)), (12, hash(12))], "{:?}", blocks);
+    assert!(matches!(
+        &events[1].change.change,
+        StateChange::AccountUpdate { account, .. } if account.amount.as_yoctonear() == 7
+    ));
+}
+
+#[tokio::test]
+async fn test_stream_changes_yields_change_of_reorged_block_once() {
+    // The change of receipt 1 is included again at height 12 after the
+    // block at 11 was replaced by another fork.
+    let mock = mock_changes(&[
+        (10, &[], vec![]),
+        (11, &["alice.near"], vec![balance_change(1, 5)]),
+        (
+            12,
+            &["alice.near"],
+            vec![balance_change(1, 5), balance_change(2, 7)],
+        ),
+    ]);
+
+    let events: Vec<_> = StateChangesStream::new(
+        mock.client(),
+        alice_changes(),
+        types::BlockId::BlockHeight(10),
+        types::Finality::Final,
+    )
+    .into_stream()
+    .take(2)
+    .map(|event| event.unwrap())
+    .collect()
+    .await;
+    let changes: Vec<_> = events
+        .iter()
+        .map(|event| match &event.change.change {
+            StateChange::AccountUpdate { account, .. } => {
+                (event.block_height, account.amount.as_yoctonear())
+            }
+            change => panic!("unexpected change {:?}", change),
+        })
+        .collect();
+    assert_eq!(changes, [(11, 5), (12, 7)], "{:?}", changes);
+}
+
+/// Expects the changes of `changes_type` at `hash(12)`.
+fn expect_changes(mock: &MockRpc, changes_type: ChangesType, changes: Vec<serde_json::Value>) {
+    let request = StateChangesRequest::new(
+        types::BlockReference::BlockId(types::BlockId::CryptoHash(hash(12))),
+        changes_type,
+    );
+    mock.expect::<methods::Changes>()
+        .with_params(&request.into())
+        .return_ok(
+            serde_json::from_value(serde_json::json!({
+                "block_hash": hash(12),
+                "changes": changes,
+            }))
+            .unwrap(),
+        );
+}
+
+#[tokio::test]
+async fn test_changes_in_block_groups_by_account_and_kind() {
+    let alice: types::AccountId = "alice.near".parse().unwrap();
+    let bob: types::AccountId = "bob.near".parse().unwrap();
+    let data_update = serde_json::json!({
+        "cause": {"type": "receipt_processing", "receipt_hash": hash(3)},
+        "type": "data_update",
+        "change": {"account_id": "bob.near", "key_base64": "U1RBVEU=", "value_base64": "AQ=="},
+    });
+    let mock = MockRpc::start();
+    mock.expect::<methods::ExperimentalChangesInBlock>()
+        .with_params(&types::RpcStateChangesInBlockRequest::Finality(
+            types::Finality::Final,
+        ))
+        .return_ok(
+            serde_json::from_value(serde_json::json!({
+                "block_hash": hash(12),
+                "changes": [
+                    {"type": "account_touched", "account_id": "alice.near"},
+                    {"type": "account_touched", "account_id": "bob.near"},
+                    {"type": "data_touched", "account_id": "bob.near"},
+                ],
+            }))
+            .unwrap(),
+        );
+    expect_changes(
+        &mock,
+        ChangesType::AccountChanges {
+            account_ids: vec![alice.clone(), bob.clone()],
+        },
+        vec![balance_change(1, 5), balance_change(2, 7)],
+    );
+    expect_changes(
+        &mock,
+        ChangesType::AllGasKeyChanges {
+            account_ids: vec![alice.clone(), bob.clone()],
+        },
+        vec![],
+    );
+    expect_changes(
+        &mock,
+        ChangesType::DataChanges {
+            account_ids: vec![bob.clone()],
+            key_prefix: types::StoreKey::from_bytes(b""),
+        },
+        vec![data_update],
+    );
+
+    let block = mock
+        .client()
+        .changes_in_block(types::BlockReference::Finality(types::Finality::Final))
+        .await
+        .unwrap();
+    assert_eq!(block.block_hash, hash(12));
+    assert_eq!(block.accounts().collect::<Vec<_>>(), [&alice, &bob]);
+    let balances: Vec<_> = block
+        .of(&alice, StateChangeKind::Account)
+        .iter()
+        .map(|change| match &change.change {
+            StateChange::AccountUpdate { account, .. } => account.amount.as_yoctonear(),
+            change => panic!("unexpected change {:?}", change),
+        })
+        .collect();
+    assert_eq!(balances, [5, 7]);
+    assert!(block.of(&bob, StateChangeKind::Account).is_empty());
+    let kinds: Vec<_> = block
+        .iter()
+        .map(|(account_id, kind, changes)| (account_id.as_str(), kind, changes.len()))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("alice.near", StateChangeKind::Account, 2),
+            ("bob.near", StateChangeKind::Data, 1),
+        ]
+    );
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::storage::{
+    DEFAULT_STORAGE_AMOUNT_PER_BYTE, StorageUsageError, storage_usage_series,
+};
+use near_openapi_client::types;
+
+const HASH: &str = "11111111111111111111111111111111";
+
+fn account(storage_usage: u64) -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":"dontcare","result":{{"amount":"5","block_hash":"{HASH}","block_height":10,"code_hash":"{HASH}","locked":"0","storage_usage":{storage_usage}}}}}"#
+    )
+}
+
+fn garbage_collected() -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":"dontcare","error":{{"name":"HANDLER_ERROR","cause":{{"name":"GARBAGE_COLLECTED_BLOCK","info":{{"block_hash":"{HASH}","block_height":10}}}},"code":-32000,"message":"Server error"}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn test_falls_back_to_archival_node() {
+    let client = RpcClient::new(&common::serve(vec![
+        (200, account(100)),
+        (200, garbage_collected()),
+    ]));
+    let archival = RpcClient::new(&common::serve(vec![(200, account(40))]));
+    let account_id: types::AccountId = "alice.near".parse().unwrap();
+
+    let series = storage_usage_series(
+        &client,
+        Some(&archival),
+        &account_id,
+        &[
+            types::BlockId::BlockHeight(20),
+            types::BlockId::BlockHeight(10),
+        ],
+        DEFAULT_STORAGE_AMOUNT_PER_BYTE,
+    )
+    .await
+    .unwrap();
+    assert_eq!(series.points.len(), 2);
+    assert!(!series.points[0].from_archival);
+    assert!(series.points[1].from_archival);
+    assert_eq!(series.points[1].storage_usage, 40);
+    assert_eq!(
+        series.points[1].locked_for_storage,
+        types::NearToken::from_yoctonear(400_000_000_000_000_000_000)
+    );
+    assert_eq!(series.growth(), -60);
+}
+
+#[tokio::test]
+async fn test_reports_missing_block_without_archival_node() {
+    let client = RpcClient::new(&common::serve(vec![(200, garbage_collected())]));
+
+    let err = storage_usage_series(
+        &client,
+        None,
+        &"alice.near".parse().unwrap(),
+        &[types::BlockId::BlockHeight(10)],
+        DEFAULT_STORAGE_AMOUNT_PER_BYTE,
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        matches!(err, StorageUsageError::Query(RpcError::Rpc(_))),
+        "{:?}",
+        err
+    );
+}
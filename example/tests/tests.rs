@@ -21,58 +21,54 @@ async fn test_openapi_client() -> Result<(), Box<dyn Error>> {
         later_block_hash,
     ) = prepare_blockchain(&signer, client_local.clone()).await?;
 
-    test_block(&client_local, block_final_hash.clone()).await?;
+    test_block(&client_local, block_final_hash).await?;
+    test_block_header(&client_local, block_final_hash).await?;
     test_status(&client_local).await?;
 
     test_broadcast_async(&client_local, base64_signed_tx.clone()).await?;
     test_broadcast_commit(&client_local, base64_signed_tx.clone()).await?;
-    test_chunk(&client_local, block_final_hash.clone()).await?;
-    test_gas_price_with_block(&client_local, block_final_hash.clone()).await?;
+    test_chunk(&client_local, block_final_hash).await?;
+    test_gas_price_with_block(&client_local, block_final_hash).await?;
     test_gas_price_without_block(&client_local).await?;
     test_health(&client_local).await?;
     test_light_client_proof(
         &client_local,
-        later_block_hash.clone(),
+        later_block_hash,
         sender_account_id.clone(),
-        sent_tx_hash.clone(),
+        sent_tx_hash,
     )
     .await?;
-    test_next_light_client_block(&client_local, block_final_hash.clone()).await?;
+    test_next_light_client_block(&client_local, block_final_hash).await?;
     test_network_info(&client_local).await?;
     test_send_tx(&client_local, base64_signed_tx.clone()).await?;
     test_status(&client_local).await?;
     test_validators(&client_local).await?;
     test_client_config(&client_local).await?;
-    test_experimental_changes(
-        &client_local,
-        block_final_hash.clone(),
-        sender_account_id.clone(),
-    )
-    .await?;
-    test_experimental_changes_in_block(&client_local, block_final_hash.clone()).await?;
-    test_experimental_congestion_level(&client_local, block_final_hash.clone()).await?;
+    test_experimental_changes(&client_local, block_final_hash, sender_account_id.clone()).await?;
+    test_experimental_changes_in_block(&client_local, block_final_hash).await?;
+    test_experimental_congestion_level(&client_local, block_final_hash).await?;
     test_experimental_genesis_config(&client_local).await?;
     test_experimental_light_client_proof(
         &client_local,
-        later_block_hash.clone(),
-        sender_account_id.clone(),
-        sent_tx_hash.clone(),
-    )
-    .await?;
-    test_experimental_light_client_block(&client_local, block_final_hash.clone()).await?;
-    test_experimental_protocol_config(&client_local, block_final_hash.clone()).await?;
-    test_experimental_receipt(&client_local, executed_receipt_id.clone()).await?;
-    test_experimental_tx_status(
-        &client_local,
-        sent_tx_hash.clone(),
+        later_block_hash,
         sender_account_id.clone(),
+        sent_tx_hash,
     )
     .await?;
+    test_experimental_light_client_block(&client_local, block_final_hash).await?;
+    test_experimental_protocol_config(&client_local, block_final_hash).await?;
+    test_experimental_receipt(&client_local, executed_receipt_id).await?;
+    test_experimental_tx_status(&client_local, sent_tx_hash, sender_account_id.clone()).await?;
     test_experimental_validators_ordered(&client_local).await?;
     test_experimental_maintenance_windows(&client_remote, sender_account_id.clone()).await?;
     test_experimental_split_storage_info(&client_local).await?;
     test_query_account(&client_local, sender_account_id.clone()).await?;
     test_function_call(&client_local, sender_account_id.clone()).await?;
+    test_wrong_network(&client_local).await?;
+    test_rpc_client_methods(&client_local, block_final_hash).await?;
+    test_tx_by_signed_transaction(&client_local, base64_signed_tx.clone(), sent_tx_hash).await?;
+    test_storage_usage_series(&client_local, sender_account_id.clone(), block_final_hash).await?;
+    test_transaction_builder(&signer)?;
 
     sandbox_node.kill().await?;
 
@@ -85,7 +81,7 @@ async fn test_block(client: &Client, block_hash: CryptoHash) -> Result<(), Box<d
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForBlockMethod::Block,
         params: client::types::RpcBlockRequest::BlockId({
-            client::types::BlockId::CryptoHash(block_hash.clone())
+            client::types::BlockId::CryptoHash(block_hash)
         }),
     };
 
@@ -104,6 +100,19 @@ async fn test_block(client: &Client, block_hash: CryptoHash) -> Result<(), Box<d
     Ok(())
 }
 
+async fn test_block_header(client: &Client, block_hash: CryptoHash) -> Result<(), Box<dyn Error>> {
+    let header = client::block::block_header(
+        client,
+        &client::types::RpcBlockRequest::BlockId(client::types::BlockId::CryptoHash(block_hash)),
+    )
+    .await?;
+    assert_eq!(header.hash, block_hash);
+
+    println!("response for block_header: {:#?}", header);
+
+    Ok(())
+}
+
 async fn test_broadcast_async(
     client: &Client,
     base64_signed_tx: String,
@@ -177,7 +186,7 @@ async fn test_chunk(client: &Client, block_hash: CryptoHash) -> Result<(), Box<d
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForChunkMethod::Chunk,
         params: client::types::RpcChunkRequest::BlockShardId {
-            block_id: client::types::BlockId::CryptoHash(block_hash.clone()),
+            block_id: client::types::BlockId::CryptoHash(block_hash),
             shard_id: client::types::ShardId(0),
         },
     };
@@ -206,7 +215,7 @@ async fn test_gas_price_with_block(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForGasPriceMethod::GasPrice,
         params: client::types::RpcGasPriceRequest {
-            block_id: Some(client::types::BlockId::CryptoHash(block_hash.clone())),
+            block_id: Some(client::types::BlockId::CryptoHash(block_hash)),
         },
     };
 
@@ -282,9 +291,9 @@ async fn test_light_client_proof(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForLightClientProofMethod::LightClientProof,
         params: client::types::RpcLightClientExecutionProofRequest::Variant0 {
-            light_client_head: block_hash.clone(),
+            light_client_head: block_hash,
             sender_id: sender_account_id.clone(),
-            transaction_hash: sent_tx_hash.clone(),
+            transaction_hash: sent_tx_hash,
             type_: client::types::RpcLightClientExecutionProofRequestVariant0Type::Transaction,
         },
     };
@@ -305,7 +314,7 @@ async fn test_next_light_client_block(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForNextLightClientBlockMethod::NextLightClientBlock,
         params: client::types::RpcLightClientNextBlockRequest {
-            last_block_hash: block_hash.clone(),
+            last_block_hash: block_hash,
         },
     };
 
@@ -460,9 +469,9 @@ async fn test_experimental_changes(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalChangesMethod::ExperimentalChanges,
         params: client::types::RpcStateChangesInBlockByTypeRequest::AccountChangesByBlockId {
-            changes_type: client::types::AccountChangesByBlockIdChangesType::AccountChanges,
+            changes_type: client::types::AccountChangesType::AccountChanges,
             account_ids: vec![sender_account_id],
-            block_id: client::types::BlockId::CryptoHash(block_hash.clone()),
+            block_id: client::types::BlockId::CryptoHash(block_hash),
         },
     };
 
@@ -499,7 +508,7 @@ async fn test_experimental_changes_in_block(
         id: String::from("dontcare"),
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalChangesInBlockMethod::ExperimentalChangesInBlock,
-        params: client::types::RpcStateChangesInBlockRequest::BlockId(client::types::BlockId::CryptoHash(block_hash.clone()))
+        params: client::types::RpcStateChangesInBlockRequest::BlockId(client::types::BlockId::CryptoHash(block_hash))
     };
 
     let experimental_changes_in_block: client::types::JsonRpcResponseForRpcStateChangesInBlockByTypeResponseAndRpcStateChangesError = client.experimental_changes_in_block(&payload_experimental_changes_in_block).await?.into_inner();
@@ -525,7 +534,7 @@ async fn test_experimental_congestion_level(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalCongestionLevelMethod::ExperimentalCongestionLevel,
         params: client::types::RpcCongestionLevelRequest::BlockShardId {
-            block_id: client::types::BlockId::CryptoHash(block_hash.clone()),
+            block_id: client::types::BlockId::CryptoHash(block_hash),
             shard_id: client::types::ShardId(0)
         }
     };
@@ -585,9 +594,9 @@ async fn test_experimental_light_client_proof(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalLightClientProofMethod::ExperimentalLightClientProof,
         params: client::types::RpcLightClientExecutionProofRequest::Variant0 {
-            light_client_head: block_hash.clone(),
+            light_client_head: block_hash,
             sender_id: sender_account_id.clone(),
-            transaction_hash: sent_tx_hash.clone(),
+            transaction_hash: sent_tx_hash,
             type_: client::types::RpcLightClientExecutionProofRequestVariant0Type::Transaction,
         }
     };
@@ -612,8 +621,8 @@ async fn test_experimental_light_client_block(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalLightClientBlockProofMethod::ExperimentalLightClientBlockProof,
         params: client::types::RpcLightClientBlockProofRequest {
-            block_hash: block_hash.clone(),
-            light_client_head: block_hash.clone(),
+            block_hash: block_hash,
+            light_client_head: block_hash,
         }
     };
 
@@ -642,7 +651,7 @@ async fn test_experimental_protocol_config(
         id: String::from("dontcare"),
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalProtocolConfigMethod::ExperimentalProtocolConfig,
-        params: client::types::RpcProtocolConfigRequest::BlockId(client::types::BlockId::CryptoHash(block_hash.clone()))
+        params: client::types::RpcProtocolConfigRequest::BlockId(client::types::BlockId::CryptoHash(block_hash))
     };
 
     let protocol_config: client::types::JsonRpcResponseForRpcProtocolConfigResponseAndRpcProtocolConfigError =
@@ -703,7 +712,7 @@ async fn test_experimental_tx_status(
         jsonrpc: String::from("2.0"),
         method: client::types::JsonRpcRequestForExperimentalTxStatusMethod::ExperimentalTxStatus,
         params: client::types::RpcTransactionStatusRequest::Variant1 {
-            tx_hash: sent_tx_hash.clone(),
+            tx_hash: sent_tx_hash,
             sender_account_id: sender_account_id.clone(),
             wait_until: client::types::TxExecutionStatus::None,
         },
@@ -887,6 +896,166 @@ async fn test_function_call(
     Ok(())
 }
 
+async fn test_storage_usage_series(
+    client: &Client,
+    sender_account_id: client::types::AccountId,
+    block_hash: CryptoHash,
+) -> Result<(), Box<dyn Error>> {
+    let rpc_client = client::rpc::RpcClient::from(client.clone());
+    let series = client::storage::storage_usage_series(
+        &rpc_client,
+        None,
+        &sender_account_id,
+        &[client::types::BlockId::CryptoHash(block_hash)],
+        client::storage::DEFAULT_STORAGE_AMOUNT_PER_BYTE,
+    )
+    .await?;
+    assert_eq!(series.points.len(), 1);
+    assert!(series.points[0].storage_usage > 0);
+    assert!(!series.points[0].from_archival);
+
+    println!("response for storage_usage_series: {:#?}", series);
+
+    Ok(())
+}
+
+async fn test_wrong_network(client: &Client) -> Result<(), Box<dyn Error>> {
+    let rpc_client = client::rpc::RpcClient::from(client.clone())
+        .with_expected_network(client::network::ExpectedNetwork::mainnet());
+
+    let status: Result<
+        client::types::RpcStatusResponse,
+        client::jsonrpc::RpcError<client::types::ErrorWrapperForRpcStatusError>,
+    > = rpc_client
+        .call("status", &client::types::RpcStatusRequest(()))
+        .await;
+    assert!(matches!(
+        status,
+        Err(client::jsonrpc::RpcError::WrongNetwork(_))
+    ));
+
+    println!("response for wrong_network: {:#?}", status);
+
+    Ok(())
+}
+
+async fn test_rpc_client_methods(
+    client: &Client,
+    block_hash: CryptoHash,
+) -> Result<(), Box<dyn Error>> {
+    let unknown_variants = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let counter = unknown_variants.clone();
+    let rpc_client =
+        client::rpc::RpcClient::from(client.clone()).with_unknown_variants(move |_| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        });
+
+    let block = rpc_client
+        .block(&client::types::RpcBlockRequest::BlockId(
+            client::types::BlockId::CryptoHash(block_hash),
+        ))
+        .await?;
+    assert_eq!(block.header.hash, block_hash);
+
+    let gas_price = rpc_client
+        .gas_price(&client::types::RpcGasPriceRequest { block_id: None })
+        .await?;
+    let health = rpc_client
+        .health(&client::types::RpcHealthRequest(()))
+        .await?;
+
+    assert_eq!(
+        unknown_variants.load(std::sync::atomic::Ordering::Relaxed),
+        0
+    );
+
+    println!(
+        "response for rpc_client_methods: {:#?} {:#?}",
+        gas_price, health
+    );
+
+    Ok(())
+}
+
+async fn test_tx_by_signed_transaction(
+    client: &Client,
+    base64_signed_tx: String,
+    sent_tx_hash: CryptoHash,
+) -> Result<(), Box<dyn Error>> {
+    let rpc_client = client::rpc::RpcClient::from(client.clone());
+
+    let tx_status = rpc_client
+        .tx_by_signed_transaction(
+            client::types::SignedTransaction(base64_signed_tx),
+            client::types::TxExecutionStatus::Executed,
+        )
+        .await?;
+    let transaction = match &tx_status {
+        client::types::RpcTransactionResponse::Variant0 { transaction, .. }
+        | client::types::RpcTransactionResponse::Variant1 { transaction, .. } => transaction,
+    };
+    assert_eq!(transaction.hash, sent_tx_hash);
+
+    println!("response for tx_by_signed_transaction: {:#?}", tx_status);
+
+    Ok(())
+}
+
+fn test_transaction_builder(signer: &Signer) -> Result<(), Box<dyn Error>> {
+    let Signer::InMemory(in_memory_signer) = signer else {
+        return Err("expected an in-memory signer".into());
+    };
+    let secret_key: client::types::tx::SecretKey =
+        in_memory_signer.secret_key.to_string().parse()?;
+    let account_id: client::types::AccountId = "test.near".parse().unwrap();
+    let code = b"\0asm\x01\0\0\0".to_vec();
+    let args = serde_json::to_vec(&serde_json::json!({"greeting": "hola"}))?;
+
+    let tx = client::types::tx::TransactionBuilder::new(
+        account_id.clone(),
+        secret_key.public_key(),
+        account_id.clone(),
+    )
+    .transfer(client::types::NearToken::from_near(1))
+    .deploy_contract(&code)
+    .function_call(
+        "set_greeting",
+        client::types::FunctionArgs::from_bytes(&args),
+        client::types::NearGas::from_tgas(300),
+        client::types::NearToken::from_yoctonear(0),
+    )
+    .build(1, CryptoHash([7; 32]));
+    let signed_tx = tx.sign(&secret_key)?;
+
+    let expected = Transaction::V0(TransactionV0 {
+        signer_id: account_id.clone(),
+        public_key: signer.public_key(),
+        nonce: 1,
+        receiver_id: account_id,
+        block_hash: near_primitives::hash::CryptoHash([7; 32]),
+        actions: vec![
+            Action::Transfer(TransferAction {
+                deposit: near_primitives::types::Balance::from_near(1),
+            }),
+            Action::DeployContract(near_primitives::transaction::DeployContractAction { code }),
+            Action::FunctionCall(Box::new(near_primitives::transaction::FunctionCallAction {
+                method_name: "set_greeting".to_string(),
+                args,
+                gas: near_primitives::types::Gas::from_teragas(300),
+                deposit: near_primitives::types::Balance::ZERO,
+            })),
+        ],
+    })
+    .sign(signer);
+    assert_eq!(signed_tx.encode()?, borsh::to_vec(&expected)?);
+    assert_eq!(
+        signed_tx.hash()?.to_string(),
+        expected.get_hash().to_string()
+    );
+
+    Ok(())
+}
+
 async fn prepare_blockchain(
     signer: &Signer,
     client_local: Client,
@@ -909,7 +1078,7 @@ async fn prepare_blockchain(
         method: client::types::JsonRpcRequestForQueryMethod::Query,
         params: client::types::RpcQueryRequest::ViewAccessKeyByFinality {
             account_id: sender_account_id.clone(),
-            public_key: client::types::PublicKey(signer.public_key().to_string()),
+            public_key: signer.public_key().to_string().parse().unwrap(),
             request_type: client::types::ViewAccessKeyByFinalityRequestType::ViewAccessKey,
             finality: client::types::Finality::Final,
         },
@@ -1004,7 +1173,7 @@ async fn prepare_blockchain(
         } = result
         {
             sent_tx_hash = transaction.hash;
-            executed_receipt_id = receipts_outcome[1].id.clone();
+            executed_receipt_id = receipts_outcome[1].id;
         } else {
             return Err("couldn't send transaction".into());
         }
@@ -0,0 +1,87 @@
+use near_openapi_client::types::token_flow::{TokenFlow, TokenFlowKind};
+use near_openapi_client::types::{NearToken, ReceiptView};
+
+fn receipt(predecessor_id: &str, signer_id: &str, actions: serde_json::Value) -> ReceiptView {
+    serde_json::from_value(serde_json::json!({
+        "predecessor_id": predecessor_id,
+        "receiver_id": "alice.near",
+        "receipt_id": "11111111111111111111111111111111",
+        "receipt": {
+            "Action": {
+                "actions": actions,
+                "gas_price": "0",
+                "input_data_ids": [],
+                "output_data_receivers": [],
+                "signer_id": signer_id,
+                "signer_public_key": "ed25519:11111111111111111111111111111111",
+            }
+        },
+    }))
+    .unwrap()
+}
+
+fn kinds(receipt: &ReceiptView) -> Vec<TokenFlowKind> {
+    receipt
+        .token_flows()
+        .into_iter()
+        .map(|flow| flow.kind)
+        .collect()
+}
+
+#[test]
+fn test_refunds() {
+    let gas_refund = receipt(
+        "system",
+        "alice.near",
+        serde_json::json!([{"Transfer": {"deposit": "5"}}]),
+    );
+    assert_eq!(
+        gas_refund.token_flows(),
+        vec![TokenFlow {
+            from: "system".parse().unwrap(),
+            to: "alice.near".parse().unwrap(),
+            amount: NearToken::from_yoctonear(5),
+            kind: TokenFlowKind::GasRefund,
+        }]
+    );
+
+    let deposit_refund = receipt(
+        "system",
+        "system",
+        serde_json::json!([{"Transfer": {"deposit": "5"}}]),
+    );
+    assert_eq!(kinds(&deposit_refund), vec![TokenFlowKind::DepositRefund]);
+}
+
+#[test]
+fn test_transfers_and_deposits() {
+    let receipt = receipt(
+        "bob.near",
+        "bob.near",
+        serde_json::json!([
+            "CreateAccount",
+            {"Transfer": {"deposit": "1000"}},
+            {"FunctionCall": {"method_name": "deposit_and_stake", "args": "e30=", "gas": 1, "deposit": "7"}},
+            {"FunctionCall": {"method_name": "ft_transfer", "args": "e30=", "gas": 1, "deposit": "1"}},
+            {"FunctionCall": {"method_name": "get", "args": "e30=", "gas": 1, "deposit": "0"}},
+            {"Stake": {"stake": "0", "public_key": "ed25519:11111111111111111111111111111111"}},
+        ]),
+    );
+    assert_eq!(
+        kinds(&receipt),
+        vec![
+            TokenFlowKind::Transfer,
+            TokenFlowKind::StakingPoolDeposit {
+                method_name: "deposit_and_stake".to_string()
+            },
+            TokenFlowKind::FunctionCallDeposit {
+                method_name: "ft_transfer".to_string()
+            },
+            TokenFlowKind::Stake,
+        ]
+    );
+    let flows = receipt.token_flows();
+    assert_eq!(flows[0].from.as_str(), "bob.near");
+    assert_eq!(flows[0].amount, NearToken::from_yoctonear(1000));
+    assert_eq!(flows[3].from.as_str(), "alice.near");
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use near_openapi_client::request_id::RequestIds;
+use near_openapi_client::retry::{Backoff, RetryPolicy};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":{"gas_price":"100000000"}}"#;
+const UNKNOWN_BLOCK: &str = r#"{"jsonrpc":"2.0","id":"dontcare","error":{"name":"HANDLER_ERROR","cause":{"name":"UNKNOWN_BLOCK","info":{}},"code":-32000,"message":"Server error"}}"#;
+
+/// Log lines written by a subscriber.
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Logs {
+    type Writer = Logs;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Runs `f` with a subscriber logging span closes, returning the logs.
+async fn logs_of<F: Future>(f: F) -> String {
+    let logs = Logs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(logs.clone())
+        .with_max_level(LevelFilter::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .finish();
+    let _guard = subscriber.set_default();
+    f.await;
+    String::from_utf8(logs.0.lock().unwrap().clone()).unwrap()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_call_span_records_retries_and_latency() {
+    let url = common::serve(vec![
+        (200, UNKNOWN_BLOCK.to_string()),
+        (200, GAS_PRICE.to_string()),
+    ]);
+    let client = RpcClient::new(&url)
+        .with_retry(RetryPolicy::new().backoff(Backoff::Fixed(Duration::from_millis(1))));
+
+    let logs = logs_of(async {
+        client
+            .gas_price(&types::RpcGasPriceRequest {
+                block_id: Some(types::BlockId::BlockHeight(10)),
+            })
+            .await
+            .unwrap();
+    })
+    .await;
+    assert!(logs.contains("retrying rpc call"), "{}", logs);
+    let close = logs.lines().find(|line| line.contains("close")).unwrap();
+    assert!(
+        close.contains("rpc_call{method=\"gas_price\" block_reference=\"10\""),
+        "{}",
+        close
+    );
+    assert!(close.contains("latency_ms="), "{}", close);
+    assert!(close.contains("attempts=2"), "{}", close);
+    assert!(!close.contains("error.cause"), "{}", close);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_call_span_records_rpc_error() {
+    let url = common::serve(vec![(200, UNKNOWN_BLOCK.to_string())]);
+    let client = RpcClient::new(&url);
+
+    let logs = logs_of(async {
+        client
+            .gas_price(&types::RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap_err();
+    })
+    .await;
+    assert!(logs.contains("WARN"), "{}", logs);
+    assert!(logs.contains("rpc call failed"), "{}", logs);
+    let close = logs.lines().find(|line| line.contains("close")).unwrap();
+    assert!(close.contains("attempts=1"), "{}", close);
+    assert!(close.contains("error.name=\"HANDLER_ERROR\""), "{}", close);
+    assert!(close.contains("error.cause=\"UNKNOWN_BLOCK\""), "{}", close);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_call_span_records_request_id_and_error_chain() {
+    let url = common::serve(vec![(200, UNKNOWN_BLOCK.to_string())]);
+    let client = RpcClient::new(&url)
+        .with_request_ids(RequestIds::with_prefix("indexer").log_error_causes());
+
+    let logs = logs_of(async {
+        client
+            .gas_price(&types::RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap_err();
+    })
+    .await;
+    let close = logs.lines().find(|line| line.contains("close")).unwrap();
+    assert!(close.contains("request_id=\"indexer-0\""), "{}", close);
+    assert!(
+        close.contains("error.chain=\"HANDLER_ERROR: UNKNOWN_BLOCK\""),
+        "{}",
+        close
+    );
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use near_openapi_client::Error;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::retry::{Backoff, RetryPolicy};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::transport::{
+    Bytes, ReqwestTransport, Transport, TransportError, TransportFuture, TransportResponse,
+};
+use near_openapi_client::types;
+
+const GAS_PRICE: &str = r#"{"jsonrpc":"2.0","id":"dontcare","result":{"gas_price":"100000000"}}"#;
+
+/// Transport answering from a queue, without network access.
+#[derive(Default)]
+struct Canned {
+    responses: Mutex<VecDeque<Result<TransportResponse, TransportError>>>,
+    requests: Mutex<Vec<(String, serde_json::Value)>>,
+}
+
+impl Canned {
+    fn new(responses: Vec<Result<(u16, &str), &str>>) -> Self {
+        let responses = responses
+            .into_iter()
+            .map(|response| match response {
+                Ok((status, body)) => Ok(TransportResponse {
+                    status,
+                    body: Bytes::from(body.to_string()),
+                }),
+                Err(err) => Err(TransportError::new(err)),
+            })
+            .collect();
+        Self {
+            responses: Mutex::new(responses),
+            requests: Mutex::default(),
+        }
+    }
+}
+
+impl Transport for Canned {
+    fn send<'a>(&'a self, url: &'a str, body: Bytes) -> TransportFuture<'a> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((url.to_string(), serde_json::from_slice(&body).unwrap()));
+        let response = self.responses.lock().unwrap().pop_front().unwrap();
+        Box::pin(async move { response })
+    }
+}
+
+/// Transport counting the requests it passes on to another one.
+struct Counting<T> {
+    inner: T,
+    count: Mutex<usize>,
+}
+
+impl<T: Transport> Transport for Counting<T> {
+    fn send<'a>(&'a self, url: &'a str, body: Bytes) -> TransportFuture<'a> {
+        *self.count.lock().unwrap() += 1;
+        self.inner.send(url, body)
+    }
+}
+
+/// Shares a transport with the test to inspect it after the calls.
+struct ArcTransport<T>(Arc<T>);
+
+impl<T: Transport> Transport for ArcTransport<T> {
+    fn send<'a>(&'a self, url: &'a str, body: Bytes) -> TransportFuture<'a> {
+        self.0.send(url, body)
+    }
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .max_attempts(3)
+        .backoff(Backoff::Fixed(Duration::from_millis(1)))
+}
+
+async fn gas_price(
+    client: &RpcClient,
+) -> Result<types::RpcGasPriceResponse, RpcError<types::ErrorWrapperForRpcGasPriceError>> {
+    client
+        .gas_price(&types::RpcGasPriceRequest { block_id: None })
+        .await
+}
+
+#[tokio::test]
+async fn test_sends_requests_with_the_transport() {
+    let transport = Arc::new(Canned::new(vec![Ok((200, GAS_PRICE))]));
+    let client =
+        RpcClient::new("http://node.invalid").with_transport(ArcTransport(transport.clone()));
+
+    let response = gas_price(&client).await.unwrap();
+    assert_eq!(response.gas_price.as_yoctonear(), 100_000_000);
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1, "{:?}", requests);
+    assert_eq!(requests[0].0, "http://node.invalid");
+    assert_eq!(requests[0].1["method"], "gas_price");
+}
+
+#[tokio::test]
+async fn test_retries_failures_of_the_transport() {
+    let client = RpcClient::new("http://node.invalid")
+        .with_transport(Canned::new(vec![
+            Err("connection reset"),
+            Ok((503, "")),
+            Ok((200, GAS_PRICE)),
+        ]))
+        .with_retry(policy());
+
+    let response = gas_price(&client).await.unwrap();
+    assert_eq!(response.gas_price.as_yoctonear(), 100_000_000);
+}
+
+#[tokio::test]
+async fn test_reports_failures_of_the_transport() {
+    let client = RpcClient::new("http://node.invalid")
+        .with_transport(Canned::new(vec![Err("connection reset")]));
+
+    let result = gas_price(&client).await;
+    assert!(
+        matches!(
+            &result,
+            Err(RpcError::Client(Error::Custom(message))) if message.contains("connection reset")
+        ),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_wraps_reqwest_transport() {
+    let url = common::serve(vec![(200, GAS_PRICE.to_string())]);
+    let transport = Arc::new(Counting {
+        inner: ReqwestTransport::default(),
+        count: Mutex::new(0),
+    });
+    let client = RpcClient::new(&url).with_transport(ArcTransport(transport.clone()));
+
+    let response = gas_price(&client).await.unwrap();
+    assert_eq!(response.gas_price.as_yoctonear(), 100_000_000);
+    assert_eq!(*transport.count.lock().unwrap(), 1);
+}
@@ -0,0 +1,158 @@
+use near_openapi_client::types::tx::{
+    SecretKey, SignedTransaction, Transaction, TransactionBuilder,
+};
+use near_openapi_client::types::{
+    AccessKeyPermission, CryptoHash, FunctionArgs, FunctionCallPermission, KeyType, NearGas,
+    NearToken, PublicKey,
+};
+
+// Key pair of the first test vector of RFC 8032.
+const ED25519_SECRET_KEY: &str = "ed25519:49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+const ED25519_PUBLIC_KEY: &str = "ed25519:FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z";
+const ED25519_PUBLIC_KEY_BYTES: &str =
+    "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+fn hex(data: &str) -> Vec<u8> {
+    (0..data.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&data[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn string(value: &str) -> Vec<u8> {
+    let mut encoded = (value.len() as u32).to_le_bytes().to_vec();
+    encoded.extend(value.as_bytes());
+    encoded
+}
+
+fn transfer() -> TransactionBuilder {
+    TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        ED25519_PUBLIC_KEY.parse().unwrap(),
+        "bob.near".parse().unwrap(),
+    )
+    .transfer(NearToken::from_near(1))
+}
+
+#[test]
+fn test_transaction_borsh_encoding() {
+    let tx = transfer().build(7, CryptoHash([1; 32]));
+
+    let mut expected = string("alice.near");
+    expected.push(0);
+    expected.extend(hex(ED25519_PUBLIC_KEY_BYTES));
+    expected.extend(7u64.to_le_bytes());
+    expected.extend(string("bob.near"));
+    expected.extend([1; 32]);
+    expected.extend(1u32.to_le_bytes());
+    expected.push(3);
+    expected.extend(NearToken::from_near(1).as_yoctonear().to_le_bytes());
+    assert_eq!(tx.encode().unwrap(), expected);
+}
+
+#[test]
+fn test_function_call_encoding() {
+    let tx = TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        ED25519_PUBLIC_KEY.parse().unwrap(),
+        "app.near".parse().unwrap(),
+    )
+    .function_call(
+        "set",
+        FunctionArgs::from_json(&serde_json::json!({"a": 1})).unwrap(),
+        NearGas::from_tgas(30),
+        NearToken::from_yoctonear(1),
+    )
+    .build(1, CryptoHash::default());
+
+    let mut action = vec![2];
+    action.extend(string("set"));
+    action.extend(string(r#"{"a":1}"#));
+    action.extend(30_000_000_000_000u64.to_le_bytes());
+    action.extend(1u128.to_le_bytes());
+    assert!(tx.encode().unwrap().ends_with(&action));
+}
+
+#[test]
+fn test_sign_ed25519() {
+    let secret_key: SecretKey = ED25519_SECRET_KEY.parse().unwrap();
+    assert_eq!(secret_key.public_key().to_string(), ED25519_PUBLIC_KEY);
+
+    let tx = transfer().build(7, CryptoHash([1; 32]));
+    let signed = tx.clone().sign(&secret_key).unwrap();
+    assert_eq!(signed.signature.key_type(), KeyType::Ed25519);
+    assert_eq!(signed.hash().unwrap(), tx.hash().unwrap());
+    // Ed25519 signatures are deterministic.
+    assert_eq!(secret_key.sign(&tx.hash().unwrap()), signed.signature);
+
+    let encoded = signed.encode().unwrap();
+    let unsigned = tx.encode().unwrap();
+    assert_eq!(encoded.len(), unsigned.len() + 1 + 64);
+    assert!(encoded.starts_with(&unsigned));
+    assert!(!signed.to_base64().unwrap().0.is_empty());
+}
+
+#[test]
+fn test_sign_secp256k1() {
+    let secret_key: SecretKey = "secp256k1:11111111111111111111111111111112"
+        .parse()
+        .unwrap();
+    // The generator point, as the secret key is 1.
+    assert_eq!(
+        secret_key.public_key().to_string(),
+        "secp256k1:3SB8tA9Kbn7FBtT6GWR6AJk73QceudisHaGThPoLCDgC9tan7d3cwZFiDZtrmhSAf8aTynEdQ3N7KXhMm3nWhekP"
+    );
+
+    let tx = TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        secret_key.public_key(),
+        "bob.near".parse().unwrap(),
+    )
+    .transfer(NearToken::from_near(1))
+    .build(1, CryptoHash::default());
+    let unsigned = tx.encode().unwrap();
+    let signed = tx.sign(&secret_key).unwrap();
+    assert_eq!(signed.encode().unwrap().len(), unsigned.len() + 1 + 65);
+}
+
+#[test]
+fn test_invalid_public_key_fails_to_parse() {
+    assert!("ed25519:abc".parse::<PublicKey>().is_err());
+    assert!("rsa:abc".parse::<SecretKey>().is_err());
+}
+
+#[test]
+fn test_decode_round_trip() {
+    let tx = transfer()
+        .create_account()
+        .deploy_contract(b"\0asm\x01\0\0\0")
+        .function_call(
+            "set",
+            FunctionArgs::from_bytes(b"{}"),
+            NearGas::from_tgas(30),
+            NearToken::from_yoctonear(1),
+        )
+        .add_key(
+            ED25519_PUBLIC_KEY.parse().unwrap(),
+            AccessKeyPermission::FunctionCall(FunctionCallPermission {
+                allowance: Some(NearToken::from_millinear(250)),
+                method_names: vec!["set".to_string()],
+                receiver_id: "app.near".to_string(),
+            }),
+        )
+        .delete_key(ED25519_PUBLIC_KEY.parse().unwrap())
+        .delete_account("carol.near".parse().unwrap())
+        .build(7, CryptoHash([1; 32]));
+    assert_eq!(Transaction::decode(&tx.encode().unwrap()).unwrap(), tx);
+
+    let secret_key: SecretKey = ED25519_SECRET_KEY.parse().unwrap();
+    let signed = tx.sign(&secret_key).unwrap();
+    assert_eq!(
+        SignedTransaction::decode(&signed.encode().unwrap()).unwrap(),
+        signed
+    );
+
+    let mut trailing = signed.encode().unwrap();
+    trailing.push(0);
+    assert!(SignedTransaction::decode(&trailing).is_err());
+}
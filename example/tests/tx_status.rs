@@ -0,0 +1,34 @@
+use near_openapi_client::types::{
+    CryptoHash, RpcTransactionStatusRequest, SignedTransaction, TxExecutionStatus,
+};
+
+#[test]
+fn test_status_request_by_signed_transaction() {
+    let request = RpcTransactionStatusRequest::by_signed_transaction(
+        SignedTransaction("dGVzdA==".to_string()),
+        TxExecutionStatus::Final,
+    );
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({"signed_tx_base64": "dGVzdA==", "wait_until": "FINAL"})
+    );
+}
+
+#[test]
+fn test_status_request_by_hash() {
+    let request = RpcTransactionStatusRequest::by_hash(
+        CryptoHash::default(),
+        "alice.near".parse().unwrap(),
+        TxExecutionStatus::Executed,
+    );
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "sender_account_id": "alice.near",
+            "tx_hash": "11111111111111111111111111111111",
+            "wait_until": "EXECUTED",
+        })
+    );
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use std::time::Duration;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::tx_wait::{TxExecutionStatus, TxWaitError};
+use near_openapi_client::types::SignedTransaction;
+use near_openapi_client::types::tx::TransactionBuilder;
+
+const HASH: &str = "11111111111111111111111111111111";
+const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+const SIGNATURE: &str = "ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj";
+
+fn signed_tx() -> SignedTransaction {
+    TransactionBuilder::new(
+        "alice.near".parse().unwrap(),
+        PUBLIC_KEY.parse().unwrap(),
+        "bob.near".parse().unwrap(),
+    )
+    .build(1, HASH.parse().unwrap())
+    .with_signature(SIGNATURE.parse().unwrap())
+    .to_base64()
+    .unwrap()
+}
+
+/// The golden transaction at `status`.
+fn response(status: &str) -> (u16, String) {
+    let mut result: serde_json::Value =
+        serde_json::from_str(include_str!("golden/tx.json")).unwrap();
+    result["final_execution_status"] = status.into();
+    let response = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": result});
+    (200, response.to_string())
+}
+
+fn error(cause: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": cause,
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    (200, response.to_string())
+}
+
+#[test]
+fn test_satisfies() {
+    use TxExecutionStatus::*;
+    assert!(Final.satisfies(ExecutedOptimistic));
+    assert!(Executed.satisfies(IncludedFinal));
+    assert!(Included.satisfies(None));
+    assert!(!IncludedFinal.satisfies(ExecutedOptimistic));
+    assert!(!ExecutedOptimistic.satisfies(IncludedFinal));
+    assert!(!None.satisfies(Included));
+}
+
+#[tokio::test]
+async fn test_polls_until_status_is_reached() {
+    let url = common::serve(vec![
+        error(serde_json::json!({"name": "TIMEOUT_ERROR"})),
+        response("EXECUTED_OPTIMISTIC"),
+        response("FINAL"),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let response = client
+        .send_tx_and_wait(
+            signed_tx(),
+            TxExecutionStatus::Final,
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.final_execution_status(), TxExecutionStatus::Final);
+}
+
+#[tokio::test]
+async fn test_times_out_with_last_status() {
+    let url = common::serve(vec![response("INCLUDED")]);
+    let client = RpcClient::new(&url);
+
+    let err = client
+        .send_tx_and_wait(
+            signed_tx(),
+            TxExecutionStatus::Final,
+            Duration::from_millis(200),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            TxWaitError::Timeout {
+                last_status: Some(TxExecutionStatus::Included)
+            }
+        ),
+        "{:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_transaction_is_not_polled() {
+    let url = common::serve(vec![error(serde_json::json!({
+        "name": "INVALID_TRANSACTION",
+        "info": {"TxExecutionError": {"InvalidTxError": "Expired"}},
+    }))]);
+    let client = RpcClient::new(&url);
+
+    let err = client
+        .send_tx_and_wait(
+            signed_tx(),
+            TxExecutionStatus::Final,
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, TxWaitError::Rpc(RpcError::Rpc(_))),
+        "{:?}",
+        err
+    );
+}
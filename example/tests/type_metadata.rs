@@ -0,0 +1,86 @@
+use near_openapi_client::types::type_metadata::{TypeChange, TypeKind, TypeMetadata};
+
+#[test]
+fn test_current_metadata_describes_generated_types() {
+    let metadata = TypeMetadata::current();
+
+    let account_view = &metadata.types["AccountView"];
+    assert_eq!(account_view.kind, TypeKind::Struct);
+    assert_eq!(
+        account_view.members["storage_usage"].as_deref(),
+        Some("u64")
+    );
+
+    let permission = &metadata.types["AccessKeyPermissionView"];
+    assert_eq!(permission.kind, TypeKind::Enum);
+    assert_eq!(permission.members["FullAccess"], None);
+}
+
+#[test]
+fn test_diff_reports_changes() {
+    let old = TypeMetadata::current();
+    let mut new = old.clone();
+    new.version = "next".to_string();
+
+    let account_view = new.types.get_mut("AccountView").unwrap();
+    account_view
+        .members
+        .insert("storage_usage".to_string(), Some("u128".to_string()));
+    account_view.members.remove("locked");
+    new.types
+        .get_mut("AccessKeyPermissionView")
+        .unwrap()
+        .members
+        .insert("GasKey".to_string(), None);
+    let removed = new.types.remove("StoreKey").unwrap();
+    new.types.insert("StoreKeyV2".to_string(), removed);
+
+    let report = old.diff(&new);
+
+    assert_eq!(report.old_version, old.version);
+    assert_eq!(report.new_version, "next");
+    assert_eq!(
+        report.changes,
+        vec![
+            TypeChange::VariantAdded {
+                type_name: "AccessKeyPermissionView".to_string(),
+                variant: "GasKey".to_string(),
+                payload: None,
+            },
+            TypeChange::FieldRemoved {
+                type_name: "AccountView".to_string(),
+                field: "locked".to_string(),
+            },
+            TypeChange::FieldTypeChanged {
+                type_name: "AccountView".to_string(),
+                field: "storage_usage".to_string(),
+                old_type: "u64".to_string(),
+                new_type: "u128".to_string(),
+            },
+            TypeChange::TypeRemoved {
+                type_name: "StoreKey".to_string(),
+            },
+            TypeChange::TypeAdded {
+                type_name: "StoreKeyV2".to_string(),
+                kind: TypeKind::Struct,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_report_serializes_with_change_tag() {
+    let change = TypeChange::VariantRemoved {
+        type_name: "ActionErrorKind".to_string(),
+        variant: "DelegateActionExpired".to_string(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&change).unwrap(),
+        serde_json::json!({
+            "change": "variant_removed",
+            "type_name": "ActionErrorKind",
+            "variant": "DelegateActionExpired",
+        })
+    );
+}
@@ -0,0 +1,98 @@
+use near_openapi_client::Error;
+use near_openapi_client::batch::BatchRequest;
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types;
+use near_openapi_client::types::state_changes::StateChangesRequestBuilder;
+use near_openapi_client::types::validate::{Validate, ValidationError};
+
+/// Nothing listens here: requests that fail validation are never sent.
+const UNREACHABLE: &str = "http://127.0.0.1:9";
+
+fn call_function(method_name: &str, args_base64: &str) -> types::RpcQueryRequest {
+    types::RpcQueryRequest::CallFunctionByFinality {
+        account_id: "contract.near".parse().unwrap(),
+        args_base64: types::FunctionArgs(args_base64.to_string()),
+        finality: types::Finality::Final,
+        method_name: method_name.to_string(),
+        request_type: types::CallFunctionByFinalityRequestType::CallFunction,
+    }
+}
+
+#[test]
+fn test_validate_changes_request() {
+    assert_eq!(
+        StateChangesRequestBuilder::new()
+            .account_changes(vec![])
+            .validate(),
+        Err(ValidationError::EmptyList {
+            field: "account_ids"
+        })
+    );
+    assert!(
+        StateChangesRequestBuilder::new()
+            .account_changes(vec!["alice.near".parse().unwrap()])
+            .validate()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_validate_call_function() {
+    assert!(call_function("get_balance", "e30=").validate().is_ok());
+    assert_eq!(
+        call_function("", "e30=").validate(),
+        Err(ValidationError::EmptyString {
+            field: "method_name"
+        })
+    );
+    let err = call_function("get_balance", "not base64!")
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ValidationError::InvalidBase64 {
+            field: "args_base64"
+        }
+    );
+    assert_eq!(err.to_string(), "args_base64 is not valid base64");
+}
+
+#[test]
+fn test_validate_send_tx() {
+    let request = types::RpcSendTransactionRequest {
+        signed_tx_base64: types::SignedTransaction("AAAA".to_string()),
+        wait_until: types::TxExecutionStatus::Final,
+    };
+    assert!(matches!(
+        request.validate(),
+        Err(ValidationError::InvalidTransaction {
+            field: "signed_tx_base64",
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_invalid_params_are_not_sent() {
+    let client = RpcClient::new(UNREACHABLE);
+    let err = client
+        .request::<methods::Query>(&call_function("", "e30="))
+        .await
+        .unwrap_err();
+    match err {
+        RpcError::Client(Error::InvalidRequest(message)) => {
+            assert_eq!(message, "method_name must not be empty")
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+
+    let mut batch = BatchRequest::new();
+    batch.add::<methods::Query>(&call_function("get_balance", "e30="));
+    batch.add::<methods::Query>(&call_function("get_balance", "not base64!"));
+    assert!(matches!(
+        batch.send(&client).await,
+        Err(RpcError::Client(Error::InvalidRequest(_)))
+    ));
+}
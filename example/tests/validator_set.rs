@@ -0,0 +1,143 @@
+use near_openapi_client::types::validator_set::ValidatorSet;
+use near_openapi_client::types::{
+    CurrentEpochValidatorInfo, NearToken, RpcValidatorResponse, ValidatorKickoutReason,
+};
+use serde_json::json;
+
+const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+
+fn validator(
+    account_id: &str,
+    stake: u128,
+    blocks: (u64, u64),
+    chunks: (u64, u64),
+    endorsements: (u64, u64),
+) -> serde_json::Value {
+    json!({
+        "account_id": account_id,
+        "is_slashed": false,
+        "public_key": PUBLIC_KEY,
+        "shards": [0],
+        "stake": NearToken::from_near(stake),
+        "num_produced_blocks": blocks.0,
+        "num_expected_blocks": blocks.1,
+        "num_produced_chunks": chunks.0,
+        "num_expected_chunks": chunks.1,
+        "num_produced_endorsements": endorsements.0,
+        "num_expected_endorsements": endorsements.1,
+        "num_produced_chunks_per_shard": [chunks.0],
+        "num_expected_chunks_per_shard": [chunks.1],
+        "num_produced_endorsements_per_shard": [endorsements.0],
+        "num_expected_endorsements_per_shard": [endorsements.1],
+        "shards_endorsed": [0],
+    })
+}
+
+fn response() -> RpcValidatorResponse {
+    serde_json::from_value(json!({
+        "current_fishermen": [],
+        "current_proposals": [],
+        "current_validators": [
+            validator("small.near", 10, (10, 10), (20, 20), (0, 0)),
+            validator("big.near", 50, (75, 100), (200, 200), (0, 0)),
+            validator("middle.near", 40, (0, 0), (0, 0), (50, 100)),
+        ],
+        "epoch_height": 100,
+        "epoch_start_height": 1000,
+        "next_fishermen": [],
+        "next_validators": [
+            {"account_id": "big.near", "public_key": PUBLIC_KEY, "shards": [0], "stake": NearToken::from_near(60)},
+        ],
+        "prev_epoch_kickout": [
+            {"account_id": "gone.near", "reason": {"NotEnoughBlocks": {"expected": 10, "produced": 2}}},
+        ],
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_validator_set_sorts_and_indexes() {
+    let set = response().current_validator_set();
+    let accounts: Vec<_> = set.iter().map(|v| v.account_id.as_str()).collect();
+    assert_eq!(
+        accounts,
+        ["big.near", "middle.near", "small.near"],
+        "{:?}",
+        accounts
+    );
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.total_stake(), NearToken::from_near(100));
+
+    let middle = "middle.near".parse().unwrap();
+    assert_eq!(set.get(&middle).unwrap().stake, NearToken::from_near(40));
+    assert_eq!(set.rank(&middle), Some(1));
+    assert_eq!(set.stake_share(&middle), Some(0.4));
+    assert!(set.get(&"gone.near".parse().unwrap()).is_none());
+    // big.near alone holds half of the stake.
+    assert_eq!(set.nakamoto_coefficient(), 1);
+}
+
+#[test]
+fn test_equal_stakes_sort_by_account_id() {
+    let set: ValidatorSet<CurrentEpochValidatorInfo> = ["c.near", "a.near", "b.near"]
+        .into_iter()
+        .map(|account_id| {
+            serde_json::from_value(validator(account_id, 10, (1, 1), (1, 1), (0, 0))).unwrap()
+        })
+        .collect();
+    let accounts: Vec<_> = set.iter().map(|v| v.account_id.as_str()).collect();
+    assert_eq!(accounts, ["a.near", "b.near", "c.near"], "{:?}", accounts);
+    assert_eq!(set.nakamoto_coefficient(), 2);
+}
+
+#[test]
+fn test_production_rates() {
+    let set = response().current_validator_set();
+    let big = set.get(&"big.near".parse().unwrap()).unwrap();
+    assert_eq!(big.block_production_rate(), Some(0.75));
+    assert_eq!(big.chunk_production_rate(), Some(1.0));
+    assert_eq!(big.endorsement_rate(), None);
+    assert_eq!(big.production_rate(), Some(275.0 / 300.0));
+
+    let middle = set.get(&"middle.near".parse().unwrap()).unwrap();
+    assert_eq!(middle.production_rate(), None);
+    assert_eq!(middle.endorsement_rate(), Some(0.5));
+}
+
+#[test]
+fn test_at_risk() {
+    let set = response().current_validator_set();
+    let big = set.get(&"big.near".parse().unwrap()).unwrap();
+    // 75% of blocks is below 80% but not below 75%.
+    assert!(big.is_at_risk(80));
+    assert!(!big.is_at_risk(75));
+
+    let at_risk: Vec<_> = set.at_risk(80).map(|v| v.account_id.as_str()).collect();
+    assert_eq!(at_risk, ["big.near", "middle.near"], "{:?}", at_risk);
+    assert_eq!(set.at_risk(50).count(), 0);
+}
+
+#[test]
+fn test_next_validators_and_kickouts() {
+    let response = response();
+    let next = response.next_validator_set();
+    assert_eq!(next.total_stake(), NearToken::from_near(60));
+
+    let reason = response.kickout_reason(&"gone.near".parse().unwrap());
+    assert!(
+        matches!(
+            reason,
+            Some(ValidatorKickoutReason::NotEnoughBlocks {
+                expected: 10,
+                produced: 2
+            })
+        ),
+        "{:?}",
+        reason
+    );
+    assert!(
+        response
+            .kickout_reason(&"big.near".parse().unwrap())
+            .is_none()
+    );
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{CryptoHash, EpochId, NearToken};
+use near_openapi_client::validators::ValidatorsError;
+
+const SIGNATURE: &str = "ed25519:1111111111111111111111111111111111111111111111111111111111111111";
+
+fn hash(n: u8) -> CryptoHash {
+    CryptoHash([n; 32])
+}
+
+fn envelope(result: serde_json::Value) -> (u16, String) {
+    let response = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": result});
+    (200, response.to_string())
+}
+
+/// Validators of epoch `epoch_height` starting at `start_height`, with their
+/// stakes in yoctoNEAR.
+fn validators(epoch_height: u64, start_height: u64, stakes: &[(&str, u128)]) -> (u16, String) {
+    let validators: Vec<_> = stakes
+        .iter()
+        .map(|(account_id, stake)| {
+            serde_json::json!({
+                "account_id": account_id,
+                "is_slashed": false,
+                "num_expected_blocks": 10,
+                "num_produced_blocks": 9,
+                "public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+                "shards": [0],
+                "stake": stake.to_string(),
+            })
+        })
+        .collect();
+    envelope(serde_json::json!({
+        "current_fishermen": [],
+        "current_proposals": [],
+        "current_validators": validators,
+        "epoch_height": epoch_height,
+        "epoch_start_height": start_height,
+        "next_fishermen": [],
+        "next_validators": [],
+        "prev_epoch_kickout": [],
+    }))
+}
+
+/// First block of epoch `hash(epoch)` at `height`, after the block
+/// `hash(prev)`.
+fn first_block(height: u64, epoch: u8, prev: u8) -> (u16, String) {
+    let header = serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": hash(0),
+        "challenges_result": [],
+        "challenges_root": hash(0),
+        "chunk_headers_root": hash(0),
+        "chunk_mask": [true],
+        "chunk_receipts_root": hash(0),
+        "chunk_tx_root": hash(0),
+        "chunks_included": 1,
+        "epoch_id": hash(epoch),
+        "gas_price": "100000000",
+        "hash": hash(height as u8),
+        "height": height,
+        "last_ds_final_block": hash(0),
+        "last_final_block": hash(0),
+        "latest_protocol_version": 80,
+        "next_bp_hash": hash(0),
+        "next_epoch_id": hash(0),
+        "outcome_root": hash(0),
+        "prev_hash": hash(prev),
+        "prev_state_root": hash(0),
+        "random_value": hash(0),
+        "rent_paid": "0",
+        "signature": SIGNATURE,
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    });
+    envelope(serde_json::json!({"author": "node.near", "chunks": [], "header": header}))
+}
+
+#[tokio::test]
+async fn test_walks_back_and_caches_finished_epochs() {
+    let url = common::serve(vec![
+        // Latest epoch 3, then epochs 2 and 1 through the blocks before the
+        // first block of each.
+        validators(3, 200, &[("a.near", 30)]),
+        first_block(200, 30, 199),
+        validators(2, 100, &[("a.near", 20), ("b.near", 5)]),
+        first_block(100, 20, 99),
+        validators(1, 1, &[("a.near", 10)]),
+        first_block(1, 10, 0),
+        // The second walk only fetches the latest epoch again.
+        validators(3, 200, &[("a.near", 31)]),
+        first_block(200, 30, 199),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let epochs = client.validators_for_epochs(..).await.unwrap();
+    assert_eq!(epochs.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    let epoch = &epochs[&2];
+    assert_eq!(epoch.epoch_id, EpochId(hash(20)));
+    assert_eq!(epoch.epoch_start_height, 100);
+    assert_eq!(epoch.prev_epoch_last_block, Some(hash(99)));
+    assert_eq!(epoch.total_stake(), NearToken::from_yoctonear(25));
+    assert_eq!(
+        epoch.stake_of(&"b.near".parse().unwrap()),
+        Some(NearToken::from_yoctonear(5))
+    );
+    assert_eq!(epochs[&1].prev_epoch_last_block, None);
+
+    let epochs = client.clone().validators_for_epochs(2..).await.unwrap();
+    assert_eq!(epochs.keys().copied().collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(epochs[&2].total_stake(), NearToken::from_yoctonear(25));
+    assert_eq!(epochs[&3].total_stake(), NearToken::from_yoctonear(31));
+}
+
+#[tokio::test]
+async fn test_stops_at_start_of_range() {
+    let url = common::serve(vec![
+        validators(5, 200, &[("a.near", 1)]),
+        first_block(200, 50, 199),
+        validators(4, 100, &[("a.near", 1)]),
+        first_block(100, 40, 99),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let epochs = client.validators_for_epochs(4..5).await.unwrap();
+    assert_eq!(epochs.keys().copied().collect::<Vec<_>>(), [4]);
+    assert_eq!(epochs[&4].epoch_id, EpochId(hash(40)));
+}
+
+#[tokio::test]
+async fn test_unavailable_epoch() {
+    let error = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "UNKNOWN_EPOCH"},
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    let url = common::serve(vec![
+        validators(7, 150, &[("a.near", 1)]),
+        first_block(150, 70, 149),
+        (200, error.to_string()),
+    ]);
+    let client = RpcClient::new(&url);
+
+    let err = client.validators_for_epochs(6..).await.unwrap_err();
+    assert!(matches!(err, ValidatorsError::Validators(_)), "{:?}", err);
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{
+    AccessKeyPermissionView, BlockId, BlockReference, ErrorWrapperForRpcQueryError, Finality,
+    RpcQueryError,
+};
+use near_openapi_client::view::ViewFunctionError;
+
+const HASH: &str = "11111111111111111111111111111111";
+
+fn call_result(result: &[u8], logs: &[&str]) -> (u16, String) {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "result": {
+            "block_hash": HASH,
+            "block_height": 10,
+            "logs": logs,
+            "result": result,
+        },
+    });
+    (200, response.to_string())
+}
+
+fn final_block() -> BlockReference {
+    BlockReference::Finality(Finality::Final)
+}
+
+#[tokio::test]
+async fn test_view_function_returns_logs_separately() {
+    let url = common::serve(vec![call_result(
+        br#"{"total":"5"}"#,
+        &["reading supply", "done"],
+    )]);
+    let client = RpcClient::new(&url);
+
+    let view = client
+        .view_function::<_, serde_json::Value>(
+            &"token.near".parse().unwrap(),
+            "ft_metadata",
+            &serde_json::json!({}),
+            final_block(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(view.result, serde_json::json!({"total": "5"}));
+    assert_eq!(view.logs, ["reading supply", "done"]);
+    assert_eq!(view.block_height, 10);
+}
+
+#[tokio::test]
+async fn test_view_function_result_that_is_not_json() {
+    let url = common::serve(vec![call_result(b"not json", &[])]);
+    let client = RpcClient::new(&url);
+
+    let err = client
+        .view_function::<_, String>(
+            &"token.near".parse().unwrap(),
+            "ft_balance_of",
+            &serde_json::json!({"account_id": "alice.near"}),
+            final_block(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ViewFunctionError::Result(_)), "{:?}", err);
+}
+
+#[tokio::test]
+async fn test_view_function_panic_is_a_query_error() {
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "dontcare",
+        "error": {
+            "name": "HANDLER_ERROR",
+            "cause": {
+                "name": "CONTRACT_EXECUTION_ERROR",
+                "info": {
+                    "block_hash": HASH,
+                    "block_height": 10,
+                    "vm_error": "wasm execution failed with error: MethodResolveError(MethodNotFound)",
+                },
+            },
+            "code": -32000,
+            "message": "Server error",
+        },
+    });
+    let url = common::serve(vec![(200, response.to_string())]);
+    let client = RpcClient::new(&url);
+
+    let err = client
+        .view_function::<_, String>(
+            &"token.near".parse().unwrap(),
+            "missing",
+            &serde_json::json!({}),
+            final_block(),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ViewFunctionError::Query(RpcError::Rpc(ErrorWrapperForRpcQueryError::HandlerError(
+                RpcQueryError::ContractExecutionError { .. }
+            )))
+        ),
+        "{:?}",
+        err
+    );
+}
+
+fn query_result(view: serde_json::Value) -> (u16, String) {
+    let mut result = serde_json::json!({"block_hash": HASH, "block_height": 10});
+    result
+        .as_object_mut()
+        .unwrap()
+        .extend(view.as_object().unwrap().clone());
+    let response = serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": result});
+    (200, response.to_string())
+}
+
+#[tokio::test]
+async fn test_view_account_and_access_keys() {
+    let url = common::serve(vec![
+        query_result(serde_json::json!({
+            "amount": "5",
+            "code_hash": HASH,
+            "locked": "0",
+            "storage_usage": 100,
+        })),
+        query_result(serde_json::json!({"nonce": 7, "permission": "FullAccess"})),
+        query_result(serde_json::json!({"keys": [{
+            "public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+            "access_key": {"nonce": 7, "permission": "FullAccess"},
+        }]})),
+    ]);
+    let client = RpcClient::new(&url);
+    let account_id = "alice.near".parse().unwrap();
+    let public_key = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+        .parse()
+        .unwrap();
+    let block = BlockReference::BlockId(BlockId::BlockHeight(10));
+
+    let account = client
+        .view_account(&account_id, block.clone())
+        .await
+        .unwrap();
+    assert_eq!(account.amount.as_yoctonear(), 5);
+    let key = client
+        .view_access_key(&account_id, &public_key, block.clone())
+        .await
+        .unwrap();
+    assert_eq!(key.nonce, 7);
+    assert_eq!(key.permission, AccessKeyPermissionView::FullAccess);
+    let keys = client
+        .view_access_key_list(&account_id, block)
+        .await
+        .unwrap();
+    assert_eq!(keys.keys.len(), 1);
+    assert_eq!(keys.keys[0].public_key, public_key);
+}
+
+#[tokio::test]
+async fn test_view_state_decodes_keys_and_values() {
+    // "STATE" -> "1", "STATEx" -> "" in base64.
+    let url = common::serve(vec![query_result(serde_json::json!({"values": [
+        {"key": "U1RBVEU=", "value": "MQ=="},
+        {"key": "U1RBVEV4", "value": ""},
+    ]}))]);
+    let client = RpcClient::new(&url);
+
+    let state = client
+        .view_state(&"app.near".parse().unwrap(), b"STATE", final_block())
+        .await
+        .unwrap();
+    assert_eq!(
+        state.into_iter().collect::<Vec<_>>(),
+        [
+            (b"STATE".to_vec(), b"1".to_vec()),
+            (b"STATEx".to_vec(), b"".to_vec())
+        ]
+    );
+}
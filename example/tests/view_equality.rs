@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use near_openapi_client::types;
+
+const ACCOUNT_VIEW: &str = r#"{
+    "amount": "1000000000000000000000000",
+    "code_hash": "11111111111111111111111111111111",
+    "locked": "0",
+    "storage_paid_at": 0,
+    "storage_usage": 182
+}"#;
+
+#[test]
+fn test_account_view_equality() {
+    let first: types::AccountView = serde_json::from_str(ACCOUNT_VIEW).unwrap();
+    let second: types::AccountView = serde_json::from_str(ACCOUNT_VIEW).unwrap();
+    assert_eq!(first, second);
+
+    let mut changed = second.clone();
+    changed.storage_usage += 1;
+    assert_ne!(first, changed);
+}
+
+#[test]
+fn test_access_key_views_deduplicate() {
+    let full_access: types::AccessKeyView =
+        serde_json::from_str(r#"{"nonce": 1, "permission": "FullAccess"}"#).unwrap();
+    let function_call: types::AccessKeyView = serde_json::from_str(
+        r#"{"nonce": 1, "permission": {"FunctionCall": {"allowance": null, "method_names": [], "receiver_id": "app.near"}}}"#,
+    )
+    .unwrap();
+
+    let keys: HashSet<_> = [full_access.clone(), function_call, full_access]
+        .into_iter()
+        .collect();
+    assert_eq!(keys.len(), 2);
+}
+
+#[test]
+fn test_execution_status_equality() {
+    // ExecutionStatusView can hold an `f64` congestion level, so it only gets PartialEq
+    let status: types::ExecutionStatusView =
+        serde_json::from_str(r#"{"SuccessValue": ""}"#).unwrap();
+    assert_eq!(
+        status,
+        types::ExecutionStatusView::SuccessValue(String::new())
+    );
+}
@@ -0,0 +1,90 @@
+use near_openapi_client::types::tx::TransactionBuilder;
+use near_openapi_client::types::{CryptoHash, FunctionArgs, NearToken};
+use near_openapi_client::wallet::{MY_NEAR_WALLET_TESTNET, SignRequest, WalletCallback};
+
+const HASH: &str = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+
+/// Splits a query string into its percent-decoded parameters.
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    fn decode(value: &str) -> String {
+        let bytes = value.replace('+', " ").into_bytes();
+        let mut decoded = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 3;
+            } else {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+        String::from_utf8(decoded).unwrap()
+    }
+
+    query
+        .split('&')
+        .map(|param| {
+            let (name, value) = param.split_once('=').unwrap();
+            (decode(name), decode(value))
+        })
+        .collect()
+}
+
+#[test]
+fn test_sign_url() {
+    let tx = TransactionBuilder::new(
+        "alice.testnet".parse().unwrap(),
+        "ed25519:11111111111111111111111111111111".parse().unwrap(),
+        "bob.testnet".parse().unwrap(),
+    )
+    .transfer(NearToken::from_near(1))
+    .build(0, CryptoHash::default());
+
+    let url = SignRequest::new(MY_NEAR_WALLET_TESTNET)
+        .transaction(tx.clone())
+        .transaction(tx.clone())
+        .callback_url("https://app.example/signed?order=1")
+        .meta("order-1")
+        .to_url()
+        .unwrap();
+
+    let (base, query) = url.split_once('?').unwrap();
+    assert_eq!(base, "https://testnet.mynearwallet.com/sign");
+    let params = parse_query(query);
+    assert_eq!(params[0].0, "transactions");
+    let transactions: Vec<_> = params[0].1.split(',').collect();
+    assert_eq!(transactions.len(), 2);
+    // `FunctionArgs` holds its bytes in the same standard base64.
+    let encoded = FunctionArgs::from_bytes(&tx.encode().unwrap()).0;
+    assert_eq!(transactions, [encoded.as_str(), encoded.as_str()]);
+    assert_eq!(
+        params[1],
+        (
+            "callbackUrl".to_string(),
+            "https://app.example/signed?order=1".to_string()
+        )
+    );
+    assert_eq!(params[2], ("meta".to_string(), "order-1".to_string()));
+}
+
+#[test]
+fn test_wallet_callback() {
+    assert_eq!(
+        WalletCallback::from_query(&format!("transactionHashes={HASH}%2C{HASH}&meta=order-1")),
+        Some(WalletCallback::Signed {
+            transaction_hashes: vec![HASH.parse().unwrap(), HASH.parse().unwrap()],
+            meta: Some("order-1".to_string()),
+        })
+    );
+    assert_eq!(
+        WalletCallback::from_query("errorCode=userRejected&errorMessage=User+rejected"),
+        Some(WalletCallback::Failed {
+            code: "userRejected".to_string(),
+            message: Some("User rejected".to_string()),
+            meta: None,
+        })
+    );
+    assert_eq!(WalletCallback::from_query("foo=bar"), None);
+}
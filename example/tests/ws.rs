@@ -0,0 +1,232 @@
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use near_openapi_client::retry::Backoff;
+use near_openapi_client::ws::{ConnectionEvent, WsClient, WsError};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+const HASH: &str = "11111111111111111111111111111111";
+
+fn header(height: u64) -> serde_json::Value {
+    serde_json::json!({
+        "approvals": [],
+        "block_merkle_root": HASH,
+        "challenges_result": [],
+        "challenges_root": HASH,
+        "chunk_headers_root": HASH,
+        "chunk_mask": [true],
+        "chunk_receipts_root": HASH,
+        "chunk_tx_root": HASH,
+        "chunks_included": 1,
+        "epoch_id": HASH,
+        "gas_price": "100000000",
+        "hash": HASH,
+        "height": height,
+        "last_ds_final_block": HASH,
+        "last_final_block": HASH,
+        "latest_protocol_version": 80,
+        "next_bp_hash": HASH,
+        "next_epoch_id": HASH,
+        "outcome_root": HASH,
+        "prev_hash": HASH,
+        "prev_state_root": HASH,
+        "random_value": HASH,
+        "rent_paid": "0",
+        "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+        "timestamp": 1,
+        "timestamp_nanosec": "1",
+        "total_supply": "0",
+        "validator_proposals": [],
+        "validator_reward": "0",
+    })
+}
+
+fn notification(result: serde_json::Value) -> Message {
+    Message::text(
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "subscription",
+            "params": {"subscription": "0x1", "result": result},
+        })
+        .to_string(),
+    )
+}
+
+/// Accepts one connection per entry of `connections`, answers its `subscribe`
+/// request with `response` and sends `messages` before closing it. Returns the
+/// URL and the params of the `subscribe` requests.
+async fn serve(
+    connections: Vec<(serde_json::Value, Vec<Message>)>,
+) -> (
+    String,
+    tokio::sync::mpsc::UnboundedReceiver<serde_json::Value>,
+) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let (params_tx, params_rx) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        for (response, messages) in connections {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let request = socket.next().await.unwrap().unwrap();
+            let request: serde_json::Value =
+                serde_json::from_str(request.to_text().unwrap()).unwrap();
+            assert_eq!(request["method"], "subscribe");
+            params_tx.send(request["params"].clone()).unwrap();
+            socket
+                .send(Message::text(response.to_string()))
+                .await
+                .unwrap();
+            for message in messages {
+                socket.send(message).await.unwrap();
+            }
+            socket.close(None).await.unwrap();
+        }
+    });
+    (url, params_rx)
+}
+
+fn accepted() -> serde_json::Value {
+    serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "result": "0x1"})
+}
+
+#[tokio::test]
+async fn test_blocks_resubscribe_after_disconnect() {
+    let (url, mut params) = serve(vec![
+        (
+            accepted(),
+            vec![notification(header(1)), notification(header(2))],
+        ),
+        (
+            accepted(),
+            vec![notification(header(2)), notification(header(3))],
+        ),
+    ])
+    .await;
+    let client = WsClient::new(url).backoff(Backoff::Fixed(Duration::from_millis(10)));
+
+    let heights: Vec<u64> = client
+        .subscribe_blocks()
+        .take(3)
+        .map(|header| header.unwrap().height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![1, 2, 3]);
+    assert_eq!(
+        params.recv().await.unwrap(),
+        serde_json::json!(["new_blocks"])
+    );
+    assert_eq!(
+        params.recv().await.unwrap(),
+        serde_json::json!(["new_blocks", {"from_block_hash": HASH}])
+    );
+}
+
+#[tokio::test]
+async fn test_tx_status_subscription() {
+    let outcome = serde_json::json!({
+        "executor_id": "bob.near",
+        "gas_burnt": 1,
+        "logs": [],
+        "receipt_ids": [],
+        "status": {"SuccessValue": ""},
+        "tokens_burnt": "0",
+    });
+    let (url, mut params) = serve(vec![
+        (
+            serde_json::json!({"jsonrpc": "2.0", "id": "dontcare", "error": {"name": "UNSUPPORTED"}}),
+            vec![],
+        ),
+        (accepted(), vec![notification(outcome)]),
+    ])
+    .await;
+    let client = WsClient::new(url).backoff(Backoff::Fixed(Duration::from_millis(10)));
+
+    let outcomes: Vec<_> = client
+        .subscribe_tx_status(HASH.parse().unwrap(), "alice.near".parse().unwrap())
+        .take(2)
+        .collect()
+        .await;
+    assert!(matches!(outcomes[0], Err(WsError::Subscribe(_))));
+    assert_eq!(
+        outcomes[1].as_ref().unwrap().executor_id.as_str(),
+        "bob.near"
+    );
+    assert_eq!(
+        params.recv().await.unwrap(),
+        serde_json::json!(["tx_status", {"tx_hash": HASH, "sender_account_id": "alice.near"}])
+    );
+}
+
+#[tokio::test]
+async fn test_blocks_resume_after_last_block() {
+    let (url, mut params) = serve(vec![
+        (accepted(), vec![notification(header(1))]),
+        (accepted(), vec![notification(header(2))]),
+    ])
+    .await;
+    let client = WsClient::new(url).backoff(Backoff::Fixed(Duration::from_millis(10)));
+    let mut events = client.events();
+
+    let heights: Vec<u64> = client
+        .subscribe_blocks()
+        .take(2)
+        .map(|header| header.unwrap().height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![1, 2]);
+    assert_eq!(
+        params.recv().await.unwrap(),
+        serde_json::json!(["new_blocks"])
+    );
+    assert_eq!(
+        params.recv().await.unwrap(),
+        serde_json::json!(["new_blocks", {"from_block_hash": HASH}])
+    );
+    assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Connected);
+    assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Disconnected);
+    assert_eq!(
+        events.recv().await.unwrap(),
+        ConnectionEvent::Resumed {
+            block_hash: Some(HASH.parse().unwrap())
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_reconnect_when_pings_are_unanswered() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        for height in 1..=2 {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            socket.next().await.unwrap().unwrap();
+            socket
+                .send(Message::text(accepted().to_string()))
+                .await
+                .unwrap();
+            socket.send(notification(header(height))).await.unwrap();
+            // Stops reading, so pings are never answered.
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                drop(socket);
+            });
+        }
+    });
+    let client = WsClient::new(url)
+        .backoff(Backoff::Fixed(Duration::from_millis(10)))
+        .keepalive(Duration::from_millis(50));
+    let mut events = client.events();
+
+    let heights: Vec<u64> = client
+        .subscribe_blocks()
+        .take(2)
+        .map(|header| header.unwrap().height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![1, 2]);
+    assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Connected);
+    assert_eq!(events.recv().await.unwrap(), ConnectionEvent::Disconnected);
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+use near_openapi_client::jsonrpc::RpcError;
+use near_openapi_client::methods;
+use near_openapi_client::testing::MockRpc;
+use near_openapi_client::types;
+use near_openapi_client::types::borrowed::{
+    ActionViewRef, ExecutionStatusViewRef, FinalExecutionStatusRef, ReceiptEnumViewRef,
+    RpcChunkResponseRef, RpcTransactionResponseRef, ToOwnedView,
+};
+
+fn golden(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_chunk_borrows_account_ids_and_matches_owned() {
+    let json = golden("chunk.json");
+    let chunk: RpcChunkResponseRef<'_> = serde_json::from_str(&json).unwrap();
+    let owned: types::RpcChunkResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(chunk.author.as_str(), owned.author.as_str());
+    assert_eq!(chunk.transactions.len(), owned.transactions.len());
+    assert_eq!(chunk.receipts.len(), owned.receipts.len());
+    assert_eq!(chunk.to_owned_view().unwrap(), owned);
+}
+
+#[test]
+fn test_tx_borrows_unescaped_strings() {
+    let json = golden("tx.json");
+    let tx: RpcTransactionResponseRef<'_> = serde_json::from_str(&json).unwrap();
+    let owned: types::RpcTransactionResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(tx.to_owned_view().unwrap(), owned);
+
+    let ReceiptEnumViewRef::Action(receipt) = &tx.receipts[0].receipt else {
+        panic!("{:?}", tx.receipts[0].receipt);
+    };
+    let ActionViewRef::FunctionCall(call) = &receipt.actions[0] else {
+        panic!("{:?}", receipt.actions[0]);
+    };
+    assert!(matches!(call.method_name, Cow::Borrowed("ft_transfer")));
+    assert!(matches!(
+        tx.status,
+        FinalExecutionStatusRef::SuccessValue("")
+    ));
+
+    // The log of the event escapes the quotes of its JSON, so it is unescaped
+    // into an owned string.
+    let logs = &tx.receipts_outcome[0].outcome.logs;
+    assert!(matches!(&logs[0], Cow::Owned(log) if log.starts_with("EVENT_JSON:{\"standard\"")));
+}
+
+#[test]
+fn test_failures_are_kept_as_json() {
+    let json = golden("tx_failure.json");
+    let tx: RpcTransactionResponseRef<'_> = serde_json::from_str(&json).unwrap();
+    let owned: types::RpcTransactionResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(tx.to_owned_view().unwrap(), owned);
+    let ExecutionStatusViewRef::Failure(error) = tx.receipts_outcome[0].outcome.status else {
+        panic!("{:?}", tx.receipts_outcome[0].outcome.status);
+    };
+    let error: types::TxExecutionError = serde_json::from_str(error.get()).unwrap();
+    assert!(matches!(error, types::TxExecutionError::ActionError(_)));
+}
+
+#[tokio::test]
+async fn test_request_raw_decodes_borrowed_response() {
+    let owned: types::RpcChunkResponse = serde_json::from_str(&golden("chunk.json")).unwrap();
+    let request = types::RpcChunkRequest::BlockShardId {
+        block_id: types::BlockId::BlockHeight(1),
+        shard_id: types::ShardId(0),
+    };
+    let mock = MockRpc::start();
+    mock.expect::<methods::Chunk>()
+        .with_params(&request)
+        .return_ok(owned.clone());
+    mock.expect::<methods::Chunk>().return_error(
+        types::ErrorWrapperForRpcChunkError::HandlerError(types::RpcChunkError::UnknownBlock(
+            serde_json::Map::new(),
+        )),
+    );
+    let client = mock.client();
+
+    let response = client
+        .request_raw::<methods::Chunk>(&request)
+        .await
+        .unwrap();
+    let chunk: RpcChunkResponseRef<'_> = response.decode().unwrap();
+    assert_eq!(chunk.to_owned_view().unwrap(), owned);
+
+    let request = types::RpcChunkRequest::BlockShardId {
+        block_id: types::BlockId::BlockHeight(2),
+        shard_id: types::ShardId(0),
+    };
+    let response = client
+        .request_raw::<methods::Chunk>(&request)
+        .await
+        .unwrap();
+    let err = response.decode::<RpcChunkResponseRef<'_>>().unwrap_err();
+    assert!(matches!(err, RpcError::Rpc(_)), "{:?}", err);
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use near_openapi_types::lenient::decode_leniently;
+use near_openapi_types::round_trip::check_json;
+use near_openapi_types::{RpcQueryError, RpcQueryResponse};
+
+fuzz_target!(|data: &[u8]| {
+    check_json::<RpcQueryResponse>(data).unwrap();
+    check_json::<RpcQueryError>(data).unwrap();
+    decode_leniently(|| check_json::<RpcQueryResponse>(data)).0.unwrap();
+    decode_leniently(|| check_json::<RpcQueryError>(data)).0.unwrap();
+});
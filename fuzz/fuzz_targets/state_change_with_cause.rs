@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use near_openapi_types::StateChangeWithCauseView;
+use near_openapi_types::lenient::decode_leniently;
+use near_openapi_types::round_trip::check_json;
+
+fuzz_target!(|data: &[u8]| {
+    check_json::<StateChangeWithCauseView>(data).unwrap();
+    // Unknown variants nested anywhere must round trip too.
+    decode_leniently(|| check_json::<StateChangeWithCauseView>(data)).0.unwrap();
+});
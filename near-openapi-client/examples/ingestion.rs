@@ -0,0 +1,96 @@
+//! Measures the blocks per second a node serves to a `BlockStream` for
+//! combinations of the settings of `IngestionConfig` and of decoding whole
+//! blocks or only headers.
+//!
+//! ```text
+//! cargo run --release --example ingestion --features bench -- <rpc-url> <start-height> [blocks]
+//! ```
+//!
+//! Start far enough below the final head for all runs to stay behind it,
+//! e.g. a day back, since blocks at the head arrive at the pace of the chain.
+//! Every run reads the same heights, so a node caching blocks may favor the
+//! later runs; the first run repeats at the end to show by how much.
+use std::time::{Duration, Instant};
+
+use futures_util::stream::{Stream, StreamExt};
+use near_openapi_client::block::{BlockStream, IngestionConfig};
+use near_openapi_client::rpc::RpcClient;
+use near_openapi_client::types::{BlockId, Finality};
+
+/// Most failed requests in a run before it is given up.
+const MAX_ERRORS: usize = 10;
+
+#[derive(Clone, Copy, Debug)]
+enum Decoding {
+    Block,
+    Header,
+}
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (url, start) = match (args.first(), args.get(1).and_then(|arg| arg.parse().ok())) {
+        (Some(url), Some(start)) => (url.clone(), start),
+        _ => {
+            eprintln!("usage: ingestion <rpc-url> <start-height> [blocks]");
+            std::process::exit(2);
+        }
+    };
+    let blocks = args.get(2).and_then(|arg| arg.parse().ok()).unwrap_or(200);
+    let client = RpcClient::new(&url);
+
+    let mut runs = Vec::new();
+    for concurrency in [1, 4, 16, 32] {
+        for cache_size in [concurrency, concurrency * 4] {
+            runs.push((concurrency, cache_size, Decoding::Block));
+        }
+    }
+    runs.push((16, 64, Decoding::Header));
+    runs.push(runs[0]);
+
+    println!(
+        "{:>11} {:>10} {:>8} {:>10} {:>8}",
+        "concurrency", "cache_size", "decoding", "blocks/s", "errors"
+    );
+    for (concurrency, cache_size, decoding) in runs {
+        let config = IngestionConfig {
+            concurrency,
+            cache_size,
+            ..Default::default()
+        };
+        let stream = BlockStream::new(client.clone(), BlockId::BlockHeight(start), Finality::Final)
+            .config(config);
+        let (elapsed, errors) = match decoding {
+            Decoding::Block => run(stream.into_stream(), blocks).await,
+            Decoding::Header => run(stream.into_header_stream(), blocks).await,
+        };
+        let rate = if errors > MAX_ERRORS {
+            "failed".to_string()
+        } else {
+            format!("{:.1}", blocks as f64 / elapsed.as_secs_f64())
+        };
+        println!(
+            "{:>11} {:>10} {:>8} {:>10} {:>8}",
+            concurrency,
+            cache_size,
+            format!("{:?}", decoding).to_lowercase(),
+            rate,
+            errors
+        );
+    }
+}
+
+/// Time to take `blocks` blocks from `stream`, and the errors it yielded.
+async fn run<T, E>(stream: impl Stream<Item = Result<T, E>>, blocks: usize) -> (Duration, usize) {
+    let started = Instant::now();
+    let mut stream = std::pin::pin!(stream);
+    let (mut taken, mut errors) = (0, 0);
+    while taken < blocks && errors <= MAX_ERRORS {
+        match stream.next().await {
+            Some(Ok(_)) => taken += 1,
+            Some(Err(_)) => errors += 1,
+            None => break,
+        }
+    }
+    (started.elapsed(), errors)
+}
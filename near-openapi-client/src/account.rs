@@ -0,0 +1,182 @@
+//! Checking whether an account exists, telling an account that was never
+//! created from one that was deleted recently.
+//!
+//! ```rust,ignore
+//! match rpc_client.account_exists_within(&account_id, 100).await? {
+//!     AccountExistence::Exists(account) => println!("balance {}", account.amount),
+//!     AccountExistence::NeverExisted => println!("{} is available", account_id),
+//!     AccountExistence::Deleted(deletion) => {
+//!         println!("deleted at {}, funds sent to {:?}", deletion.block_height, deletion.beneficiary_id)
+//!     }
+//! }
+//! ```
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::state_changes::{ChangesType, StateChange, StateChangesRequest};
+use crate::types::{
+    AccountId, AccountView, ActionView, BlockId, BlockReference, CryptoHash,
+    ErrorWrapperForRpcQueryError, ErrorWrapperForRpcReceiptError,
+    ErrorWrapperForRpcStateChangesError, Finality, ReceiptEnumView, RpcQueryError, RpcQueryRequest,
+    RpcReceiptRequest, RpcStateChangesError, StateChangeCauseView,
+    ViewAccountByFinalityRequestType,
+};
+
+/// Whether an account exists, returned by [`RpcClient::account_exists`].
+#[derive(Clone, Debug)]
+pub enum AccountExistence {
+    /// The account exists in the latest final block.
+    Exists(AccountView),
+    /// The account does not exist, and no deletion of it was found in the
+    /// blocks searched.
+    NeverExisted,
+    /// The account does not exist and was deleted in one of the blocks
+    /// searched.
+    Deleted(AccountDeletion),
+}
+
+/// Deletion of an account found in its state changes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountDeletion {
+    pub block_hash: CryptoHash,
+    pub block_height: u64,
+    /// The receipt that deleted the account.
+    pub receipt_id: Option<CryptoHash>,
+    /// The account that received the remaining balance, if the receipt could
+    /// still be fetched.
+    pub beneficiary_id: Option<AccountId>,
+}
+
+/// Error of [`RpcClient::account_exists`].
+#[derive(Debug)]
+pub enum AccountExistsError {
+    /// The `view_account` query failed with something else than
+    /// `UNKNOWN_ACCOUNT`.
+    Query(RpcError<ErrorWrapperForRpcQueryError>),
+    /// The changes of the account in a block searched could not be fetched.
+    Changes(RpcError<ErrorWrapperForRpcStateChangesError>),
+    /// The receipt that deleted the account could not be fetched.
+    Receipt(RpcError<ErrorWrapperForRpcReceiptError>),
+}
+
+impl std::fmt::Display for AccountExistsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Query(err) => write!(f, "view_account query failed: {}", err),
+            Self::Changes(err) => write!(f, "failed to fetch account changes: {}", err),
+            Self::Receipt(err) => write!(f, "failed to fetch deletion receipt: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AccountExistsError {}
+
+impl RpcClient {
+    /// Whether `account_id` exists in the latest final block, with a single
+    /// query. A deleted account is reported as
+    /// [`NeverExisted`](AccountExistence::NeverExisted); use
+    /// [`account_exists_within`](Self::account_exists_within) to tell them
+    /// apart.
+    pub async fn account_exists(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<AccountExistence, AccountExistsError> {
+        self.account_exists_within(account_id, 0).await
+    }
+
+    /// Whether `account_id` exists in the latest final block. If it does
+    /// not, the changes of the account in the last `blocks` blocks up to that
+    /// one are searched, newest first, for its deletion and the beneficiary
+    /// of the receipt that deleted it. Heights without a block are skipped.
+    pub async fn account_exists_within(
+        &self,
+        account_id: &AccountId,
+        blocks: u64,
+    ) -> Result<AccountExistence, AccountExistsError> {
+        let request = RpcQueryRequest::ViewAccountByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            request_type: ViewAccountByFinalityRequestType::ViewAccount,
+        };
+        let height = match self.call::<_, AccountView, _>("query", &request).await {
+            Ok(account) => return Ok(AccountExistence::Exists(account)),
+            Err(RpcError::Rpc(ErrorWrapperForRpcQueryError::HandlerError(
+                RpcQueryError::UnknownAccount { block_height, .. },
+            ))) => block_height,
+            Err(err) => return Err(AccountExistsError::Query(err)),
+        };
+        for height in (0..=height).rev().take(blocks as usize) {
+            if let Some(deletion) = self.find_deletion(account_id, height).await? {
+                return Ok(AccountExistence::Deleted(deletion));
+            }
+        }
+        Ok(AccountExistence::NeverExisted)
+    }
+
+    /// Deletion of `account_id` in the block at `height`, if any.
+    async fn find_deletion(
+        &self,
+        account_id: &AccountId,
+        height: u64,
+    ) -> Result<Option<AccountDeletion>, AccountExistsError> {
+        let request = StateChangesRequest::new(
+            BlockReference::BlockId(BlockId::BlockHeight(height)),
+            ChangesType::AccountChanges {
+                account_ids: vec![account_id.clone()],
+            },
+        );
+        let changes = match self.state_changes(&request).await {
+            Ok(changes) => changes,
+            Err(RpcError::Rpc(ErrorWrapperForRpcStateChangesError::HandlerError(
+                RpcStateChangesError::UnknownBlock(_),
+            ))) => return Ok(None),
+            Err(err) => return Err(AccountExistsError::Changes(err)),
+        };
+        let deletion = changes.changes.into_iter().rev().find(|change| {
+            matches!(&change.change, StateChange::AccountDeletion { .. })
+                && change.change.account_id() == account_id
+        });
+        let Some(change) = deletion else {
+            return Ok(None);
+        };
+        let receipt_id = match change.cause {
+            StateChangeCauseView::ReceiptProcessing { receipt_hash }
+            | StateChangeCauseView::ActionReceiptProcessingStarted { receipt_hash } => {
+                Some(receipt_hash)
+            }
+            _ => None,
+        };
+        let beneficiary_id = match &receipt_id {
+            Some(receipt_id) => self.beneficiary(receipt_id).await?,
+            None => None,
+        };
+        Ok(Some(AccountDeletion {
+            block_hash: changes.block_hash,
+            block_height: height,
+            receipt_id,
+            beneficiary_id,
+        }))
+    }
+
+    /// Beneficiary of the `DeleteAccount` action of a receipt, or `None` if
+    /// the node no longer has the receipt.
+    async fn beneficiary(
+        &self,
+        receipt_id: &CryptoHash,
+    ) -> Result<Option<AccountId>, AccountExistsError> {
+        let request = RpcReceiptRequest {
+            receipt_id: *receipt_id,
+        };
+        let receipt = match self.experimental_receipt(&request).await {
+            Ok(receipt) => receipt,
+            Err(RpcError::Rpc(_)) => return Ok(None),
+            Err(err) => return Err(AccountExistsError::Receipt(err)),
+        };
+        let ReceiptEnumView::Action { actions, .. } = receipt.receipt else {
+            return Ok(None);
+        };
+        Ok(actions.into_iter().find_map(|action| match action {
+            ActionView::DeleteAccount { beneficiary_id } => Some(beneficiary_id),
+            _ => None,
+        }))
+    }
+}
@@ -0,0 +1,574 @@
+//! Paying many accounts in NEAR or a fungible token, e.g. for an airdrop.
+//!
+//! An [`Airdrop`] splits the recipients into batches: one transaction per
+//! recipient for NEAR, whose transfers go to the receiver of the
+//! transaction, and `ft_transfer` calls grouped up to the action and gas
+//! limits of a transaction for a token. The calls of a batch run in the same
+//! receipt, so they succeed or fail together. Batches are sent concurrently
+//! with nonces from a [`NonceManager`], and rebuilt with a fresh nonce when
+//! they expire or their nonce was taken.
+//!
+//! With a checkpoint file, every batch is recorded before it is sent and
+//! once its outcome is known, so an interrupted run can be started again:
+//! batches that succeeded are skipped, batches whose outcome is unknown are
+//! sent again as the same signed transaction, which cannot execute twice,
+//! and the others are rebuilt.
+//!
+//! ```rust,ignore
+//! let report = Airdrop::new(AirdropAsset::FungibleToken { contract_id }, recipients)
+//!     .concurrency(8)
+//!     .checkpoint("airdrop.json")
+//!     .run(&rpc_client, &signer_id, &secret_key.public_key(), |tx| {
+//!         let signed = tx.sign(&secret_key).unwrap();
+//!         async move { signed }
+//!     })
+//!     .await?;
+//! for outcome in report.failed() {
+//!     println!("{} was not paid: {:?}", outcome.account_id, outcome.status);
+//! }
+//! ```
+//!
+//! Recipients of a token must already be registered with the contract, see
+//! `storage_deposit` of NEP-145.
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use futures_util::StreamExt;
+
+use crate::block::HeaderOnly;
+use crate::jsonrpc::RpcError;
+use crate::nonce::NonceManager;
+use crate::resubmit::invalid_tx_error;
+use crate::rpc::RpcClient;
+use crate::types::tx::{self, Transaction, TransactionBuilder};
+use crate::types::{
+    self, AccountId, CryptoHash, ErrorWrapperForRpcTransactionError, FinalExecutionStatus,
+    Finality, FunctionArgs, InvalidTxError, NearGas, NearToken, PublicKey, RpcBlockRequest,
+    RpcSendTransactionRequest, RpcTransactionResponse, RpcTransactionStatusRequest,
+    TxExecutionStatus,
+};
+
+/// Number of times a batch is built when it expires or its nonce was taken.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// What an [`Airdrop`] pays.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub enum AirdropAsset {
+    /// NEAR, in yoctoNEAR.
+    Near,
+    /// A NEP-141 fungible token, in its smallest unit.
+    FungibleToken { contract_id: AccountId },
+}
+
+/// Transfers sent in one transaction.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct AirdropBatch {
+    /// Position of the batch in [`Airdrop::batches`].
+    pub index: usize,
+    pub receiver_id: AccountId,
+    pub transfers: Vec<(AccountId, u128)>,
+}
+
+/// Payment of `amount` to each of a list of accounts.
+///
+/// By default a token batch holds up to 100 `ft_transfer` calls of 10 Tgas
+/// each within 300 Tgas, and 4 batches are sent at a time.
+#[derive(Clone, Debug)]
+pub struct Airdrop {
+    asset: AirdropAsset,
+    recipients: Vec<(AccountId, u128)>,
+    max_actions: usize,
+    max_gas: NearGas,
+    gas_per_transfer: NearGas,
+    concurrency: usize,
+    checkpoint: Option<PathBuf>,
+}
+
+impl Airdrop {
+    pub fn new(asset: AirdropAsset, recipients: Vec<(AccountId, u128)>) -> Self {
+        Self {
+            asset,
+            recipients,
+            max_actions: 100,
+            max_gas: NearGas::from_tgas(300),
+            gas_per_transfer: NearGas::from_tgas(10),
+            concurrency: 4,
+            checkpoint: None,
+        }
+    }
+
+    /// Most transfers in a token batch.
+    pub fn max_actions(mut self, max_actions: usize) -> Self {
+        self.max_actions = max_actions.max(1);
+        self
+    }
+
+    /// Most gas attached to the calls of a token batch.
+    pub fn max_gas(mut self, max_gas: NearGas) -> Self {
+        self.max_gas = max_gas;
+        self
+    }
+
+    /// Gas attached to each `ft_transfer` call.
+    pub fn gas_per_transfer(mut self, gas: NearGas) -> Self {
+        self.gas_per_transfer = gas;
+        self
+    }
+
+    /// Most batches waiting for their outcome at a time.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Records the progress of the airdrop in the JSON file at `path`, and
+    /// resumes from it if it exists.
+    pub fn checkpoint(mut self, path: impl Into<PathBuf>) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+
+    /// The transactions the recipients are paid with, in order.
+    pub fn batches(&self) -> Vec<AirdropBatch> {
+        let size = match &self.asset {
+            AirdropAsset::Near => 1,
+            AirdropAsset::FungibleToken { .. } => {
+                let by_gas = self.max_gas.as_gas() / self.gas_per_transfer.as_gas().max(1);
+                self.max_actions.min(by_gas as usize).max(1)
+            }
+        };
+        self.recipients
+            .chunks(size)
+            .enumerate()
+            .map(|(index, transfers)| AirdropBatch {
+                index,
+                receiver_id: match &self.asset {
+                    AirdropAsset::Near => transfers[0].0.clone(),
+                    AirdropAsset::FungibleToken { contract_id } => contract_id.clone(),
+                },
+                transfers: transfers.to_vec(),
+            })
+            .collect()
+    }
+
+    /// Pays every recipient with transactions of `signer_id` signed with
+    /// `public_key` by `sign`, returning the outcome of each recipient in
+    /// order. Only failures to read or write the checkpoint end the run
+    /// early.
+    pub async fn run<F, Fut>(
+        &self,
+        client: &RpcClient,
+        signer_id: &AccountId,
+        public_key: &PublicKey,
+        sign: F,
+    ) -> Result<AirdropReport, AirdropError>
+    where
+        F: Fn(Transaction) -> Fut,
+        Fut: Future<Output = tx::SignedTransaction>,
+    {
+        let batches = self.batches();
+        let plan = format!(
+            "{:016x}",
+            crate::replay::fnv1a(&serde_json::to_vec(&(&self.asset, &batches)).unwrap())
+        );
+        let checkpoint = Recorder::open(self.checkpoint.clone(), plan)?;
+        let sender = Sender {
+            client,
+            nonces: NonceManager::new(client.clone()),
+            signer_id,
+            public_key,
+            sign: &sign,
+            checkpoint: &checkpoint,
+        };
+        let pending: Vec<_> = batches
+            .iter()
+            .filter_map(|batch| match checkpoint.state(batch.index) {
+                Some(BatchState::Succeeded { .. }) => None,
+                state => Some((batch, state)),
+            })
+            .collect();
+        let mut sent = futures_util::stream::iter(pending)
+            .map(|(batch, state)| sender.send(self, batch, state))
+            .buffer_unordered(self.concurrency);
+        while let Some(result) = sent.next().await {
+            result?;
+        }
+        drop(sent);
+        let states = std::mem::take(&mut checkpoint.state.lock().unwrap().batches);
+        let outcomes = batches
+            .into_iter()
+            .flat_map(|batch| {
+                let status = match states.get(&batch.index) {
+                    Some(BatchState::Succeeded { tx_hash }) => {
+                        TransferStatus::Transferred { tx_hash: *tx_hash }
+                    }
+                    Some(BatchState::Failed { tx_hash, error }) => TransferStatus::Failed {
+                        tx_hash: *tx_hash,
+                        error: error.clone(),
+                    },
+                    Some(BatchState::Pending { tx_hash, .. }) => {
+                        TransferStatus::Unknown { tx_hash: *tx_hash }
+                    }
+                    None => unreachable!("every batch is sent"),
+                };
+                batch
+                    .transfers
+                    .into_iter()
+                    .map(move |(account_id, amount)| RecipientOutcome {
+                        account_id,
+                        amount,
+                        batch: batch.index,
+                        status: status.clone(),
+                    })
+            })
+            .collect();
+        Ok(AirdropReport { outcomes })
+    }
+
+    /// Transaction paying the recipients of `batch`.
+    fn transaction(
+        &self,
+        batch: &AirdropBatch,
+        signer_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> TransactionBuilder {
+        let builder =
+            TransactionBuilder::new(signer_id.clone(), *public_key, batch.receiver_id.clone());
+        match &self.asset {
+            AirdropAsset::Near => batch
+                .transfers
+                .iter()
+                .fold(builder, |builder, (_, amount)| {
+                    builder.transfer(NearToken::from_yoctonear(*amount))
+                }),
+            AirdropAsset::FungibleToken { .. } => {
+                batch
+                    .transfers
+                    .iter()
+                    .fold(builder, |builder, (receiver_id, amount)| {
+                        let args = serde_json::json!({
+                            "receiver_id": receiver_id,
+                            "amount": amount.to_string(),
+                        });
+                        builder.function_call(
+                            "ft_transfer",
+                            FunctionArgs::from_json(&args).expect("arguments encode to JSON"),
+                            self.gas_per_transfer,
+                            NearToken::from_yoctonear(1),
+                        )
+                    })
+            }
+        }
+    }
+}
+
+/// Outcome of an [`Airdrop`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AirdropReport {
+    /// Outcome of each recipient, in the order they were given.
+    pub outcomes: Vec<RecipientOutcome>,
+}
+
+impl AirdropReport {
+    /// Recipients that were not paid, or may not have been.
+    pub fn failed(&self) -> impl Iterator<Item = &RecipientOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| !matches!(outcome.status, TransferStatus::Transferred { .. }))
+    }
+}
+
+/// Whether a recipient of an [`Airdrop`] was paid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipientOutcome {
+    pub account_id: AccountId,
+    pub amount: u128,
+    /// Index of the batch that paid the recipient.
+    pub batch: usize,
+    pub status: TransferStatus,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferStatus {
+    /// The transaction of the batch succeeded.
+    Transferred { tx_hash: CryptoHash },
+    /// The batch was not paid: it could not be built or sent, the node
+    /// rejected it, or its receipt failed. It is sent again when the airdrop
+    /// is resumed.
+    Failed {
+        tx_hash: Option<CryptoHash>,
+        error: String,
+    },
+    /// The transaction was sent but its outcome is unknown, e.g. because the
+    /// connection failed. Resuming the airdrop sends it again to find out.
+    Unknown { tx_hash: CryptoHash },
+}
+
+/// Error ending [`Airdrop::run`].
+#[derive(Debug)]
+pub enum AirdropError {
+    /// The checkpoint file could not be read or written.
+    Checkpoint(PathBuf, std::io::Error),
+    /// The checkpoint file was written by an airdrop with other recipients,
+    /// amounts or batches.
+    CheckpointMismatch(PathBuf),
+}
+
+impl std::fmt::Display for AirdropError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Checkpoint(path, err) => {
+                write!(f, "failed to access checkpoint {}: {}", path.display(), err)
+            }
+            Self::CheckpointMismatch(path) => write!(
+                f,
+                "checkpoint {} belongs to another airdrop",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AirdropError {}
+
+/// Progress of a batch, as recorded in the checkpoint.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+enum BatchState {
+    /// Sent with an unknown outcome.
+    Pending {
+        tx_hash: CryptoHash,
+        signed_tx: types::SignedTransaction,
+    },
+    Succeeded {
+        tx_hash: CryptoHash,
+    },
+    Failed {
+        tx_hash: Option<CryptoHash>,
+        error: String,
+    },
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+struct Checkpoint {
+    /// Hash of the batches, to refuse resuming another airdrop.
+    plan: String,
+    batches: BTreeMap<usize, BatchState>,
+}
+
+/// The checkpoint of a run, written to its file on every change.
+struct Recorder {
+    path: Option<PathBuf>,
+    state: Mutex<Checkpoint>,
+}
+
+impl Recorder {
+    fn open(path: Option<PathBuf>, plan: String) -> Result<Self, AirdropError> {
+        let checkpoint = match &path {
+            Some(path) if path.exists() => {
+                let file = std::fs::read(path)
+                    .map_err(|err| AirdropError::Checkpoint(path.clone(), err))?;
+                let checkpoint: Checkpoint = serde_json::from_slice(&file)
+                    .map_err(|err| AirdropError::Checkpoint(path.clone(), err.into()))?;
+                if checkpoint.plan != plan {
+                    return Err(AirdropError::CheckpointMismatch(path.clone()));
+                }
+                checkpoint
+            }
+            _ => Checkpoint {
+                plan,
+                batches: BTreeMap::new(),
+            },
+        };
+        Ok(Self {
+            path,
+            state: Mutex::new(checkpoint),
+        })
+    }
+
+    fn state(&self, index: usize) -> Option<BatchState> {
+        self.state.lock().unwrap().batches.get(&index).cloned()
+    }
+
+    fn record(&self, index: usize, state: BatchState) -> Result<(), AirdropError> {
+        let mut checkpoint = self.state.lock().unwrap();
+        checkpoint.batches.insert(index, state);
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        // Written aside and renamed so an interrupted write never loses the
+        // previous checkpoint.
+        let file = serde_json::to_vec_pretty(&*checkpoint).expect("checkpoints encode to JSON");
+        let temporary = path.with_extension("tmp");
+        std::fs::write(&temporary, file)
+            .and_then(|()| std::fs::rename(&temporary, path))
+            .map_err(|err| AirdropError::Checkpoint(path.clone(), err))
+    }
+}
+
+/// Sends the batches of a run.
+struct Sender<'a, F> {
+    client: &'a RpcClient,
+    nonces: NonceManager,
+    signer_id: &'a AccountId,
+    public_key: &'a PublicKey,
+    sign: &'a F,
+    checkpoint: &'a Recorder,
+}
+
+impl<F, Fut> Sender<'_, F>
+where
+    F: Fn(Transaction) -> Fut,
+    Fut: Future<Output = tx::SignedTransaction>,
+{
+    /// Sends `batch` until its outcome is known or it cannot be rebuilt,
+    /// starting from the signed transaction of a pending `state`.
+    async fn send(
+        &self,
+        airdrop: &Airdrop,
+        batch: &AirdropBatch,
+        state: Option<BatchState>,
+    ) -> Result<(), AirdropError> {
+        let mut pending = match state {
+            Some(BatchState::Pending { tx_hash, signed_tx }) => Some((tx_hash, signed_tx)),
+            _ => None,
+        };
+        let mut attempt = 0;
+        let state = loop {
+            attempt += 1;
+            let resent = pending.is_some();
+            let (tx_hash, signed_tx) = match pending.take() {
+                Some(pending) => pending,
+                None => match self.build(airdrop, batch).await {
+                    Ok((tx_hash, signed_tx)) => {
+                        self.checkpoint.record(
+                            batch.index,
+                            BatchState::Pending {
+                                tx_hash,
+                                signed_tx: signed_tx.clone(),
+                            },
+                        )?;
+                        (tx_hash, signed_tx)
+                    }
+                    Err(error) => {
+                        break BatchState::Failed {
+                            tx_hash: None,
+                            error,
+                        };
+                    }
+                },
+            };
+            let request = RpcSendTransactionRequest {
+                signed_tx_base64: signed_tx.clone(),
+                wait_until: TxExecutionStatus::ExecutedOptimistic,
+            };
+            let err = match self.client.send_tx(&request).await {
+                Ok(response) => break outcome(tx_hash, signed_tx, &response),
+                Err(err) => err,
+            };
+            match invalid_tx_error(&err) {
+                // A transaction sent before may have taken its own nonce.
+                Some(InvalidTxError::InvalidNonce { .. }) if resent => {
+                    if let Some(response) = self.find(&tx_hash).await {
+                        break outcome(tx_hash, signed_tx, &response);
+                    }
+                }
+                Some(InvalidTxError::Expired | InvalidTxError::InvalidNonce { .. }) => {}
+                Some(reason) => {
+                    break BatchState::Failed {
+                        tx_hash: Some(tx_hash),
+                        error: format!("{:?}", reason),
+                    };
+                }
+                None if rejected(&err) => {
+                    break BatchState::Failed {
+                        tx_hash: Some(tx_hash),
+                        error: err.to_string(),
+                    };
+                }
+                None => break BatchState::Pending { tx_hash, signed_tx },
+            }
+            if attempt >= MAX_ATTEMPTS {
+                break BatchState::Failed {
+                    tx_hash: Some(tx_hash),
+                    error: err.to_string(),
+                };
+            }
+            self.nonces.invalidate(self.signer_id, self.public_key);
+        };
+        self.checkpoint.record(batch.index, state)
+    }
+
+    /// Builds and signs the transaction of `batch` with the next nonce.
+    async fn build(
+        &self,
+        airdrop: &Airdrop,
+        batch: &AirdropBatch,
+    ) -> Result<(CryptoHash, types::SignedTransaction), String> {
+        let nonce = self
+            .nonces
+            .next_nonce(self.signer_id, self.public_key)
+            .await
+            .map_err(|err| err.to_string())?;
+        let block: HeaderOnly = self
+            .client
+            .call::<_, _, types::ErrorWrapperForRpcBlockError>(
+                "block",
+                &RpcBlockRequest::Finality(Finality::Final),
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+        let tx = airdrop
+            .transaction(batch, self.signer_id, self.public_key)
+            .build(nonce, block.header.hash);
+        let signed = (self.sign)(tx).await;
+        let tx_hash = signed.hash().map_err(|err| err.to_string())?;
+        let signed_tx = signed.to_base64().map_err(|err| err.to_string())?;
+        Ok((tx_hash, signed_tx))
+    }
+
+    /// Outcome of the transaction `tx_hash`, if the node knows it.
+    async fn find(&self, tx_hash: &CryptoHash) -> Option<RpcTransactionResponse> {
+        let request = RpcTransactionStatusRequest::by_hash(
+            *tx_hash,
+            self.signer_id.clone(),
+            TxExecutionStatus::ExecutedOptimistic,
+        );
+        self.client.tx(&request).await.ok()
+    }
+}
+
+/// State of a batch from the response to its transaction.
+fn outcome(
+    tx_hash: CryptoHash,
+    signed_tx: types::SignedTransaction,
+    response: &RpcTransactionResponse,
+) -> BatchState {
+    let status = match response {
+        RpcTransactionResponse::Variant0 { status, .. }
+        | RpcTransactionResponse::Variant1 { status, .. } => status,
+    };
+    match status {
+        FinalExecutionStatus::Failure(error) => BatchState::Failed {
+            tx_hash: Some(tx_hash),
+            error: format!("{:?}", error),
+        },
+        FinalExecutionStatus::SuccessValue(_) => BatchState::Succeeded { tx_hash },
+        _ => BatchState::Pending { tx_hash, signed_tx },
+    }
+}
+
+/// Whether the node refused the transaction, so that it was not executed.
+fn rejected(err: &RpcError<ErrorWrapperForRpcTransactionError>) -> bool {
+    matches!(
+        err,
+        RpcError::Rpc(
+            ErrorWrapperForRpcTransactionError::RequestValidationError(_)
+                | ErrorWrapperForRpcTransactionError::HandlerError(
+                    types::RpcTransactionError::InvalidTransaction(_)
+                )
+        )
+    )
+}
@@ -0,0 +1,130 @@
+//! Sending several JSON-RPC calls in a single HTTP request.
+//!
+//! ```rust,ignore
+//! let mut batch = BatchRequest::new();
+//! let calls: Vec<_> = accounts
+//!     .iter()
+//!     .map(|account_id| batch.add::<methods::Query>(&view_account(account_id)))
+//!     .collect();
+//! let response = batch.send(&client).await?;
+//! for call in calls {
+//!     println!("{:?}", response.get(call));
+//! }
+//! ```
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+use crate::Error;
+use crate::jsonrpc::{self, Request, RpcError};
+use crate::methods::RpcMethod;
+use crate::rpc::RpcClient;
+use crate::types::validate::Validate;
+
+/// Calls collected to be sent together by [`BatchRequest::send`].
+#[derive(Debug, Default)]
+pub struct BatchRequest {
+    calls: Vec<(&'static str, Result<serde_json::Value, String>)>,
+}
+
+/// Handle to the result of a call added to a [`BatchRequest`].
+#[derive(Clone, Copy, Debug)]
+pub struct BatchCall<M> {
+    index: usize,
+    method: PhantomData<M>,
+}
+
+impl BatchRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a call of the JSON-RPC method `M`, returning the handle to read
+    /// its result from the [`BatchResponse`] with. Params failing
+    /// [`Validate`] make [`BatchRequest::send`] fail.
+    pub fn add<M: RpcMethod>(&mut self, params: &M::Params) -> BatchCall<M> {
+        let params = match params.validate() {
+            Ok(()) => serde_json::to_value(params).map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        self.calls.push((M::NAME, params));
+        BatchCall {
+            index: self.calls.len() - 1,
+            method: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Sends all calls in one HTTP request. Errors of individual calls are
+    /// returned by [`BatchResponse::get`].
+    pub async fn send(self, client: &RpcClient) -> Result<BatchResponse, RpcError<Infallible>> {
+        if self.calls.is_empty() {
+            return Ok(BatchResponse::default());
+        }
+        let ids: Vec<String> = (0..self.calls.len()).map(|id| id.to_string()).collect();
+        let mut params = Vec::with_capacity(self.calls.len());
+        for (_, call_params) in &self.calls {
+            params.push(
+                call_params
+                    .as_ref()
+                    .map_err(|err| Error::InvalidRequest(err.clone()))?,
+            );
+        }
+        let requests: Vec<_> = self
+            .calls
+            .iter()
+            .zip(&ids)
+            .zip(params)
+            .map(|(((method, _), id), params)| Request {
+                id,
+                jsonrpc: "2.0",
+                method,
+                params,
+            })
+            .collect();
+        let body = client.post_batch(&requests).await?;
+
+        // Responses may come in any order and are matched to calls by id.
+        let decoded: Vec<serde_json::Value> = serde_json::from_slice(&body)
+            .map_err(|err| Error::InvalidResponsePayload(body.clone(), err))?;
+        let mut responses = vec![None; self.calls.len()];
+        for response in decoded {
+            let index = response
+                .get("id")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|id| id.parse::<usize>().ok());
+            if let Some(slot) = index.and_then(|index| responses.get_mut(index)) {
+                *slot = Some(response);
+            }
+        }
+        Ok(BatchResponse { body, responses })
+    }
+}
+
+/// Responses to the calls of a [`BatchRequest`].
+#[derive(Debug, Default)]
+pub struct BatchResponse {
+    body: bytes::Bytes,
+    responses: Vec<Option<serde_json::Value>>,
+}
+
+impl BatchResponse {
+    /// Decodes the result of `call`.
+    #[allow(clippy::result_large_err)]
+    pub fn get<M: RpcMethod>(&self, call: BatchCall<M>) -> Result<M::Response, RpcError<M::Error>> {
+        match self.responses.get(call.index) {
+            Some(Some(response)) => jsonrpc::decode(response.to_string().into()),
+            _ => Err(Error::InvalidResponsePayload(
+                self.body.clone(),
+                serde::de::Error::custom(format!("no response with id {}", call.index)),
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! Lightweight block fetching and walking the chain block by block.
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use futures_util::stream::FuturesUnordered;
+
+use crate::jsonrpc::{self, RpcError};
+use crate::rpc::RpcClient;
+use crate::{Client, time, types};
+
+#[derive(serde::Deserialize)]
+pub(crate) struct HeaderOnly {
+    pub(crate) header: types::BlockHeaderView,
+}
+
+/// The `block` request of the block `block` references.
+pub(crate) fn block_request(block: types::BlockReference) -> types::RpcBlockRequest {
+    match block {
+        types::BlockReference::BlockId(block_id) => types::RpcBlockRequest::BlockId(block_id),
+        types::BlockReference::Finality(finality) => types::RpcBlockRequest::Finality(finality),
+        types::BlockReference::SyncCheckpoint(sync_checkpoint) => {
+            types::RpcBlockRequest::SyncCheckpoint(sync_checkpoint)
+        }
+    }
+}
+
+/// Fetches only the header of a block.
+///
+/// The node has no header-only endpoint, so this issues a regular `block`
+/// request but decodes just the `header` member of the result: chunk headers
+/// are skipped over without being materialized. Useful for monitoring loops
+/// that only need heights, hashes and timestamps.
+pub async fn block_header(
+    client: &Client,
+    request: &types::RpcBlockRequest,
+) -> Result<types::BlockHeaderView, RpcError<types::ErrorWrapperForRpcBlockError>> {
+    let block: HeaderOnly = jsonrpc::call(client, "block", request).await?;
+    Ok(block.header)
+}
+
+/// A block together with the bodies of its chunks, returned by
+/// [`RpcClient::block_with_chunks`].
+#[derive(Clone, Debug)]
+pub struct BlockWithChunks {
+    pub author: types::AccountId,
+    pub header: types::BlockHeaderView,
+    /// Chunks produced in this block, in shard order.
+    pub chunks: Vec<types::RpcChunkResponse>,
+}
+
+impl BlockWithChunks {
+    /// Transactions of all chunks with the shard of their chunk, in shard
+    /// order and in the order of each chunk.
+    pub fn transactions(
+        &self,
+    ) -> impl Iterator<Item = (&types::ShardId, &types::SignedTransactionView)> {
+        self.chunks.iter().flat_map(|chunk| {
+            chunk
+                .transactions
+                .iter()
+                .map(move |tx| (&chunk.header.shard_id, tx))
+        })
+    }
+
+    /// Receipts of all chunks with the shard of their chunk, in shard order
+    /// and in the order of each chunk.
+    pub fn receipts(&self) -> impl Iterator<Item = (&types::ShardId, &types::ReceiptView)> {
+        self.chunks.iter().flat_map(|chunk| {
+            chunk
+                .receipts
+                .iter()
+                .map(move |receipt| (&chunk.header.shard_id, receipt))
+        })
+    }
+}
+
+/// Error of [`RpcClient::block_with_chunks`].
+#[derive(Debug)]
+pub enum BlockWithChunksError {
+    Block(RpcError<types::ErrorWrapperForRpcBlockError>),
+    Chunk(RpcError<types::ErrorWrapperForRpcChunkError>),
+}
+
+impl std::fmt::Display for BlockWithChunksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(err) => write!(f, "failed to fetch block: {}", err),
+            Self::Chunk(err) => write!(f, "failed to fetch chunk: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for BlockWithChunksError {}
+
+impl RpcClient {
+    /// Fetches the block at `block` and then all of its chunks concurrently.
+    ///
+    /// Shards whose chunk was missed carry the header of an earlier chunk in
+    /// the block; those chunks are left out, so each transaction and receipt
+    /// shows up in a single block when walking the chain.
+    pub async fn block_with_chunks(
+        &self,
+        block: types::BlockReference,
+    ) -> Result<BlockWithChunks, BlockWithChunksError> {
+        let block = self
+            .block(&block_request(block))
+            .await
+            .map_err(BlockWithChunksError::Block)?;
+        let chunks = block
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.height_included == block.header.height)
+            .map(|chunk| {
+                let request = types::RpcChunkRequest::ChunkHash {
+                    chunk_id: chunk.chunk_hash,
+                };
+                async move { self.chunk(&request).await }
+            });
+        let chunks = futures_util::future::try_join_all(chunks)
+            .await
+            .map_err(BlockWithChunksError::Chunk)?;
+        Ok(BlockWithChunks {
+            author: block.author,
+            header: block.header,
+            chunks,
+        })
+    }
+}
+
+/// Tuning of the requests a [`BlockStream`] sends while it is behind the
+/// head, e.g. when catching up from an old block.
+///
+/// By default blocks are fetched one at a time, which keeps up with the chain
+/// but takes a round trip per block to catch up. Fetching several blocks at
+/// once speeds this up to what the node allows; the `ingestion` example
+/// measures the blocks per second of a node for given settings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IngestionConfig {
+    /// Block requests in flight at once.
+    pub concurrency: usize,
+    /// Most blocks fetched ahead of the one the consumer waits for, whether
+    /// in flight or kept until the consumer takes them. Raised to
+    /// `concurrency` if lower.
+    pub cache_size: usize,
+    /// Delay between polls for a new head once the stream caught up with it.
+    pub poll_interval: Duration,
+}
+
+impl Default for IngestionConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 1,
+            cache_size: 1,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Walks the chain from a starting block, yielding each block once it has
+/// reached the given finality, e.g. for indexers.
+///
+/// Heights without a block (skipped by their producer), the starting one
+/// included, are passed over. Once the stream reaches the head at its
+/// finality, it polls for new blocks every
+/// [`poll_interval`](Self::poll_interval). A failed request is yielded as an
+/// error and retried on the next poll, so the stream never ends. Blocks are
+/// fetched as set by an [`IngestionConfig`] and yielded in order.
+#[derive(Clone, Debug)]
+pub struct BlockStream {
+    client: RpcClient,
+    start: types::BlockId,
+    finality: types::Finality,
+    config: IngestionConfig,
+}
+
+impl BlockStream {
+    /// Stream starting at `start`, inclusive, with the default
+    /// [`IngestionConfig`].
+    pub fn new(client: RpcClient, start: types::BlockId, finality: types::Finality) -> Self {
+        Self {
+            client,
+            start,
+            finality,
+            config: IngestionConfig::default(),
+        }
+    }
+
+    /// Delay between polls for a new head once the stream caught up with it.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.config.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn config(mut self, config: IngestionConfig) -> Self {
+        self.config = IngestionConfig {
+            concurrency: config.concurrency.max(1),
+            cache_size: config.cache_size.max(config.concurrency).max(1),
+            ..config
+        };
+        self
+    }
+
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<types::RpcBlockResponse, RpcError<types::ErrorWrapperForRpcBlockError>>>
+    {
+        Walker::<types::RpcBlockResponse>::new(self).into_stream()
+    }
+
+    /// Stream of the headers of the blocks only, which decodes faster than
+    /// whole blocks, see [`block_header`].
+    #[allow(clippy::result_large_err)]
+    pub fn into_header_stream(
+        self,
+    ) -> impl Stream<Item = Result<types::BlockHeaderView, RpcError<types::ErrorWrapperForRpcBlockError>>>
+    {
+        Walker::<HeaderOnly>::new(self)
+            .into_stream()
+            .map(|block| block.map(|block| block.header))
+    }
+}
+
+/// A decoded `block` response, of which the stream needs the height.
+trait StreamedBlock: serde::de::DeserializeOwned + MaybeSend + 'static {
+    fn height(&self) -> u64;
+}
+
+impl StreamedBlock for types::RpcBlockResponse {
+    fn height(&self) -> u64 {
+        self.header.height
+    }
+}
+
+impl StreamedBlock for HeaderOnly {
+    fn height(&self) -> u64 {
+        self.header.height
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+#[cfg(target_arch = "wasm32")]
+trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// Block at a height, once requested.
+enum Fetched<B> {
+    Block(B),
+    /// No block was produced at the height.
+    Skipped,
+    Failed(RpcError<types::ErrorWrapperForRpcBlockError>),
+}
+
+/// Request of the block at a height. It is `Send`, except on wasm32 where
+/// the futures of `reqwest` are not.
+#[cfg(not(target_arch = "wasm32"))]
+type FetchFuture<B> = futures_util::future::BoxFuture<'static, (u64, Fetched<B>)>;
+#[cfg(target_arch = "wasm32")]
+type FetchFuture<B> = futures_util::future::LocalBoxFuture<'static, (u64, Fetched<B>)>;
+
+fn fetch<B: StreamedBlock>(client: RpcClient, height: u64) -> FetchFuture<B> {
+    Box::pin(async move {
+        let request = types::RpcBlockRequest::BlockId(types::BlockId::BlockHeight(height));
+        let fetched = match client.call("block", &request).await {
+            Ok(block) => Fetched::Block(block),
+            // Below the head, a missing block was skipped for good.
+            Err(RpcError::Rpc(types::ErrorWrapperForRpcBlockError::HandlerError(
+                types::RpcBlockError::UnknownBlock(_),
+            ))) => Fetched::Skipped,
+            Err(err) => Fetched::Failed(err),
+        };
+        (height, fetched)
+    })
+}
+
+struct Walker<B> {
+    stream: BlockStream,
+    /// `None` until the starting block is fetched.
+    next_height: Option<u64>,
+    /// Height of the last known head at the finality of the stream.
+    head_height: u64,
+    in_flight: FuturesUnordered<FetchFuture<B>>,
+    /// Heights fetched ahead and not taken by the consumer yet, with their
+    /// block once it arrived.
+    cache: BTreeMap<u64, Option<Fetched<B>>>,
+}
+
+impl<B: StreamedBlock> Walker<B> {
+    fn new(stream: BlockStream) -> Self {
+        Self {
+            stream,
+            next_height: None,
+            head_height: 0,
+            in_flight: FuturesUnordered::new(),
+            cache: BTreeMap::new(),
+        }
+    }
+
+    fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<B, RpcError<types::ErrorWrapperForRpcBlockError>>> {
+        futures_util::stream::unfold(self, |mut walker| async move {
+            let block = walker.next_block().await;
+            Some((block, walker))
+        })
+    }
+
+    async fn next_block(&mut self) -> Result<B, RpcError<types::ErrorWrapperForRpcBlockError>> {
+        let mut height = match self.next_height {
+            Some(height) => height,
+            None => {
+                let height = self.start_height().await?;
+                self.next_height = Some(height);
+                height
+            }
+        };
+        loop {
+            if let Some(fetched) = self.cache.get_mut(&height).and_then(Option::take) {
+                self.cache.remove(&height);
+                match fetched {
+                    Fetched::Block(block) => {
+                        self.next_height = Some(height + 1);
+                        return Ok(block);
+                    }
+                    Fetched::Skipped => {
+                        height += 1;
+                        self.next_height = Some(height);
+                        continue;
+                    }
+                    Fetched::Failed(err) => return Err(err),
+                }
+            }
+            self.request_ahead(height);
+            let Some((fetched_height, fetched)) = self.in_flight.next().await else {
+                // Every height up to the known head was taken.
+                let head: B = self
+                    .stream
+                    .client
+                    .call(
+                        "block",
+                        &types::RpcBlockRequest::Finality(self.stream.finality),
+                    )
+                    .await?;
+                self.head_height = head.height();
+                if height > self.head_height {
+                    time::sleep(self.stream.config.poll_interval).await;
+                }
+                continue;
+            };
+            self.cache.insert(fetched_height, Some(fetched));
+        }
+    }
+
+    /// Height of the starting block. It is then fetched like any other
+    /// height, once the head at the finality of the stream reached it and
+    /// passed over if it was skipped.
+    async fn start_height(&self) -> Result<u64, RpcError<types::ErrorWrapperForRpcBlockError>> {
+        match &self.stream.start {
+            types::BlockId::BlockHeight(height) => Ok(*height),
+            types::BlockId::CryptoHash(hash) => {
+                let block: HeaderOnly = self
+                    .stream
+                    .client
+                    .call(
+                        "block",
+                        &types::RpcBlockRequest::BlockId(types::BlockId::CryptoHash(*hash)),
+                    )
+                    .await?;
+                Ok(block.header.height)
+            }
+        }
+    }
+
+    /// Requests the heights from `height` on up to the known head that are
+    /// not cached yet, as far as the config allows.
+    fn request_ahead(&mut self, height: u64) {
+        let config = &self.stream.config;
+        let last = self
+            .head_height
+            .min(height.saturating_add(config.cache_size as u64 - 1));
+        for height in height..=last {
+            if self.in_flight.len() >= config.concurrency {
+                break;
+            }
+            if self.cache.contains_key(&height) {
+                continue;
+            }
+            self.cache.insert(height, None);
+            self.in_flight
+                .push(fetch(self.stream.client.clone(), height));
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Building a [`Client`] that sends extra headers with every request, e.g.
+//! the API key of a hosted RPC provider, or with other timeouts and
+//! connection pooling than the defaults of `reqwest`.
+//!
+//! ```rust,ignore
+//! let client = Client::builder("https://rpc.mainnet.fastnear.com")
+//!     .with_api_key("my-key")
+//!     .build()?;
+//! let rpc_client = RpcClient::from(client);
+//!
+//! let rpc_client = Client::builder("https://archival-rpc.mainnet.fastnear.com")
+//!     .timeout(Some(Duration::from_secs(10)))
+//!     .connect_timeout(Some(Duration::from_secs(2)))
+//!     .pool_max_idle_per_host(32)
+//!     .tcp_keepalive(Some(Duration::from_secs(30)))
+//!     .method_timeout("EXPERIMENTAL_light_client_proof", Duration::from_secs(60))
+//!     .build_rpc()?;
+//! ```
+use std::time::Duration;
+
+use reqwest::header::{self, InvalidHeaderValue};
+pub use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::Client;
+use crate::rpc::RpcClient;
+
+/// Header most hosted RPC providers read API keys from.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Builder of a [`Client`], see [`Client::builder`].
+#[derive(Debug)]
+pub struct ClientBuilder {
+    baseurl: String,
+    headers: HeaderMap,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    timeout: Option<Duration>,
+    /// Unset to connect within [`Self::timeout`].
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    connect_timeout: Option<Option<Duration>>,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pool_max_idle_per_host: Option<usize>,
+    /// Unset for the defaults of `reqwest`, as is `tcp_keepalive`.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pool_idle_timeout: Option<Option<Duration>>,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    tcp_keepalive: Option<Option<Duration>>,
+    method_timeouts: Vec<(String, Duration)>,
+    /// First invalid header value, reported by [`ClientBuilder::build`].
+    error: Option<InvalidHeaderValue>,
+}
+
+/// Error of [`ClientBuilder::build`].
+#[derive(Debug)]
+pub enum ClientBuilderError {
+    /// A header value contains characters not allowed in headers, e.g. a
+    /// newline.
+    InvalidHeader(InvalidHeaderValue),
+    /// The underlying `reqwest::Client` could not be built.
+    Build(reqwest::Error),
+}
+
+impl std::fmt::Display for ClientBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader(err) => write!(f, "invalid header value: {}", err),
+            Self::Build(err) => write!(f, "failed to build the HTTP client: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ClientBuilderError {}
+
+impl From<InvalidHeaderValue> for ClientBuilderError {
+    fn from(value: InvalidHeaderValue) -> Self {
+        Self::InvalidHeader(value)
+    }
+}
+
+impl From<reqwest::Error> for ClientBuilderError {
+    fn from(value: reqwest::Error) -> Self {
+        Self::Build(value)
+    }
+}
+
+impl Client {
+    /// Builder of a client for the node at `baseurl`, with the timeouts of
+    /// [`Client::new`] unless changed.
+    pub fn builder(baseurl: &str) -> ClientBuilder {
+        ClientBuilder {
+            baseurl: baseurl.to_string(),
+            headers: HeaderMap::new(),
+            timeout: Some(Duration::from_secs(15)),
+            connect_timeout: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            method_timeouts: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Sends `headers` with every request, in addition to the ones set
+    /// before. A header set again replaces the earlier value.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        for (name, value) in headers {
+            if let Some(name) = name {
+                self.headers.insert(name, value);
+            }
+        }
+        self
+    }
+
+    /// Sends the header `name` with `value` with every request.
+    pub fn header(self, name: HeaderName, value: &str) -> Self {
+        self.sensitive_header(name, value, false)
+    }
+
+    /// Sends `api_key` in the [`API_KEY_HEADER`] header with every request.
+    pub fn with_api_key(self, api_key: &str) -> Self {
+        self.sensitive_header(HeaderName::from_static(API_KEY_HEADER), api_key, true)
+    }
+
+    /// Sends `token` as `Authorization: Bearer <token>` with every request.
+    pub fn with_bearer_token(self, token: &str) -> Self {
+        self.sensitive_header(header::AUTHORIZATION, &format!("Bearer {}", token), true)
+    }
+
+    /// Timeout of whole requests, and of connecting unless set with
+    /// [`ClientBuilder::connect_timeout`], `None` for no timeout. Ignored on
+    /// wasm, where the browser handles timeouts and connections, like the
+    /// pool and keep-alive settings.
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Timeout of connecting to the node, e.g. shorter than the timeout of
+    /// requests to fail over quickly. `None` only bounds connecting by the
+    /// timeout of the whole request.
+    pub fn connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Number of idle connections kept open to the node, for clients
+    /// sending many concurrent requests to one provider.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long idle connections are kept open, `None` for as long as the
+    /// node keeps them.
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Interval of TCP keep-alive probes on open connections, `None` to
+    /// send none.
+    pub fn tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Times out calls of `method` after `timeout` instead of the timeout
+    /// of requests, e.g. longer for `EXPERIMENTAL_light_client_proof`. Only
+    /// applied to the [`RpcClient`] of [`ClientBuilder::build_rpc`], see
+    /// [`RpcClient::with_method_timeout`].
+    pub fn method_timeout(mut self, method: &str, timeout: Duration) -> Self {
+        self.method_timeouts.push((method.to_string(), timeout));
+        self
+    }
+
+    /// Builds an [`RpcClient`] with the timeouts set with
+    /// [`ClientBuilder::method_timeout`].
+    pub fn build_rpc(mut self) -> Result<RpcClient, ClientBuilderError> {
+        let method_timeouts = std::mem::take(&mut self.method_timeouts);
+        Ok(method_timeouts.into_iter().fold(
+            RpcClient::from(self.build()?),
+            |rpc_client, (method, timeout)| rpc_client.with_method_timeout(&method, timeout),
+        ))
+    }
+
+    pub fn build(self) -> Result<Client, ClientBuilderError> {
+        if let Some(err) = self.error {
+            return Err(err.into());
+        }
+        let client = reqwest::ClientBuilder::new().default_headers(self.headers);
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let mut client = match self.timeout {
+                Some(timeout) => client.timeout(timeout),
+                None => client,
+            };
+            if let Some(timeout) = self.connect_timeout.unwrap_or(self.timeout) {
+                client = client.connect_timeout(timeout);
+            }
+            if let Some(max) = self.pool_max_idle_per_host {
+                client = client.pool_max_idle_per_host(max);
+            }
+            if let Some(timeout) = self.pool_idle_timeout {
+                client = client.pool_idle_timeout(timeout);
+            }
+            if let Some(interval) = self.tcp_keepalive {
+                client = client.tcp_keepalive(interval);
+            }
+            client
+        };
+        Ok(Client::new_with_client(&self.baseurl, client.build()?))
+    }
+
+    /// Sets a header, marking secrets as sensitive so they are left out of
+    /// `Debug` output.
+    fn sensitive_header(mut self, name: HeaderName, value: &str, sensitive: bool) -> Self {
+        match HeaderValue::from_str(value) {
+            Ok(mut value) => {
+                value.set_sensitive(sensitive);
+                self.headers.insert(name, value);
+            }
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+        self
+    }
+}
@@ -0,0 +1,259 @@
+//! Caching responses about blocks that can no longer change.
+//!
+//! Everything the node answers about a block it no longer replaces is
+//! immutable: blocks, chunks, state and changes at a block hash, or at the
+//! height of a final block. A [`ResponseCache`] set with
+//! [`RpcClient::with_cache`](crate::rpc::RpcClient::with_cache) keeps these
+//! responses, keyed by the method and params of the request, and answers
+//! the same requests from memory afterwards, e.g. for dashboards showing
+//! the same historical blocks to many users.
+//!
+//! Requests at a block hash or the genesis checkpoint are always cached.
+//! Requests at a height are cached once the cache knows the height to be
+//! final, from the `block_height` or `header.height` of earlier responses
+//! with `finality: final`, or from [`ResponseCache::set_final_height`].
+//! Requests with a finality, including `final`, and requests without a
+//! block reference, such as `tx` or `gas_price` of the latest block, always
+//! go to the node, and so do responses with an error.
+//!
+//! ```rust,ignore
+//! let cache = ResponseCache::new(10_000).with_ttl(Duration::from_secs(3600));
+//! let rpc_client = RpcClient::new("https://archival-rpc.mainnet.fastnear.com")
+//!     .with_cache(cache.clone());
+//! let block = rpc_client.block(&RpcBlockRequest::BlockId(BlockId::BlockHeight(9_820_210))).await?;
+//! ```
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::time::Instant;
+
+/// Least recently used responses of an [`RpcClient`](crate::rpc::RpcClient),
+/// shared by its clones and by every client set to use a clone of it.
+#[derive(Clone)]
+pub struct ResponseCache(Arc<Mutex<Entries>>);
+
+struct Entries {
+    capacity: usize,
+    ttl: Option<Duration>,
+    /// Responses by request body.
+    responses: HashMap<bytes::Bytes, Entry>,
+    /// Request bodies by the tick of their last use, oldest first.
+    recency: BTreeMap<u64, bytes::Bytes>,
+    tick: u64,
+    final_height: Option<u64>,
+}
+
+struct Entry {
+    response: bytes::Bytes,
+    inserted: Instant,
+    used: u64,
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries = self.0.lock().unwrap();
+        write!(
+            f,
+            "ResponseCache({}/{} responses)",
+            entries.responses.len(),
+            entries.capacity
+        )
+    }
+}
+
+impl ResponseCache {
+    /// Cache of at most `capacity` responses, evicting the least recently
+    /// used one beyond that.
+    pub fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(Entries {
+            capacity,
+            ttl: None,
+            responses: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            final_height: None,
+        })))
+    }
+
+    /// Drops responses `ttl` after they were cached, e.g. to bound how long
+    /// a response of a node that was wrong is served.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        self.0.lock().unwrap().ttl = Some(ttl);
+        self
+    }
+
+    /// Marks the blocks up to `height` as final, so that requests at these
+    /// heights are cached.
+    pub fn set_final_height(&self, height: u64) {
+        let mut entries = self.0.lock().unwrap();
+        entries.final_height = entries.final_height.max(Some(height));
+    }
+
+    /// Height of the last block known to be final.
+    pub fn final_height(&self) -> Option<u64> {
+        self.0.lock().unwrap().final_height
+    }
+
+    /// Number of cached responses.
+    pub fn len(&self) -> usize {
+        self.0.lock().unwrap().responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every cached response.
+    pub fn clear(&self) {
+        let mut entries = self.0.lock().unwrap();
+        entries.responses.clear();
+        entries.recency.clear();
+    }
+
+    /// Cached response to the encoded request `body`.
+    pub(crate) fn get(&self, body: &bytes::Bytes) -> Option<bytes::Bytes> {
+        let mut entries = self.0.lock().unwrap();
+        let entries = &mut *entries;
+        let entry = entries.responses.get_mut(body)?;
+        if entries
+            .ttl
+            .is_some_and(|ttl| entry.inserted.elapsed() >= ttl)
+        {
+            entries.recency.remove(&entry.used);
+            entries.responses.remove(body);
+            return None;
+        }
+        entries.tick += 1;
+        entries.recency.remove(&entry.used);
+        entries.recency.insert(entries.tick, body.clone());
+        entry.used = entries.tick;
+        Some(entry.response.clone())
+    }
+
+    /// Keeps the successful `response` to the encoded request `body` if the
+    /// request is about a block that can no longer change.
+    pub(crate) fn insert(&self, body: &bytes::Bytes, response: &bytes::Bytes) {
+        let Ok(request) = serde_json::from_slice::<Request>(body) else {
+            return;
+        };
+        let Ok(Response {
+            result: Some(result),
+            error: None,
+        }) = serde_json::from_slice::<Response>(response)
+        else {
+            return;
+        };
+        let mut entries = self.0.lock().unwrap();
+        let cacheable = match request.params.block() {
+            Block::Hash => true,
+            Block::Height(height) => entries.final_height.is_some_and(|last| height <= last),
+            Block::Final => {
+                let height = result.block_height.or(result.header.and_then(|h| h.height));
+                entries.final_height = entries.final_height.max(height);
+                false
+            }
+            Block::Latest => false,
+        };
+        if !cacheable || entries.capacity == 0 {
+            return;
+        }
+        entries.tick += 1;
+        let used = entries.tick;
+        let entry = Entry {
+            response: response.clone(),
+            inserted: Instant::now(),
+            used,
+        };
+        if let Some(replaced) = entries.responses.insert(body.clone(), entry) {
+            entries.recency.remove(&replaced.used);
+        }
+        entries.recency.insert(used, body.clone());
+        while entries.responses.len() > entries.capacity {
+            let Some((_, oldest)) = entries.recency.pop_first() else {
+                break;
+            };
+            entries.responses.remove(&oldest);
+        }
+    }
+}
+
+/// The block a request is about.
+enum Block {
+    /// A block hash, or the genesis block.
+    Hash,
+    Height(u64),
+    /// The last final block, whose height the response tells.
+    Final,
+    /// Another finality, another sync checkpoint, or no block reference.
+    Latest,
+}
+
+#[derive(Deserialize)]
+struct Request {
+    params: Params,
+}
+
+/// Block reference of the params, which requests flatten into them.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Params {
+    Object {
+        block_id: Option<BlockId>,
+        finality: Option<String>,
+        sync_checkpoint: Option<String>,
+    },
+    Other(serde::de::IgnoredAny),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BlockId {
+    Height(u64),
+    Hash(serde::de::IgnoredAny),
+}
+
+impl Params {
+    fn block(&self) -> Block {
+        match self {
+            Self::Object {
+                block_id: Some(BlockId::Hash(_)),
+                ..
+            } => Block::Hash,
+            Self::Object {
+                block_id: Some(BlockId::Height(height)),
+                ..
+            } => Block::Height(*height),
+            Self::Object {
+                finality: Some(finality),
+                ..
+            } if finality == "final" => Block::Final,
+            Self::Object {
+                sync_checkpoint: Some(checkpoint),
+                ..
+            } if checkpoint == "genesis" => Block::Hash,
+            _ => Block::Latest,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Response {
+    result: Option<Heights>,
+    error: Option<serde::de::IgnoredAny>,
+}
+
+/// Height of the block of a response, for the few shapes telling it. The
+/// rare results that are not objects fail to decode, and are not cached.
+#[derive(Deserialize)]
+struct Heights {
+    block_height: Option<u64>,
+    header: Option<Header>,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    height: Option<u64>,
+}
@@ -0,0 +1,18 @@
+//! Reading the root key of the MPC contract. Requests to sign and their
+//! responses are in [`types::chain_signatures`].
+use crate::rpc::RpcClient;
+use crate::types::{AccountId, BlockReference, PublicKey};
+use crate::view::ViewFunctionError;
+
+/// Root public key of the MPC contract `contract_id`, from which the keys of
+/// each account and path are derived.
+pub async fn mpc_public_key(
+    client: &RpcClient,
+    contract_id: &AccountId,
+    block: BlockReference,
+) -> Result<PublicKey, ViewFunctionError> {
+    Ok(client
+        .view_function(contract_id, "public_key", &serde_json::json!({}), block)
+        .await?
+        .result)
+}
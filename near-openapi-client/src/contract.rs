@@ -0,0 +1,183 @@
+//! Typed bindings of contracts, declared with [`near_contract!`].
+//!
+//! The macro takes the view and change methods of a contract with the types
+//! of their arguments and results, and defines a struct with a method each.
+//! View methods are called with [`RpcClient::view_function`], change methods
+//! return a [`ContractCall`] to add to a transaction:
+//!
+//! ```rust,ignore
+//! near_contract! {
+//!     /// A NEP-141 fungible token.
+//!     pub struct FungibleToken {
+//!         view fn ft_balance_of(account_id: AccountId) -> String;
+//!         call fn ft_transfer(receiver_id: AccountId, amount: String, memo: Option<String>);
+//!     }
+//! }
+//!
+//! let token = FungibleToken::new(rpc_client, "usdt.tether-token.near".parse()?);
+//! let balance = token.ft_balance_of(account_id, BlockReference::final_()).await?;
+//! let tx = token
+//!     .ft_transfer(receiver_id, "1000000".to_string(), None)?
+//!     .deposit(NearToken::from_yoctonear(1))
+//!     .transaction(signer_id, public_key)
+//!     .build(nonce, block_hash);
+//! ```
+//!
+//! The arguments are passed as a JSON object with a member per argument, as
+//! contracts built with near-sdk expect them.
+use serde::Serialize;
+
+use crate::types::tx::{Action, TransactionBuilder};
+use crate::types::{
+    AccountId, FunctionArgs, FunctionCallAction, NearGas, NearToken, NonDelegateAction, PublicKey,
+};
+
+/// Gas attached to a [`ContractCall`] unless set otherwise.
+pub const DEFAULT_GAS: NearGas = NearGas::from_tgas(30);
+
+/// A call of a change method of a contract, to be sent in a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCall {
+    pub contract_id: AccountId,
+    pub method_name: String,
+    pub args: FunctionArgs,
+    pub gas: NearGas,
+    pub deposit: NearToken,
+}
+
+impl ContractCall {
+    /// Call of `method_name` with `args` encoded as JSON, with
+    /// [`DEFAULT_GAS`] and no deposit.
+    pub fn new(
+        contract_id: AccountId,
+        method_name: impl Into<String>,
+        args: &impl Serialize,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            contract_id,
+            method_name: method_name.into(),
+            args: FunctionArgs::from_json(args)?,
+            gas: DEFAULT_GAS,
+            deposit: NearToken::from_yoctonear(0),
+        })
+    }
+
+    pub fn gas(mut self, gas: NearGas) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    pub fn deposit(mut self, deposit: NearToken) -> Self {
+        self.deposit = deposit;
+        self
+    }
+
+    /// Transaction from `signer_id` to the contract with this call as its
+    /// first action.
+    pub fn transaction(self, signer_id: AccountId, public_key: PublicKey) -> TransactionBuilder {
+        TransactionBuilder::new(signer_id, public_key, self.contract_id.clone()).action(self)
+    }
+}
+
+impl From<ContractCall> for Action {
+    fn from(value: ContractCall) -> Self {
+        Action::NonDelegate(NonDelegateAction::FunctionCall(FunctionCallAction {
+            args: value.args.0,
+            deposit: value.deposit,
+            gas: value.gas,
+            method_name: value.method_name,
+        }))
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
+
+/// Defines a struct binding the methods of a contract, see the
+/// [`contract`](crate::contract) module.
+///
+/// Each method is `view fn name(args) -> Result;` or `call fn name(args);`.
+/// The struct is built with `new(client, contract_id)`. A view method takes
+/// its arguments and a [`BlockReference`](crate::types::BlockReference) and
+/// returns its decoded result or a
+/// [`ViewFunctionError`](crate::view::ViewFunctionError). A call method
+/// returns a [`ContractCall`], or the error of encoding its arguments.
+#[macro_export]
+macro_rules! near_contract {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$method_meta:meta])*
+                $kind:ident fn $method:ident ($($arg:ident : $arg_type:ty),* $(,)?) $(-> $result:ty)?;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        $vis struct $name {
+            client: $crate::rpc::RpcClient,
+            contract_id: $crate::types::AccountId,
+        }
+
+        impl $name {
+            pub fn new(client: $crate::rpc::RpcClient, contract_id: $crate::types::AccountId) -> Self {
+                Self { client, contract_id }
+            }
+
+            pub fn client(&self) -> &$crate::rpc::RpcClient {
+                &self.client
+            }
+
+            pub fn contract_id(&self) -> &$crate::types::AccountId {
+                &self.contract_id
+            }
+
+            $(
+                $crate::near_contract!(@method $kind $(#[$method_meta])* $method ($($arg: $arg_type),*) $(-> $result)?);
+            )*
+        }
+    };
+    (@method view $(#[$meta:meta])* $method:ident ($($arg:ident : $arg_type:ty),*) -> $result:ty) => {
+        $(#[$meta])*
+        pub async fn $method(
+            &self,
+            $($arg: $arg_type,)*
+            block: $crate::types::BlockReference,
+        ) -> ::std::result::Result<$result, $crate::view::ViewFunctionError> {
+            #[allow(unused_mut)]
+            let mut args = $crate::contract::__private::serde_json::Map::new();
+            $(
+                args.insert(
+                    stringify!($arg).to_string(),
+                    $crate::contract::__private::serde_json::to_value(&$arg)
+                        .map_err($crate::view::ViewFunctionError::Args)?,
+                );
+            )*
+            let result = self
+                .client
+                .view_function(&self.contract_id, stringify!($method), &args, block)
+                .await?;
+            Ok(result.result)
+        }
+    };
+    (@method call $(#[$meta:meta])* $method:ident ($($arg:ident : $arg_type:ty),*)) => {
+        $(#[$meta])*
+        pub fn $method(
+            &self,
+            $($arg: $arg_type,)*
+        ) -> ::std::result::Result<$crate::contract::ContractCall, $crate::contract::__private::serde_json::Error> {
+            #[allow(unused_mut)]
+            let mut args = $crate::contract::__private::serde_json::Map::new();
+            $(
+                args.insert(
+                    stringify!($arg).to_string(),
+                    $crate::contract::__private::serde_json::to_value(&$arg)?,
+                );
+            )*
+            $crate::contract::ContractCall::new(self.contract_id.clone(), stringify!($method), &args)
+        }
+    };
+}
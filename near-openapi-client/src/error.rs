@@ -0,0 +1,197 @@
+//! Classifying failed calls without matching on the nested error types.
+//!
+//! A JSON-RPC error is an `ErrorWrapperFor*` whose handler errors differ per
+//! method, and a rejected transaction nests an `InvalidTxError` or an
+//! `ActionErrorKind` a few levels deeper. [`classify`] reduces the error of
+//! any method to an [`ErrorClass`] to base a policy on:
+//!
+//! ```rust,ignore
+//! match error::classify(&err) {
+//!     ErrorClass::Retryable => retry_later(),
+//!     ErrorClass::ExecutionFailure { reason } if reason.is_nonce_error() => resync_nonce(),
+//!     _ => return Err(err),
+//! }
+//! ```
+use crate::Error;
+use crate::jsonrpc::RpcError;
+use crate::retry::FailedAttempt;
+use crate::types::{ActionErrorKind, InvalidAccessKeyError, InvalidTxError, TxExecutionError};
+
+/// JSON-RPC error causes after which the same request may succeed later.
+const RETRYABLE_CAUSES: &[&str] = &[
+    "NODE_IS_SYNCING",
+    "NO_NEW_BLOCKS",
+    "NO_SYNCED_BLOCKS",
+    "NOT_SYNCED_YET",
+    "REQUEST_ROUTED",
+    "TIMEOUT_ERROR",
+];
+
+/// JSON-RPC error causes naming something the node does not have.
+const NOT_FOUND_CAUSES: &[&str] = &[
+    "GARBAGE_COLLECTED_BLOCK",
+    "NO_CONTRACT_CODE",
+    "NO_GLOBAL_CONTRACT_CODE",
+    "UNKNOWN_ACCESS_KEY",
+    "UNKNOWN_ACCOUNT",
+    "UNKNOWN_BLOCK",
+    "UNKNOWN_CHUNK",
+    "UNKNOWN_EPOCH",
+    "UNKNOWN_GAS_KEY",
+    "UNKNOWN_RECEIPT",
+    "UNKNOWN_TRANSACTION",
+    "UNKNOWN_TRANSACTION_OR_RECEIPT",
+];
+
+/// JSON-RPC error causes rejecting the request itself.
+const INVALID_REQUEST_CAUSES: &[&str] = &[
+    "EPOCH_OUT_OF_BOUNDS",
+    "INVALID_ACCOUNT",
+    "INVALID_SHARD_ID",
+    "METHOD_NOT_FOUND",
+    "PARSE_ERROR",
+    "TOO_LARGE_CONTRACT_STATE",
+];
+
+/// What a failed call means for the caller, as returned by [`classify`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorClass {
+    /// The node could not be reached, is overloaded or catching up, or
+    /// timed out waiting: the same request may succeed later.
+    Retryable,
+    /// The request is malformed, names an invalid account or shard, or was
+    /// sent to a node of another network.
+    InvalidRequest,
+    /// The block, account, key, transaction or other item asked for is
+    /// unknown to the node, or garbage collected. A block unknown to a node
+    /// behind the head may still show up, which is why the default
+    /// [`RetryPolicy`](crate::retry::RetryPolicy) retries `UNKNOWN_BLOCK`.
+    NotFound,
+    /// The transaction was rejected or a contract call failed.
+    ExecutionFailure { reason: FailureReason },
+    /// The node failed, or its response could not be decoded.
+    Internal,
+}
+
+impl ErrorClass {
+    /// Whether the transaction was rejected for its nonce, or a delegate
+    /// action for the nonce of the delegate action.
+    pub fn is_nonce_error(&self) -> bool {
+        matches!(self, Self::ExecutionFailure { reason } if reason.is_nonce_error())
+    }
+
+    /// Whether the transaction or one of its actions failed for lack of
+    /// balance or of access key allowance.
+    pub fn is_balance_error(&self) -> bool {
+        matches!(self, Self::ExecutionFailure { reason } if reason.is_balance_error())
+    }
+}
+
+/// Why a transaction or contract call failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FailureReason {
+    /// The node rejected the transaction, e.g. on `send_tx`.
+    Transaction(TxExecutionError),
+    /// A view call of a contract failed, with the error of the VM.
+    Contract(String),
+}
+
+impl FailureReason {
+    /// See [`ErrorClass::is_nonce_error`].
+    pub fn is_nonce_error(&self) -> bool {
+        match self {
+            Self::Transaction(TxExecutionError::InvalidTxError(err)) => matches!(
+                err,
+                InvalidTxError::InvalidNonce { .. } | InvalidTxError::NonceTooLarge { .. }
+            ),
+            Self::Transaction(TxExecutionError::ActionError(err)) => matches!(
+                err.kind,
+                ActionErrorKind::DelegateActionInvalidNonce { .. }
+                    | ActionErrorKind::DelegateActionNonceTooLarge { .. }
+            ),
+            _ => false,
+        }
+    }
+
+    /// See [`ErrorClass::is_balance_error`].
+    pub fn is_balance_error(&self) -> bool {
+        match self {
+            Self::Transaction(TxExecutionError::InvalidTxError(err)) => matches!(
+                err,
+                InvalidTxError::NotEnoughBalance { .. }
+                    | InvalidTxError::LackBalanceForState { .. }
+                    | InvalidTxError::InvalidAccessKeyError(
+                        InvalidAccessKeyError::NotEnoughAllowance { .. }
+                    )
+            ),
+            Self::Transaction(TxExecutionError::ActionError(err)) => matches!(
+                err.kind,
+                ActionErrorKind::LackBalanceForState { .. }
+                    | ActionErrorKind::TriesToStake { .. }
+                    | ActionErrorKind::DelegateActionAccessKeyError(
+                        InvalidAccessKeyError::NotEnoughAllowance { .. }
+                    )
+            ),
+            _ => false,
+        }
+    }
+}
+
+/// Classifies the error of a call of any method.
+pub fn classify<E: serde::Serialize>(err: &RpcError<E>) -> ErrorClass {
+    match err {
+        RpcError::Client(Error::InvalidRequest(_)) => ErrorClass::InvalidRequest,
+        RpcError::Client(err) => match FailedAttempt::from_client_error(err) {
+            Some(attempt) if attempt.is_transient() => ErrorClass::Retryable,
+            Some(FailedAttempt::Status(400..=499)) => ErrorClass::InvalidRequest,
+            _ => ErrorClass::Internal,
+        },
+        RpcError::Rpc(err) => classify_rpc_error(err),
+        RpcError::WrongNetwork(_) => ErrorClass::InvalidRequest,
+    }
+}
+
+/// Whether `err` is a transaction rejected for its nonce. See
+/// [`ErrorClass::is_nonce_error`].
+pub fn is_nonce_error<E: serde::Serialize>(err: &RpcError<E>) -> bool {
+    classify(err).is_nonce_error()
+}
+
+/// Whether `err` is a transaction rejected for lack of balance. See
+/// [`ErrorClass::is_balance_error`].
+pub fn is_balance_error<E: serde::Serialize>(err: &RpcError<E>) -> bool {
+    classify(err).is_balance_error()
+}
+
+/// Classifies an `ErrorWrapperFor*` error by the names of the error and of
+/// its cause, which are the same for all methods.
+fn classify_rpc_error<E: serde::Serialize>(err: &E) -> ErrorClass {
+    let Ok(value) = serde_json::to_value(err) else {
+        return ErrorClass::Internal;
+    };
+    let cause = &value["cause"];
+    let cause_name = cause["name"].as_str().unwrap_or_default();
+    match (value["name"].as_str(), cause_name) {
+        (Some("REQUEST_VALIDATION_ERROR"), _) => ErrorClass::InvalidRequest,
+        (_, "INVALID_TRANSACTION") => {
+            match serde_json::from_value(cause["info"]["TxExecutionError"].clone()) {
+                Ok(err) => ErrorClass::ExecutionFailure {
+                    reason: FailureReason::Transaction(err),
+                },
+                Err(_) => ErrorClass::InvalidRequest,
+            }
+        }
+        (_, "CONTRACT_EXECUTION_ERROR") => ErrorClass::ExecutionFailure {
+            reason: FailureReason::Contract(
+                cause["info"]["vm_error"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+        },
+        (_, cause) if RETRYABLE_CAUSES.contains(&cause) => ErrorClass::Retryable,
+        (_, cause) if NOT_FOUND_CAUSES.contains(&cause) => ErrorClass::NotFound,
+        (_, cause) if INVALID_REQUEST_CAUSES.contains(&cause) => ErrorClass::InvalidRequest,
+        _ => ErrorClass::Internal,
+    }
+}
@@ -0,0 +1,61 @@
+//! Fee previews for transactions at a block of a node, see
+//! [`types::fees`](crate::types::fees) for the estimates themselves.
+//!
+//! ```rust,ignore
+//! let estimator = rpc_client.fee_estimator(BlockReference::final_()).await?;
+//! let estimate = estimator.estimate_transaction(&transaction)?;
+//! println!("{} gas, {} in fees, {} in total", estimate.gas, estimate.gas_cost, estimate.total);
+//! ```
+use crate::block::{HeaderOnly, block_request};
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+pub use crate::types::fees::{FeeError, FeeEstimate, FeeEstimator};
+use crate::types::{
+    BlockId, BlockReference, ErrorWrapperForRpcBlockError, ErrorWrapperForRpcProtocolConfigError,
+    RpcProtocolConfigRequest,
+};
+
+/// Error of [`RpcClient::fee_estimator`].
+#[derive(Debug)]
+pub enum FeesError {
+    /// The block could not be fetched.
+    Block(RpcError<ErrorWrapperForRpcBlockError>),
+    /// The protocol config at the block could not be fetched.
+    ProtocolConfig(RpcError<ErrorWrapperForRpcProtocolConfigError>),
+    /// The runtime config at the block has no fee tables.
+    MissingFees,
+}
+
+impl std::fmt::Display for FeesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(err) => write!(f, "failed to fetch block: {}", err),
+            Self::ProtocolConfig(err) => write!(f, "failed to fetch protocol config: {}", err),
+            Self::MissingFees => write!(f, "runtime config has no transaction_costs"),
+        }
+    }
+}
+
+impl std::error::Error for FeesError {}
+
+impl RpcClient {
+    /// Estimator with the gas price of the block `block` references and the
+    /// fee tables of the protocol config at that block.
+    pub async fn fee_estimator(&self, block: BlockReference) -> Result<FeeEstimator, FeesError> {
+        let block: HeaderOnly = self
+            .call("block", &block_request(block))
+            .await
+            .map_err(FeesError::Block)?;
+        let config = self
+            .experimental_protocol_config(&RpcProtocolConfigRequest::BlockId(BlockId::CryptoHash(
+                block.header.hash,
+            )))
+            .await
+            .map_err(FeesError::ProtocolConfig)?;
+        let fees = config
+            .runtime_config
+            .and_then(|config| config.transaction_costs)
+            .ok_or(FeesError::MissingFees)?;
+        Ok(FeeEstimator::new(fees, block.header.gas_price))
+    }
+}
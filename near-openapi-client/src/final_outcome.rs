@@ -0,0 +1,168 @@
+//! Following the receipts of a transaction to its terminal outcome.
+//!
+//! The outcome of a transaction lists the receipt it was converted to, whose
+//! outcome lists the receipts it created in turn, e.g. cross-contract calls
+//! and refunds. [`RpcClient::final_outcome`] walks this tree from the
+//! transaction and flattens the statuses of its outcomes into one:
+//!
+//! ```rust,ignore
+//! let outcome = rpc_client.final_outcome(tx_hash, signer_id).await?;
+//! match &outcome.status {
+//!     FinalExecutionStatus::Failure(err) => println!("failed: {:?}", err),
+//!     status => println!("{:?} after {} receipts", status, outcome.receipts_outcome.len()),
+//! }
+//! ```
+use std::collections::{HashMap, HashSet};
+
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccountId, CryptoHash, ErrorWrapperForRpcReceiptError, ErrorWrapperForRpcTransactionError,
+    ExecutionOutcomeWithIdView, ExecutionStatusView, FinalExecutionStatus, RpcReceiptRequest,
+    RpcReceiptResponse, RpcTransactionStatusRequest, TxExecutionStatus,
+};
+
+/// Error of [`RpcClient::final_outcome`].
+#[derive(Debug)]
+pub enum FinalOutcomeError {
+    Tx(RpcError<ErrorWrapperForRpcTransactionError>),
+    Receipt(RpcError<ErrorWrapperForRpcReceiptError>),
+}
+
+impl std::fmt::Display for FinalOutcomeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tx(err) => write!(f, "failed to fetch transaction: {}", err),
+            Self::Receipt(err) => write!(f, "failed to fetch receipt: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FinalOutcomeError {}
+
+/// Outcomes of a transaction and of every receipt it led to, as returned by
+/// [`RpcClient::final_outcome`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FinalOutcome {
+    pub transaction_outcome: ExecutionOutcomeWithIdView,
+    /// Outcomes of the receipts of the tree, depth first and in the order
+    /// each outcome lists the receipts it created.
+    pub receipts_outcome: Vec<ExecutionOutcomeWithIdView>,
+    /// Receipts of the tree not executed yet, e.g. refunds, which the node
+    /// does not wait for.
+    pub pending_receipts: Vec<RpcReceiptResponse>,
+    /// `Failure` with the first failure of the tree, else the value the
+    /// receipts the transaction was converted to returned in the end, or
+    /// `Started` while this depends on a pending receipt.
+    pub status: FinalExecutionStatus,
+}
+
+impl FinalOutcome {
+    /// Outcome of the transaction or receipt `id`.
+    pub fn outcome(&self, id: &CryptoHash) -> Option<&ExecutionOutcomeWithIdView> {
+        std::iter::once(&self.transaction_outcome)
+            .chain(&self.receipts_outcome)
+            .find(|outcome| &outcome.id == id)
+    }
+
+    /// Outcomes of the receipts created by the transaction or receipt `id`,
+    /// leaving out pending ones.
+    pub fn children(&self, id: &CryptoHash) -> impl Iterator<Item = &ExecutionOutcomeWithIdView> {
+        self.outcome(id)
+            .into_iter()
+            .flat_map(|outcome| &outcome.outcome.receipt_ids)
+            .filter_map(|receipt_id| self.outcome(receipt_id))
+    }
+
+    /// Whether every receipt of the tree was executed.
+    pub fn is_complete(&self) -> bool {
+        self.pending_receipts.is_empty()
+    }
+}
+
+impl RpcClient {
+    /// Fetches the transaction `tx_hash` signed by `sender` once it is final
+    /// and resolves the receipts it led to. Receipts without an outcome yet
+    /// are fetched with `EXPERIMENTAL_receipt`.
+    pub async fn final_outcome(
+        &self,
+        tx_hash: CryptoHash,
+        sender: AccountId,
+    ) -> Result<FinalOutcome, FinalOutcomeError> {
+        let request =
+            RpcTransactionStatusRequest::by_hash(tx_hash, sender, TxExecutionStatus::Final);
+        let response = self.tx(&request).await.map_err(FinalOutcomeError::Tx)?;
+        let mut outcomes: HashMap<_, _> = response
+            .receipts_outcome()
+            .iter()
+            .map(|outcome| (outcome.id, outcome))
+            .collect();
+        let transaction_outcome = response.transaction_outcome().clone();
+
+        let mut receipts_outcome = Vec::new();
+        let mut pending = Vec::new();
+        let mut seen = HashSet::new();
+        let mut stack: Vec<_> = transaction_outcome
+            .outcome
+            .receipt_ids
+            .iter()
+            .rev()
+            .collect();
+        while let Some(receipt_id) = stack.pop() {
+            if !seen.insert(receipt_id) {
+                continue;
+            }
+            match outcomes.remove(receipt_id) {
+                Some(outcome) => {
+                    stack.extend(outcome.outcome.receipt_ids.iter().rev());
+                    receipts_outcome.push(outcome.clone());
+                }
+                None => pending.push(*receipt_id),
+            }
+        }
+
+        let mut pending_receipts = Vec::new();
+        for receipt_id in pending {
+            let receipt = self
+                .experimental_receipt(&RpcReceiptRequest { receipt_id })
+                .await
+                .map_err(FinalOutcomeError::Receipt)?;
+            pending_receipts.push(receipt);
+        }
+
+        let mut outcome = FinalOutcome {
+            transaction_outcome,
+            receipts_outcome,
+            pending_receipts,
+            status: FinalExecutionStatus::Started,
+        };
+        outcome.status = flatten_status(&outcome);
+        Ok(outcome)
+    }
+}
+
+/// Status of the whole tree, the way the node computes the `status` of a
+/// transaction from its outcomes.
+fn flatten_status(outcome: &FinalOutcome) -> FinalExecutionStatus {
+    let outcomes = std::iter::once(&outcome.transaction_outcome).chain(&outcome.receipts_outcome);
+    for outcome in outcomes {
+        if let ExecutionStatusView::Failure(err) = &outcome.outcome.status {
+            return FinalExecutionStatus::Failure(err.clone());
+        }
+    }
+    let mut current = &outcome.transaction_outcome;
+    loop {
+        match &current.outcome.status {
+            ExecutionStatusView::SuccessValue(value) => {
+                return FinalExecutionStatus::SuccessValue(value.clone());
+            }
+            ExecutionStatusView::SuccessReceiptId(receipt_id) => {
+                match outcome.outcome(receipt_id) {
+                    Some(next) => current = next,
+                    None => return FinalExecutionStatus::Started,
+                }
+            }
+            _ => return FinalExecutionStatus::Started,
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Tracking whether blocks seen before they were final end up final.
+//!
+//! Blocks at `Finality::Optimistic` come about two seconds before they are final,
+//! but may be replaced by another fork in the meantime. A
+//! [`FinalityTracker`] follows the optimistic and the final head, and once
+//! the final head passes a block it saw, reports whether that block is the
+//! final one at its height or was orphaned. Data ingested from optimistic
+//! blocks can be tagged with its block, and the tag flips to final or
+//! reverted when the tracker finds out:
+//!
+//! ```rust,ignore
+//! let tracker = FinalityTracker::new(rpc_client.clone());
+//! let tagged = tracker.tag(outcome, block.header.height, block.header.hash);
+//! let events = tracker.clone().into_stream();
+//! futures_util::pin_mut!(events);
+//! while let Some(event) = events.next().await {
+//!     if let FinalityEvent::Orphaned { height, hash } = event? {
+//!         println!("block {} at {} was orphaned", hash, height);
+//!     }
+//! }
+//! // Later: tagged.status() is FinalityStatus::Final or Reverted.
+//! ```
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_core::Stream;
+
+use crate::block::HeaderOnly;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::time;
+use crate::types::{
+    BlockId, CryptoHash, ErrorWrapperForRpcBlockError, Finality, RpcBlockError, RpcBlockRequest,
+};
+
+/// Whether a block is final, as far as a [`FinalityTracker`] knows.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FinalityStatus {
+    /// The final head has not reached the block yet.
+    Optimistic,
+    Final,
+    /// Another block, or none, is final at the height of the block.
+    Reverted,
+}
+
+/// Change observed by [`FinalityTracker::poll`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FinalityEvent {
+    /// The optimistic head moved to a block not seen before.
+    NewHead { height: u64, hash: CryptoHash },
+    /// A block seen before it was final is now final.
+    Finalized { height: u64, hash: CryptoHash },
+    /// A block seen before it was final was replaced by another fork.
+    Orphaned { height: u64, hash: CryptoHash },
+}
+
+/// Value ingested from a block, with the finality status of that block,
+/// returned by [`FinalityTracker::tag`].
+#[derive(Clone, Debug)]
+pub struct Tagged<T> {
+    pub value: T,
+    pub height: u64,
+    pub block_hash: CryptoHash,
+    status: Arc<Mutex<FinalityStatus>>,
+}
+
+impl<T> Tagged<T> {
+    /// Status of the block of the value, updated by the polls of the
+    /// tracker that tagged it.
+    pub fn status(&self) -> FinalityStatus {
+        *self.status.lock().unwrap()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    optimistic_head: Option<CryptoHash>,
+    final_height: Option<u64>,
+    /// Blocks not known to be final or orphaned yet, by height.
+    pending: BTreeMap<u64, HashMap<CryptoHash, Arc<Mutex<FinalityStatus>>>>,
+}
+
+impl State {
+    fn status(&mut self, height: u64, hash: CryptoHash) -> Arc<Mutex<FinalityStatus>> {
+        self.pending
+            .entry(height)
+            .or_default()
+            .entry(hash)
+            .or_insert_with(|| Arc::new(Mutex::new(FinalityStatus::Optimistic)))
+            .clone()
+    }
+}
+
+/// Follows the optimistic and the final head with `block` calls. Clones
+/// share the blocks they track, so one clone can be polled, e.g. with
+/// [`FinalityTracker::into_stream`], while others tag data; only one
+/// should be polled at a time.
+#[derive(Clone, Debug)]
+pub struct FinalityTracker {
+    client: RpcClient,
+    poll_interval: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+impl FinalityTracker {
+    /// Tracker polling every second when turned into a stream.
+    pub fn new(client: RpcClient) -> Self {
+        Self {
+            client,
+            poll_interval: Duration::from_secs(1),
+            state: Arc::default(),
+        }
+    }
+
+    /// Delay between polls of [`FinalityTracker::into_stream`].
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Tags `value` ingested from the block `block_hash` at `height`, and
+    /// tracks the block until its finality is known. Tags of blocks the
+    /// tracker already resolved are final or reverted after the next poll.
+    pub fn tag<T>(&self, value: T, height: u64, block_hash: CryptoHash) -> Tagged<T> {
+        let status = self.state.lock().unwrap().status(height, block_hash);
+        Tagged {
+            value,
+            height,
+            block_hash,
+            status,
+        }
+    }
+
+    /// Fetches both heads and resolves the tracked blocks the final head
+    /// reached, by comparing them with the final block at their height.
+    /// Events are ordered by height, with `NewHead` last.
+    pub async fn poll(&self) -> Result<Vec<FinalityEvent>, RpcError<ErrorWrapperForRpcBlockError>> {
+        let optimistic: HeaderOnly = self
+            .client
+            .call("block", &RpcBlockRequest::Finality(Finality::Optimistic))
+            .await?;
+        let head: HeaderOnly = self
+            .client
+            .call("block", &RpcBlockRequest::Finality(Finality::Final))
+            .await?;
+        let (optimistic, head) = (optimistic.header, head.header);
+
+        let heights: Vec<u64> = {
+            let mut state = self.state.lock().unwrap();
+            if head.height < optimistic.height {
+                state.status(optimistic.height, optimistic.hash);
+            }
+            state
+                .pending
+                .range(..=head.height)
+                .map(|(&height, _)| height)
+                .collect()
+        };
+        let mut canonical = Vec::with_capacity(heights.len());
+        for height in heights {
+            let hash = if height == head.height {
+                Some(head.hash)
+            } else {
+                let request = RpcBlockRequest::BlockId(BlockId::BlockHeight(height));
+                match self
+                    .client
+                    .call::<_, HeaderOnly, _>("block", &request)
+                    .await
+                {
+                    Ok(block) => Some(block.header.hash),
+                    // Below the final head, a missing block was skipped for
+                    // good.
+                    Err(RpcError::Rpc(ErrorWrapperForRpcBlockError::HandlerError(
+                        RpcBlockError::UnknownBlock(_),
+                    ))) => None,
+                    Err(err) => return Err(err),
+                }
+            };
+            canonical.push((height, hash));
+        }
+
+        let mut events = Vec::new();
+        let mut state = self.state.lock().unwrap();
+        for (height, final_hash) in canonical {
+            let Some(blocks) = state.pending.remove(&height) else {
+                continue;
+            };
+            let mut blocks: Vec<_> = blocks.into_iter().collect();
+            blocks.sort_by_key(|(hash, _)| *hash);
+            for (hash, status) in blocks {
+                let event = if Some(&hash) == final_hash.as_ref() {
+                    *status.lock().unwrap() = FinalityStatus::Final;
+                    FinalityEvent::Finalized { height, hash }
+                } else {
+                    *status.lock().unwrap() = FinalityStatus::Reverted;
+                    FinalityEvent::Orphaned { height, hash }
+                };
+                events.push(event);
+            }
+        }
+        state.final_height = Some(head.height);
+        if state.optimistic_head.as_ref() != Some(&optimistic.hash) {
+            state.optimistic_head = Some(optimistic.hash);
+            events.push(FinalityEvent::NewHead {
+                height: optimistic.height,
+                hash: optimistic.hash,
+            });
+        }
+        Ok(events)
+    }
+
+    /// Height of the final head at the last poll.
+    pub fn final_height(&self) -> Option<u64> {
+        self.state.lock().unwrap().final_height
+    }
+
+    /// Events of polls every `poll_interval`. A failed poll yields its error
+    /// and is retried after the interval.
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<FinalityEvent, RpcError<ErrorWrapperForRpcBlockError>>> {
+        futures_util::stream::unfold(
+            (self, VecDeque::new(), true),
+            |(tracker, mut events, mut first)| async move {
+                loop {
+                    if let Some(event) = events.pop_front() {
+                        return Some((Ok(event), (tracker, events, first)));
+                    }
+                    if !first {
+                        time::sleep(tracker.poll_interval).await;
+                    }
+                    first = false;
+                    match tracker.poll().await {
+                        Ok(polled) => events.extend(polled),
+                        Err(err) => return Some((Err(err), (tracker, events, first))),
+                    }
+                }
+            },
+        )
+    }
+}
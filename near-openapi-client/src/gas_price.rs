@@ -0,0 +1,67 @@
+//! Gas price smoothed over the last blocks, for fee estimators that need a
+//! steadier figure than the price of a single block.
+use crate::block::HeaderOnly;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{self, CryptoHash, NearToken};
+
+/// Gas prices of consecutive blocks, returned by
+/// [`RpcClient::gas_price_smoothed`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasPriceStats {
+    /// Height and gas price of each block, oldest first.
+    pub samples: Vec<(u64, NearToken)>,
+    pub min: NearToken,
+    pub max: NearToken,
+    /// Exponential moving average from the oldest block to the newest, with
+    /// a smoothing factor of `2 / (samples + 1)`.
+    pub ema: NearToken,
+}
+
+impl GasPriceStats {
+    /// Statistics of `samples`, oldest first, or `None` if there are none.
+    pub fn from_samples(samples: Vec<(u64, NearToken)>) -> Option<Self> {
+        let prices: Vec<u128> = samples
+            .iter()
+            .map(|(_, price)| price.as_yoctonear())
+            .collect();
+        let (&first, rest) = prices.split_first()?;
+        let n = prices.len() as u128;
+        // ema += (price - ema) * 2 / (n + 1), kept in integers.
+        let ema = rest.iter().fold(first, |ema, &price| {
+            (ema.saturating_mul(n - 1)
+                .saturating_add(price.saturating_mul(2)))
+                / (n + 1)
+        });
+        Some(Self {
+            min: NearToken::from_yoctonear(*prices.iter().min()?),
+            max: NearToken::from_yoctonear(*prices.iter().max()?),
+            ema: NearToken::from_yoctonear(ema),
+            samples,
+        })
+    }
+}
+
+impl RpcClient {
+    /// Gas prices of the last `window` final blocks, at least one, fetched
+    /// by walking back from the latest final block through `prev_hash`.
+    /// Fewer blocks are sampled if the chain starts within the window.
+    pub async fn gas_price_smoothed(
+        &self,
+        window: usize,
+    ) -> Result<GasPriceStats, RpcError<types::ErrorWrapperForRpcBlockError>> {
+        let mut request = types::RpcBlockRequest::Finality(types::Finality::Final);
+        let mut samples = Vec::with_capacity(window.max(1));
+        loop {
+            let block: HeaderOnly = self.call("block", &request).await?;
+            let header = block.header;
+            samples.push((header.height, header.gas_price));
+            if samples.len() >= window || header.prev_hash == CryptoHash::default() {
+                break;
+            }
+            request = types::RpcBlockRequest::BlockId(types::BlockId::CryptoHash(header.prev_hash));
+        }
+        samples.reverse();
+        Ok(GasPriceStats::from_samples(samples).expect("at least one block is sampled"))
+    }
+}
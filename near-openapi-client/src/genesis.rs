@@ -0,0 +1,66 @@
+//! Genesis config of the node, fetched once per client.
+//!
+//! The genesis config of a network never changes, so each [`RpcClient`]
+//! keeps the first one it fetched, shared by its clones, instead of sending
+//! `genesis_config` again, e.g. to tell mainnet from testnet before every
+//! transaction.
+//!
+//! ```rust,ignore
+//! if rpc_client.is_mainnet().await? {
+//!     println!("sending real NEAR");
+//! }
+//! let genesis = rpc_client.cached_genesis_config().await?;
+//! println!("epoch length: {}", genesis.epoch_length);
+//! ```
+use std::sync::{Arc, Mutex};
+
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    ChainId, ErrorWrapperForGenesisConfigError, GenesisConfig, GenesisConfigRequest,
+};
+
+/// Genesis config fetched by an [`RpcClient`], shared by its clones.
+#[derive(Clone, Default)]
+pub(crate) struct GenesisCache(Arc<Mutex<Option<Arc<GenesisConfig>>>>);
+
+impl std::fmt::Debug for GenesisCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self.0.lock().unwrap() {
+            Some(genesis) => write!(f, "GenesisCache({})", genesis.chain_id),
+            None => f.write_str("GenesisCache(empty)"),
+        }
+    }
+}
+
+impl RpcClient {
+    /// Genesis config of the node, fetched with `genesis_config` on the first
+    /// call and answered from memory afterwards. Calls made before the first
+    /// one returns may each fetch it; failures are not cached.
+    pub async fn cached_genesis_config(
+        &self,
+    ) -> Result<Arc<GenesisConfig>, RpcError<ErrorWrapperForGenesisConfigError>> {
+        if let Some(genesis) = self.genesis.0.lock().unwrap().clone() {
+            return Ok(genesis);
+        }
+        let genesis = Arc::new(self.genesis_config(&GenesisConfigRequest(())).await?);
+        Ok(self
+            .genesis
+            .0
+            .lock()
+            .unwrap()
+            .get_or_insert(genesis)
+            .clone())
+    }
+
+    /// Network of the node, read from its cached genesis config.
+    pub async fn chain_id(&self) -> Result<ChainId, RpcError<ErrorWrapperForGenesisConfigError>> {
+        let genesis = self.cached_genesis_config().await?;
+        Ok(ChainId::from(genesis.chain_id.as_str()))
+    }
+
+    /// Whether the node is on mainnet, see [`RpcClient::chain_id`].
+    pub async fn is_mainnet(&self) -> Result<bool, RpcError<ErrorWrapperForGenesisConfigError>> {
+        Ok(self.chain_id().await? == ChainId::Mainnet)
+    }
+}
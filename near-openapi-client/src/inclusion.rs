@@ -0,0 +1,208 @@
+//! Finding the chunks that carried a transaction and its receipts.
+//!
+//! Outcomes only tell the block a transaction or receipt was executed in, not
+//! the shard. A transaction is listed in the `transactions` of a chunk of the
+//! block its outcome is in. A receipt is listed in the `receipts` of the chunk
+//! of the shard that sent it, included in the block it is executed in, or in
+//! an earlier one if its execution was delayed. The only receipt no chunk
+//! lists is the one a transaction to its own signer is converted to, which is
+//! executed right away in the chunk of the transaction.
+//!
+//! ```rust,ignore
+//! let response = rpc_client.tx(&RpcTransactionStatusRequest::by_hash(hash, signer, wait_until)).await?;
+//! let inclusion = rpc_client.tx_inclusion(&response).await?;
+//! println!("shard {}", *inclusion.transaction.shard_id);
+//! for receipt in &inclusion.receipts {
+//!     println!("{} in chunk {} at height {}", receipt.id, receipt.chunk_hash, receipt.block_height);
+//! }
+//! ```
+use std::collections::HashMap;
+
+use crate::block::{BlockWithChunks, BlockWithChunksError};
+use crate::rpc::RpcClient;
+use crate::types::{
+    BlockId, BlockReference, CryptoHash, ExecutionOutcomeWithIdView, RpcTransactionResponse,
+    ShardId,
+};
+
+/// Chunk that carried a transaction or receipt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Inclusion {
+    /// Hash of the transaction or id of the receipt.
+    pub id: CryptoHash,
+    pub block_hash: CryptoHash,
+    pub block_height: u64,
+    pub shard_id: ShardId,
+    pub chunk_hash: CryptoHash,
+    /// Whether this is the receipt of a transaction to its own signer, which
+    /// is executed in the chunk of the transaction instead of being listed by
+    /// one.
+    pub local: bool,
+}
+
+/// Result of [`RpcClient::tx_inclusion`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TxInclusion {
+    pub transaction: Inclusion,
+    /// The receipts executed so far, in the order of their outcomes.
+    pub receipts: Vec<Inclusion>,
+}
+
+/// Error of [`RpcClient::tx_inclusion`].
+#[derive(Debug)]
+pub enum InclusionError {
+    /// A block or one of its chunks could not be fetched.
+    Block(BlockWithChunksError),
+    /// No chunk lists the transaction or receipt with this hash, e.g. because
+    /// the node garbage collected them.
+    NotFound(CryptoHash),
+}
+
+impl std::fmt::Display for InclusionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(err) => write!(f, "{}", err),
+            Self::NotFound(id) => write!(f, "no chunk lists {}", id),
+        }
+    }
+}
+
+impl std::error::Error for InclusionError {}
+
+impl From<BlockWithChunksError> for InclusionError {
+    fn from(value: BlockWithChunksError) -> Self {
+        Self::Block(value)
+    }
+}
+
+impl RpcClient {
+    /// The chunks that carried the transaction of `response` and each of
+    /// its receipts, found in the chunks of the blocks of their outcomes.
+    pub async fn tx_inclusion(
+        &self,
+        response: &RpcTransactionResponse,
+    ) -> Result<TxInclusion, InclusionError> {
+        let mut blocks = Blocks {
+            client: self,
+            by_hash: HashMap::new(),
+        };
+        let transaction = response.transaction();
+        let transaction_outcome = response.transaction_outcome();
+        let block = blocks.get(&transaction_outcome.block_hash).await?;
+        let chunk = block
+            .chunks
+            .iter()
+            .find(|chunk| {
+                chunk
+                    .transactions
+                    .iter()
+                    .any(|tx| tx.hash == transaction.hash)
+            })
+            .ok_or_else(|| InclusionError::NotFound(transaction.hash))?;
+        let transaction_inclusion = Inclusion {
+            id: transaction.hash,
+            block_hash: block.header.hash,
+            block_height: block.header.height,
+            shard_id: chunk.header.shard_id.clone(),
+            chunk_hash: chunk.header.chunk_hash,
+            local: false,
+        };
+
+        // Block of the outcome that created each receipt: the receipt is
+        // carried by a chunk included after it.
+        let mut created_in: HashMap<&CryptoHash, &ExecutionOutcomeWithIdView> = HashMap::new();
+        for outcome in std::iter::once(transaction_outcome).chain(response.receipts_outcome()) {
+            for receipt_id in &outcome.outcome.receipt_ids {
+                created_in.insert(receipt_id, outcome);
+            }
+        }
+        let mut receipts = Vec::with_capacity(response.receipts_outcome().len());
+        for outcome in response.receipts_outcome() {
+            let parent = created_in.get(&outcome.id).copied();
+            if parent.is_some_and(|parent| parent.id == transaction.hash)
+                && transaction.signer_id == transaction.receiver_id
+            {
+                receipts.push(Inclusion {
+                    id: outcome.id,
+                    local: true,
+                    ..transaction_inclusion.clone()
+                });
+                continue;
+            }
+            // A receipt of unknown origin is only looked for in the block it
+            // was executed in.
+            let created_at_height = match parent {
+                Some(parent) => blocks.get(&parent.block_hash).await?.header.height,
+                None => blocks
+                    .get(&outcome.block_hash)
+                    .await?
+                    .header
+                    .height
+                    .saturating_sub(1),
+            };
+            receipts.push(
+                blocks
+                    .find_receipt(&outcome.id, &outcome.block_hash, created_at_height)
+                    .await?,
+            );
+        }
+        Ok(TxInclusion {
+            transaction: transaction_inclusion,
+            receipts,
+        })
+    }
+}
+
+/// Blocks with their chunks, fetched once each.
+struct Blocks<'a> {
+    client: &'a RpcClient,
+    by_hash: HashMap<CryptoHash, BlockWithChunks>,
+}
+
+impl Blocks<'_> {
+    async fn get(&mut self, block_hash: &CryptoHash) -> Result<&BlockWithChunks, InclusionError> {
+        if !self.by_hash.contains_key(block_hash) {
+            let block = self
+                .client
+                .block_with_chunks(BlockReference::BlockId(BlockId::CryptoHash(*block_hash)))
+                .await?;
+            self.by_hash.insert(*block_hash, block);
+        }
+        Ok(&self.by_hash[block_hash])
+    }
+
+    /// Looks for the chunk listing `receipt_id` in the block it was executed
+    /// in and then in the blocks before it, down to the block it was created
+    /// in.
+    async fn find_receipt(
+        &mut self,
+        receipt_id: &CryptoHash,
+        executed_in: &CryptoHash,
+        created_at_height: u64,
+    ) -> Result<Inclusion, InclusionError> {
+        let mut block_hash = *executed_in;
+        loop {
+            let block = self.get(&block_hash).await?;
+            if block.header.height <= created_at_height {
+                return Err(InclusionError::NotFound(*receipt_id));
+            }
+            let chunk = block.chunks.iter().find(|chunk| {
+                chunk
+                    .receipts
+                    .iter()
+                    .any(|receipt| receipt.receipt_id == *receipt_id)
+            });
+            if let Some(chunk) = chunk {
+                return Ok(Inclusion {
+                    id: *receipt_id,
+                    block_hash: block.header.hash,
+                    block_height: block.header.height,
+                    shard_id: chunk.header.shard_id.clone(),
+                    chunk_hash: chunk.header.chunk_hash,
+                    local: false,
+                });
+            }
+            block_hash = block.header.prev_hash;
+        }
+    }
+}
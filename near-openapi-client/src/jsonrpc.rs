@@ -0,0 +1,241 @@
+//! JSON-RPC envelope handling shared by the hand-written helpers.
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::network::WrongNetwork;
+use crate::request_id::RequestId;
+use crate::{Client, ClientInfo, Error};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+/// Error of a JSON-RPC call: either the request failed, or the node answered
+/// with an error object (one of the `ErrorWrapperFor*` types).
+#[derive(Debug)]
+pub enum RpcError<E> {
+    /// The request could not be performed or its response could not be decoded.
+    Client(Error<()>),
+    /// The node returned a JSON-RPC error.
+    Rpc(E),
+    /// The node is not on the network the client was pinned to.
+    WrongNetwork(Box<WrongNetwork>),
+}
+
+impl<E: std::fmt::Debug> std::fmt::Display for RpcError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client(err) => write!(f, "request failed: {}", err),
+            Self::Rpc(err) => write!(f, "rpc error: {:?}", err),
+            Self::WrongNetwork(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug> std::error::Error for RpcError<E> {}
+
+impl RpcError<Infallible> {
+    /// The same failure, typed as the error of a call of any method.
+    pub(crate) fn widen<E>(self) -> RpcError<E> {
+        match self {
+            Self::Client(err) => RpcError::Client(err),
+            Self::Rpc(never) => match never {},
+            Self::WrongNetwork(err) => RpcError::WrongNetwork(err),
+        }
+    }
+}
+
+impl<E> From<Error<()>> for RpcError<E> {
+    fn from(value: Error<()>) -> Self {
+        Self::Client(value)
+    }
+}
+
+impl<E> From<reqwest::Error> for RpcError<E> {
+    fn from(value: reqwest::Error) -> Self {
+        Self::Client(Error::CommunicationError(value))
+    }
+}
+
+#[derive(serde::Serialize)]
+pub(crate) struct Request<'a, P> {
+    pub(crate) id: &'a str,
+    pub(crate) jsonrpc: &'a str,
+    pub(crate) method: &'a str,
+    pub(crate) params: &'a P,
+}
+
+#[derive(serde::Deserialize)]
+struct Response<R, E> {
+    result: Option<R>,
+    error: Option<E>,
+}
+
+/// Sends `method` with `params` and decodes the `result` member into `R`.
+///
+/// Unlike the generated methods, `R` may be any projection of the result,
+/// which lets callers skip decoding parts of large responses.
+pub(crate) async fn call<P, R, E>(
+    client: &Client,
+    method: &str,
+    params: &P,
+) -> Result<R, RpcError<E>>
+where
+    P: serde::Serialize,
+    R: DeserializeOwned,
+    E: DeserializeOwned,
+{
+    decode(send(client, method, params).await?)
+}
+
+/// Sends `method` with `params` and returns the raw response body.
+pub(crate) async fn send<P, E>(
+    client: &Client,
+    method: &str,
+    params: &P,
+) -> Result<bytes::Bytes, RpcError<E>>
+where
+    P: serde::Serialize,
+{
+    Ok(post(client, encode(method, params)?, None).await?)
+}
+
+/// Encodes the request of `method` with `params`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn encode<P: serde::Serialize>(
+    method: &str,
+    params: &P,
+) -> Result<bytes::Bytes, Error<()>> {
+    let request = Request {
+        id: "dontcare",
+        jsonrpc: "2.0",
+        method,
+        params,
+    };
+    serde_json::to_vec(&request)
+        .map(bytes::Bytes::from)
+        .map_err(|err| Error::InvalidRequest(err.to_string()))
+}
+
+/// `body` encoded by [`encode`], with the JSON-RPC id `request_id` instead
+/// of `dontcare`. Batches keep their ids.
+pub(crate) fn with_id(body: &bytes::Bytes, request_id: &RequestId) -> bytes::Bytes {
+    let Some(rest) = body.strip_prefix(br#"{"id":"dontcare""#.as_slice()) else {
+        return body.clone();
+    };
+    let mut encoded = br#"{"id":"#.to_vec();
+    serde_json::to_writer(&mut encoded, request_id.as_str()).expect("strings encode to JSON");
+    encoded.extend_from_slice(rest);
+    encoded.into()
+}
+
+/// Posts an encoded request, or a batch of them, and returns the raw
+/// response body. `timeout` replaces the timeout of the `reqwest` client for
+/// this request.
+pub(crate) async fn post(
+    client: &Client,
+    body: bytes::Bytes,
+    timeout: Option<Duration>,
+) -> Result<bytes::Bytes, Error<()>> {
+    let request = client.client().post(client.baseurl());
+    #[cfg(not(target_arch = "wasm32"))]
+    let request = match timeout {
+        Some(timeout) => request.timeout(timeout),
+        None => request,
+    };
+    #[cfg(target_arch = "wasm32")]
+    let _ = timeout;
+    let response = request
+        .header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        )
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        )
+        .body(body)
+        .send()
+        .await
+        .map_err(Error::CommunicationError)?;
+    if response.status().as_u16() != 200 {
+        return Err(Error::UnexpectedResponse(response));
+    }
+    response.bytes().await.map_err(Error::ResponseBodyError)
+}
+
+/// Decodes a response body returned by [`send`].
+#[allow(clippy::result_large_err)]
+pub(crate) fn decode<R, E>(body: bytes::Bytes) -> Result<R, RpcError<E>>
+where
+    R: DeserializeOwned,
+    E: DeserializeOwned,
+{
+    decode_borrowed(&body)
+}
+
+/// Decodes a response body into `R`, which may borrow from it.
+#[allow(clippy::result_large_err)]
+fn decode_borrowed<'a, R, E>(body: &'a bytes::Bytes) -> Result<R, RpcError<E>>
+where
+    R: Deserialize<'a>,
+    E: DeserializeOwned,
+{
+    let decoded: Response<R, E> = serde_json::from_slice(body)
+        .map_err(|err| Error::InvalidResponsePayload(body.clone(), err))?;
+    match decoded {
+        Response {
+            error: Some(error), ..
+        } => Err(RpcError::Rpc(error)),
+        Response {
+            result: Some(result),
+            ..
+        } => Ok(result),
+        // `null` results are only valid for nullable `R`.
+        Response { result: None, .. } => serde_json::from_str("null")
+            .map_err(|err| Error::InvalidResponsePayload(body.clone(), err).into()),
+    }
+}
+
+/// Undecoded response body of a call, see `RpcClient::request_raw`.
+///
+/// Its result can be decoded into types borrowing from the body, such as
+/// the views of `types::borrowed`, as long as the response is kept alive.
+pub struct RawResponse<E> {
+    body: bytes::Bytes,
+    error: PhantomData<fn() -> E>,
+}
+
+impl<E> RawResponse<E> {
+    pub(crate) fn new(body: bytes::Bytes) -> Self {
+        Self {
+            body,
+            error: PhantomData,
+        }
+    }
+
+    /// The whole JSON-RPC response, as returned by the node.
+    pub fn body(&self) -> &bytes::Bytes {
+        &self.body
+    }
+}
+
+impl<E: DeserializeOwned> RawResponse<E> {
+    /// Decodes the `result` member into `R`, which may borrow from the
+    /// response, or the `error` member into `E`.
+    #[allow(clippy::result_large_err)]
+    pub fn decode<'a, R: Deserialize<'a>>(&'a self) -> Result<R, RpcError<E>> {
+        decode_borrowed(&self.body)
+    }
+}
+
+impl<E> Clone for RawResponse<E> {
+    fn clone(&self) -> Self {
+        Self::new(self.body.clone())
+    }
+}
+
+impl<E> std::fmt::Debug for RawResponse<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RawResponse").field(&self.body).finish()
+    }
+}
@@ -14,6 +14,22 @@
 
 //!
 
+//!near-openapi-types, together with its offline helpers, has no HTTP or async runtime dependencies, so it can be used on its own, e.g. in air-gapped signing services.
+
+//!
+
+//!near-openapi-types builds without std when its default features are off (`default-features = false`): it is then `no_std` and only has the hand-written primitives `CryptoHash`, `PublicKey`, `Signature`, `ChainId` and `error::ConversionError`, with their serde and borsh encodings, e.g. for hardware signers. The generated types and every feature need the `std` feature, including `tx` with the actions, the transaction builder and the borsh encoding of transactions, since they are built on `AccountId`, `NearToken` and `NearGas`, whose crates are std-only; CI builds the primitives for `thumbv7em-none-eabihf`.
+
+//!
+
+//!`CryptoHash` is `Copy`, `Hash` and `Ord`, so it can key maps without cloning. `CryptoHash::from_bytes`, `From<[u8; 32]>` and `TryFrom<&[u8]>` build it from raw bytes and `as_bytes()` returns them, without a round trip through base58; `CryptoHash::ZERO` is the all-zero hash, usable in `const` items. Parsing, `Display` and serde encode and decode base58 on the stack, without allocating.
+
+//!
+
+//!`PublicKey` holds the raw bytes of an ed25519 or secp256k1 key (`PublicKey::Ed25519([u8; 32])`, `PublicKey::Secp256k1([u8; 64])`) and is also `Copy`. Parsing checks the `ed25519:`/`secp256k1:` prefix and the length of the key, so responses with malformed keys fail to decode instead of failing later, e.g. when a transaction is signed; `key_type()` and `key_data()` return the curve and the bytes, and serde keeps the string wire format. `Signature` is parsed the same way (`Signature::Ed25519([u8; 64])`, `Signature::Secp256k1([u8; 65])`, `to_bytes()` for the raw bytes). With the `crypto` feature, `PublicKey::verify(message, &signature)` and `Signature::verify(message, &public_key)` check a signature the way nearcore does, e.g. over the hash of a transaction, and `tx::SignedTransaction::verify` and `SignedDelegateAction::verify` check the signature of a transaction or of a delegate action (over `DelegateAction::hash`, its NEP-461 hash) against its public key.
+
+//!
+
 //!### Usage example:
 
 //!
@@ -246,9 +262,13 @@
 
 //!            challenges_result: [],
 
-//!            challenges_root: CryptoHash(
+//!            challenges_root: Some(
 
-//!                "11111111111111111111111111111111",
+//!                CryptoHash(
+
+//!                    "11111111111111111111111111111111",
+
+//!                ),
 
 //!            ),
 
@@ -396,8 +416,414 @@
 
 //!</details>
 
+//!
+
+//!`rpc::RpcClient` has one method per RPC method that takes the `params` of the request and returns the `result` of the response, with node errors returned as `RpcError::Rpc`:
+
+//!```rust,ignore
+
+//!let rpc_client = client::rpc::RpcClient::new(NEAR_RPC_URL_REMOTE);
+
+//!let block = rpc_client
+
+//!    .block(&client::types::RpcBlockRequest::Finality(client::types::Finality::Final))
+
+//!    .await?;
+
+//!println!("block height: {}", block.header.height);
+
+//!```
+
+//!Methods whose result is `null` on success, such as `health`, return their unit type, e.g. `RpcHealthResponse(())`, so a failure is always an `Err`.
+
+//!
+
+//!Hosted RPC providers requiring an API key or a bearer token are reached with a client from `Client::builder(url)`, whose `with_api_key` sends the `x-api-key` header, `with_bearer_token` the `Authorization` header and `default_headers` any other headers with every request: `RpcClient::from(Client::builder(url).with_api_key(key).build()?)`. Secrets are left out of `Debug` output.
+
+//!
+
+//!The builder also replaces the timeouts and connection pooling of `reqwest`: `timeout` bounds whole requests (15 seconds by default), `connect_timeout` connecting, `pool_max_idle_per_host` and `pool_idle_timeout` the idle connections kept open and `tcp_keepalive` the keep-alive probes on them. `method_timeout(method, timeout)` gives slow methods such as `EXPERIMENTAL_light_client_proof` their own timeout in the `RpcClient` of `build_rpc()`; `RpcClient::with_method_timeout` does the same for any client. These settings are ignored on wasm.
+
+//!
+
+//!Responses about blocks that can no longer change can be served from memory with `RpcClient::with_cache(ResponseCache::new(capacity))`, optionally `.with_ttl(ttl)`. The cache keeps the least recently used responses to requests at a block hash or the genesis checkpoint, and to requests at a height once a response with `finality: final` has shown that height to be final (or `set_final_height` was called). Requests with a finality, requests without a block reference and error responses always go to the node.
+
+//!
+
+//!To tell an RPC provider which call failed, `RpcClient::with_request_ids(request_id::RequestIds::with_prefix("indexer"))` sends every call with a locally generated JSON-RPC id such as `indexer-17` instead of `dontcare`; retries keep the id of their call. `request_with_id::<M>(&params)` returns a `RequestError` holding the id, whose message tells the whole cause of the error, e.g. `request indexer-17 failed: HANDLER_ERROR: UNKNOWN_BLOCK: {"block_reference":{"block_id":1}}`. With the `tracing` feature, the id is a field of the call span, and `RequestIds::log_error_causes()` adds the cause chain of failed calls to it.
+
+//!
+
+//!`RpcClient::with_retry(retry::RetryPolicy::new())` retries calls failing with transport errors, 429/5xx statuses or transient node errors such as `UNKNOWN_BLOCK`, with exponential backoff and jitter. Reads right at the chain tip, e.g. `EXPERIMENTAL_changes_in_block` for a block just yielded by a `BlockStream`, can go through `request_at_tip`, which retries a read failing with `UNKNOWN_BLOCK` once, either after a delay or at the previous block (`retry::TipRetry`).
+
+//!
+
+//!`error::classify(&err)` reduces the error of any call to an `error::ErrorClass`: `Retryable`, `InvalidRequest`, `NotFound`, `ExecutionFailure { reason }` for rejected transactions and failed contract calls, or `Internal`. `error::is_nonce_error` and `error::is_balance_error` tell whether a transaction was rejected for its nonce or for lack of balance, without matching on the nested `InvalidTxError` and `ActionErrorKind`.
+
+//!
+
+//!`ActionErrorKind` and `ActionError` implement `Display` and `Error` with the messages nearcore uses, filled in with the accounts, keys and amounts of the error, e.g. "The account alice.near wouldn't have enough balance to cover storage, required to have 1000 yoctoNEAR more", so wallets can show failed actions to their users as they are.
+
+//!
+
+//!`RpcClient::with_transport` sends the encoded requests with a `transport::Transport` instead of the `reqwest` client of the generated client, e.g. to use another HTTP stack or to add middleware around `transport::ReqwestTransport`. A transport posts a request body and returns the status and body of the response; retries, metrics, fixtures and the network check work the same with any transport.
+
+//!
+
+//!`RpcClient::with_fixtures(replay::Fixtures::record(dir))` writes every request and its response to a JSON file in `dir`, named after the method and a hash of the request; `Fixtures::replay(dir)` answers the same requests from these files without network access, e.g. for deterministic tests of an indexer against mainnet payloads captured once. Replaying a request that was not recorded fails with `Error::InvalidRequest`.
+
+//!
+
+//!Libraries that take a client without being generic over it can take a `&dyn near_client::NearClient` (or `Arc<dyn NearClient>`), which `RpcClient` implements: its only method sends JSON params and returns the JSON result, and `request::<M>` on `dyn NearClient` validates the params and decodes the response of the method `M`. The typed methods of `RpcClient` only encode params and decode responses, sharing one retry, metrics and network check path, so each method instantiated in a crate adds little code.
+
+//!
+
+//!Several calls can be sent in a single HTTP request with `batch::BatchRequest`, which takes the method as a marker type from `methods` and returns the results in the order of the calls:
+
+//!```rust,ignore
+
+//!let mut batch = client::batch::BatchRequest::new();
+
+//!let calls: Vec<_> = requests
+
+//!    .iter()
+
+//!    .map(|request| batch.add::<client::methods::Query>(request))
+
+//!    .collect();
+
+//!let response = batch.send(&rpc_client).await?;
+
+//!let results: Vec<_> = calls.into_iter().map(|call| response.get(call)).collect();
+
+//!```
+
+//!
+
+//!`block::BlockStream` walks the chain from a starting block as an async `Stream` of `RpcBlockResponse`s, skipping heights without a block and waiting for new blocks to reach the requested finality. `RpcClient::block_with_chunks` fetches a block and the bodies of its new chunks concurrently. Its `transactions()` and `receipts()` iterate over the typed transactions and receipts of all its chunks as `(shard_id, item)` pairs, in shard order.
+
+//!
+
+//!`BlockStream::config(block::IngestionConfig { .. })` tunes how a stream catches up with the head:
+
+//! - `concurrency` - block requests in flight at once, 1 by default. Raise it when catching up from an old block, as far as the node and its rate limits allow; at the head blocks arrive at the pace of the chain whatever the setting.
+
+//! - `cache_size` - blocks fetched ahead of the one the consumer waits for, in flight or waiting to be taken, at least `concurrency`. A few times `concurrency` keeps requests in flight while a slow block holds up the ones after it, at the cost of the memory of the cached blocks.
+
+//! - `poll_interval` - delay between polls for a new head once caught up, 1 second by default.
+
+//!
+
+//!Blocks are always yielded in order. `BlockStream::into_header_stream` yields only the headers of the blocks, skipping over their chunk headers while decoding. The `ingestion` example measures the blocks per second of a node for a range of settings: `cargo run --release --example ingestion --features bench -- <rpc-url> <start-height> [blocks]`.
+
+//!
+
+//!`RpcClient::gas_price_smoothed(window)` samples the gas price of the last `window` final blocks, walking back from the latest one, and returns a `gas_price::GasPriceStats` with the samples, their minimum, maximum and exponential moving average, a steadier base for fee estimates than the price of a single block.
+
+//!
+
+//!`RpcClient::cached_genesis_config()` fetches the genesis config of the node once and answers later calls, also from clones of the client, from memory, since it never changes. `RpcClient::chain_id()` reads the network from it as a `ChainId` (`Mainnet`, `Testnet` or `Custom`), and `is_mainnet()` checks for mainnet, e.g. before sending real funds.
+
+//!
+
+//!`RpcClient::validators_for_epochs(range)` returns the validators of the epochs with a height in `range` as a map from epoch height to `validators::EpochValidators`, with the epoch id, its first block, stakes and production counts. It walks back from the latest epoch through the block before the first block of each, since the `validators` method can't be asked for an epoch by height. Finished epochs are cached by the client and its clones, so later walks only fetch the latest epoch again.
+
+//!
+
+//!`RpcClient::maintenance_schedule(account_id)` returns the `EXPERIMENTAL_maintenance_windows` of a validator in the current epoch as a `maintenance::MaintenanceSchedule`, with the estimated start and end time of every window. Heights are converted to times at the average block time since the first block of the epoch, counted from the final block. `next_maintenance_window(account_id)` returns the window in progress or coming next. Blocks may come faster or slower than before, so the times are estimates to plan around with some margin.
+
+//!
+
+//!`RpcValidatorResponse::current_validator_set()` wraps the validators of the `validators` response in a `types::validator_set::ValidatorSet`, sorted by stake and indexed by account id, with `total_stake()`, `stake_share(account_id)` and `nakamoto_coefficient()`, the fewest validators holding more than a third of the stake. `CurrentEpochValidatorInfo::production_rate()` and the per-kind block, chunk and endorsement rates give the share of expected work produced so far, and `is_at_risk(threshold)` and `ValidatorSet::at_risk(threshold)` flag validators below a kickout threshold of the epoch config, in percent, e.g. `block_producer_kickout_threshold`. `kickout_reason(account_id)` tells why a validator was kicked out at the end of the previous epoch. Requires the `validators` feature.
+
+//!
+
+//!`stake_events::StakeEventStream` walks blocks like a `BlockStream` and yields typed `StakeEvent`s per account: `ProposalSubmitted` for the stake proposals in block headers, and at each epoch transition `JoinedSet`, `Kicked` with the kickout reason, and `StakeChanged` for validators whose stake changed or who left the set.
+
+//!
+
+//!`RpcClient::fee_estimator(block)` reads the gas price of a block and the fee tables of the protocol config at that block into a `types::fees::FeeEstimator`, also re-exported as `fees::FeeEstimator`. The estimator does no requests: `FeeEstimator::new(transaction_costs, gas_price)` builds one offline from near-openapi-types alone, with its `config` and `tx` features. Its `estimate_transaction` adds up the send and execution fees of the receipt and of every action, per-byte fees of code, arguments and method names, the gas attached to function calls and the attached deposits, except those of the actions inside delegate actions, which their sender pays, and returns a `FeeEstimate` with the gas, its cost in tokens and the total the signer needs. `estimate_total_cost` does the same for a list of actions, assuming the receiver is another account. Prepaid gas that is not used is refunded, so estimates of function calls are an upper bound.
+
+//!
+
+//!`RpcClient::tx_inclusion` finds the chunks that carried a transaction and each of its receipts from an `RpcTransactionResponse`, with the shard and height of each, following receipts whose execution was delayed back to the block whose chunk listed them.
+
+//!
+
+//!`RpcClient::final_outcome(tx_hash, sender)` fetches a final transaction and walks the receipts it led to from its outcome, returning a `final_outcome::FinalOutcome` with the outcomes of the tree in depth-first order and a status flattened from them: the first failure, else the value the transaction's receipts returned. Receipts of the tree not executed yet, such as refunds, are fetched with `EXPERIMENTAL_receipt` into `pending_receipts`.
+
+//!
+
+//!`RpcClient::account_exists` tells whether an account exists in the latest final block, turning the `UNKNOWN_ACCOUNT` error of `view_account` into `account::AccountExistence::NeverExisted`. `account_exists_within(account_id, blocks)` also searches the changes of a missing account in the last `blocks` blocks for its deletion, returning `AccountExistence::Deleted` with the receipt that deleted it and its beneficiary while the node still has the receipt.
+
+//!
+
+//!`RpcClient::view_function` calls a view method of a contract with JSON arguments and decodes its JSON result. `view_account`, `view_access_key`, `view_access_key_list` and `view_state` read an account, its access keys and its contract state at a `BlockReference` in one call, `view_state` returning the state as a map of decoded keys and values; `RpcQueryRequest::with_block` moves any query to another block. `BlockReference::final_()`, `optimistic()` (also `latest()`), `near_final()`, `at_height(height)` and `at_hash(hash)` spell out the block. `RpcClient::with_default_finality(finality)` sends the params of `block`, `query`, `changes` and the other methods taking a block at that finality when they have no `block_id`, `finality` or `sync_checkpoint`, e.g. untyped params of `call`. An `AccessKeyList` can be filtered by permission (`full_access`, `function_call`), by receiver (`for_receiver`) and by remaining allowance (`allowance_below`), iterated, and turned into a `HashMap` by public key with `into_map`. `multi_token` reads balances of NEP-245 multi-token contracts, and `types::multi_token` has the arguments of their transfer methods and their events.
+
+//!
+
+//!`RpcClient::view_state_iter` iterates over the contract state of an account, yielding decoded keys and values in key order, with `StateEntry::value_borsh` to decode values written by `near-sdk`. When a node refuses a prefix as too large, the iterator splits it into the 256 prefixes one byte longer and reads those instead, all at the block of the first page. A page is only fetched once the previous one is consumed, by `next()` or by the stream of `into_stream()`.
+
+//!
+
+//!`airdrop::Airdrop` pays a list of accounts in NEAR or in a NEP-141 token: token transfers are grouped into transactions of `ft_transfer` calls within the action and gas limits of a transaction, sent with bounded concurrency, and reported per recipient as transferred, failed or unknown. With `Airdrop::checkpoint(path)`, progress is written to a JSON file so that an interrupted airdrop resumes where it stopped, sending transactions with an unknown outcome again unchanged so that no recipient is paid twice.
+
+//!
+
+//!`snapshot::Snapshot` reads several queries from the same block: the first query pins the block it was answered at, e.g. by finality, and the following ones are sent at its hash, so a balance, the state and the access keys of an account are read consistently. `query_all` sends the queries after the first one concurrently. `RpcClient::snapshot(finality)` resolves the finality to a block hash up front and returns a snapshot pinned to it, with `view_account`, `view_access_key`, `view_access_key_list`, `view_code`, `view_state` and `view_function` read at that block, e.g. to value a portfolio across many token contracts consistently.
+
+//!
+
+//!`RpcClient::state_changes` queries state changes with a `state_changes::StateChangesRequest` made of a `BlockReference` and a `ChangesType` (e.g. `DataChanges { account_ids, key_prefix }`), picking the matching generated request variant, and decodes every change into one `StateChange` enum with a variant per kind, such as `AccountUpdate` or `DataUpdate`.
+
+//!
+
+//!`RpcClient::changes_in_block(block_reference)` fetches every change of a block for per-block indexing: it reads the accounts the block touched with `EXPERIMENTAL_changes_in_block`, fetches their changes with one `changes` request per kind at the hash of the block, and returns a `state_changes::BlockChanges` grouping them by account and `StateChangeKind` (`Account`, `AccessKey`, `GasKey`, `Data`, `ContractCode`), with `of(account_id, kind)` and `iter()`.
+
+//!
+
+//!`RpcClient::stream_changes(changes_type, from_block)` follows a `ChangesType` through the final blocks from `from_block` on, yielding a `StateChangeEvent` per change with the height and hash of its block. For every block it asks `EXPERIMENTAL_changes_in_block` which accounts changed, and fetches the changes only for blocks touching the followed accounts. `state_changes::StateChangesStream` sets the finality and poll interval; when following blocks that are not final, a change that a fork moves to another block is yielded only once.
+
+//!
+
+//!`finality::FinalityTracker` follows the optimistic and the final head with `block` calls. Each `poll()` reports a `NewHead` when the optimistic head moves, and for the blocks it saw before they were final, `Finalized` or `Orphaned` once the final head passes them. Data ingested from optimistic blocks can be tagged with `tracker.tag(value, height, hash)`, and the `status()` of the `Tagged` value flips from `Optimistic` to `Final` or `Reverted` when a poll resolves its block. `into_stream()` polls at an interval and yields the events.
+
+//!
+
+//!`nonce::NonceManager` caches access key nonces and hands out increasing ones, so that transactions can be signed and sent concurrently with the same key.
+
+//!
+
+//!`RpcClient::send_tx_and_wait(signed_tx, wait_until, timeout)` sends a transaction and keeps asking for it with `tx` until it reaches the `TxExecutionStatus` `wait_until`, when the node times out waiting or doesn't know the transaction yet, and fails with `tx_wait::TxWaitError::Timeout` and the last reported status once `timeout` expires. `TxExecutionStatus::satisfies` compares statuses, which are not totally ordered: `INCLUDED_FINAL` doesn't imply `EXECUTED_OPTIMISTIC`.
+
+//!
+
+//!`socialdb::SocialDb` reads profiles and other data of the Social DB contract (`social.near`) with its `get` and `keys` methods, returning the nested JSON as `SocialData`, which is navigable by key paths such as `alice.near/profile/name`.
+
+//!
+
+//!`near_contract!` declares typed bindings of other contracts: `view fn` methods with their argument and result types become async methods calling `view_function` at a given block, and `call fn` methods return a `contract::ContractCall` with 30 Tgas and no deposit attached, to adjust with `gas` and `deposit` and turn into a transaction with `transaction(signer_id, public_key)` or add to one as an action. Arguments are passed as a JSON object keyed by their names:
+
+//!```rust,ignore
+
+//!client::near_contract! {
+
+//!    pub struct FungibleToken {
+
+//!        view fn ft_balance_of(account_id: AccountId) -> String;
+
+//!        call fn ft_transfer(receiver_id: AccountId, amount: String, memo: Option<String>);
+
+//!    }
+
+//!}
+
+//!```
+
+//!
+
+//!`types::tx` builds, borsh-encodes, hashes and signs transactions, so they can be sent without other NEAR crates:
+
+//!```rust,ignore
+
+//!let secret_key: client::types::tx::SecretKey = "ed25519:...".parse()?;
+
+//!let signed_tx = client::types::tx::TransactionBuilder::new(signer_id, secret_key.public_key(), receiver_id)
+
+//!    .transfer(client::types::NearToken::from_near(1))
+
+//!    .build(nonce, block_hash)
+
+//!    .sign(&secret_key)?;
+
+//!rpc_client
+
+//!    .send_tx(&client::types::RpcSendTransactionRequest {
+
+//!        signed_tx_base64: signed_tx.to_base64()?,
+
+//!        wait_until: client::types::TxExecutionStatus::Final,
+
+//!    })
+
+//!    .await?;
+
+//!```
+
+//!
+
+//!`SignedTransaction::receipt_id(block_height)` derives the id of the receipt a transaction is converted to in the block at that height, and `tx::child_receipt_id` the ids of the receipts that receipt creates, so they can be looked up before the transaction outcome is known.
+
+//!
+
+//!The encoding is tested against vectors of nearcore and near-api-js (`example/tests/borsh_vectors.rs`): the hash of a transaction with one action of each kind, the bytes and ed25519 signature of a transfer, the bytes and NEP-461 hash of a delegate action and the ids of receipts, so transactions, delegate actions and receipts hashed and signed locally are the ones the network verifies.
+
+//!
+
+//!`types::chain_signatures` builds `sign` calls to the chain signatures MPC contract (`v1.signer`) with `TransactionBuilder::mpc_sign`, reads the returned `SignatureResponse` and derives the public key of an account and path with `derive_public_key`; `chain_signatures::mpc_public_key` fetches the root key it is derived from.
+
+//!
+
+//!`types::eth_implicit::EvmAddress` derives the EVM address and ETH-implicit account (`0x...`) of a secp256k1 public key, parses and prints addresses with their EIP-55 checksum, and builds transfers to them with `TransactionBuilder::transfer_to_evm_address`.
+
+//!
+
+//!`types::compact::CompactTransaction` wraps an unsigned or signed transaction in a small versioned and checksummed envelope, written as base45 for QR codes or as base64url for NFC, to pass transactions between an online wallet and an offline signer. Transactions can also be decoded from borsh with `Transaction::decode` and `SignedTransaction::decode`.
+
+//!
+
+//!`ReceiptView::token_flows` classifies the NEAR moved by each action of a receipt as a transfer, a function call or staking pool deposit, a validator stake, or a gas or deposit refund from `system` (`types::token_flow`).
+
+//!
+
+//!`ReceiptView::actions`, `input_data_ids`, `output_data_receivers` and `data_id` read the parts of Action and Data receipts without matching on `ReceiptEnumView`. `types::data_flow::DataFlow` pairs a set of receipts up by `data_id`: the call that promised its result in `output_data_receivers`, the Data receipt carrying it and the callback waiting for it in `input_data_ids`, with `producers` and `consumers` to walk cross-contract calls and `DataDependency::is_complete` to spot data that went missing or to the wrong account.
+
+//!
+
+//!`types::gas_refund::RefundPenaltyConfig` computes the penalty taken from gas refunds since protocol version 78 (5% of the unused gas, at least 1 Tgas), and `refund` splits the unused prepaid gas of a receipt into the refunded and the burnt part, e.g. to explain fee breakdowns.
+
+//!
+
+//!`types::known` lists well-known contract accounts per network (`known::mainnet::WRAP_NEAR`, `USDC`, `USDT`, `STAKING_POOL_FACTORY`, `LINKDROP`, `MPC_SIGNER`, `SOCIAL_DB`, and the same under `known::testnet`) as `AccountIdRef` constants, and `KnownAccounts::for_chain` picks them by `ChainId`.
+
+//!
+
+//!`types::balance` computes what an account can spend: `AccountView::balance(balance::STORAGE_AMOUNT_PER_BYTE)` splits its balance into the total, the stake, the cost of its storage and the available rest, the way wallets show it, and `balance::available_balance` returns the latter alone. `balance::add`, `sub`, `fraction` and `percent` are checked arithmetic on `NearToken` amounts that fail with a `BalanceError` telling overflow from insufficient balance, and `format_near` and `format_millinear` format amounts with a given number of decimals, rounded down so a balance is never shown larger than it is.
+
+//!
+
+//!`RuntimeConfigView::diff(&other)` compares two runtime configs, e.g. the `runtime_config` of `EXPERIMENTAL_protocol_config` at two protocol versions, and returns a `config_diff::ConfigDiff` listing every changed value by its path, such as `transaction_costs.action_creation_config.transfer_cost.execution`, with its old and new JSON value, for alerts when fees or limits change. Requires the `config` feature.
+
+//!
+
+//!`types::shard_layout` assigns accounts to shards the way nearcore does, for the `shard_layout` of `EXPERIMENTAL_protocol_config` or of the genesis config: `ShardLayout::account_to_shard(account_id)` returns the `ShardId` holding an account (by hash for version 0 layouts, by ranges between `boundary_accounts` for later ones), `shard_count()` and `shard_ids()` list the shards, and `validate_shard_id(shard_id)` returns the index of a shard or an `UnknownShardId` error, e.g. to partition indexing work by shard. Requires the `config` feature.
+
+//!
+
+//!With the `zero-copy` feature, `types::borrowed` has views of chunks, transactions, receipts and execution outcomes (`RpcChunkResponseRef`, `RpcTransactionResponseRef`, ...) that borrow account ids, base64 blobs, method names and logs from the response instead of allocating a `String` for each, for indexers decoding every block. Actions and receipts other than function calls, deployments and action or data receipts, and the errors of failures, are kept as undecoded JSON, and `ToOwnedView::to_owned_view` decodes a view into its generated type. `RpcClient::request_raw::<methods::Chunk>(&params)` returns a `RawResponse`, whose `decode::<RpcChunkResponseRef>()` borrows from it.
+
+//!
+
+//!`types::round_trip::canonical_json(&value)` encodes a value with sorted keys and no whitespace, so equal responses always give the same bytes to hash or compare, whatever the key order of the node or of `preserve-order` maps; block, chunk, transaction, receipt, account and other major views implement it as `CanonicalJson::canonical_json()`. `check_canonical_json::<T>(json)` checks that the canonical JSON of a response decodes to the same value and encodes to the same canonical JSON again.
+
+//!
+
+//!`types::descriptions::descriptions()` returns the descriptions of the OpenAPI spec by generated type, field and variant, e.g. `descriptions().member("ActionErrorKind", "AccountDoesNotExist")`, for documentation UIs or error messages that show the upstream explanation of a field. Fields of struct variants are named `Variant.field`.
+
+//!
+
+//!`types::limits` exposes the bounds the schema puts on numeric and array fields: every generated struct with such fields implements `FieldLimits`, whose `FIELD_LIMITS` list the minimum, maximum or item counts by JSON field name (e.g. `CongestionInfoView::field_limit("allowed_shard")` is a `uint16` of at most 65535). `FieldLimit::check` checks a value computed in a wider type before it is converted, and `validate()` checks every bounded field of a value.
+
+//!
+
+//!The params of every JSON-RPC method implement `types::validate::Validate`, which checks what their types cannot: changes requests must name at least one account or key, function calls need a method name, and base64 args, state prefixes and signed transactions must decode. `RpcClient::request` and batches validate params before sending them, failing with `Error::InvalidRequest` instead of a round trip to the node.
+
+//!
+
+//!`types::protocol_features::protocol_features(version)` lists the protocol features enabled at a protocol version, with the NEP specifying each of them, e.g. to gate what is sent on the version of the node. `ActionsValidationError::unsupported_protocol_feature` looks up the feature a transaction was rejected for with `UnsupportedProtocolFeature`.
+
+//!
+
+//!`BlockHeaderView::challenges_result` and `challenges_root` are empty since stateless validation (protocol version 69) removed challenges, so both default when missing and `challenges_root` is an `Option`. `types::legacy::LEGACY_FIELDS` lists such fields with the protocol version since which they carry nothing, and `BlockHeaderView::has_challenges` tells the few old blocks that do apart.
+
+//!
+
+//!`types::merkle` checks Merkle proofs the way nearcore builds them: `compute_root` follows a path of `MerklePathItem`s from a leaf, and `ExecutionOutcomeWithIdView::leaf_hash` and `RpcLightClientExecutionProofResponse::verify_outcome` check that an execution outcome hashes to the `outcome_root` of its block.
+
+//!
+
+//!Fields holding bytes as base64 strings have decoding accessors, e.g. `ContractCodeView::code_bytes`, `FunctionCallAction::args_bytes`, `StoreValue::to_bytes` and `FinalExecutionStatus::success_value_bytes`. Function call arguments and view results are JSON in most contracts: `FunctionArgs::from_json` and `FunctionArgs::to_json` encode and decode them, and `CallResult::json` decodes a result.
+
+//!
+
+//!`wallet::SignRequest` builds the URL that hands unsigned transactions to a web wallet such as MyNearWallet for signing, and `wallet::WalletCallback::from_query` reads the transaction hashes or the error from the query of the callback URL.
+
+//!
+
+//!With the `ws` feature, `ws::WsClient` subscribes to new block headers (`subscribe_blocks`) and to the execution outcomes of a transaction (`subscribe_tx_status`) on WebSocket endpoints of providers that stream them. The streams reconnect with backoff and subscribe again when the connection drops or stops answering pings (`WsClient::keepalive`), resuming block subscriptions after the last block received. `WsClient::events` reports `Connected`, `Disconnected` and `Resumed` changes, e.g. to surface the health of a pipeline.
+
+//!
+
+//!### Cargo features
+
+//!
+
+//! - `arbitrary` - derive `arbitrary::Arbitrary` for the generated types, e.g. to fuzz or property-test code handling `ActionView` or `ActionErrorKind`; variants only decoded by `lenient::decode_leniently` are never generated
+
+//! - `block`, `changes`, `config`, `debug`, `tx`, `validators` - compile only these groups of generated types of near-openapi-types and the helpers using them, e.g. `--no-default-features --features tx` for a signing service; `block` enables `tx` and `validators`, and `debug` (network and node status types) enables `block`. Errors, queries and the types they share are always compiled
+
+//! - `crypto` - verify signatures of messages, transactions and delegate actions (`PublicKey::verify`, `Signature::verify`), pulling in `ed25519-dalek` and `k256`
+
+//! - `full` - all groups of generated types, enabled by default; near-openapi-client always enables it
+
+//! - `historical-compat` - fall back to default values for fields that responses about old blocks lack (e.g. `BlockHeaderView::timestamp_nanosec`, `CongestionInfoView::allowed_shard`), so archival data can be replayed with the same types
+
+//! - `metrics` - `metrics::PrometheusRecorder`, which counts calls (`near_rpc_requests_total`) and errors by `ErrorWrapperFor*` variant and cause (`near_rpc_errors_total`) and measures their latency (`near_rpc_request_duration_seconds`) per method in a Prometheus registry; set it, or another `metrics::MetricsRecorder`, with `RpcClient::with_metrics`
+
+//! - `preserve-order` - decode maps of the generated types (`types::OrderedMap`, e.g. the `data` of `DeterministicStateInit` actions) into an `indexmap::IndexMap` keeping the order of the response, so re-encoding a block gives back the same JSON; without it they are `BTreeMap`s sorted by key
+
+//! - `schemars` - derive `schemars::JsonSchema` for the generated types, e.g. to embed `AccountView` or `ActionView` in the OpenAPI spec of a service; `CryptoHash`, `PublicKey` and `Signature` are strings as in the NEAR spec, and variants only decoded by `lenient::decode_leniently` are left out
+
+//! - `signing` - sign transactions with ed25519 and secp256k1 keys (`types::tx::SecretKey`) and derive chain signatures keys (`types::chain_signatures::derive_public_key`); enables `crypto`
+
+//! - `testing` - `testing::MockRpc`, a local JSON-RPC server for tests answering with typed responses: `mock.expect::<methods::GasPrice>().with_params(&params).return_ok(response)` registers a response (or `return_error`, `return_status`), `mock.client()` is an `RpcClient` sending to it, and dropping the mock panics if an expectation was not called as often as set with `times` or a request matched none
+
+//! - `tracing` - run every `RpcClient` call in an `rpc_call` span of the `tracing` crate with the method, the block reference of the params, the latency, the number of attempts and the `name`/`cause` of the RPC error, and log retries and failures as events in it
+
+//! - `wasm` - required to build for `wasm32-unknown-unknown`, e.g. for browser dApps and web wallets: `reqwest` sends requests with the fetch API there, retries and polls wait with `gloo-timers` instead of tokio, and retry jitter and signing draw randomness from the browser. The futures of `near_client::NearClient` are not `Send` on wasm32, and `ws` and `testing` are only available natively
+
+//! - `ws` - subscriptions over WebSocket (`ws::WsClient`); enable a TLS feature of `tokio-tungstenite` for `wss://` URLs
+
+//! - `zero-copy` - borrowed views of chunks and transactions (`types::borrowed`) and `RpcClient::request_raw` to decode them from the response body; enables `block` and the `raw_value` feature of `serde_json`
+
 //!
 pub use near_openapi_types as types;
+pub mod account;
+pub mod airdrop;
+pub mod batch;
+pub mod block;
+pub mod builder;
+pub mod cache;
+pub mod chain_signatures;
+pub mod contract;
+pub mod error;
+pub mod fees;
+pub mod final_outcome;
+pub mod finality;
+pub mod gas_price;
+pub mod genesis;
+pub mod inclusion;
+pub mod jsonrpc;
+pub mod maintenance;
+pub mod methods;
+pub mod metrics;
+pub mod multi_token;
+pub mod near_client;
+pub mod network;
+pub mod nonce;
+pub mod replay;
+pub mod request_id;
+pub mod resubmit;
+pub mod retry;
+pub mod rpc;
+pub mod snapshot;
+pub mod socialdb;
+pub mod stake_events;
+pub mod state;
+pub mod state_changes;
+pub mod storage;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod time;
+#[cfg(feature = "tracing")]
+mod trace;
+pub mod transport;
+pub mod tx_wait;
+pub mod validators;
+pub mod view;
+pub mod wallet;
+#[cfg(feature = "ws")]
+pub mod ws;
 #[allow(unused_imports)]
 use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
 #[allow(unused_imports)]
@@ -0,0 +1,188 @@
+//! Maintenance windows of a validator as wall-clock time ranges.
+//!
+//! `EXPERIMENTAL_maintenance_windows` returns the ranges of block heights of
+//! the current epoch in which a validator produces neither blocks nor
+//! chunks, so that its node can be restarted without missing any. Operators
+//! plan by the clock, so [`RpcClient::maintenance_schedule`] also estimates
+//! when each range starts and ends, from the time blocks took since the
+//! start of the epoch:
+//!
+//! ```rust,ignore
+//! let account_id = "validator.poolv1.near".parse()?;
+//! if let Some(window) = rpc_client.next_maintenance_window(&account_id).await? {
+//!     println!("blocks {}..{}, from {:?} to {:?}", window.start_height, window.end_height, window.start_time, window.end_time);
+//! }
+//! ```
+//!
+//! The times are estimates: blocks come faster or slower than in the past
+//! and heights may be skipped, so leave a margin at both ends of a window.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::block::HeaderOnly;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccountId, BlockHeaderView, BlockId, ErrorWrapperForRpcBlockError,
+    ErrorWrapperForRpcMaintenanceWindowsError, ErrorWrapperForRpcValidatorError, Finality,
+    RpcBlockRequest, RpcMaintenanceWindowsRequest, RpcValidatorRequest,
+};
+
+/// Error of [`RpcClient::maintenance_schedule`].
+#[derive(Debug)]
+pub enum MaintenanceError {
+    Validators(RpcError<ErrorWrapperForRpcValidatorError>),
+    Block(RpcError<ErrorWrapperForRpcBlockError>),
+    MaintenanceWindows(RpcError<ErrorWrapperForRpcMaintenanceWindowsError>),
+}
+
+impl std::fmt::Display for MaintenanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Validators(err) => write!(f, "failed to fetch validators: {}", err),
+            Self::Block(err) => write!(f, "failed to fetch block: {}", err),
+            Self::MaintenanceWindows(err) => {
+                write!(f, "failed to fetch maintenance windows: {}", err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MaintenanceError {}
+
+/// A range of heights without duties, with its estimated start and end.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceWindow {
+    pub start_height: u64,
+    /// First height after the window.
+    pub end_height: u64,
+    /// Estimated time of the block at `start_height`.
+    pub start_time: SystemTime,
+    /// Estimated time of the block at `end_height`.
+    pub end_time: SystemTime,
+}
+
+/// Maintenance windows of an account in the current epoch, returned by
+/// [`RpcClient::maintenance_schedule`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceSchedule {
+    pub account_id: AccountId,
+    pub epoch_height: u64,
+    pub epoch_start_height: u64,
+    /// Height of the final block the estimates start from.
+    pub head_height: u64,
+    /// Time of the final block.
+    pub head_time: SystemTime,
+    /// Average time between two heights since the start of the epoch.
+    pub block_time: Duration,
+    /// Windows sorted by height.
+    pub windows: Vec<MaintenanceWindow>,
+}
+
+impl MaintenanceSchedule {
+    /// The window in progress at the final block, or else the first one
+    /// after it.
+    pub fn next(&self) -> Option<&MaintenanceWindow> {
+        self.windows
+            .iter()
+            .find(|window| window.end_height > self.head_height)
+    }
+
+    /// Estimated time of the block at `height`, counting `block_time` per
+    /// height from the final block.
+    pub fn time_at(&self, height: u64) -> SystemTime {
+        let nanos = |heights: u64| {
+            let nanos = self.block_time.as_nanos() * u128::from(heights);
+            Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+        };
+        if height >= self.head_height {
+            self.head_time + nanos(height - self.head_height)
+        } else {
+            self.head_time
+                .checked_sub(nanos(self.head_height - height))
+                .unwrap_or(UNIX_EPOCH)
+        }
+    }
+}
+
+fn block_time(header: &BlockHeaderView) -> SystemTime {
+    UNIX_EPOCH + Duration::from_nanos(header.timestamp)
+}
+
+impl RpcClient {
+    /// Maintenance windows of `account_id` in the current epoch, with their
+    /// heights converted to estimated times.
+    ///
+    /// Uses the deprecated `EXPERIMENTAL_maintenance_windows`, which nodes
+    /// of all versions serve.
+    pub async fn maintenance_schedule(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<MaintenanceSchedule, MaintenanceError> {
+        let head = header(self, RpcBlockRequest::Finality(Finality::Final)).await?;
+        let validators = self
+            .validators(&RpcValidatorRequest::Latest)
+            .await
+            .map_err(MaintenanceError::Validators)?;
+        let ranges = self
+            .experimental_maintenance_windows(&RpcMaintenanceWindowsRequest {
+                account_id: account_id.clone(),
+            })
+            .await
+            .map_err(MaintenanceError::MaintenanceWindows)?;
+
+        // The rate since the first block of the epoch, or of the last block
+        // before it when the epoch just started.
+        let reference = if validators.epoch_start_height < head.height {
+            let height = validators.epoch_start_height;
+            header(self, RpcBlockRequest::BlockId(BlockId::BlockHeight(height))).await?
+        } else {
+            let hash = head.prev_hash;
+            header(self, RpcBlockRequest::BlockId(BlockId::CryptoHash(hash))).await?
+        };
+        let heights = head.height.saturating_sub(reference.height).max(1);
+        let elapsed = head.timestamp.saturating_sub(reference.timestamp);
+
+        let mut schedule = MaintenanceSchedule {
+            account_id: account_id.clone(),
+            epoch_height: validators.epoch_height,
+            epoch_start_height: validators.epoch_start_height,
+            head_height: head.height,
+            head_time: block_time(&head),
+            block_time: Duration::from_nanos(elapsed / heights),
+            windows: Vec::new(),
+        };
+        let mut windows: Vec<_> = ranges
+            .iter()
+            .map(|range| MaintenanceWindow {
+                start_height: range.start,
+                end_height: range.end,
+                start_time: schedule.time_at(range.start),
+                end_time: schedule.time_at(range.end),
+            })
+            .collect();
+        windows.sort_by_key(|window| window.start_height);
+        schedule.windows = windows;
+        Ok(schedule)
+    }
+
+    /// The maintenance window of `account_id` in progress or coming next in
+    /// the current epoch, see [`RpcClient::maintenance_schedule`].
+    pub async fn next_maintenance_window(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<MaintenanceWindow>, MaintenanceError> {
+        let schedule = self.maintenance_schedule(account_id).await?;
+        Ok(schedule.next().cloned())
+    }
+}
+
+async fn header(
+    client: &RpcClient,
+    request: RpcBlockRequest,
+) -> Result<BlockHeaderView, MaintenanceError> {
+    let block: HeaderOnly = client
+        .call("block", &request)
+        .await
+        .map_err(MaintenanceError::Block)?;
+    Ok(block.header)
+}
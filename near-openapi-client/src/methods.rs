@@ -0,0 +1,650 @@
+//! Typed JSON-RPC methods: a marker type implementing [`RpcMethod`] for
+//! each method of the spec, and the matching methods of [`RpcClient`].
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types;
+use crate::types::validate::Validate;
+
+/// A JSON-RPC method together with the types of its params, result and error.
+pub trait RpcMethod {
+    const NAME: &'static str;
+    type Params: Serialize + Validate;
+    type Response: DeserializeOwned;
+    type Error: DeserializeOwned;
+}
+
+#[doc = "[Deprecated] Returns changes for a given account, contract or contract code for given block height or hash. Consider using changes instead."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalChanges;
+
+impl RpcMethod for ExperimentalChanges {
+    const NAME: &'static str = "EXPERIMENTAL_changes";
+    type Params = types::RpcStateChangesInBlockByTypeRequest;
+    type Response = types::RpcStateChangesInBlockResponse;
+    type Error = types::ErrorWrapperForRpcStateChangesError;
+}
+
+#[doc = "[Deprecated] Returns changes in block for given block height or hash over all transactions for all the types. Includes changes like account_touched, access_key_touched, data_touched, contract_code_touched. Consider using block_effects instead"]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalChangesInBlock;
+
+impl RpcMethod for ExperimentalChangesInBlock {
+    const NAME: &'static str = "EXPERIMENTAL_changes_in_block";
+    type Params = types::RpcStateChangesInBlockRequest;
+    type Response = types::RpcStateChangesInBlockByTypeResponse;
+    type Error = types::ErrorWrapperForRpcStateChangesError;
+}
+
+#[doc = "Queries the congestion level of a shard. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)"]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalCongestionLevel;
+
+impl RpcMethod for ExperimentalCongestionLevel {
+    const NAME: &'static str = "EXPERIMENTAL_congestion_level";
+    type Params = types::RpcCongestionLevelRequest;
+    type Response = types::RpcCongestionLevelResponse;
+    type Error = types::ErrorWrapperForRpcChunkError;
+}
+
+#[doc = "[Deprecated] Get initial state and parameters for the genesis block. Consider genesis_config instead."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalGenesisConfig;
+
+impl RpcMethod for ExperimentalGenesisConfig {
+    const NAME: &'static str = "EXPERIMENTAL_genesis_config";
+    type Params = types::GenesisConfigRequest;
+    type Response = types::GenesisConfig;
+    type Error = types::ErrorWrapperForGenesisConfigError;
+}
+
+#[doc = "Returns the proofs for a transaction execution."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalLightClientBlockProof;
+
+impl RpcMethod for ExperimentalLightClientBlockProof {
+    const NAME: &'static str = "EXPERIMENTAL_light_client_block_proof";
+    type Params = types::RpcLightClientBlockProofRequest;
+    type Response = types::RpcLightClientBlockProofResponse;
+    type Error = types::ErrorWrapperForRpcLightClientProofError;
+}
+
+#[doc = "Returns the proofs for a transaction execution."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalLightClientProof;
+
+impl RpcMethod for ExperimentalLightClientProof {
+    const NAME: &'static str = "EXPERIMENTAL_light_client_proof";
+    type Params = types::RpcLightClientExecutionProofRequest;
+    type Response = types::RpcLightClientExecutionProofResponse;
+    type Error = types::ErrorWrapperForRpcLightClientProofError;
+}
+
+#[doc = "[Deprecated] Returns the future windows for maintenance in current epoch for the specified account. In the maintenance windows, the node will not be block producer or chunk producer. Consider using maintenance_windows instead."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalMaintenanceWindows;
+
+impl RpcMethod for ExperimentalMaintenanceWindows {
+    const NAME: &'static str = "EXPERIMENTAL_maintenance_windows";
+    type Params = types::RpcMaintenanceWindowsRequest;
+    type Response = Vec<types::RangeOfUint64>;
+    type Error = types::ErrorWrapperForRpcMaintenanceWindowsError;
+}
+
+#[doc = "A configuration that defines the protocol-level parameters such as gas/storage costs, limits, feature flags, other settings"]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalProtocolConfig;
+
+impl RpcMethod for ExperimentalProtocolConfig {
+    const NAME: &'static str = "EXPERIMENTAL_protocol_config";
+    type Params = types::RpcProtocolConfigRequest;
+    type Response = types::RpcProtocolConfigResponse;
+    type Error = types::ErrorWrapperForRpcProtocolConfigError;
+}
+
+#[doc = "Fetches a receipt by its ID (as is, without a status or execution outcome)"]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalReceipt;
+
+impl RpcMethod for ExperimentalReceipt {
+    const NAME: &'static str = "EXPERIMENTAL_receipt";
+    type Params = types::RpcReceiptRequest;
+    type Response = types::RpcReceiptResponse;
+    type Error = types::ErrorWrapperForRpcReceiptError;
+}
+
+#[doc = "Contains the split storage information. More info on split storage [here](https://near-nodes.io/archival/split-storage-archival)"]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalSplitStorageInfo;
+
+impl RpcMethod for ExperimentalSplitStorageInfo {
+    const NAME: &'static str = "EXPERIMENTAL_split_storage_info";
+    type Params = types::RpcSplitStorageInfoRequest;
+    type Response = types::RpcSplitStorageInfoResponse;
+    type Error = types::ErrorWrapperForRpcSplitStorageInfoError;
+}
+
+#[doc = "Queries status of a transaction by hash, returning the final transaction result and details of all receipts."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalTxStatus;
+
+impl RpcMethod for ExperimentalTxStatus {
+    const NAME: &'static str = "EXPERIMENTAL_tx_status";
+    type Params = types::RpcTransactionStatusRequest;
+    type Response = types::RpcTransactionResponse;
+    type Error = types::ErrorWrapperForRpcTransactionError;
+}
+
+#[doc = "Returns the current epoch validators ordered in the block producer order with repetition. This endpoint is solely used for bridge currently and is not intended for other external use cases."]
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalValidatorsOrdered;
+
+impl RpcMethod for ExperimentalValidatorsOrdered {
+    const NAME: &'static str = "EXPERIMENTAL_validators_ordered";
+    type Params = types::RpcValidatorsOrderedRequest;
+    type Response = Vec<types::ValidatorStakeView>;
+    type Error = types::ErrorWrapperForRpcValidatorError;
+}
+
+#[doc = "Returns block details for given height or hash"]
+#[derive(Clone, Copy, Debug)]
+pub struct Block;
+
+impl RpcMethod for Block {
+    const NAME: &'static str = "block";
+    type Params = types::RpcBlockRequest;
+    type Response = types::RpcBlockResponse;
+    type Error = types::ErrorWrapperForRpcBlockError;
+}
+
+#[doc = "Returns changes in block for given block height or hash over all transactions for all the types. Includes changes like account_touched, access_key_touched, data_touched, contract_code_touched."]
+#[derive(Clone, Copy, Debug)]
+pub struct BlockEffects;
+
+impl RpcMethod for BlockEffects {
+    const NAME: &'static str = "block_effects";
+    type Params = types::RpcStateChangesInBlockRequest;
+    type Response = types::RpcStateChangesInBlockByTypeResponse;
+    type Error = types::ErrorWrapperForRpcStateChangesError;
+}
+
+#[doc = "[Deprecated] Sends a transaction and immediately returns transaction hash. Consider using send_tx instead."]
+#[derive(Clone, Copy, Debug)]
+pub struct BroadcastTxAsync;
+
+impl RpcMethod for BroadcastTxAsync {
+    const NAME: &'static str = "broadcast_tx_async";
+    type Params = types::RpcSendTransactionRequest;
+    type Response = types::CryptoHash;
+    type Error = types::ErrorWrapperForRpcTransactionError;
+}
+
+#[doc = "[Deprecated] Sends a transaction and waits until transaction is fully complete. (Has a 10 second timeout). Consider using send_tx instead."]
+#[derive(Clone, Copy, Debug)]
+pub struct BroadcastTxCommit;
+
+impl RpcMethod for BroadcastTxCommit {
+    const NAME: &'static str = "broadcast_tx_commit";
+    type Params = types::RpcSendTransactionRequest;
+    type Response = types::RpcTransactionResponse;
+    type Error = types::ErrorWrapperForRpcTransactionError;
+}
+
+#[doc = "Returns changes for a given account, contract or contract code for given block height or hash."]
+#[derive(Clone, Copy, Debug)]
+pub struct Changes;
+
+impl RpcMethod for Changes {
+    const NAME: &'static str = "changes";
+    type Params = types::RpcStateChangesInBlockByTypeRequest;
+    type Response = types::RpcStateChangesInBlockResponse;
+    type Error = types::ErrorWrapperForRpcStateChangesError;
+}
+
+#[doc = "Returns details of a specific chunk. You can run a block details query to get a valid chunk hash."]
+#[derive(Clone, Copy, Debug)]
+pub struct Chunk;
+
+impl RpcMethod for Chunk {
+    const NAME: &'static str = "chunk";
+    type Params = types::RpcChunkRequest;
+    type Response = types::RpcChunkResponse;
+    type Error = types::ErrorWrapperForRpcChunkError;
+}
+
+#[doc = "Queries client node configuration"]
+#[derive(Clone, Copy, Debug)]
+pub struct ClientConfig;
+
+impl RpcMethod for ClientConfig {
+    const NAME: &'static str = "client_config";
+    type Params = types::RpcClientConfigRequest;
+    type Response = types::RpcClientConfigResponse;
+    type Error = types::ErrorWrapperForRpcClientConfigError;
+}
+
+#[doc = "Returns gas price for a specific block_height or block_hash. Using [null] will return the most recent block's gas price."]
+#[derive(Clone, Copy, Debug)]
+pub struct GasPrice;
+
+impl RpcMethod for GasPrice {
+    const NAME: &'static str = "gas_price";
+    type Params = types::RpcGasPriceRequest;
+    type Response = types::RpcGasPriceResponse;
+    type Error = types::ErrorWrapperForRpcGasPriceError;
+}
+
+#[doc = "Get initial state and parameters for the genesis block"]
+#[derive(Clone, Copy, Debug)]
+pub struct GenesisConfig;
+
+impl RpcMethod for GenesisConfig {
+    const NAME: &'static str = "genesis_config";
+    type Params = types::GenesisConfigRequest;
+    type Response = types::GenesisConfig;
+    type Error = types::ErrorWrapperForGenesisConfigError;
+}
+
+#[doc = "Returns the current health status of the RPC node the client connects to."]
+#[derive(Clone, Copy, Debug)]
+pub struct Health;
+
+impl RpcMethod for Health {
+    const NAME: &'static str = "health";
+    type Params = types::RpcHealthRequest;
+    type Response = types::RpcHealthResponse;
+    type Error = types::ErrorWrapperForRpcStatusError;
+}
+
+#[doc = "Returns the proofs for a transaction execution."]
+#[derive(Clone, Copy, Debug)]
+pub struct LightClientProof;
+
+impl RpcMethod for LightClientProof {
+    const NAME: &'static str = "light_client_proof";
+    type Params = types::RpcLightClientExecutionProofRequest;
+    type Response = types::RpcLightClientExecutionProofResponse;
+    type Error = types::ErrorWrapperForRpcLightClientProofError;
+}
+
+#[doc = "Returns the future windows for maintenance in current epoch for the specified account. In the maintenance windows, the node will not be block producer or chunk producer."]
+#[derive(Clone, Copy, Debug)]
+pub struct MaintenanceWindows;
+
+impl RpcMethod for MaintenanceWindows {
+    const NAME: &'static str = "maintenance_windows";
+    type Params = types::RpcMaintenanceWindowsRequest;
+    type Response = Vec<types::RangeOfUint64>;
+    type Error = types::ErrorWrapperForRpcMaintenanceWindowsError;
+}
+
+#[doc = "Queries the current state of node network connections. This includes information about active peers, transmitted data, known producers, etc."]
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkInfo;
+
+impl RpcMethod for NetworkInfo {
+    const NAME: &'static str = "network_info";
+    type Params = types::RpcNetworkInfoRequest;
+    type Response = types::RpcNetworkInfoResponse;
+    type Error = types::ErrorWrapperForRpcNetworkInfoError;
+}
+
+#[doc = "Returns the next light client block."]
+#[derive(Clone, Copy, Debug)]
+pub struct NextLightClientBlock;
+
+impl RpcMethod for NextLightClientBlock {
+    const NAME: &'static str = "next_light_client_block";
+    type Params = types::RpcLightClientNextBlockRequest;
+    type Response = types::RpcLightClientNextBlockResponse;
+    type Error = types::ErrorWrapperForRpcLightClientNextBlockError;
+}
+
+#[doc = "This module allows you to make generic requests to the network.\n\nThe `RpcQueryRequest` struct takes in a [`BlockReference`](https://docs.rs/near-primitives/0.12.0/near_primitives/types/enum.BlockReference.html) and a [`QueryRequest`](https://docs.rs/near-primitives/0.12.0/near_primitives/views/enum.QueryRequest.html).\n\nThe `BlockReference` enum allows you to specify a block by `Finality`, `BlockId` or `SyncCheckpoint`.\n\nThe `QueryRequest` enum provides multiple variants for performing the following actions:\n - View an account's details\n - View a contract's code\n - View the state of an account\n - View the `AccessKey` of an account\n - View the `AccessKeyList` of an account\n - Call a function in a contract deployed on the network."]
+#[derive(Clone, Copy, Debug)]
+pub struct Query;
+
+impl RpcMethod for Query {
+    const NAME: &'static str = "query";
+    type Params = types::RpcQueryRequest;
+    type Response = types::RpcQueryResponse;
+    type Error = types::ErrorWrapperForRpcQueryError;
+}
+
+#[doc = "Sends transaction. Returns the guaranteed execution status and the results the blockchain can provide at the moment."]
+#[derive(Clone, Copy, Debug)]
+pub struct SendTx;
+
+impl RpcMethod for SendTx {
+    const NAME: &'static str = "send_tx";
+    type Params = types::RpcSendTransactionRequest;
+    type Response = types::RpcTransactionResponse;
+    type Error = types::ErrorWrapperForRpcTransactionError;
+}
+
+#[doc = "Requests the status of the connected RPC node. This includes information about sync status, nearcore node version, protocol version, the current set of validators, etc."]
+#[derive(Clone, Copy, Debug)]
+pub struct Status;
+
+impl RpcMethod for Status {
+    const NAME: &'static str = "status";
+    type Params = types::RpcStatusRequest;
+    type Response = types::RpcStatusResponse;
+    type Error = types::ErrorWrapperForRpcStatusError;
+}
+
+#[doc = "Queries status of a transaction by hash and returns the final transaction result."]
+#[derive(Clone, Copy, Debug)]
+pub struct Tx;
+
+impl RpcMethod for Tx {
+    const NAME: &'static str = "tx";
+    type Params = types::RpcTransactionStatusRequest;
+    type Response = types::RpcTransactionResponse;
+    type Error = types::ErrorWrapperForRpcTransactionError;
+}
+
+#[doc = "Queries active validators on the network. Returns details and the state of validation on the blockchain."]
+#[derive(Clone, Copy, Debug)]
+pub struct Validators;
+
+impl RpcMethod for Validators {
+    const NAME: &'static str = "validators";
+    type Params = types::RpcValidatorRequest;
+    type Response = types::RpcValidatorResponse;
+    type Error = types::ErrorWrapperForRpcValidatorError;
+}
+
+impl RpcClient {
+    #[doc = "[Deprecated] Returns changes for a given account, contract or contract code for given block height or hash. Consider using changes instead."]
+    pub async fn experimental_changes(
+        &self,
+        params: &types::RpcStateChangesInBlockByTypeRequest,
+    ) -> Result<
+        types::RpcStateChangesInBlockResponse,
+        RpcError<types::ErrorWrapperForRpcStateChangesError>,
+    > {
+        self.request::<ExperimentalChanges>(params).await
+    }
+
+    #[doc = "[Deprecated] Returns changes in block for given block height or hash over all transactions for all the types. Includes changes like account_touched, access_key_touched, data_touched, contract_code_touched. Consider using block_effects instead"]
+    pub async fn experimental_changes_in_block(
+        &self,
+        params: &types::RpcStateChangesInBlockRequest,
+    ) -> Result<
+        types::RpcStateChangesInBlockByTypeResponse,
+        RpcError<types::ErrorWrapperForRpcStateChangesError>,
+    > {
+        self.request::<ExperimentalChangesInBlock>(params).await
+    }
+
+    #[doc = "Queries the congestion level of a shard. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)"]
+    pub async fn experimental_congestion_level(
+        &self,
+        params: &types::RpcCongestionLevelRequest,
+    ) -> Result<types::RpcCongestionLevelResponse, RpcError<types::ErrorWrapperForRpcChunkError>>
+    {
+        self.request::<ExperimentalCongestionLevel>(params).await
+    }
+
+    #[doc = "[Deprecated] Get initial state and parameters for the genesis block. Consider genesis_config instead."]
+    pub async fn experimental_genesis_config(
+        &self,
+        params: &types::GenesisConfigRequest,
+    ) -> Result<types::GenesisConfig, RpcError<types::ErrorWrapperForGenesisConfigError>> {
+        self.request::<ExperimentalGenesisConfig>(params).await
+    }
+
+    #[doc = "Returns the proofs for a transaction execution."]
+    pub async fn experimental_light_client_block_proof(
+        &self,
+        params: &types::RpcLightClientBlockProofRequest,
+    ) -> Result<
+        types::RpcLightClientBlockProofResponse,
+        RpcError<types::ErrorWrapperForRpcLightClientProofError>,
+    > {
+        self.request::<ExperimentalLightClientBlockProof>(params)
+            .await
+    }
+
+    #[doc = "Returns the proofs for a transaction execution."]
+    pub async fn experimental_light_client_proof(
+        &self,
+        params: &types::RpcLightClientExecutionProofRequest,
+    ) -> Result<
+        types::RpcLightClientExecutionProofResponse,
+        RpcError<types::ErrorWrapperForRpcLightClientProofError>,
+    > {
+        self.request::<ExperimentalLightClientProof>(params).await
+    }
+
+    #[doc = "[Deprecated] Returns the future windows for maintenance in current epoch for the specified account. In the maintenance windows, the node will not be block producer or chunk producer. Consider using maintenance_windows instead."]
+    pub async fn experimental_maintenance_windows(
+        &self,
+        params: &types::RpcMaintenanceWindowsRequest,
+    ) -> Result<Vec<types::RangeOfUint64>, RpcError<types::ErrorWrapperForRpcMaintenanceWindowsError>>
+    {
+        self.request::<ExperimentalMaintenanceWindows>(params).await
+    }
+
+    #[doc = "A configuration that defines the protocol-level parameters such as gas/storage costs, limits, feature flags, other settings"]
+    pub async fn experimental_protocol_config(
+        &self,
+        params: &types::RpcProtocolConfigRequest,
+    ) -> Result<
+        types::RpcProtocolConfigResponse,
+        RpcError<types::ErrorWrapperForRpcProtocolConfigError>,
+    > {
+        self.request::<ExperimentalProtocolConfig>(params).await
+    }
+
+    #[doc = "Fetches a receipt by its ID (as is, without a status or execution outcome)"]
+    pub async fn experimental_receipt(
+        &self,
+        params: &types::RpcReceiptRequest,
+    ) -> Result<types::RpcReceiptResponse, RpcError<types::ErrorWrapperForRpcReceiptError>> {
+        self.request::<ExperimentalReceipt>(params).await
+    }
+
+    #[doc = "Contains the split storage information. More info on split storage [here](https://near-nodes.io/archival/split-storage-archival)"]
+    pub async fn experimental_split_storage_info(
+        &self,
+        params: &types::RpcSplitStorageInfoRequest,
+    ) -> Result<
+        types::RpcSplitStorageInfoResponse,
+        RpcError<types::ErrorWrapperForRpcSplitStorageInfoError>,
+    > {
+        self.request::<ExperimentalSplitStorageInfo>(params).await
+    }
+
+    #[doc = "Queries status of a transaction by hash, returning the final transaction result and details of all receipts."]
+    pub async fn experimental_tx_status(
+        &self,
+        params: &types::RpcTransactionStatusRequest,
+    ) -> Result<types::RpcTransactionResponse, RpcError<types::ErrorWrapperForRpcTransactionError>>
+    {
+        self.request::<ExperimentalTxStatus>(params).await
+    }
+
+    #[doc = "Returns the current epoch validators ordered in the block producer order with repetition. This endpoint is solely used for bridge currently and is not intended for other external use cases."]
+    pub async fn experimental_validators_ordered(
+        &self,
+        params: &types::RpcValidatorsOrderedRequest,
+    ) -> Result<Vec<types::ValidatorStakeView>, RpcError<types::ErrorWrapperForRpcValidatorError>>
+    {
+        self.request::<ExperimentalValidatorsOrdered>(params).await
+    }
+
+    #[doc = "Returns block details for given height or hash"]
+    pub async fn block(
+        &self,
+        params: &types::RpcBlockRequest,
+    ) -> Result<types::RpcBlockResponse, RpcError<types::ErrorWrapperForRpcBlockError>> {
+        self.request::<Block>(params).await
+    }
+
+    #[doc = "Returns changes in block for given block height or hash over all transactions for all the types. Includes changes like account_touched, access_key_touched, data_touched, contract_code_touched."]
+    pub async fn block_effects(
+        &self,
+        params: &types::RpcStateChangesInBlockRequest,
+    ) -> Result<
+        types::RpcStateChangesInBlockByTypeResponse,
+        RpcError<types::ErrorWrapperForRpcStateChangesError>,
+    > {
+        self.request::<BlockEffects>(params).await
+    }
+
+    #[doc = "[Deprecated] Sends a transaction and immediately returns transaction hash. Consider using send_tx instead."]
+    pub async fn broadcast_tx_async(
+        &self,
+        params: &types::RpcSendTransactionRequest,
+    ) -> Result<types::CryptoHash, RpcError<types::ErrorWrapperForRpcTransactionError>> {
+        self.request::<BroadcastTxAsync>(params).await
+    }
+
+    #[doc = "[Deprecated] Sends a transaction and waits until transaction is fully complete. (Has a 10 second timeout). Consider using send_tx instead."]
+    pub async fn broadcast_tx_commit(
+        &self,
+        params: &types::RpcSendTransactionRequest,
+    ) -> Result<types::RpcTransactionResponse, RpcError<types::ErrorWrapperForRpcTransactionError>>
+    {
+        self.request::<BroadcastTxCommit>(params).await
+    }
+
+    #[doc = "Returns changes for a given account, contract or contract code for given block height or hash."]
+    pub async fn changes(
+        &self,
+        params: &types::RpcStateChangesInBlockByTypeRequest,
+    ) -> Result<
+        types::RpcStateChangesInBlockResponse,
+        RpcError<types::ErrorWrapperForRpcStateChangesError>,
+    > {
+        self.request::<Changes>(params).await
+    }
+
+    #[doc = "Returns details of a specific chunk. You can run a block details query to get a valid chunk hash."]
+    pub async fn chunk(
+        &self,
+        params: &types::RpcChunkRequest,
+    ) -> Result<types::RpcChunkResponse, RpcError<types::ErrorWrapperForRpcChunkError>> {
+        self.request::<Chunk>(params).await
+    }
+
+    #[doc = "Queries client node configuration"]
+    pub async fn client_config(
+        &self,
+        params: &types::RpcClientConfigRequest,
+    ) -> Result<types::RpcClientConfigResponse, RpcError<types::ErrorWrapperForRpcClientConfigError>>
+    {
+        self.request::<ClientConfig>(params).await
+    }
+
+    #[doc = "Returns gas price for a specific block_height or block_hash. Using [null] will return the most recent block's gas price."]
+    pub async fn gas_price(
+        &self,
+        params: &types::RpcGasPriceRequest,
+    ) -> Result<types::RpcGasPriceResponse, RpcError<types::ErrorWrapperForRpcGasPriceError>> {
+        self.request::<GasPrice>(params).await
+    }
+
+    #[doc = "Get initial state and parameters for the genesis block"]
+    pub async fn genesis_config(
+        &self,
+        params: &types::GenesisConfigRequest,
+    ) -> Result<types::GenesisConfig, RpcError<types::ErrorWrapperForGenesisConfigError>> {
+        self.request::<GenesisConfig>(params).await
+    }
+
+    #[doc = "Returns the current health status of the RPC node the client connects to."]
+    pub async fn health(
+        &self,
+        params: &types::RpcHealthRequest,
+    ) -> Result<types::RpcHealthResponse, RpcError<types::ErrorWrapperForRpcStatusError>> {
+        self.request::<Health>(params).await
+    }
+
+    #[doc = "Returns the proofs for a transaction execution."]
+    pub async fn light_client_proof(
+        &self,
+        params: &types::RpcLightClientExecutionProofRequest,
+    ) -> Result<
+        types::RpcLightClientExecutionProofResponse,
+        RpcError<types::ErrorWrapperForRpcLightClientProofError>,
+    > {
+        self.request::<LightClientProof>(params).await
+    }
+
+    #[doc = "Returns the future windows for maintenance in current epoch for the specified account. In the maintenance windows, the node will not be block producer or chunk producer."]
+    pub async fn maintenance_windows(
+        &self,
+        params: &types::RpcMaintenanceWindowsRequest,
+    ) -> Result<Vec<types::RangeOfUint64>, RpcError<types::ErrorWrapperForRpcMaintenanceWindowsError>>
+    {
+        self.request::<MaintenanceWindows>(params).await
+    }
+
+    #[doc = "Queries the current state of node network connections. This includes information about active peers, transmitted data, known producers, etc."]
+    pub async fn network_info(
+        &self,
+        params: &types::RpcNetworkInfoRequest,
+    ) -> Result<types::RpcNetworkInfoResponse, RpcError<types::ErrorWrapperForRpcNetworkInfoError>>
+    {
+        self.request::<NetworkInfo>(params).await
+    }
+
+    #[doc = "Returns the next light client block."]
+    pub async fn next_light_client_block(
+        &self,
+        params: &types::RpcLightClientNextBlockRequest,
+    ) -> Result<
+        types::RpcLightClientNextBlockResponse,
+        RpcError<types::ErrorWrapperForRpcLightClientNextBlockError>,
+    > {
+        self.request::<NextLightClientBlock>(params).await
+    }
+
+    #[doc = "This module allows you to make generic requests to the network.\n\nThe `RpcQueryRequest` struct takes in a [`BlockReference`](https://docs.rs/near-primitives/0.12.0/near_primitives/types/enum.BlockReference.html) and a [`QueryRequest`](https://docs.rs/near-primitives/0.12.0/near_primitives/views/enum.QueryRequest.html).\n\nThe `BlockReference` enum allows you to specify a block by `Finality`, `BlockId` or `SyncCheckpoint`.\n\nThe `QueryRequest` enum provides multiple variants for performing the following actions:\n - View an account's details\n - View a contract's code\n - View the state of an account\n - View the `AccessKey` of an account\n - View the `AccessKeyList` of an account\n - Call a function in a contract deployed on the network."]
+    pub async fn query(
+        &self,
+        params: &types::RpcQueryRequest,
+    ) -> Result<types::RpcQueryResponse, RpcError<types::ErrorWrapperForRpcQueryError>> {
+        self.request::<Query>(params).await
+    }
+
+    #[doc = "Sends transaction. Returns the guaranteed execution status and the results the blockchain can provide at the moment."]
+    pub async fn send_tx(
+        &self,
+        params: &types::RpcSendTransactionRequest,
+    ) -> Result<types::RpcTransactionResponse, RpcError<types::ErrorWrapperForRpcTransactionError>>
+    {
+        self.request::<SendTx>(params).await
+    }
+
+    #[doc = "Requests the status of the connected RPC node. This includes information about sync status, nearcore node version, protocol version, the current set of validators, etc."]
+    pub async fn status(
+        &self,
+        params: &types::RpcStatusRequest,
+    ) -> Result<types::RpcStatusResponse, RpcError<types::ErrorWrapperForRpcStatusError>> {
+        self.request::<Status>(params).await
+    }
+
+    #[doc = "Queries status of a transaction by hash and returns the final transaction result."]
+    pub async fn tx(
+        &self,
+        params: &types::RpcTransactionStatusRequest,
+    ) -> Result<types::RpcTransactionResponse, RpcError<types::ErrorWrapperForRpcTransactionError>>
+    {
+        self.request::<Tx>(params).await
+    }
+
+    #[doc = "Queries active validators on the network. Returns details and the state of validation on the blockchain."]
+    pub async fn validators(
+        &self,
+        params: &types::RpcValidatorRequest,
+    ) -> Result<types::RpcValidatorResponse, RpcError<types::ErrorWrapperForRpcValidatorError>>
+    {
+        self.request::<Validators>(params).await
+    }
+}
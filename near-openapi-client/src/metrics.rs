@@ -0,0 +1,155 @@
+//! Client-side metrics of JSON-RPC calls, recorded by a [`MetricsRecorder`]
+//! set with [`RpcClient::with_metrics`](crate::rpc::RpcClient::with_metrics).
+//!
+//! With the `metrics` feature, [`PrometheusRecorder`] counts calls and errors
+//! and measures their latency in a Prometheus registry:
+//!
+//! ```rust,ignore
+//! let registry = prometheus::Registry::new();
+//! let rpc_client = RpcClient::new(url).with_metrics(PrometheusRecorder::new(&registry)?);
+//! ```
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::jsonrpc::RpcError;
+use crate::retry::FailedAttempt;
+
+#[cfg(feature = "metrics")]
+pub use prometheus;
+
+/// How a call ended.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Success,
+    /// The node returned a JSON-RPC error. `name` is the variant of the
+    /// `ErrorWrapperFor*` type (e.g. `HANDLER_ERROR`) and `cause` the name of
+    /// the wrapped error (e.g. `UNKNOWN_BLOCK`).
+    Rpc {
+        name: String,
+        cause: Option<String>,
+    },
+    /// The node answered with a non-200 HTTP status.
+    Status(u16),
+    /// The request could not be sent, or its response could not be read or
+    /// decoded.
+    Client,
+    /// The node is not on the network the client was pinned to.
+    WrongNetwork,
+}
+
+impl Outcome {
+    pub(crate) fn of<T, E>(
+        result: &Result<T, RpcError<E>>,
+        failure: Option<&FailedAttempt>,
+    ) -> Self {
+        match (result, failure) {
+            (Ok(_), _) => Self::Success,
+            (Err(RpcError::Rpc(_)), Some(FailedAttempt::Rpc { name, cause })) => Self::Rpc {
+                name: name.clone(),
+                cause: cause.clone(),
+            },
+            (Err(RpcError::Rpc(_)), _) => Self::Rpc {
+                name: "UNKNOWN".to_string(),
+                cause: None,
+            },
+            (Err(RpcError::Client(_)), Some(FailedAttempt::Status(status))) => {
+                Self::Status(*status)
+            }
+            (Err(RpcError::Client(_)), _) => Self::Client,
+            (Err(RpcError::WrongNetwork(_)), _) => Self::WrongNetwork,
+        }
+    }
+
+    /// Label of the outcome in metrics: `success`, the name of the JSON-RPC
+    /// error, `http_<status>`, `client_error` or `wrong_network`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Success => "success".to_string(),
+            Self::Rpc { name, .. } => name.clone(),
+            Self::Status(status) => format!("http_{}", status),
+            Self::Client => "client_error".to_string(),
+            Self::WrongNetwork => "wrong_network".to_string(),
+        }
+    }
+}
+
+/// Receiver of the metrics of every call of an
+/// [`RpcClient`](crate::rpc::RpcClient).
+pub trait MetricsRecorder: Send + Sync {
+    /// Records a call of `method`, or of a batch as `batch`, that ended with
+    /// `outcome` after `latency`, including retries.
+    fn record_call(&self, method: &str, latency: Duration, outcome: &Outcome);
+}
+
+#[derive(Clone)]
+pub(crate) struct Recorder(pub(crate) Arc<dyn MetricsRecorder>);
+
+impl std::fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MetricsRecorder")
+    }
+}
+
+/// [`MetricsRecorder`] keeping Prometheus metrics:
+/// - `near_rpc_requests_total` by `method`
+/// - `near_rpc_errors_total` by `method`, `error` (the [`Outcome::label`])
+///   and `cause` (of JSON-RPC errors, empty otherwise)
+/// - `near_rpc_request_duration_seconds`, a histogram by `method`
+#[cfg(feature = "metrics")]
+#[derive(Clone, Debug)]
+pub struct PrometheusRecorder {
+    requests: prometheus::IntCounterVec,
+    errors: prometheus::IntCounterVec,
+    latency: prometheus::HistogramVec,
+}
+
+#[cfg(feature = "metrics")]
+impl PrometheusRecorder {
+    /// Creates the metrics and registers them in `registry`.
+    pub fn new(registry: &prometheus::Registry) -> prometheus::Result<Self> {
+        let requests = prometheus::IntCounterVec::new(
+            prometheus::Opts::new("near_rpc_requests_total", "JSON-RPC calls sent"),
+            &["method"],
+        )?;
+        let errors = prometheus::IntCounterVec::new(
+            prometheus::Opts::new("near_rpc_errors_total", "JSON-RPC calls that failed"),
+            &["method", "error", "cause"],
+        )?;
+        let latency = prometheus::HistogramVec::new(
+            prometheus::HistogramOpts::new(
+                "near_rpc_request_duration_seconds",
+                "Latency of JSON-RPC calls, including retries",
+            ),
+            &["method"],
+        )?;
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(errors.clone()))?;
+        registry.register(Box::new(latency.clone()))?;
+        Ok(Self {
+            requests,
+            errors,
+            latency,
+        })
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for PrometheusRecorder {
+    fn record_call(&self, method: &str, latency: Duration, outcome: &Outcome) {
+        self.requests.with_label_values(&[method]).inc();
+        self.latency
+            .with_label_values(&[method])
+            .observe(latency.as_secs_f64());
+        if *outcome != Outcome::Success {
+            let cause = match outcome {
+                Outcome::Rpc {
+                    cause: Some(cause), ..
+                } => cause.as_str(),
+                _ => "",
+            };
+            self.errors
+                .with_label_values(&[method, &outcome.label(), cause])
+                .inc();
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Reading balances of NEP-245 multi-token contracts. Arguments of the call
+//! methods and the events are in [`types::multi_token`].
+use crate::rpc::RpcClient;
+use crate::types::multi_token::{MtBalanceOfArgs, MtBatchBalanceOfArgs};
+use crate::types::{AccountId, BlockReference};
+use crate::view::ViewFunctionError;
+
+/// Balance of `token_id` owned by `account_id` in the contract `contract_id`.
+pub async fn mt_balance_of(
+    client: &RpcClient,
+    contract_id: &AccountId,
+    account_id: &AccountId,
+    token_id: &str,
+    block: BlockReference,
+) -> Result<u128, ViewFunctionError> {
+    let args = MtBalanceOfArgs {
+        account_id: account_id.clone(),
+        token_id: token_id.to_string(),
+    };
+    let balance: String = client
+        .view_function(contract_id, "mt_balance_of", &args, block)
+        .await?
+        .result;
+    parse_amount(&balance).map_err(ViewFunctionError::Result)
+}
+
+/// Balances of each of `token_ids` owned by `account_id`, in the same order.
+pub async fn mt_batch_balance_of(
+    client: &RpcClient,
+    contract_id: &AccountId,
+    account_id: &AccountId,
+    token_ids: &[String],
+    block: BlockReference,
+) -> Result<Vec<u128>, ViewFunctionError> {
+    let args = MtBatchBalanceOfArgs {
+        account_id: account_id.clone(),
+        token_ids: token_ids.to_vec(),
+    };
+    let balances: Vec<String> = client
+        .view_function(contract_id, "mt_batch_balance_of", &args, block)
+        .await?
+        .result;
+    balances
+        .iter()
+        .map(|balance| parse_amount(balance))
+        .collect::<Result<_, _>>()
+        .map_err(ViewFunctionError::Result)
+}
+
+fn parse_amount(amount: &str) -> Result<u128, serde_json::Error> {
+    amount
+        .parse()
+        .map_err(|_| serde::de::Error::custom(format!("invalid amount {:?}", amount)))
+}
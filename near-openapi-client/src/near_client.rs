@@ -0,0 +1,83 @@
+//! Object-safe facade of [`RpcClient`], for code that takes a client without
+//! being generic over it.
+//!
+//! The typed methods of [`RpcClient`] are compiled again in every crate
+//! instantiating them. [`NearClient`] only has a JSON method, so a
+//! `&dyn NearClient` or `Arc<dyn NearClient>` can be passed between crates
+//! and swapped for another implementation, e.g. a fake in tests, while
+//! [`request`](NearClient::request) still returns typed responses:
+//!
+//! ```rust,ignore
+//! async fn gas_price(client: &dyn NearClient) -> Result<NearToken, RpcError<ErrorWrapperForRpcGasPriceError>> {
+//!     let response = client
+//!         .request::<methods::GasPrice>(&RpcGasPriceRequest { block_id: None })
+//!         .await?;
+//!     Ok(response.gas_price)
+//! }
+//! ```
+use serde_json::Value;
+
+use crate::Error;
+use crate::jsonrpc::RpcError;
+use crate::methods::RpcMethod;
+use crate::rpc::RpcClient;
+use crate::types::validate::Validate;
+
+/// Future of a [`NearClient::call_json`] call. It is `Send`, except on wasm32
+/// where the futures of `reqwest` are not.
+#[cfg(not(target_arch = "wasm32"))]
+pub type CallFuture<'a> = futures_util::future::BoxFuture<'a, Result<Value, RpcError<Value>>>;
+#[cfg(target_arch = "wasm32")]
+pub type CallFuture<'a> = futures_util::future::LocalBoxFuture<'a, Result<Value, RpcError<Value>>>;
+
+/// `Send + Sync`, except on wasm32 where clients live on the single thread of
+/// the page.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + Sync + ?Sized> MaybeSendSync for T {}
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSendSync {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSendSync for T {}
+
+/// Client sending JSON-RPC calls with JSON params and results.
+pub trait NearClient: MaybeSendSync {
+    /// Sends `method` with `params`, returning the `result`, or the `error`
+    /// of the response as [`RpcError::Rpc`].
+    fn call_json<'a>(&'a self, method: &'a str, params: Value) -> CallFuture<'a>;
+}
+
+impl NearClient for RpcClient {
+    fn call_json<'a>(&'a self, method: &'a str, params: Value) -> CallFuture<'a> {
+        Box::pin(async move { self.call(method, &params).await })
+    }
+}
+
+impl dyn NearClient + '_ {
+    /// Sends the JSON-RPC method `M`, like [`RpcClient::request`]. The
+    /// unknown variant handler of an [`RpcClient`] does not see the
+    /// responses, which are decoded here.
+    pub async fn request<M: RpcMethod>(
+        &self,
+        params: &M::Params,
+    ) -> Result<M::Response, RpcError<M::Error>> {
+        params
+            .validate()
+            .map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        let params =
+            serde_json::to_value(params).map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        match self.call_json(M::NAME, params).await {
+            Ok(result) => Ok(decode(result)?),
+            Err(RpcError::Rpc(error)) => Err(RpcError::Rpc(decode(error)?)),
+            Err(RpcError::Client(err)) => Err(RpcError::Client(err)),
+            Err(RpcError::WrongNetwork(err)) => Err(RpcError::WrongNetwork(err)),
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn decode<T: serde::de::DeserializeOwned>(value: Value) -> Result<T, Error<()>> {
+    T::deserialize(&value)
+        .map_err(|err| Error::InvalidResponsePayload(value.to_string().into(), err))
+}
@@ -0,0 +1,112 @@
+//! Guarding against talking to the wrong network.
+use crate::jsonrpc::{self, RpcError};
+use crate::types::{self, ChainId, CryptoHash};
+use crate::{Client, Error};
+
+/// Network a client is expected to be connected to.
+///
+/// Fields left as `None` are not checked.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExpectedNetwork {
+    pub chain_id: Option<ChainId>,
+    pub genesis_hash: Option<CryptoHash>,
+}
+
+impl ExpectedNetwork {
+    pub fn mainnet() -> Self {
+        Self::chain_id(ChainId::Mainnet)
+    }
+
+    pub fn testnet() -> Self {
+        Self::chain_id(ChainId::Testnet)
+    }
+
+    pub fn chain_id(chain_id: ChainId) -> Self {
+        Self {
+            chain_id: Some(chain_id),
+            genesis_hash: None,
+        }
+    }
+
+    /// Additionally pins the genesis hash, which tells apart networks that
+    /// share a chain id (e.g. two localnets).
+    pub fn with_genesis_hash(mut self, genesis_hash: CryptoHash) -> Self {
+        self.genesis_hash = Some(genesis_hash);
+        self
+    }
+
+    fn matches(&self, chain_id: &ChainId, genesis_hash: &CryptoHash) -> bool {
+        self.chain_id
+            .as_ref()
+            .is_none_or(|expected| expected == chain_id)
+            && self
+                .genesis_hash
+                .as_ref()
+                .is_none_or(|expected| expected == genesis_hash)
+    }
+}
+
+/// The node reported a different network than expected.
+#[derive(Clone, Debug)]
+pub struct WrongNetwork {
+    pub expected: ExpectedNetwork,
+    pub chain_id: ChainId,
+    pub genesis_hash: CryptoHash,
+}
+
+impl std::fmt::Display for WrongNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "node is on chain {} with genesis {}, expected ",
+            self.chain_id, self.genesis_hash
+        )?;
+        match (&self.expected.chain_id, &self.expected.genesis_hash) {
+            (Some(chain_id), Some(genesis_hash)) => {
+                write!(f, "chain {} with genesis {}", chain_id, genesis_hash)
+            }
+            (Some(chain_id), None) => write!(f, "chain {}", chain_id),
+            (None, Some(genesis_hash)) => write!(f, "genesis {}", genesis_hash),
+            (None, None) => write!(f, "any network"),
+        }
+    }
+}
+
+impl std::error::Error for WrongNetwork {}
+
+/// Checks via the `status` method that `client` is connected to `expected`.
+pub async fn verify_network<E>(
+    client: &Client,
+    expected: &ExpectedNetwork,
+) -> Result<(), RpcError<E>> {
+    verify_status(
+        jsonrpc::call(client, "status", &types::RpcStatusRequest(())).await,
+        expected,
+    )
+}
+
+/// Checks the response of a node to `status` against `expected`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn verify_status<E>(
+    status: Result<types::RpcStatusResponse, RpcError<types::ErrorWrapperForRpcStatusError>>,
+    expected: &ExpectedNetwork,
+) -> Result<(), RpcError<E>> {
+    let status = status.map_err(|err| match err {
+        RpcError::Rpc(err) => RpcError::Client(Error::Custom(format!(
+            "failed to fetch node status: {}",
+            err
+        ))),
+        RpcError::Client(err) => RpcError::Client(err),
+        RpcError::WrongNetwork(err) => RpcError::WrongNetwork(err),
+    })?;
+    let chain_id = ChainId::from(status.chain_id.as_str());
+    if expected.matches(&chain_id, &status.genesis_hash) {
+        Ok(())
+    } else {
+        Err(RpcError::WrongNetwork(Box::new(WrongNetwork {
+            expected: expected.clone(),
+            chain_id,
+            genesis_hash: status.genesis_hash,
+        })))
+    }
+}
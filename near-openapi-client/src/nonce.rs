@@ -0,0 +1,118 @@
+//! Handing out access key nonces for concurrent transaction submission.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{self, AccountId, CryptoHash, ErrorWrapperForRpcQueryError, PublicKey};
+
+/// Error fetching an access key.
+#[derive(Debug)]
+pub enum NonceError {
+    /// The `view_access_key` query failed, e.g. because the key doesn't exist.
+    Query(RpcError<ErrorWrapperForRpcQueryError>),
+    /// The node answered the query with something else than an access key.
+    UnexpectedResponse(Box<types::RpcQueryResponse>),
+}
+
+impl std::fmt::Display for NonceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Query(err) => write!(f, "failed to query access key: {}", err),
+            Self::UnexpectedResponse(response) => {
+                write!(f, "unexpected access key query response: {:?}", response)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NonceError {}
+
+/// Caches the nonce of each access key and hands out increasing nonces, so
+/// that transactions signed concurrently with the same key don't collide.
+///
+/// Clones share the cache. The nonce of a key is fetched with
+/// `view_access_key` the first time it is needed and after
+/// [`invalidate`](Self::invalidate), e.g. on an `InvalidNonce` error.
+#[derive(Clone, Debug)]
+pub struct NonceManager {
+    client: RpcClient,
+    nonces: Arc<Mutex<HashMap<(AccountId, PublicKey), u64>>>,
+}
+
+impl NonceManager {
+    pub fn new(client: RpcClient) -> Self {
+        Self {
+            client,
+            nonces: Arc::default(),
+        }
+    }
+
+    /// Nonce for the next transaction signed with `public_key` of
+    /// `account_id`, higher than all nonces handed out for it before.
+    pub async fn next_nonce(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Result<u64, NonceError> {
+        let key = (account_id.clone(), *public_key);
+        if let Some(nonce) = self.nonces.lock().unwrap().get_mut(&key) {
+            *nonce += 1;
+            return Ok(*nonce);
+        }
+        let (fetched, _) = access_key(&self.client, account_id, public_key).await?;
+        // Another call may have fetched the nonce meanwhile and handed out
+        // nonces above it.
+        let mut nonces = self.nonces.lock().unwrap();
+        let nonce = nonces.entry(key).or_insert(fetched);
+        *nonce = (*nonce).max(fetched) + 1;
+        Ok(*nonce)
+    }
+
+    /// Fetches the nonce of the key again, keeping the cached one if it is
+    /// higher because transactions using it are still in flight.
+    pub async fn refresh(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Result<(), NonceError> {
+        let (fetched, _) = access_key(&self.client, account_id, public_key).await?;
+        let mut nonces = self.nonces.lock().unwrap();
+        let nonce = nonces
+            .entry((account_id.clone(), *public_key))
+            .or_insert(fetched);
+        *nonce = (*nonce).max(fetched);
+        Ok(())
+    }
+
+    /// Forgets the cached nonce of the key, so that the next call fetches it.
+    pub fn invalidate(&self, account_id: &AccountId, public_key: &PublicKey) {
+        self.nonces
+            .lock()
+            .unwrap()
+            .remove(&(account_id.clone(), *public_key));
+    }
+}
+
+/// Fetches the nonce of an access key and the final block hash it was read at.
+pub(crate) async fn access_key(
+    client: &RpcClient,
+    account_id: &AccountId,
+    public_key: &PublicKey,
+) -> Result<(u64, CryptoHash), NonceError> {
+    let response = client
+        .query(&types::RpcQueryRequest::ViewAccessKeyByFinality {
+            account_id: account_id.clone(),
+            finality: types::Finality::Final,
+            public_key: *public_key,
+            request_type: types::ViewAccessKeyByFinalityRequestType::ViewAccessKey,
+        })
+        .await
+        .map_err(NonceError::Query)?;
+    match response {
+        types::RpcQueryResponse::Variant4 {
+            nonce, block_hash, ..
+        } => Ok((nonce, block_hash)),
+        other => Err(NonceError::UnexpectedResponse(Box::new(other))),
+    }
+}
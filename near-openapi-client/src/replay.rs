@@ -0,0 +1,120 @@
+//! Recording responses of the node to files, and answering the same requests
+//! from them later without network access, e.g. to test an indexer against
+//! real mainnet payloads captured once.
+//!
+//! ```rust,ignore
+//! // Once, against the node:
+//! let rpc_client = RpcClient::new(url).with_fixtures(Fixtures::record("tests/fixtures"));
+//! // In tests:
+//! let rpc_client = RpcClient::new(url).with_fixtures(Fixtures::replay("tests/fixtures"));
+//! ```
+//!
+//! Each request, or batch of them, is stored in its own file named after the
+//! method and a hash of the request, holding the `request` and the
+//! `response`. Only responses with a 200 status are recorded; replaying a
+//! request that was not recorded fails with [`Error::InvalidRequest`].
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::Error;
+
+/// Whether [`Fixtures`] are written or read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixtureMode {
+    /// Requests are sent to the node and their responses written to files.
+    Record,
+    /// Requests are answered from the files, without sending them.
+    Replay,
+}
+
+/// Directory of recorded requests and responses, set with
+/// [`RpcClient::with_fixtures`](crate::rpc::RpcClient::with_fixtures).
+#[derive(Clone, Debug)]
+pub struct Fixtures {
+    dir: PathBuf,
+    mode: FixtureMode,
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct Fixture {
+    request: Value,
+    response: Value,
+}
+
+impl Fixtures {
+    /// Records the responses to every request into `dir`, which is created
+    /// if needed.
+    pub fn record(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: FixtureMode::Record,
+        }
+    }
+
+    /// Answers every request with its response recorded in `dir`.
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: FixtureMode::Replay,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn mode(&self) -> FixtureMode {
+        self.mode
+    }
+
+    /// File of the request encoded as `body`, e.g. `block-0123456789abcdef.json`.
+    pub fn path(&self, body: &[u8]) -> PathBuf {
+        let method = match serde_json::from_slice::<Value>(body) {
+            Ok(Value::Array(_)) => "batch".to_string(),
+            Ok(request) => request["method"].as_str().unwrap_or("request").to_string(),
+            Err(_) => "request".to_string(),
+        };
+        self.dir
+            .join(format!("{}-{:016x}.json", method, fnv1a(body)))
+    }
+
+    /// Recorded response to the request encoded as `body`.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn read(&self, body: &[u8]) -> Result<bytes::Bytes, Error<()>> {
+        let path = self.path(body);
+        let file = std::fs::read(&path).map_err(|err| {
+            Error::InvalidRequest(format!("no fixture at {}: {}", path.display(), err))
+        })?;
+        let fixture: Fixture = serde_json::from_slice(&file).map_err(|err| {
+            Error::InvalidRequest(format!("invalid fixture at {}: {}", path.display(), err))
+        })?;
+        Ok(fixture.response.to_string().into())
+    }
+
+    /// Records `response` to the request encoded as `body`. Responses that are
+    /// not JSON are not recorded, as the node never answers 200 with them.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn write(&self, body: &[u8], response: &[u8]) -> Result<(), Error<()>> {
+        let (Ok(request), Ok(response)) = (
+            serde_json::from_slice(body),
+            serde_json::from_slice(response),
+        ) else {
+            return Ok(());
+        };
+        let path = self.path(body);
+        let fixture = serde_json::to_vec_pretty(&Fixture { request, response })
+            .expect("fixtures encode to JSON");
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|()| std::fs::write(&path, fixture))
+            .map_err(|err| Error::Custom(format!("failed to record {}: {}", path.display(), err)))
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions unlike
+/// the hashers of `std`.
+pub(crate) fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
@@ -0,0 +1,202 @@
+//! Request ids of JSON-RPC calls, to tell an RPC provider which call failed.
+//!
+//! Calls are sent with the JSON-RPC id `dontcare` by default. A client set
+//! with [`RpcClient::with_request_ids`](crate::rpc::RpcClient::with_request_ids)
+//! sends each call with a locally generated id instead, e.g. `5f3a9c01-17`,
+//! which providers log along with the request. Retries of a call keep its
+//! id; calls in a batch keep their positions as ids.
+//!
+//! [`RpcClient::request_with_id`](crate::rpc::RpcClient::request_with_id)
+//! returns the id of a failed call in a [`RequestError`], whose message
+//! tells the whole cause of the error, e.g. `request 5f3a9c01-17 failed:
+//! HANDLER_ERROR: UNKNOWN_BLOCK: {"block_reference":{"block_id":1}}`:
+//!
+//! ```rust,ignore
+//! let rpc_client = RpcClient::new(url).with_request_ids(RequestIds::new().log_error_causes());
+//! match rpc_client.request_with_id::<methods::Block>(&params).await {
+//!     Ok(block) => process(block),
+//!     Err(err) => eprintln!("{err}, please quote {} to the provider", err.request_id),
+//! }
+//! ```
+//!
+//! With the `tracing` feature, the id is the `request_id` field of the
+//! `rpc_call` span of the call, and with [`RequestIds::log_error_causes`]
+//! its cause chain is the `error.chain` field, logged with the `WARN` event
+//! of the failed call.
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use serde_json::Value;
+
+use crate::Error;
+use crate::jsonrpc::RpcError;
+
+/// Locally generated id of a JSON-RPC call.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Generator of the ids of the calls of an
+/// [`RpcClient`](crate::rpc::RpcClient), shared by its clones. Ids are a
+/// prefix and the number of the call, e.g. `5f3a9c01-17`.
+#[derive(Clone, Debug)]
+pub struct RequestIds {
+    prefix: Arc<str>,
+    next: Arc<AtomicU64>,
+    log_error_causes: bool,
+}
+
+impl Default for RequestIds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestIds {
+    /// Ids prefixed with 8 random hex digits, so that the ids of different
+    /// processes do not collide.
+    pub fn new() -> Self {
+        Self::with_prefix(format!("{:08x}", fastrand::u32(..)))
+    }
+
+    /// Ids prefixed with `prefix`, e.g. the name of the service.
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into().into(),
+            next: Arc::default(),
+            log_error_causes: false,
+        }
+    }
+
+    /// Records the cause chain of failed calls in their spans, with the
+    /// `tracing` feature.
+    pub fn log_error_causes(mut self) -> Self {
+        self.log_error_causes = true;
+        self
+    }
+
+    #[cfg(feature = "tracing")]
+    pub(crate) fn logs_error_causes(&self) -> bool {
+        self.log_error_causes
+    }
+
+    /// Id of the next call.
+    pub(crate) fn next_id(&self) -> RequestId {
+        let number = self.next.fetch_add(1, Ordering::Relaxed);
+        RequestId(format!("{}-{}", self.prefix, number))
+    }
+}
+
+/// Ids of the calls of [`RpcClient::request_with_id`](crate::rpc::RpcClient::request_with_id)
+/// of clients without ids of their own.
+pub(crate) fn default_ids() -> &'static RequestIds {
+    static IDS: OnceLock<RequestIds> = OnceLock::new();
+    IDS.get_or_init(RequestIds::new)
+}
+
+/// Error of a call of
+/// [`RpcClient::request_with_id`](crate::rpc::RpcClient::request_with_id),
+/// with the id the call was sent with.
+#[derive(Debug)]
+pub struct RequestError<E> {
+    pub request_id: RequestId,
+    pub error: RpcError<E>,
+}
+
+impl<E: serde::Serialize> RequestError<E> {
+    /// See [`cause_chain`].
+    pub fn cause_chain(&self) -> Vec<String> {
+        cause_chain(&self.error)
+    }
+}
+
+impl<E: std::fmt::Debug + serde::Serialize> std::fmt::Display for RequestError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "request {} failed: {}",
+            self.request_id,
+            self.cause_chain().join(": ")
+        )
+    }
+}
+
+impl<E: std::fmt::Debug + serde::Serialize + 'static> std::error::Error for RequestError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Causes of `err`, outermost first: for errors of the node, the name of
+/// the error, e.g. `REQUEST_VALIDATION_ERROR` or `HANDLER_ERROR`, the name
+/// of its cause, e.g. `PARSE_ERROR` or `UNKNOWN_BLOCK`, and the info of the
+/// cause; for other errors, their messages and those of their sources.
+pub fn cause_chain<E: serde::Serialize>(err: &RpcError<E>) -> Vec<String> {
+    match err {
+        RpcError::Client(err) => client_error_chain(err),
+        RpcError::Rpc(err) => match serde_json::to_value(err) {
+            Ok(value) => rpc_error_chain(&value),
+            Err(err) => vec![err.to_string()],
+        },
+        RpcError::WrongNetwork(err) => vec![err.to_string()],
+    }
+}
+
+/// Cause chain of the JSON-RPC error of the response `body`, if it has one.
+#[cfg(feature = "tracing")]
+pub(crate) fn response_cause_chain(body: &[u8]) -> Option<Vec<String>> {
+    let response: Value = serde_json::from_slice(body).ok()?;
+    response.get("error").map(rpc_error_chain)
+}
+
+pub(crate) fn client_error_chain(err: &Error<()>) -> Vec<String> {
+    let mut chain = vec![err.to_string()];
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        chain.push(err.to_string());
+        source = err.source();
+    }
+    chain
+}
+
+/// Cause chain of an `ErrorWrapperFor*` error. Errors of nodes predating
+/// the structured errors only have a `message` and `data`.
+fn rpc_error_chain(error: &Value) -> Vec<String> {
+    let Some(name) = error["name"].as_str() else {
+        return [&error["message"], &error["data"]]
+            .into_iter()
+            .filter_map(info)
+            .collect();
+    };
+    let cause = &error["cause"];
+    [name.to_string()]
+        .into_iter()
+        .chain(cause["name"].as_str().map(str::to_string))
+        .chain(info(&cause["info"]))
+        .collect()
+}
+
+/// `info` of a cause, rendered as its `error_message` if that is all it
+/// holds, e.g. for `PARSE_ERROR`, or as JSON.
+fn info(info: &Value) -> Option<String> {
+    match info {
+        Value::Null => None,
+        Value::String(message) => Some(message.clone()),
+        Value::Object(fields) if fields.is_empty() => None,
+        Value::Object(fields) if fields.len() == 1 && fields.contains_key("error_message") => {
+            self::info(&fields["error_message"])
+        }
+        info => Some(info.to_string()),
+    }
+}
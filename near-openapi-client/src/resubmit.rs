@@ -0,0 +1,227 @@
+//! Resubmitting transactions that expired or were rejected due to congestion.
+use std::future::Future;
+
+use crate::jsonrpc::RpcError;
+use crate::nonce::{self, NonceError};
+use crate::rpc::RpcClient;
+use crate::types::{
+    self, AccountId, CryptoHash, ErrorWrapperForRpcQueryError, ErrorWrapperForRpcTransactionError,
+    InvalidTxError, NearGas, PublicKey, RpcTransactionError,
+};
+
+/// How [`send_with_resubmission`] rebuilds rejected transactions.
+///
+/// By default a transaction is submitted up to 3 times with the same gas and
+/// waits for [`TxExecutionStatus::ExecutedOptimistic`](types::TxExecutionStatus).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResubmitPolicy {
+    max_attempts: u32,
+    gas_multiplier: f64,
+    max_gas: Option<NearGas>,
+    wait_until: types::TxExecutionStatus,
+}
+
+impl Default for ResubmitPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            gas_multiplier: 1.0,
+            max_gas: None,
+            wait_until: types::TxExecutionStatus::ExecutedOptimistic,
+        }
+    }
+}
+
+impl ResubmitPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Total number of submissions, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Multiplies the attached gas by `multiplier` on every resubmission,
+    /// never exceeding `max_gas`.
+    pub fn escalate_gas(mut self, multiplier: f64, max_gas: NearGas) -> Self {
+        self.gas_multiplier = multiplier;
+        self.max_gas = Some(max_gas);
+        self
+    }
+
+    pub fn wait_until(mut self, wait_until: types::TxExecutionStatus) -> Self {
+        self.wait_until = wait_until;
+        self
+    }
+
+    fn escalated_gas(&self, gas: NearGas) -> NearGas {
+        let escalated = NearGas::from_gas((gas.as_gas() as f64 * self.gas_multiplier) as u64);
+        self.max_gas
+            .map_or(escalated, |max_gas| escalated.min(max_gas))
+    }
+}
+
+/// Parameters to build and sign one submission with.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionParams {
+    /// Number of the submission, counting from 1.
+    pub attempt: u32,
+    pub nonce: u64,
+    pub block_hash: CryptoHash,
+    /// Gas to attach to the function calls of the transaction.
+    pub gas: NearGas,
+}
+
+/// A submission that the node rejected and that was then resubmitted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RejectedAttempt {
+    pub params: TransactionParams,
+    pub reason: InvalidTxError,
+}
+
+/// Error ending [`send_with_resubmission`].
+#[derive(Debug)]
+pub enum ResubmitError {
+    /// Fetching the access key nonce and a recent block hash failed.
+    Query(RpcError<ErrorWrapperForRpcQueryError>),
+    /// The node answered the access key query with something else.
+    UnexpectedQueryResponse(Box<types::RpcQueryResponse>),
+    /// Sending the transaction failed and was not resubmitted.
+    Send(RpcError<ErrorWrapperForRpcTransactionError>),
+}
+
+impl std::fmt::Display for ResubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Query(err) => write!(f, "failed to query access key: {}", err),
+            Self::UnexpectedQueryResponse(response) => {
+                write!(f, "unexpected access key query response: {:?}", response)
+            }
+            Self::Send(err) => write!(f, "failed to send transaction: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ResubmitError {}
+
+/// Outcome of [`send_with_resubmission`]: the final result together with the
+/// submissions rejected before it.
+#[derive(Debug)]
+pub struct Resubmission {
+    pub rejected: Vec<RejectedAttempt>,
+    pub result: Result<types::RpcTransactionResponse, ResubmitError>,
+}
+
+/// Sends a transaction of `signer_id` signed with `public_key`, resubmitting
+/// it when it expires, its nonce is stale, or its shard is congested or stuck.
+///
+/// Before every submission the access key nonce and a final block hash are
+/// fetched, and `sign` is called to build and sign the transaction with them
+/// and with the gas to attach, which starts at `gas` and is escalated
+/// according to `policy`.
+pub async fn send_with_resubmission<F, Fut>(
+    client: &RpcClient,
+    signer_id: &AccountId,
+    public_key: &PublicKey,
+    gas: NearGas,
+    policy: &ResubmitPolicy,
+    mut sign: F,
+) -> Resubmission
+where
+    F: FnMut(TransactionParams) -> Fut,
+    Fut: Future<Output = types::SignedTransaction>,
+{
+    let mut rejected = Vec::new();
+    let mut gas = gas;
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let (nonce, block_hash) = match access_key(client, signer_id, public_key).await {
+            Ok(access_key) => access_key,
+            Err(err) => {
+                return Resubmission {
+                    rejected,
+                    result: Err(err),
+                };
+            }
+        };
+        let params = TransactionParams {
+            attempt,
+            nonce: nonce + 1,
+            block_hash,
+            gas,
+        };
+        let request = types::RpcSendTransactionRequest {
+            signed_tx_base64: sign(params.clone()).await,
+            wait_until: policy.wait_until,
+        };
+        let err = match client.send_tx(&request).await {
+            Ok(response) => {
+                return Resubmission {
+                    rejected,
+                    result: Ok(response),
+                };
+            }
+            Err(err) => err,
+        };
+        match invalid_tx_error(&err).filter(is_resubmittable) {
+            Some(reason) if attempt < policy.max_attempts => {
+                rejected.push(RejectedAttempt { params, reason });
+                gas = policy.escalated_gas(gas);
+            }
+            _ => {
+                return Resubmission {
+                    rejected,
+                    result: Err(ResubmitError::Send(err)),
+                };
+            }
+        }
+    }
+}
+
+async fn access_key(
+    client: &RpcClient,
+    signer_id: &AccountId,
+    public_key: &PublicKey,
+) -> Result<(u64, CryptoHash), ResubmitError> {
+    nonce::access_key(client, signer_id, public_key)
+        .await
+        .map_err(|err| match err {
+            NonceError::Query(err) => ResubmitError::Query(err),
+            NonceError::UnexpectedResponse(response) => {
+                ResubmitError::UnexpectedQueryResponse(response)
+            }
+        })
+}
+
+/// Finds the `InvalidTxError` in the context of an `INVALID_TRANSACTION` error.
+pub(crate) fn invalid_tx_error(
+    err: &RpcError<ErrorWrapperForRpcTransactionError>,
+) -> Option<InvalidTxError> {
+    fn find(value: &serde_json::Value) -> Option<InvalidTxError> {
+        let object = value.as_object()?;
+        match object.get("InvalidTxError") {
+            Some(error) => serde_json::from_value(error.clone()).ok(),
+            None => object.values().find_map(find),
+        }
+    }
+
+    match err {
+        RpcError::Rpc(ErrorWrapperForRpcTransactionError::HandlerError(
+            RpcTransactionError::InvalidTransaction(context),
+        )) => find(&serde_json::Value::Object(context.clone())),
+        _ => None,
+    }
+}
+
+fn is_resubmittable(reason: &InvalidTxError) -> bool {
+    matches!(
+        reason,
+        InvalidTxError::Expired
+            | InvalidTxError::InvalidNonce { .. }
+            | InvalidTxError::ShardCongested { .. }
+            | InvalidTxError::ShardStuck { .. }
+    )
+}
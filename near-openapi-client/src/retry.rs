@@ -0,0 +1,281 @@
+//! Retrying transient failures of JSON-RPC calls.
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::Error;
+use crate::jsonrpc::RpcError;
+use crate::methods::RpcMethod;
+use crate::rpc::RpcClient;
+use crate::time;
+use crate::types::{BlockHeaderView, CryptoHash};
+
+/// Why an attempt of a call failed, as seen by [`RetryPolicy`] predicates.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FailedAttempt {
+    /// The request could not be sent or the response body not read.
+    Transport,
+    /// The node answered with a non-200 HTTP status.
+    Status(u16),
+    /// The node answered with a JSON-RPC error. `name` is the variant of the
+    /// `ErrorWrapperFor*` type (e.g. `HANDLER_ERROR`) and `cause` the name of
+    /// the wrapped error (e.g. `UNKNOWN_BLOCK`).
+    Rpc { name: String, cause: Option<String> },
+}
+
+impl FailedAttempt {
+    /// JSON-RPC error causes that usually go away on their own, e.g. while
+    /// the node is catching up.
+    pub const TRANSIENT_CAUSES: &'static [&'static str] = &[
+        "NO_SYNCED_BLOCKS",
+        "NOT_SYNCED_YET",
+        "TIMEOUT_ERROR",
+        "UNKNOWN_BLOCK",
+    ];
+
+    pub(crate) fn from_client_error(err: &Error<()>) -> Option<Self> {
+        match err {
+            Error::CommunicationError(_) | Error::ResponseBodyError(_) => Some(Self::Transport),
+            Error::UnexpectedResponse(response) => Some(Self::Status(response.status().as_u16())),
+            _ => None,
+        }
+    }
+
+    /// Reads the error names from a JSON-RPC error response.
+    pub(crate) fn from_error_response(body: &[u8]) -> Option<Self> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            error: ErrorNames,
+        }
+
+        let response: Response = serde_json::from_slice(body).ok()?;
+        Some(response.error.into())
+    }
+
+    /// Reads the error names from a decoded `ErrorWrapperFor*` error.
+    pub(crate) fn from_rpc_error<E: serde::Serialize>(error: &E) -> Option<Self> {
+        let value = serde_json::to_value(error).ok()?;
+        let names: ErrorNames = serde_json::from_value(value).ok()?;
+        Some(names.into())
+    }
+
+    /// Name of the wrapped error of a JSON-RPC error.
+    pub fn cause(&self) -> Option<&str> {
+        match self {
+            Self::Rpc { cause, .. } => cause.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Default retry predicate: transport errors, 408, 429 and 5xx statuses,
+    /// and the [`TRANSIENT_CAUSES`](Self::TRANSIENT_CAUSES).
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Transport => true,
+            Self::Status(status) => matches!(status, 408 | 429 | 500..=599),
+            Self::Rpc { cause, .. } => cause
+                .as_deref()
+                .is_some_and(|cause| Self::TRANSIENT_CAUSES.contains(&cause)),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct ErrorNames {
+    name: String,
+    cause: Option<Cause>,
+}
+
+#[derive(serde::Deserialize)]
+struct Cause {
+    name: String,
+}
+
+impl From<ErrorNames> for FailedAttempt {
+    fn from(value: ErrorNames) -> Self {
+        Self::Rpc {
+            name: value.name,
+            cause: value.cause.map(|cause| cause.name),
+        }
+    }
+}
+
+/// Delay between attempts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Backoff {
+    /// The same delay before every retry.
+    Fixed(Duration),
+    /// `initial` before the first retry, doubled before each following one
+    /// up to `max`.
+    Exponential { initial: Duration, max: Duration },
+}
+
+impl Backoff {
+    /// Delay before retry number `retry`, counting from 0.
+    pub fn delay(&self, retry: u32) -> Duration {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Exponential { initial, max } => initial
+                .checked_mul(2u32.saturating_pow(retry))
+                .map_or(max, |delay| delay.min(max)),
+        }
+    }
+}
+
+/// How [`RpcClient`](crate::rpc::RpcClient) retries failed calls.
+///
+/// By default a call is attempted up to 3 times, with exponential backoff
+/// from 100ms to 5s with full jitter, when [`FailedAttempt::is_transient`].
+#[derive(Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) backoff: Backoff,
+    pub(crate) jitter: bool,
+    pub(crate) retry_on: Arc<dyn Fn(&FailedAttempt) -> bool + Send + Sync>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: Backoff::Exponential {
+                initial: Duration::from_millis(100),
+                max: Duration::from_secs(5),
+            },
+            jitter: true,
+            retry_on: Arc::new(FailedAttempt::is_transient),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Total number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Whether to wait a random duration between zero and the backoff delay
+    /// instead of the full delay, so that clients don't retry in lockstep.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Replaces the predicate deciding which failures are retried.
+    pub fn retry_on(
+        mut self,
+        predicate: impl Fn(&FailedAttempt) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.retry_on = Arc::new(predicate);
+        self
+    }
+
+    /// Delay before retrying after `attempt` failed with `failure`, or `None`
+    /// if the call should fail. `attempt` counts from 1.
+    pub(crate) fn retry_delay(&self, attempt: u32, failure: &FailedAttempt) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(self.retry_on)(failure) {
+            return None;
+        }
+        let delay = self.backoff.delay(attempt - 1);
+        Some(if self.jitter {
+            delay.mul_f64(fastrand::f64())
+        } else {
+            delay
+        })
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How [`RpcClient::request_at_tip`] retries a read of a block the node
+/// does not know yet.
+///
+/// Right after a block is produced, nodes behind a load balancer may not
+/// have it yet and answer `UNKNOWN_BLOCK`, which fails reads of the tip in
+/// streaming pipelines spuriously. Such reads are retried once.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TipRetry {
+    /// Reads the same block again after the delay.
+    After(Duration),
+    /// Reads the previous block instead, which every node has.
+    PrevBlock,
+}
+
+impl Default for TipRetry {
+    fn default() -> Self {
+        Self::After(Duration::from_millis(500))
+    }
+}
+
+/// Response of [`RpcClient::request_at_tip`] and the block it was read at.
+#[derive(Clone, Debug)]
+pub struct TipResponse<R> {
+    pub response: R,
+    /// The requested block, or its predecessor after a retry with
+    /// [`TipRetry::PrevBlock`].
+    pub block_hash: CryptoHash,
+}
+
+impl RpcClient {
+    /// Sends the JSON-RPC method `M` at the block of `header`, e.g. one just
+    /// yielded by a [`BlockStream`](crate::block::BlockStream), with the
+    /// params `params` builds for a block hash. If the node does not know the
+    /// block yet, the read is retried once as configured by `retry`; other
+    /// errors are returned as they are.
+    pub async fn request_at_tip<M: RpcMethod>(
+        &self,
+        header: &BlockHeaderView,
+        retry: TipRetry,
+        params: impl Fn(&CryptoHash) -> M::Params,
+    ) -> Result<TipResponse<M::Response>, RpcError<M::Error>>
+    where
+        M::Error: serde::Serialize,
+    {
+        let err = match self.request::<M>(&params(&header.hash)).await {
+            Ok(response) => {
+                return Ok(TipResponse {
+                    response,
+                    block_hash: header.hash,
+                });
+            }
+            Err(err) => err,
+        };
+        let unknown_block = match &err {
+            RpcError::Rpc(error) => FailedAttempt::from_rpc_error(error)
+                .is_some_and(|failure| failure.cause() == Some("UNKNOWN_BLOCK")),
+            _ => false,
+        };
+        if !unknown_block {
+            return Err(err);
+        }
+        let block_hash = match retry {
+            TipRetry::After(delay) => {
+                time::sleep(delay).await;
+                &header.hash
+            }
+            TipRetry::PrevBlock => &header.prev_hash,
+        };
+        let response = self.request::<M>(&params(block_hash)).await?;
+        Ok(TipResponse {
+            response,
+            block_hash: *block_hash,
+        })
+    }
+}
@@ -0,0 +1,520 @@
+//! Client wrapper that handles the JSON-RPC envelope.
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crate::cache::ResponseCache;
+use crate::genesis::GenesisCache;
+use crate::jsonrpc::{self, RawResponse, RpcError};
+use crate::methods::RpcMethod;
+use crate::metrics::{self, MetricsRecorder, Outcome};
+use crate::network::{self, ExpectedNetwork};
+use crate::replay::{FixtureMode, Fixtures};
+use crate::request_id::{self, RequestError, RequestId, RequestIds};
+use crate::retry::{FailedAttempt, RetryPolicy};
+use crate::time::{self, Instant};
+#[cfg(feature = "tracing")]
+use crate::trace;
+use crate::transport::{self, SendError, Transport};
+use crate::types::validate::Validate;
+use crate::types::{self, lenient};
+use crate::validators;
+use crate::{Client, ClientInfo, Error};
+
+/// Methods whose params hold a block reference, flattened into them.
+const BLOCK_REFERENCE_METHODS: &[&str] = &[
+    "EXPERIMENTAL_changes",
+    "EXPERIMENTAL_changes_in_block",
+    "EXPERIMENTAL_protocol_config",
+    "block",
+    "block_effects",
+    "changes",
+    "query",
+];
+
+/// Keys of the params holding a block reference.
+const BLOCK_REFERENCE_KEYS: &[&str] = &["block_id", "finality", "sync_checkpoint"];
+
+/// Wrapper around the generated [`Client`] that sends JSON-RPC calls and
+/// unwraps their responses.
+///
+/// The generated client remains available through [`RpcClient::client`].
+#[derive(Clone, Debug)]
+pub struct RpcClient {
+    client: Client,
+    expected_network: Option<ExpectedNetwork>,
+    network_verified: Arc<OnceLock<()>>,
+    unknown_variant_handler: Option<UnknownVariantHandler>,
+    retry_policy: Option<RetryPolicy>,
+    metrics: Option<metrics::Recorder>,
+    fixtures: Option<Fixtures>,
+    transport: Option<transport::Handle>,
+    method_timeouts: Arc<HashMap<String, Duration>>,
+    cache: Option<ResponseCache>,
+    request_ids: Option<RequestIds>,
+    default_finality: Option<types::Finality>,
+    pub(crate) epochs: validators::EpochCache,
+    pub(crate) genesis: GenesisCache,
+}
+
+impl RpcClient {
+    /// Create a new client for the node at `baseurl`.
+    pub fn new(baseurl: &str) -> Self {
+        Self::from(Client::new(baseurl))
+    }
+
+    /// Pins the client to a network: the first call checks the node's
+    /// `status` against `expected` and every call fails with
+    /// [`RpcError::WrongNetwork`] until the node matches it.
+    pub fn with_expected_network(mut self, expected: ExpectedNetwork) -> Self {
+        self.expected_network = Some(expected);
+        self.network_verified = Arc::default();
+        self
+    }
+
+    /// Accepts enum variants missing from this version of the crate instead of
+    /// failing to decode the response, see [`lenient`]. `handler` is called
+    /// for each of them, e.g. to log the spec drift or count it in a metric.
+    pub fn with_unknown_variants(
+        mut self,
+        handler: impl Fn(&UnknownVariant) + Send + Sync + 'static,
+    ) -> Self {
+        self.unknown_variant_handler = Some(UnknownVariantHandler(Arc::new(handler)));
+        self
+    }
+
+    /// Retries calls that fail transiently according to `policy`.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Records the method, latency and outcome of every call with
+    /// `recorder`, see [`metrics`].
+    pub fn with_metrics(mut self, recorder: impl MetricsRecorder + 'static) -> Self {
+        self.metrics = Some(metrics::Recorder(Arc::new(recorder)));
+        self
+    }
+
+    /// Records the responses to every call to files, or answers calls from
+    /// them without sending them, see [`replay`]. Replaying clients skip the
+    /// check of [`RpcClient::with_expected_network`].
+    pub fn with_fixtures(mut self, fixtures: Fixtures) -> Self {
+        self.fixtures = Some(fixtures);
+        self
+    }
+
+    /// Sends requests with `transport` instead of the `reqwest` client of
+    /// the generated client, to the same URL, see [`transport`].
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(transport::Handle(Arc::new(transport)));
+        self
+    }
+
+    /// Times out calls of `method`, e.g. `EXPERIMENTAL_light_client_proof`,
+    /// after `timeout` instead of the timeout of the underlying client; each
+    /// retry gets the full `timeout`. Ignored by custom transports and on
+    /// wasm. See also [`ClientBuilder::method_timeout`](crate::builder::ClientBuilder::method_timeout).
+    pub fn with_method_timeout(mut self, method: &str, timeout: Duration) -> Self {
+        Arc::make_mut(&mut self.method_timeouts).insert(method.to_string(), timeout);
+        self
+    }
+
+    /// Answers requests about blocks that can no longer change from `cache`,
+    /// see [`cache`](crate::cache). Cached responses skip retries, fixtures
+    /// and the transport, but are still recorded in metrics and traces.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sends every call with an id from `ids` instead of `dontcare`, see
+    /// [`request_id`].
+    pub fn with_request_ids(mut self, ids: RequestIds) -> Self {
+        self.request_ids = Some(ids);
+        self
+    }
+
+    /// Sends calls of methods taking a block reference, such as `block` or
+    /// `query`, whose params have none at `finality`, e.g.
+    /// `client.call("query", &json!({"request_type": "view_account", "account_id": "alice.near"}))`.
+    /// Typed params always have one, see the shorthands of
+    /// [`BlockReference`](types::BlockReference) such as
+    /// `BlockReference::final_()`.
+    pub fn with_default_finality(mut self, finality: types::Finality) -> Self {
+        self.default_finality = Some(finality);
+        self
+    }
+
+    /// The underlying generated client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Sends `method` with `params`, returning the decoded `result`.
+    pub async fn call<P, R, E>(&self, method: &str, params: &P) -> Result<R, RpcError<E>>
+    where
+        P: serde::Serialize,
+        R: serde::de::DeserializeOwned,
+        E: serde::de::DeserializeOwned,
+    {
+        let request_id = self.request_ids.as_ref().map(RequestIds::next_id);
+        self.call_with_id(method, params, request_id.as_ref()).await
+    }
+
+    /// Sends `method` with `params` and the JSON-RPC id `request_id`, if set.
+    async fn call_with_id<P, R, E>(
+        &self,
+        method: &str,
+        params: &P,
+        request_id: Option<&RequestId>,
+    ) -> Result<R, RpcError<E>>
+    where
+        P: serde::Serialize,
+        R: serde::de::DeserializeOwned,
+        E: serde::de::DeserializeOwned,
+    {
+        // Only encoding the params and decoding the response depend on the
+        // method: sending and retrying are the same code for all of them.
+        let body = self.encode(method, params)?;
+        let call = async {
+            let start = self.metrics.as_ref().map(|_| Instant::now());
+            let (result, failure) = self.post_with_retries(body, method, request_id).await;
+            let result = match result {
+                Ok(body) => self.decode(method, body),
+                Err(err) => Err(err.widen()),
+            };
+            self.record_metrics(method, start, &result, failure.as_ref());
+            result
+        };
+        #[cfg(feature = "tracing")]
+        let call = trace::instrument(method, trace::block_reference(params), request_id, call);
+        call.await
+    }
+
+    /// Sends the JSON-RPC method `M`, e.g.
+    /// `client.request::<methods::Block>(&params)`. Params failing
+    /// [`Validate`] are not sent and fail with [`Error::InvalidRequest`].
+    pub async fn request<M: RpcMethod>(
+        &self,
+        params: &M::Params,
+    ) -> Result<M::Response, RpcError<M::Error>> {
+        params
+            .validate()
+            .map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        self.call(M::NAME, params).await
+    }
+
+    /// Sends the JSON-RPC method `M` like [`RpcClient::request`], returning
+    /// the id it was sent with along with its error, see [`request_id`]. The
+    /// id is taken from the ids of [`RpcClient::with_request_ids`], or from
+    /// ids with a random prefix shared by clients without them.
+    pub async fn request_with_id<M: RpcMethod>(
+        &self,
+        params: &M::Params,
+    ) -> Result<M::Response, RequestError<M::Error>> {
+        let request_id = self
+            .request_ids
+            .as_ref()
+            .unwrap_or_else(|| request_id::default_ids())
+            .next_id();
+        let result = match params.validate() {
+            Ok(()) => self.call_with_id(M::NAME, params, Some(&request_id)).await,
+            Err(err) => Err(Error::InvalidRequest(err.to_string()).into()),
+        };
+        result.map_err(|error| RequestError { request_id, error })
+    }
+
+    /// Sends the JSON-RPC method `M` like [`RpcClient::request`], but leaves
+    /// its response undecoded so that it can be decoded into types borrowing
+    /// from it, e.g. `client.request_raw::<methods::Chunk>(&params)` and then
+    /// `response.decode::<types::borrowed::RpcChunkResponseRef>()`. Unknown
+    /// variants are not reported to the handler of
+    /// [`RpcClient::with_unknown_variants`], and errors of the node are only
+    /// returned by [`RawResponse::decode`].
+    pub async fn request_raw<M: RpcMethod>(
+        &self,
+        params: &M::Params,
+    ) -> Result<RawResponse<M::Error>, RpcError<M::Error>> {
+        params
+            .validate()
+            .map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        let body = self.encode(M::NAME, params)?;
+        let request_id = self.request_ids.as_ref().map(RequestIds::next_id);
+        let request_id = request_id.as_ref();
+        let call = async {
+            let start = self.metrics.as_ref().map(|_| Instant::now());
+            let (result, failure) = self.post_with_retries(body, M::NAME, request_id).await;
+            let result = result.map(RawResponse::new).map_err(RpcError::widen);
+            // Errors of the node are only decoded by the caller, but are
+            // still recorded as such.
+            match (&result, &failure) {
+                (Ok(_), Some(FailedAttempt::Rpc { .. })) => {
+                    let error = Err::<(), _>(RpcError::Rpc(()));
+                    self.record_metrics(M::NAME, start, &error, failure.as_ref())
+                }
+                _ => self.record_metrics(M::NAME, start, &result, failure.as_ref()),
+            }
+            result
+        };
+        #[cfg(feature = "tracing")]
+        let call = trace::instrument(M::NAME, trace::block_reference(params), request_id, call);
+        call.await
+    }
+
+    /// Status of a transaction looked up by the signed transaction itself
+    /// instead of its hash and signer.
+    pub async fn tx_by_signed_transaction(
+        &self,
+        signed_tx: types::SignedTransaction,
+        wait_until: types::TxExecutionStatus,
+    ) -> Result<types::RpcTransactionResponse, RpcError<types::ErrorWrapperForRpcTransactionError>>
+    {
+        self.tx(&types::RpcTransactionStatusRequest::by_signed_transaction(
+            signed_tx, wait_until,
+        ))
+        .await
+    }
+
+    /// Encodes the request of `method` with `params`, at the default
+    /// finality if they omit the block.
+    #[allow(clippy::result_large_err)]
+    fn encode<P: serde::Serialize>(
+        &self,
+        method: &str,
+        params: &P,
+    ) -> Result<bytes::Bytes, Error<()>> {
+        let Some(finality) = self
+            .default_finality
+            .as_ref()
+            .filter(|_| BLOCK_REFERENCE_METHODS.contains(&method))
+        else {
+            return jsonrpc::encode(method, params);
+        };
+        let mut params =
+            serde_json::to_value(params).map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        if let Some(fields) = params.as_object_mut()
+            && !BLOCK_REFERENCE_KEYS
+                .iter()
+                .any(|key| fields.contains_key(*key))
+        {
+            let finality = serde_json::to_value(finality).expect("finality encodes to JSON");
+            fields.insert("finality".to_string(), finality);
+        }
+        jsonrpc::encode(method, &params)
+    }
+
+    /// Posts a batch of requests and returns the raw response body. Only
+    /// failures of the whole request are retried.
+    pub(crate) async fn post_batch<B: serde::Serialize>(
+        &self,
+        batch: &B,
+    ) -> Result<bytes::Bytes, RpcError<Infallible>> {
+        let body =
+            serde_json::to_vec(batch).map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        let post = async {
+            let start = self.metrics.as_ref().map(|_| Instant::now());
+            let (result, failure) = self.post_with_retries(body.into(), "batch", None).await;
+            self.record_metrics("batch", start, &result, failure.as_ref());
+            result
+        };
+        #[cfg(feature = "tracing")]
+        let post = trace::instrument("batch", None, None, post);
+        post.await
+    }
+
+    /// Posts the encoded `body` until it succeeds or the retry policy gives
+    /// up, returning the last response body and how the last attempt failed,
+    /// if it did. Responses to batches are arrays, which never read as a
+    /// JSON-RPC error, so batches are not retried for errors of their calls.
+    /// Each attempt times out after the timeout set for `method`, if any,
+    /// and is sent with the JSON-RPC id `request_id`, if set.
+    async fn post_with_retries(
+        &self,
+        body: bytes::Bytes,
+        method: &str,
+        request_id: Option<&RequestId>,
+    ) -> (
+        Result<bytes::Bytes, RpcError<Infallible>>,
+        Option<FailedAttempt>,
+    ) {
+        if let Err(err) = self.ensure_network().await {
+            return (Err(err), None);
+        }
+        if let Some(response) = self.cache.as_ref().and_then(|cache| cache.get(&body)) {
+            return (Ok(response), None);
+        }
+        let timeout = self.method_timeouts.get(method).copied();
+        let mut attempt = 1;
+        loop {
+            let (result, failure) = match self.post(&body, timeout, request_id).await {
+                Ok(response) => {
+                    let failure = FailedAttempt::from_error_response(&response);
+                    (Ok(response), failure)
+                }
+                Err(err) => (Err(RpcError::Client(err.error)), err.failure),
+            };
+            let delay = match (&self.retry_policy, &failure) {
+                (Some(policy), Some(failure)) => policy.retry_delay(attempt, failure),
+                _ => None,
+            };
+            let Some(delay) = delay else {
+                #[cfg(feature = "tracing")]
+                trace::record_outcome(attempt, failure.as_ref());
+                #[cfg(feature = "tracing")]
+                if self
+                    .request_ids
+                    .as_ref()
+                    .is_some_and(RequestIds::logs_error_causes)
+                {
+                    trace::record_error_chain(&result);
+                }
+                if let (Some(cache), Ok(response), None) = (&self.cache, &result, &failure) {
+                    cache.insert(&body, response);
+                }
+                return (result, failure);
+            };
+            #[cfg(feature = "tracing")]
+            if let Some(failure) = &failure {
+                trace::record_retry(attempt, failure, delay);
+            }
+            time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Posts `body` to the node, or answers it from the fixtures. Fixtures
+    /// are keyed by the request without its id.
+    async fn post(
+        &self,
+        body: &bytes::Bytes,
+        timeout: Option<Duration>,
+        request_id: Option<&RequestId>,
+    ) -> Result<bytes::Bytes, SendError> {
+        match &self.fixtures {
+            Some(fixtures) if fixtures.mode() == FixtureMode::Replay => Ok(fixtures.read(body)?),
+            Some(fixtures) => {
+                let response = self.send(body, timeout, request_id).await?;
+                fixtures.write(body, &response)?;
+                Ok(response)
+            }
+            None => self.send(body, timeout, request_id).await,
+        }
+    }
+
+    /// Posts `body` to the node with the transport of the client. Custom
+    /// transports handle timeouts themselves.
+    async fn send(
+        &self,
+        body: &bytes::Bytes,
+        timeout: Option<Duration>,
+        request_id: Option<&RequestId>,
+    ) -> Result<bytes::Bytes, SendError> {
+        let body = &match request_id {
+            Some(request_id) => jsonrpc::with_id(body, request_id),
+            None => body.clone(),
+        };
+        match &self.transport {
+            Some(transport) => {
+                transport::send(transport, self.client.baseurl(), body.clone()).await
+            }
+            None => Ok(jsonrpc::post(&self.client, body.clone(), timeout).await?),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn decode<R, E>(&self, method: &str, body: bytes::Bytes) -> Result<R, RpcError<E>>
+    where
+        R: serde::de::DeserializeOwned,
+        E: serde::de::DeserializeOwned,
+    {
+        let Some(handler) = &self.unknown_variant_handler else {
+            return jsonrpc::decode(body);
+        };
+        let (result, discriminants) = lenient::decode_leniently(|| jsonrpc::decode(body));
+        for discriminant in discriminants {
+            (handler.0)(&UnknownVariant {
+                method: method.to_string(),
+                discriminant,
+            });
+        }
+        result
+    }
+
+    /// Records a call that started at `start`, which is only set when
+    /// metrics are recorded.
+    fn record_metrics<T, E>(
+        &self,
+        method: &str,
+        start: Option<Instant>,
+        result: &Result<T, RpcError<E>>,
+        failure: Option<&FailedAttempt>,
+    ) {
+        if let (Some(metrics), Some(start)) = (&self.metrics, start) {
+            metrics
+                .0
+                .record_call(method, start.elapsed(), &Outcome::of(result, failure));
+        }
+    }
+
+    async fn ensure_network(&self) -> Result<(), RpcError<Infallible>> {
+        let Some(expected) = &self.expected_network else {
+            return Ok(());
+        };
+        if let Some(fixtures) = &self.fixtures
+            && fixtures.mode() == FixtureMode::Replay
+        {
+            return Ok(());
+        }
+        if self.network_verified.get().is_none() {
+            let request = jsonrpc::encode("status", &types::RpcStatusRequest(()))?;
+            let timeout = self.method_timeouts.get("status").copied();
+            let response = self
+                .send(&request, timeout, None)
+                .await
+                .map_err(|err| err.error)?;
+            network::verify_status(jsonrpc::decode(response), expected)?;
+            let _ = self.network_verified.set(());
+        }
+        Ok(())
+    }
+}
+
+impl From<Client> for RpcClient {
+    fn from(client: Client) -> Self {
+        Self {
+            client,
+            expected_network: None,
+            network_verified: Arc::default(),
+            unknown_variant_handler: None,
+            retry_policy: None,
+            metrics: None,
+            fixtures: None,
+            transport: None,
+            method_timeouts: Arc::default(),
+            cache: None,
+            request_ids: None,
+            default_finality: None,
+            epochs: validators::EpochCache::default(),
+            genesis: GenesisCache::default(),
+        }
+    }
+}
+
+/// Enum variant unknown to this version of the crate, found in the response to
+/// `method`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownVariant {
+    pub method: String,
+    pub discriminant: String,
+}
+
+#[derive(Clone)]
+struct UnknownVariantHandler(Arc<dyn Fn(&UnknownVariant) + Send + Sync>);
+
+impl std::fmt::Debug for UnknownVariantHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("UnknownVariantHandler")
+    }
+}
@@ -0,0 +1,303 @@
+//! Reading several queries from the same block.
+//!
+//! Queries by finality are answered at whatever block the node reached when
+//! it got them, so reading e.g. the balance, the state and the access keys of
+//! an account with separate queries may mix blocks. A [`Snapshot`] pins a
+//! block hash and sends every query at that block. [`RpcClient::snapshot`]
+//! resolves a finality to a block up front; a snapshot made with
+//! [`Snapshot::new`] pins the block its first query is answered at.
+//!
+//! ```rust,ignore
+//! let snapshot = rpc_client.snapshot(Finality::Final).await?;
+//! let account = snapshot.view_account(&account_id).await?;
+//! let balances = try_join_all(tokens.iter().map(|token| {
+//!     snapshot.view_function::<_, String>(token, "ft_balance_of", &json!({"account_id": account_id}))
+//! }))
+//! .await?;
+//! println!("read at block {:?}", snapshot.block_height());
+//! ```
+use std::sync::OnceLock;
+
+use futures_util::future::try_join_all;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::block::HeaderOnly;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccessKeyList, AccessKeyView, AccountId, AccountView, BlockId, BlockReference, CallResult,
+    ContractCodeView, CryptoHash, ErrorWrapperForRpcBlockError, ErrorWrapperForRpcQueryError,
+    Finality, FunctionArgs, PublicKey, RpcBlockRequest, RpcQueryRequest, RpcQueryResponse,
+    StoreKey, ViewAccessKeyByFinalityRequestType, ViewAccessKeyListByFinalityRequestType,
+    ViewAccountByFinalityRequestType, ViewCodeByFinalityRequestType,
+    ViewStateByFinalityRequestType, ViewStateResult,
+};
+use crate::view::ViewResult;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The block to pin could not be fetched.
+    Block(RpcError<ErrorWrapperForRpcBlockError>),
+    /// A query failed.
+    Query(RpcError<ErrorWrapperForRpcQueryError>),
+    /// A query was answered at another block than the pinned one.
+    BlockMismatch {
+        expected: CryptoHash,
+        found: CryptoHash,
+    },
+    /// The arguments of a view method could not be encoded as JSON.
+    Args(serde_json::Error),
+    /// A view method returned something that could not be decoded.
+    Result(serde_json::Error),
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(err) => write!(f, "failed to fetch block: {}", err),
+            Self::Query(err) => write!(f, "query failed: {}", err),
+            Self::BlockMismatch { expected, found } => write!(
+                f,
+                "query answered at block {} instead of {}",
+                found, expected
+            ),
+            Self::Args(err) => write!(f, "failed to encode arguments: {}", err),
+            Self::Result(err) => write!(f, "failed to decode result: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<RpcError<ErrorWrapperForRpcQueryError>> for SnapshotError {
+    fn from(value: RpcError<ErrorWrapperForRpcQueryError>) -> Self {
+        Self::Query(value)
+    }
+}
+
+/// Queries answered at a single block.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    client: RpcClient,
+    block: OnceLock<(CryptoHash, u64)>,
+}
+
+/// A query result together with the block it was read at.
+#[derive(serde::Deserialize)]
+struct AtBlock<T> {
+    block_hash: CryptoHash,
+    block_height: u64,
+    #[serde(flatten)]
+    view: T,
+}
+
+impl RpcClient {
+    /// Snapshot of the latest block with `finality`, pinned before any query
+    /// is sent.
+    pub async fn snapshot(&self, finality: Finality) -> Result<Snapshot, SnapshotError> {
+        let block: HeaderOnly = self
+            .call("block", &RpcBlockRequest::Finality(finality))
+            .await
+            .map_err(SnapshotError::Block)?;
+        Ok(Snapshot::at(
+            self.clone(),
+            block.header.hash,
+            block.header.height,
+        ))
+    }
+}
+
+impl Snapshot {
+    pub fn new(client: RpcClient) -> Self {
+        Self {
+            client,
+            block: OnceLock::new(),
+        }
+    }
+
+    /// Snapshot of the block `block_hash` at `block_height`, e.g. of a block
+    /// read before.
+    pub fn at(client: RpcClient, block_hash: CryptoHash, block_height: u64) -> Self {
+        Self {
+            client,
+            block: OnceLock::from((block_hash, block_height)),
+        }
+    }
+
+    /// Hash of the pinned block, once a query was answered.
+    pub fn block_hash(&self) -> Option<&CryptoHash> {
+        self.block.get().map(|(hash, _)| hash)
+    }
+
+    /// Height of the pinned block, once a query was answered.
+    pub fn block_height(&self) -> Option<u64> {
+        self.block.get().map(|(_, height)| *height)
+    }
+
+    /// Sends `request` at the pinned block, or pins the block it is answered
+    /// at if none is pinned yet.
+    pub async fn query(
+        &self,
+        request: &RpcQueryRequest,
+    ) -> Result<RpcQueryResponse, SnapshotError> {
+        let response = self.client.query(&self.pinned(request)).await?;
+        match self.pin(response.block_hash(), response.block_height()) {
+            Some(mismatch) => Err(mismatch),
+            None => Ok(response),
+        }
+    }
+
+    /// Sends `requests` at the same block, returning their responses in the
+    /// same order. Unless a block is pinned already, the first request pins
+    /// it and the others are sent concurrently after it.
+    pub async fn query_all(
+        &self,
+        requests: &[RpcQueryRequest],
+    ) -> Result<Vec<RpcQueryResponse>, SnapshotError> {
+        let mut responses = Vec::with_capacity(requests.len());
+        let mut requests = requests.iter();
+        if self.block.get().is_none() {
+            let Some(first) = requests.next() else {
+                return Ok(responses);
+            };
+            responses.push(self.query(first).await?);
+        }
+        responses.extend(try_join_all(requests.map(|request| self.query(request))).await?);
+        Ok(responses)
+    }
+
+    /// The account `account_id`.
+    pub async fn view_account(&self, account_id: &AccountId) -> Result<AccountView, SnapshotError> {
+        self.view(RpcQueryRequest::ViewAccountByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            request_type: ViewAccountByFinalityRequestType::ViewAccount,
+        })
+        .await
+    }
+
+    /// The access key `public_key` of `account_id`.
+    pub async fn view_access_key(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+    ) -> Result<AccessKeyView, SnapshotError> {
+        self.view(RpcQueryRequest::ViewAccessKeyByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            public_key: *public_key,
+            request_type: ViewAccessKeyByFinalityRequestType::ViewAccessKey,
+        })
+        .await
+    }
+
+    /// All access keys of `account_id`.
+    pub async fn view_access_key_list(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<AccessKeyList, SnapshotError> {
+        self.view(RpcQueryRequest::ViewAccessKeyListByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            request_type: ViewAccessKeyListByFinalityRequestType::ViewAccessKeyList,
+        })
+        .await
+    }
+
+    /// The contract code deployed to `account_id`.
+    pub async fn view_code(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<ContractCodeView, SnapshotError> {
+        self.view(RpcQueryRequest::ViewCodeByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            request_type: ViewCodeByFinalityRequestType::ViewCode,
+        })
+        .await
+    }
+
+    /// The contract state of `account_id` under the keys starting with
+    /// `prefix`.
+    pub async fn view_state(
+        &self,
+        account_id: &AccountId,
+        prefix: &[u8],
+    ) -> Result<ViewStateResult, SnapshotError> {
+        self.view(RpcQueryRequest::ViewStateByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            include_proof: None,
+            prefix_base64: StoreKey::from_bytes(prefix),
+            request_type: ViewStateByFinalityRequestType::ViewState,
+        })
+        .await
+    }
+
+    /// Calls the view method `method_name` of `contract_id` with `args`
+    /// encoded as JSON, like [`RpcClient::view_function`].
+    pub async fn view_function<A, R>(
+        &self,
+        contract_id: &AccountId,
+        method_name: &str,
+        args: &A,
+    ) -> Result<ViewResult<R>, SnapshotError>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        let args = FunctionArgs::from_json(args).map_err(SnapshotError::Args)?;
+        let request = RpcQueryRequest::call_function(
+            contract_id.clone(),
+            method_name,
+            args,
+            BlockReference::Finality(Finality::Final),
+        );
+        let (view, block_height) = self.view_at::<CallResult>(request).await?;
+        Ok(ViewResult {
+            result: view.json().map_err(SnapshotError::Result)?,
+            logs: view.logs,
+            block_height,
+        })
+    }
+
+    /// The query at the pinned block. Queries of an unpinned snapshot are
+    /// sent as given.
+    fn pinned(&self, request: &RpcQueryRequest) -> RpcQueryRequest {
+        match self.block.get() {
+            Some((block_hash, _)) => request.with_block_id(BlockId::CryptoHash(*block_hash)),
+            None => request.clone(),
+        }
+    }
+
+    /// Pins the block a query was answered at unless one is pinned already,
+    /// returning the mismatch if it is not the pinned one.
+    fn pin(&self, block_hash: &CryptoHash, block_height: u64) -> Option<SnapshotError> {
+        let (expected, _) = self.block.get_or_init(|| (*block_hash, block_height));
+        (expected != block_hash).then(|| SnapshotError::BlockMismatch {
+            expected: *expected,
+            found: *block_hash,
+        })
+    }
+
+    async fn view<T: DeserializeOwned>(
+        &self,
+        request: RpcQueryRequest,
+    ) -> Result<T, SnapshotError> {
+        Ok(self.view_at(request).await?.0)
+    }
+
+    /// Sends a query and decodes only the view in its result, together with
+    /// the height of the block.
+    async fn view_at<T: DeserializeOwned>(
+        &self,
+        request: RpcQueryRequest,
+    ) -> Result<(T, u64), SnapshotError> {
+        let response: AtBlock<T> = self.client.call("query", &self.pinned(&request)).await?;
+        match self.pin(&response.block_hash, response.block_height) {
+            Some(mismatch) => Err(mismatch),
+            None => Ok((response.view, response.block_height)),
+        }
+    }
+}
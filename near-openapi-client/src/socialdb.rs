@@ -0,0 +1,158 @@
+//! Reading data of the Social DB contract (`social.near`), e.g. profiles and
+//! the follow graph.
+//!
+//! ```rust,ignore
+//! let social = SocialDb::new(rpc_client);
+//! let data = social.get(&["alice.near/profile/**"], GetOptions::default(), block).await?;
+//! println!("{:?}", data.value("alice.near/profile/name"));
+//! ```
+use serde_json::Value;
+
+use crate::rpc::RpcClient;
+use crate::types::{AccountId, BlockReference};
+use crate::view::ViewFunctionError;
+
+/// Social DB contract on mainnet.
+pub const MAINNET_CONTRACT: &str = "social.near";
+/// Social DB contract on testnet.
+pub const TESTNET_CONTRACT: &str = "v1.social08.testnet";
+
+/// Options of [`SocialDb::get`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize)]
+pub struct GetOptions {
+    /// Returns each value as `{"": value, ":block": height}`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub with_block_height: bool,
+    /// Returns deleted keys with `null` values.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub return_deleted: bool,
+}
+
+/// What [`SocialDb::keys`] returns for each matching key.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize)]
+pub enum KeysReturnType {
+    /// `true`.
+    #[default]
+    True,
+    /// The height of the block the key was last written at.
+    BlockHeight,
+}
+
+/// Options of [`SocialDb::keys`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize)]
+pub struct KeysOptions {
+    pub return_type: KeysReturnType,
+    /// Returns only keys holding values, not intermediate nodes.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub values_only: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub return_deleted: bool,
+}
+
+/// Reads the Social DB contract through `call_function` queries.
+#[derive(Clone, Debug)]
+pub struct SocialDb {
+    client: RpcClient,
+    contract_id: AccountId,
+}
+
+impl SocialDb {
+    /// Reads the mainnet contract.
+    pub fn new(client: RpcClient) -> Self {
+        Self::with_contract(client, MAINNET_CONTRACT.parse().unwrap())
+    }
+
+    pub fn with_contract(client: RpcClient, contract_id: AccountId) -> Self {
+        Self {
+            client,
+            contract_id,
+        }
+    }
+
+    /// Values matching `keys`, which are paths like `alice.near/profile/name`
+    /// and may end with `*` (direct children) or `**` (whole subtree).
+    pub async fn get(
+        &self,
+        keys: &[&str],
+        options: GetOptions,
+        block: BlockReference,
+    ) -> Result<SocialData, ViewFunctionError> {
+        #[derive(serde::Serialize)]
+        struct Args<'a> {
+            keys: &'a [&'a str],
+            options: GetOptions,
+        }
+
+        let args = Args { keys, options };
+        self.call("get", &args, block).await
+    }
+
+    /// Keys matching `keys`, without their values.
+    pub async fn keys(
+        &self,
+        keys: &[&str],
+        options: KeysOptions,
+        block: BlockReference,
+    ) -> Result<SocialData, ViewFunctionError> {
+        #[derive(serde::Serialize)]
+        struct Args<'a> {
+            keys: &'a [&'a str],
+            options: KeysOptions,
+        }
+
+        let args = Args { keys, options };
+        self.call("keys", &args, block).await
+    }
+
+    async fn call<A: serde::Serialize>(
+        &self,
+        method_name: &str,
+        args: &A,
+        block: BlockReference,
+    ) -> Result<SocialData, ViewFunctionError> {
+        let result = self
+            .client
+            .view_function(&self.contract_id, method_name, args, block)
+            .await?;
+        Ok(SocialData(result.result))
+    }
+}
+
+/// Nested JSON returned by the Social DB, navigable by key paths.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SocialData(pub Value);
+
+impl SocialData {
+    /// Node at `path`, e.g. `alice.near/profile/linktree`.
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(&self.0, |node, segment| node.get(segment))
+    }
+
+    /// String value at `path`. Values returned with their block height are
+    /// unwrapped.
+    pub fn value(&self, path: &str) -> Option<&str> {
+        match self.get(path)? {
+            Value::String(value) => Some(value),
+            Value::Object(node) => node.get("")?.as_str(),
+            _ => None,
+        }
+    }
+
+    /// Height of the block the value at `path` was written at, when fetched
+    /// with [`GetOptions::with_block_height`].
+    pub fn block_height(&self, path: &str) -> Option<u64> {
+        self.get(path)?.get(":block")?.as_u64()
+    }
+
+    /// Names of the children of the node at `path`.
+    pub fn children(&self, path: &str) -> impl Iterator<Item = &str> {
+        self.get(path)
+            .and_then(Value::as_object)
+            .into_iter()
+            .flat_map(|node| node.keys())
+            .filter(|key| !key.is_empty() && !key.starts_with(':'))
+            .map(String::as_str)
+    }
+}
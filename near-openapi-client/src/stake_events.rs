@@ -0,0 +1,257 @@
+//! Staking events derived from the chain, e.g. for staking providers
+//! watching their validators.
+//!
+//! Block headers carry the stake proposals of their block, and the
+//! `validators` method the set of each epoch. A [`StakeEventStream`] walks
+//! the blocks with a [`BlockStream`] and compares the set of each new epoch
+//! with the one before:
+//!
+//! ```rust,ignore
+//! let events = StakeEventStream::new(rpc_client, BlockId::BlockHeight(start), Finality::Final).into_stream();
+//! futures_util::pin_mut!(events);
+//! while let Some(event) = events.next().await {
+//!     match event? {
+//!         StakeEvent::Kicked { account_id, reason, .. } => println!("{} kicked: {:?}", account_id, reason),
+//!         event => println!("{:?}", event),
+//!     }
+//! }
+//! ```
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::time::Duration;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+
+use crate::block::BlockStream;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccountId, BlockId, CryptoHash, EpochId, ErrorWrapperForRpcBlockError,
+    ErrorWrapperForRpcValidatorError, Finality, NearToken, RpcBlockResponse, RpcValidatorRequest,
+    ValidatorKickoutReason,
+};
+
+/// Change of the stake of an account, yielded by [`StakeEventStream`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakeEvent {
+    /// The block at `height` includes a stake proposal of the account, to
+    /// take effect two epochs later.
+    ProposalSubmitted {
+        account_id: AccountId,
+        stake: NearToken,
+        height: u64,
+    },
+    /// The account validates epoch `epoch_height` but not the one before.
+    JoinedSet {
+        account_id: AccountId,
+        stake: NearToken,
+        epoch_height: u64,
+    },
+    /// The node reported the account as kicked out at the start of epoch
+    /// `epoch_height`.
+    Kicked {
+        account_id: AccountId,
+        reason: ValidatorKickoutReason,
+        epoch_height: u64,
+    },
+    /// The account validates epoch `epoch_height` with another stake than
+    /// the epoch before. A validator leaving the set without being kicked
+    /// out changes its stake to zero.
+    StakeChanged {
+        account_id: AccountId,
+        old_stake: NearToken,
+        new_stake: NearToken,
+        epoch_height: u64,
+    },
+}
+
+impl StakeEvent {
+    pub fn account_id(&self) -> &AccountId {
+        match self {
+            Self::ProposalSubmitted { account_id, .. }
+            | Self::JoinedSet { account_id, .. }
+            | Self::Kicked { account_id, .. }
+            | Self::StakeChanged { account_id, .. } => account_id,
+        }
+    }
+}
+
+/// Error of a [`StakeEventStream`]. The failed request is retried when the
+/// next event is polled.
+#[derive(Debug)]
+pub enum StakeEventError {
+    Block(RpcError<ErrorWrapperForRpcBlockError>),
+    Validators(RpcError<ErrorWrapperForRpcValidatorError>),
+}
+
+impl std::fmt::Display for StakeEventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(err) => write!(f, "failed to fetch block: {}", err),
+            Self::Validators(err) => write!(f, "failed to fetch validators: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for StakeEventError {}
+
+/// Stream of the [`StakeEvent`]s of the blocks from a starting block on. See
+/// the [module documentation](self).
+///
+/// Events of an epoch transition come before the proposals of the first
+/// block of the new epoch, ordered by account. The epoch of the starting
+/// block is the baseline and yields no transition events.
+#[derive(Clone, Debug)]
+pub struct StakeEventStream {
+    client: RpcClient,
+    blocks: BlockStream,
+}
+
+impl StakeEventStream {
+    /// Stream starting at `start`, inclusive, polling every second once it
+    /// caught up with the head at `finality`.
+    pub fn new(client: RpcClient, start: BlockId, finality: Finality) -> Self {
+        Self {
+            blocks: BlockStream::new(client.clone(), start, finality),
+            client,
+        }
+    }
+
+    /// Delay between polls for a new head once the stream caught up with it.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.blocks = self.blocks.poll_interval(poll_interval);
+        self
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = Result<StakeEvent, StakeEventError>> {
+        let watcher = Watcher {
+            client: self.client,
+            blocks: Box::pin(self.blocks.into_stream()),
+            block: None,
+            epoch: None,
+            events: VecDeque::new(),
+        };
+        futures_util::stream::unfold(watcher, |mut watcher| async move {
+            let event = watcher.next_event().await?;
+            Some((event, watcher))
+        })
+    }
+}
+
+/// Validators of an epoch by account.
+struct EpochSet {
+    epoch_id: CryptoHash,
+    stakes: BTreeMap<AccountId, NearToken>,
+}
+
+struct Watcher<S> {
+    client: RpcClient,
+    blocks: std::pin::Pin<Box<S>>,
+    /// Block whose events are not queued yet, kept while fetching the
+    /// validators of its epoch fails.
+    block: Option<RpcBlockResponse>,
+    /// `None` until the epoch of the starting block is fetched.
+    epoch: Option<EpochSet>,
+    events: VecDeque<StakeEvent>,
+}
+
+impl<S> Watcher<S>
+where
+    S: Stream<Item = Result<RpcBlockResponse, RpcError<ErrorWrapperForRpcBlockError>>>,
+{
+    async fn next_event(&mut self) -> Option<Result<StakeEvent, StakeEventError>> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            let block = match self.block.take() {
+                Some(block) => block,
+                None => match self.blocks.next().await? {
+                    Ok(block) => block,
+                    Err(err) => return Some(Err(StakeEventError::Block(err))),
+                },
+            };
+            let header = &block.header;
+            if self.epoch.as_ref().map(|epoch| &epoch.epoch_id) != Some(&header.epoch_id) {
+                let request = RpcValidatorRequest::EpochId(EpochId(header.epoch_id));
+                let validators = match self.client.validators(&request).await {
+                    Ok(validators) => validators,
+                    Err(err) => {
+                        self.block = Some(block);
+                        return Some(Err(StakeEventError::Validators(err)));
+                    }
+                };
+                let epoch = EpochSet {
+                    epoch_id: header.epoch_id,
+                    stakes: validators
+                        .current_validators
+                        .into_iter()
+                        .map(|validator| (validator.account_id, validator.stake))
+                        .collect(),
+                };
+                if let Some(previous) = self.epoch.take() {
+                    let kickouts: BTreeMap<_, _> = validators
+                        .prev_epoch_kickout
+                        .into_iter()
+                        .map(|kickout| (kickout.account_id, kickout.reason))
+                        .collect();
+                    self.queue_transition(&previous, &epoch, kickouts, validators.epoch_height);
+                }
+                self.epoch = Some(epoch);
+            }
+            self.events
+                .extend(header.validator_proposals.iter().map(|proposal| {
+                    StakeEvent::ProposalSubmitted {
+                        account_id: proposal.account_id.clone(),
+                        stake: proposal.stake,
+                        height: header.height,
+                    }
+                }));
+        }
+    }
+
+    fn queue_transition(
+        &mut self,
+        previous: &EpochSet,
+        epoch: &EpochSet,
+        mut kickouts: BTreeMap<AccountId, ValidatorKickoutReason>,
+        epoch_height: u64,
+    ) {
+        let accounts: BTreeSet<_> = previous
+            .stakes
+            .keys()
+            .chain(epoch.stakes.keys())
+            .chain(kickouts.keys())
+            .cloned()
+            .collect();
+        for account_id in accounts {
+            let old_stake = previous.stakes.get(&account_id).copied();
+            let new_stake = epoch.stakes.get(&account_id).copied();
+            if let Some(reason) = kickouts.remove(&account_id) {
+                self.events.push_back(StakeEvent::Kicked {
+                    account_id,
+                    reason,
+                    epoch_height,
+                });
+                continue;
+            }
+            let event = match (old_stake, new_stake) {
+                (None, Some(stake)) => StakeEvent::JoinedSet {
+                    account_id,
+                    stake,
+                    epoch_height,
+                },
+                (Some(old_stake), new_stake) if Some(old_stake) != new_stake => {
+                    StakeEvent::StakeChanged {
+                        account_id,
+                        old_stake,
+                        new_stake: new_stake.unwrap_or(NearToken::from_yoctonear(0)),
+                        epoch_height,
+                    }
+                }
+                _ => continue,
+            };
+            self.events.push_back(event);
+        }
+    }
+}
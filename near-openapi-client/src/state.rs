@@ -0,0 +1,157 @@
+//! Iterating over the contract state of an account, however large.
+//!
+//! Nodes refuse `view_state` queries matching more state than their
+//! configured limit with `TOO_LARGE_CONTRACT_STATE`. A [`StateIterator`]
+//! then splits the prefix it queried into the 256 prefixes one byte longer
+//! and queries those instead, so large states are read page by page. Pages
+//! are only fetched when the previous one is consumed:
+//!
+//! ```rust,ignore
+//! let mut state = rpc_client.view_state_iter(&account_id, b"", BlockReference::final_());
+//! while let Some(entry) = state.next().await {
+//!     let entry = entry?;
+//!     let balance: u128 = entry.value_borsh()?;
+//!     println!("{:?} = {}", entry.key, balance);
+//! }
+//! ```
+use futures_core::Stream;
+
+use crate::Error;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccountId, BlockId, BlockReference, CryptoHash, ErrorWrapperForRpcQueryError, Finality,
+    RpcQueryError, RpcQueryRequest, StoreKey, ViewStateByFinalityRequestType, ViewStateResult,
+};
+
+/// A key of the contract state with its value, decoded from base64.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StateEntry {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+impl StateEntry {
+    /// Decodes the value as borsh, the encoding of `near-sdk` collections.
+    pub fn value_borsh<T: borsh::BorshDeserialize>(&self) -> std::io::Result<T> {
+        borsh::from_slice(&self.value)
+    }
+}
+
+/// Contract state of an account under a prefix, yielded in key order. See
+/// the [module documentation](self).
+///
+/// Every page after the first is read at the block the first one was
+/// answered at. A key equal to a prefix that had to be split is not
+/// returned: no query can select it without the longer keys under it.
+#[derive(Debug)]
+pub struct StateIterator {
+    client: RpcClient,
+    account_id: AccountId,
+    block: BlockReference,
+    /// Prefixes left to query, the next one last.
+    prefixes: Vec<Vec<u8>>,
+    page: std::vec::IntoIter<StateEntry>,
+}
+
+/// A `view_state` result together with the block it was read at.
+#[derive(serde::Deserialize)]
+struct StatePage {
+    block_hash: CryptoHash,
+    #[serde(flatten)]
+    state: ViewStateResult,
+}
+
+impl RpcClient {
+    /// Iterator over the contract state of `account_id` at `block` under the
+    /// keys starting with `prefix`.
+    pub fn view_state_iter(
+        &self,
+        account_id: &AccountId,
+        prefix: &[u8],
+        block: BlockReference,
+    ) -> StateIterator {
+        StateIterator {
+            client: self.clone(),
+            account_id: account_id.clone(),
+            block,
+            prefixes: vec![prefix.to_vec()],
+            page: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl StateIterator {
+    /// The next entry, fetching the next page if the current one is
+    /// consumed, or `None` once every prefix was read. A failed query is
+    /// retried by the following call.
+    pub async fn next(
+        &mut self,
+    ) -> Option<Result<StateEntry, RpcError<ErrorWrapperForRpcQueryError>>> {
+        loop {
+            if let Some(entry) = self.page.next() {
+                return Some(Ok(entry));
+            }
+            let prefix = self.prefixes.pop()?;
+            match self.fetch(&prefix).await {
+                Ok(page) => self.page = page.into_iter(),
+                Err(RpcError::Rpc(ErrorWrapperForRpcQueryError::HandlerError(
+                    RpcQueryError::TooLargeContractState { .. },
+                ))) => {
+                    self.prefixes.extend((0..=u8::MAX).rev().map(|byte| {
+                        let mut longer = prefix.clone();
+                        longer.push(byte);
+                        longer
+                    }));
+                }
+                Err(err) => {
+                    self.prefixes.push(prefix);
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+
+    /// The entries as a stream, fetching each page once the previous one is
+    /// consumed. It ends after the last entry or the first error.
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<StateEntry, RpcError<ErrorWrapperForRpcQueryError>>> {
+        futures_util::stream::unfold(Some(self), |iterator| async move {
+            let mut iterator = iterator?;
+            let entry = iterator.next().await?;
+            let iterator = entry.is_ok().then_some(iterator);
+            Some((entry, iterator))
+        })
+    }
+
+    /// The entries under `prefix`, in key order, pinning the block the first
+    /// page is read at.
+    async fn fetch(
+        &mut self,
+        prefix: &[u8],
+    ) -> Result<Vec<StateEntry>, RpcError<ErrorWrapperForRpcQueryError>> {
+        let request = RpcQueryRequest::ViewStateByFinality {
+            account_id: self.account_id.clone(),
+            finality: Finality::Final,
+            include_proof: None,
+            prefix_base64: StoreKey::from_bytes(prefix),
+            request_type: ViewStateByFinalityRequestType::ViewState,
+        };
+        let page: StatePage = self
+            .client
+            .call("query", &request.with_block(self.block.clone()))
+            .await?;
+        self.block = BlockReference::BlockId(BlockId::CryptoHash(page.block_hash));
+        let state = page.state.to_map().map_err(|err| {
+            RpcError::Client(Error::Custom(format!(
+                "invalid contract state in view_state response: {}",
+                err
+            )))
+        })?;
+        Ok(state
+            .into_iter()
+            .map(|(key, value)| StateEntry { key, value })
+            .collect())
+    }
+}
@@ -0,0 +1,594 @@
+//! Querying state changes with a single request type and a single response
+//! type, instead of the 21 generated `*ChangesBy*` request variants and the
+//! numbered variants of `StateChangeWithCauseView`.
+//!
+//! ```rust,ignore
+//! let request = StateChangesRequest::new(
+//!     BlockReference::final_(),
+//!     ChangesType::DataChanges {
+//!         account_ids: vec!["alice.near".parse()?],
+//!         key_prefix: StoreKey::from_bytes(b"STATE"),
+//!     },
+//! );
+//! for change in rpc_client.state_changes(&request).await?.changes {
+//!     if let StateChange::DataUpdate { key, value, .. } = change.change {
+//!         println!("{:?} = {:?}", key, value);
+//!     }
+//! }
+//! ```
+//!
+//! [`RpcClient::changes_in_block`] fetches all changes of a block, grouped by
+//! account and kind, e.g. to index every block:
+//!
+//! ```rust,ignore
+//! let block = rpc_client.changes_in_block(BlockReference::final_()).await?;
+//! for (account_id, kind, changes) in block.iter() {
+//!     println!("{} {:?}: {} changes", account_id, kind, changes.len());
+//! }
+//! ```
+//!
+//! [`RpcClient::stream_changes`] follows the changes of a kind block after
+//! block, so an indexer can watch a few accounts without running a full
+//! indexer framework:
+//!
+//! ```rust,ignore
+//! let changes = rpc_client.stream_changes(
+//!     ChangesType::AccountChanges { account_ids: vec!["alice.near".parse()?] },
+//!     BlockId::BlockHeight(start),
+//! );
+//! futures_util::pin_mut!(changes);
+//! while let Some(event) = changes.next().await {
+//!     let event = event?;
+//!     println!("{} {:?}", event.block_height, event.change.change);
+//! }
+//! ```
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use std::time::Duration;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+
+use crate::block::BlockStream;
+use crate::jsonrpc::RpcError;
+use crate::methods::{self, RpcMethod};
+use crate::rpc::RpcClient;
+use crate::types::state_changes::StateChangesRequestBuilder;
+use crate::types::{
+    AccessKeyView, AccountId, AccountView, AccountWithPublicKey, BlockHeaderView, BlockId,
+    BlockReference, CryptoHash, ErrorWrapperForRpcBlockError, ErrorWrapperForRpcStateChangesError,
+    Finality, GasKey, PublicKey, RpcStateChangesInBlockByTypeRequest,
+    RpcStateChangesInBlockRequest, StateChangeCauseView, StateChangeKindView, StoreKey, StoreValue,
+};
+
+/// Heights back from the latest block whose changes a [`StateChangesStream`]
+/// remembers, deeper than the forks of blocks that are not final yet.
+const REORG_DEPTH: u64 = 16;
+
+/// Kind of changes to query, with the accounts or keys they are about.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangesType {
+    /// Changes of the accounts themselves (balance, storage, code hash).
+    AccountChanges { account_ids: Vec<AccountId> },
+    /// Changes of specific access keys.
+    SingleAccessKeyChanges { keys: Vec<AccountWithPublicKey> },
+    /// Changes of all access keys of the accounts.
+    AllAccessKeyChanges { account_ids: Vec<AccountId> },
+    /// Changes of specific gas keys.
+    SingleGasKeyChanges { keys: Vec<AccountWithPublicKey> },
+    /// Changes of all gas keys of the accounts.
+    AllGasKeyChanges { account_ids: Vec<AccountId> },
+    /// Contract code deployments of the accounts.
+    ContractCodeChanges { account_ids: Vec<AccountId> },
+    /// Contract storage changes of the accounts under keys starting with
+    /// `key_prefix`; an empty prefix matches every key.
+    DataChanges {
+        account_ids: Vec<AccountId>,
+        key_prefix: StoreKey,
+    },
+}
+
+impl ChangesType {
+    /// Whether the changes of a block may include changes of this kind,
+    /// given the kinds of changes of the block by account. Gas keys have no
+    /// kind of their own, so they may always have changed.
+    fn touched_by(&self, kinds: &[StateChangeKindView]) -> bool {
+        let touched = |account_id: &AccountId| {
+            kinds.iter().any(|kind| match (self, kind) {
+                (Self::AccountChanges { .. }, StateChangeKindView::AccountTouched(touched))
+                | (
+                    Self::SingleAccessKeyChanges { .. } | Self::AllAccessKeyChanges { .. },
+                    StateChangeKindView::AccessKeyTouched(touched),
+                )
+                | (
+                    Self::ContractCodeChanges { .. },
+                    StateChangeKindView::ContractCodeTouched(touched),
+                )
+                | (Self::DataChanges { .. }, StateChangeKindView::DataTouched(touched)) => {
+                    touched == account_id
+                }
+                _ => false,
+            })
+        };
+        match self {
+            Self::SingleAccessKeyChanges { keys } => {
+                keys.iter().any(|key| touched(&key.account_id))
+            }
+            Self::SingleGasKeyChanges { .. } | Self::AllGasKeyChanges { .. } => true,
+            Self::AccountChanges { account_ids }
+            | Self::AllAccessKeyChanges { account_ids }
+            | Self::ContractCodeChanges { account_ids }
+            | Self::DataChanges { account_ids, .. } => account_ids.iter().any(touched),
+        }
+    }
+}
+
+/// Request of [`RpcClient::state_changes`]: the changes of a kind in a block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateChangesRequest {
+    pub block_reference: BlockReference,
+    pub changes_type: ChangesType,
+}
+
+impl StateChangesRequest {
+    pub fn new(block_reference: BlockReference, changes_type: ChangesType) -> Self {
+        Self {
+            block_reference,
+            changes_type,
+        }
+    }
+}
+
+impl From<StateChangesRequest> for RpcStateChangesInBlockByTypeRequest {
+    fn from(value: StateChangesRequest) -> Self {
+        let builder = StateChangesRequestBuilder::new().block_reference(value.block_reference);
+        match value.changes_type {
+            ChangesType::AccountChanges { account_ids } => builder.account_changes(account_ids),
+            ChangesType::SingleAccessKeyChanges { keys } => builder.single_access_key_changes(keys),
+            ChangesType::AllAccessKeyChanges { account_ids } => {
+                builder.all_access_key_changes(account_ids)
+            }
+            ChangesType::SingleGasKeyChanges { keys } => builder.single_gas_key_changes(keys),
+            ChangesType::AllGasKeyChanges { account_ids } => {
+                builder.all_gas_key_changes(account_ids)
+            }
+            ChangesType::ContractCodeChanges { account_ids } => {
+                builder.contract_code_changes(account_ids)
+            }
+            ChangesType::DataChanges {
+                account_ids,
+                key_prefix,
+            } => builder.data_changes(account_ids, key_prefix),
+        }
+    }
+}
+
+/// Response of [`RpcClient::state_changes`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct StateChanges {
+    pub block_hash: CryptoHash,
+    pub changes: Vec<StateChangeWithCause>,
+}
+
+/// A change and what caused it.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct StateChangeWithCause {
+    pub cause: StateChangeCauseView,
+    #[serde(flatten)]
+    pub change: StateChange,
+}
+
+/// A change of the state of an account, by kind.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", content = "change", rename_all = "snake_case")]
+pub enum StateChange {
+    AccountUpdate {
+        account_id: AccountId,
+        /// The account after the change.
+        #[serde(flatten)]
+        account: AccountView,
+    },
+    AccountDeletion {
+        account_id: AccountId,
+    },
+    AccessKeyUpdate {
+        account_id: AccountId,
+        public_key: PublicKey,
+        access_key: AccessKeyView,
+    },
+    AccessKeyDeletion {
+        account_id: AccountId,
+        public_key: PublicKey,
+    },
+    GasKeyUpdate {
+        account_id: AccountId,
+        public_key: PublicKey,
+        gas_key: GasKey,
+    },
+    GasKeyNonceUpdate {
+        account_id: AccountId,
+        public_key: PublicKey,
+        index: u32,
+        nonce: u64,
+    },
+    GasKeyDeletion {
+        account_id: AccountId,
+        public_key: PublicKey,
+    },
+    DataUpdate {
+        account_id: AccountId,
+        #[serde(rename = "key_base64")]
+        key: StoreKey,
+        #[serde(rename = "value_base64")]
+        value: StoreValue,
+    },
+    DataDeletion {
+        account_id: AccountId,
+        #[serde(rename = "key_base64")]
+        key: StoreKey,
+    },
+    ContractCodeUpdate {
+        account_id: AccountId,
+        /// The deployed code, base64-encoded.
+        #[serde(rename = "code_base64")]
+        code: String,
+    },
+    ContractCodeDeletion {
+        account_id: AccountId,
+    },
+}
+
+impl StateChange {
+    /// The account whose state changed.
+    pub fn account_id(&self) -> &AccountId {
+        match self {
+            Self::AccountUpdate { account_id, .. }
+            | Self::AccountDeletion { account_id }
+            | Self::AccessKeyUpdate { account_id, .. }
+            | Self::AccessKeyDeletion { account_id, .. }
+            | Self::GasKeyUpdate { account_id, .. }
+            | Self::GasKeyNonceUpdate { account_id, .. }
+            | Self::GasKeyDeletion { account_id, .. }
+            | Self::DataUpdate { account_id, .. }
+            | Self::DataDeletion { account_id, .. }
+            | Self::ContractCodeUpdate { account_id, .. }
+            | Self::ContractCodeDeletion { account_id } => account_id,
+        }
+    }
+
+    pub fn kind(&self) -> StateChangeKind {
+        match self {
+            Self::AccountUpdate { .. } | Self::AccountDeletion { .. } => StateChangeKind::Account,
+            Self::AccessKeyUpdate { .. } | Self::AccessKeyDeletion { .. } => {
+                StateChangeKind::AccessKey
+            }
+            Self::GasKeyUpdate { .. }
+            | Self::GasKeyNonceUpdate { .. }
+            | Self::GasKeyDeletion { .. } => StateChangeKind::GasKey,
+            Self::DataUpdate { .. } | Self::DataDeletion { .. } => StateChangeKind::Data,
+            Self::ContractCodeUpdate { .. } | Self::ContractCodeDeletion { .. } => {
+                StateChangeKind::ContractCode
+            }
+        }
+    }
+}
+
+/// Kind of a [`StateChange`], the part of the state of an account it is
+/// about.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StateChangeKind {
+    Account,
+    AccessKey,
+    GasKey,
+    Data,
+    ContractCode,
+}
+
+/// All changes of a block, returned by [`RpcClient::changes_in_block`].
+#[derive(Clone, Debug, Default)]
+pub struct BlockChanges {
+    pub block_hash: CryptoHash,
+    /// Changes by account and kind, in the order of the block within each.
+    pub changes: BTreeMap<AccountId, BTreeMap<StateChangeKind, Vec<StateChangeWithCause>>>,
+}
+
+impl BlockChanges {
+    /// Groups `changes` of the block `block_hash` by account and kind.
+    pub fn new(block_hash: CryptoHash, changes: Vec<StateChangeWithCause>) -> Self {
+        let mut grouped: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
+        for change in changes {
+            grouped
+                .entry(change.change.account_id().clone())
+                .or_default()
+                .entry(change.change.kind())
+                .or_default()
+                .push(change);
+        }
+        Self {
+            block_hash,
+            changes: grouped,
+        }
+    }
+
+    /// Accounts with changes in the block.
+    pub fn accounts(&self) -> impl Iterator<Item = &AccountId> {
+        self.changes.keys()
+    }
+
+    /// Changes of `kind` of `account_id`, empty if there are none.
+    pub fn of(&self, account_id: &AccountId, kind: StateChangeKind) -> &[StateChangeWithCause] {
+        self.changes
+            .get(account_id)
+            .and_then(|kinds| kinds.get(&kind))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Changes by account and kind, accounts in order.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&AccountId, StateChangeKind, &[StateChangeWithCause])> {
+        self.changes.iter().flat_map(|(account_id, kinds)| {
+            kinds
+                .iter()
+                .map(move |(kind, changes)| (account_id, *kind, changes.as_slice()))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl RpcClient {
+    /// The changes of `request` with the `changes` method.
+    pub async fn state_changes(
+        &self,
+        request: &StateChangesRequest,
+    ) -> Result<StateChanges, RpcError<ErrorWrapperForRpcStateChangesError>> {
+        let params = RpcStateChangesInBlockByTypeRequest::from(request.clone());
+        self.call(methods::Changes::NAME, &params).await
+    }
+
+    /// All changes of the block `block_reference` references. The accounts
+    /// touched by the block are read with `EXPERIMENTAL_changes_in_block`,
+    /// then their changes are fetched with `changes`, one request per kind,
+    /// all at the hash of the block so they agree on the block. Gas keys
+    /// have no kind of their own, so the gas keys of every touched account
+    /// are fetched.
+    pub async fn changes_in_block(
+        &self,
+        block_reference: BlockReference,
+    ) -> Result<BlockChanges, RpcError<ErrorWrapperForRpcStateChangesError>> {
+        let request = match block_reference {
+            BlockReference::BlockId(block_id) => RpcStateChangesInBlockRequest::BlockId(block_id),
+            BlockReference::Finality(finality) => RpcStateChangesInBlockRequest::Finality(finality),
+            BlockReference::SyncCheckpoint(checkpoint) => {
+                RpcStateChangesInBlockRequest::SyncCheckpoint(checkpoint)
+            }
+        };
+        let touched = self.experimental_changes_in_block(&request).await?;
+        let block_hash = touched.block_hash;
+
+        let mut accounts: BTreeMap<StateChangeKind, BTreeSet<AccountId>> = BTreeMap::new();
+        for kind in touched.changes {
+            let (kind, account_id) = match kind {
+                StateChangeKindView::AccountTouched(account_id) => {
+                    (StateChangeKind::Account, account_id)
+                }
+                StateChangeKindView::AccessKeyTouched(account_id) => {
+                    (StateChangeKind::AccessKey, account_id)
+                }
+                StateChangeKindView::DataTouched(account_id) => (StateChangeKind::Data, account_id),
+                StateChangeKindView::ContractCodeTouched(account_id) => {
+                    (StateChangeKind::ContractCode, account_id)
+                }
+            };
+            accounts
+                .entry(StateChangeKind::GasKey)
+                .or_default()
+                .insert(account_id.clone());
+            accounts.entry(kind).or_default().insert(account_id);
+        }
+
+        let mut changes = Vec::new();
+        for (kind, account_ids) in accounts {
+            let account_ids = account_ids.into_iter().collect();
+            let changes_type = match kind {
+                StateChangeKind::Account => ChangesType::AccountChanges { account_ids },
+                StateChangeKind::AccessKey => ChangesType::AllAccessKeyChanges { account_ids },
+                StateChangeKind::GasKey => ChangesType::AllGasKeyChanges { account_ids },
+                StateChangeKind::Data => ChangesType::DataChanges {
+                    account_ids,
+                    key_prefix: StoreKey::from_bytes(b""),
+                },
+                StateChangeKind::ContractCode => ChangesType::ContractCodeChanges { account_ids },
+            };
+            let request = StateChangesRequest::new(
+                BlockReference::BlockId(BlockId::CryptoHash(block_hash)),
+                changes_type,
+            );
+            changes.extend(self.state_changes(&request).await?.changes);
+        }
+        Ok(BlockChanges::new(block_hash, changes))
+    }
+
+    /// The changes of `changes_type` in the final blocks from `from_block`
+    /// on, see [`StateChangesStream`].
+    pub fn stream_changes(
+        &self,
+        changes_type: ChangesType,
+        from_block: BlockId,
+    ) -> impl Stream<Item = Result<StateChangeEvent, StateChangesStreamError>> + use<> {
+        StateChangesStream::new(self.clone(), changes_type, from_block, Finality::Final)
+            .into_stream()
+    }
+}
+
+/// A change yielded by [`StateChangesStream`], with the block it is in.
+#[derive(Clone, Debug)]
+pub struct StateChangeEvent {
+    pub block_height: u64,
+    pub block_hash: CryptoHash,
+    pub change: StateChangeWithCause,
+}
+
+/// Error of a [`StateChangesStream`]. The failed request is retried when the
+/// next change is polled.
+#[derive(Debug)]
+pub enum StateChangesStreamError {
+    Block(RpcError<ErrorWrapperForRpcBlockError>),
+    Changes(RpcError<ErrorWrapperForRpcStateChangesError>),
+}
+
+impl std::fmt::Display for StateChangesStreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(err) => write!(f, "failed to fetch block: {}", err),
+            Self::Changes(err) => write!(f, "failed to fetch changes: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for StateChangesStreamError {}
+
+/// Stream of the changes of a kind in the blocks from a starting block on.
+///
+/// For every block, `EXPERIMENTAL_changes_in_block` tells whether the
+/// accounts of the [`ChangesType`] changed, and only then are the changes
+/// fetched with `changes`. Changes are yielded in the order of their blocks.
+///
+/// Blocks that are not final yet may be replaced by another fork, which
+/// can include the same changes again at another height. A change already
+/// yielded for one of the last 16 heights is not yielded again, so the
+/// stream can follow the head at `Finality::Optimistic` with each change once.
+#[derive(Clone, Debug)]
+pub struct StateChangesStream {
+    client: RpcClient,
+    blocks: BlockStream,
+    changes_type: ChangesType,
+}
+
+impl StateChangesStream {
+    /// Stream starting at `start`, inclusive, polling every second once it
+    /// caught up with the head at `finality`.
+    pub fn new(
+        client: RpcClient,
+        changes_type: ChangesType,
+        start: BlockId,
+        finality: Finality,
+    ) -> Self {
+        Self {
+            blocks: BlockStream::new(client.clone(), start, finality),
+            client,
+            changes_type,
+        }
+    }
+
+    /// Delay between polls for a new head once the stream caught up with it.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.blocks = self.blocks.poll_interval(poll_interval);
+        self
+    }
+
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<StateChangeEvent, StateChangesStreamError>> {
+        let follower = Follower {
+            client: self.client,
+            changes_type: self.changes_type,
+            blocks: Box::pin(self.blocks.into_header_stream()),
+            header: None,
+            recent: VecDeque::new(),
+            events: VecDeque::new(),
+        };
+        futures_util::stream::unfold(follower, |mut follower| async move {
+            let event = follower.next_event().await?;
+            Some((event, follower))
+        })
+    }
+}
+
+struct Follower<S> {
+    client: RpcClient,
+    changes_type: ChangesType,
+    blocks: std::pin::Pin<Box<S>>,
+    /// Block whose changes are not queued yet, kept while fetching them
+    /// fails.
+    header: Option<BlockHeaderView>,
+    /// Heights of the latest blocks with the JSON of the changes yielded
+    /// for them.
+    recent: VecDeque<(u64, HashSet<String>)>,
+    events: VecDeque<StateChangeEvent>,
+}
+
+impl<S> Follower<S>
+where
+    S: Stream<Item = Result<BlockHeaderView, RpcError<ErrorWrapperForRpcBlockError>>>,
+{
+    async fn next_event(&mut self) -> Option<Result<StateChangeEvent, StateChangesStreamError>> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            let header = match self.header.take() {
+                Some(header) => header,
+                None => match self.blocks.next().await? {
+                    Ok(header) => header,
+                    Err(err) => return Some(Err(StateChangesStreamError::Block(err))),
+                },
+            };
+            let changes = match self.changes(&header).await {
+                Ok(changes) => changes,
+                Err(err) => {
+                    self.header = Some(header);
+                    return Some(Err(StateChangesStreamError::Changes(err)));
+                }
+            };
+            self.queue(&header, changes);
+        }
+    }
+
+    /// Changes of the block of `header`, none when the changes of the block
+    /// don't touch the accounts.
+    async fn changes(
+        &self,
+        header: &BlockHeaderView,
+    ) -> Result<Vec<StateChangeWithCause>, RpcError<ErrorWrapperForRpcStateChangesError>> {
+        let block_id = BlockId::CryptoHash(header.hash);
+        let kinds = self
+            .client
+            .experimental_changes_in_block(&RpcStateChangesInBlockRequest::BlockId(
+                block_id.clone(),
+            ))
+            .await?;
+        if !self.changes_type.touched_by(&kinds.changes) {
+            return Ok(Vec::new());
+        }
+        let request =
+            StateChangesRequest::new(BlockReference::BlockId(block_id), self.changes_type.clone());
+        Ok(self.client.state_changes(&request).await?.changes)
+    }
+
+    fn queue(&mut self, header: &BlockHeaderView, changes: Vec<StateChangeWithCause>) {
+        while self
+            .recent
+            .front()
+            .is_some_and(|(height, _)| height + REORG_DEPTH <= header.height)
+        {
+            self.recent.pop_front();
+        }
+        let mut yielded = HashSet::new();
+        for change in changes {
+            let key = serde_json::to_string(&change).expect("change serializes to JSON");
+            let seen = self.recent.iter().any(|(_, keys)| keys.contains(&key));
+            if seen || !yielded.insert(key) {
+                continue;
+            }
+            self.events.push_back(StateChangeEvent {
+                block_height: header.height,
+                block_hash: header.hash,
+                change,
+            });
+        }
+        self.recent.push_back((header.height, yielded));
+    }
+}
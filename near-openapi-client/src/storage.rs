@@ -0,0 +1,127 @@
+//! Sampling of account storage usage across historical blocks.
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types;
+
+/// Storage staking price on mainnet and testnet: 10^19 yoctoNEAR (1 NEAR per 100 kB).
+pub const DEFAULT_STORAGE_AMOUNT_PER_BYTE: types::NearToken =
+    types::NearToken::from_yoctonear(10_000_000_000_000_000_000);
+
+/// Storage usage of an account at a single block.
+#[derive(Clone, Debug)]
+pub struct StorageUsagePoint {
+    pub block_height: u64,
+    pub block_hash: types::CryptoHash,
+    /// Bytes of state occupied by the account.
+    pub storage_usage: u64,
+    /// Balance locked to pay for `storage_usage`.
+    pub locked_for_storage: types::NearToken,
+    /// Whether the point had to be fetched from the archival node.
+    pub from_archival: bool,
+}
+
+/// Storage usage samples of an account, ordered as the requested blocks.
+#[derive(Clone, Debug)]
+pub struct StorageUsageSeries {
+    pub account_id: types::AccountId,
+    pub points: Vec<StorageUsagePoint>,
+}
+
+impl StorageUsageSeries {
+    /// Difference in bytes between the last and the first sample.
+    pub fn growth(&self) -> i128 {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => last.storage_usage as i128 - first.storage_usage as i128,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StorageUsageError {
+    /// The `view_account` query failed.
+    Query(RpcError<types::ErrorWrapperForRpcQueryError>),
+    /// The node returned something other than an account view.
+    UnexpectedResponse,
+}
+
+impl std::fmt::Display for StorageUsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Query(err) => write!(f, "failed to query account: {}", err),
+            Self::UnexpectedResponse => write!(f, "unexpected response to view_account query"),
+        }
+    }
+}
+
+impl std::error::Error for StorageUsageError {}
+
+/// Samples `AccountView::storage_usage` of `account_id` at each of `blocks`.
+///
+/// Blocks that `client` has already garbage collected are re-queried against
+/// `archival`, if given. `storage_amount_per_byte` is usually
+/// [`DEFAULT_STORAGE_AMOUNT_PER_BYTE`] or the value from the protocol config.
+pub async fn storage_usage_series(
+    client: &RpcClient,
+    archival: Option<&RpcClient>,
+    account_id: &types::AccountId,
+    blocks: &[types::BlockId],
+    storage_amount_per_byte: types::NearToken,
+) -> Result<StorageUsageSeries, StorageUsageError> {
+    let mut points = Vec::with_capacity(blocks.len());
+    for block_id in blocks {
+        let mut result = view_account(client, account_id, block_id).await;
+        let mut from_archival = false;
+        if let (Err(err), Some(archival)) = (&result, archival)
+            && is_missing_block(err)
+        {
+            result = view_account(archival, account_id, block_id).await;
+            from_archival = true;
+        }
+        let result = result.map_err(StorageUsageError::Query)?;
+        let types::RpcQueryResponse::Variant0 {
+            block_hash,
+            block_height,
+            storage_usage,
+            ..
+        } = result
+        else {
+            return Err(StorageUsageError::UnexpectedResponse);
+        };
+        points.push(StorageUsagePoint {
+            block_height,
+            block_hash,
+            storage_usage,
+            locked_for_storage: storage_amount_per_byte.saturating_mul(storage_usage as u128),
+            from_archival,
+        });
+    }
+    Ok(StorageUsageSeries {
+        account_id: account_id.clone(),
+        points,
+    })
+}
+
+async fn view_account(
+    client: &RpcClient,
+    account_id: &types::AccountId,
+    block_id: &types::BlockId,
+) -> Result<types::RpcQueryResponse, RpcError<types::ErrorWrapperForRpcQueryError>> {
+    client
+        .query(&types::RpcQueryRequest::ViewAccountByBlockId {
+            account_id: account_id.clone(),
+            block_id: block_id.clone(),
+            request_type: types::ViewAccountByBlockIdRequestType::ViewAccount,
+        })
+        .await
+}
+
+fn is_missing_block(error: &RpcError<types::ErrorWrapperForRpcQueryError>) -> bool {
+    matches!(
+        error,
+        RpcError::Rpc(types::ErrorWrapperForRpcQueryError::HandlerError(
+            types::RpcQueryError::GarbageCollectedBlock { .. }
+                | types::RpcQueryError::UnknownBlock { .. }
+        ))
+    )
+}
@@ -0,0 +1,298 @@
+//! A local JSON-RPC server answering with typed responses, to test code built
+//! on [`RpcClient`] without a node or hand-written JSON fixtures.
+//!
+//! Responses are registered per method with [`MockRpc::expect`]. Each request
+//! is answered by the first expectation of its method whose params match and
+//! that has calls left. Dropping the [`MockRpc`] panics if an expectation was
+//! not called as often as expected or a request matched none of them.
+//!
+//! ```rust,ignore
+//! let mock = MockRpc::start();
+//! mock.expect::<methods::GasPrice>()
+//!     .with_params(&RpcGasPriceRequest { block_id: None })
+//!     .return_ok(RpcGasPriceResponse { gas_price: NearToken::from_yoctonear(100_000_000) });
+//! let response = mock.client().gas_price(&RpcGasPriceRequest { block_id: None }).await?;
+//! ```
+use std::io::{BufRead, BufReader, Read, Write};
+use std::marker::PhantomData;
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::methods::RpcMethod;
+use crate::rpc::RpcClient;
+
+/// Local JSON-RPC server, stopped when dropped.
+#[derive(Debug)]
+pub struct MockRpc {
+    url: String,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    expectations: Vec<Expected>,
+    received: Vec<(String, Value)>,
+    unexpected: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Expected {
+    method: &'static str,
+    params: Option<Value>,
+    times: usize,
+    calls: usize,
+    reply: Reply,
+}
+
+#[derive(Clone, Debug)]
+enum Reply {
+    Result(Value),
+    Error(Value),
+    Status(u16),
+}
+
+impl MockRpc {
+    /// Binds a server on a free local port.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind the mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let (server_state, server_stopped) = (state.clone(), stopped.clone());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if server_stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let state = server_state.clone();
+                    std::thread::spawn(move || serve(stream, &state));
+                }
+            }
+        });
+        Self {
+            url,
+            state,
+            stopped,
+        }
+    }
+
+    /// URL of the server.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A client sending its requests to the server.
+    pub fn client(&self) -> RpcClient {
+        RpcClient::new(&self.url)
+    }
+
+    /// Expects one call of `M`, with any params unless set.
+    pub fn expect<M: RpcMethod>(&self) -> Expectation<'_, M> {
+        Expectation {
+            mock: self,
+            params: None,
+            times: 1,
+            method: PhantomData,
+        }
+    }
+
+    /// Method and params of every request received so far, in order.
+    pub fn received(&self) -> Vec<(String, Value)> {
+        self.state.lock().unwrap().received.clone()
+    }
+}
+
+impl Drop for MockRpc {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wakes the server up so it sees that it is stopped.
+        let _ = TcpStream::connect(self.url.trim_start_matches("http://"));
+        if std::thread::panicking() {
+            return;
+        }
+        let state = self.state.lock().unwrap();
+        let mut failures = state.unexpected.clone();
+        for expected in &state.expectations {
+            if expected.calls != expected.times {
+                failures.push(format!(
+                    "expected {} call(s) of {}{}, got {}",
+                    expected.times,
+                    expected.method,
+                    expected
+                        .params
+                        .as_ref()
+                        .map_or_else(String::new, |params| format!(" with {}", params)),
+                    expected.calls
+                ));
+            }
+        }
+        if !failures.is_empty() {
+            panic!("unmet mock expectations:\n{}", failures.join("\n"));
+        }
+    }
+}
+
+/// Calls of a method expected by a [`MockRpc`], registered by one of the
+/// `return_*` methods.
+#[must_use = "expectations are only registered by a `return_*` method"]
+pub struct Expectation<'a, M> {
+    mock: &'a MockRpc,
+    params: Option<Value>,
+    times: usize,
+    method: PhantomData<M>,
+}
+
+impl<M: RpcMethod> Expectation<'_, M> {
+    /// Only matches calls with these params.
+    pub fn with_params(mut self, params: &M::Params) -> Self {
+        self.params = Some(serde_json::to_value(params).expect("params encode to JSON"));
+        self
+    }
+
+    /// Expects `times` calls instead of one.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times;
+        self
+    }
+
+    /// Answers with `response` as the `result`.
+    pub fn return_ok(self, response: M::Response)
+    where
+        M::Response: Serialize,
+    {
+        let response = serde_json::to_value(response).expect("responses encode to JSON");
+        self.register(Reply::Result(response));
+    }
+
+    /// Answers with `error` as the JSON-RPC `error`.
+    pub fn return_error(self, error: M::Error)
+    where
+        M::Error: Serialize,
+    {
+        let error = serde_json::to_value(error).expect("errors encode to JSON");
+        self.register(Reply::Error(error));
+    }
+
+    /// Answers with an empty response with the HTTP `status`, e.g. 503.
+    pub fn return_status(self, status: u16) {
+        self.register(Reply::Status(status));
+    }
+
+    fn register(self, reply: Reply) {
+        self.mock.state.lock().unwrap().expectations.push(Expected {
+            method: M::NAME,
+            params: self.params,
+            times: self.times,
+            calls: 0,
+            reply,
+        });
+    }
+}
+
+/// Answers the request on `stream`.
+fn serve(mut stream: TcpStream, state: &Mutex<State>) {
+    let Some(body) = read_body(&stream) else {
+        return;
+    };
+    let (status, body) = match serde_json::from_slice::<Value>(&body) {
+        Ok(Value::Array(requests)) => {
+            let replies: Vec<_> = requests
+                .iter()
+                .map(|request| reply(request, state))
+                .collect();
+            match replies
+                .iter()
+                .find_map(|(status, _)| (*status != 200).then_some(*status))
+            {
+                Some(status) => (status, String::new()),
+                None => (
+                    200,
+                    Value::from_iter(replies.into_iter().map(|(_, body)| body)).to_string(),
+                ),
+            }
+        }
+        Ok(request) => {
+            let (status, body) = reply(&request, state);
+            (
+                status,
+                if status == 200 {
+                    body.to_string()
+                } else {
+                    String::new()
+                },
+            )
+        }
+        Err(_) => (400, String::new()),
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+}
+
+/// Status and JSON-RPC response of a single request.
+fn reply(request: &Value, state: &Mutex<State>) -> (u16, Value) {
+    let method = request["method"].as_str().unwrap_or_default().to_string();
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let mut state = state.lock().unwrap();
+    state.received.push((method.clone(), params.clone()));
+    let expected = state.expectations.iter_mut().find(|expected| {
+        expected.method == method
+            && expected.calls < expected.times
+            && expected
+                .params
+                .as_ref()
+                .is_none_or(|expected| *expected == params)
+    });
+    let Some(expected) = expected else {
+        state
+            .unexpected
+            .push(format!("unexpected call of {} with {}", method, params));
+        return (500, Value::Null);
+    };
+    expected.calls += 1;
+    let member = match expected.reply.clone() {
+        Reply::Result(result) => ("result", result),
+        Reply::Error(error) => ("error", error),
+        Reply::Status(status) => return (status, Value::Null),
+    };
+    let mut response = serde_json::Map::new();
+    response.insert("jsonrpc".to_string(), "2.0".into());
+    response.insert("id".to_string(), id);
+    response.insert(member.0.to_string(), member.1);
+    (200, Value::Object(response))
+}
+
+/// Reads the body of the HTTP request on `stream`.
+fn read_body(stream: &TcpStream) -> Option<Vec<u8>> {
+    let mut reader = BufReader::new(stream);
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().ok()?;
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+    Some(body)
+}
@@ -0,0 +1,32 @@
+//! Clock and timer working natively, on tokio, and on wasm32 in browsers,
+//! where `std::time::Instant` panics and tokio has no timer.
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("near-openapi-client needs the `wasm` feature on wasm32 targets");
+
+/// Waits for `duration` without blocking the thread.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Runs `future` for at most `duration`, returning `None` if it takes longer.
+pub(crate) async fn timeout<F: std::future::Future>(
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    let future = std::pin::pin!(future);
+    let sleep = std::pin::pin!(sleep(duration));
+    match futures_util::future::select(future, sleep).await {
+        futures_util::future::Either::Left((output, _)) => Some(output),
+        futures_util::future::Either::Right(_) => None,
+    }
+}
@@ -0,0 +1,121 @@
+//! Spans of JSON-RPC calls, recorded with the `tracing` feature.
+//!
+//! Every call of [`RpcClient`](crate::rpc::RpcClient) runs in an `rpc_call`
+//! span with the fields:
+//! - `method`, e.g. `block`, or `batch` for batches
+//! - `request_id`: the JSON-RPC id of the call, if the client sends calls
+//!   with ids, see [`request_id`](crate::request_id)
+//! - `block_reference`: the `block_id`, `finality` or `sync_checkpoint` of the
+//!   params, if any
+//! - `latency_ms`, including retries
+//! - `attempts`
+//! - `error.name` and `error.cause` of a JSON-RPC error, e.g. `HANDLER_ERROR`
+//!   and `UNKNOWN_BLOCK`, or `http.status` of a non-200 response, for the
+//!   last attempt
+//! - `error.chain`: the causes of the error of a failed call, joined with
+//!   `: `, if enabled with
+//!   [`RequestIds::log_error_causes`](crate::request_id::RequestIds::log_error_causes)
+//!
+//! Retries are logged as `DEBUG` events and failed calls as `WARN` events in
+//! the span.
+use std::convert::Infallible;
+use std::future::Future;
+use std::time::Duration;
+
+use tracing::Instrument;
+use tracing::field::Empty;
+
+use crate::jsonrpc::RpcError;
+use crate::request_id::{self, RequestId};
+use crate::retry::FailedAttempt;
+use crate::time::Instant;
+
+/// Keys of the params holding the block the call is about.
+const BLOCK_REFERENCE_KEYS: &[&str] = &["block_id", "finality", "sync_checkpoint"];
+
+/// Block reference of `params`, rendered as its JSON value without quotes.
+pub(crate) fn block_reference<P: serde::Serialize>(params: &P) -> Option<String> {
+    let params = serde_json::to_value(params).ok()?;
+    BLOCK_REFERENCE_KEYS
+        .iter()
+        .find_map(|key| params.get(key))
+        .map(|value| match value {
+            serde_json::Value::String(value) => value.clone(),
+            value => value.to_string(),
+        })
+}
+
+/// Runs `call` in the span of `method`.
+pub(crate) async fn instrument<F, T, E>(
+    method: &str,
+    block_reference: Option<String>,
+    request_id: Option<&RequestId>,
+    call: F,
+) -> Result<T, RpcError<E>>
+where
+    F: Future<Output = Result<T, RpcError<E>>>,
+{
+    let span = tracing::info_span!(
+        "rpc_call",
+        method,
+        block_reference,
+        request_id = request_id.map(RequestId::as_str),
+        latency_ms = Empty,
+        attempts = Empty,
+        error.name = Empty,
+        error.cause = Empty,
+        http.status = Empty,
+        error.chain = Empty,
+    );
+    let start = Instant::now();
+    let result = call.instrument(span.clone()).await;
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+    match &result {
+        Ok(_) => {}
+        // The names of the error are fields of the span.
+        Err(RpcError::Rpc(_)) => tracing::warn!(parent: &span, "rpc call failed"),
+        Err(RpcError::Client(err)) => {
+            tracing::warn!(parent: &span, error = %err, "rpc call failed")
+        }
+        Err(RpcError::WrongNetwork(err)) => {
+            tracing::warn!(parent: &span, error = %err, "rpc call failed")
+        }
+    }
+    result
+}
+
+/// Logs that attempt number `attempt` failed with `failure` and is retried
+/// after `delay`.
+pub(crate) fn record_retry(attempt: u32, failure: &FailedAttempt, delay: Duration) {
+    tracing::debug!(attempt, ?failure, ?delay, "retrying rpc call");
+}
+
+/// Records the number of attempts and how the last one failed, if it did.
+pub(crate) fn record_outcome(attempts: u32, failure: Option<&FailedAttempt>) {
+    let span = tracing::Span::current();
+    span.record("attempts", attempts);
+    match failure {
+        Some(FailedAttempt::Rpc { name, cause }) => {
+            span.record("error.name", name.as_str());
+            span.record("error.cause", cause.as_deref());
+        }
+        Some(FailedAttempt::Status(status)) => {
+            span.record("http.status", status);
+        }
+        Some(FailedAttempt::Transport) | None => {}
+    }
+}
+
+/// Records the causes of the error of a failed call, whose last response
+/// or error is `result`.
+pub(crate) fn record_error_chain(result: &Result<bytes::Bytes, RpcError<Infallible>>) {
+    let chain = match result {
+        Ok(body) => request_id::response_cause_chain(body),
+        Err(RpcError::Client(err)) => Some(request_id::client_error_chain(err)),
+        Err(RpcError::Rpc(never)) => match *never {},
+        Err(RpcError::WrongNetwork(err)) => Some(vec![err.to_string()]),
+    };
+    if let Some(chain) = chain {
+        tracing::Span::current().record("error.chain", chain.join(": "));
+    }
+}
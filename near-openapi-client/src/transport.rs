@@ -0,0 +1,166 @@
+//! Sending the encoded JSON-RPC requests of an [`RpcClient`] with another
+//! HTTP stack than the `reqwest` client of the generated [`Client`].
+//!
+//! A [`Transport`] posts a request body and returns the status and body of
+//! the response; encoding, decoding, retries, metrics and fixtures stay in
+//! the [`RpcClient`]. Wrapping [`ReqwestTransport`] adds middleware, e.g.
+//! signing requests for an authenticating proxy:
+//!
+//! ```rust,ignore
+//! struct Signed(ReqwestTransport);
+//!
+//! impl Transport for Signed {
+//!     fn send<'a>(&'a self, url: &'a str, body: Bytes) -> TransportFuture<'a> {
+//!         let url = format!("{}?signature={}", url, sign(&body));
+//!         Box::pin(async move { self.0.send(&url, body).await })
+//!     }
+//! }
+//!
+//! let rpc_client = RpcClient::new(url).with_transport(Signed(ReqwestTransport::default()));
+//! ```
+//!
+//! [`RpcClient`]: crate::rpc::RpcClient
+//! [`Client`]: crate::Client
+use std::sync::Arc;
+
+pub use bytes::Bytes;
+
+use crate::near_client::MaybeSendSync;
+use crate::retry::FailedAttempt;
+use crate::{Error, ResponseValue};
+
+/// Future of a [`Transport::send`] call. It is `Send`, except on wasm32
+/// where the futures of `reqwest` are not.
+#[cfg(not(target_arch = "wasm32"))]
+pub type TransportFuture<'a> =
+    futures_util::future::BoxFuture<'a, Result<TransportResponse, TransportError>>;
+#[cfg(target_arch = "wasm32")]
+pub type TransportFuture<'a> =
+    futures_util::future::LocalBoxFuture<'a, Result<TransportResponse, TransportError>>;
+
+/// HTTP client posting JSON-RPC requests, set with
+/// [`RpcClient::with_transport`](crate::rpc::RpcClient::with_transport).
+pub trait Transport: MaybeSendSync {
+    /// Posts `body`, a JSON-RPC request or a batch of them, to `url` with
+    /// the `application/json` content type.
+    fn send<'a>(&'a self, url: &'a str, body: Bytes) -> TransportFuture<'a>;
+}
+
+/// Response to a request posted by a [`Transport`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransportResponse {
+    /// HTTP status, 200 for every JSON-RPC response, including errors.
+    pub status: u16,
+    pub body: Bytes,
+}
+
+/// The request could not be sent or its response could not be read. Calls
+/// failing with it are retried like connection errors, see
+/// [`FailedAttempt::Transport`].
+#[derive(Debug)]
+pub struct TransportError(Box<dyn std::error::Error + Send + Sync>);
+
+impl TransportError {
+    pub fn new(err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self(err.into())
+    }
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "transport error: {}", self.0)
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.0)
+    }
+}
+
+/// [`Transport`] sending requests with a `reqwest` client, as an
+/// [`RpcClient`](crate::rpc::RpcClient) does without one.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send<'a>(&'a self, url: &'a str, body: Bytes) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .body(body)
+                .send()
+                .await
+                .map_err(TransportError::new)?;
+            let status = response.status().as_u16();
+            let body = response.bytes().await.map_err(TransportError::new)?;
+            Ok(TransportResponse { status, body })
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Handle(pub(crate) Arc<dyn Transport>);
+
+impl std::fmt::Debug for Handle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Transport")
+    }
+}
+
+/// A failed attempt to post a request, with how it failed for retries and
+/// metrics.
+pub(crate) struct SendError {
+    pub(crate) error: Error<()>,
+    pub(crate) failure: Option<FailedAttempt>,
+}
+
+impl From<Error<()>> for SendError {
+    fn from(error: Error<()>) -> Self {
+        let failure = FailedAttempt::from_client_error(&error);
+        Self { error, failure }
+    }
+}
+
+/// Posts `body` to `url` with `transport`, returning the body of a 200
+/// response.
+pub(crate) async fn send(transport: &Handle, url: &str, body: Bytes) -> Result<Bytes, SendError> {
+    match transport.0.send(url, body).await {
+        Ok(TransportResponse { status: 200, body }) => Ok(body),
+        Ok(TransportResponse { status, .. }) => Err(SendError {
+            error: Error::ErrorResponse(ResponseValue::new(
+                (),
+                reqwest::StatusCode::from_u16(status)
+                    .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR),
+                reqwest::header::HeaderMap::new(),
+            )),
+            failure: Some(FailedAttempt::Status(status)),
+        }),
+        Err(TransportError(err)) => Err(SendError {
+            // Errors of a wrapped `ReqwestTransport` read as those of the
+            // generated client.
+            error: match err.downcast::<reqwest::Error>() {
+                Ok(err) => Error::CommunicationError(*err),
+                Err(err) => Error::Custom(format!("transport error: {}", err)),
+            },
+            failure: Some(FailedAttempt::Transport),
+        }),
+    }
+}
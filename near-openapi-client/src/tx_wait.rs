@@ -0,0 +1,109 @@
+//! Sending a transaction and waiting until it reaches an execution status.
+//!
+//! `send_tx` already takes a `wait_until` status, but the node gives up
+//! waiting after its own timeout, e.g. while the receipts of a transaction
+//! wait for congested shards. [`RpcClient::send_tx_and_wait`] then keeps
+//! asking with `tx` until the status is reached or its own timeout expires:
+//!
+//! ```rust,ignore
+//! let response = rpc_client
+//!     .send_tx_and_wait(signed_tx, TxExecutionStatus::Final, Duration::from_secs(60))
+//!     .await?;
+//! ```
+use std::time::Duration;
+
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::time::{self, Instant};
+pub use crate::types::TxExecutionStatus;
+use crate::types::{
+    ErrorWrapperForRpcTransactionError, RpcSendTransactionRequest, RpcTransactionError,
+    RpcTransactionResponse, RpcTransactionStatusRequest, SignedTransaction,
+};
+
+/// Delay before asking again when the node answered before the transaction
+/// reached the requested status.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Error of [`RpcClient::send_tx_and_wait`].
+#[derive(Debug)]
+pub enum TxWaitError {
+    /// Sending the transaction or asking for its status failed, e.g.
+    /// because it is invalid.
+    Rpc(RpcError<ErrorWrapperForRpcTransactionError>),
+    /// The transaction did not reach the requested status in time.
+    /// `last_status` is the status the node last reported, if any.
+    Timeout {
+        last_status: Option<TxExecutionStatus>,
+    },
+}
+
+impl std::fmt::Display for TxWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rpc(err) => write!(f, "failed to send transaction: {}", err),
+            Self::Timeout {
+                last_status: Some(status),
+            } => write!(f, "transaction still {} at timeout", status),
+            Self::Timeout { last_status: None } => {
+                f.write_str("transaction not seen by the node at timeout")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TxWaitError {}
+
+impl RpcClient {
+    /// Sends `signed_tx` and waits until it reaches `wait_until`, for at
+    /// most `timeout`. A transaction the node does not know yet or a node
+    /// timing out while waiting is asked about again with `tx`.
+    pub async fn send_tx_and_wait(
+        &self,
+        signed_tx: SignedTransaction,
+        wait_until: TxExecutionStatus,
+        timeout: Duration,
+    ) -> Result<RpcTransactionResponse, TxWaitError> {
+        let deadline = Instant::now() + timeout;
+        let request = RpcSendTransactionRequest {
+            signed_tx_base64: signed_tx.clone(),
+            wait_until,
+        };
+        let mut last_status = None;
+        let mut result = time::timeout(timeout, self.send_tx(&request)).await;
+        loop {
+            match result {
+                Some(Ok(response)) => {
+                    let status = response.final_execution_status();
+                    if status.satisfies(wait_until) {
+                        return Ok(response);
+                    }
+                    last_status = Some(status);
+                }
+                Some(Err(err)) if !is_pending(&err) => return Err(TxWaitError::Rpc(err)),
+                _ => {}
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            time::sleep(POLL_INTERVAL.min(remaining)).await;
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(TxWaitError::Timeout { last_status });
+            }
+            let request =
+                RpcTransactionStatusRequest::by_signed_transaction(signed_tx.clone(), wait_until);
+            result = time::timeout(remaining, self.tx(&request)).await;
+        }
+    }
+}
+
+/// Whether `err` means the transaction may still reach its status.
+fn is_pending(err: &RpcError<ErrorWrapperForRpcTransactionError>) -> bool {
+    matches!(
+        err,
+        RpcError::Rpc(ErrorWrapperForRpcTransactionError::HandlerError(
+            RpcTransactionError::TimeoutError
+                | RpcTransactionError::UnknownTransaction { .. }
+                | RpcTransactionError::RequestRouted { .. }
+        ))
+    )
+}
@@ -0,0 +1,159 @@
+//! Validator sets of past epochs, for historical stake analysis.
+//!
+//! The `validators` method only takes the id of an epoch or the hash of its
+//! last block, neither of which can be derived from an epoch height. An
+//! epoch is instead reached by walking back from the latest one: the block
+//! before the first block of an epoch is the last block of the epoch before.
+//! Finished epochs don't change, so each [`RpcClient`] keeps those it walked
+//! through, shared by its clones, and only fetches the latest one again.
+//!
+//! ```rust,ignore
+//! let epochs = rpc_client.validators_for_epochs(1000..=1010).await?;
+//! for (epoch_height, epoch) in &epochs {
+//!     println!("{}: {} validators, {} staked", epoch_height, epoch.validators.len(), epoch.total_stake());
+//! }
+//! ```
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+use std::sync::{Arc, Mutex};
+
+use crate::block::HeaderOnly;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccountId, BlockId, CryptoHash, CurrentEpochValidatorInfo, EpochId,
+    ErrorWrapperForRpcBlockError, ErrorWrapperForRpcValidatorError, NearToken, RpcBlockRequest,
+    RpcValidatorRequest, RpcValidatorResponse,
+};
+
+/// Error of [`RpcClient::validators_for_epochs`].
+#[derive(Debug)]
+pub enum ValidatorsError {
+    /// The validators of an epoch could not be fetched, e.g. because the
+    /// node is not an archival node and has discarded them.
+    Validators(RpcError<ErrorWrapperForRpcValidatorError>),
+    /// The first block of an epoch could not be fetched.
+    Block(RpcError<ErrorWrapperForRpcBlockError>),
+}
+
+impl std::fmt::Display for ValidatorsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Validators(err) => write!(f, "failed to fetch validators: {}", err),
+            Self::Block(err) => write!(f, "failed to fetch first block of epoch: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ValidatorsError {}
+
+/// Validators of one epoch, returned by [`RpcClient::validators_for_epochs`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochValidators {
+    pub epoch_id: EpochId,
+    pub epoch_height: u64,
+    pub epoch_start_height: u64,
+    /// Validators with their stake and the blocks and chunks they produced,
+    /// so far for the latest epoch.
+    pub validators: Vec<CurrentEpochValidatorInfo>,
+    /// Last block of the epoch before, `None` for the first epoch.
+    pub prev_epoch_last_block: Option<CryptoHash>,
+}
+
+impl EpochValidators {
+    pub fn total_stake(&self) -> NearToken {
+        NearToken::from_yoctonear(
+            self.validators
+                .iter()
+                .map(|validator| validator.stake.as_yoctonear())
+                .sum(),
+        )
+    }
+
+    /// Stake of `account_id`, if it validated the epoch.
+    pub fn stake_of(&self, account_id: &AccountId) -> Option<NearToken> {
+        self.validators
+            .iter()
+            .find(|validator| &validator.account_id == account_id)
+            .map(|validator| validator.stake)
+    }
+}
+
+/// Finished epochs by height, shared by the clones of an [`RpcClient`].
+#[derive(Clone, Default)]
+pub(crate) struct EpochCache(Arc<Mutex<BTreeMap<u64, EpochValidators>>>);
+
+impl std::fmt::Debug for EpochCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let epochs = self.0.lock().unwrap().len();
+        write!(f, "EpochCache({} epochs)", epochs)
+    }
+}
+
+impl RpcClient {
+    /// Validators of the epochs with a height in `epochs`, walking back from
+    /// the latest epoch. Epochs after the latest one are left out, and so
+    /// are epochs before the first one when `epochs` is unbounded below.
+    pub async fn validators_for_epochs(
+        &self,
+        epochs: impl RangeBounds<u64>,
+    ) -> Result<BTreeMap<u64, EpochValidators>, ValidatorsError> {
+        let first = match epochs.start_bound() {
+            Bound::Included(&height) => height,
+            Bound::Excluded(&height) => height.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let mut found = BTreeMap::new();
+        let mut epoch = self.epoch_validators(RpcValidatorRequest::Latest).await?;
+        loop {
+            let height = epoch.epoch_height;
+            let prev = epoch.prev_epoch_last_block;
+            if epochs.contains(&height) {
+                found.insert(height, epoch);
+            }
+            let Some(prev) = prev.filter(|_| height > first) else {
+                return Ok(found);
+            };
+            let cached = self.epochs.0.lock().unwrap().get(&(height - 1)).cloned();
+            epoch = match cached {
+                Some(epoch) => epoch,
+                None => {
+                    let request = RpcValidatorRequest::BlockId(BlockId::CryptoHash(prev));
+                    let epoch = self.epoch_validators(request).await?;
+                    self.epochs
+                        .0
+                        .lock()
+                        .unwrap()
+                        .insert(epoch.epoch_height, epoch.clone());
+                    epoch
+                }
+            };
+        }
+    }
+
+    /// Validators of the epoch `request` references, with the id and the
+    /// previous epoch read from its first block.
+    async fn epoch_validators(
+        &self,
+        request: RpcValidatorRequest,
+    ) -> Result<EpochValidators, ValidatorsError> {
+        let response: RpcValidatorResponse = self
+            .validators(&request)
+            .await
+            .map_err(ValidatorsError::Validators)?;
+        let first_block =
+            RpcBlockRequest::BlockId(BlockId::BlockHeight(response.epoch_start_height));
+        let block: HeaderOnly = self
+            .call("block", &first_block)
+            .await
+            .map_err(ValidatorsError::Block)?;
+        let prev_hash = block.header.prev_hash;
+        Ok(EpochValidators {
+            epoch_id: EpochId(block.header.epoch_id),
+            epoch_height: response.epoch_height,
+            epoch_start_height: response.epoch_start_height,
+            validators: response.current_validators,
+            prev_epoch_last_block: (prev_hash != CryptoHash::default()).then_some(prev_hash),
+        })
+    }
+}
@@ -0,0 +1,165 @@
+//! Calling view methods of contracts with JSON arguments and results, and
+//! reading accounts, access keys and contract state with one call each.
+//!
+//! ```rust,ignore
+//! let block = BlockReference::final_();
+//! let account = rpc_client.view_account(&account_id, block.clone()).await?;
+//! let keys = rpc_client.view_access_key_list(&account_id, block.clone()).await?;
+//! let state = rpc_client.view_state(&account_id, b"STATE", block).await?;
+//! ```
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types::{
+    AccessKeyList, AccessKeyView, AccountId, AccountView, BlockReference,
+    ErrorWrapperForRpcQueryError, Finality, FunctionArgs, PublicKey, RpcQueryRequest,
+    RpcQueryResponse, StoreKey, ViewAccessKeyByFinalityRequestType,
+    ViewAccessKeyListByFinalityRequestType, ViewAccountByFinalityRequestType,
+    ViewStateByFinalityRequestType, ViewStateResult,
+};
+
+/// Error of [`RpcClient::view_function`].
+#[derive(Debug)]
+pub enum ViewFunctionError {
+    /// The arguments could not be encoded as JSON.
+    Args(serde_json::Error),
+    /// The `call_function` query failed, e.g. because the method panicked.
+    Query(RpcError<ErrorWrapperForRpcQueryError>),
+    /// The node answered the query with something else than a call result.
+    UnexpectedResponse(Box<RpcQueryResponse>),
+    /// The method returned something that could not be decoded.
+    Result(serde_json::Error),
+}
+
+impl std::fmt::Display for ViewFunctionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Args(err) => write!(f, "failed to encode arguments: {}", err),
+            Self::Query(err) => write!(f, "call_function query failed: {}", err),
+            Self::UnexpectedResponse(response) => {
+                write!(f, "unexpected call_function response: {:?}", response)
+            }
+            Self::Result(err) => write!(f, "failed to decode result: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ViewFunctionError {}
+
+/// Result of a view method together with what it logged.
+#[derive(Clone, Debug)]
+pub struct ViewResult<R> {
+    pub result: R,
+    pub logs: Vec<String>,
+    pub block_height: u64,
+}
+
+impl RpcClient {
+    /// Calls the view method `method_name` of `contract_id` at `block` with
+    /// `args` encoded as JSON, decoding its JSON result into `R`.
+    pub async fn view_function<A, R>(
+        &self,
+        contract_id: &AccountId,
+        method_name: &str,
+        args: &A,
+        block: BlockReference,
+    ) -> Result<ViewResult<R>, ViewFunctionError>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        let args = FunctionArgs::from_json(args).map_err(ViewFunctionError::Args)?;
+        let request = RpcQueryRequest::call_function(contract_id.clone(), method_name, args, block);
+        match self
+            .query(&request)
+            .await
+            .map_err(ViewFunctionError::Query)?
+        {
+            RpcQueryResponse::Variant3 {
+                result,
+                logs,
+                block_height,
+                ..
+            } => Ok(ViewResult {
+                result: serde_json::from_slice(&result).map_err(ViewFunctionError::Result)?,
+                logs,
+                block_height,
+            }),
+            other => Err(ViewFunctionError::UnexpectedResponse(Box::new(other))),
+        }
+    }
+
+    /// The account `account_id` at `block`.
+    pub async fn view_account(
+        &self,
+        account_id: &AccountId,
+        block: BlockReference,
+    ) -> Result<AccountView, RpcError<ErrorWrapperForRpcQueryError>> {
+        let request = RpcQueryRequest::ViewAccountByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            request_type: ViewAccountByFinalityRequestType::ViewAccount,
+        };
+        self.call("query", &request.with_block(block)).await
+    }
+
+    /// The access key `public_key` of `account_id` at `block`.
+    pub async fn view_access_key(
+        &self,
+        account_id: &AccountId,
+        public_key: &PublicKey,
+        block: BlockReference,
+    ) -> Result<AccessKeyView, RpcError<ErrorWrapperForRpcQueryError>> {
+        let request = RpcQueryRequest::ViewAccessKeyByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            public_key: *public_key,
+            request_type: ViewAccessKeyByFinalityRequestType::ViewAccessKey,
+        };
+        self.call("query", &request.with_block(block)).await
+    }
+
+    /// All access keys of `account_id` at `block`.
+    pub async fn view_access_key_list(
+        &self,
+        account_id: &AccountId,
+        block: BlockReference,
+    ) -> Result<AccessKeyList, RpcError<ErrorWrapperForRpcQueryError>> {
+        let request = RpcQueryRequest::ViewAccessKeyListByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            request_type: ViewAccessKeyListByFinalityRequestType::ViewAccessKeyList,
+        };
+        self.call("query", &request.with_block(block)).await
+    }
+
+    /// The contract state of `account_id` at `block` under the keys starting
+    /// with `prefix`, decoded from base64. Nodes refuse to return the state
+    /// of contracts larger than their configured limit.
+    pub async fn view_state(
+        &self,
+        account_id: &AccountId,
+        prefix: &[u8],
+        block: BlockReference,
+    ) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, RpcError<ErrorWrapperForRpcQueryError>> {
+        let request = RpcQueryRequest::ViewStateByFinality {
+            account_id: account_id.clone(),
+            finality: Finality::Final,
+            include_proof: None,
+            prefix_base64: StoreKey::from_bytes(prefix),
+            request_type: ViewStateByFinalityRequestType::ViewState,
+        };
+        let state: ViewStateResult = self.call("query", &request.with_block(block)).await?;
+        state.to_map().map_err(|err| {
+            RpcError::Client(Error::Custom(format!(
+                "invalid contract state in view_state response: {}",
+                err
+            )))
+        })
+    }
+}
@@ -0,0 +1,144 @@
+//! Handing off signing to a web wallet, e.g. from a server-side app.
+//!
+//! The app redirects the user to the URL built by [`SignRequest`]. The wallet
+//! signs and sends the transactions with the user's key and redirects back to
+//! the callback URL, whose query is read by [`WalletCallback::from_query`].
+//!
+//! ```rust,ignore
+//! let tx = TransactionBuilder::new(user_id, placeholder_key, receiver_id)
+//!     .function_call("buy", args, NearGas::from_tgas(30), price)
+//!     .build(0, CryptoHash::default());
+//! let url = SignRequest::new(MY_NEAR_WALLET_MAINNET)
+//!     .transaction(tx)
+//!     .callback_url("https://app.example/signed")
+//!     .to_url()?;
+//! ```
+use base64::Engine;
+
+use crate::types::CryptoHash;
+use crate::types::tx::Transaction;
+
+/// MyNearWallet on mainnet.
+pub const MY_NEAR_WALLET_MAINNET: &str = "https://app.mynearwallet.com";
+/// MyNearWallet on testnet.
+pub const MY_NEAR_WALLET_TESTNET: &str = "https://testnet.mynearwallet.com";
+
+/// Request to sign transactions, in the `/sign` URL format of the NEAR web
+/// wallets.
+///
+/// The wallet signs with its own access key and fetches the nonce and block
+/// hash itself, so those fields of the transactions may be left at zero.
+#[derive(Clone, Debug)]
+pub struct SignRequest {
+    wallet_url: String,
+    transactions: Vec<Transaction>,
+    callback_url: Option<String>,
+    meta: Option<String>,
+}
+
+impl SignRequest {
+    /// Request to the wallet at `wallet_url`, e.g. [`MY_NEAR_WALLET_MAINNET`].
+    pub fn new(wallet_url: impl Into<String>) -> Self {
+        Self {
+            wallet_url: wallet_url.into(),
+            transactions: Vec::new(),
+            callback_url: None,
+            meta: None,
+        }
+    }
+
+    /// Adds a transaction; the wallet signs all of them in order.
+    pub fn transaction(mut self, transaction: Transaction) -> Self {
+        self.transactions.push(transaction);
+        self
+    }
+
+    /// URL the wallet redirects to once the transactions are sent or the user
+    /// rejected them.
+    pub fn callback_url(mut self, callback_url: impl Into<String>) -> Self {
+        self.callback_url = Some(callback_url.into());
+        self
+    }
+
+    /// Opaque value passed back in the `meta` parameter of the callback.
+    pub fn meta(mut self, meta: impl Into<String>) -> Self {
+        self.meta = Some(meta.into());
+        self
+    }
+
+    /// The URL to redirect the user to. Fails if a transaction can't be
+    /// encoded, e.g. because of an invalid public key.
+    pub fn to_url(&self) -> std::io::Result<String> {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(|transaction| {
+                Ok(base64::engine::general_purpose::STANDARD.encode(transaction.encode()?))
+            })
+            .collect::<std::io::Result<Vec<_>>>()?
+            .join(",");
+        let mut params = vec![("transactions", transactions.as_str())];
+        if let Some(callback_url) = &self.callback_url {
+            params.push(("callbackUrl", callback_url));
+        }
+        if let Some(meta) = &self.meta {
+            params.push(("meta", meta));
+        }
+        let query =
+            serde_urlencoded::to_string(params).expect("string parameters are always encodable");
+        Ok(format!(
+            "{}/sign?{}",
+            self.wallet_url.trim_end_matches('/'),
+            query
+        ))
+    }
+}
+
+/// Outcome reported by the wallet in the query of the callback URL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WalletCallback {
+    /// The transactions were sent.
+    Signed {
+        transaction_hashes: Vec<CryptoHash>,
+        meta: Option<String>,
+    },
+    /// The user rejected the request or the wallet failed to send it.
+    Failed {
+        code: String,
+        message: Option<String>,
+        meta: Option<String>,
+    },
+}
+
+impl WalletCallback {
+    /// Reads the query string of the callback URL, without the leading `?`.
+    /// Returns `None` if it holds neither transaction hashes nor an error.
+    pub fn from_query(query: &str) -> Option<Self> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Query {
+            transaction_hashes: Option<String>,
+            error_code: Option<String>,
+            error_message: Option<String>,
+            meta: Option<String>,
+        }
+
+        let query: Query = serde_urlencoded::from_str(query).ok()?;
+        if let Some(code) = query.error_code {
+            return Some(Self::Failed {
+                code,
+                message: query.error_message,
+                meta: query.meta,
+            });
+        }
+        let transaction_hashes = query
+            .transaction_hashes?
+            .split(',')
+            .map(|hash| hash.parse().ok())
+            .collect::<Option<_>>()?;
+        Some(Self::Signed {
+            transaction_hashes,
+            meta: query.meta,
+        })
+    }
+}
@@ -0,0 +1,336 @@
+//! Subscriptions to new blocks and transaction outcomes over WebSocket, for
+//! providers that stream them. Requires the `ws` feature.
+//!
+//! After connecting, the client sends a JSON-RPC `subscribe` request whose
+//! params are the name of the subscription and its arguments, e.g.
+//! `["new_blocks"]`. The server answers with a subscription id and then sends
+//! a notification per item:
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "method": "subscription", "params": {"subscription": "0x1", "result": {...}}}
+//! ```
+//!
+//! When the connection drops, the client reconnects with backoff and
+//! subscribes again, so the streams only end when they are dropped. While no
+//! message arrives, the client pings the server, and a connection that does
+//! not answer is dropped as well. Block subscriptions are resumed with the
+//! hash of the last block received, e.g. `["new_blocks", {"from_block_hash":
+//! "..."}]`, so providers supporting it send the blocks missed in between.
+//! [`WsClient::events`] reports the connection changes. Like
+//! `reqwest`, `tokio-tungstenite` is built without TLS; enable one of its TLS
+//! features to connect to `wss://` URLs.
+//!
+//! ```rust,ignore
+//! let client = WsClient::new("wss://ws.example.com");
+//! let mut blocks = std::pin::pin!(client.subscribe_blocks());
+//! while let Some(header) = blocks.next().await {
+//!     println!("{}", header?.height);
+//! }
+//! ```
+use std::time::Duration;
+
+use futures_core::Stream;
+use futures_util::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use tokio::net::TcpStream;
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::retry::Backoff;
+use crate::types::{AccountId, BlockHeaderView, CryptoHash, ExecutionOutcomeView};
+
+/// Error of a subscription. The stream keeps reconnecting after it.
+#[derive(Debug)]
+pub enum WsError {
+    /// The connection could not be opened or broke while subscribing.
+    Connect(Box<tokio_tungstenite::tungstenite::Error>),
+    /// The server answered the `subscribe` request with this JSON-RPC error.
+    Subscribe(serde_json::Value),
+    /// A message of the server could not be decoded.
+    Decode(serde_json::Error),
+}
+
+impl std::fmt::Display for WsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connect(err) => write!(f, "WebSocket connection failed: {}", err),
+            Self::Subscribe(error) => write!(f, "subscription rejected: {}", error),
+            Self::Decode(err) => write!(f, "failed to decode message: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for WsError {}
+
+impl From<tokio_tungstenite::tungstenite::Error> for WsError {
+    fn from(value: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::Connect(Box::new(value))
+    }
+}
+
+impl From<serde_json::Error> for WsError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Decode(value)
+    }
+}
+
+/// Change of the connection of a subscription, e.g. to report the health of
+/// a pipeline consuming it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionEvent {
+    /// The subscription was accepted for the first time.
+    Connected,
+    /// The connection dropped or stopped answering pings. The subscription
+    /// reconnects with backoff.
+    Disconnected,
+    /// The subscription was accepted again after a disconnection, resuming
+    /// after the block `block_hash` if it streams blocks.
+    Resumed { block_hash: Option<CryptoHash> },
+}
+
+/// Client of a WebSocket endpoint streaming new blocks and transaction
+/// outcomes.
+#[derive(Clone, Debug)]
+pub struct WsClient {
+    url: String,
+    backoff: Backoff,
+    keepalive: Duration,
+    events: broadcast::Sender<ConnectionEvent>,
+}
+
+impl WsClient {
+    /// Client of the endpoint at `url`, reconnecting with exponential backoff
+    /// from 1s to 30s and pinging the server after 30s without messages.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            backoff: Backoff::Exponential {
+                initial: Duration::from_secs(1),
+                max: Duration::from_secs(30),
+            },
+            keepalive: Duration::from_secs(30),
+            events: broadcast::channel(16).0,
+        }
+    }
+
+    /// Delay before each reconnection attempt.
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Time without messages after which the server is pinged. A connection
+    /// still silent after another `interval` is dropped and reconnected.
+    pub fn keepalive(mut self, interval: Duration) -> Self {
+        self.keepalive = interval;
+        self
+    }
+
+    /// Connection changes of the subscriptions of this client and of its
+    /// clones, from now on. A receiver more than 16 events behind misses the
+    /// oldest ones.
+    pub fn events(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.events.subscribe()
+    }
+
+    /// Headers of new final blocks. After a reconnection, the subscription
+    /// resumes after the last block received, and blocks sent again are
+    /// skipped.
+    pub fn subscribe_blocks(&self) -> impl Stream<Item = Result<BlockHeaderView, WsError>> {
+        let mut last_height = None;
+        self.subscribe(
+            serde_json::json!(["new_blocks"]),
+            |header: &BlockHeaderView| Some(header.hash),
+        )
+        .filter(move |header| {
+            let new = match header {
+                Ok(header) if last_height >= Some(header.height) => false,
+                Ok(header) => {
+                    last_height = Some(header.height);
+                    true
+                }
+                Err(_) => true,
+            };
+            std::future::ready(new)
+        })
+    }
+
+    /// Outcomes of the transaction `tx_hash` and of its receipts as they are
+    /// executed.
+    pub fn subscribe_tx_status(
+        &self,
+        tx_hash: CryptoHash,
+        sender_account_id: AccountId,
+    ) -> impl Stream<Item = Result<ExecutionOutcomeView, WsError>> {
+        self.subscribe(
+            serde_json::json!([
+                "tx_status",
+                {"tx_hash": tx_hash, "sender_account_id": sender_account_id},
+            ]),
+            |_: &ExecutionOutcomeView| None,
+        )
+    }
+
+    /// Subscribes with `params`, resuming after the block `resume` gives for
+    /// the last item received.
+    fn subscribe<T: DeserializeOwned>(
+        &self,
+        params: serde_json::Value,
+        resume: fn(&T) -> Option<CryptoHash>,
+    ) -> impl Stream<Item = Result<T, WsError>> {
+        let subscription = Subscription {
+            url: self.url.clone(),
+            backoff: self.backoff,
+            keepalive: self.keepalive,
+            events: self.events.clone(),
+            params,
+            resume_from: None,
+            socket: None,
+            retry: 0,
+            subscribed: false,
+            pinged: false,
+        };
+        futures_util::stream::unfold(subscription, move |mut subscription| async move {
+            let item = subscription
+                .next_result()
+                .await
+                .and_then(|result| Ok(serde_json::from_value(result)?));
+            if let Some(block_hash) = item.as_ref().ok().and_then(resume) {
+                subscription.resume_from = Some(block_hash);
+            }
+            Some((item, subscription))
+        })
+    }
+}
+
+struct Subscription {
+    url: String,
+    backoff: Backoff,
+    keepalive: Duration,
+    events: broadcast::Sender<ConnectionEvent>,
+    params: serde_json::Value,
+    /// Hash of the block to resume after when subscribing again.
+    resume_from: Option<CryptoHash>,
+    socket: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+    /// Reconnection attempts since the last successful subscription.
+    retry: u32,
+    /// Whether the subscription was accepted before.
+    subscribed: bool,
+    /// Whether the server was pinged since the last message.
+    pinged: bool,
+}
+
+impl Subscription {
+    /// The `result` of the next notification, reconnecting as needed.
+    async fn next_result(&mut self) -> Result<serde_json::Value, WsError> {
+        loop {
+            let socket = match &mut self.socket {
+                Some(socket) => socket,
+                None => {
+                    if self.retry > 0 {
+                        tokio::time::sleep(self.backoff.delay(self.retry - 1)).await;
+                    }
+                    self.retry += 1;
+                    let socket = self.connect().await?;
+                    self.retry = 0;
+                    let event = if self.subscribed {
+                        ConnectionEvent::Resumed {
+                            block_hash: self.resume_from,
+                        }
+                    } else {
+                        ConnectionEvent::Connected
+                    };
+                    self.subscribed = true;
+                    // Nobody may be listening.
+                    let _ = self.events.send(event);
+                    self.socket.insert(socket)
+                }
+            };
+            let message = match tokio::time::timeout(self.keepalive, socket.next()).await {
+                Ok(message) => message,
+                // The ping went unanswered.
+                Err(_) if self.pinged => None,
+                Err(_) => {
+                    self.pinged = true;
+                    if socket
+                        .send(Message::Ping(Default::default()))
+                        .await
+                        .is_err()
+                    {
+                        self.disconnect();
+                    }
+                    continue;
+                }
+            };
+            self.pinged = false;
+            match message {
+                Some(Ok(Message::Text(text))) => {
+                    if let Some(result) = notification_result(text.as_str())? {
+                        return Ok(result);
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => self.disconnect(),
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+
+    fn disconnect(&mut self) {
+        self.socket = None;
+        self.retry = 1;
+        self.pinged = false;
+        let _ = self.events.send(ConnectionEvent::Disconnected);
+    }
+
+    /// The `subscribe` request, resuming after the last block if known.
+    fn request(&self) -> String {
+        let mut params = self.params.clone();
+        if let (Some(block_hash), Some(params)) = (&self.resume_from, params.as_array_mut()) {
+            params.push(serde_json::json!({"from_block_hash": block_hash}));
+        }
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "dontcare",
+            "method": "subscribe",
+            "params": params,
+        })
+        .to_string()
+    }
+
+    /// Connects and subscribes, waiting for the server to accept.
+    async fn connect(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let (mut socket, _) = tokio_tungstenite::connect_async(&self.url).await?;
+        socket.send(Message::text(self.request())).await?;
+        loop {
+            let Some(message) = socket.next().await else {
+                return Err(tokio_tungstenite::tungstenite::Error::ConnectionClosed.into());
+            };
+            let Message::Text(text) = message? else {
+                continue;
+            };
+            let mut response: serde_json::Value = serde_json::from_str(text.as_str())?;
+            if let Some(error) = response.get_mut("error") {
+                return Err(WsError::Subscribe(error.take()));
+            }
+            if response.get("result").is_some() {
+                return Ok(socket);
+            }
+        }
+    }
+}
+
+/// Reads the `result` of a notification; other messages give `None`.
+fn notification_result(text: &str) -> Result<Option<serde_json::Value>, WsError> {
+    #[derive(serde::Deserialize)]
+    struct Notification {
+        params: Option<Params>,
+    }
+    #[derive(serde::Deserialize)]
+    struct Params {
+        result: serde_json::Value,
+    }
+
+    let notification: Notification = serde_json::from_str(text)?;
+    Ok(notification.params.map(|params| params.result))
+}
@@ -0,0 +1,128 @@
+//! Filtering and looking up the keys of an [`AccessKeyList`].
+//!
+//! The `view_access_key_list` query returns every key of an account at once,
+//! which can be thousands for accounts handing out function call keys. These
+//! helpers narrow the list down client-side without copying it.
+use std::collections::HashMap;
+
+use crate::{
+    AccessKeyInfoView, AccessKeyList, AccessKeyPermissionView, AccessKeyView, NearToken, PublicKey,
+};
+
+impl AccessKeyPermissionView {
+    pub fn is_full_access(&self) -> bool {
+        matches!(self, Self::FullAccess)
+    }
+
+    /// Contract a function call key may call, `None` for a full access key.
+    pub fn receiver_id(&self) -> Option<&str> {
+        match self {
+            Self::FullAccess => None,
+            Self::FunctionCall { receiver_id, .. } => Some(receiver_id),
+        }
+    }
+
+    /// Whether the key may call `method_name` of `receiver_id`. A function
+    /// call key without method names may call any method of its receiver.
+    pub fn allows(&self, receiver_id: &str, method_name: &str) -> bool {
+        match self {
+            Self::FullAccess => true,
+            Self::FunctionCall {
+                method_names,
+                receiver_id: allowed,
+                ..
+            } => {
+                allowed == receiver_id
+                    && (method_names.is_empty() || method_names.iter().any(|m| m == method_name))
+            }
+        }
+    }
+
+    /// Whether the key is a function call key with less than `threshold` of
+    /// its allowance left. Full access keys and keys with an unlimited
+    /// allowance are never depleted.
+    pub fn is_allowance_below(&self, threshold: NearToken) -> bool {
+        match self {
+            Self::FunctionCall {
+                allowance: Some(allowance),
+                ..
+            } => *allowance < threshold,
+            _ => false,
+        }
+    }
+}
+
+impl AccessKeyList {
+    pub fn iter(&self) -> std::slice::Iter<'_, AccessKeyInfoView> {
+        self.keys.iter()
+    }
+
+    /// The access key `public_key`, if the account has it.
+    pub fn get(&self, public_key: &PublicKey) -> Option<&AccessKeyView> {
+        self.keys
+            .iter()
+            .find(|key| &key.public_key == public_key)
+            .map(|key| &key.access_key)
+    }
+
+    pub fn full_access(&self) -> impl Iterator<Item = &AccessKeyInfoView> {
+        self.iter()
+            .filter(|key| key.access_key.permission.is_full_access())
+    }
+
+    pub fn function_call(&self) -> impl Iterator<Item = &AccessKeyInfoView> {
+        self.iter()
+            .filter(|key| !key.access_key.permission.is_full_access())
+    }
+
+    /// Function call keys that may call `receiver_id`.
+    pub fn for_receiver<'a>(
+        &'a self,
+        receiver_id: &'a str,
+    ) -> impl Iterator<Item = &'a AccessKeyInfoView> {
+        self.iter()
+            .filter(move |key| key.access_key.permission.receiver_id() == Some(receiver_id))
+    }
+
+    /// Function call keys with less than `threshold` of their allowance left,
+    /// e.g. to top them up or replace them before they run out.
+    pub fn allowance_below(
+        &self,
+        threshold: NearToken,
+    ) -> impl Iterator<Item = &AccessKeyInfoView> {
+        self.iter()
+            .filter(move |key| key.access_key.permission.is_allowance_below(threshold))
+    }
+
+    /// The keys by public key, for repeated lookups.
+    pub fn into_map(self) -> HashMap<PublicKey, AccessKeyView> {
+        self.into()
+    }
+}
+
+impl<'a> IntoIterator for &'a AccessKeyList {
+    type Item = &'a AccessKeyInfoView;
+    type IntoIter = std::slice::Iter<'a, AccessKeyInfoView>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.iter()
+    }
+}
+
+impl IntoIterator for AccessKeyList {
+    type Item = AccessKeyInfoView;
+    type IntoIter = std::vec::IntoIter<AccessKeyInfoView>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter()
+    }
+}
+
+impl From<AccessKeyList> for HashMap<PublicKey, AccessKeyView> {
+    fn from(list: AccessKeyList) -> Self {
+        list.keys
+            .into_iter()
+            .map(|key| (key.public_key, key.access_key))
+            .collect()
+    }
+}
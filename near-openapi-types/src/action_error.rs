@@ -0,0 +1,183 @@
+//! Messages of failed actions, worded as nearcore words them.
+use std::fmt;
+
+use crate::{ActionError, ActionErrorKind, FunctionCallError, GlobalContractIdentifier};
+
+impl fmt::Display for ActionErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountAlreadyExists { account_id } => write!(
+                f,
+                "Can't create a new account {}, because it already exists",
+                account_id
+            ),
+            Self::AccountDoesNotExist { account_id } => write!(
+                f,
+                "Can't complete the action because account {} doesn't exist",
+                account_id
+            ),
+            Self::CreateAccountOnlyByRegistrar {
+                account_id,
+                predecessor_id,
+                registrar_account_id,
+            } => write!(
+                f,
+                "A top-level account ID {} can't be created by {}, short top-level account IDs can only be created by {}",
+                account_id, predecessor_id, registrar_account_id
+            ),
+            Self::CreateAccountNotAllowed {
+                account_id,
+                predecessor_id,
+            } => write!(
+                f,
+                "A sub-account ID {} can't be created by account {}",
+                account_id, predecessor_id
+            ),
+            Self::ActorNoPermission {
+                account_id,
+                actor_id,
+            } => write!(
+                f,
+                "Actor {} doesn't have permission to account {} to complete the action",
+                actor_id, account_id
+            ),
+            Self::DeleteKeyDoesNotExist { account_id, .. } => write!(
+                f,
+                "Account {} tries to remove an access key that doesn't exist",
+                account_id
+            ),
+            Self::AddKeyAlreadyExists { public_key, .. } => write!(
+                f,
+                "The public key {} is already used for an existing access key",
+                public_key
+            ),
+            Self::DeleteAccountStaking { account_id } => {
+                write!(
+                    f,
+                    "Account {} is staking and can not be deleted",
+                    account_id
+                )
+            }
+            Self::LackBalanceForState { account_id, amount } => write!(
+                f,
+                "The account {} wouldn't have enough balance to cover storage, required to have {} yoctoNEAR more",
+                account_id,
+                amount.as_yoctonear()
+            ),
+            Self::TriesToUnstake { account_id } => write!(
+                f,
+                "Account {} is not yet staked, but tries to unstake",
+                account_id
+            ),
+            Self::TriesToStake {
+                account_id,
+                balance,
+                locked,
+                stake,
+            } => write!(
+                f,
+                "Account {} tries to stake {} yoctoNEAR, but has staked {} yoctoNEAR and only has {} yoctoNEAR",
+                account_id,
+                stake.as_yoctonear(),
+                locked.as_yoctonear(),
+                balance.as_yoctonear()
+            ),
+            Self::InsufficientStake {
+                account_id,
+                minimum_stake,
+                stake,
+            } => write!(
+                f,
+                "Account {} tries to stake {} yoctoNEAR but minimum required stake is {} yoctoNEAR",
+                account_id,
+                stake.as_yoctonear(),
+                minimum_stake.as_yoctonear()
+            ),
+            Self::FunctionCallError(err) => match err {
+                FunctionCallError::ExecutionError(message) => f.write_str(message),
+                FunctionCallError::LinkError { msg } => f.write_str(msg),
+                err => write!(f, "{:?}", err),
+            },
+            Self::NewReceiptValidationError(err) => write!(
+                f,
+                "An new action receipt created during a FunctionCall is not valid: {:?}",
+                err
+            ),
+            Self::OnlyImplicitAccountCreationAllowed { account_id } => write!(
+                f,
+                "CreateAccount action is called on hex-characters account of length 64 {}",
+                account_id
+            ),
+            Self::DeleteAccountWithLargeState { account_id } => write!(
+                f,
+                "The state of account {} is too large and therefore cannot be deleted",
+                account_id
+            ),
+            Self::DelegateActionInvalidSignature => {
+                f.write_str("DelegateAction is not signed with the given public key")
+            }
+            Self::DelegateActionSenderDoesNotMatchTxReceiver {
+                receiver_id,
+                sender_id,
+            } => write!(
+                f,
+                "Transaction receiver {} doesn't match DelegateAction sender {}",
+                receiver_id, sender_id
+            ),
+            Self::DelegateActionExpired => f.write_str("DelegateAction has expired"),
+            Self::DelegateActionAccessKeyError(err) => write!(f, "{:?}", err),
+            Self::DelegateActionInvalidNonce {
+                ak_nonce,
+                delegate_nonce,
+            } => write!(
+                f,
+                "DelegateAction nonce {} must be larger than nonce of the used access key {}",
+                delegate_nonce, ak_nonce
+            ),
+            Self::DelegateActionNonceTooLarge {
+                delegate_nonce,
+                upper_bound,
+            } => write!(
+                f,
+                "DelegateAction nonce {} must be smaller than the access key nonce upper bound {}",
+                delegate_nonce, upper_bound
+            ),
+            Self::GlobalContractDoesNotExist { identifier } => match identifier {
+                GlobalContractIdentifier::CodeHash(hash) => {
+                    write!(f, "Global contract with code hash {} not found", hash)
+                }
+                GlobalContractIdentifier::AccountId(account_id) => write!(
+                    f,
+                    "Global contract deployed by account {} not found",
+                    account_id
+                ),
+            },
+            Self::GasKeyDoesNotExist {
+                account_id,
+                public_key,
+            } => write!(f, "Account {} has no gas key {}", account_id, public_key),
+            Self::GasKeyAlreadyExists {
+                account_id,
+                public_key,
+            } => write!(
+                f,
+                "Account {} already has a gas key {}",
+                account_id, public_key
+            ),
+            Self::Unknown(value) => write!(f, "Unknown action error: {}", value.0),
+        }
+    }
+}
+
+impl std::error::Error for ActionErrorKind {}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "Action #{}: {}", index, self.kind),
+            None => fmt::Display::fmt(&self.kind, f),
+        }
+    }
+}
+
+impl std::error::Error for ActionError {}
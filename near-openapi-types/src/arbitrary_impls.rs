@@ -0,0 +1,39 @@
+//! `Arbitrary` for the types that can't derive it, with the `arbitrary`
+//! feature.
+use arbitrary::{Result, Unstructured};
+use serde_json::{Map, Value};
+
+#[cfg(feature = "tx")]
+use crate::CreateAccountAction;
+#[cfg(feature = "debug")]
+use crate::RpcSplitStorageInfoRequest;
+
+/// A JSON object of arbitrary keys and scalar values.
+pub(crate) fn json_map(u: &mut Unstructured<'_>) -> Result<Map<String, Value>> {
+    let mut map = Map::new();
+    for _ in 0..u.arbitrary_len::<(String, i64)>()? {
+        let key = u.arbitrary()?;
+        let value = match u.int_in_range(0..=3)? {
+            0 => Value::Null,
+            1 => Value::Bool(u.arbitrary()?),
+            2 => Value::from(u.arbitrary::<i64>()?),
+            _ => Value::String(u.arbitrary()?),
+        };
+        map.insert(key, value);
+    }
+    Ok(map)
+}
+
+#[cfg(feature = "tx")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateAccountAction {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self(json_map(u)?))
+    }
+}
+
+#[cfg(feature = "debug")]
+impl<'a> arbitrary::Arbitrary<'a> for RpcSplitStorageInfoRequest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self(json_map(u)?))
+    }
+}
@@ -0,0 +1,185 @@
+//! Balances of accounts and arithmetic on the amounts of `NearToken` fields.
+//!
+//! `AccountView::amount` is the liquid balance of an account and `locked`
+//! its stake. Part of the balance must stay on the account to pay for its
+//! storage, unless the stake already covers it, so the balance that can be
+//! spent is less than `amount`:
+//!
+//! ```
+//! # use near_openapi_types::{balance, AccountView, NearToken};
+//! # let account: AccountView = serde_json::from_value(serde_json::json!({
+//! #     "amount": "5000000000000000000000000", "locked": "0",
+//! #     "code_hash": "11111111111111111111111111111111", "storage_usage": 100000,
+//! # })).unwrap();
+//! let available = balance::available_balance(&account, balance::STORAGE_AMOUNT_PER_BYTE);
+//! assert_eq!(available, NearToken::from_near(4));
+//! assert_eq!(balance::format_near(available, 2), "4 NEAR");
+//! ```
+//!
+//! The [`NearToken`] methods `checked_add`, `checked_sub`, `checked_mul` and
+//! `checked_div` are the overflow-safe way to combine amounts; [`add`],
+//! [`sub`] and [`fraction`] return a [`BalanceError`] saying what went wrong
+//! instead of `None`. Amounts are formatted rounded down, unlike the
+//! `Display` of `NearToken`, which rounds up and so may show more than an
+//! account can spend.
+use crate::{AccountView, NearToken};
+
+/// Cost of storing a byte on mainnet and testnet, the
+/// `storage_amount_per_byte` of the runtime config.
+pub const STORAGE_AMOUNT_PER_BYTE: NearToken = NearToken::from_yoctonear(10u128.pow(19));
+
+const YOCTO_PER_MILLINEAR: u128 = 10u128.pow(21);
+const YOCTO_PER_NEAR: u128 = 10u128.pow(24);
+
+/// Error of the arithmetic of this module.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BalanceError {
+    /// The result exceeds `u128::MAX` yoctoNEAR.
+    Overflow,
+    /// `required` was subtracted from `available`, which is less.
+    Insufficient {
+        required: NearToken,
+        available: NearToken,
+    },
+    DivisionByZero,
+}
+
+impl std::fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("amount overflows u128 yoctoNEAR"),
+            Self::Insufficient {
+                required,
+                available,
+            } => write!(
+                f,
+                "{} yoctoNEAR required but only {} yoctoNEAR available",
+                required.as_yoctonear(),
+                available.as_yoctonear()
+            ),
+            Self::DivisionByZero => f.write_str("division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for BalanceError {}
+
+pub fn add(lhs: NearToken, rhs: NearToken) -> Result<NearToken, BalanceError> {
+    lhs.checked_add(rhs).ok_or(BalanceError::Overflow)
+}
+
+/// `available - required`, e.g. `amount - locked`.
+pub fn sub(available: NearToken, required: NearToken) -> Result<NearToken, BalanceError> {
+    available
+        .checked_sub(required)
+        .ok_or(BalanceError::Insufficient {
+            required,
+            available,
+        })
+}
+
+/// `amount * numerator / denominator`, rounded down, without overflowing
+/// when only the intermediate product exceeds `u128`.
+pub fn fraction(
+    amount: NearToken,
+    numerator: u128,
+    denominator: u128,
+) -> Result<NearToken, BalanceError> {
+    if denominator == 0 {
+        return Err(BalanceError::DivisionByZero);
+    }
+    let amount = amount.as_yoctonear();
+    let whole = (amount / denominator).checked_mul(numerator);
+    let rest = (amount % denominator)
+        .checked_mul(numerator)
+        .map(|rest| rest / denominator);
+    whole
+        .zip(rest)
+        .and_then(|(whole, rest)| whole.checked_add(rest))
+        .map(NearToken::from_yoctonear)
+        .ok_or(BalanceError::Overflow)
+}
+
+/// `percent`% of `amount`, rounded down.
+pub fn percent(amount: NearToken, percent: u128) -> Result<NearToken, BalanceError> {
+    fraction(amount, percent, 100)
+}
+
+/// Balance of an account split the way wallets show it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountBalance {
+    /// `amount + locked`.
+    pub total: NearToken,
+    /// Stake of the account, `locked`.
+    pub staked: NearToken,
+    /// Cost of the storage used by the account.
+    pub state_staked: NearToken,
+    /// What the account can spend: the total minus the stake or the storage
+    /// cost, whichever is more.
+    pub available: NearToken,
+}
+
+impl AccountBalance {
+    /// Balance of `account` when storage costs `storage_amount_per_byte`,
+    /// usually [`STORAGE_AMOUNT_PER_BYTE`].
+    pub fn new(
+        account: &AccountView,
+        storage_amount_per_byte: NearToken,
+    ) -> Result<Self, BalanceError> {
+        let total = add(account.amount, account.locked)?;
+        let state_staked = storage_amount_per_byte
+            .checked_mul(u128::from(account.storage_usage))
+            .ok_or(BalanceError::Overflow)?;
+        let available = total.saturating_sub(account.locked.max(state_staked));
+        Ok(Self {
+            total,
+            staked: account.locked,
+            state_staked,
+            available,
+        })
+    }
+}
+
+impl AccountView {
+    /// See [`AccountBalance::new`].
+    pub fn balance(
+        &self,
+        storage_amount_per_byte: NearToken,
+    ) -> Result<AccountBalance, BalanceError> {
+        AccountBalance::new(self, storage_amount_per_byte)
+    }
+}
+
+/// Balance `account` can spend, see [`AccountBalance::available`]. Zero when
+/// the storage cost can't be computed, which no real account reaches.
+pub fn available_balance(account: &AccountView, storage_amount_per_byte: NearToken) -> NearToken {
+    AccountBalance::new(account, storage_amount_per_byte)
+        .map(|balance| balance.available)
+        .unwrap_or(NearToken::from_yoctonear(0))
+}
+
+/// `amount` in NEAR with at most `decimals` decimals, rounded down and
+/// without trailing zeros, e.g. `1.25 NEAR`.
+pub fn format_near(amount: NearToken, decimals: u32) -> String {
+    format_units(amount, YOCTO_PER_NEAR, 24, decimals, "NEAR")
+}
+
+/// `amount` in millinear with at most `decimals` decimals, rounded down and
+/// without trailing zeros, e.g. `1250 mNEAR`.
+pub fn format_millinear(amount: NearToken, decimals: u32) -> String {
+    format_units(amount, YOCTO_PER_MILLINEAR, 21, decimals, "mNEAR")
+}
+
+fn format_units(amount: NearToken, unit: u128, digits: u32, decimals: u32, symbol: &str) -> String {
+    let amount = amount.as_yoctonear();
+    let decimals = decimals.min(digits);
+    let shown = 10u128.pow(digits - decimals);
+    let fraction = amount % unit / shown;
+    let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{} {}", amount / unit, symbol)
+    } else {
+        format!("{}.{} {}", amount / unit, fraction, symbol)
+    }
+}
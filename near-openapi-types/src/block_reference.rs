@@ -0,0 +1,46 @@
+use crate::{BlockId, BlockReference, CryptoHash, Finality};
+
+/// Shorthands for the block a request is about:
+///
+/// ```
+/// # use near_openapi_types::{BlockId, BlockReference, Finality};
+/// assert_eq!(BlockReference::final_(), BlockReference::Finality(Finality::Final));
+/// assert_eq!(
+///     BlockReference::at_height(9_820_210),
+///     BlockReference::BlockId(BlockId::BlockHeight(9_820_210))
+/// );
+/// ```
+impl BlockReference {
+    /// The latest block, which may still be replaced by another fork. The
+    /// same as [`BlockReference::optimistic`], like `BlockReference::latest`
+    /// of nearcore.
+    pub fn latest() -> Self {
+        Self::optimistic()
+    }
+
+    /// The latest final block.
+    pub fn final_() -> Self {
+        Self::Finality(Finality::Final)
+    }
+
+    /// The latest block, which may still be replaced by another fork.
+    pub fn optimistic() -> Self {
+        Self::Finality(Finality::Optimistic)
+    }
+
+    /// The latest block with a final block after it, which can no longer be
+    /// replaced unless validators get slashed.
+    pub fn near_final() -> Self {
+        Self::Finality(Finality::NearFinal)
+    }
+
+    /// The block at `height`.
+    pub fn at_height(height: u64) -> Self {
+        Self::BlockId(BlockId::BlockHeight(height))
+    }
+
+    /// The block with the hash `hash`.
+    pub fn at_hash(hash: CryptoHash) -> Self {
+        Self::BlockId(BlockId::CryptoHash(hash))
+    }
+}
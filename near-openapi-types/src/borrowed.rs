@@ -0,0 +1,378 @@
+//! Borrowed views of chunks, transactions and execution outcomes.
+//!
+//! Decoding a chunk or a transaction into the generated types allocates a
+//! `String` for every account id, method name, log and base64 blob in it.
+//! The `*Ref` types of this module decode the same JSON but borrow those
+//! strings from the response body instead, for consumers indexing every
+//! block: account ids are `&AccountIdRef`, base64 blobs `&str`, and logs
+//! and method names `Cow<str>`, only allocated when the JSON escapes
+//! characters in them. Actions other than function calls and deployments
+//! and the errors of failed executions are kept as undecoded JSON
+//! (`&RawValue`), and [`ToOwnedView::to_owned_view`] decodes any of these
+//! views into its generated type when all of it is needed.
+//!
+//! The views borrow from the buffer they are decoded from, so they can only
+//! be decoded from a `&str` or `&[u8]`, e.g. with `serde_json::from_slice`
+//! or `RawResponse::decode` of near-openapi-client:
+//!
+//! ```
+//! # use near_openapi_types::borrowed::{ActionViewRef, SignedTransactionViewRef};
+//! let json = r#"{
+//!     "actions": [
+//!         {"FunctionCall": {"args": "e30=", "deposit": "0", "gas": 30000000000000, "method_name": "ft_transfer"}},
+//!         {"Transfer": {"deposit": "1"}}
+//!     ],
+//!     "hash": "11111111111111111111111111111111",
+//!     "nonce": 1,
+//!     "public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+//!     "receiver_id": "token.near",
+//!     "signature": "ed25519:1111111111111111111111111111111111111111111111111111111111111111",
+//!     "signer_id": "alice.near"
+//! }"#;
+//! let tx: SignedTransactionViewRef<'_> = serde_json::from_str(json).unwrap();
+//! assert_eq!(tx.signer_id.as_str(), "alice.near");
+//! let ActionViewRef::FunctionCall(call) = &tx.actions[0] else { panic!() };
+//! assert_eq!(call.method_name, "ft_transfer");
+//! assert!(matches!(tx.actions[1], ActionViewRef::Other(_)));
+//! ```
+use std::borrow::Cow;
+
+use serde::de::{DeserializeOwned, Error as _, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
+
+use crate::{
+    AccountIdRef, ActionView, ChunkHeaderView, CryptoHash, DataReceiverView, ExecutionMetadataView,
+    ExecutionOutcomeView, ExecutionOutcomeWithIdView, ExecutionStatusView, FinalExecutionStatus,
+    MerklePathItem, NearGas, NearToken, PublicKey, ReceiptEnumView, ReceiptView, RpcChunkResponse,
+    RpcTransactionResponse, Signature, SignedTransactionView, TxExecutionStatus,
+};
+
+/// A borrowed view decoding into a generated type.
+pub trait ToOwnedView: Serialize {
+    type Owned: DeserializeOwned;
+
+    /// Decodes the whole view, including the parts kept as JSON, into its
+    /// generated type.
+    fn to_owned_view(&self) -> Result<Self::Owned, serde_json::Error> {
+        serde_json::from_value(serde_json::to_value(self)?)
+    }
+}
+
+/// Borrowed `RpcChunkResponse`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RpcChunkResponseRef<'a> {
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub author: &'a AccountIdRef,
+    pub header: ChunkHeaderView,
+    #[serde(borrow)]
+    pub receipts: Vec<ReceiptViewRef<'a>>,
+    #[serde(borrow)]
+    pub transactions: Vec<SignedTransactionViewRef<'a>>,
+}
+
+impl ToOwnedView for RpcChunkResponseRef<'_> {
+    type Owned = RpcChunkResponse;
+}
+
+/// Borrowed `SignedTransactionView`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignedTransactionViewRef<'a> {
+    #[serde(borrow)]
+    pub actions: Vec<ActionViewRef<'a>>,
+    pub hash: CryptoHash,
+    pub nonce: u64,
+    #[serde(default)]
+    pub priority_fee: u64,
+    pub public_key: PublicKey,
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub receiver_id: &'a AccountIdRef,
+    pub signature: Signature,
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub signer_id: &'a AccountIdRef,
+}
+
+impl ToOwnedView for SignedTransactionViewRef<'_> {
+    type Owned = SignedTransactionView;
+}
+
+/// Borrowed `ActionView`: function calls and deployments are decoded, any
+/// other action is kept as JSON.
+#[derive(Clone, Debug, Serialize)]
+pub enum ActionViewRef<'a> {
+    FunctionCall(FunctionCallActionRef<'a>),
+    DeployContract {
+        /// The deployed code, base64-encoded.
+        code: &'a str,
+    },
+    /// Any other action, e.g. `Transfer` or `Delegate`.
+    #[serde(untagged)]
+    Other(&'a RawValue),
+}
+
+/// Function call of an [`ActionViewRef`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FunctionCallActionRef<'a> {
+    /// The arguments, base64-encoded.
+    pub args: &'a str,
+    pub deposit: NearToken,
+    pub gas: NearGas,
+    #[serde(borrow)]
+    pub method_name: Cow<'a, str>,
+}
+
+#[derive(Deserialize)]
+enum HotAction<'a> {
+    #[serde(borrow)]
+    FunctionCall(FunctionCallActionRef<'a>),
+    DeployContract {
+        code: &'a str,
+    },
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for ActionViewRef<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = <&RawValue>::deserialize(deserializer)?;
+        if !matches!(variant(raw), Some("FunctionCall" | "DeployContract")) {
+            return Ok(Self::Other(raw));
+        }
+        Ok(
+            match serde_json::from_str(raw.get()).map_err(D::Error::custom)? {
+                HotAction::FunctionCall(call) => Self::FunctionCall(call),
+                HotAction::DeployContract { code } => Self::DeployContract { code },
+            },
+        )
+    }
+}
+
+impl ToOwnedView for ActionViewRef<'_> {
+    type Owned = ActionView;
+}
+
+/// Borrowed `ReceiptView`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReceiptViewRef<'a> {
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub predecessor_id: &'a AccountIdRef,
+    #[serde(default)]
+    pub priority: u64,
+    #[serde(borrow)]
+    pub receipt: ReceiptEnumViewRef<'a>,
+    pub receipt_id: CryptoHash,
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub receiver_id: &'a AccountIdRef,
+}
+
+impl ToOwnedView for ReceiptViewRef<'_> {
+    type Owned = ReceiptView;
+}
+
+/// Borrowed `ReceiptEnumView`: action and data receipts are decoded, any
+/// other receipt is kept as JSON.
+#[derive(Clone, Debug, Serialize)]
+pub enum ReceiptEnumViewRef<'a> {
+    Action(ActionReceiptRef<'a>),
+    Data(DataReceiptRef<'a>),
+    /// Any other receipt, e.g. `GlobalContractDistribution`.
+    #[serde(untagged)]
+    Other(&'a RawValue),
+}
+
+/// Action receipt of a [`ReceiptEnumViewRef`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ActionReceiptRef<'a> {
+    #[serde(borrow)]
+    pub actions: Vec<ActionViewRef<'a>>,
+    pub gas_price: NearToken,
+    pub input_data_ids: Vec<CryptoHash>,
+    #[serde(default)]
+    pub is_promise_yield: bool,
+    pub output_data_receivers: Vec<DataReceiverView>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "optional_account_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub refund_to: Option<&'a AccountIdRef>,
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub signer_id: &'a AccountIdRef,
+    pub signer_public_key: PublicKey,
+}
+
+/// Data receipt of a [`ReceiptEnumViewRef`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DataReceiptRef<'a> {
+    /// The data, base64-encoded.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<&'a str>,
+    pub data_id: CryptoHash,
+    #[serde(default)]
+    pub is_promise_resume: bool,
+}
+
+#[derive(Deserialize)]
+enum HotReceipt<'a> {
+    #[serde(borrow)]
+    Action(ActionReceiptRef<'a>),
+    #[serde(borrow)]
+    Data(DataReceiptRef<'a>),
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for ReceiptEnumViewRef<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = <&RawValue>::deserialize(deserializer)?;
+        if !matches!(variant(raw), Some("Action" | "Data")) {
+            return Ok(Self::Other(raw));
+        }
+        Ok(
+            match serde_json::from_str(raw.get()).map_err(D::Error::custom)? {
+                HotReceipt::Action(receipt) => Self::Action(receipt),
+                HotReceipt::Data(receipt) => Self::Data(receipt),
+            },
+        )
+    }
+}
+
+impl ToOwnedView for ReceiptEnumViewRef<'_> {
+    type Owned = ReceiptEnumView;
+}
+
+/// Borrowed `ExecutionOutcomeWithIdView`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExecutionOutcomeWithIdViewRef<'a> {
+    pub block_hash: CryptoHash,
+    pub id: CryptoHash,
+    #[serde(borrow)]
+    pub outcome: ExecutionOutcomeViewRef<'a>,
+    pub proof: Vec<MerklePathItem>,
+}
+
+impl ToOwnedView for ExecutionOutcomeWithIdViewRef<'_> {
+    type Owned = ExecutionOutcomeWithIdView;
+}
+
+/// Borrowed `ExecutionOutcomeView`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExecutionOutcomeViewRef<'a> {
+    #[serde(borrow, deserialize_with = "account_id")]
+    pub executor_id: &'a AccountIdRef,
+    pub gas_burnt: NearGas,
+    #[serde(borrow, deserialize_with = "cow_strs")]
+    pub logs: Vec<Cow<'a, str>>,
+    /// `None` for outcomes of nodes that leave it out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ExecutionMetadataView>,
+    pub receipt_ids: Vec<CryptoHash>,
+    #[serde(borrow)]
+    pub status: ExecutionStatusViewRef<'a>,
+    pub tokens_burnt: NearToken,
+}
+
+impl ToOwnedView for ExecutionOutcomeViewRef<'_> {
+    type Owned = ExecutionOutcomeView;
+}
+
+/// Borrowed `ExecutionStatusView`, with the error of a failure kept as JSON.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum ExecutionStatusViewRef<'a> {
+    Unknown,
+    Failure(#[serde(borrow)] &'a RawValue),
+    /// The returned value, base64-encoded.
+    SuccessValue(&'a str),
+    SuccessReceiptId(CryptoHash),
+}
+
+impl ToOwnedView for ExecutionStatusViewRef<'_> {
+    type Owned = ExecutionStatusView;
+}
+
+/// Borrowed `FinalExecutionStatus`, with the error of a failure kept as JSON.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum FinalExecutionStatusRef<'a> {
+    NotStarted,
+    Started,
+    Failure(#[serde(borrow)] &'a RawValue),
+    /// The returned value, base64-encoded.
+    SuccessValue(&'a str),
+}
+
+impl ToOwnedView for FinalExecutionStatusRef<'_> {
+    type Owned = FinalExecutionStatus;
+}
+
+/// Borrowed `RpcTransactionResponse`. `receipts` are only set by
+/// `EXPERIMENTAL_tx_status`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RpcTransactionResponseRef<'a> {
+    pub final_execution_status: TxExecutionStatus,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub receipts: Vec<ReceiptViewRef<'a>>,
+    #[serde(borrow)]
+    pub receipts_outcome: Vec<ExecutionOutcomeWithIdViewRef<'a>>,
+    #[serde(borrow)]
+    pub status: FinalExecutionStatusRef<'a>,
+    #[serde(borrow)]
+    pub transaction: SignedTransactionViewRef<'a>,
+    #[serde(borrow)]
+    pub transaction_outcome: ExecutionOutcomeWithIdViewRef<'a>,
+}
+
+impl ToOwnedView for RpcTransactionResponseRef<'_> {
+    type Owned = RpcTransactionResponse;
+}
+
+/// Name of the variant of an externally tagged enum, `None` for unit
+/// variants, which are only ever kept as JSON here.
+fn variant(raw: &RawValue) -> Option<&str> {
+    struct FirstKey;
+
+    impl<'de> Visitor<'de> for FirstKey {
+        type Value = Option<&'de str>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("an enum variant")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, _: &str) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let key = map.next_key::<&'de str>()?;
+            if key.is_some() {
+                map.next_value::<IgnoredAny>()?;
+            }
+            while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+            Ok(key)
+        }
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(raw.get());
+    deserializer.deserialize_any(FirstKey).ok().flatten()
+}
+
+// `&AccountIdRef` only deserializes for the lifetime of the deserializer,
+// which derived impls can't name.
+fn account_id<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<&'a AccountIdRef, D::Error> {
+    <&'de AccountIdRef>::deserialize(deserializer)
+}
+
+fn optional_account_id<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<&'a AccountIdRef>, D::Error> {
+    Option::<&'de AccountIdRef>::deserialize(deserializer)
+}
+
+/// Strings borrowed unless the JSON escapes characters in them.
+fn cow_strs<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Cow<'a, str>>, D::Error> {
+    #[derive(Deserialize)]
+    struct CowStr<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    let strs = Vec::<CowStr<'a>>::deserialize(deserializer)?;
+    Ok(strs.into_iter().map(|s| s.0).collect())
+}
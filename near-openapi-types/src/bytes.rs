@@ -0,0 +1,97 @@
+//! Decoding of the fields that hold bytes as base64 strings.
+use std::collections::BTreeMap;
+
+use base64::Engine;
+
+use crate::error::ConversionError;
+use crate::{ContractCodeView, FunctionArgs, StoreKey, StoreValue, ViewStateResult};
+#[cfg(feature = "tx")]
+use crate::{
+    DeployContractAction, DeployGlobalContractAction, ExecutionStatusView, FinalExecutionStatus,
+    FunctionCallAction,
+};
+
+fn decode(data: &str) -> Result<Vec<u8>, ConversionError> {
+    Ok(base64::engine::general_purpose::STANDARD.decode(data)?)
+}
+
+impl FunctionArgs {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.0)
+    }
+}
+
+impl StoreKey {
+    pub fn from_bytes(key: &[u8]) -> Self {
+        Self(base64::engine::general_purpose::STANDARD.encode(key))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.0)
+    }
+}
+
+impl StoreValue {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.0)
+    }
+}
+
+impl ViewStateResult {
+    /// The contract state as a map from keys to values.
+    pub fn to_map(&self) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, ConversionError> {
+        self.values
+            .iter()
+            .map(|item| Ok((item.key.to_bytes()?, item.value.to_bytes()?)))
+            .collect()
+    }
+}
+
+impl ContractCodeView {
+    pub fn code_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.code_base64)
+    }
+}
+
+#[cfg(feature = "tx")]
+impl DeployContractAction {
+    pub fn code_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.code)
+    }
+}
+
+#[cfg(feature = "tx")]
+impl DeployGlobalContractAction {
+    pub fn code_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.code)
+    }
+}
+
+#[cfg(feature = "tx")]
+impl FunctionCallAction {
+    pub fn args_bytes(&self) -> Result<Vec<u8>, ConversionError> {
+        decode(&self.args)
+    }
+}
+
+#[cfg(feature = "tx")]
+impl ExecutionStatusView {
+    /// Value returned by the last action, if it succeeded.
+    pub fn success_value_bytes(&self) -> Option<Result<Vec<u8>, ConversionError>> {
+        match self {
+            Self::SuccessValue(value) => Some(decode(value)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "tx")]
+impl FinalExecutionStatus {
+    /// Value returned by the transaction, if it succeeded.
+    pub fn success_value_bytes(&self) -> Option<Result<Vec<u8>, ConversionError>> {
+        match self {
+            Self::SuccessValue(value) => Some(decode(value)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use base64::Engine;
+
+use crate::error::ConversionError;
+use crate::{
+    AccountId, BlockReference, CallFunctionByBlockIdRequestType, CallFunctionByFinalityRequestType,
+    CallFunctionBySyncCheckpointRequestType, CallResult, FunctionArgs, RpcQueryRequest,
+};
+
+impl FunctionArgs {
+    /// Encodes `args` as the JSON arguments of a function call.
+    pub fn from_json<T: serde::Serialize>(args: &T) -> serde_json::Result<Self> {
+        Ok(Self::from_bytes(&serde_json::to_vec(args)?))
+    }
+
+    pub fn from_bytes(args: &[u8]) -> Self {
+        Self(base64::engine::general_purpose::STANDARD.encode(args))
+    }
+
+    /// Decodes the JSON arguments of a function call.
+    pub fn to_json<T: serde::de::DeserializeOwned>(&self) -> Result<T, ConversionError> {
+        Ok(serde_json::from_slice(&self.to_bytes()?)?)
+    }
+}
+
+impl CallResult {
+    /// Decodes the JSON value returned by the view method.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_slice(&self.result)
+    }
+}
+
+impl RpcQueryRequest {
+    /// Calls the view method `method_name` of the contract `account_id` at
+    /// `block`.
+    pub fn call_function(
+        account_id: AccountId,
+        method_name: impl Into<String>,
+        args_base64: FunctionArgs,
+        block: BlockReference,
+    ) -> Self {
+        let method_name = method_name.into();
+        match block {
+            BlockReference::BlockId(block_id) => Self::CallFunctionByBlockId {
+                account_id,
+                args_base64,
+                block_id,
+                method_name,
+                request_type: CallFunctionByBlockIdRequestType::CallFunction,
+            },
+            BlockReference::Finality(finality) => Self::CallFunctionByFinality {
+                account_id,
+                args_base64,
+                finality,
+                method_name,
+                request_type: CallFunctionByFinalityRequestType::CallFunction,
+            },
+            BlockReference::SyncCheckpoint(sync_checkpoint) => Self::CallFunctionBySyncCheckpoint {
+                account_id,
+                args_base64,
+                method_name,
+                request_type: CallFunctionBySyncCheckpointRequestType::CallFunction,
+                sync_checkpoint,
+            },
+        }
+    }
+}
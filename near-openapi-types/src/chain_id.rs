@@ -0,0 +1,62 @@
+/// Identifier of a NEAR network, as reported in `chain_id` of the node status
+/// and genesis config.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ChainId {
+    Mainnet,
+    Testnet,
+    /// Any other network: localnet, sandbox, private shards, etc.
+    Custom(alloc::string::String),
+}
+
+impl ChainId {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mainnet => "mainnet",
+            Self::Testnet => "testnet",
+            Self::Custom(chain_id) => chain_id,
+        }
+    }
+}
+
+impl core::str::FromStr for ChainId {
+    type Err = core::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl From<&str> for ChainId {
+    fn from(value: &str) -> Self {
+        match value {
+            "mainnet" => Self::Mainnet,
+            "testnet" => Self::Testnet,
+            other => Self::Custom(other.into()),
+        }
+    }
+}
+
+impl core::fmt::Display for ChainId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for ChainId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ChainId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = alloc::string::String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
@@ -0,0 +1,187 @@
+//! Chain signatures: signing payloads of other chains with the MPC contract
+//! (`v1.signer` on mainnet).
+//!
+//! The contract signs a 32-byte payload, e.g. the hash of an Ethereum
+//! transaction, with a secp256k1 key derived from its root key, the account
+//! calling it and a `path` chosen by that account. `derive_public_key`,
+//! which requires the `signing` feature, computes the derived public key
+//! offline, e.g. to find the address funds must be sent to before anything
+//! is signed.
+//!
+//! ```
+//! # use near_openapi_types::{chain_signatures::{self, SignRequest}, tx::TransactionBuilder, CryptoHash};
+//! let tx = TransactionBuilder::new(
+//!     "alice.near".parse().unwrap(),
+//!     "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap(),
+//!     chain_signatures::MAINNET_CONTRACT.parse().unwrap(),
+//! )
+//! .mpc_sign(&SignRequest::new([7; 32], "ethereum-1"))
+//! .build(7, CryptoHash::default());
+//! ```
+use crate::error::ConversionError;
+use crate::tx::TransactionBuilder;
+#[cfg(feature = "signing")]
+use crate::{AccountId, PublicKey};
+use crate::{FinalExecutionStatus, FunctionArgs, NearGas, NearToken};
+
+/// The MPC contract on mainnet.
+pub const MAINNET_CONTRACT: &str = "v1.signer";
+/// The MPC contract on testnet.
+pub const TESTNET_CONTRACT: &str = "v1.signer-prod.testnet";
+
+/// Gas to attach to `sign`, which waits for the MPC nodes to respond.
+pub const SIGN_GAS: NearGas = NearGas::from_tgas(250);
+/// Deposit to attach to `sign`.
+pub const SIGN_DEPOSIT: NearToken = NearToken::from_yoctonear(1);
+
+/// Request to sign `payload` with the key derived for `path`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SignRequest {
+    pub payload: [u8; 32],
+    pub path: String,
+    /// Version of the root key; 0 unless the contract rotated it.
+    pub key_version: u32,
+}
+
+impl SignRequest {
+    pub fn new(payload: [u8; 32], path: impl Into<String>) -> Self {
+        Self {
+            payload,
+            path: path.into(),
+            key_version: 0,
+        }
+    }
+
+    pub fn key_version(mut self, key_version: u32) -> Self {
+        self.key_version = key_version;
+        self
+    }
+
+    /// Arguments of the `sign` call.
+    pub fn args(&self) -> FunctionArgs {
+        FunctionArgs::from_json(&SignArgs {
+            request: self.clone(),
+        })
+        .expect("sign request is always serializable")
+    }
+}
+
+/// Arguments of `sign`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SignArgs {
+    pub request: SignRequest,
+}
+
+impl TransactionBuilder {
+    /// Calls `sign` of the MPC contract, which must be the receiver of the
+    /// transaction.
+    pub fn mpc_sign(self, request: &SignRequest) -> Self {
+        self.function_call("sign", request.args(), SIGN_GAS, SIGN_DEPOSIT)
+    }
+}
+
+/// A secp256k1 signature returned by `sign`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SignatureResponse {
+    pub big_r: AffinePoint,
+    pub s: Scalar,
+    pub recovery_id: u8,
+}
+
+/// Curve point as the hex of its compressed SEC1 encoding.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AffinePoint {
+    pub affine_point: String,
+}
+
+/// Scalar as the hex of its 32 big-endian bytes.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Scalar {
+    pub scalar: String,
+}
+
+impl SignatureResponse {
+    /// Reads the signature returned by a `sign` transaction.
+    pub fn from_status(status: &FinalExecutionStatus) -> Result<Self, ConversionError> {
+        let value = status
+            .success_value_bytes()
+            .ok_or("sign transaction did not succeed")??;
+        Ok(serde_json::from_slice(&value)?)
+    }
+
+    /// The signature as `r`, `s` and the recovery id, the layout Ethereum
+    /// transactions use.
+    pub fn to_bytes(&self) -> Result<[u8; 65], ConversionError> {
+        let big_r = decode_hex(&self.big_r.affine_point)?;
+        let s = decode_hex(&self.s.scalar)?;
+        if big_r.len() != 33 || s.len() != 32 {
+            return Err("signature has an invalid length".into());
+        }
+        let mut signature = [0; 65];
+        signature[..32].copy_from_slice(&big_r[1..]);
+        signature[32..64].copy_from_slice(&s);
+        signature[64] = self.recovery_id;
+        Ok(signature)
+    }
+}
+
+fn decode_hex(data: &str) -> Result<Vec<u8>, ConversionError> {
+    if !data.len().is_multiple_of(2) {
+        return Err("hex has an odd length".into());
+    }
+    (0..data.len())
+        .step_by(2)
+        .map(|i| {
+            data.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| "invalid hex".into())
+        })
+        .collect()
+}
+
+/// Prefix of the string hashed into the tweak of the root key.
+#[cfg(feature = "signing")]
+pub const EPSILON_DERIVATION_PREFIX: &str = "near-mpc-recovery v0.1.0 epsilon derivation:";
+
+/// Tweak added to the root secret key for `predecessor_id` and `path`, as a
+/// big-endian scalar.
+#[cfg(feature = "signing")]
+pub fn derive_epsilon(predecessor_id: &AccountId, path: &str) -> [u8; 32] {
+    use sha3::Digest;
+    sha3::Sha3_256::digest(format!(
+        "{}{},{}",
+        EPSILON_DERIVATION_PREFIX, predecessor_id, path
+    ))
+    .into()
+}
+
+/// Public key of the key that signs requests of `predecessor_id` for `path`,
+/// derived from the `root` public key of the contract, which its
+/// `public_key` view method returns.
+#[cfg(feature = "signing")]
+pub fn derive_public_key(
+    root: &PublicKey,
+    predecessor_id: &AccountId,
+    path: &str,
+) -> Result<PublicKey, ConversionError> {
+    use k256::elliptic_curve::PrimeField;
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+    let PublicKey::Secp256k1(data) = root else {
+        return Err("root key is not a secp256k1 key".into());
+    };
+    let mut point = vec![0x04];
+    point.extend(data);
+    let root = k256::PublicKey::from_sec1_bytes(&point).map_err(|_| "invalid root key")?;
+    let epsilon = Option::<k256::Scalar>::from(k256::Scalar::from_repr(
+        derive_epsilon(predecessor_id, path).into(),
+    ))
+    .ok_or("epsilon is out of range")?;
+    let derived = (k256::ProjectivePoint::GENERATOR * epsilon + root.to_projective()).to_affine();
+    // Uncompressed point without the leading 0x04.
+    Ok(PublicKey::Secp256k1(
+        derived.to_encoded_point(false).as_bytes()[1..]
+            .try_into()
+            .expect("uncompressed point is 65 bytes long"),
+    ))
+}
@@ -0,0 +1,163 @@
+//! Compact encoding of transactions for QR codes and NFC, e.g. to pass an
+//! unsigned transaction from an online wallet to an offline signer and the
+//! signed one back.
+//!
+//! The envelope holds a version byte, a byte telling whether the transaction
+//! is signed, the borsh encoding of the transaction and the first 4 bytes of
+//! the SHA-256 hash of everything before them, so that misread codes are
+//! rejected. It is written as text either in base45 (RFC 9285), which fits the
+//! alphanumeric mode of QR codes, or in unpadded base64url.
+//!
+//! ```
+//! # use near_openapi_types::{compact::{CompactTransaction, TextEncoding}, tx::TransactionBuilder, CryptoHash, NearToken};
+//! let tx = TransactionBuilder::new(
+//!     "alice.near".parse().unwrap(),
+//!     "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap(),
+//!     "bob.near".parse().unwrap(),
+//! )
+//! .transfer(NearToken::from_near(1))
+//! .build(7, CryptoHash::default());
+//! let text = CompactTransaction::Unsigned(tx.clone())
+//!     .encode(TextEncoding::Base45)
+//!     .unwrap();
+//! assert_eq!(
+//!     CompactTransaction::decode(&text, TextEncoding::Base45).unwrap(),
+//!     CompactTransaction::Unsigned(tx)
+//! );
+//! ```
+use std::io;
+
+use base64::Engine;
+
+use crate::tx::{SignedTransaction, Transaction, sha256};
+
+/// Version of the envelope written by [`CompactTransaction::to_bytes`].
+pub const VERSION: u8 = 1;
+
+const CHECKSUM_LEN: usize = 4;
+
+const BASE45_ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// A transaction on its way to or back from a signer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CompactTransaction {
+    Unsigned(Transaction),
+    Signed(SignedTransaction),
+}
+
+/// Text form of the envelope.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextEncoding {
+    /// Base45, for the alphanumeric mode of QR codes.
+    Base45,
+    /// Unpadded base64url, for NFC records and URLs.
+    Base64Url,
+}
+
+impl CompactTransaction {
+    /// The binary envelope, e.g. for byte mode QR codes or NFC.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let (kind, payload) = match self {
+            Self::Unsigned(transaction) => (0, transaction.encode()?),
+            Self::Signed(transaction) => (1, transaction.encode()?),
+        };
+        let mut data = vec![VERSION, kind];
+        data.extend(payload);
+        let checksum = sha256(&data);
+        data.extend(&checksum.0[..CHECKSUM_LEN]);
+        Ok(data)
+    }
+
+    /// Reads the binary envelope, checking its version and checksum.
+    pub fn from_bytes(data: &[u8]) -> io::Result<Self> {
+        if data.len() < 2 + CHECKSUM_LEN {
+            return Err(invalid_data("envelope is too short"));
+        }
+        let (data, checksum) = data.split_at(data.len() - CHECKSUM_LEN);
+        if sha256(data).0[..CHECKSUM_LEN] != *checksum {
+            return Err(invalid_data("checksum mismatch"));
+        }
+        match data {
+            [VERSION, 0, payload @ ..] => Transaction::decode(payload).map(Self::Unsigned),
+            [VERSION, 1, payload @ ..] => SignedTransaction::decode(payload).map(Self::Signed),
+            [VERSION, _, ..] => Err(invalid_data("unknown transaction kind")),
+            _ => Err(invalid_data("unsupported envelope version")),
+        }
+    }
+
+    /// The envelope as text.
+    pub fn encode(&self, encoding: TextEncoding) -> io::Result<String> {
+        let data = self.to_bytes()?;
+        Ok(match encoding {
+            TextEncoding::Base45 => base45_encode(&data),
+            TextEncoding::Base64Url => {
+                base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(data)
+            }
+        })
+    }
+
+    /// Reads the envelope from text written by [`encode`](Self::encode).
+    pub fn decode(text: &str, encoding: TextEncoding) -> io::Result<Self> {
+        let data = match encoding {
+            TextEncoding::Base45 => base45_decode(text)?,
+            TextEncoding::Base64Url => base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .decode(text)
+                .map_err(|_| invalid_data("invalid base64url"))?,
+        };
+        Self::from_bytes(&data)
+    }
+}
+
+impl From<Transaction> for CompactTransaction {
+    fn from(value: Transaction) -> Self {
+        Self::Unsigned(value)
+    }
+}
+
+impl From<SignedTransaction> for CompactTransaction {
+    fn from(value: SignedTransaction) -> Self {
+        Self::Signed(value)
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Every 2 bytes become 3 characters, a trailing byte becomes 2.
+fn base45_encode(data: &[u8]) -> String {
+    let mut text = String::with_capacity(data.len().div_ceil(2) * 3);
+    for chunk in data.chunks(2) {
+        let (mut value, digits) = match chunk {
+            [a, b] => (usize::from(*a) << 8 | usize::from(*b), 3),
+            [a] => (usize::from(*a), 2),
+            _ => unreachable!(),
+        };
+        for _ in 0..digits {
+            text.push(char::from(BASE45_ALPHABET[value % 45]));
+            value /= 45;
+        }
+    }
+    text
+}
+
+fn base45_decode(text: &str) -> io::Result<Vec<u8>> {
+    let digits = text
+        .bytes()
+        .map(|c| BASE45_ALPHABET.iter().position(|&d| d == c))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| invalid_data("invalid base45 character"))?;
+    let mut data = Vec::with_capacity(digits.len() / 3 * 2 + 1);
+    for chunk in digits.chunks(3) {
+        let value = chunk
+            .iter()
+            .rev()
+            .fold(0, |value, digit| value * 45 + digit);
+        match chunk.len() {
+            3 if value <= 0xffff => data.extend([(value >> 8) as u8, value as u8]),
+            2 if value <= 0xff => data.push(value as u8),
+            _ => return Err(invalid_data("invalid base45")),
+        }
+    }
+    Ok(data)
+}
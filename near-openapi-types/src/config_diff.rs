@@ -0,0 +1,136 @@
+//! Changes of the runtime config between two protocol versions.
+//!
+//! The fee tables of `EXPERIMENTAL_protocol_config` nest a few levels deep,
+//! e.g. `transaction_costs.action_creation_config.transfer_cost.execution`.
+//! [`RuntimeConfigView::diff`] compares every value of two configs, so that a
+//! change of `EXPERIMENTAL_protocol_config` output can raise an alert naming
+//! the fees that changed:
+//!
+//! ```
+//! # use near_openapi_types::{NearToken, RuntimeConfigView};
+//! let old = RuntimeConfigView::default();
+//! let new = RuntimeConfigView {
+//!     storage_amount_per_byte: Some(NearToken::from_yoctonear(10u128.pow(19))),
+//!     ..old.clone()
+//! };
+//! let diff = old.diff(&new);
+//! assert_eq!(diff.changes[0].path, "storage_amount_per_byte");
+//! assert_eq!(diff.changes[0].old, None);
+//! ```
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::RuntimeConfigView;
+
+/// A value of the config that differs, as returned by
+/// [`RuntimeConfigView::diff`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigChange {
+    /// Field names from the top of the config, joined with `.`.
+    pub path: String,
+    /// JSON of the old value, `None` if the old config leaves it out.
+    pub old: Option<Value>,
+    /// JSON of the new value, `None` if the new config leaves it out.
+    pub new: Option<Value>,
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "(none)".to_string(),
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            show(&self.old),
+            show(&self.new)
+        )
+    }
+}
+
+/// Changes between two configs, sorted by path.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConfigDiff {
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ConfigDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The change of the value at `path`, if it changed.
+    pub fn get(&self, path: &str) -> Option<&ConfigChange> {
+        self.changes.iter().find(|change| change.path == path)
+    }
+
+    /// Changes at `prefix` or below it, e.g. all of `wasm_config.ext_costs`.
+    pub fn under<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a ConfigChange> {
+        self.changes.iter().filter(move |change| {
+            change
+                .path
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    }
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl RuntimeConfigView {
+    /// Lists the values that differ between `self` and `other`. Objects are
+    /// compared field by field down to their values; lists are compared as
+    /// a whole.
+    pub fn diff(&self, other: &RuntimeConfigView) -> ConfigDiff {
+        let old = serde_json::to_value(self).expect("config serializes to JSON");
+        let new = serde_json::to_value(other).expect("config serializes to JSON");
+        let mut changes = Vec::new();
+        diff_values(String::new(), Some(&old), Some(&new), &mut changes);
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        ConfigDiff { changes }
+    }
+}
+
+fn diff_values(
+    path: String,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    changes: &mut Vec<ConfigChange>,
+) {
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            for (key, old_value) in old {
+                diff_values(join(&path, key), Some(old_value), new.get(key), changes);
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    diff_values(join(&path, key), None, Some(new_value), changes);
+                }
+            }
+        }
+        (old, new) if old != new => changes.push(ConfigChange {
+            path,
+            old: old.cloned(),
+            new: new.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
@@ -0,0 +1,190 @@
+//! Following data between receipts, for cross-contract call analysis.
+//!
+//! When a contract chains promises, e.g. a call with a `.then()` callback,
+//! the receipt of the call lists a [`DataReceiverView`] in its
+//! `output_data_receivers` for every receipt waiting for its result. Once it
+//! is executed, its result is sent in a Data receipt with that `data_id`,
+//! and the waiting Action receipt lists the same `data_id` in its
+//! `input_data_ids`. A [`DataFlow`] pairs the three up by `data_id`:
+//!
+//! ```
+//! # use near_openapi_types::{data_flow::DataFlow, ReceiptView};
+//! # fn example(receipts: &[ReceiptView]) {
+//! let flow = DataFlow::new(receipts);
+//! for receipt in receipts {
+//!     for callback in flow.consumers(receipt) {
+//!         println!("{} -> {}", receipt.receipt_id, callback.receipt_id);
+//!     }
+//! }
+//! # }
+//! ```
+use std::collections::BTreeMap;
+
+use crate::{ActionView, CryptoHash, DataReceiverView, ReceiptEnumView, ReceiptView};
+
+impl ReceiptView {
+    /// Actions of an Action receipt, `None` for other receipts.
+    pub fn actions(&self) -> Option<&[ActionView]> {
+        match &self.receipt {
+            ReceiptEnumView::Action { actions, .. } => Some(actions),
+            _ => None,
+        }
+    }
+
+    /// Data an Action receipt waits for before it is executed, empty for
+    /// other receipts.
+    pub fn input_data_ids(&self) -> &[CryptoHash] {
+        match &self.receipt {
+            ReceiptEnumView::Action { input_data_ids, .. } => input_data_ids,
+            _ => &[],
+        }
+    }
+
+    /// Receipts an Action receipt sends its result to, empty for other
+    /// receipts.
+    pub fn output_data_receivers(&self) -> &[DataReceiverView] {
+        match &self.receipt {
+            ReceiptEnumView::Action {
+                output_data_receivers,
+                ..
+            } => output_data_receivers,
+            _ => &[],
+        }
+    }
+
+    /// Id of the data a Data receipt carries, `None` for other receipts.
+    pub fn data_id(&self) -> Option<&CryptoHash> {
+        match &self.receipt {
+            ReceiptEnumView::Data { data_id, .. } => Some(data_id),
+            _ => None,
+        }
+    }
+}
+
+impl DataReceiverView {
+    /// Whether `receipt` is the Data receipt this receiver expects: it has
+    /// the same `data_id` and is sent to `receiver_id`.
+    pub fn is_fulfilled_by(&self, receipt: &ReceiptView) -> bool {
+        receipt.data_id() == Some(&self.data_id) && receipt.receiver_id == self.receiver_id
+    }
+}
+
+/// The receipts a piece of data passes through, as far as they were seen by
+/// [`DataFlow::new`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataDependency<'a> {
+    pub data_id: &'a CryptoHash,
+    /// Action receipt whose result the data is.
+    pub producer: Option<&'a ReceiptView>,
+    /// Where `producer` promised to send the data.
+    pub receiver: Option<&'a DataReceiverView>,
+    /// Data receipt carrying the result.
+    pub data: Option<&'a ReceiptView>,
+    /// Action receipt waiting for the data.
+    pub consumer: Option<&'a ReceiptView>,
+}
+
+impl DataDependency<'_> {
+    /// Whether all receipts were seen and the Data receipt went where the
+    /// producer promised.
+    pub fn is_complete(&self) -> bool {
+        match (self.producer, self.receiver, self.data, self.consumer) {
+            (Some(_), Some(receiver), Some(data), Some(consumer)) => {
+                receiver.is_fulfilled_by(data) && consumer.receiver_id == receiver.receiver_id
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Receipts paired up by the data they exchange. See the
+/// [module documentation](self).
+#[derive(Clone, Debug, Default)]
+pub struct DataFlow<'a> {
+    dependencies: BTreeMap<&'a CryptoHash, DataDependency<'a>>,
+}
+
+impl<'a> DataFlow<'a> {
+    /// Pairs up `receipts`, e.g. the receipts of a transaction from
+    /// `EXPERIMENTAL_tx_status` or of a range of chunks.
+    pub fn new(receipts: impl IntoIterator<Item = &'a ReceiptView>) -> Self {
+        let mut flow = Self::default();
+        for receipt in receipts {
+            for receiver in receipt.output_data_receivers() {
+                let dependency = flow.entry(&receiver.data_id);
+                dependency.producer = Some(receipt);
+                dependency.receiver = Some(receiver);
+            }
+            if let Some(data_id) = receipt.data_id() {
+                flow.entry(data_id).data = Some(receipt);
+            }
+            for data_id in receipt.input_data_ids() {
+                flow.entry(data_id).consumer = Some(receipt);
+            }
+        }
+        flow
+    }
+
+    pub fn get(&self, data_id: &CryptoHash) -> Option<&DataDependency<'a>> {
+        self.dependencies.get(data_id)
+    }
+
+    /// All dependencies, ordered by `data_id`.
+    pub fn iter(&self) -> impl Iterator<Item = &DataDependency<'a>> {
+        self.dependencies.values()
+    }
+
+    /// Dependencies `receipt` waits for, in the order of its
+    /// `input_data_ids`.
+    pub fn inputs<'s>(
+        &'s self,
+        receipt: &'s ReceiptView,
+    ) -> impl Iterator<Item = &'s DataDependency<'a>> {
+        receipt
+            .input_data_ids()
+            .iter()
+            .filter_map(|data_id| self.get(data_id))
+    }
+
+    /// Dependencies `receipt` sends its result to, in the order of its
+    /// `output_data_receivers`.
+    pub fn outputs<'s>(
+        &'s self,
+        receipt: &'s ReceiptView,
+    ) -> impl Iterator<Item = &'s DataDependency<'a>> {
+        receipt
+            .output_data_receivers()
+            .iter()
+            .filter_map(|receiver| self.get(&receiver.data_id))
+    }
+
+    /// Receipts whose results `receipt` waits for.
+    pub fn producers<'s>(
+        &'s self,
+        receipt: &'s ReceiptView,
+    ) -> impl Iterator<Item = &'a ReceiptView> + 's {
+        self.inputs(receipt)
+            .filter_map(|dependency| dependency.producer)
+    }
+
+    /// Receipts waiting for the result of `receipt`, e.g. its callbacks.
+    pub fn consumers<'s>(
+        &'s self,
+        receipt: &'s ReceiptView,
+    ) -> impl Iterator<Item = &'a ReceiptView> + 's {
+        self.outputs(receipt)
+            .filter_map(|dependency| dependency.consumer)
+    }
+
+    fn entry(&mut self, data_id: &'a CryptoHash) -> &mut DataDependency<'a> {
+        self.dependencies
+            .entry(data_id)
+            .or_insert_with(|| DataDependency {
+                data_id,
+                producer: None,
+                receiver: None,
+                data: None,
+                consumer: None,
+            })
+    }
+}
@@ -0,0 +1,68 @@
+//! Descriptions of the OpenAPI spec, by generated type and member.
+//!
+//! The spec explains many types, fields and variants, e.g. that
+//! `BlockHeaderView::timestamp` is a legacy number. These explanations end up
+//! in the rustdoc of the generated types and are also embedded here, so
+//! documentation UIs or error messages can show them at runtime:
+//!
+//! ```
+//! # use near_openapi_types::descriptions::descriptions;
+//! let description = descriptions().member("BlockHeaderView", "prev_hash");
+//! assert_eq!(description, Some("The hash of the previous Block"));
+//! ```
+//!
+//! Types are named as in this crate. Fields of struct variants are named
+//! `Variant.field`.
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+/// Descriptions of a single type.
+///
+/// `members` maps field and variant names to their description. Members
+/// without a description in the spec are left out.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TypeDescription {
+    pub description: Option<String>,
+    pub members: BTreeMap<String, String>,
+}
+
+/// Descriptions of all generated types, as returned by [`descriptions`].
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(transparent)]
+pub struct Descriptions {
+    types: BTreeMap<String, TypeDescription>,
+}
+
+impl Descriptions {
+    /// Descriptions of `type_name`, if the spec describes the type or any
+    /// of its members.
+    pub fn get(&self, type_name: &str) -> Option<&TypeDescription> {
+        self.types.get(type_name)
+    }
+
+    /// Description of the type `type_name` itself.
+    pub fn type_description(&self, type_name: &str) -> Option<&str> {
+        self.get(type_name)?.description.as_deref()
+    }
+
+    /// Description of the field or variant `member` of `type_name`.
+    pub fn member(&self, type_name: &str, member: &str) -> Option<&str> {
+        self.get(type_name)?.members.get(member).map(String::as_str)
+    }
+
+    /// All described types, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &TypeDescription)> {
+        self.types
+            .iter()
+            .map(|(type_name, description)| (type_name.as_str(), description))
+    }
+}
+
+/// Descriptions embedded in this version of the crate.
+pub fn descriptions() -> &'static Descriptions {
+    static DESCRIPTIONS: OnceLock<Descriptions> = OnceLock::new();
+    DESCRIPTIONS.get_or_init(|| {
+        serde_json::from_str(include_str!("descriptions.json"))
+            .expect("embedded descriptions are valid")
+    })
+}
@@ -1,14 +1,14 @@
 #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
-pub struct ConversionError(::std::borrow::Cow<'static, str>);
-impl ::std::error::Error for ConversionError {}
-impl ::std::fmt::Display for ConversionError {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
-        ::std::fmt::Display::fmt(&self.0, f)
+pub struct ConversionError(::alloc::borrow::Cow<'static, str>);
+impl ::core::error::Error for ConversionError {}
+impl ::core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        ::core::fmt::Display::fmt(&self.0, f)
     }
 }
-impl ::std::fmt::Debug for ConversionError {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
-        ::std::fmt::Debug::fmt(&self.0, f)
+impl ::core::fmt::Debug for ConversionError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.0, f)
     }
 }
 impl From<&'static str> for ConversionError {
@@ -18,11 +18,21 @@ impl From<&'static str> for ConversionError {
 }
 impl From<bs58::decode::Error> for ConversionError {
     fn from(value: bs58::decode::Error) -> Self {
-        Self(format!("Base58 decode error: {}", value).into())
+        Self(::alloc::format!("Base58 decode error: {}", value).into())
     }
 }
-impl From<String> for ConversionError {
-    fn from(value: String) -> Self {
+impl From<alloc::string::String> for ConversionError {
+    fn from(value: alloc::string::String) -> Self {
         Self(value.into())
     }
 }
+impl From<base64::DecodeError> for ConversionError {
+    fn from(value: base64::DecodeError) -> Self {
+        Self(::alloc::format!("Base64 decode error: {}", value).into())
+    }
+}
+impl From<serde_json::Error> for ConversionError {
+    fn from(value: serde_json::Error) -> Self {
+        Self(::alloc::format!("JSON decode error: {}", value).into())
+    }
+}
@@ -0,0 +1,134 @@
+//! ETH-implicit accounts: NEAR accounts named after the EVM address of a
+//! secp256k1 key, e.g. `0xb794f5ea0ba39494ce839613fffba74279579268`.
+//!
+//! The address is the last 20 bytes of the Keccak-256 hash of the public
+//! key. Account ids are lowercase, while EVM tools usually show addresses with
+//! the EIP-55 mixed-case checksum, which [`EvmAddress`] parses and displays.
+//!
+//! ```
+//! # use near_openapi_types::eth_implicit::EvmAddress;
+//! let address: EvmAddress = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse().unwrap();
+//! assert_eq!(address.account_id().as_str(), "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+//! assert_eq!(address.to_string(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+//! ```
+use sha3::{Digest, Keccak256};
+
+use crate::error::ConversionError;
+use crate::{AccountId, PublicKey};
+#[cfg(feature = "tx")]
+use crate::{NearToken, tx::TransactionBuilder};
+
+/// A 20-byte EVM address.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EvmAddress(pub [u8; 20]);
+
+impl EvmAddress {
+    /// Address of a `secp256k1:...` public key.
+    pub fn from_public_key(public_key: &PublicKey) -> Result<Self, ConversionError> {
+        let PublicKey::Secp256k1(data) = public_key else {
+            return Err("ETH-implicit accounts need a secp256k1 key".into());
+        };
+        let hash = Keccak256::digest(data);
+        Ok(Self(hash[12..].try_into().unwrap()))
+    }
+
+    /// Address of an ETH-implicit account, or `None` for other accounts.
+    pub fn from_account_id(account_id: &AccountId) -> Option<Self> {
+        let hex = account_id.as_str().strip_prefix("0x")?;
+        if hex.len() != 40 {
+            return None;
+        }
+        decode_hex(hex).ok()
+    }
+
+    /// The ETH-implicit account of this address.
+    pub fn account_id(&self) -> AccountId {
+        format!("0x{}", encode_hex(&self.0))
+            .parse()
+            .expect("0x followed by 40 hex digits is a valid account id")
+    }
+
+    /// The address with the EIP-55 checksum: each letter is uppercase if the
+    /// matching nibble of the Keccak-256 hash of the lowercase hex is 8 or
+    /// more.
+    pub fn to_checksum_string(&self) -> String {
+        let hex = encode_hex(&self.0);
+        let hash = Keccak256::digest(hex.as_bytes());
+        let checksummed: String = hex
+            .char_indices()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0xf;
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        format!("0x{}", checksummed)
+    }
+}
+
+/// Parses a `0x`-prefixed address. Mixed-case addresses must have a valid
+/// EIP-55 checksum; all-lowercase and all-uppercase ones carry none.
+impl core::str::FromStr for EvmAddress {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix("0x")
+            .ok_or("EVM address does not start with 0x")?;
+        if hex.len() != 40 {
+            return Err("EVM address is not 20 bytes long".into());
+        }
+        let address = decode_hex(hex)?;
+        let mixed_case = hex.chars().any(|c| c.is_ascii_lowercase())
+            && hex.chars().any(|c| c.is_ascii_uppercase());
+        if mixed_case && address.to_checksum_string() != s {
+            return Err("EVM address has an invalid checksum".into());
+        }
+        Ok(address)
+    }
+}
+
+impl core::fmt::Display for EvmAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_checksum_string())
+    }
+}
+
+impl From<EvmAddress> for AccountId {
+    fn from(value: EvmAddress) -> Self {
+        value.account_id()
+    }
+}
+
+#[cfg(feature = "tx")]
+impl TransactionBuilder {
+    /// Builder of a transfer of `deposit` to the ETH-implicit account of
+    /// `address`, which is created by the transfer if it doesn't exist yet.
+    pub fn transfer_to_evm_address(
+        signer_id: AccountId,
+        public_key: PublicKey,
+        address: EvmAddress,
+        deposit: NearToken,
+    ) -> Self {
+        Self::new(signer_id, public_key, address.account_id()).transfer(deposit)
+    }
+}
+
+fn encode_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes the 40 hex digits of an address.
+fn decode_hex(hex: &str) -> Result<EvmAddress, ConversionError> {
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err("EVM address is not hex".into());
+    }
+    let mut address = [0; 20];
+    for (i, byte) in address.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    Ok(EvmAddress(address))
+}
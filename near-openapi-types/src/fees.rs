@@ -0,0 +1,273 @@
+//! Fee previews for transactions, from the gas price of a block and the fee
+//! tables of the runtime config at that block, without talking to a node.
+//!
+//! The estimate follows how the runtime charges a transaction: the send fees
+//! of its receipt and actions are burnt when it is converted, and the
+//! execution fees and the gas attached to function calls are prepaid at the
+//! same gas price. Unused prepaid gas is refunded later, so the estimate is
+//! an upper bound for function calls.
+//!
+//! ```rust,ignore
+//! let estimator = FeeEstimator::new(runtime_config.transaction_costs.unwrap(), header.gas_price);
+//! let estimate = estimator.estimate_transaction(&transaction)?;
+//! println!("{} gas, {} in fees, {} in total", estimate.gas, estimate.gas_cost, estimate.total);
+//! ```
+use crate::error::ConversionError;
+use crate::tx::{Action, Transaction};
+use crate::{
+    AccessKeyPermission, ActionCreationConfigView, Fee, NearGas, NearToken, NonDelegateAction,
+    RuntimeFeesConfigView,
+};
+
+/// Error of the estimates of a [`FeeEstimator`].
+#[derive(Debug)]
+pub enum FeeError {
+    /// The runtime config has no fee for something the transaction does.
+    MissingFee(&'static str),
+    /// The runtime config has no fee table for this kind of action.
+    UnsupportedAction(&'static str),
+    /// The code or the arguments of an action are not valid base64.
+    Encoding(ConversionError),
+}
+
+impl std::fmt::Display for FeeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingFee(name) => write!(f, "runtime config has no {} fee", name),
+            Self::UnsupportedAction(name) => write!(f, "no fee table for {} actions", name),
+            Self::Encoding(err) => write!(f, "invalid action: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FeeError {}
+
+/// Cost of a transaction, returned by [`FeeEstimator::estimate_transaction`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// Gas burnt for sending and executing the actions, plus the gas
+    /// attached to function calls.
+    pub gas: NearGas,
+    /// `gas` at the gas price of the estimator.
+    pub gas_cost: NearToken,
+    /// Tokens attached to transfers and function calls. Those of the actions
+    /// inside delegate actions are left out, their sender pays them.
+    pub deposit: NearToken,
+    /// What the signer needs to have: `gas_cost` plus `deposit`.
+    pub total: NearToken,
+}
+
+/// Fee tables and gas price of a block, e.g. the `transaction_costs` of the
+/// runtime config and the `gas_price` of the header of a block.
+#[derive(Clone, Debug)]
+pub struct FeeEstimator {
+    fees: RuntimeFeesConfigView,
+    gas_price: NearToken,
+}
+
+/// Gas and deposit of some actions.
+#[derive(Default)]
+struct Cost {
+    gas: u64,
+    deposit: u128,
+}
+
+impl Cost {
+    fn add_fee(&mut self, fee: &Fee, sir: bool) {
+        let send = if sir { fee.send_sir } else { fee.send_not_sir };
+        self.add_gas(send.as_gas().saturating_add(fee.execution.as_gas()));
+    }
+
+    fn add_fee_per_byte(&mut self, fee: &Fee, sir: bool, bytes: usize) {
+        let mut per_byte = Cost::default();
+        per_byte.add_fee(fee, sir);
+        self.add_gas(per_byte.gas.saturating_mul(bytes as u64));
+    }
+
+    fn add_gas(&mut self, gas: u64) {
+        self.gas = self.gas.saturating_add(gas);
+    }
+}
+
+impl FeeEstimator {
+    pub fn new(fees: RuntimeFeesConfigView, gas_price: NearToken) -> Self {
+        Self { fees, gas_price }
+    }
+
+    pub fn gas_price(&self) -> NearToken {
+        self.gas_price
+    }
+
+    /// Cost of a transaction with `actions`, assuming its receiver is another
+    /// account than its signer, which costs the most.
+    pub fn estimate_total_cost(&self, actions: &[Action]) -> Result<FeeEstimate, FeeError> {
+        self.estimate(actions, false)
+    }
+
+    /// Cost of `transaction`. Sending to itself is cheaper for an account.
+    pub fn estimate_transaction(&self, transaction: &Transaction) -> Result<FeeEstimate, FeeError> {
+        self.estimate(
+            &transaction.actions,
+            transaction.signer_id == transaction.receiver_id,
+        )
+    }
+
+    fn estimate(&self, actions: &[Action], sir: bool) -> Result<FeeEstimate, FeeError> {
+        let mut cost = Cost::default();
+        cost.add_fee(self.receipt_fee()?, sir);
+        for action in actions {
+            match action {
+                Action::NonDelegate(action) => self.add_action(&mut cost, action, sir)?,
+                Action::Delegate(signed) => {
+                    let delegate = &signed.delegate_action;
+                    cost.add_fee(self.fee(|fees| &fees.delegate_cost, "delegate")?, sir);
+                    // The inner actions go out in a receipt of their own.
+                    let inner_sir = delegate.sender_id == delegate.receiver_id;
+                    cost.add_fee(self.receipt_fee()?, inner_sir);
+                    // Their deposits are paid by the sender of the delegate
+                    // action, only their gas by the signer.
+                    let mut inner = Cost::default();
+                    for action in &delegate.actions {
+                        self.add_action(&mut inner, action, inner_sir)?;
+                    }
+                    cost.add_gas(inner.gas);
+                }
+            }
+        }
+        let gas_cost = self
+            .gas_price
+            .as_yoctonear()
+            .saturating_mul(cost.gas as u128);
+        Ok(FeeEstimate {
+            gas: NearGas::from_gas(cost.gas),
+            gas_cost: NearToken::from_yoctonear(gas_cost),
+            deposit: NearToken::from_yoctonear(cost.deposit),
+            total: NearToken::from_yoctonear(gas_cost.saturating_add(cost.deposit)),
+        })
+    }
+
+    fn add_action(
+        &self,
+        cost: &mut Cost,
+        action: &NonDelegateAction,
+        sir: bool,
+    ) -> Result<(), FeeError> {
+        match action {
+            NonDelegateAction::CreateAccount(_) => {
+                cost.add_fee(
+                    self.fee(|fees| &fees.create_account_cost, "create_account")?,
+                    sir,
+                );
+            }
+            NonDelegateAction::DeployContract(action) => {
+                let code = action.code_bytes().map_err(FeeError::Encoding)?;
+                cost.add_fee(
+                    self.fee(|fees| &fees.deploy_contract_cost, "deploy_contract")?,
+                    sir,
+                );
+                cost.add_fee_per_byte(
+                    self.fee(
+                        |fees| &fees.deploy_contract_cost_per_byte,
+                        "deploy_contract_per_byte",
+                    )?,
+                    sir,
+                    code.len(),
+                );
+            }
+            NonDelegateAction::FunctionCall(action) => {
+                let args = action.args_bytes().map_err(FeeError::Encoding)?;
+                cost.add_fee(
+                    self.fee(|fees| &fees.function_call_cost, "function_call")?,
+                    sir,
+                );
+                cost.add_fee_per_byte(
+                    self.fee(
+                        |fees| &fees.function_call_cost_per_byte,
+                        "function_call_per_byte",
+                    )?,
+                    sir,
+                    action.method_name.len() + args.len(),
+                );
+                cost.add_gas(action.gas.as_gas());
+                cost.deposit = cost.deposit.saturating_add(action.deposit.as_yoctonear());
+            }
+            NonDelegateAction::Transfer(action) => {
+                cost.add_fee(self.fee(|fees| &fees.transfer_cost, "transfer")?, sir);
+                cost.deposit = cost.deposit.saturating_add(action.deposit.as_yoctonear());
+            }
+            NonDelegateAction::Stake(_) => {
+                cost.add_fee(self.fee(|fees| &fees.stake_cost, "stake")?, sir);
+            }
+            NonDelegateAction::AddKey(action) => {
+                let add_key = self
+                    .action_fees()?
+                    .add_key_cost
+                    .as_ref()
+                    .ok_or(FeeError::MissingFee("add_key"))?;
+                match &action.access_key.permission {
+                    AccessKeyPermission::FullAccess => cost.add_fee(&add_key.full_access_cost, sir),
+                    AccessKeyPermission::FunctionCall(permission) => {
+                        cost.add_fee(&add_key.function_call_cost, sir);
+                        cost.add_fee_per_byte(
+                            &add_key.function_call_cost_per_byte,
+                            sir,
+                            permission.method_names.iter().map(String::len).sum(),
+                        );
+                    }
+                }
+            }
+            NonDelegateAction::DeleteKey(_) => {
+                cost.add_fee(self.fee(|fees| &fees.delete_key_cost, "delete_key")?, sir);
+            }
+            NonDelegateAction::DeleteAccount(_) => {
+                cost.add_fee(
+                    self.fee(|fees| &fees.delete_account_cost, "delete_account")?,
+                    sir,
+                );
+            }
+            NonDelegateAction::DeployGlobalContract(_) => {
+                return Err(FeeError::UnsupportedAction("DeployGlobalContract"));
+            }
+            NonDelegateAction::UseGlobalContract(_) => {
+                return Err(FeeError::UnsupportedAction("UseGlobalContract"));
+            }
+            NonDelegateAction::DeterministicStateInit(_) => {
+                return Err(FeeError::UnsupportedAction("DeterministicStateInit"));
+            }
+            NonDelegateAction::AddGasKey(_) => {
+                return Err(FeeError::UnsupportedAction("AddGasKey"));
+            }
+            NonDelegateAction::DeleteGasKey(_) => {
+                return Err(FeeError::UnsupportedAction("DeleteGasKey"));
+            }
+            NonDelegateAction::TransferToGasKey(_) => {
+                return Err(FeeError::UnsupportedAction("TransferToGasKey"));
+            }
+        }
+        Ok(())
+    }
+
+    fn receipt_fee(&self) -> Result<&Fee, FeeError> {
+        self.fees
+            .action_receipt_creation_config
+            .as_ref()
+            .ok_or(FeeError::MissingFee("action_receipt_creation"))
+    }
+
+    fn action_fees(&self) -> Result<&ActionCreationConfigView, FeeError> {
+        self.fees
+            .action_creation_config
+            .as_ref()
+            .ok_or(FeeError::MissingFee("action_creation"))
+    }
+
+    fn fee(
+        &self,
+        field: impl FnOnce(&ActionCreationConfigView) -> &Option<Fee>,
+        name: &'static str,
+    ) -> Result<&Fee, FeeError> {
+        field(self.action_fees()?)
+            .as_ref()
+            .ok_or(FeeError::MissingFee(name))
+    }
+}
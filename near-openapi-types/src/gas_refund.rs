@@ -0,0 +1,102 @@
+//! Penalty on gas refunds (NEP-536), to compute fee breakdowns client-side.
+//!
+//! Since protocol version [`REFUND_PENALTY_PROTOCOL_VERSION`], part of the
+//! unused prepaid gas of a receipt is not refunded: the penalty is a fraction
+//! of the refund, at least a fixed amount of gas and at most the whole
+//! refund. Its cost is burnt with the receipt, which is why `tokens_burnt` of
+//! an outcome can exceed `gas_burnt` times the gas price.
+//!
+//! ```
+//! # use near_openapi_types::{gas_refund::RefundPenaltyConfig, NearGas};
+//! let config = RefundPenaltyConfig::for_protocol_version(78);
+//! let refund = config.refund(NearGas::from_tgas(100), NearGas::from_tgas(20));
+//! assert_eq!(refund.penalty, NearGas::from_tgas(4));
+//! assert_eq!(refund.net, NearGas::from_tgas(76));
+//! ```
+use crate::{NearGas, NearToken};
+
+/// First protocol version charging the penalty.
+pub const REFUND_PENALTY_PROTOCOL_VERSION: u32 = 78;
+
+/// Parameters of the penalty, which are part of the runtime config.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RefundPenaltyConfig {
+    /// Fraction of the refund charged, as `numerator / denominator`.
+    pub numerator: u64,
+    pub denominator: u64,
+    /// Least penalty, unless the refund itself is smaller.
+    pub min_penalty: NearGas,
+}
+
+impl RefundPenaltyConfig {
+    /// No penalty, as before [`REFUND_PENALTY_PROTOCOL_VERSION`].
+    pub const NONE: Self = Self {
+        numerator: 0,
+        denominator: 1,
+        min_penalty: NearGas::from_gas(0),
+    };
+
+    /// The penalty of mainnet and testnet since
+    /// [`REFUND_PENALTY_PROTOCOL_VERSION`]: 5% of the refund and at least
+    /// 1 Tgas.
+    pub const NEP_536: Self = Self {
+        numerator: 5,
+        denominator: 100,
+        min_penalty: NearGas::from_tgas(1),
+    };
+
+    pub fn for_protocol_version(protocol_version: u32) -> Self {
+        if protocol_version >= REFUND_PENALTY_PROTOCOL_VERSION {
+            Self::NEP_536
+        } else {
+            Self::NONE
+        }
+    }
+
+    /// Penalty taken from a refund of `gross_refund`.
+    pub fn penalty(&self, gross_refund: NearGas) -> NearGas {
+        let gross_refund = gross_refund.as_gas();
+        let relative = (u128::from(gross_refund) * u128::from(self.numerator)
+            / u128::from(self.denominator)) as u64;
+        NearGas::from_gas(relative.max(self.min_penalty.as_gas()).min(gross_refund))
+    }
+
+    /// Refund of a receipt with `prepaid` gas of which `used` was used,
+    /// including the gas of the receipts it created.
+    pub fn refund(&self, prepaid: NearGas, used: NearGas) -> GasRefund {
+        let gross = prepaid.saturating_sub(used);
+        let penalty = self.penalty(gross);
+        GasRefund {
+            gross,
+            penalty,
+            net: gross.saturating_sub(penalty),
+        }
+    }
+}
+
+/// Unused gas of a receipt and how much of it is refunded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GasRefund {
+    /// Unused prepaid gas.
+    pub gross: NearGas,
+    /// Part of `gross` that is burnt instead of refunded.
+    pub penalty: NearGas,
+    /// Gas refunded to the signer.
+    pub net: NearGas,
+}
+
+impl GasRefund {
+    /// Tokens refunded when gas is bought at `gas_price`.
+    pub fn net_tokens(&self, gas_price: NearToken) -> NearToken {
+        tokens(self.net, gas_price)
+    }
+
+    /// Tokens burnt for the penalty when gas is bought at `gas_price`.
+    pub fn penalty_tokens(&self, gas_price: NearToken) -> NearToken {
+        tokens(self.penalty, gas_price)
+    }
+}
+
+fn tokens(gas: NearGas, gas_price: NearToken) -> NearToken {
+    gas_price.saturating_mul(u128::from(gas.as_gas()))
+}
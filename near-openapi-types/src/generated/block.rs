@@ -0,0 +1,1180 @@
+//! Blocks, their headers and light client proofs.
+use crate::*;
+
+#[doc = "A part of a state for the current head of a light client. More info [here](https://nomicon.io/ChainSpec/LightClient)."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"A part of a state for the current head of a light client. More info [here](https://nomicon.io/ChainSpec/LightClient).\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_merkle_root\","]
+#[doc = "    \"epoch_id\","]
+#[doc = "    \"height\","]
+#[doc = "    \"next_bp_hash\","]
+#[doc = "    \"next_epoch_id\","]
+#[doc = "    \"outcome_root\","]
+#[doc = "    \"prev_state_root\","]
+#[doc = "    \"timestamp\","]
+#[doc = "    \"timestamp_nanosec\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_merkle_root\": {"]
+#[doc = "      \"description\": \"The merkle root of all the block hashes\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"epoch_id\": {"]
+#[doc = "      \"description\": \"The epoch to which the block that is the current known head belongs\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"height\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"next_bp_hash\": {"]
+#[doc = "      \"description\": \"The hash of the block producers set for the next epoch\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"next_epoch_id\": {"]
+#[doc = "      \"description\": \"The epoch that will follow the current epoch\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"outcome_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"prev_state_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"timestamp\": {"]
+#[doc = "      \"description\": \"Legacy json number. Should not be used.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"timestamp_nanosec\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BlockHeaderInnerLiteView {
+    #[doc = "The merkle root of all the block hashes"]
+    pub block_merkle_root: CryptoHash,
+    #[doc = "The epoch to which the block that is the current known head belongs"]
+    pub epoch_id: CryptoHash,
+    pub height: u64,
+    #[doc = "The hash of the block producers set for the next epoch"]
+    pub next_bp_hash: CryptoHash,
+    #[doc = "The epoch that will follow the current epoch"]
+    pub next_epoch_id: CryptoHash,
+    pub outcome_root: CryptoHash,
+    pub prev_state_root: CryptoHash,
+    #[doc = "Legacy json number. Should not be used."]
+    pub timestamp: u64,
+    pub timestamp_nanosec: ::std::string::String,
+}
+impl limits::FieldLimits for BlockHeaderInnerLiteView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("timestamp", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&BlockHeaderInnerLiteView> for BlockHeaderInnerLiteView {
+    fn from(value: &BlockHeaderInnerLiteView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Contains main info about the block."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Contains main info about the block.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"approvals\","]
+#[doc = "    \"block_merkle_root\","]
+#[doc = "    \"challenges_result\","]
+#[doc = "    \"challenges_root\","]
+#[doc = "    \"chunk_headers_root\","]
+#[doc = "    \"chunk_mask\","]
+#[doc = "    \"chunk_receipts_root\","]
+#[doc = "    \"chunk_tx_root\","]
+#[doc = "    \"chunks_included\","]
+#[doc = "    \"epoch_id\","]
+#[doc = "    \"gas_price\","]
+#[doc = "    \"hash\","]
+#[doc = "    \"height\","]
+#[doc = "    \"last_ds_final_block\","]
+#[doc = "    \"last_final_block\","]
+#[doc = "    \"latest_protocol_version\","]
+#[doc = "    \"next_bp_hash\","]
+#[doc = "    \"next_epoch_id\","]
+#[doc = "    \"outcome_root\","]
+#[doc = "    \"prev_hash\","]
+#[doc = "    \"prev_state_root\","]
+#[doc = "    \"random_value\","]
+#[doc = "    \"signature\","]
+#[doc = "    \"timestamp\","]
+#[doc = "    \"timestamp_nanosec\","]
+#[doc = "    \"total_supply\","]
+#[doc = "    \"validator_proposals\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"approvals\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"anyOf\": ["]
+#[doc = "          {"]
+#[doc = "            \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "          },"]
+#[doc = "          {"]
+#[doc = "            \"type\": \"null\""]
+#[doc = "          }"]
+#[doc = "        ]"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"block_body_hash\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"block_merkle_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"block_ordinal\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"challenges_result\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/SlashedValidator\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"challenges_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"chunk_endorsements\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"array\","]
+#[doc = "        \"items\": {"]
+#[doc = "          \"type\": \"integer\","]
+#[doc = "          \"format\": \"uint8\","]
+#[doc = "          \"maximum\": 255.0,"]
+#[doc = "          \"minimum\": 0.0"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"chunk_headers_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"chunk_mask\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"boolean\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"chunk_receipts_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"chunk_tx_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"chunks_included\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"epoch_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"epoch_sync_data_hash\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gas_price\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"height\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"last_ds_final_block\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"last_final_block\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"latest_protocol_version\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"next_bp_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"next_epoch_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"outcome_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"prev_hash\": {"]
+#[doc = "      \"description\": \"The hash of the previous Block\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"prev_height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"prev_state_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"random_value\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"rent_paid\": {"]
+#[doc = "      \"description\": \"TODO(2271): deprecated.\","]
+#[doc = "      \"default\": \"0\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"signature\": {"]
+#[doc = "      \"description\": \"Signature of the block producer.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"timestamp\": {"]
+#[doc = "      \"description\": \"Legacy json number. Should not be used.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"timestamp_nanosec\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"total_supply\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"validator_proposals\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorStakeView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"validator_reward\": {"]
+#[doc = "      \"description\": \"TODO(2271): deprecated.\","]
+#[doc = "      \"default\": \"0\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BlockHeaderView {
+    pub approvals: ::std::vec::Vec<::std::option::Option<Signature>>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_body_hash: ::std::option::Option<CryptoHash>,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub block_merkle_root: CryptoHash,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_ordinal: ::std::option::Option<u64>,
+    #[doc = "Legacy field, see [`crate::legacy`]."]
+    #[serde(default)]
+    pub challenges_result: ::std::vec::Vec<SlashedValidator>,
+    #[doc = "Legacy field, see [`crate::legacy`]."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub challenges_root: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_endorsements: ::std::option::Option<::std::vec::Vec<::std::vec::Vec<u8>>>,
+    pub chunk_headers_root: CryptoHash,
+    pub chunk_mask: ::std::vec::Vec<bool>,
+    pub chunk_receipts_root: CryptoHash,
+    pub chunk_tx_root: CryptoHash,
+    pub chunks_included: u64,
+    pub epoch_id: CryptoHash,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_sync_data_hash: ::std::option::Option<CryptoHash>,
+    pub gas_price: NearToken,
+    pub hash: CryptoHash,
+    pub height: u64,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub last_ds_final_block: CryptoHash,
+    pub last_final_block: CryptoHash,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub latest_protocol_version: u32,
+    pub next_bp_hash: CryptoHash,
+    pub next_epoch_id: CryptoHash,
+    pub outcome_root: CryptoHash,
+    #[doc = "The hash of the previous Block"]
+    pub prev_hash: CryptoHash,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub prev_height: ::std::option::Option<u64>,
+    pub prev_state_root: CryptoHash,
+    pub random_value: CryptoHash,
+    #[doc = "TODO(2271): deprecated."]
+    #[serde(default = "defaults::block_header_view_rent_paid")]
+    pub rent_paid: NearToken,
+    #[doc = "Signature of the block producer."]
+    pub signature: Signature,
+    #[doc = "Legacy json number. Should not be used."]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub timestamp_nanosec: ::std::string::String,
+    pub total_supply: NearToken,
+    pub validator_proposals: ::std::vec::Vec<ValidatorStakeView>,
+    #[doc = "TODO(2271): deprecated."]
+    #[serde(default = "defaults::block_header_view_validator_reward")]
+    pub validator_reward: NearToken,
+}
+impl limits::FieldLimits for BlockHeaderView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("block_ordinal", "uint64", Some(0), None),
+        limits::FieldLimit::integer("chunks_included", "uint64", Some(0), None),
+        limits::FieldLimit::integer("height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("latest_protocol_version", "uint32", Some(0), None),
+        limits::FieldLimit::integer("prev_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("timestamp", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&BlockHeaderView> for BlockHeaderView {
+    fn from(value: &BlockHeaderView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Height and hash of a block"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Height and hash of a block\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"hash\","]
+#[doc = "    \"height\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"height\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BlockStatusView {
+    pub hash: CryptoHash,
+    pub height: u64,
+}
+impl limits::FieldLimits for BlockStatusView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "height",
+        "uint64",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&BlockStatusView> for BlockStatusView {
+    fn from(value: &BlockStatusView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`LightClientBlockLiteView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"inner_lite\","]
+#[doc = "    \"inner_rest_hash\","]
+#[doc = "    \"prev_block_hash\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"inner_lite\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/BlockHeaderInnerLiteView\""]
+#[doc = "    },"]
+#[doc = "    \"inner_rest_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"prev_block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LightClientBlockLiteView {
+    pub inner_lite: BlockHeaderInnerLiteView,
+    pub inner_rest_hash: CryptoHash,
+    pub prev_block_hash: CryptoHash,
+}
+impl ::std::convert::From<&LightClientBlockLiteView> for LightClientBlockLiteView {
+    fn from(value: &LightClientBlockLiteView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcBlockRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcBlockRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"block_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"block_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"finality\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"finality\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"sync_checkpoint\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"sync_checkpoint\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RpcBlockRequest {
+    #[serde(rename = "block_id")]
+    BlockId(BlockId),
+    #[serde(rename = "finality")]
+    Finality(Finality),
+    #[serde(rename = "sync_checkpoint")]
+    SyncCheckpoint(SyncCheckpoint),
+}
+impl ::std::convert::From<&Self> for RpcBlockRequest {
+    fn from(value: &RpcBlockRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<BlockId> for RpcBlockRequest {
+    fn from(value: BlockId) -> Self {
+        Self::BlockId(value)
+    }
+}
+impl ::std::convert::From<Finality> for RpcBlockRequest {
+    fn from(value: Finality) -> Self {
+        Self::Finality(value)
+    }
+}
+impl ::std::convert::From<SyncCheckpoint> for RpcBlockRequest {
+    fn from(value: SyncCheckpoint) -> Self {
+        Self::SyncCheckpoint(value)
+    }
+}
+#[doc = "`RpcBlockResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"author\","]
+#[doc = "    \"chunks\","]
+#[doc = "    \"header\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"author\": {"]
+#[doc = "      \"description\": \"The AccountId of the author of the Block\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"chunks\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ChunkHeaderView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"header\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/BlockHeaderView\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcBlockResponse {
+    #[doc = "The AccountId of the author of the Block"]
+    pub author: AccountId,
+    pub chunks: ::std::vec::Vec<ChunkHeaderView>,
+    pub header: BlockHeaderView,
+}
+impl ::std::convert::From<&RpcBlockResponse> for RpcBlockResponse {
+    fn from(value: &RpcBlockResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcGasPriceRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcGasPriceRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_id\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcGasPriceRequest {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_id: ::std::option::Option<BlockId>,
+}
+impl ::std::convert::From<&RpcGasPriceRequest> for RpcGasPriceRequest {
+    fn from(value: &RpcGasPriceRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RpcGasPriceRequest {
+    fn default() -> Self {
+        Self {
+            block_id: Default::default(),
+        }
+    }
+}
+#[doc = "`RpcGasPriceResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"gas_price\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"gas_price\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcGasPriceResponse {
+    pub gas_price: NearToken,
+}
+impl ::std::convert::From<&RpcGasPriceResponse> for RpcGasPriceResponse {
+    fn from(value: &RpcGasPriceResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcLightClientBlockProofRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcLightClientBlockProofRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_hash\","]
+#[doc = "    \"light_client_head\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"light_client_head\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcLightClientBlockProofRequest {
+    pub block_hash: CryptoHash,
+    pub light_client_head: CryptoHash,
+}
+impl ::std::convert::From<&RpcLightClientBlockProofRequest> for RpcLightClientBlockProofRequest {
+    fn from(value: &RpcLightClientBlockProofRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcLightClientBlockProofResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_header_lite\","]
+#[doc = "    \"block_proof\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_header_lite\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/LightClientBlockLiteView\""]
+#[doc = "    },"]
+#[doc = "    \"block_proof\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/MerklePathItem\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcLightClientBlockProofResponse {
+    pub block_header_lite: LightClientBlockLiteView,
+    pub block_proof: ::std::vec::Vec<MerklePathItem>,
+}
+impl ::std::convert::From<&RpcLightClientBlockProofResponse> for RpcLightClientBlockProofResponse {
+    fn from(value: &RpcLightClientBlockProofResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcLightClientExecutionProofRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcLightClientExecutionProofRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"sender_id\","]
+#[doc = "        \"transaction_hash\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"sender_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"transaction_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"transaction\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"receipt_id\","]
+#[doc = "        \"receiver_id\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"receipt_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"receiver_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"receipt\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ],"]
+#[doc = "  \"required\": ["]
+#[doc = "    \"light_client_head\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"light_client_head\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum RpcLightClientExecutionProofRequest {
+    Variant0 {
+        light_client_head: CryptoHash,
+        sender_id: AccountId,
+        transaction_hash: CryptoHash,
+        #[serde(rename = "type")]
+        type_: RpcLightClientExecutionProofRequestVariant0Type,
+    },
+    Variant1 {
+        light_client_head: CryptoHash,
+        receipt_id: CryptoHash,
+        receiver_id: AccountId,
+        #[serde(rename = "type")]
+        type_: RpcLightClientExecutionProofRequestVariant1Type,
+    },
+}
+impl ::std::convert::From<&Self> for RpcLightClientExecutionProofRequest {
+    fn from(value: &RpcLightClientExecutionProofRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcLightClientExecutionProofRequestVariant0Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"transaction\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RpcLightClientExecutionProofRequestVariant0Type {
+    #[serde(rename = "transaction")]
+    Transaction,
+}
+impl ::std::convert::From<&Self> for RpcLightClientExecutionProofRequestVariant0Type {
+    fn from(value: &RpcLightClientExecutionProofRequestVariant0Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for RpcLightClientExecutionProofRequestVariant0Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Transaction => f.write_str("transaction"),
+        }
+    }
+}
+impl ::std::str::FromStr for RpcLightClientExecutionProofRequestVariant0Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "transaction" => Ok(Self::Transaction),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for RpcLightClientExecutionProofRequestVariant0Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String>
+    for RpcLightClientExecutionProofRequestVariant0Type
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String>
+    for RpcLightClientExecutionProofRequestVariant0Type
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`RpcLightClientExecutionProofRequestVariant1Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"receipt\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RpcLightClientExecutionProofRequestVariant1Type {
+    #[serde(rename = "receipt")]
+    Receipt,
+}
+impl ::std::convert::From<&Self> for RpcLightClientExecutionProofRequestVariant1Type {
+    fn from(value: &RpcLightClientExecutionProofRequestVariant1Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for RpcLightClientExecutionProofRequestVariant1Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Receipt => f.write_str("receipt"),
+        }
+    }
+}
+impl ::std::str::FromStr for RpcLightClientExecutionProofRequestVariant1Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "receipt" => Ok(Self::Receipt),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for RpcLightClientExecutionProofRequestVariant1Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String>
+    for RpcLightClientExecutionProofRequestVariant1Type
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String>
+    for RpcLightClientExecutionProofRequestVariant1Type
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`RpcLightClientExecutionProofResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_header_lite\","]
+#[doc = "    \"block_proof\","]
+#[doc = "    \"outcome_proof\","]
+#[doc = "    \"outcome_root_proof\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_header_lite\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/LightClientBlockLiteView\""]
+#[doc = "    },"]
+#[doc = "    \"block_proof\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/MerklePathItem\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"outcome_proof\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ExecutionOutcomeWithIdView\""]
+#[doc = "    },"]
+#[doc = "    \"outcome_root_proof\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/MerklePathItem\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcLightClientExecutionProofResponse {
+    pub block_header_lite: LightClientBlockLiteView,
+    pub block_proof: ::std::vec::Vec<MerklePathItem>,
+    pub outcome_proof: ExecutionOutcomeWithIdView,
+    pub outcome_root_proof: ::std::vec::Vec<MerklePathItem>,
+}
+impl ::std::convert::From<&RpcLightClientExecutionProofResponse>
+    for RpcLightClientExecutionProofResponse
+{
+    fn from(value: &RpcLightClientExecutionProofResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcLightClientNextBlockRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcLightClientNextBlockRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"last_block_hash\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"last_block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcLightClientNextBlockRequest {
+    pub last_block_hash: CryptoHash,
+}
+impl ::std::convert::From<&RpcLightClientNextBlockRequest> for RpcLightClientNextBlockRequest {
+    fn from(value: &RpcLightClientNextBlockRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "A state for the current head of a light client. More info [here](https://nomicon.io/ChainSpec/LightClient)."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"A state for the current head of a light client. More info [here](https://nomicon.io/ChainSpec/LightClient).\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"approvals_after_next\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"anyOf\": ["]
+#[doc = "          {"]
+#[doc = "            \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "          },"]
+#[doc = "          {"]
+#[doc = "            \"type\": \"null\""]
+#[doc = "          }"]
+#[doc = "        ]"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"inner_lite\": {"]
+#[doc = "      \"description\": \"Inner part of the block header that gets hashed, split into two parts, one that is sent\\n   to light clients, and the rest\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockHeaderInnerLiteView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"inner_rest_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"next_block_inner_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"next_bps\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorStakeView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"prev_block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcLightClientNextBlockResponse {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub approvals_after_next: ::std::vec::Vec<::std::option::Option<Signature>>,
+    #[doc = "Inner part of the block header that gets hashed, split into two parts, one that is sent\n   to light clients, and the rest"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub inner_lite: ::std::option::Option<BlockHeaderInnerLiteView>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub inner_rest_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub next_block_inner_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub next_bps: ::std::option::Option<::std::vec::Vec<ValidatorStakeView>>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub prev_block_hash: ::std::option::Option<CryptoHash>,
+}
+impl ::std::convert::From<&RpcLightClientNextBlockResponse> for RpcLightClientNextBlockResponse {
+    fn from(value: &RpcLightClientNextBlockResponse) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RpcLightClientNextBlockResponse {
+    fn default() -> Self {
+        Self {
+            approvals_after_next: Default::default(),
+            inner_lite: Default::default(),
+            inner_rest_hash: Default::default(),
+            next_block_inner_hash: Default::default(),
+            next_bps: Default::default(),
+            prev_block_hash: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,3920 @@
+//! State changes and the requests listing them.
+use crate::*;
+
+#[doc = "`AccountChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `AccountChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"account_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum AccountChangesType {
+    #[serde(rename = "account_changes")]
+    AccountChanges,
+}
+impl ::std::convert::From<&Self> for AccountChangesType {
+    fn from(value: &AccountChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for AccountChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AccountChanges => f.write_str("account_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for AccountChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "account_changes" => Ok(Self::AccountChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for AccountChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for AccountChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for AccountChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`AccountChangesType`]."]
+pub type AccountChangesByBlockIdChangesType = AccountChangesType;
+#[doc = "Alias of [`AccountChangesType`]."]
+pub type AccountChangesByFinalityChangesType = AccountChangesType;
+#[doc = "Alias of [`AccountChangesType`]."]
+pub type AccountChangesBySyncCheckpointChangesType = AccountChangesType;
+#[doc = "`AllAccessKeyChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `AllAccessKeyChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"all_access_key_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum AllAccessKeyChangesType {
+    #[serde(rename = "all_access_key_changes")]
+    AllAccessKeyChanges,
+}
+impl ::std::convert::From<&Self> for AllAccessKeyChangesType {
+    fn from(value: &AllAccessKeyChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for AllAccessKeyChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AllAccessKeyChanges => f.write_str("all_access_key_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for AllAccessKeyChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "all_access_key_changes" => Ok(Self::AllAccessKeyChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for AllAccessKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for AllAccessKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for AllAccessKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`AllAccessKeyChangesType`]."]
+pub type AllAccessKeyChangesByBlockIdChangesType = AllAccessKeyChangesType;
+#[doc = "Alias of [`AllAccessKeyChangesType`]."]
+pub type AllAccessKeyChangesByFinalityChangesType = AllAccessKeyChangesType;
+#[doc = "Alias of [`AllAccessKeyChangesType`]."]
+pub type AllAccessKeyChangesBySyncCheckpointChangesType = AllAccessKeyChangesType;
+#[doc = "`AllGasKeyChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `AllGasKeyChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"all_gas_key_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum AllGasKeyChangesType {
+    #[serde(rename = "all_gas_key_changes")]
+    AllGasKeyChanges,
+}
+impl ::std::convert::From<&Self> for AllGasKeyChangesType {
+    fn from(value: &AllGasKeyChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for AllGasKeyChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AllGasKeyChanges => f.write_str("all_gas_key_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for AllGasKeyChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "all_gas_key_changes" => Ok(Self::AllGasKeyChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for AllGasKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for AllGasKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for AllGasKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`AllGasKeyChangesType`]."]
+pub type AllGasKeyChangesByBlockIdChangesType = AllGasKeyChangesType;
+#[doc = "Alias of [`AllGasKeyChangesType`]."]
+pub type AllGasKeyChangesByFinalityChangesType = AllGasKeyChangesType;
+#[doc = "Alias of [`AllGasKeyChangesType`]."]
+pub type AllGasKeyChangesBySyncCheckpointChangesType = AllGasKeyChangesType;
+#[doc = "`ContractCodeChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `ContractCodeChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"contract_code_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ContractCodeChangesType {
+    #[serde(rename = "contract_code_changes")]
+    ContractCodeChanges,
+}
+impl ::std::convert::From<&Self> for ContractCodeChangesType {
+    fn from(value: &ContractCodeChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for ContractCodeChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::ContractCodeChanges => f.write_str("contract_code_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for ContractCodeChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "contract_code_changes" => Ok(Self::ContractCodeChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for ContractCodeChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for ContractCodeChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for ContractCodeChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`ContractCodeChangesType`]."]
+pub type ContractCodeChangesByBlockIdChangesType = ContractCodeChangesType;
+#[doc = "Alias of [`ContractCodeChangesType`]."]
+pub type ContractCodeChangesByFinalityChangesType = ContractCodeChangesType;
+#[doc = "Alias of [`ContractCodeChangesType`]."]
+pub type ContractCodeChangesBySyncCheckpointChangesType = ContractCodeChangesType;
+#[doc = "`DataChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `DataChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"data_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DataChangesType {
+    #[serde(rename = "data_changes")]
+    DataChanges,
+}
+impl ::std::convert::From<&Self> for DataChangesType {
+    fn from(value: &DataChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for DataChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::DataChanges => f.write_str("data_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for DataChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "data_changes" => Ok(Self::DataChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for DataChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for DataChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for DataChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`DataChangesType`]."]
+pub type DataChangesByBlockIdChangesType = DataChangesType;
+#[doc = "Alias of [`DataChangesType`]."]
+pub type DataChangesByFinalityChangesType = DataChangesType;
+#[doc = "Alias of [`DataChangesType`]."]
+pub type DataChangesBySyncCheckpointChangesType = DataChangesType;
+#[doc = "It is a [serializable view] of [`StateChangesRequest`].\n\n[serializable view]: ./index.html\n[`StateChangesRequest`]: ../types/struct.StateChangesRequest.html"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcStateChangesInBlockByTypeRequest\","]
+#[doc = "  \"description\": \"It is a [serializable view] of [`StateChangesRequest`].\\n\\n[serializable view]: ./index.html\\n[`StateChangesRequest`]: ../types/struct.StateChangesRequest.html\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"title\": \"account_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"account_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"single_access_key_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"keys\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"single_access_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"keys\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountWithPublicKey\""]
+#[doc = "              }"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"single_gas_key_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"keys\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"single_gas_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"keys\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountWithPublicKey\""]
+#[doc = "              }"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"all_access_key_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"all_access_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"all_gas_key_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"all_gas_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"contract_code_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"contract_code_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"data_changes_by_block_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"block_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"block_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"key_prefix_base64\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"data_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"key_prefix_base64\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"account_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"account_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"single_access_key_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"keys\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"single_access_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"keys\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountWithPublicKey\""]
+#[doc = "              }"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"single_gas_key_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"keys\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"single_gas_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"keys\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountWithPublicKey\""]
+#[doc = "              }"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"all_access_key_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"all_access_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"all_gas_key_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"all_gas_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"contract_code_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"contract_code_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"data_changes_by_finality\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"finality\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"finality\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"key_prefix_base64\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"data_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"key_prefix_base64\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"account_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"account_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"single_access_key_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"keys\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"single_access_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"keys\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountWithPublicKey\""]
+#[doc = "              }"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"single_gas_key_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"keys\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"single_gas_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"keys\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountWithPublicKey\""]
+#[doc = "              }"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"all_access_key_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"all_access_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"all_gas_key_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"all_gas_key_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"contract_code_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"contract_code_changes\""]
+#[doc = "              ]"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"data_changes_by_sync_checkpoint\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"sync_checkpoint\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"sync_checkpoint\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_ids\","]
+#[doc = "            \"changes_type\","]
+#[doc = "            \"key_prefix_base64\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"changes_type\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"enum\": ["]
+#[doc = "                \"data_changes\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"key_prefix_base64\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum RpcStateChangesInBlockByTypeRequest {
+    AccountChangesByBlockId {
+        account_ids: ::std::vec::Vec<AccountId>,
+        block_id: BlockId,
+        changes_type: AccountChangesType,
+    },
+    SingleAccessKeyChangesByBlockId {
+        block_id: BlockId,
+        changes_type: SingleAccessKeyChangesType,
+        keys: ::std::vec::Vec<AccountWithPublicKey>,
+    },
+    SingleGasKeyChangesByBlockId {
+        block_id: BlockId,
+        changes_type: SingleGasKeyChangesType,
+        keys: ::std::vec::Vec<AccountWithPublicKey>,
+    },
+    AllAccessKeyChangesByBlockId {
+        account_ids: ::std::vec::Vec<AccountId>,
+        block_id: BlockId,
+        changes_type: AllAccessKeyChangesType,
+    },
+    AllGasKeyChangesByBlockId {
+        account_ids: ::std::vec::Vec<AccountId>,
+        block_id: BlockId,
+        changes_type: AllGasKeyChangesType,
+    },
+    ContractCodeChangesByBlockId {
+        account_ids: ::std::vec::Vec<AccountId>,
+        block_id: BlockId,
+        changes_type: ContractCodeChangesType,
+    },
+    DataChangesByBlockId {
+        account_ids: ::std::vec::Vec<AccountId>,
+        block_id: BlockId,
+        changes_type: DataChangesType,
+        key_prefix_base64: StoreKey,
+    },
+    AccountChangesByFinality {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: AccountChangesType,
+        finality: Finality,
+    },
+    SingleAccessKeyChangesByFinality {
+        changes_type: SingleAccessKeyChangesType,
+        finality: Finality,
+        keys: ::std::vec::Vec<AccountWithPublicKey>,
+    },
+    SingleGasKeyChangesByFinality {
+        changes_type: SingleGasKeyChangesType,
+        finality: Finality,
+        keys: ::std::vec::Vec<AccountWithPublicKey>,
+    },
+    AllAccessKeyChangesByFinality {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: AllAccessKeyChangesType,
+        finality: Finality,
+    },
+    AllGasKeyChangesByFinality {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: AllGasKeyChangesType,
+        finality: Finality,
+    },
+    ContractCodeChangesByFinality {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: ContractCodeChangesType,
+        finality: Finality,
+    },
+    DataChangesByFinality {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: DataChangesType,
+        finality: Finality,
+        key_prefix_base64: StoreKey,
+    },
+    AccountChangesBySyncCheckpoint {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: AccountChangesType,
+        sync_checkpoint: SyncCheckpoint,
+    },
+    SingleAccessKeyChangesBySyncCheckpoint {
+        changes_type: SingleAccessKeyChangesType,
+        keys: ::std::vec::Vec<AccountWithPublicKey>,
+        sync_checkpoint: SyncCheckpoint,
+    },
+    SingleGasKeyChangesBySyncCheckpoint {
+        changes_type: SingleGasKeyChangesType,
+        keys: ::std::vec::Vec<AccountWithPublicKey>,
+        sync_checkpoint: SyncCheckpoint,
+    },
+    AllAccessKeyChangesBySyncCheckpoint {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: AllAccessKeyChangesType,
+        sync_checkpoint: SyncCheckpoint,
+    },
+    AllGasKeyChangesBySyncCheckpoint {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: AllGasKeyChangesType,
+        sync_checkpoint: SyncCheckpoint,
+    },
+    ContractCodeChangesBySyncCheckpoint {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: ContractCodeChangesType,
+        sync_checkpoint: SyncCheckpoint,
+    },
+    DataChangesBySyncCheckpoint {
+        account_ids: ::std::vec::Vec<AccountId>,
+        changes_type: DataChangesType,
+        key_prefix_base64: StoreKey,
+        sync_checkpoint: SyncCheckpoint,
+    },
+}
+impl ::std::convert::From<&Self> for RpcStateChangesInBlockByTypeRequest {
+    fn from(value: &RpcStateChangesInBlockByTypeRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcStateChangesInBlockByTypeResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_hash\","]
+#[doc = "    \"changes\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"changes\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/StateChangeKindView\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcStateChangesInBlockByTypeResponse {
+    pub block_hash: CryptoHash,
+    pub changes: ::std::vec::Vec<StateChangeKindView>,
+}
+impl ::std::convert::From<&RpcStateChangesInBlockByTypeResponse>
+    for RpcStateChangesInBlockByTypeResponse
+{
+    fn from(value: &RpcStateChangesInBlockByTypeResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcStateChangesInBlockRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcStateChangesInBlockRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"block_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"block_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"finality\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"finality\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"sync_checkpoint\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"sync_checkpoint\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RpcStateChangesInBlockRequest {
+    #[serde(rename = "block_id")]
+    BlockId(BlockId),
+    #[serde(rename = "finality")]
+    Finality(Finality),
+    #[serde(rename = "sync_checkpoint")]
+    SyncCheckpoint(SyncCheckpoint),
+}
+impl ::std::convert::From<&Self> for RpcStateChangesInBlockRequest {
+    fn from(value: &RpcStateChangesInBlockRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<BlockId> for RpcStateChangesInBlockRequest {
+    fn from(value: BlockId) -> Self {
+        Self::BlockId(value)
+    }
+}
+impl ::std::convert::From<Finality> for RpcStateChangesInBlockRequest {
+    fn from(value: Finality) -> Self {
+        Self::Finality(value)
+    }
+}
+impl ::std::convert::From<SyncCheckpoint> for RpcStateChangesInBlockRequest {
+    fn from(value: SyncCheckpoint) -> Self {
+        Self::SyncCheckpoint(value)
+    }
+}
+#[doc = "`RpcStateChangesInBlockResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_hash\","]
+#[doc = "    \"changes\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"changes\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/StateChangeWithCauseView\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcStateChangesInBlockResponse {
+    pub block_hash: CryptoHash,
+    pub changes: ::std::vec::Vec<StateChangeWithCauseView>,
+}
+impl ::std::convert::From<&RpcStateChangesInBlockResponse> for RpcStateChangesInBlockResponse {
+    fn from(value: &RpcStateChangesInBlockResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`SingleAccessKeyChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `SingleAccessKeyChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"single_access_key_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SingleAccessKeyChangesType {
+    #[serde(rename = "single_access_key_changes")]
+    SingleAccessKeyChanges,
+}
+impl ::std::convert::From<&Self> for SingleAccessKeyChangesType {
+    fn from(value: &SingleAccessKeyChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for SingleAccessKeyChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::SingleAccessKeyChanges => f.write_str("single_access_key_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for SingleAccessKeyChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "single_access_key_changes" => Ok(Self::SingleAccessKeyChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for SingleAccessKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for SingleAccessKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for SingleAccessKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`SingleAccessKeyChangesType`]."]
+pub type SingleAccessKeyChangesByBlockIdChangesType = SingleAccessKeyChangesType;
+#[doc = "Alias of [`SingleAccessKeyChangesType`]."]
+pub type SingleAccessKeyChangesByFinalityChangesType = SingleAccessKeyChangesType;
+#[doc = "Alias of [`SingleAccessKeyChangesType`]."]
+pub type SingleAccessKeyChangesBySyncCheckpointChangesType = SingleAccessKeyChangesType;
+#[doc = "`SingleGasKeyChangesType`"]
+#[doc = r""]
+#[doc = "Marker of the `SingleGasKeyChanges` requests by block id, finality and sync checkpoint."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"single_gas_key_changes\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SingleGasKeyChangesType {
+    #[serde(rename = "single_gas_key_changes")]
+    SingleGasKeyChanges,
+}
+impl ::std::convert::From<&Self> for SingleGasKeyChangesType {
+    fn from(value: &SingleGasKeyChangesType) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for SingleGasKeyChangesType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::SingleGasKeyChanges => f.write_str("single_gas_key_changes"),
+        }
+    }
+}
+impl ::std::str::FromStr for SingleGasKeyChangesType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "single_gas_key_changes" => Ok(Self::SingleGasKeyChanges),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for SingleGasKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for SingleGasKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for SingleGasKeyChangesType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Alias of [`SingleGasKeyChangesType`]."]
+pub type SingleGasKeyChangesByBlockIdChangesType = SingleGasKeyChangesType;
+#[doc = "Alias of [`SingleGasKeyChangesType`]."]
+pub type SingleGasKeyChangesByFinalityChangesType = SingleGasKeyChangesType;
+#[doc = "Alias of [`SingleGasKeyChangesType`]."]
+pub type SingleGasKeyChangesBySyncCheckpointChangesType = SingleGasKeyChangesType;
+#[doc = "See crate::types::StateChangeCause for details."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"See crate::types::StateChangeCause for details.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"not_writable_to_disk\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"initial_state\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"tx_hash\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"tx_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"transaction_processing\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"receipt_hash\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"receipt_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"action_receipt_processing_started\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"receipt_hash\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"receipt_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"action_receipt_gas_reward\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"receipt_hash\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"receipt_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"receipt_processing\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"receipt_hash\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"receipt_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"postponed_receipt\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"updated_delayed_receipts\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"validator_accounts_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"migration\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"bandwidth_scheduler_state_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum StateChangeCauseView {
+    #[serde(rename = "not_writable_to_disk")]
+    NotWritableToDisk,
+    #[serde(rename = "initial_state")]
+    InitialState,
+    #[serde(rename = "transaction_processing")]
+    TransactionProcessing { tx_hash: CryptoHash },
+    #[serde(rename = "action_receipt_processing_started")]
+    ActionReceiptProcessingStarted { receipt_hash: CryptoHash },
+    #[serde(rename = "action_receipt_gas_reward")]
+    ActionReceiptGasReward { receipt_hash: CryptoHash },
+    #[serde(rename = "receipt_processing")]
+    ReceiptProcessing { receipt_hash: CryptoHash },
+    #[serde(rename = "postponed_receipt")]
+    PostponedReceipt { receipt_hash: CryptoHash },
+    #[serde(rename = "updated_delayed_receipts")]
+    UpdatedDelayedReceipts,
+    #[serde(rename = "validator_accounts_update")]
+    ValidatorAccountsUpdate,
+    #[serde(rename = "migration")]
+    Migration,
+    #[serde(rename = "bandwidth_scheduler_state_update")]
+    BandwidthSchedulerStateUpdate,
+    #[doc = "Variant missing from this version of the crate, only decoded inside `lenient::decode_leniently`"]
+    #[serde(untagged)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    Unknown(
+        #[serde(deserialize_with = "lenient::deserialize_unknown::<StateChangeCauseView, _>")]
+        lenient::UnknownValue,
+    ),
+}
+impl lenient::OpenEnum for StateChangeCauseView {
+    const KNOWN_VARIANTS: &'static [&'static str] = &[
+        "not_writable_to_disk",
+        "initial_state",
+        "transaction_processing",
+        "action_receipt_processing_started",
+        "action_receipt_gas_reward",
+        "receipt_processing",
+        "postponed_receipt",
+        "updated_delayed_receipts",
+        "validator_accounts_update",
+        "migration",
+        "bandwidth_scheduler_state_update",
+    ];
+}
+impl ::std::convert::From<&Self> for StateChangeCauseView {
+    fn from(value: &StateChangeCauseView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "It is a [serializable view] of [`StateChangeKind`].\n\n[serializable view]: ./index.html\n[`StateChangeKind`]: ../types/struct.StateChangeKind.html"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"It is a [serializable view] of [`StateChangeKind`].\\n\\n[serializable view]: ./index.html\\n[`StateChangeKind`]: ../types/struct.StateChangeKind.html\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"account_id\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"account_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"account_touched\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"account_id\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"account_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"access_key_touched\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"account_id\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"account_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"data_touched\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"account_id\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"account_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"contract_code_touched\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type", content = "account_id")]
+pub enum StateChangeKindView {
+    #[serde(rename = "account_touched")]
+    AccountTouched(AccountId),
+    #[serde(rename = "access_key_touched")]
+    AccessKeyTouched(AccountId),
+    #[serde(rename = "data_touched")]
+    DataTouched(AccountId),
+    #[serde(rename = "contract_code_touched")]
+    ContractCodeTouched(AccountId),
+}
+impl ::std::convert::From<&Self> for StateChangeKindView {
+    fn from(value: &StateChangeKindView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"description\": \"A view of the account\","]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"amount\","]
+#[doc = "            \"code_hash\","]
+#[doc = "            \"locked\","]
+#[doc = "            \"storage_usage\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"amount\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            },"]
+#[doc = "            \"code_hash\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "            },"]
+#[doc = "            \"global_contract_account_id\": {"]
+#[doc = "              \"anyOf\": ["]
+#[doc = "                {"]
+#[doc = "                  \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "                },"]
+#[doc = "                {"]
+#[doc = "                  \"type\": \"null\""]
+#[doc = "                }"]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"global_contract_hash\": {"]
+#[doc = "              \"anyOf\": ["]
+#[doc = "                {"]
+#[doc = "                  \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "                },"]
+#[doc = "                {"]
+#[doc = "                  \"type\": \"null\""]
+#[doc = "                }"]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"locked\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            },"]
+#[doc = "            \"storage_paid_at\": {"]
+#[doc = "              \"description\": \"TODO(2271): deprecated.\","]
+#[doc = "              \"default\": 0,"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"storage_usage\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"account_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"account_deletion\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"access_key\","]
+#[doc = "            \"account_id\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"access_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccessKeyView\""]
+#[doc = "            },"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"access_key_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"access_key_deletion\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"gas_key\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"gas_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/GasKey\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"gas_key_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"index\","]
+#[doc = "            \"nonce\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"index\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint32\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"nonce\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"gas_key_nonce_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"gas_key_deletion\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"key_base64\","]
+#[doc = "            \"value_base64\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"key_base64\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "            },"]
+#[doc = "            \"value_base64\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/StoreValue\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"data_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"key_base64\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"key_base64\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"data_deletion\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\","]
+#[doc = "            \"code_base64\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"code_base64\": {"]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"contract_code_update\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"change\","]
+#[doc = "        \"type\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"change\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        },"]
+#[doc = "        \"type\": {"]
+#[doc = "          \"type\": \"string\","]
+#[doc = "          \"enum\": ["]
+#[doc = "            \"contract_code_deletion\""]
+#[doc = "          ]"]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ],"]
+#[doc = "  \"required\": ["]
+#[doc = "    \"cause\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"cause\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/StateChangeCauseView\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum StateChangeWithCauseView {
+    Variant0 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant0Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant0Type,
+    },
+    Variant1 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant1Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant1Type,
+    },
+    Variant2 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant2Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant2Type,
+    },
+    Variant3 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant3Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant3Type,
+    },
+    Variant4 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant4Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant4Type,
+    },
+    Variant5 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant5Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant5Type,
+    },
+    Variant6 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant6Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant6Type,
+    },
+    Variant7 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant7Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant7Type,
+    },
+    Variant8 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant8Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant8Type,
+    },
+    Variant9 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant9Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant9Type,
+    },
+    Variant10 {
+        cause: StateChangeCauseView,
+        change: StateChangeWithCauseViewVariant10Change,
+        #[serde(rename = "type")]
+        type_: StateChangeWithCauseViewVariant10Type,
+    },
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseView {
+    fn from(value: &StateChangeWithCauseView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "A view of the account"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"A view of the account\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"amount\","]
+#[doc = "    \"code_hash\","]
+#[doc = "    \"locked\","]
+#[doc = "    \"storage_usage\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"global_contract_hash\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": false,"]
+#[doc = "    \"storage_paid_at\": {"]
+#[doc = "      \"description\": \"TODO(2271): deprecated.\","]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"storage_usage\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant0Change {
+    pub account_id: AccountId,
+    pub amount: NearToken,
+    pub code_hash: CryptoHash,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub global_contract_account_id: ::std::option::Option<AccountId>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub global_contract_hash: ::std::option::Option<CryptoHash>,
+    pub locked: NearToken,
+    #[doc = "TODO(2271): deprecated."]
+    #[serde(default)]
+    pub storage_paid_at: u64,
+    pub storage_usage: u64,
+}
+impl limits::FieldLimits for StateChangeWithCauseViewVariant0Change {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("storage_paid_at", "uint64", Some(0), None),
+        limits::FieldLimit::integer("storage_usage", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant0Change>
+    for StateChangeWithCauseViewVariant0Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant0Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant0Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"account_update\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant0Type {
+    #[serde(rename = "account_update")]
+    AccountUpdate,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant0Type {
+    fn from(value: &StateChangeWithCauseViewVariant0Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant0Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AccountUpdate => f.write_str("account_update"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant0Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "account_update" => Ok(Self::AccountUpdate),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant0Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant0Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant0Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant10Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": false,"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant10Change {
+    pub account_id: AccountId,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant10Change>
+    for StateChangeWithCauseViewVariant10Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant10Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant10Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"contract_code_deletion\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant10Type {
+    #[serde(rename = "contract_code_deletion")]
+    ContractCodeDeletion,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant10Type {
+    fn from(value: &StateChangeWithCauseViewVariant10Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant10Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::ContractCodeDeletion => f.write_str("contract_code_deletion"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant10Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "contract_code_deletion" => Ok(Self::ContractCodeDeletion),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant10Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant10Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant10Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant1Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": false,"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant1Change {
+    pub account_id: AccountId,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant1Change>
+    for StateChangeWithCauseViewVariant1Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant1Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant1Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"account_deletion\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant1Type {
+    #[serde(rename = "account_deletion")]
+    AccountDeletion,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant1Type {
+    fn from(value: &StateChangeWithCauseViewVariant1Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant1Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AccountDeletion => f.write_str("account_deletion"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant1Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "account_deletion" => Ok(Self::AccountDeletion),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant1Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant1Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant1Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant2Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"access_key\","]
+#[doc = "    \"account_id\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccessKeyView\""]
+#[doc = "    },"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant2Change {
+    pub access_key: AccessKeyView,
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant2Change>
+    for StateChangeWithCauseViewVariant2Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant2Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant2Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"access_key_update\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant2Type {
+    #[serde(rename = "access_key_update")]
+    AccessKeyUpdate,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant2Type {
+    fn from(value: &StateChangeWithCauseViewVariant2Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant2Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AccessKeyUpdate => f.write_str("access_key_update"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant2Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "access_key_update" => Ok(Self::AccessKeyUpdate),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant2Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant2Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant2Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant3Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant3Change {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant3Change>
+    for StateChangeWithCauseViewVariant3Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant3Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant3Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"access_key_deletion\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant3Type {
+    #[serde(rename = "access_key_deletion")]
+    AccessKeyDeletion,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant3Type {
+    fn from(value: &StateChangeWithCauseViewVariant3Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant3Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::AccessKeyDeletion => f.write_str("access_key_deletion"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant3Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "access_key_deletion" => Ok(Self::AccessKeyDeletion),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant3Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant3Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant3Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant4Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"gas_key\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/GasKey\""]
+#[doc = "    },"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant4Change {
+    pub account_id: AccountId,
+    pub gas_key: GasKey,
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant4Change>
+    for StateChangeWithCauseViewVariant4Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant4Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant4Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"gas_key_update\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant4Type {
+    #[serde(rename = "gas_key_update")]
+    GasKeyUpdate,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant4Type {
+    fn from(value: &StateChangeWithCauseViewVariant4Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant4Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::GasKeyUpdate => f.write_str("gas_key_update"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant4Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "gas_key_update" => Ok(Self::GasKeyUpdate),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant4Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant4Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant4Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant5Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"index\","]
+#[doc = "    \"nonce\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant5Change {
+    pub account_id: AccountId,
+    pub index: u32,
+    pub nonce: u64,
+    pub public_key: PublicKey,
+}
+impl limits::FieldLimits for StateChangeWithCauseViewVariant5Change {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("index", "uint32", Some(0), None),
+        limits::FieldLimit::integer("nonce", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant5Change>
+    for StateChangeWithCauseViewVariant5Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant5Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant5Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"gas_key_nonce_update\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant5Type {
+    #[serde(rename = "gas_key_nonce_update")]
+    GasKeyNonceUpdate,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant5Type {
+    fn from(value: &StateChangeWithCauseViewVariant5Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant5Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::GasKeyNonceUpdate => f.write_str("gas_key_nonce_update"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant5Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "gas_key_nonce_update" => Ok(Self::GasKeyNonceUpdate),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant5Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant5Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant5Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant6Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant6Change {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant6Change>
+    for StateChangeWithCauseViewVariant6Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant6Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant6Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"gas_key_deletion\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant6Type {
+    #[serde(rename = "gas_key_deletion")]
+    GasKeyDeletion,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant6Type {
+    fn from(value: &StateChangeWithCauseViewVariant6Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant6Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::GasKeyDeletion => f.write_str("gas_key_deletion"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant6Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "gas_key_deletion" => Ok(Self::GasKeyDeletion),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant6Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant6Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant6Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant7Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"key_base64\","]
+#[doc = "    \"value_base64\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "    },"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": false,"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/StoreValue\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant7Change {
+    pub account_id: AccountId,
+    pub key_base64: StoreKey,
+    pub value_base64: StoreValue,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant7Change>
+    for StateChangeWithCauseViewVariant7Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant7Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant7Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"data_update\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant7Type {
+    #[serde(rename = "data_update")]
+    DataUpdate,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant7Type {
+    fn from(value: &StateChangeWithCauseViewVariant7Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant7Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::DataUpdate => f.write_str("data_update"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant7Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "data_update" => Ok(Self::DataUpdate),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant7Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant7Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant7Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant8Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"key_base64\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": false,"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/StoreKey\""]
+#[doc = "    },"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": false,"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant8Change {
+    pub account_id: AccountId,
+    pub key_base64: StoreKey,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant8Change>
+    for StateChangeWithCauseViewVariant8Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant8Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant8Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"data_deletion\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant8Type {
+    #[serde(rename = "data_deletion")]
+    DataDeletion,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant8Type {
+    fn from(value: &StateChangeWithCauseViewVariant8Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant8Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::DataDeletion => f.write_str("data_deletion"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant8Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "data_deletion" => Ok(Self::DataDeletion),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant8Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant8Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant8Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant9Change`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"code_base64\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": false,"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": false,"]
+#[doc = "    \"code_base64\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"code_hash\": false,"]
+#[doc = "    \"gas_key\": false,"]
+#[doc = "    \"global_contract_account_id\": false,"]
+#[doc = "    \"global_contract_hash\": false,"]
+#[doc = "    \"index\": false,"]
+#[doc = "    \"key_base64\": false,"]
+#[doc = "    \"locked\": false,"]
+#[doc = "    \"nonce\": false,"]
+#[doc = "    \"public_key\": false,"]
+#[doc = "    \"storage_paid_at\": false,"]
+#[doc = "    \"storage_usage\": false,"]
+#[doc = "    \"value_base64\": false"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateChangeWithCauseViewVariant9Change {
+    pub account_id: AccountId,
+    pub code_base64: ::std::string::String,
+}
+impl ::std::convert::From<&StateChangeWithCauseViewVariant9Change>
+    for StateChangeWithCauseViewVariant9Change
+{
+    fn from(value: &StateChangeWithCauseViewVariant9Change) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateChangeWithCauseViewVariant9Type`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"contract_code_update\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StateChangeWithCauseViewVariant9Type {
+    #[serde(rename = "contract_code_update")]
+    ContractCodeUpdate,
+}
+impl ::std::convert::From<&Self> for StateChangeWithCauseViewVariant9Type {
+    fn from(value: &StateChangeWithCauseViewVariant9Type) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StateChangeWithCauseViewVariant9Type {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::ContractCodeUpdate => f.write_str("contract_code_update"),
+        }
+    }
+}
+impl ::std::str::FromStr for StateChangeWithCauseViewVariant9Type {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "contract_code_update" => Ok(Self::ContractCodeUpdate),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StateChangeWithCauseViewVariant9Type {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StateChangeWithCauseViewVariant9Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StateChangeWithCauseViewVariant9Type {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
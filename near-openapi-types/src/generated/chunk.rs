@@ -0,0 +1,599 @@
+//! Chunks, shards and congestion.
+use crate::*;
+
+#[doc = "`BandwidthRequest` describes the size of receipts that a shard would like to send to another shard.\nWhen a shard wants to send a lot of receipts to another shard, it needs to create a request and wait\nfor a bandwidth grant from the bandwidth scheduler."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"`BandwidthRequest` describes the size of receipts that a shard would like to send to another shard.\\nWhen a shard wants to send a lot of receipts to another shard, it needs to create a request and wait\\nfor a bandwidth grant from the bandwidth scheduler.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"requested_values_bitmap\","]
+#[doc = "    \"to_shard\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"requested_values_bitmap\": {"]
+#[doc = "      \"description\": \"Bitmap which describes what values of bandwidth are requested.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BandwidthRequestBitmap\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"to_shard\": {"]
+#[doc = "      \"description\": \"Requesting bandwidth to this shard.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint16\","]
+#[doc = "      \"maximum\": 65535.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BandwidthRequest {
+    #[doc = "Bitmap which describes what values of bandwidth are requested."]
+    pub requested_values_bitmap: BandwidthRequestBitmap,
+    #[doc = "Requesting bandwidth to this shard."]
+    pub to_shard: u16,
+}
+impl limits::FieldLimits for BandwidthRequest {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "to_shard",
+        "uint16",
+        Some(0),
+        Some(65535),
+    )];
+}
+impl ::std::convert::From<&BandwidthRequest> for BandwidthRequest {
+    fn from(value: &BandwidthRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Bitmap which describes which values from the predefined list are being requested.\nThe nth bit is set to 1 when the nth value from the list is being requested."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Bitmap which describes which values from the predefined list are being requested.\\nThe nth bit is set to 1 when the nth value from the list is being requested.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"data\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"data\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint8\","]
+#[doc = "        \"maximum\": 255.0,"]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 5,"]
+#[doc = "      \"minItems\": 5"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BandwidthRequestBitmap {
+    pub data: [u8; 5usize],
+}
+impl limits::FieldLimits for BandwidthRequestBitmap {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] =
+        &[limits::FieldLimit::items("data", Some(5), Some(5))];
+}
+impl ::std::convert::From<&BandwidthRequestBitmap> for BandwidthRequestBitmap {
+    fn from(value: &BandwidthRequestBitmap) -> Self {
+        value.clone()
+    }
+}
+#[doc = "A list of shard's bandwidth requests.\nDescribes how much the shard would like to send to other shards."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"A list of shard's bandwidth requests.\\nDescribes how much the shard would like to send to other shards.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"V1\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"V1\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BandwidthRequestsV1\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BandwidthRequests {
+    V1(BandwidthRequestsV1),
+}
+impl ::std::convert::From<&Self> for BandwidthRequests {
+    fn from(value: &BandwidthRequests) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<BandwidthRequestsV1> for BandwidthRequests {
+    fn from(value: BandwidthRequestsV1) -> Self {
+        Self::V1(value)
+    }
+}
+#[doc = "Version 1 of [`BandwidthRequest`]."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Version 1 of [`BandwidthRequest`].\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"requests\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"requests\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/BandwidthRequest\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BandwidthRequestsV1 {
+    pub requests: ::std::vec::Vec<BandwidthRequest>,
+}
+impl ::std::convert::From<&BandwidthRequestsV1> for BandwidthRequestsV1 {
+    fn from(value: &BandwidthRequestsV1) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Contains main info about the chunk."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Contains main info about the chunk.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"balance_burnt\","]
+#[doc = "    \"chunk_hash\","]
+#[doc = "    \"encoded_length\","]
+#[doc = "    \"encoded_merkle_root\","]
+#[doc = "    \"gas_limit\","]
+#[doc = "    \"gas_used\","]
+#[doc = "    \"height_created\","]
+#[doc = "    \"height_included\","]
+#[doc = "    \"outcome_root\","]
+#[doc = "    \"outgoing_receipts_root\","]
+#[doc = "    \"prev_block_hash\","]
+#[doc = "    \"prev_state_root\","]
+#[doc = "    \"shard_id\","]
+#[doc = "    \"signature\","]
+#[doc = "    \"tx_root\","]
+#[doc = "    \"validator_proposals\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"balance_burnt\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"bandwidth_requests\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BandwidthRequests\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"chunk_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"congestion_info\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CongestionInfoView\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"encoded_length\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"encoded_merkle_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"gas_limit\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"gas_used\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"height_created\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"height_included\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"outcome_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"outgoing_receipts_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"prev_block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"prev_state_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"rent_paid\": {"]
+#[doc = "      \"description\": \"TODO(2271): deprecated.\","]
+#[doc = "      \"default\": \"0\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"shard_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "    },"]
+#[doc = "    \"signature\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "    },"]
+#[doc = "    \"tx_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"validator_proposals\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorStakeView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"validator_reward\": {"]
+#[doc = "      \"description\": \"TODO(2271): deprecated.\","]
+#[doc = "      \"default\": \"0\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ChunkHeaderView {
+    pub balance_burnt: NearToken,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bandwidth_requests: ::std::option::Option<BandwidthRequests>,
+    pub chunk_hash: CryptoHash,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub congestion_info: ::std::option::Option<CongestionInfoView>,
+    pub encoded_length: u64,
+    pub encoded_merkle_root: CryptoHash,
+    pub gas_limit: NearGas,
+    pub gas_used: NearGas,
+    pub height_created: u64,
+    pub height_included: u64,
+    pub outcome_root: CryptoHash,
+    pub outgoing_receipts_root: CryptoHash,
+    pub prev_block_hash: CryptoHash,
+    pub prev_state_root: CryptoHash,
+    #[doc = "TODO(2271): deprecated."]
+    #[serde(default = "defaults::chunk_header_view_rent_paid")]
+    pub rent_paid: NearToken,
+    pub shard_id: ShardId,
+    pub signature: Signature,
+    pub tx_root: CryptoHash,
+    pub validator_proposals: ::std::vec::Vec<ValidatorStakeView>,
+    #[doc = "TODO(2271): deprecated."]
+    #[serde(default = "defaults::chunk_header_view_validator_reward")]
+    pub validator_reward: NearToken,
+}
+impl limits::FieldLimits for ChunkHeaderView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("encoded_length", "uint64", Some(0), None),
+        limits::FieldLimit::integer("height_created", "uint64", Some(0), None),
+        limits::FieldLimit::integer("height_included", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&ChunkHeaderView> for ChunkHeaderView {
+    fn from(value: &ChunkHeaderView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Stores the congestion level of a shard. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Stores the congestion level of a shard. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"allowed_shard\","]
+#[doc = "    \"buffered_receipts_gas\","]
+#[doc = "    \"delayed_receipts_gas\","]
+#[doc = "    \"receipt_bytes\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"allowed_shard\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint16\","]
+#[doc = "      \"maximum\": 65535.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"buffered_receipts_gas\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"delayed_receipts_gas\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"receipt_bytes\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CongestionInfoView {
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub allowed_shard: u16,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub buffered_receipts_gas: ::std::string::String,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub delayed_receipts_gas: ::std::string::String,
+    #[cfg_attr(feature = "historical-compat", serde(default))]
+    pub receipt_bytes: u64,
+}
+impl limits::FieldLimits for CongestionInfoView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("allowed_shard", "uint16", Some(0), Some(65535)),
+        limits::FieldLimit::integer("receipt_bytes", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&CongestionInfoView> for CongestionInfoView {
+    fn from(value: &CongestionInfoView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcChunkRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcChunkRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"anyOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"title\": \"block_shard_id\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"block_id\","]
+#[doc = "        \"shard_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"block_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        },"]
+#[doc = "        \"shard_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"chunk_hash\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"chunk_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"chunk_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum RpcChunkRequest {
+    BlockShardId {
+        block_id: BlockId,
+        shard_id: ShardId,
+    },
+    ChunkHash {
+        chunk_id: CryptoHash,
+    },
+}
+impl ::std::convert::From<&Self> for RpcChunkRequest {
+    fn from(value: &RpcChunkRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcChunkResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"author\","]
+#[doc = "    \"header\","]
+#[doc = "    \"receipts\","]
+#[doc = "    \"transactions\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"author\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"header\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ChunkHeaderView\""]
+#[doc = "    },"]
+#[doc = "    \"receipts\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ReceiptView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"transactions\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/SignedTransactionView\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcChunkResponse {
+    pub author: AccountId,
+    pub header: ChunkHeaderView,
+    pub receipts: ::std::vec::Vec<ReceiptView>,
+    pub transactions: ::std::vec::Vec<SignedTransactionView>,
+}
+impl ::std::convert::From<&RpcChunkResponse> for RpcChunkResponse {
+    fn from(value: &RpcChunkResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcCongestionLevelRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcCongestionLevelRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"anyOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"title\": \"block_shard_id\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"block_id\","]
+#[doc = "        \"shard_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"block_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        },"]
+#[doc = "        \"shard_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"title\": \"chunk_hash\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"chunk_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"chunk_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum RpcCongestionLevelRequest {
+    BlockShardId {
+        block_id: BlockId,
+        shard_id: ShardId,
+    },
+    ChunkHash {
+        chunk_id: CryptoHash,
+    },
+}
+impl ::std::convert::From<&Self> for RpcCongestionLevelRequest {
+    fn from(value: &RpcCongestionLevelRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcCongestionLevelResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"congestion_level\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"congestion_level\": {"]
+#[doc = "      \"type\": \"number\","]
+#[doc = "      \"format\": \"double\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcCongestionLevelResponse {
+    pub congestion_level: f64,
+}
+impl ::std::convert::From<&RpcCongestionLevelResponse> for RpcCongestionLevelResponse {
+    fn from(value: &RpcCongestionLevelResponse) -> Self {
+        value.clone()
+    }
+}
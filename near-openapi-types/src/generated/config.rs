@@ -0,0 +1,6390 @@
+//! Genesis, protocol, runtime and node configuration.
+use crate::*;
+
+#[doc = "Describes the cost of creating an access key."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes the cost of creating an access key.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"full_access_cost\","]
+#[doc = "    \"function_call_cost\","]
+#[doc = "    \"function_call_cost_per_byte\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"full_access_cost\": {"]
+#[doc = "      \"description\": \"Base cost of creating a full access access-key.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"function_call_cost\": {"]
+#[doc = "      \"description\": \"Base cost of creating an access-key restricted to specific functions.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"function_call_cost_per_byte\": {"]
+#[doc = "      \"description\": \"Cost per byte of method_names of creating a restricted access-key.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AccessKeyCreationConfigView {
+    #[doc = "Base cost of creating a full access access-key."]
+    pub full_access_cost: Fee,
+    #[doc = "Base cost of creating an access-key restricted to specific functions."]
+    pub function_call_cost: Fee,
+    #[doc = "Cost per byte of method_names of creating a restricted access-key."]
+    pub function_call_cost_per_byte: Fee,
+}
+impl ::std::convert::From<&AccessKeyCreationConfigView> for AccessKeyCreationConfigView {
+    fn from(value: &AccessKeyCreationConfigView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "The structure describes configuration for creation of new accounts."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"The structure describes configuration for creation of new accounts.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"min_allowed_top_level_account_length\": {"]
+#[doc = "      \"description\": \"The minimum length of the top-level account ID that is allowed to be created by any account.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"registrar_account_id\": {"]
+#[doc = "      \"description\": \"The account ID of the account registrar. This account ID allowed to create top-level\\naccounts of any valid length.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AccountCreationConfigView {
+    #[doc = "The minimum length of the top-level account ID that is allowed to be created by any account."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub min_allowed_top_level_account_length: ::std::option::Option<u8>,
+    #[doc = "The account ID of the account registrar. This account ID allowed to create top-level\naccounts of any valid length."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub registrar_account_id: ::std::option::Option<AccountId>,
+}
+impl limits::FieldLimits for AccountCreationConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "min_allowed_top_level_account_length",
+        "uint8",
+        Some(0),
+        Some(255),
+    )];
+}
+impl ::std::convert::From<&AccountCreationConfigView> for AccountCreationConfigView {
+    fn from(value: &AccountCreationConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for AccountCreationConfigView {
+    fn default() -> Self {
+        Self {
+            min_allowed_top_level_account_length: Default::default(),
+            registrar_account_id: Default::default(),
+        }
+    }
+}
+#[doc = "`AccountIdValidityRulesVersion`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"integer\","]
+#[doc = "  \"format\": \"uint8\","]
+#[doc = "  \"maximum\": 255.0,"]
+#[doc = "  \"minimum\": 0.0"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct AccountIdValidityRulesVersion(pub u8);
+impl ::std::ops::Deref for AccountIdValidityRulesVersion {
+    type Target = u8;
+    fn deref(&self) -> &u8 {
+        &self.0
+    }
+}
+impl ::std::convert::From<AccountIdValidityRulesVersion> for u8 {
+    fn from(value: AccountIdValidityRulesVersion) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&AccountIdValidityRulesVersion> for AccountIdValidityRulesVersion {
+    fn from(value: &AccountIdValidityRulesVersion) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<u8> for AccountIdValidityRulesVersion {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl ::std::str::FromStr for AccountIdValidityRulesVersion {
+    type Err = <u8 as ::std::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        Ok(Self(value.parse()?))
+    }
+}
+impl ::std::convert::TryFrom<&str> for AccountIdValidityRulesVersion {
+    type Error = <u8 as ::std::str::FromStr>::Err;
+    fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&String> for AccountIdValidityRulesVersion {
+    type Error = <u8 as ::std::str::FromStr>::Err;
+    fn try_from(value: &String) -> ::std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<String> for AccountIdValidityRulesVersion {
+    type Error = <u8 as ::std::str::FromStr>::Err;
+    fn try_from(value: String) -> ::std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+impl ::std::fmt::Display for AccountIdValidityRulesVersion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+#[doc = "Account info for validators"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Account info for validators\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"amount\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"amount\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AccountInfo {
+    pub account_id: AccountId,
+    pub amount: NearToken,
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&AccountInfo> for AccountInfo {
+    fn from(value: &AccountInfo) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Describes the cost of creating a specific action, `Action`. Includes all variants."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes the cost of creating a specific action, `Action`. Includes all variants.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"add_key_cost\": {"]
+#[doc = "      \"description\": \"Base cost of adding a key.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccessKeyCreationConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"create_account_cost\": {"]
+#[doc = "      \"description\": \"Base cost of creating an account.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"delegate_cost\": {"]
+#[doc = "      \"description\": \"Base cost for processing a delegate action.\\n\\nThis is on top of the costs for the actions inside the delegate action.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"delete_account_cost\": {"]
+#[doc = "      \"description\": \"Base cost of deleting an account.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"delete_key_cost\": {"]
+#[doc = "      \"description\": \"Base cost of deleting a key.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"deploy_contract_cost\": {"]
+#[doc = "      \"description\": \"Base cost of deploying a contract.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"deploy_contract_cost_per_byte\": {"]
+#[doc = "      \"description\": \"Cost per byte of deploying a contract.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"function_call_cost\": {"]
+#[doc = "      \"description\": \"Base cost of calling a function.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"function_call_cost_per_byte\": {"]
+#[doc = "      \"description\": \"Cost per byte of method name and arguments of calling a function.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"stake_cost\": {"]
+#[doc = "      \"description\": \"Base cost of staking.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transfer_cost\": {"]
+#[doc = "      \"description\": \"Base cost of making a transfer.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ActionCreationConfigView {
+    #[doc = "Base cost of adding a key."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub add_key_cost: ::std::option::Option<AccessKeyCreationConfigView>,
+    #[doc = "Base cost of creating an account."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub create_account_cost: ::std::option::Option<Fee>,
+    #[doc = "Base cost for processing a delegate action.\n\nThis is on top of the costs for the actions inside the delegate action."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub delegate_cost: ::std::option::Option<Fee>,
+    #[doc = "Base cost of deleting an account."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub delete_account_cost: ::std::option::Option<Fee>,
+    #[doc = "Base cost of deleting a key."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub delete_key_cost: ::std::option::Option<Fee>,
+    #[doc = "Base cost of deploying a contract."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub deploy_contract_cost: ::std::option::Option<Fee>,
+    #[doc = "Cost per byte of deploying a contract."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub deploy_contract_cost_per_byte: ::std::option::Option<Fee>,
+    #[doc = "Base cost of calling a function."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub function_call_cost: ::std::option::Option<Fee>,
+    #[doc = "Cost per byte of method name and arguments of calling a function."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub function_call_cost_per_byte: ::std::option::Option<Fee>,
+    #[doc = "Base cost of staking."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub stake_cost: ::std::option::Option<Fee>,
+    #[doc = "Base cost of making a transfer."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub transfer_cost: ::std::option::Option<Fee>,
+}
+impl ::std::convert::From<&ActionCreationConfigView> for ActionCreationConfigView {
+    fn from(value: &ActionCreationConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for ActionCreationConfigView {
+    fn default() -> Self {
+        Self {
+            add_key_cost: Default::default(),
+            create_account_cost: Default::default(),
+            delegate_cost: Default::default(),
+            delete_account_cost: Default::default(),
+            delete_key_cost: Default::default(),
+            deploy_contract_cost: Default::default(),
+            deploy_contract_cost_per_byte: Default::default(),
+            function_call_cost: Default::default(),
+            function_call_cost_per_byte: Default::default(),
+            stake_cost: Default::default(),
+            transfer_cost: Default::default(),
+        }
+    }
+}
+#[doc = "Config for the Chunk Distribution Network feature.\nThis allows nodes to push and pull chunks from a central stream.\nThe two benefits of this approach are: (1) less request/response traffic\non the peer-to-peer network and (2) lower latency for RPC nodes indexing the chain."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Config for the Chunk Distribution Network feature.\\nThis allows nodes to push and pull chunks from a central stream.\\nThe two benefits of this approach are: (1) less request/response traffic\\non the peer-to-peer network and (2) lower latency for RPC nodes indexing the chain.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"enabled\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"uris\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ChunkDistributionUris\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ChunkDistributionNetworkConfig {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub enabled: ::std::option::Option<bool>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub uris: ::std::option::Option<ChunkDistributionUris>,
+}
+impl ::std::convert::From<&ChunkDistributionNetworkConfig> for ChunkDistributionNetworkConfig {
+    fn from(value: &ChunkDistributionNetworkConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for ChunkDistributionNetworkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Default::default(),
+            uris: Default::default(),
+        }
+    }
+}
+#[doc = "URIs for the Chunk Distribution Network feature."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"URIs for the Chunk Distribution Network feature.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"get\": {"]
+#[doc = "      \"description\": \"URI for pulling chunks from the stream.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"set\": {"]
+#[doc = "      \"description\": \"URI for publishing chunks to the stream.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ChunkDistributionUris {
+    #[doc = "URI for pulling chunks from the stream."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub get: ::std::option::Option<::std::string::String>,
+    #[doc = "URI for publishing chunks to the stream."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub set: ::std::option::Option<::std::string::String>,
+}
+impl ::std::convert::From<&ChunkDistributionUris> for ChunkDistributionUris {
+    fn from(value: &ChunkDistributionUris) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for ChunkDistributionUris {
+    fn default() -> Self {
+        Self {
+            get: Default::default(),
+            set: Default::default(),
+        }
+    }
+}
+#[doc = "Configuration for a cloud-based archival writer. If this config is present, the writer is enabled and\nwrites chunk-related data based on the tracked shards. This config also controls additional archival\nbehavior such as block data and polling interval."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configuration for a cloud-based archival writer. If this config is present, the writer is enabled and\\nwrites chunk-related data based on the tracked shards. This config also controls additional archival\\nbehavior such as block data and polling interval.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"archive_block_data\": {"]
+#[doc = "      \"description\": \"Determines whether block-related data should be written to cloud storage.\","]
+#[doc = "      \"default\": false,"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"polling_interval\": {"]
+#[doc = "      \"description\": \"Interval at which the system checks for new blocks or chunks to archive.\","]
+#[doc = "      \"default\": {"]
+#[doc = "        \"nanos\": 0,"]
+#[doc = "        \"secs\": 1"]
+#[doc = "      },"]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DurationAsStdSchemaProvider\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CloudArchivalWriterConfig {
+    #[doc = "Determines whether block-related data should be written to cloud storage."]
+    #[serde(default)]
+    pub archive_block_data: bool,
+    #[doc = "Interval at which the system checks for new blocks or chunks to archive."]
+    #[serde(default = "defaults::cloud_archival_writer_config_polling_interval")]
+    pub polling_interval: DurationAsStdSchemaProvider,
+}
+impl ::std::convert::From<&CloudArchivalWriterConfig> for CloudArchivalWriterConfig {
+    fn from(value: &CloudArchivalWriterConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for CloudArchivalWriterConfig {
+    fn default() -> Self {
+        Self {
+            archive_block_data: Default::default(),
+            polling_interval: defaults::cloud_archival_writer_config_polling_interval(),
+        }
+    }
+}
+#[doc = "The configuration for congestion control. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"The configuration for congestion control. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"allowed_shard_outgoing_gas\": {"]
+#[doc = "      \"description\": \"How much gas the chosen allowed shard can send to a 100% congested shard.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_congestion_incoming_gas\": {"]
+#[doc = "      \"description\": \"How much gas in delayed receipts of a shard is 100% incoming congestion.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_congestion_memory_consumption\": {"]
+#[doc = "      \"description\": \"How much memory space of all delayed and buffered receipts in a shard is\\nconsidered 100% congested.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_congestion_missed_chunks\": {"]
+#[doc = "      \"description\": \"How many missed chunks in a row in a shard is considered 100% congested.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_congestion_outgoing_gas\": {"]
+#[doc = "      \"description\": \"How much gas in outgoing buffered receipts of a shard is 100% congested.\\n\\nOutgoing congestion contributes to overall congestion, which reduces how\\nmuch other shards are allowed to forward to this shard.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_outgoing_gas\": {"]
+#[doc = "      \"description\": \"The maximum amount of gas attached to receipts a shard can forward to\\nanother shard per chunk.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_tx_gas\": {"]
+#[doc = "      \"description\": \"The maximum amount of gas in a chunk spent on converting new transactions to\\nreceipts.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"min_outgoing_gas\": {"]
+#[doc = "      \"description\": \"The minimum gas each shard can send to a shard that is not fully congested.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"min_tx_gas\": {"]
+#[doc = "      \"description\": \"The minimum amount of gas in a chunk spent on converting new transactions\\nto receipts, as long as the receiving shard is not congested.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"outgoing_receipts_big_size_limit\": {"]
+#[doc = "      \"description\": \"Large size limit for outgoing receipts to a shard, used when it's safe\\nto send a lot of receipts without making the state witness too large.\\nIt limits the total sum of outgoing receipts, not individual receipts.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"outgoing_receipts_usual_size_limit\": {"]
+#[doc = "      \"description\": \"The standard size limit for outgoing receipts aimed at a single shard.\\nThis limit is pretty small to keep the size of source_receipt_proofs under control.\\nIt limits the total sum of outgoing receipts, not individual receipts.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"reject_tx_congestion_threshold\": {"]
+#[doc = "      \"description\": \"How much congestion a shard can tolerate before it stops all shards from\\naccepting new transactions with the receiver set to the congested shard.\","]
+#[doc = "      \"type\": \"number\","]
+#[doc = "      \"format\": \"double\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CongestionControlConfigView {
+    #[doc = "How much gas the chosen allowed shard can send to a 100% congested shard.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub allowed_shard_outgoing_gas: ::std::option::Option<NearGas>,
+    #[doc = "How much gas in delayed receipts of a shard is 100% incoming congestion.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_congestion_incoming_gas: ::std::option::Option<NearGas>,
+    #[doc = "How much memory space of all delayed and buffered receipts in a shard is\nconsidered 100% congested.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_congestion_memory_consumption: ::std::option::Option<u64>,
+    #[doc = "How many missed chunks in a row in a shard is considered 100% congested."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_congestion_missed_chunks: ::std::option::Option<u64>,
+    #[doc = "How much gas in outgoing buffered receipts of a shard is 100% congested.\n\nOutgoing congestion contributes to overall congestion, which reduces how\nmuch other shards are allowed to forward to this shard."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_congestion_outgoing_gas: ::std::option::Option<NearGas>,
+    #[doc = "The maximum amount of gas attached to receipts a shard can forward to\nanother shard per chunk.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_outgoing_gas: ::std::option::Option<NearGas>,
+    #[doc = "The maximum amount of gas in a chunk spent on converting new transactions to\nreceipts.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_tx_gas: ::std::option::Option<NearGas>,
+    #[doc = "The minimum gas each shard can send to a shard that is not fully congested.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub min_outgoing_gas: ::std::option::Option<NearGas>,
+    #[doc = "The minimum amount of gas in a chunk spent on converting new transactions\nto receipts, as long as the receiving shard is not congested.\n\nSee [`CongestionControlConfig`] for more details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub min_tx_gas: ::std::option::Option<NearGas>,
+    #[doc = "Large size limit for outgoing receipts to a shard, used when it's safe\nto send a lot of receipts without making the state witness too large.\nIt limits the total sum of outgoing receipts, not individual receipts."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub outgoing_receipts_big_size_limit: ::std::option::Option<u64>,
+    #[doc = "The standard size limit for outgoing receipts aimed at a single shard.\nThis limit is pretty small to keep the size of source_receipt_proofs under control.\nIt limits the total sum of outgoing receipts, not individual receipts."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub outgoing_receipts_usual_size_limit: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub reject_tx_congestion_threshold: ::std::option::Option<f64>,
+}
+impl limits::FieldLimits for CongestionControlConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("max_congestion_memory_consumption", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_congestion_missed_chunks", "uint64", Some(0), None),
+        limits::FieldLimit::integer("outgoing_receipts_big_size_limit", "uint64", Some(0), None),
+        limits::FieldLimit::integer(
+            "outgoing_receipts_usual_size_limit",
+            "uint64",
+            Some(0),
+            None,
+        ),
+    ];
+}
+impl ::std::convert::From<&CongestionControlConfigView> for CongestionControlConfigView {
+    fn from(value: &CongestionControlConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for CongestionControlConfigView {
+    fn default() -> Self {
+        Self {
+            allowed_shard_outgoing_gas: Default::default(),
+            max_congestion_incoming_gas: Default::default(),
+            max_congestion_memory_consumption: Default::default(),
+            max_congestion_missed_chunks: Default::default(),
+            max_congestion_outgoing_gas: Default::default(),
+            max_outgoing_gas: Default::default(),
+            max_tx_gas: Default::default(),
+            min_outgoing_gas: Default::default(),
+            min_tx_gas: Default::default(),
+            outgoing_receipts_big_size_limit: Default::default(),
+            outgoing_receipts_usual_size_limit: Default::default(),
+            reject_tx_congestion_threshold: Default::default(),
+        }
+    }
+}
+#[doc = "The fees settings for a data receipt creation"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"The fees settings for a data receipt creation\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"base_cost\": {"]
+#[doc = "      \"description\": \"Base cost of creating a data receipt.\\nBoth `send` and `exec` costs are burned when a new receipt has input dependencies. The gas\\nis charged for each input dependency. The dependencies are specified when a receipt is\\ncreated using `promise_then` and `promise_batch_then`.\\nNOTE: Any receipt with output dependencies will produce data receipts. Even if it fails.\\nEven if the last action is not a function call (in case of success it will return empty\\nvalue).\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"cost_per_byte\": {"]
+#[doc = "      \"description\": \"Additional cost per byte sent.\\nBoth `send` and `exec` costs are burned when a function call finishes execution and returns\\n`N` bytes of data to every output dependency. For each output dependency the cost is\\n`(send(sir) + exec()) * N`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DataReceiptCreationConfigView {
+    #[doc = "Base cost of creating a data receipt.\nBoth `send` and `exec` costs are burned when a new receipt has input dependencies. The gas\nis charged for each input dependency. The dependencies are specified when a receipt is\ncreated using `promise_then` and `promise_batch_then`.\nNOTE: Any receipt with output dependencies will produce data receipts. Even if it fails.\nEven if the last action is not a function call (in case of success it will return empty\nvalue)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub base_cost: ::std::option::Option<Fee>,
+    #[doc = "Additional cost per byte sent.\nBoth `send` and `exec` costs are burned when a function call finishes execution and returns\n`N` bytes of data to every output dependency. For each output dependency the cost is\n`(send(sir) + exec()) * N`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub cost_per_byte: ::std::option::Option<Fee>,
+}
+impl ::std::convert::From<&DataReceiptCreationConfigView> for DataReceiptCreationConfigView {
+    fn from(value: &DataReceiptCreationConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for DataReceiptCreationConfigView {
+    fn default() -> Self {
+        Self {
+            base_cost: Default::default(),
+            cost_per_byte: Default::default(),
+        }
+    }
+}
+#[doc = "Configures how to dump state to external storage."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configures how to dump state to external storage.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"credentials_file\": {"]
+#[doc = "      \"description\": \"Location of a json file with credentials allowing access to the bucket.\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"iteration_delay\": {"]
+#[doc = "      \"description\": \"How often to check if a new epoch has started.\\nFeel free to set to `None`, defaults are sensible.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DurationAsStdSchemaProvider\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"location\": {"]
+#[doc = "      \"description\": \"Specifies where to write the obtained state parts.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExternalStorageLocation\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"restart_dump_for_shards\": {"]
+#[doc = "      \"description\": \"Use in case a node that dumps state to the external storage\\ngets in trouble.\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DumpConfig {
+    #[doc = "Location of a json file with credentials allowing access to the bucket."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub credentials_file: ::std::option::Option<::std::string::String>,
+    #[doc = "How often to check if a new epoch has started.\nFeel free to set to `None`, defaults are sensible."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub iteration_delay: ::std::option::Option<DurationAsStdSchemaProvider>,
+    #[doc = "Specifies where to write the obtained state parts."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub location: ::std::option::Option<ExternalStorageLocation>,
+    #[doc = "Use in case a node that dumps state to the external storage\ngets in trouble."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub restart_dump_for_shards: ::std::option::Option<::std::vec::Vec<ShardId>>,
+}
+impl ::std::convert::From<&DumpConfig> for DumpConfig {
+    fn from(value: &DumpConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for DumpConfig {
+    fn default() -> Self {
+        Self {
+            credentials_file: Default::default(),
+            iteration_delay: Default::default(),
+            location: Default::default(),
+            restart_dump_for_shards: Default::default(),
+        }
+    }
+}
+#[doc = "`DurationAsStdSchemaProvider`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"nanos\","]
+#[doc = "    \"secs\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"nanos\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"int32\""]
+#[doc = "    },"]
+#[doc = "    \"secs\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"int64\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DurationAsStdSchemaProvider {
+    pub nanos: i32,
+    pub secs: i64,
+}
+impl ::std::convert::From<&DurationAsStdSchemaProvider> for DurationAsStdSchemaProvider {
+    fn from(value: &DurationAsStdSchemaProvider) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Configuration for dynamic resharding feature"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configuration for dynamic resharding feature\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"max_number_of_shards\","]
+#[doc = "    \"memory_usage_threshold\","]
+#[doc = "    \"min_child_memory_usage\","]
+#[doc = "    \"min_epochs_between_resharding\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"max_number_of_shards\": {"]
+#[doc = "      \"description\": \"Maximum number of shards in the network.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"memory_usage_threshold\": {"]
+#[doc = "      \"description\": \"Memory threshold over which a shard is marked for a split.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"min_child_memory_usage\": {"]
+#[doc = "      \"description\": \"Minimum memory usage of a child shard.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"min_epochs_between_resharding\": {"]
+#[doc = "      \"description\": \"Minimum number of epochs until next resharding can be scheduled.\\n\\nSee [`CongestionControlConfig`] for more details.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DynamicReshardingConfigView {
+    #[doc = "Maximum number of shards in the network.\n\nSee [`CongestionControlConfig`] for more details."]
+    pub max_number_of_shards: u64,
+    #[doc = "Memory threshold over which a shard is marked for a split.\n\nSee [`CongestionControlConfig`] for more details."]
+    pub memory_usage_threshold: u64,
+    #[doc = "Minimum memory usage of a child shard.\n\nSee [`CongestionControlConfig`] for more details."]
+    pub min_child_memory_usage: u64,
+    #[doc = "Minimum number of epochs until next resharding can be scheduled.\n\nSee [`CongestionControlConfig`] for more details."]
+    pub min_epochs_between_resharding: u64,
+}
+impl limits::FieldLimits for DynamicReshardingConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("max_number_of_shards", "uint64", Some(0), None),
+        limits::FieldLimit::integer("memory_usage_threshold", "uint64", Some(0), None),
+        limits::FieldLimit::integer("min_child_memory_usage", "uint64", Some(0), None),
+        limits::FieldLimit::integer("min_epochs_between_resharding", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&DynamicReshardingConfigView> for DynamicReshardingConfigView {
+    fn from(value: &DynamicReshardingConfigView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`EpochSyncConfig`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"disable_epoch_sync_for_bootstrapping\": {"]
+#[doc = "      \"description\": \"If true, even if the node started from genesis, it will not perform epoch sync.\\nThere should be no reason to set this flag in production, because on both mainnet\\nand testnet it would be infeasible to catch up from genesis without epoch sync.\","]
+#[doc = "      \"default\": false,"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"epoch_sync_horizon\": {"]
+#[doc = "      \"description\": \"This serves as two purposes: (1) the node will not epoch sync and instead resort to\\nheader sync, if the genesis block is within this many blocks from the current block;\\n(2) the node will reject an epoch sync proof if the provided proof is for an epoch\\nthat is more than this many blocks behind the current block.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"ignore_epoch_sync_network_requests\": {"]
+#[doc = "      \"description\": \"If true, the node will ignore epoch sync requests from the network. It is strongly\\nrecommended not to set this flag, because it will prevent other nodes from\\nbootstrapping. This flag is only included as a kill-switch and may be removed in a\\nfuture release. Please note that epoch sync requests are heavily rate limited and\\ncached, and therefore should not affect the performance of the node or introduce\\nany non-negligible increase in network traffic.\","]
+#[doc = "      \"default\": false,"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"timeout_for_epoch_sync\": {"]
+#[doc = "      \"description\": \"Timeout for epoch sync requests. The node will continue retrying indefinitely even\\nif this timeout is exceeded.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DurationAsStdSchemaProvider\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct EpochSyncConfig {
+    #[doc = "If true, even if the node started from genesis, it will not perform epoch sync.\nThere should be no reason to set this flag in production, because on both mainnet\nand testnet it would be infeasible to catch up from genesis without epoch sync."]
+    #[serde(default)]
+    pub disable_epoch_sync_for_bootstrapping: bool,
+    #[doc = "This serves as two purposes: (1) the node will not epoch sync and instead resort to\nheader sync, if the genesis block is within this many blocks from the current block;\n(2) the node will reject an epoch sync proof if the provided proof is for an epoch\nthat is more than this many blocks behind the current block."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_sync_horizon: ::std::option::Option<u64>,
+    #[doc = "If true, the node will ignore epoch sync requests from the network. It is strongly\nrecommended not to set this flag, because it will prevent other nodes from\nbootstrapping. This flag is only included as a kill-switch and may be removed in a\nfuture release. Please note that epoch sync requests are heavily rate limited and\ncached, and therefore should not affect the performance of the node or introduce\nany non-negligible increase in network traffic."]
+    #[serde(default)]
+    pub ignore_epoch_sync_network_requests: bool,
+    #[doc = "Timeout for epoch sync requests. The node will continue retrying indefinitely even\nif this timeout is exceeded."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub timeout_for_epoch_sync: ::std::option::Option<DurationAsStdSchemaProvider>,
+}
+impl limits::FieldLimits for EpochSyncConfig {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "epoch_sync_horizon",
+        "uint64",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&EpochSyncConfig> for EpochSyncConfig {
+    fn from(value: &EpochSyncConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for EpochSyncConfig {
+    fn default() -> Self {
+        Self {
+            disable_epoch_sync_for_bootstrapping: Default::default(),
+            epoch_sync_horizon: Default::default(),
+            ignore_epoch_sync_network_requests: Default::default(),
+            timeout_for_epoch_sync: Default::default(),
+        }
+    }
+}
+#[doc = "Typed view of ExtCostsConfig to preserve JSON output field names in protocol\nconfig RPC output."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Typed view of ExtCostsConfig to preserve JSON output field names in protocol\\nconfig RPC output.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"alt_bn128_g1_multiexp_base\": {"]
+#[doc = "      \"description\": \"Base cost for multiexp\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"alt_bn128_g1_multiexp_element\": {"]
+#[doc = "      \"description\": \"Per element cost for multiexp\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"alt_bn128_g1_sum_base\": {"]
+#[doc = "      \"description\": \"Base cost for sum\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"alt_bn128_g1_sum_element\": {"]
+#[doc = "      \"description\": \"Per element cost for sum\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"alt_bn128_pairing_check_base\": {"]
+#[doc = "      \"description\": \"Base cost for pairing check\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"alt_bn128_pairing_check_element\": {"]
+#[doc = "      \"description\": \"Per element cost for pairing check\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"base\": {"]
+#[doc = "      \"description\": \"Base cost for calling a host function.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"bls12381_g1_multiexp_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_g1_multiexp_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_g2_multiexp_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_g2_multiexp_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_map_fp2_to_g2_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_map_fp2_to_g2_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_map_fp_to_g1_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_map_fp_to_g1_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p1_decompress_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p1_decompress_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p1_sum_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p1_sum_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p2_decompress_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p2_decompress_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p2_sum_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_p2_sum_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_pairing_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"bls12381_pairing_element\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"contract_compile_base\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"contract_compile_bytes\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"contract_loading_base\": {"]
+#[doc = "      \"description\": \"Base cost of loading a pre-compiled contract\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"contract_loading_bytes\": {"]
+#[doc = "      \"description\": \"Cost per byte of loading a pre-compiled contract\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"ecrecover_base\": {"]
+#[doc = "      \"description\": \"Cost of calling ecrecover\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"ed25519_verify_base\": {"]
+#[doc = "      \"description\": \"Cost of getting ed25519 base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"ed25519_verify_byte\": {"]
+#[doc = "      \"description\": \"Cost of getting ed25519 per byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"keccak256_base\": {"]
+#[doc = "      \"description\": \"Cost of getting sha256 base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"keccak256_byte\": {"]
+#[doc = "      \"description\": \"Cost of getting sha256 per byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"keccak512_base\": {"]
+#[doc = "      \"description\": \"Cost of getting sha256 base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"keccak512_byte\": {"]
+#[doc = "      \"description\": \"Cost of getting sha256 per byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"log_base\": {"]
+#[doc = "      \"description\": \"Cost for calling logging.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"log_byte\": {"]
+#[doc = "      \"description\": \"Cost for logging per byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"promise_and_base\": {"]
+#[doc = "      \"description\": \"Cost for calling `promise_and`\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"promise_and_per_promise\": {"]
+#[doc = "      \"description\": \"Cost for calling `promise_and` for each promise\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"promise_return\": {"]
+#[doc = "      \"description\": \"Cost for calling `promise_return`\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"read_cached_trie_node\": {"]
+#[doc = "      \"description\": \"Cost for reading trie node from memory\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"read_memory_base\": {"]
+#[doc = "      \"description\": \"Base cost for guest memory read\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"read_memory_byte\": {"]
+#[doc = "      \"description\": \"Cost for guest memory read\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"read_register_base\": {"]
+#[doc = "      \"description\": \"Base cost for reading from register\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"read_register_byte\": {"]
+#[doc = "      \"description\": \"Cost for reading byte from register\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"ripemd160_base\": {"]
+#[doc = "      \"description\": \"Cost of getting ripemd160 base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"ripemd160_block\": {"]
+#[doc = "      \"description\": \"Cost of getting ripemd160 per message block\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"sha256_base\": {"]
+#[doc = "      \"description\": \"Cost of getting sha256 base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"sha256_byte\": {"]
+#[doc = "      \"description\": \"Cost of getting sha256 per byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_has_key_base\": {"]
+#[doc = "      \"description\": \"Storage trie check for key existence cost base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_has_key_byte\": {"]
+#[doc = "      \"description\": \"Storage trie check for key existence per key byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_create_from_byte\": {"]
+#[doc = "      \"description\": \"Create trie range iterator cost per byte of from key.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_create_prefix_base\": {"]
+#[doc = "      \"description\": \"Create trie prefix iterator cost base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_create_prefix_byte\": {"]
+#[doc = "      \"description\": \"Create trie prefix iterator cost per byte.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_create_range_base\": {"]
+#[doc = "      \"description\": \"Create trie range iterator cost base\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_create_to_byte\": {"]
+#[doc = "      \"description\": \"Create trie range iterator cost per byte of to key.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_next_base\": {"]
+#[doc = "      \"description\": \"Trie iterator per key base cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_next_key_byte\": {"]
+#[doc = "      \"description\": \"Trie iterator next key byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_iter_next_value_byte\": {"]
+#[doc = "      \"description\": \"Trie iterator next key byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_large_read_overhead_base\": {"]
+#[doc = "      \"description\": \"Storage trie read key overhead base cost, when doing large reads\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_large_read_overhead_byte\": {"]
+#[doc = "      \"description\": \"Storage trie read key overhead  per-byte cost, when doing large reads\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_read_base\": {"]
+#[doc = "      \"description\": \"Storage trie read key base cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_read_key_byte\": {"]
+#[doc = "      \"description\": \"Storage trie read key per byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_read_value_byte\": {"]
+#[doc = "      \"description\": \"Storage trie read value cost per byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_remove_base\": {"]
+#[doc = "      \"description\": \"Remove key from trie base cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_remove_key_byte\": {"]
+#[doc = "      \"description\": \"Remove key from trie per byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_remove_ret_value_byte\": {"]
+#[doc = "      \"description\": \"Remove key from trie ret value byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_write_base\": {"]
+#[doc = "      \"description\": \"Storage trie write key base cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_write_evicted_byte\": {"]
+#[doc = "      \"description\": \"Storage trie write cost per byte of evicted value.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_write_key_byte\": {"]
+#[doc = "      \"description\": \"Storage trie write key per byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_write_value_byte\": {"]
+#[doc = "      \"description\": \"Storage trie write value per byte cost\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"touching_trie_node\": {"]
+#[doc = "      \"description\": \"Cost per reading trie node from DB\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"utf16_decoding_base\": {"]
+#[doc = "      \"description\": \"Base cost of decoding utf16. It's used for `log_utf16`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"utf16_decoding_byte\": {"]
+#[doc = "      \"description\": \"Cost per byte of decoding utf16. It's used for `log_utf16`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"utf8_decoding_base\": {"]
+#[doc = "      \"description\": \"Base cost of decoding utf8. It's used for `log_utf8` and `panic_utf8`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"utf8_decoding_byte\": {"]
+#[doc = "      \"description\": \"Cost per byte of decoding utf8. It's used for `log_utf8` and `panic_utf8`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"validator_stake_base\": {"]
+#[doc = "      \"description\": \"Cost of calling `validator_stake`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"validator_total_stake_base\": {"]
+#[doc = "      \"description\": \"Cost of calling `validator_total_stake`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"write_memory_base\": {"]
+#[doc = "      \"description\": \"Base cost for guest memory write\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"write_memory_byte\": {"]
+#[doc = "      \"description\": \"Cost for guest memory write per byte\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"write_register_base\": {"]
+#[doc = "      \"description\": \"Base cost for writing into register\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"write_register_byte\": {"]
+#[doc = "      \"description\": \"Cost for writing byte into register\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"yield_create_base\": {"]
+#[doc = "      \"description\": \"Base cost for creating a yield promise.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"yield_create_byte\": {"]
+#[doc = "      \"description\": \"Per byte cost of arguments and method name.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"yield_resume_base\": {"]
+#[doc = "      \"description\": \"Base cost for resuming a yield receipt.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"yield_resume_byte\": {"]
+#[doc = "      \"description\": \"Per byte cost of resume payload.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExtCostsConfigView {
+    #[doc = "Base cost for multiexp"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub alt_bn128_g1_multiexp_base: ::std::option::Option<NearGas>,
+    #[doc = "Per element cost for multiexp"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub alt_bn128_g1_multiexp_element: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for sum"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub alt_bn128_g1_sum_base: ::std::option::Option<NearGas>,
+    #[doc = "Per element cost for sum"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub alt_bn128_g1_sum_element: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for pairing check"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub alt_bn128_pairing_check_base: ::std::option::Option<NearGas>,
+    #[doc = "Per element cost for pairing check"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub alt_bn128_pairing_check_element: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for calling a host function."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_g1_multiexp_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_g1_multiexp_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_g2_multiexp_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_g2_multiexp_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_map_fp2_to_g2_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_map_fp2_to_g2_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_map_fp_to_g1_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_map_fp_to_g1_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p1_decompress_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p1_decompress_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p1_sum_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p1_sum_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p2_decompress_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p2_decompress_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p2_sum_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_p2_sum_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_pairing_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub bls12381_pairing_element: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub contract_compile_base: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub contract_compile_bytes: ::std::option::Option<NearGas>,
+    #[doc = "Base cost of loading a pre-compiled contract"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub contract_loading_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost per byte of loading a pre-compiled contract"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub contract_loading_bytes: ::std::option::Option<NearGas>,
+    #[doc = "Cost of calling ecrecover"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ecrecover_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting ed25519 base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ed25519_verify_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting ed25519 per byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ed25519_verify_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting sha256 base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub keccak256_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting sha256 per byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub keccak256_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting sha256 base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub keccak512_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting sha256 per byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub keccak512_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost for calling logging."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub log_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost for logging per byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub log_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost for calling `promise_and`"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub promise_and_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost for calling `promise_and` for each promise"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub promise_and_per_promise: ::std::option::Option<NearGas>,
+    #[doc = "Cost for calling `promise_return`"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub promise_return: ::std::option::Option<NearGas>,
+    #[doc = "Cost for reading trie node from memory"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub read_cached_trie_node: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for guest memory read"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub read_memory_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost for guest memory read"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub read_memory_byte: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for reading from register"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub read_register_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost for reading byte from register"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub read_register_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting ripemd160 base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ripemd160_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting ripemd160 per message block"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ripemd160_block: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting sha256 base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sha256_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of getting sha256 per byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sha256_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie check for key existence cost base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_has_key_base: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie check for key existence per key byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_has_key_byte: ::std::option::Option<NearGas>,
+    #[doc = "Create trie range iterator cost per byte of from key."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_create_from_byte: ::std::option::Option<NearGas>,
+    #[doc = "Create trie prefix iterator cost base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_create_prefix_base: ::std::option::Option<NearGas>,
+    #[doc = "Create trie prefix iterator cost per byte."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_create_prefix_byte: ::std::option::Option<NearGas>,
+    #[doc = "Create trie range iterator cost base"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_create_range_base: ::std::option::Option<NearGas>,
+    #[doc = "Create trie range iterator cost per byte of to key."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_create_to_byte: ::std::option::Option<NearGas>,
+    #[doc = "Trie iterator per key base cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_next_base: ::std::option::Option<NearGas>,
+    #[doc = "Trie iterator next key byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_next_key_byte: ::std::option::Option<NearGas>,
+    #[doc = "Trie iterator next key byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_iter_next_value_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie read key overhead base cost, when doing large reads"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_large_read_overhead_base: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie read key overhead  per-byte cost, when doing large reads"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_large_read_overhead_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie read key base cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_read_base: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie read key per byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_read_key_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie read value cost per byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_read_value_byte: ::std::option::Option<NearGas>,
+    #[doc = "Remove key from trie base cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_remove_base: ::std::option::Option<NearGas>,
+    #[doc = "Remove key from trie per byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_remove_key_byte: ::std::option::Option<NearGas>,
+    #[doc = "Remove key from trie ret value byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_remove_ret_value_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie write key base cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_write_base: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie write cost per byte of evicted value."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_write_evicted_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie write key per byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_write_key_byte: ::std::option::Option<NearGas>,
+    #[doc = "Storage trie write value per byte cost"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_write_value_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost per reading trie node from DB"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub touching_trie_node: ::std::option::Option<NearGas>,
+    #[doc = "Base cost of decoding utf16. It's used for `log_utf16`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub utf16_decoding_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost per byte of decoding utf16. It's used for `log_utf16`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub utf16_decoding_byte: ::std::option::Option<NearGas>,
+    #[doc = "Base cost of decoding utf8. It's used for `log_utf8` and `panic_utf8`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub utf8_decoding_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost per byte of decoding utf8. It's used for `log_utf8` and `panic_utf8`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub utf8_decoding_byte: ::std::option::Option<NearGas>,
+    #[doc = "Cost of calling `validator_stake`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub validator_stake_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost of calling `validator_total_stake`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub validator_total_stake_base: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for guest memory write"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub write_memory_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost for guest memory write per byte"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub write_memory_byte: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for writing into register"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub write_register_base: ::std::option::Option<NearGas>,
+    #[doc = "Cost for writing byte into register"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub write_register_byte: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for creating a yield promise."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub yield_create_base: ::std::option::Option<NearGas>,
+    #[doc = "Per byte cost of arguments and method name."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub yield_create_byte: ::std::option::Option<NearGas>,
+    #[doc = "Base cost for resuming a yield receipt."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub yield_resume_base: ::std::option::Option<NearGas>,
+    #[doc = "Per byte cost of resume payload."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub yield_resume_byte: ::std::option::Option<NearGas>,
+}
+impl ::std::convert::From<&ExtCostsConfigView> for ExtCostsConfigView {
+    fn from(value: &ExtCostsConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for ExtCostsConfigView {
+    fn default() -> Self {
+        Self {
+            alt_bn128_g1_multiexp_base: Default::default(),
+            alt_bn128_g1_multiexp_element: Default::default(),
+            alt_bn128_g1_sum_base: Default::default(),
+            alt_bn128_g1_sum_element: Default::default(),
+            alt_bn128_pairing_check_base: Default::default(),
+            alt_bn128_pairing_check_element: Default::default(),
+            base: Default::default(),
+            bls12381_g1_multiexp_base: Default::default(),
+            bls12381_g1_multiexp_element: Default::default(),
+            bls12381_g2_multiexp_base: Default::default(),
+            bls12381_g2_multiexp_element: Default::default(),
+            bls12381_map_fp2_to_g2_base: Default::default(),
+            bls12381_map_fp2_to_g2_element: Default::default(),
+            bls12381_map_fp_to_g1_base: Default::default(),
+            bls12381_map_fp_to_g1_element: Default::default(),
+            bls12381_p1_decompress_base: Default::default(),
+            bls12381_p1_decompress_element: Default::default(),
+            bls12381_p1_sum_base: Default::default(),
+            bls12381_p1_sum_element: Default::default(),
+            bls12381_p2_decompress_base: Default::default(),
+            bls12381_p2_decompress_element: Default::default(),
+            bls12381_p2_sum_base: Default::default(),
+            bls12381_p2_sum_element: Default::default(),
+            bls12381_pairing_base: Default::default(),
+            bls12381_pairing_element: Default::default(),
+            contract_compile_base: Default::default(),
+            contract_compile_bytes: Default::default(),
+            contract_loading_base: Default::default(),
+            contract_loading_bytes: Default::default(),
+            ecrecover_base: Default::default(),
+            ed25519_verify_base: Default::default(),
+            ed25519_verify_byte: Default::default(),
+            keccak256_base: Default::default(),
+            keccak256_byte: Default::default(),
+            keccak512_base: Default::default(),
+            keccak512_byte: Default::default(),
+            log_base: Default::default(),
+            log_byte: Default::default(),
+            promise_and_base: Default::default(),
+            promise_and_per_promise: Default::default(),
+            promise_return: Default::default(),
+            read_cached_trie_node: Default::default(),
+            read_memory_base: Default::default(),
+            read_memory_byte: Default::default(),
+            read_register_base: Default::default(),
+            read_register_byte: Default::default(),
+            ripemd160_base: Default::default(),
+            ripemd160_block: Default::default(),
+            sha256_base: Default::default(),
+            sha256_byte: Default::default(),
+            storage_has_key_base: Default::default(),
+            storage_has_key_byte: Default::default(),
+            storage_iter_create_from_byte: Default::default(),
+            storage_iter_create_prefix_base: Default::default(),
+            storage_iter_create_prefix_byte: Default::default(),
+            storage_iter_create_range_base: Default::default(),
+            storage_iter_create_to_byte: Default::default(),
+            storage_iter_next_base: Default::default(),
+            storage_iter_next_key_byte: Default::default(),
+            storage_iter_next_value_byte: Default::default(),
+            storage_large_read_overhead_base: Default::default(),
+            storage_large_read_overhead_byte: Default::default(),
+            storage_read_base: Default::default(),
+            storage_read_key_byte: Default::default(),
+            storage_read_value_byte: Default::default(),
+            storage_remove_base: Default::default(),
+            storage_remove_key_byte: Default::default(),
+            storage_remove_ret_value_byte: Default::default(),
+            storage_write_base: Default::default(),
+            storage_write_evicted_byte: Default::default(),
+            storage_write_key_byte: Default::default(),
+            storage_write_value_byte: Default::default(),
+            touching_trie_node: Default::default(),
+            utf16_decoding_base: Default::default(),
+            utf16_decoding_byte: Default::default(),
+            utf8_decoding_base: Default::default(),
+            utf8_decoding_byte: Default::default(),
+            validator_stake_base: Default::default(),
+            validator_total_stake_base: Default::default(),
+            write_memory_base: Default::default(),
+            write_memory_byte: Default::default(),
+            write_register_base: Default::default(),
+            write_register_byte: Default::default(),
+            yield_create_base: Default::default(),
+            yield_create_byte: Default::default(),
+            yield_resume_base: Default::default(),
+            yield_resume_byte: Default::default(),
+        }
+    }
+}
+#[doc = "`ExternalStorageConfig`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"external_storage_fallback_threshold\": {"]
+#[doc = "      \"description\": \"The number of attempts the node will make to obtain a part from peers in\\nthe network before it fetches from external storage.\","]
+#[doc = "      \"default\": 3,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"location\": {"]
+#[doc = "      \"description\": \"Location of state parts.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExternalStorageLocation\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"num_concurrent_requests\": {"]
+#[doc = "      \"description\": \"When fetching state parts from external storage, throttle fetch requests\\nto this many concurrent requests.\","]
+#[doc = "      \"default\": 25,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_concurrent_requests_during_catchup\": {"]
+#[doc = "      \"description\": \"During catchup, the node will use a different number of concurrent requests\\nto reduce the performance impact of state sync.\","]
+#[doc = "      \"default\": 5,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExternalStorageConfig {
+    #[doc = "The number of attempts the node will make to obtain a part from peers in\nthe network before it fetches from external storage."]
+    #[serde(default = "defaults::default_u64::<u64, 3>")]
+    pub external_storage_fallback_threshold: u64,
+    #[doc = "Location of state parts."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub location: ::std::option::Option<ExternalStorageLocation>,
+    #[doc = "When fetching state parts from external storage, throttle fetch requests\nto this many concurrent requests."]
+    #[serde(default = "defaults::default_u64::<u8, 25>")]
+    pub num_concurrent_requests: u8,
+    #[doc = "During catchup, the node will use a different number of concurrent requests\nto reduce the performance impact of state sync."]
+    #[serde(default = "defaults::default_u64::<u8, 5>")]
+    pub num_concurrent_requests_during_catchup: u8,
+}
+impl limits::FieldLimits for ExternalStorageConfig {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer(
+            "external_storage_fallback_threshold",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("num_concurrent_requests", "uint8", Some(0), Some(255)),
+        limits::FieldLimit::integer(
+            "num_concurrent_requests_during_catchup",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+    ];
+}
+impl ::std::convert::From<&ExternalStorageConfig> for ExternalStorageConfig {
+    fn from(value: &ExternalStorageConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for ExternalStorageConfig {
+    fn default() -> Self {
+        Self {
+            external_storage_fallback_threshold: defaults::default_u64::<u64, 3>(),
+            location: Default::default(),
+            num_concurrent_requests: defaults::default_u64::<u8, 25>(),
+            num_concurrent_requests_during_catchup: defaults::default_u64::<u8, 5>(),
+        }
+    }
+}
+#[doc = "Supported external storage backends and their minimal config."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Supported external storage backends and their minimal config.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"S3\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"S3\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"bucket\","]
+#[doc = "            \"region\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"bucket\": {"]
+#[doc = "              \"description\": \"Location on S3.\","]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            },"]
+#[doc = "            \"region\": {"]
+#[doc = "              \"description\": \"Data may only be available in certain locations.\","]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Local filesystem root for storing data.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Filesystem\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Filesystem\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"root_dir\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"root_dir\": {"]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Google Cloud Storage bucket name.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"GCS\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"GCS\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"bucket\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"bucket\": {"]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ExternalStorageLocation {
+    S3 {
+        #[doc = "Location on S3."]
+        bucket: ::std::string::String,
+        #[doc = "Data may only be available in certain locations."]
+        region: ::std::string::String,
+    },
+    #[doc = "Local filesystem root for storing data."]
+    Filesystem { root_dir: ::std::string::String },
+    #[doc = "Google Cloud Storage bucket name."]
+    #[serde(rename = "GCS")]
+    Gcs { bucket: ::std::string::String },
+}
+impl ::std::convert::From<&Self> for ExternalStorageLocation {
+    fn from(value: &ExternalStorageLocation) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Costs associated with an object that can only be sent over the network (and executed\nby the receiver).\nNOTE: `send_sir` or `send_not_sir` fees are usually burned when the item is being created.\nAnd `execution` fee is burned when the item is being executed."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Costs associated with an object that can only be sent over the network (and executed\\nby the receiver).\\nNOTE: `send_sir` or `send_not_sir` fees are usually burned when the item is being created.\\nAnd `execution` fee is burned when the item is being executed.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"execution\","]
+#[doc = "    \"send_not_sir\","]
+#[doc = "    \"send_sir\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"execution\": {"]
+#[doc = "      \"description\": \"Fee for executing the object.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"send_not_sir\": {"]
+#[doc = "      \"description\": \"Fee for sending an object potentially across the shards.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"send_sir\": {"]
+#[doc = "      \"description\": \"Fee for sending an object from the sender to itself, guaranteeing that it does not leave\\nthe shard.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Fee {
+    #[doc = "Fee for executing the object."]
+    pub execution: NearGas,
+    #[doc = "Fee for sending an object potentially across the shards."]
+    pub send_not_sir: NearGas,
+    #[doc = "Fee for sending an object from the sender to itself, guaranteeing that it does not leave\nthe shard."]
+    pub send_sir: NearGas,
+}
+impl ::std::convert::From<&Fee> for Fee {
+    fn from(value: &Fee) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Configuration for garbage collection."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configuration for garbage collection.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"gc_blocks_limit\": {"]
+#[doc = "      \"description\": \"Maximum number of blocks to garbage collect at every garbage collection\\ncall.\","]
+#[doc = "      \"default\": 2,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"gc_fork_clean_step\": {"]
+#[doc = "      \"description\": \"Maximum number of height to go through at each garbage collection step\\nwhen cleaning forks during garbage collection.\","]
+#[doc = "      \"default\": 100,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"gc_num_epochs_to_keep\": {"]
+#[doc = "      \"description\": \"Number of epochs for which we keep store data.\","]
+#[doc = "      \"default\": 5,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"gc_step_period\": {"]
+#[doc = "      \"description\": \"How often gc should be run\","]
+#[doc = "      \"default\": {"]
+#[doc = "        \"nanos\": 500000000,"]
+#[doc = "        \"secs\": 0"]
+#[doc = "      },"]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DurationAsStdSchemaProvider\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GcConfig {
+    #[doc = "Maximum number of blocks to garbage collect at every garbage collection\ncall."]
+    #[serde(default = "defaults::default_u64::<u64, 2>")]
+    pub gc_blocks_limit: u64,
+    #[doc = "Maximum number of height to go through at each garbage collection step\nwhen cleaning forks during garbage collection."]
+    #[serde(default = "defaults::default_u64::<u64, 100>")]
+    pub gc_fork_clean_step: u64,
+    #[doc = "Number of epochs for which we keep store data."]
+    #[serde(default = "defaults::default_u64::<u64, 5>")]
+    pub gc_num_epochs_to_keep: u64,
+    #[doc = "How often gc should be run"]
+    #[serde(default = "defaults::gc_config_gc_step_period")]
+    pub gc_step_period: DurationAsStdSchemaProvider,
+}
+impl limits::FieldLimits for GcConfig {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("gc_blocks_limit", "uint64", Some(0), None),
+        limits::FieldLimit::integer("gc_fork_clean_step", "uint64", Some(0), None),
+        limits::FieldLimit::integer("gc_num_epochs_to_keep", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&GcConfig> for GcConfig {
+    fn from(value: &GcConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for GcConfig {
+    fn default() -> Self {
+        Self {
+            gc_blocks_limit: defaults::default_u64::<u64, 2>(),
+            gc_fork_clean_step: defaults::default_u64::<u64, 100>(),
+            gc_num_epochs_to_keep: defaults::default_u64::<u64, 5>(),
+            gc_step_period: defaults::gc_config_gc_step_period(),
+        }
+    }
+}
+#[doc = "`GenesisConfig`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"avg_hidden_validator_seats_per_shard\","]
+#[doc = "    \"block_producer_kickout_threshold\","]
+#[doc = "    \"chain_id\","]
+#[doc = "    \"chunk_producer_kickout_threshold\","]
+#[doc = "    \"dynamic_resharding\","]
+#[doc = "    \"epoch_length\","]
+#[doc = "    \"fishermen_threshold\","]
+#[doc = "    \"gas_limit\","]
+#[doc = "    \"gas_price_adjustment_rate\","]
+#[doc = "    \"genesis_height\","]
+#[doc = "    \"genesis_time\","]
+#[doc = "    \"max_gas_price\","]
+#[doc = "    \"max_inflation_rate\","]
+#[doc = "    \"min_gas_price\","]
+#[doc = "    \"num_block_producer_seats\","]
+#[doc = "    \"num_block_producer_seats_per_shard\","]
+#[doc = "    \"num_blocks_per_year\","]
+#[doc = "    \"protocol_reward_rate\","]
+#[doc = "    \"protocol_treasury_account\","]
+#[doc = "    \"protocol_version\","]
+#[doc = "    \"total_supply\","]
+#[doc = "    \"transaction_validity_period\","]
+#[doc = "    \"validators\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"avg_hidden_validator_seats_per_shard\": {"]
+#[doc = "      \"description\": \"Expected number of hidden validators per shard.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"block_producer_kickout_threshold\": {"]
+#[doc = "      \"description\": \"Threshold for kicking out block producers, between 0 and 100.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"chain_id\": {"]
+#[doc = "      \"description\": \"ID of the blockchain. This must be unique for every blockchain.\\nIf your testnet blockchains do not have unique chain IDs, you will have a bad time.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"chunk_producer_assignment_changes_limit\": {"]
+#[doc = "      \"description\": \"Limits the number of shard changes in chunk producer assignments,\\nif algorithm is able to choose assignment with better balance of\\nnumber of chunk producers for shards.\","]
+#[doc = "      \"default\": 5,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"chunk_producer_kickout_threshold\": {"]
+#[doc = "      \"description\": \"Threshold for kicking out chunk producers, between 0 and 100.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"chunk_validator_only_kickout_threshold\": {"]
+#[doc = "      \"description\": \"Threshold for kicking out nodes which are only chunk validators, between 0 and 100.\","]
+#[doc = "      \"default\": 80,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"dynamic_resharding\": {"]
+#[doc = "      \"description\": \"Enable dynamic re-sharding.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"epoch_length\": {"]
+#[doc = "      \"description\": \"Epoch length counted in block heights.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"fishermen_threshold\": {"]
+#[doc = "      \"description\": \"Fishermen stake threshold.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gas_limit\": {"]
+#[doc = "      \"description\": \"Initial gas limit.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gas_price_adjustment_rate\": {"]
+#[doc = "      \"description\": \"Gas price adjustment rate\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"genesis_height\": {"]
+#[doc = "      \"description\": \"Height of genesis block.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"genesis_time\": {"]
+#[doc = "      \"description\": \"Official time of blockchain start.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"format\": \"date-time\""]
+#[doc = "    },"]
+#[doc = "    \"max_gas_price\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"max_inflation_rate\": {"]
+#[doc = "      \"description\": \"Maximum inflation on the total supply every epoch.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"max_kickout_stake_perc\": {"]
+#[doc = "      \"description\": \"Max stake percentage of the validators we will kick out.\","]
+#[doc = "      \"default\": 100,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"min_gas_price\": {"]
+#[doc = "      \"description\": \"Minimum gas price. It is also the initial gas price.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"minimum_stake_divisor\": {"]
+#[doc = "      \"description\": \"The minimum stake required for staking is last seat price divided by this number.\","]
+#[doc = "      \"default\": 10,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"minimum_stake_ratio\": {"]
+#[doc = "      \"description\": \"The lowest ratio s/s_total any block producer can have.\\nSee <https://github.com/near/NEPs/pull/167> for details\","]
+#[doc = "      \"default\": ["]
+#[doc = "        1,"]
+#[doc = "        6250"]
+#[doc = "      ],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"minimum_validators_per_shard\": {"]
+#[doc = "      \"description\": \"The minimum number of validators each shard must have\","]
+#[doc = "      \"default\": 1,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_block_producer_seats\": {"]
+#[doc = "      \"description\": \"Number of block producer seats at genesis.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_block_producer_seats_per_shard\": {"]
+#[doc = "      \"description\": \"Defines number of shards and number of block producer seats per each shard at genesis.\\nNote: not used with protocol_feature_chunk_only_producers -- replaced by minimum_validators_per_shard\\nNote: not used before as all block producers produce chunks for all shards\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_blocks_per_year\": {"]
+#[doc = "      \"description\": \"Expected number of blocks per year\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_chunk_only_producer_seats\": {"]
+#[doc = "      \"description\": \"Deprecated.\","]
+#[doc = "      \"default\": 300,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_chunk_producer_seats\": {"]
+#[doc = "      \"description\": \"Number of chunk producers.\\nDon't mess it up with chunk-only producers feature which is deprecated.\","]
+#[doc = "      \"default\": 100,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_chunk_validator_seats\": {"]
+#[doc = "      \"default\": 300,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"online_max_threshold\": {"]
+#[doc = "      \"description\": \"Online maximum threshold above which validator gets full reward.\","]
+#[doc = "      \"default\": ["]
+#[doc = "        99,"]
+#[doc = "        100"]
+#[doc = "      ],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"online_min_threshold\": {"]
+#[doc = "      \"description\": \"Online minimum threshold below which validator doesn't receive reward.\","]
+#[doc = "      \"default\": ["]
+#[doc = "        9,"]
+#[doc = "        10"]
+#[doc = "      ],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"protocol_reward_rate\": {"]
+#[doc = "      \"description\": \"Protocol treasury rate\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"protocol_treasury_account\": {"]
+#[doc = "      \"description\": \"Protocol treasury account\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"protocol_upgrade_stake_threshold\": {"]
+#[doc = "      \"description\": \"Threshold of stake that needs to indicate that they ready for upgrade.\","]
+#[doc = "      \"default\": ["]
+#[doc = "        4,"]
+#[doc = "        5"]
+#[doc = "      ],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"protocol_version\": {"]
+#[doc = "      \"description\": \"Protocol version that this genesis works with.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"shard_layout\": {"]
+#[doc = "      \"description\": \"Layout information regarding how to split accounts to shards\","]
+#[doc = "      \"default\": {"]
+#[doc = "        \"V2\": {"]
+#[doc = "          \"boundary_accounts\": [],"]
+#[doc = "          \"id_to_index_map\": {"]
+#[doc = "            \"0\": 0"]
+#[doc = "          },"]
+#[doc = "          \"index_to_id_map\": {"]
+#[doc = "            \"0\": 0"]
+#[doc = "          },"]
+#[doc = "          \"shard_ids\": ["]
+#[doc = "            0"]
+#[doc = "          ],"]
+#[doc = "          \"version\": 0"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardLayout\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"shuffle_shard_assignment_for_chunk_producers\": {"]
+#[doc = "      \"description\": \"If true, shuffle the chunk producers across shards. In other words, if\\nthe shard assignments were `[S_0, S_1, S_2, S_3]` where `S_i` represents\\nthe set of chunk producers for shard `i`, if this flag were true, the\\nshard assignments might become, for example, `[S_2, S_0, S_3, S_1]`.\","]
+#[doc = "      \"default\": false,"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"target_validator_mandates_per_shard\": {"]
+#[doc = "      \"description\": \"Number of target chunk validator mandates for each shard.\","]
+#[doc = "      \"default\": 68,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"total_supply\": {"]
+#[doc = "      \"description\": \"Total supply of tokens at genesis.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transaction_validity_period\": {"]
+#[doc = "      \"description\": \"Number of blocks for which a given transaction is valid\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"use_production_config\": {"]
+#[doc = "      \"description\": \"This is only for test purposes. We hard code some configs for mainnet and testnet\\nin AllEpochConfig, and we want to have a way to test that code path. This flag is for that.\\nIf set to true, the node will use the same config override path as mainnet and testnet.\","]
+#[doc = "      \"default\": false,"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"validators\": {"]
+#[doc = "      \"description\": \"List of initial validators.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/AccountInfo\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GenesisConfig {
+    #[doc = "Expected number of hidden validators per shard."]
+    pub avg_hidden_validator_seats_per_shard: ::std::vec::Vec<u64>,
+    #[doc = "Threshold for kicking out block producers, between 0 and 100."]
+    pub block_producer_kickout_threshold: u8,
+    #[doc = "ID of the blockchain. This must be unique for every blockchain.\nIf your testnet blockchains do not have unique chain IDs, you will have a bad time."]
+    pub chain_id: ::std::string::String,
+    #[doc = "Limits the number of shard changes in chunk producer assignments,\nif algorithm is able to choose assignment with better balance of\nnumber of chunk producers for shards."]
+    #[serde(default = "defaults::default_u64::<u64, 5>")]
+    pub chunk_producer_assignment_changes_limit: u64,
+    #[doc = "Threshold for kicking out chunk producers, between 0 and 100."]
+    pub chunk_producer_kickout_threshold: u8,
+    #[doc = "Threshold for kicking out nodes which are only chunk validators, between 0 and 100."]
+    #[serde(default = "defaults::default_u64::<u8, 80>")]
+    pub chunk_validator_only_kickout_threshold: u8,
+    #[doc = "Enable dynamic re-sharding."]
+    pub dynamic_resharding: bool,
+    #[doc = "Epoch length counted in block heights."]
+    pub epoch_length: u64,
+    #[doc = "Fishermen stake threshold."]
+    pub fishermen_threshold: NearToken,
+    #[doc = "Initial gas limit."]
+    pub gas_limit: NearGas,
+    #[doc = "Gas price adjustment rate"]
+    pub gas_price_adjustment_rate: [i32; 2usize],
+    #[doc = "Height of genesis block."]
+    pub genesis_height: u64,
+    #[doc = "Official time of blockchain start."]
+    pub genesis_time: ::chrono::DateTime<::chrono::offset::Utc>,
+    pub max_gas_price: NearToken,
+    #[doc = "Maximum inflation on the total supply every epoch."]
+    pub max_inflation_rate: [i32; 2usize],
+    #[doc = "Max stake percentage of the validators we will kick out."]
+    #[serde(default = "defaults::default_u64::<u8, 100>")]
+    pub max_kickout_stake_perc: u8,
+    #[doc = "Minimum gas price. It is also the initial gas price."]
+    pub min_gas_price: NearToken,
+    #[doc = "The minimum stake required for staking is last seat price divided by this number."]
+    #[serde(default = "defaults::default_u64::<u64, 10>")]
+    pub minimum_stake_divisor: u64,
+    #[doc = "The lowest ratio s/s_total any block producer can have.\nSee <https://github.com/near/NEPs/pull/167> for details"]
+    #[serde(default = "defaults::genesis_config_minimum_stake_ratio")]
+    pub minimum_stake_ratio: [i32; 2usize],
+    #[doc = "The minimum number of validators each shard must have"]
+    #[serde(default = "defaults::default_u64::<u64, 1>")]
+    pub minimum_validators_per_shard: u64,
+    #[doc = "Number of block producer seats at genesis."]
+    pub num_block_producer_seats: u64,
+    #[doc = "Defines number of shards and number of block producer seats per each shard at genesis.\nNote: not used with protocol_feature_chunk_only_producers -- replaced by minimum_validators_per_shard\nNote: not used before as all block producers produce chunks for all shards"]
+    pub num_block_producer_seats_per_shard: ::std::vec::Vec<u64>,
+    #[doc = "Expected number of blocks per year"]
+    pub num_blocks_per_year: u64,
+    #[doc = "Deprecated."]
+    #[serde(default = "defaults::default_u64::<u64, 300>")]
+    pub num_chunk_only_producer_seats: u64,
+    #[doc = "Number of chunk producers.\nDon't mess it up with chunk-only producers feature which is deprecated."]
+    #[serde(default = "defaults::default_u64::<u64, 100>")]
+    pub num_chunk_producer_seats: u64,
+    #[serde(default = "defaults::default_u64::<u64, 300>")]
+    pub num_chunk_validator_seats: u64,
+    #[doc = "Online maximum threshold above which validator gets full reward."]
+    #[serde(default = "defaults::genesis_config_online_max_threshold")]
+    pub online_max_threshold: [i32; 2usize],
+    #[doc = "Online minimum threshold below which validator doesn't receive reward."]
+    #[serde(default = "defaults::genesis_config_online_min_threshold")]
+    pub online_min_threshold: [i32; 2usize],
+    #[doc = "Protocol treasury rate"]
+    pub protocol_reward_rate: [i32; 2usize],
+    #[doc = "Protocol treasury account"]
+    pub protocol_treasury_account: AccountId,
+    #[doc = "Threshold of stake that needs to indicate that they ready for upgrade."]
+    #[serde(default = "defaults::genesis_config_protocol_upgrade_stake_threshold")]
+    pub protocol_upgrade_stake_threshold: [i32; 2usize],
+    #[doc = "Protocol version that this genesis works with."]
+    pub protocol_version: u32,
+    #[doc = "Layout information regarding how to split accounts to shards"]
+    #[serde(default = "defaults::genesis_config_shard_layout")]
+    pub shard_layout: ShardLayout,
+    #[doc = "If true, shuffle the chunk producers across shards. In other words, if\nthe shard assignments were `[S_0, S_1, S_2, S_3]` where `S_i` represents\nthe set of chunk producers for shard `i`, if this flag were true, the\nshard assignments might become, for example, `[S_2, S_0, S_3, S_1]`."]
+    #[serde(default)]
+    pub shuffle_shard_assignment_for_chunk_producers: bool,
+    #[doc = "Number of target chunk validator mandates for each shard."]
+    #[serde(default = "defaults::default_u64::<u64, 68>")]
+    pub target_validator_mandates_per_shard: u64,
+    #[doc = "Total supply of tokens at genesis."]
+    pub total_supply: NearToken,
+    #[doc = "Number of blocks for which a given transaction is valid"]
+    pub transaction_validity_period: u64,
+    #[doc = "This is only for test purposes. We hard code some configs for mainnet and testnet\nin AllEpochConfig, and we want to have a way to test that code path. This flag is for that.\nIf set to true, the node will use the same config override path as mainnet and testnet."]
+    #[serde(default)]
+    pub use_production_config: bool,
+    #[doc = "List of initial validators."]
+    pub validators: ::std::vec::Vec<AccountInfo>,
+}
+impl limits::FieldLimits for GenesisConfig {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer(
+            "block_producer_kickout_threshold",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+        limits::FieldLimit::integer(
+            "chunk_producer_assignment_changes_limit",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer(
+            "chunk_producer_kickout_threshold",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+        limits::FieldLimit::integer(
+            "chunk_validator_only_kickout_threshold",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+        limits::FieldLimit::integer("epoch_length", "uint64", Some(0), None),
+        limits::FieldLimit::items("gas_price_adjustment_rate", Some(2), Some(2)),
+        limits::FieldLimit::integer("genesis_height", "uint64", Some(0), None),
+        limits::FieldLimit::items("max_inflation_rate", Some(2), Some(2)),
+        limits::FieldLimit::integer("max_kickout_stake_perc", "uint8", Some(0), Some(255)),
+        limits::FieldLimit::integer("minimum_stake_divisor", "uint64", Some(0), None),
+        limits::FieldLimit::items("minimum_stake_ratio", Some(2), Some(2)),
+        limits::FieldLimit::integer("minimum_validators_per_shard", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_block_producer_seats", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_blocks_per_year", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_chunk_only_producer_seats", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_chunk_producer_seats", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_chunk_validator_seats", "uint64", Some(0), None),
+        limits::FieldLimit::items("online_max_threshold", Some(2), Some(2)),
+        limits::FieldLimit::items("online_min_threshold", Some(2), Some(2)),
+        limits::FieldLimit::items("protocol_reward_rate", Some(2), Some(2)),
+        limits::FieldLimit::items("protocol_upgrade_stake_threshold", Some(2), Some(2)),
+        limits::FieldLimit::integer("protocol_version", "uint32", Some(0), None),
+        limits::FieldLimit::integer(
+            "target_validator_mandates_per_shard",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("transaction_validity_period", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&GenesisConfig> for GenesisConfig {
+    fn from(value: &GenesisConfig) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`GenesisConfigRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"GenesisConfigRequest\","]
+#[doc = "  \"type\": \"null\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct GenesisConfigRequest(pub ());
+impl ::std::ops::Deref for GenesisConfigRequest {
+    type Target = ();
+    fn deref(&self) -> &() {
+        &self.0
+    }
+}
+impl ::std::convert::From<GenesisConfigRequest> for () {
+    fn from(value: GenesisConfigRequest) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&GenesisConfigRequest> for GenesisConfigRequest {
+    fn from(value: &GenesisConfigRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<()> for GenesisConfigRequest {
+    fn from(value: ()) -> Self {
+        Self(value)
+    }
+}
+#[doc = "Describes limits for VM and Runtime.\nTODO #4139: consider switching to strongly-typed wrappers instead of raw quantities"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes limits for VM and Runtime.\\nTODO #4139: consider switching to strongly-typed wrappers instead of raw quantities\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id_validity_rules_version\": {"]
+#[doc = "      \"description\": \"Whether to enforce account_id well-formed-ness where it wasn't enforced\\nhistorically.\","]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountIdValidityRulesVersion\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"initial_memory_pages\": {"]
+#[doc = "      \"description\": \"The initial number of memory pages.\\nNOTE: It's not a limiter itself, but it's a value we use for initial_memory_pages.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_actions_per_receipt\": {"]
+#[doc = "      \"description\": \"Max number of actions per receipt.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_arguments_length\": {"]
+#[doc = "      \"description\": \"Max length of arguments in a function call action.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_contract_size\": {"]
+#[doc = "      \"description\": \"Max contract size\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_elements_per_contract_table\": {"]
+#[doc = "      \"description\": \"If present, stores max number of elements in a single contract's table\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_functions_number_per_contract\": {"]
+#[doc = "      \"description\": \"If present, stores max number of functions in one contract\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_gas_burnt\": {"]
+#[doc = "      \"description\": \"Max amount of gas that can be used, excluding gas attached to promises.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_length_method_name\": {"]
+#[doc = "      \"description\": \"Max length of any method name (without terminating character).\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_length_returned_data\": {"]
+#[doc = "      \"description\": \"Max length of returned data\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_length_storage_key\": {"]
+#[doc = "      \"description\": \"Max storage key size\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_length_storage_value\": {"]
+#[doc = "      \"description\": \"Max storage value size\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_locals_per_contract\": {"]
+#[doc = "      \"description\": \"If present, stores max number of locals declared globally in one contract\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_memory_pages\": {"]
+#[doc = "      \"description\": \"What is the maximal memory pages amount is allowed to have for a contract.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_number_bytes_method_names\": {"]
+#[doc = "      \"description\": \"Max total length of all method names (including terminating character) for a function call\\npermission access key.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_number_input_data_dependencies\": {"]
+#[doc = "      \"description\": \"Max number of input data dependencies\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_number_logs\": {"]
+#[doc = "      \"description\": \"Maximum number of log entries.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_number_registers\": {"]
+#[doc = "      \"description\": \"Maximum number of registers that can be used simultaneously.\\n\\nNote that due to an implementation quirk [read: a bug] in VMLogic, if we\\nhave this number of registers, no subsequent writes to the registers\\nwill succeed even if they replace an existing register.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_promises_per_function_call_action\": {"]
+#[doc = "      \"description\": \"Max number of promises that a function call can create\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_receipt_size\": {"]
+#[doc = "      \"description\": \"Max receipt size\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_register_size\": {"]
+#[doc = "      \"description\": \"Maximum number of bytes that can be stored in a single register.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_stack_height\": {"]
+#[doc = "      \"description\": \"How tall the stack is allowed to grow?\\n\\nSee <https://wiki.parity.io/WebAssembly-StackHeight> to find out how the stack frame cost\\nis calculated.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_tables_per_contract\": {"]
+#[doc = "      \"description\": \"If present, stores max number of tables declared globally in one contract\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_total_log_length\": {"]
+#[doc = "      \"description\": \"Maximum total length in bytes of all log messages.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_total_prepaid_gas\": {"]
+#[doc = "      \"description\": \"Max total prepaid gas for all function call actions per receipt.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_transaction_size\": {"]
+#[doc = "      \"description\": \"Max transaction size\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"max_yield_payload_size\": {"]
+#[doc = "      \"description\": \"Maximum number of bytes for payload passed over a yield resume.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"per_receipt_storage_proof_size_limit\": {"]
+#[doc = "      \"description\": \"Hard limit on the size of storage proof generated while executing a single receipt.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"registers_memory_limit\": {"]
+#[doc = "      \"description\": \"Limit of memory used by registers.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"yield_timeout_length_in_blocks\": {"]
+#[doc = "      \"description\": \"Number of blocks after which a yielded promise times out.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LimitConfig {
+    #[doc = "Whether to enforce account_id well-formed-ness where it wasn't enforced\nhistorically."]
+    #[serde(default = "defaults::limit_config_account_id_validity_rules_version")]
+    pub account_id_validity_rules_version: AccountIdValidityRulesVersion,
+    #[doc = "The initial number of memory pages.\nNOTE: It's not a limiter itself, but it's a value we use for initial_memory_pages."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub initial_memory_pages: ::std::option::Option<u32>,
+    #[doc = "Max number of actions per receipt."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_actions_per_receipt: ::std::option::Option<u64>,
+    #[doc = "Max length of arguments in a function call action."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_arguments_length: ::std::option::Option<u64>,
+    #[doc = "Max contract size"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_contract_size: ::std::option::Option<u64>,
+    #[doc = "If present, stores max number of elements in a single contract's table"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_elements_per_contract_table: ::std::option::Option<u32>,
+    #[doc = "If present, stores max number of functions in one contract"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_functions_number_per_contract: ::std::option::Option<u64>,
+    #[doc = "Max amount of gas that can be used, excluding gas attached to promises."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_gas_burnt: ::std::option::Option<NearGas>,
+    #[doc = "Max length of any method name (without terminating character)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_length_method_name: ::std::option::Option<u64>,
+    #[doc = "Max length of returned data"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_length_returned_data: ::std::option::Option<u64>,
+    #[doc = "Max storage key size"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_length_storage_key: ::std::option::Option<u64>,
+    #[doc = "Max storage value size"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_length_storage_value: ::std::option::Option<u64>,
+    #[doc = "If present, stores max number of locals declared globally in one contract"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_locals_per_contract: ::std::option::Option<u64>,
+    #[doc = "What is the maximal memory pages amount is allowed to have for a contract."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_memory_pages: ::std::option::Option<u32>,
+    #[doc = "Max total length of all method names (including terminating character) for a function call\npermission access key."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_number_bytes_method_names: ::std::option::Option<u64>,
+    #[doc = "Max number of input data dependencies"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_number_input_data_dependencies: ::std::option::Option<u64>,
+    #[doc = "Maximum number of log entries."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_number_logs: ::std::option::Option<u64>,
+    #[doc = "Maximum number of registers that can be used simultaneously.\n\nNote that due to an implementation quirk [read: a bug] in VMLogic, if we\nhave this number of registers, no subsequent writes to the registers\nwill succeed even if they replace an existing register."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_number_registers: ::std::option::Option<u64>,
+    #[doc = "Max number of promises that a function call can create"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_promises_per_function_call_action: ::std::option::Option<u64>,
+    #[doc = "Max receipt size"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_receipt_size: ::std::option::Option<u64>,
+    #[doc = "Maximum number of bytes that can be stored in a single register."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_register_size: ::std::option::Option<u64>,
+    #[doc = "How tall the stack is allowed to grow?\n\nSee <https://wiki.parity.io/WebAssembly-StackHeight> to find out how the stack frame cost\nis calculated."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_stack_height: ::std::option::Option<u32>,
+    #[doc = "If present, stores max number of tables declared globally in one contract"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_tables_per_contract: ::std::option::Option<u32>,
+    #[doc = "Maximum total length in bytes of all log messages."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_total_log_length: ::std::option::Option<u64>,
+    #[doc = "Max total prepaid gas for all function call actions per receipt."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_total_prepaid_gas: ::std::option::Option<NearGas>,
+    #[doc = "Max transaction size"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_transaction_size: ::std::option::Option<u64>,
+    #[doc = "Maximum number of bytes for payload passed over a yield resume."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_yield_payload_size: ::std::option::Option<u64>,
+    #[doc = "Hard limit on the size of storage proof generated while executing a single receipt."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub per_receipt_storage_proof_size_limit: ::std::option::Option<u32>,
+    #[doc = "Limit of memory used by registers."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub registers_memory_limit: ::std::option::Option<u64>,
+    #[doc = "Number of blocks after which a yielded promise times out."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub yield_timeout_length_in_blocks: ::std::option::Option<u64>,
+}
+impl limits::FieldLimits for LimitConfig {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("initial_memory_pages", "uint32", Some(0), None),
+        limits::FieldLimit::integer("max_actions_per_receipt", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_arguments_length", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_contract_size", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_elements_per_contract_table", "uint", Some(0), None),
+        limits::FieldLimit::integer("max_functions_number_per_contract", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_length_method_name", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_length_returned_data", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_length_storage_key", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_length_storage_value", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_locals_per_contract", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_memory_pages", "uint32", Some(0), None),
+        limits::FieldLimit::integer("max_number_bytes_method_names", "uint64", Some(0), None),
+        limits::FieldLimit::integer(
+            "max_number_input_data_dependencies",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("max_number_logs", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_number_registers", "uint64", Some(0), None),
+        limits::FieldLimit::integer(
+            "max_promises_per_function_call_action",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("max_receipt_size", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_register_size", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_stack_height", "uint32", Some(0), None),
+        limits::FieldLimit::integer("max_tables_per_contract", "uint32", Some(0), None),
+        limits::FieldLimit::integer("max_total_log_length", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_transaction_size", "uint64", Some(0), None),
+        limits::FieldLimit::integer("max_yield_payload_size", "uint64", Some(0), None),
+        limits::FieldLimit::integer(
+            "per_receipt_storage_proof_size_limit",
+            "uint",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("registers_memory_limit", "uint64", Some(0), None),
+        limits::FieldLimit::integer("yield_timeout_length_in_blocks", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&LimitConfig> for LimitConfig {
+    fn from(value: &LimitConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for LimitConfig {
+    fn default() -> Self {
+        Self {
+            account_id_validity_rules_version:
+                defaults::limit_config_account_id_validity_rules_version(),
+            initial_memory_pages: Default::default(),
+            max_actions_per_receipt: Default::default(),
+            max_arguments_length: Default::default(),
+            max_contract_size: Default::default(),
+            max_elements_per_contract_table: Default::default(),
+            max_functions_number_per_contract: Default::default(),
+            max_gas_burnt: Default::default(),
+            max_length_method_name: Default::default(),
+            max_length_returned_data: Default::default(),
+            max_length_storage_key: Default::default(),
+            max_length_storage_value: Default::default(),
+            max_locals_per_contract: Default::default(),
+            max_memory_pages: Default::default(),
+            max_number_bytes_method_names: Default::default(),
+            max_number_input_data_dependencies: Default::default(),
+            max_number_logs: Default::default(),
+            max_number_registers: Default::default(),
+            max_promises_per_function_call_action: Default::default(),
+            max_receipt_size: Default::default(),
+            max_register_size: Default::default(),
+            max_stack_height: Default::default(),
+            max_tables_per_contract: Default::default(),
+            max_total_log_length: Default::default(),
+            max_total_prepaid_gas: Default::default(),
+            max_transaction_size: Default::default(),
+            max_yield_payload_size: Default::default(),
+            per_receipt_storage_proof_size_limit: Default::default(),
+            registers_memory_limit: Default::default(),
+            yield_timeout_length_in_blocks: Default::default(),
+        }
+    }
+}
+#[doc = "`LogSummaryStyle`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"plain\","]
+#[doc = "    \"colored\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LogSummaryStyle {
+    #[serde(rename = "plain")]
+    Plain,
+    #[serde(rename = "colored")]
+    Colored,
+}
+impl ::std::convert::From<&Self> for LogSummaryStyle {
+    fn from(value: &LogSummaryStyle) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for LogSummaryStyle {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Plain => f.write_str("plain"),
+            Self::Colored => f.write_str("colored"),
+        }
+    }
+}
+impl ::std::str::FromStr for LogSummaryStyle {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "plain" => Ok(Self::Plain),
+            "colored" => Ok(Self::Colored),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for LogSummaryStyle {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for LogSummaryStyle {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for LogSummaryStyle {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`MutableConfigValue`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct MutableConfigValue(pub ::std::string::String);
+impl ::std::ops::Deref for MutableConfigValue {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<MutableConfigValue> for ::std::string::String {
+    fn from(value: MutableConfigValue) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&MutableConfigValue> for MutableConfigValue {
+    fn from(value: &MutableConfigValue) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<::std::string::String> for MutableConfigValue {
+    fn from(value: ::std::string::String) -> Self {
+        Self(value)
+    }
+}
+impl ::std::str::FromStr for MutableConfigValue {
+    type Err = ::std::convert::Infallible;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::fmt::Display for MutableConfigValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+#[doc = "Configures whether the node checks the next or the next next epoch for network version compatibility."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configures whether the node checks the next or the next next epoch for network version compatibility.\","]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"Next\","]
+#[doc = "    \"NextNext\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ProtocolVersionCheckConfig {
+    Next,
+    NextNext,
+}
+impl ::std::convert::From<&Self> for ProtocolVersionCheckConfig {
+    fn from(value: &ProtocolVersionCheckConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for ProtocolVersionCheckConfig {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Next => f.write_str("Next"),
+            Self::NextNext => f.write_str("NextNext"),
+        }
+    }
+}
+impl ::std::str::FromStr for ProtocolVersionCheckConfig {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "Next" => Ok(Self::Next),
+            "NextNext" => Ok(Self::NextNext),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for ProtocolVersionCheckConfig {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for ProtocolVersionCheckConfig {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for ProtocolVersionCheckConfig {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`RpcClientConfigRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcClientConfigRequest\","]
+#[doc = "  \"type\": \"null\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct RpcClientConfigRequest(pub ());
+impl ::std::ops::Deref for RpcClientConfigRequest {
+    type Target = ();
+    fn deref(&self) -> &() {
+        &self.0
+    }
+}
+impl ::std::convert::From<RpcClientConfigRequest> for () {
+    fn from(value: RpcClientConfigRequest) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&RpcClientConfigRequest> for RpcClientConfigRequest {
+    fn from(value: &RpcClientConfigRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<()> for RpcClientConfigRequest {
+    fn from(value: ()) -> Self {
+        Self(value)
+    }
+}
+#[doc = "ClientConfig where some fields can be updated at runtime."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"ClientConfig where some fields can be updated at runtime.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"archive\": {"]
+#[doc = "      \"description\": \"Not clear old data, set `true` for archive nodes.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"block_fetch_horizon\": {"]
+#[doc = "      \"description\": \"Horizon at which instead of fetching block, fetch full state.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"block_header_fetch_horizon\": {"]
+#[doc = "      \"description\": \"Behind this horizon header fetch kicks in.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"block_production_tracking_delay\": {"]
+#[doc = "      \"description\": \"Duration to check for producing / skipping block.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"catchup_step_period\": {"]
+#[doc = "      \"description\": \"Time between check to perform catchup.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"chain_id\": {"]
+#[doc = "      \"description\": \"Chain id for status.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"chunk_distribution_network\": {"]
+#[doc = "      \"description\": \"Optional config for the Chunk Distribution Network feature.\\nIf set to `None` then this node does not participate in the Chunk Distribution Network.\\nNodes not participating will still function fine, but possibly with higher\\nlatency due to the need of requesting chunks over the peer-to-peer network.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ChunkDistributionNetworkConfig\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"chunk_request_retry_period\": {"]
+#[doc = "      \"description\": \"Time between checking to re-request chunks.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"chunk_validation_threads\": {"]
+#[doc = "      \"description\": \"Number of threads for ChunkValidationActor pool.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"chunk_wait_mult\": {"]
+#[doc = "      \"description\": \"Multiplier for the wait time for all chunks to be received.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"chunks_cache_height_horizon\": {"]
+#[doc = "      \"description\": \"Height horizon for the chunk cache. A chunk is removed from the cache\\nif its height + chunks_cache_height_horizon < largest_seen_height.\\nThe default value is DEFAULT_CHUNKS_CACHE_HEIGHT_HORIZON.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"client_background_migration_threads\": {"]
+#[doc = "      \"description\": \"Number of threads to execute background migration work in client.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"cloud_archival_writer\": {"]
+#[doc = "      \"description\": \"Configuration for a cloud-based archival writer. If this config is present, the writer is enabled and\\nwrites chunk-related data based on the tracked shards.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CloudArchivalWriterConfig\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"disable_tx_routing\": {"]
+#[doc = "      \"description\": \"If true, the node won't forward transactions to next the chunk producers.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"doomslug_step_period\": {"]
+#[doc = "      \"description\": \"Time between running doomslug timer.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"dynamic_resharding_dry_run\": {"]
+#[doc = "      \"description\": \"If true, the runtime will do a dynamic resharding 'dry run' at the last block of each epoch.\\nThis means calculating tentative boundary accounts for splitting the tracked shards.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"enable_early_prepare_transactions\": {"]
+#[doc = "      \"description\": \"If true, transactions for the next chunk will be prepared early, right after the previous chunk's\\npost-state is ready. This can help produce chunks faster, for high-throughput chains.\\nThe current implementation increases latency on low-load chains, which will be fixed in the future.\\nThe default is disabled.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"enable_multiline_logging\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"enable_statistics_export\": {"]
+#[doc = "      \"description\": \"Re-export storage layer statistics as prometheus metrics.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"epoch_length\": {"]
+#[doc = "      \"description\": \"Epoch length.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"epoch_sync\": {"]
+#[doc = "      \"description\": \"Options for epoch sync.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/EpochSyncConfig\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"expected_shutdown\": {"]
+#[doc = "      \"description\": \"Graceful shutdown at expected block height.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/MutableConfigValue\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gc\": {"]
+#[doc = "      \"description\": \"Garbage collection configuration.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/GCConfig\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"header_sync_expected_height_per_second\": {"]
+#[doc = "      \"description\": \"Expected increase of header head height per second during header sync\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"header_sync_initial_timeout\": {"]
+#[doc = "      \"description\": \"How much time to wait after initial header sync\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"header_sync_progress_timeout\": {"]
+#[doc = "      \"description\": \"How much time to wait after some progress is made in header sync\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"header_sync_stall_ban_timeout\": {"]
+#[doc = "      \"description\": \"How much time to wait before banning a peer in header sync if sync is too slow\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"log_summary_period\": {"]
+#[doc = "      \"description\": \"Period between logging summary information.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"log_summary_style\": {"]
+#[doc = "      \"description\": \"Enable coloring of the logs\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/LogSummaryStyle\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_block_production_delay\": {"]
+#[doc = "      \"description\": \"Maximum wait for approvals before producing block.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"max_block_wait_delay\": {"]
+#[doc = "      \"description\": \"Maximum duration before skipping given height.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"max_gas_burnt_view\": {"]
+#[doc = "      \"description\": \"Max burnt gas per view method.  If present, overrides value stored in\\ngenesis file.  The value only affects the RPCs without influencing the\\nprotocol thus changing it per-node doesn’t affect the blockchain.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"min_block_production_delay\": {"]
+#[doc = "      \"description\": \"Minimum duration before producing block.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"min_num_peers\": {"]
+#[doc = "      \"description\": \"Minimum number of peers to start syncing.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_block_producer_seats\": {"]
+#[doc = "      \"description\": \"Number of block producer seats\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"orphan_state_witness_max_size\": {"]
+#[doc = "      \"description\": \"Maximum size of state witnesses in the OrphanStateWitnessPool.\\n\\nWe keep only orphan witnesses which are smaller than this size.\\nThis limits the maximum memory usage of OrphanStateWitnessPool.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"orphan_state_witness_pool_size\": {"]
+#[doc = "      \"description\": \"OrphanStateWitnessPool keeps instances of ChunkStateWitness which can't be processed\\nbecause the previous block isn't available. The witnesses wait in the pool until the\\nrequired block appears. This variable controls how many witnesses can be stored in the pool.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"produce_chunk_add_transactions_time_limit\": {"]
+#[doc = "      \"description\": \"Limit the time of adding transactions to a chunk.\\nA node produces a chunk by adding transactions from the transaction pool until\\nsome limit is reached. This time limit ensures that adding transactions won't take\\nlonger than the specified duration, which helps to produce the chunk quickly.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"produce_empty_blocks\": {"]
+#[doc = "      \"description\": \"Produce empty blocks, use `false` for testing.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"protocol_version_check\": {"]
+#[doc = "      \"description\": \"Determines whether client should exit if the protocol version is not supported\\nfor the next or next next epoch.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ProtocolVersionCheckConfig\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"resharding_config\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/MutableConfigValue\""]
+#[doc = "    },"]
+#[doc = "    \"rpc_addr\": {"]
+#[doc = "      \"description\": \"Listening rpc port for status.\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"save_invalid_witnesses\": {"]
+#[doc = "      \"description\": \"Save observed instances of invalid ChunkStateWitness to the database in DBCol::InvalidChunkStateWitnesses.\\nSaving invalid witnesses is useful for analysis and debugging.\\nThis option can cause extra load on the database and is not recommended for production use.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"save_latest_witnesses\": {"]
+#[doc = "      \"description\": \"Save observed instances of ChunkStateWitness to the database in DBCol::LatestChunkStateWitnesses.\\nSaving the latest witnesses is useful for analysis and debugging.\\nThis option can cause extra load on the database and is not recommended for production use.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"save_state_changes\": {"]
+#[doc = "      \"description\": \"Whether to persist state changes on disk or not.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"save_trie_changes\": {"]
+#[doc = "      \"description\": \"save_trie_changes should be set to true iff\\n- archive if false - non-archival nodes need trie changes to perform garbage collection\\n- archive is true, cold_store is configured and migration to split_storage is finished - node\\nworking in split storage mode needs trie changes in order to do garbage collection on hot.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"save_tx_outcomes\": {"]
+#[doc = "      \"description\": \"Whether to persist transaction outcomes to disk or not.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"save_untracked_partial_chunks_parts\": {"]
+#[doc = "      \"description\": \"Whether to persist partial chunk parts for untracked shards or not.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"skip_sync_wait\": {"]
+#[doc = "      \"description\": \"Skip waiting for sync (for testing or single node testnet).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"state_request_server_threads\": {"]
+#[doc = "      \"description\": \"Number of threads for StateRequestActor pool.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"state_request_throttle_period\": {"]
+#[doc = "      \"description\": \"Number of seconds between state requests for view client.\\nThrottling window for state requests (headers and parts).\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"state_requests_per_throttle_period\": {"]
+#[doc = "      \"description\": \"Maximum number of state requests served per throttle period\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"state_sync\": {"]
+#[doc = "      \"description\": \"Options for syncing state.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/StateSyncConfig\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"state_sync_enabled\": {"]
+#[doc = "      \"description\": \"Whether to use the State Sync mechanism.\\nIf disabled, the node will do Block Sync instead of State Sync.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"state_sync_external_backoff\": {"]
+#[doc = "      \"description\": \"Additional waiting period after a failed request to external storage\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"state_sync_external_timeout\": {"]
+#[doc = "      \"description\": \"How long to wait for a response from centralized state sync\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"state_sync_p2p_timeout\": {"]
+#[doc = "      \"description\": \"How long to wait for a response from p2p state sync\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"state_sync_retry_backoff\": {"]
+#[doc = "      \"description\": \"How long to wait after a failed state sync request\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"sync_check_period\": {"]
+#[doc = "      \"description\": \"How often to check that we are not out of sync.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"sync_height_threshold\": {"]
+#[doc = "      \"description\": \"Sync height threshold: below this difference in height don't start syncing.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"sync_max_block_requests\": {"]
+#[doc = "      \"description\": \"Maximum number of block requests to send to peers to sync\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"sync_step_period\": {"]
+#[doc = "      \"description\": \"While syncing, how long to check for each step.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"tracked_shards_config\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/TrackedShardsConfig\""]
+#[doc = "    },"]
+#[doc = "    \"transaction_pool_size_limit\": {"]
+#[doc = "      \"description\": \"Limit of the size of per-shard transaction pool measured in bytes. If not set, the size\\nwill be unbounded.\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"transaction_request_handler_threads\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"trie_viewer_state_size_limit\": {"]
+#[doc = "      \"description\": \"Upper bound of the byte size of contract state that is still viewable. None is no limit\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"ttl_account_id_router\": {"]
+#[doc = "      \"description\": \"Time to persist Accounts Id in the router without removing them.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"tx_routing_height_horizon\": {"]
+#[doc = "      \"description\": \"If the node is not a chunk producer within that many blocks, then route\\nto upcoming chunk producers.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"description\": \"Version of the binary.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Version\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"view_client_threads\": {"]
+#[doc = "      \"description\": \"Number of threads for ViewClientActor pool.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcClientConfigResponse {
+    #[doc = "Not clear old data, set `true` for archive nodes."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub archive: ::std::option::Option<bool>,
+    #[doc = "Horizon at which instead of fetching block, fetch full state."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_fetch_horizon: ::std::option::Option<u64>,
+    #[doc = "Behind this horizon header fetch kicks in."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_header_fetch_horizon: ::std::option::Option<u64>,
+    #[doc = "Duration to check for producing / skipping block."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_production_tracking_delay: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Time between check to perform catchup."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub catchup_step_period: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Chain id for status."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chain_id: ::std::option::Option<::std::string::String>,
+    #[doc = "Optional config for the Chunk Distribution Network feature.\nIf set to `None` then this node does not participate in the Chunk Distribution Network.\nNodes not participating will still function fine, but possibly with higher\nlatency due to the need of requesting chunks over the peer-to-peer network."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_distribution_network: ::std::option::Option<ChunkDistributionNetworkConfig>,
+    #[doc = "Time between checking to re-request chunks."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_request_retry_period: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Number of threads for ChunkValidationActor pool."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_validation_threads: ::std::option::Option<u32>,
+    #[doc = "Multiplier for the wait time for all chunks to be received."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_wait_mult: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Height horizon for the chunk cache. A chunk is removed from the cache\nif its height + chunks_cache_height_horizon < largest_seen_height.\nThe default value is DEFAULT_CHUNKS_CACHE_HEIGHT_HORIZON."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunks_cache_height_horizon: ::std::option::Option<u64>,
+    #[doc = "Number of threads to execute background migration work in client."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub client_background_migration_threads: ::std::option::Option<u32>,
+    #[doc = "Configuration for a cloud-based archival writer. If this config is present, the writer is enabled and\nwrites chunk-related data based on the tracked shards."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub cloud_archival_writer: ::std::option::Option<CloudArchivalWriterConfig>,
+    #[doc = "If true, the node won't forward transactions to next the chunk producers."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub disable_tx_routing: ::std::option::Option<bool>,
+    #[doc = "Time between running doomslug timer."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub doomslug_step_period: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "If true, the runtime will do a dynamic resharding 'dry run' at the last block of each epoch.\nThis means calculating tentative boundary accounts for splitting the tracked shards."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub dynamic_resharding_dry_run: ::std::option::Option<bool>,
+    #[doc = "If true, transactions for the next chunk will be prepared early, right after the previous chunk's\npost-state is ready. This can help produce chunks faster, for high-throughput chains.\nThe current implementation increases latency on low-load chains, which will be fixed in the future.\nThe default is disabled."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub enable_early_prepare_transactions: ::std::option::Option<bool>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub enable_multiline_logging: ::std::option::Option<bool>,
+    #[doc = "Re-export storage layer statistics as prometheus metrics."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub enable_statistics_export: ::std::option::Option<bool>,
+    #[doc = "Epoch length."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_length: ::std::option::Option<u64>,
+    #[doc = "Options for epoch sync."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_sync: ::std::option::Option<EpochSyncConfig>,
+    #[doc = "Graceful shutdown at expected block height."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub expected_shutdown: ::std::option::Option<MutableConfigValue>,
+    #[doc = "Garbage collection configuration."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub gc: ::std::option::Option<GcConfig>,
+    #[doc = "Expected increase of header head height per second during header sync"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub header_sync_expected_height_per_second: ::std::option::Option<u64>,
+    #[doc = "How much time to wait after initial header sync"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub header_sync_initial_timeout: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "How much time to wait after some progress is made in header sync"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub header_sync_progress_timeout: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "How much time to wait before banning a peer in header sync if sync is too slow"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub header_sync_stall_ban_timeout: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Period between logging summary information."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub log_summary_period: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Enable coloring of the logs"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub log_summary_style: ::std::option::Option<LogSummaryStyle>,
+    #[doc = "Maximum wait for approvals before producing block."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_block_production_delay: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Maximum duration before skipping given height."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_block_wait_delay: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Max burnt gas per view method.  If present, overrides value stored in\ngenesis file.  The value only affects the RPCs without influencing the\nprotocol thus changing it per-node doesn’t affect the blockchain."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_gas_burnt_view: ::std::option::Option<NearGas>,
+    #[doc = "Minimum duration before producing block."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub min_block_production_delay: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Minimum number of peers to start syncing."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub min_num_peers: ::std::option::Option<u32>,
+    #[doc = "Number of block producer seats"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub num_block_producer_seats: ::std::option::Option<u64>,
+    #[doc = "Maximum size of state witnesses in the OrphanStateWitnessPool.\n\nWe keep only orphan witnesses which are smaller than this size.\nThis limits the maximum memory usage of OrphanStateWitnessPool."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub orphan_state_witness_max_size: ::std::option::Option<u64>,
+    #[doc = "OrphanStateWitnessPool keeps instances of ChunkStateWitness which can't be processed\nbecause the previous block isn't available. The witnesses wait in the pool until the\nrequired block appears. This variable controls how many witnesses can be stored in the pool."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub orphan_state_witness_pool_size: ::std::option::Option<u32>,
+    #[doc = "Limit the time of adding transactions to a chunk.\nA node produces a chunk by adding transactions from the transaction pool until\nsome limit is reached. This time limit ensures that adding transactions won't take\nlonger than the specified duration, which helps to produce the chunk quickly."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub produce_chunk_add_transactions_time_limit: ::std::option::Option<::std::string::String>,
+    #[doc = "Produce empty blocks, use `false` for testing."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub produce_empty_blocks: ::std::option::Option<bool>,
+    #[doc = "Determines whether client should exit if the protocol version is not supported\nfor the next or next next epoch."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub protocol_version_check: ::std::option::Option<ProtocolVersionCheckConfig>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub resharding_config: ::std::option::Option<MutableConfigValue>,
+    #[doc = "Listening rpc port for status."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub rpc_addr: ::std::option::Option<::std::string::String>,
+    #[doc = "Save observed instances of invalid ChunkStateWitness to the database in DBCol::InvalidChunkStateWitnesses.\nSaving invalid witnesses is useful for analysis and debugging.\nThis option can cause extra load on the database and is not recommended for production use."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub save_invalid_witnesses: ::std::option::Option<bool>,
+    #[doc = "Save observed instances of ChunkStateWitness to the database in DBCol::LatestChunkStateWitnesses.\nSaving the latest witnesses is useful for analysis and debugging.\nThis option can cause extra load on the database and is not recommended for production use."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub save_latest_witnesses: ::std::option::Option<bool>,
+    #[doc = "Whether to persist state changes on disk or not."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub save_state_changes: ::std::option::Option<bool>,
+    #[doc = "save_trie_changes should be set to true iff\n- archive if false - non-archival nodes need trie changes to perform garbage collection\n- archive is true, cold_store is configured and migration to split_storage is finished - node\nworking in split storage mode needs trie changes in order to do garbage collection on hot."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub save_trie_changes: ::std::option::Option<bool>,
+    #[doc = "Whether to persist transaction outcomes to disk or not."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub save_tx_outcomes: ::std::option::Option<bool>,
+    #[doc = "Whether to persist partial chunk parts for untracked shards or not."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub save_untracked_partial_chunks_parts: ::std::option::Option<bool>,
+    #[doc = "Skip waiting for sync (for testing or single node testnet)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub skip_sync_wait: ::std::option::Option<bool>,
+    #[doc = "Number of threads for StateRequestActor pool."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_request_server_threads: ::std::option::Option<u32>,
+    #[doc = "Number of seconds between state requests for view client.\nThrottling window for state requests (headers and parts)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_request_throttle_period: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Maximum number of state requests served per throttle period"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_requests_per_throttle_period: ::std::option::Option<u32>,
+    #[doc = "Options for syncing state."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_sync: ::std::option::Option<StateSyncConfig>,
+    #[doc = "Whether to use the State Sync mechanism.\nIf disabled, the node will do Block Sync instead of State Sync."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_sync_enabled: ::std::option::Option<bool>,
+    #[doc = "Additional waiting period after a failed request to external storage"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_sync_external_backoff: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "How long to wait for a response from centralized state sync"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_sync_external_timeout: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "How long to wait for a response from p2p state sync"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_sync_p2p_timeout: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "How long to wait after a failed state sync request"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state_sync_retry_backoff: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "How often to check that we are not out of sync."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sync_check_period: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "Sync height threshold: below this difference in height don't start syncing."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sync_height_threshold: ::std::option::Option<u64>,
+    #[doc = "Maximum number of block requests to send to peers to sync"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sync_max_block_requests: ::std::option::Option<u32>,
+    #[doc = "While syncing, how long to check for each step."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sync_step_period: ::std::option::Option<[u64; 2usize]>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub tracked_shards_config: ::std::option::Option<TrackedShardsConfig>,
+    #[doc = "Limit of the size of per-shard transaction pool measured in bytes. If not set, the size\nwill be unbounded."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub transaction_pool_size_limit: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub transaction_request_handler_threads: ::std::option::Option<u32>,
+    #[doc = "Upper bound of the byte size of contract state that is still viewable. None is no limit"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub trie_viewer_state_size_limit: ::std::option::Option<u64>,
+    #[doc = "Time to persist Accounts Id in the router without removing them."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ttl_account_id_router: ::std::option::Option<[u64; 2usize]>,
+    #[doc = "If the node is not a chunk producer within that many blocks, then route\nto upcoming chunk producers."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub tx_routing_height_horizon: ::std::option::Option<u64>,
+    #[doc = "Version of the binary."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub version: ::std::option::Option<Version>,
+    #[doc = "Number of threads for ViewClientActor pool."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub view_client_threads: ::std::option::Option<u32>,
+}
+impl limits::FieldLimits for RpcClientConfigResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("block_fetch_horizon", "uint64", Some(0), None),
+        limits::FieldLimit::integer("block_header_fetch_horizon", "uint64", Some(0), None),
+        limits::FieldLimit::items("block_production_tracking_delay", Some(2), Some(2)),
+        limits::FieldLimit::items("catchup_step_period", Some(2), Some(2)),
+        limits::FieldLimit::items("chunk_request_retry_period", Some(2), Some(2)),
+        limits::FieldLimit::integer("chunk_validation_threads", "uint", Some(0), None),
+        limits::FieldLimit::items("chunk_wait_mult", Some(2), Some(2)),
+        limits::FieldLimit::integer("chunks_cache_height_horizon", "uint64", Some(0), None),
+        limits::FieldLimit::integer("client_background_migration_threads", "uint", Some(0), None),
+        limits::FieldLimit::items("doomslug_step_period", Some(2), Some(2)),
+        limits::FieldLimit::integer("epoch_length", "uint64", Some(0), None),
+        limits::FieldLimit::integer(
+            "header_sync_expected_height_per_second",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::items("header_sync_initial_timeout", Some(2), Some(2)),
+        limits::FieldLimit::items("header_sync_progress_timeout", Some(2), Some(2)),
+        limits::FieldLimit::items("header_sync_stall_ban_timeout", Some(2), Some(2)),
+        limits::FieldLimit::items("log_summary_period", Some(2), Some(2)),
+        limits::FieldLimit::items("max_block_production_delay", Some(2), Some(2)),
+        limits::FieldLimit::items("max_block_wait_delay", Some(2), Some(2)),
+        limits::FieldLimit::items("min_block_production_delay", Some(2), Some(2)),
+        limits::FieldLimit::integer("min_num_peers", "uint", Some(0), None),
+        limits::FieldLimit::integer("num_block_producer_seats", "uint64", Some(0), None),
+        limits::FieldLimit::integer("orphan_state_witness_max_size", "uint64", Some(0), None),
+        limits::FieldLimit::integer("orphan_state_witness_pool_size", "uint", Some(0), None),
+        limits::FieldLimit::integer("state_request_server_threads", "uint", Some(0), None),
+        limits::FieldLimit::items("state_request_throttle_period", Some(2), Some(2)),
+        limits::FieldLimit::integer("state_requests_per_throttle_period", "uint", Some(0), None),
+        limits::FieldLimit::items("state_sync_external_backoff", Some(2), Some(2)),
+        limits::FieldLimit::items("state_sync_external_timeout", Some(2), Some(2)),
+        limits::FieldLimit::items("state_sync_p2p_timeout", Some(2), Some(2)),
+        limits::FieldLimit::items("state_sync_retry_backoff", Some(2), Some(2)),
+        limits::FieldLimit::items("sync_check_period", Some(2), Some(2)),
+        limits::FieldLimit::integer("sync_height_threshold", "uint64", Some(0), None),
+        limits::FieldLimit::integer("sync_max_block_requests", "uint", Some(0), None),
+        limits::FieldLimit::items("sync_step_period", Some(2), Some(2)),
+        limits::FieldLimit::integer("transaction_pool_size_limit", "uint64", Some(0), None),
+        limits::FieldLimit::integer("transaction_request_handler_threads", "uint", Some(0), None),
+        limits::FieldLimit::integer("trie_viewer_state_size_limit", "uint64", Some(0), None),
+        limits::FieldLimit::items("ttl_account_id_router", Some(2), Some(2)),
+        limits::FieldLimit::integer("tx_routing_height_horizon", "uint64", Some(0), None),
+        limits::FieldLimit::integer("view_client_threads", "uint", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RpcClientConfigResponse> for RpcClientConfigResponse {
+    fn from(value: &RpcClientConfigResponse) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RpcClientConfigResponse {
+    fn default() -> Self {
+        Self {
+            archive: Default::default(),
+            block_fetch_horizon: Default::default(),
+            block_header_fetch_horizon: Default::default(),
+            block_production_tracking_delay: Default::default(),
+            catchup_step_period: Default::default(),
+            chain_id: Default::default(),
+            chunk_distribution_network: Default::default(),
+            chunk_request_retry_period: Default::default(),
+            chunk_validation_threads: Default::default(),
+            chunk_wait_mult: Default::default(),
+            chunks_cache_height_horizon: Default::default(),
+            client_background_migration_threads: Default::default(),
+            cloud_archival_writer: Default::default(),
+            disable_tx_routing: Default::default(),
+            doomslug_step_period: Default::default(),
+            dynamic_resharding_dry_run: Default::default(),
+            enable_early_prepare_transactions: Default::default(),
+            enable_multiline_logging: Default::default(),
+            enable_statistics_export: Default::default(),
+            epoch_length: Default::default(),
+            epoch_sync: Default::default(),
+            expected_shutdown: Default::default(),
+            gc: Default::default(),
+            header_sync_expected_height_per_second: Default::default(),
+            header_sync_initial_timeout: Default::default(),
+            header_sync_progress_timeout: Default::default(),
+            header_sync_stall_ban_timeout: Default::default(),
+            log_summary_period: Default::default(),
+            log_summary_style: Default::default(),
+            max_block_production_delay: Default::default(),
+            max_block_wait_delay: Default::default(),
+            max_gas_burnt_view: Default::default(),
+            min_block_production_delay: Default::default(),
+            min_num_peers: Default::default(),
+            num_block_producer_seats: Default::default(),
+            orphan_state_witness_max_size: Default::default(),
+            orphan_state_witness_pool_size: Default::default(),
+            produce_chunk_add_transactions_time_limit: Default::default(),
+            produce_empty_blocks: Default::default(),
+            protocol_version_check: Default::default(),
+            resharding_config: Default::default(),
+            rpc_addr: Default::default(),
+            save_invalid_witnesses: Default::default(),
+            save_latest_witnesses: Default::default(),
+            save_state_changes: Default::default(),
+            save_trie_changes: Default::default(),
+            save_tx_outcomes: Default::default(),
+            save_untracked_partial_chunks_parts: Default::default(),
+            skip_sync_wait: Default::default(),
+            state_request_server_threads: Default::default(),
+            state_request_throttle_period: Default::default(),
+            state_requests_per_throttle_period: Default::default(),
+            state_sync: Default::default(),
+            state_sync_enabled: Default::default(),
+            state_sync_external_backoff: Default::default(),
+            state_sync_external_timeout: Default::default(),
+            state_sync_p2p_timeout: Default::default(),
+            state_sync_retry_backoff: Default::default(),
+            sync_check_period: Default::default(),
+            sync_height_threshold: Default::default(),
+            sync_max_block_requests: Default::default(),
+            sync_step_period: Default::default(),
+            tracked_shards_config: Default::default(),
+            transaction_pool_size_limit: Default::default(),
+            transaction_request_handler_threads: Default::default(),
+            trie_viewer_state_size_limit: Default::default(),
+            ttl_account_id_router: Default::default(),
+            tx_routing_height_horizon: Default::default(),
+            version: Default::default(),
+            view_client_threads: Default::default(),
+        }
+    }
+}
+#[doc = "`RpcProtocolConfigRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcProtocolConfigRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"block_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"block_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"finality\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"finality\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Finality\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"sync_checkpoint\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"sync_checkpoint\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/SyncCheckpoint\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RpcProtocolConfigRequest {
+    #[serde(rename = "block_id")]
+    BlockId(BlockId),
+    #[serde(rename = "finality")]
+    Finality(Finality),
+    #[serde(rename = "sync_checkpoint")]
+    SyncCheckpoint(SyncCheckpoint),
+}
+impl ::std::convert::From<&Self> for RpcProtocolConfigRequest {
+    fn from(value: &RpcProtocolConfigRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<BlockId> for RpcProtocolConfigRequest {
+    fn from(value: BlockId) -> Self {
+        Self::BlockId(value)
+    }
+}
+impl ::std::convert::From<Finality> for RpcProtocolConfigRequest {
+    fn from(value: Finality) -> Self {
+        Self::Finality(value)
+    }
+}
+impl ::std::convert::From<SyncCheckpoint> for RpcProtocolConfigRequest {
+    fn from(value: SyncCheckpoint) -> Self {
+        Self::SyncCheckpoint(value)
+    }
+}
+#[doc = "`RpcProtocolConfigResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"avg_hidden_validator_seats_per_shard\": {"]
+#[doc = "      \"description\": \"Expected number of hidden validators per shard.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"block_producer_kickout_threshold\": {"]
+#[doc = "      \"description\": \"Threshold for kicking out block producers, between 0 and 100.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"chain_id\": {"]
+#[doc = "      \"description\": \"ID of the blockchain. This must be unique for every blockchain.\\nIf your testnet blockchains do not have unique chain IDs, you will have a bad time.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"chunk_producer_kickout_threshold\": {"]
+#[doc = "      \"description\": \"Threshold for kicking out chunk producers, between 0 and 100.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"chunk_validator_only_kickout_threshold\": {"]
+#[doc = "      \"description\": \"Threshold for kicking out nodes which are only chunk validators, between 0 and 100.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"dynamic_resharding\": {"]
+#[doc = "      \"description\": \"Enable dynamic re-sharding.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"epoch_length\": {"]
+#[doc = "      \"description\": \"Epoch length counted in block heights.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"fishermen_threshold\": {"]
+#[doc = "      \"description\": \"Fishermen stake threshold.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gas_limit\": {"]
+#[doc = "      \"description\": \"Initial gas limit.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gas_price_adjustment_rate\": {"]
+#[doc = "      \"description\": \"Gas price adjustment rate\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"genesis_height\": {"]
+#[doc = "      \"description\": \"Height of genesis block.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"genesis_time\": {"]
+#[doc = "      \"description\": \"Official time of blockchain start.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"format\": \"date-time\""]
+#[doc = "    },"]
+#[doc = "    \"max_gas_price\": {"]
+#[doc = "      \"description\": \"Maximum gas price.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"max_inflation_rate\": {"]
+#[doc = "      \"description\": \"Maximum inflation on the total supply every epoch.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"max_kickout_stake_perc\": {"]
+#[doc = "      \"description\": \"Max stake percentage of the validators we will kick out.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"min_gas_price\": {"]
+#[doc = "      \"description\": \"Minimum gas price. It is also the initial gas price.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"minimum_stake_divisor\": {"]
+#[doc = "      \"description\": \"The minimum stake required for staking is last seat price divided by this number.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"minimum_stake_ratio\": {"]
+#[doc = "      \"description\": \"The lowest ratio s/s_total any block producer can have.\\nSee <https://github.com/near/NEPs/pull/167> for details\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"minimum_validators_per_shard\": {"]
+#[doc = "      \"description\": \"The minimum number of validators each shard must have\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_block_producer_seats\": {"]
+#[doc = "      \"description\": \"Number of block producer seats at genesis.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_block_producer_seats_per_shard\": {"]
+#[doc = "      \"description\": \"Defines number of shards and number of block producer seats per each shard at genesis.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_blocks_per_year\": {"]
+#[doc = "      \"description\": \"Expected number of blocks per year\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"online_max_threshold\": {"]
+#[doc = "      \"description\": \"Online maximum threshold above which validator gets full reward.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"online_min_threshold\": {"]
+#[doc = "      \"description\": \"Online minimum threshold below which validator doesn't receive reward.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"protocol_reward_rate\": {"]
+#[doc = "      \"description\": \"Protocol treasury rate\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"protocol_treasury_account\": {"]
+#[doc = "      \"description\": \"Protocol treasury account\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"protocol_upgrade_stake_threshold\": {"]
+#[doc = "      \"description\": \"Threshold of stake that needs to indicate that they ready for upgrade.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"protocol_version\": {"]
+#[doc = "      \"description\": \"Current Protocol Version\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"runtime_config\": {"]
+#[doc = "      \"description\": \"Runtime configuration (mostly economics constants).\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/RuntimeConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"shard_layout\": {"]
+#[doc = "      \"description\": \"Layout information regarding how to split accounts to shards\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardLayout\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"shuffle_shard_assignment_for_chunk_producers\": {"]
+#[doc = "      \"description\": \"If true, shuffle the chunk producers across shards. In other words, if\\nthe shard assignments were `[S_0, S_1, S_2, S_3]` where `S_i` represents\\nthe set of chunk producers for shard `i`, if this flag were true, the\\nshard assignments might become, for example, `[S_2, S_0, S_3, S_1]`.\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"target_validator_mandates_per_shard\": {"]
+#[doc = "      \"description\": \"Number of target chunk validator mandates for each shard.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"transaction_validity_period\": {"]
+#[doc = "      \"description\": \"Number of blocks for which a given transaction is valid\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcProtocolConfigResponse {
+    #[doc = "Expected number of hidden validators per shard."]
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub avg_hidden_validator_seats_per_shard: ::std::vec::Vec<u64>,
+    #[doc = "Threshold for kicking out block producers, between 0 and 100."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_producer_kickout_threshold: ::std::option::Option<u8>,
+    #[doc = "ID of the blockchain. This must be unique for every blockchain.\nIf your testnet blockchains do not have unique chain IDs, you will have a bad time."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chain_id: ::std::option::Option<::std::string::String>,
+    #[doc = "Threshold for kicking out chunk producers, between 0 and 100."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_producer_kickout_threshold: ::std::option::Option<u8>,
+    #[doc = "Threshold for kicking out nodes which are only chunk validators, between 0 and 100."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub chunk_validator_only_kickout_threshold: ::std::option::Option<u8>,
+    #[doc = "Enable dynamic re-sharding."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub dynamic_resharding: ::std::option::Option<bool>,
+    #[doc = "Epoch length counted in block heights."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_length: ::std::option::Option<u64>,
+    #[doc = "Fishermen stake threshold."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub fishermen_threshold: ::std::option::Option<NearToken>,
+    #[doc = "Initial gas limit."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub gas_limit: ::std::option::Option<NearGas>,
+    #[doc = "Gas price adjustment rate"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub gas_price_adjustment_rate: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Height of genesis block."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub genesis_height: ::std::option::Option<u64>,
+    #[doc = "Official time of blockchain start."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub genesis_time: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+    #[doc = "Maximum gas price."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_gas_price: ::std::option::Option<NearToken>,
+    #[doc = "Maximum inflation on the total supply every epoch."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_inflation_rate: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Max stake percentage of the validators we will kick out."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max_kickout_stake_perc: ::std::option::Option<u8>,
+    #[doc = "Minimum gas price. It is also the initial gas price."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub min_gas_price: ::std::option::Option<NearToken>,
+    #[doc = "The minimum stake required for staking is last seat price divided by this number."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub minimum_stake_divisor: ::std::option::Option<u64>,
+    #[doc = "The lowest ratio s/s_total any block producer can have.\nSee <https://github.com/near/NEPs/pull/167> for details"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub minimum_stake_ratio: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "The minimum number of validators each shard must have"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub minimum_validators_per_shard: ::std::option::Option<u64>,
+    #[doc = "Number of block producer seats at genesis."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub num_block_producer_seats: ::std::option::Option<u64>,
+    #[doc = "Defines number of shards and number of block producer seats per each shard at genesis."]
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub num_block_producer_seats_per_shard: ::std::vec::Vec<u64>,
+    #[doc = "Expected number of blocks per year"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub num_blocks_per_year: ::std::option::Option<u64>,
+    #[doc = "Online maximum threshold above which validator gets full reward."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub online_max_threshold: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Online minimum threshold below which validator doesn't receive reward."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub online_min_threshold: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Protocol treasury rate"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub protocol_reward_rate: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Protocol treasury account"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub protocol_treasury_account: ::std::option::Option<AccountId>,
+    #[doc = "Threshold of stake that needs to indicate that they ready for upgrade."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub protocol_upgrade_stake_threshold: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Current Protocol Version"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub protocol_version: ::std::option::Option<u32>,
+    #[doc = "Runtime configuration (mostly economics constants)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub runtime_config: ::std::option::Option<RuntimeConfigView>,
+    #[doc = "Layout information regarding how to split accounts to shards"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub shard_layout: ::std::option::Option<ShardLayout>,
+    #[doc = "If true, shuffle the chunk producers across shards. In other words, if\nthe shard assignments were `[S_0, S_1, S_2, S_3]` where `S_i` represents\nthe set of chunk producers for shard `i`, if this flag were true, the\nshard assignments might become, for example, `[S_2, S_0, S_3, S_1]`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub shuffle_shard_assignment_for_chunk_producers: ::std::option::Option<bool>,
+    #[doc = "Number of target chunk validator mandates for each shard."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub target_validator_mandates_per_shard: ::std::option::Option<u64>,
+    #[doc = "Number of blocks for which a given transaction is valid"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub transaction_validity_period: ::std::option::Option<u64>,
+}
+impl limits::FieldLimits for RpcProtocolConfigResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer(
+            "block_producer_kickout_threshold",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+        limits::FieldLimit::integer(
+            "chunk_producer_kickout_threshold",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+        limits::FieldLimit::integer(
+            "chunk_validator_only_kickout_threshold",
+            "uint8",
+            Some(0),
+            Some(255),
+        ),
+        limits::FieldLimit::integer("epoch_length", "uint64", Some(0), None),
+        limits::FieldLimit::items("gas_price_adjustment_rate", Some(2), Some(2)),
+        limits::FieldLimit::integer("genesis_height", "uint64", Some(0), None),
+        limits::FieldLimit::items("max_inflation_rate", Some(2), Some(2)),
+        limits::FieldLimit::integer("max_kickout_stake_perc", "uint8", Some(0), Some(255)),
+        limits::FieldLimit::integer("minimum_stake_divisor", "uint64", Some(0), None),
+        limits::FieldLimit::items("minimum_stake_ratio", Some(2), Some(2)),
+        limits::FieldLimit::integer("minimum_validators_per_shard", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_block_producer_seats", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_blocks_per_year", "uint64", Some(0), None),
+        limits::FieldLimit::items("online_max_threshold", Some(2), Some(2)),
+        limits::FieldLimit::items("online_min_threshold", Some(2), Some(2)),
+        limits::FieldLimit::items("protocol_reward_rate", Some(2), Some(2)),
+        limits::FieldLimit::items("protocol_upgrade_stake_threshold", Some(2), Some(2)),
+        limits::FieldLimit::integer("protocol_version", "uint32", Some(0), None),
+        limits::FieldLimit::integer(
+            "target_validator_mandates_per_shard",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("transaction_validity_period", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RpcProtocolConfigResponse> for RpcProtocolConfigResponse {
+    fn from(value: &RpcProtocolConfigResponse) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RpcProtocolConfigResponse {
+    fn default() -> Self {
+        Self {
+            avg_hidden_validator_seats_per_shard: Default::default(),
+            block_producer_kickout_threshold: Default::default(),
+            chain_id: Default::default(),
+            chunk_producer_kickout_threshold: Default::default(),
+            chunk_validator_only_kickout_threshold: Default::default(),
+            dynamic_resharding: Default::default(),
+            epoch_length: Default::default(),
+            fishermen_threshold: Default::default(),
+            gas_limit: Default::default(),
+            gas_price_adjustment_rate: Default::default(),
+            genesis_height: Default::default(),
+            genesis_time: Default::default(),
+            max_gas_price: Default::default(),
+            max_inflation_rate: Default::default(),
+            max_kickout_stake_perc: Default::default(),
+            min_gas_price: Default::default(),
+            minimum_stake_divisor: Default::default(),
+            minimum_stake_ratio: Default::default(),
+            minimum_validators_per_shard: Default::default(),
+            num_block_producer_seats: Default::default(),
+            num_block_producer_seats_per_shard: Default::default(),
+            num_blocks_per_year: Default::default(),
+            online_max_threshold: Default::default(),
+            online_min_threshold: Default::default(),
+            protocol_reward_rate: Default::default(),
+            protocol_treasury_account: Default::default(),
+            protocol_upgrade_stake_threshold: Default::default(),
+            protocol_version: Default::default(),
+            runtime_config: Default::default(),
+            shard_layout: Default::default(),
+            shuffle_shard_assignment_for_chunk_producers: Default::default(),
+            target_validator_mandates_per_shard: Default::default(),
+            transaction_validity_period: Default::default(),
+        }
+    }
+}
+#[doc = "View that preserves JSON format of the runtime config."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"View that preserves JSON format of the runtime config.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_creation_config\": {"]
+#[doc = "      \"description\": \"Config that defines rules for account creation.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountCreationConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"congestion_control_config\": {"]
+#[doc = "      \"description\": \"The configuration for congestion control.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CongestionControlConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"dynamic_resharding_config\": {"]
+#[doc = "      \"description\": \"Configuration for dynamic resharding feature.\","]
+#[doc = "      \"default\": {"]
+#[doc = "        \"max_number_of_shards\": 999999999999999,"]
+#[doc = "        \"memory_usage_threshold\": 999999999999999,"]
+#[doc = "        \"min_child_memory_usage\": 999999999999999,"]
+#[doc = "        \"min_epochs_between_resharding\": 999999999999999"]
+#[doc = "      },"]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DynamicReshardingConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"storage_amount_per_byte\": {"]
+#[doc = "      \"description\": \"Amount of yN per byte required to have on the account.  See\\n<https://nomicon.io/Economics/Economics.html#state-stake> for details.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transaction_costs\": {"]
+#[doc = "      \"description\": \"Costs of different actions that need to be performed when sending and\\nprocessing transaction and receipts.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/RuntimeFeesConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"wasm_config\": {"]
+#[doc = "      \"description\": \"Config of wasm operations.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/VMConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"witness_config\": {"]
+#[doc = "      \"description\": \"Configuration specific to ChunkStateWitness.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/WitnessConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RuntimeConfigView {
+    #[doc = "Config that defines rules for account creation."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub account_creation_config: ::std::option::Option<AccountCreationConfigView>,
+    #[doc = "The configuration for congestion control."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub congestion_control_config: ::std::option::Option<CongestionControlConfigView>,
+    #[doc = "Configuration for dynamic resharding feature."]
+    #[serde(default = "defaults::runtime_config_view_dynamic_resharding_config")]
+    pub dynamic_resharding_config: DynamicReshardingConfigView,
+    #[doc = "Amount of yN per byte required to have on the account.  See\n<https://nomicon.io/Economics/Economics.html#state-stake> for details."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_amount_per_byte: ::std::option::Option<NearToken>,
+    #[doc = "Costs of different actions that need to be performed when sending and\nprocessing transaction and receipts."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub transaction_costs: ::std::option::Option<RuntimeFeesConfigView>,
+    #[doc = "Config of wasm operations."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub wasm_config: ::std::option::Option<VmConfigView>,
+    #[doc = "Configuration specific to ChunkStateWitness."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub witness_config: ::std::option::Option<WitnessConfigView>,
+}
+impl ::std::convert::From<&RuntimeConfigView> for RuntimeConfigView {
+    fn from(value: &RuntimeConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RuntimeConfigView {
+    fn default() -> Self {
+        Self {
+            account_creation_config: Default::default(),
+            congestion_control_config: Default::default(),
+            dynamic_resharding_config: defaults::runtime_config_view_dynamic_resharding_config(),
+            storage_amount_per_byte: Default::default(),
+            transaction_costs: Default::default(),
+            wasm_config: Default::default(),
+            witness_config: Default::default(),
+        }
+    }
+}
+#[doc = "Describes different fees for the runtime"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes different fees for the runtime\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"action_creation_config\": {"]
+#[doc = "      \"description\": \"Describes the cost of creating a certain action, `Action`. Includes all variants.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ActionCreationConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"action_receipt_creation_config\": {"]
+#[doc = "      \"description\": \"Describes the cost of creating an action receipt, `ActionReceipt`, excluding the actual cost\\nof actions.\\n- `send` cost is burned when a receipt is created using `promise_create` or\\n    `promise_batch_create`\\n- `exec` cost is burned when the receipt is being executed.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Fee\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"burnt_gas_reward\": {"]
+#[doc = "      \"description\": \"Fraction of the burnt gas to reward to the contract account for execution.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"data_receipt_creation_config\": {"]
+#[doc = "      \"description\": \"Describes the cost of creating a data receipt, `DataReceipt`.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DataReceiptCreationConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"pessimistic_gas_price_inflation_ratio\": {"]
+#[doc = "      \"description\": \"Pessimistic gas price inflation ratio.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"int32\""]
+#[doc = "      },"]
+#[doc = "      \"maxItems\": 2,"]
+#[doc = "      \"minItems\": 2"]
+#[doc = "    },"]
+#[doc = "    \"storage_usage_config\": {"]
+#[doc = "      \"description\": \"Describes fees for storage.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/StorageUsageConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RuntimeFeesConfigView {
+    #[doc = "Describes the cost of creating a certain action, `Action`. Includes all variants."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub action_creation_config: ::std::option::Option<ActionCreationConfigView>,
+    #[doc = "Describes the cost of creating an action receipt, `ActionReceipt`, excluding the actual cost\nof actions.\n- `send` cost is burned when a receipt is created using `promise_create` or\n    `promise_batch_create`\n- `exec` cost is burned when the receipt is being executed."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub action_receipt_creation_config: ::std::option::Option<Fee>,
+    #[doc = "Fraction of the burnt gas to reward to the contract account for execution."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub burnt_gas_reward: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Describes the cost of creating a data receipt, `DataReceipt`."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub data_receipt_creation_config: ::std::option::Option<DataReceiptCreationConfigView>,
+    #[doc = "Pessimistic gas price inflation ratio."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub pessimistic_gas_price_inflation_ratio: ::std::option::Option<[i32; 2usize]>,
+    #[doc = "Describes fees for storage."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_usage_config: ::std::option::Option<StorageUsageConfigView>,
+}
+impl limits::FieldLimits for RuntimeFeesConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::items("burnt_gas_reward", Some(2), Some(2)),
+        limits::FieldLimit::items("pessimistic_gas_price_inflation_ratio", Some(2), Some(2)),
+    ];
+}
+impl ::std::convert::From<&RuntimeFeesConfigView> for RuntimeFeesConfigView {
+    fn from(value: &RuntimeFeesConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RuntimeFeesConfigView {
+    fn default() -> Self {
+        Self {
+            action_creation_config: Default::default(),
+            action_receipt_creation_config: Default::default(),
+            burnt_gas_reward: Default::default(),
+            data_receipt_creation_config: Default::default(),
+            pessimistic_gas_price_inflation_ratio: Default::default(),
+            storage_usage_config: Default::default(),
+        }
+    }
+}
+#[doc = "A versioned struct that contains all information needed to assign accounts to shards.\n\nBecause of re-sharding, the chain may use different shard layout to split shards at different\ntimes. Currently, `ShardLayout` is stored as part of `EpochConfig`, which is generated each\nepoch given the epoch protocol version. In mainnet/testnet, we use two shard layouts since\nre-sharding has only happened once. It is stored as part of genesis config, see\ndefault_simple_nightshade_shard_layout() Below is an overview for some important\nfunctionalities of ShardLayout interface."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"A versioned struct that contains all information needed to assign accounts to shards.\\n\\nBecause of re-sharding, the chain may use different shard layout to split shards at different\\ntimes. Currently, `ShardLayout` is stored as part of `EpochConfig`, which is generated each\\nepoch given the epoch protocol version. In mainnet/testnet, we use two shard layouts since\\nre-sharding has only happened once. It is stored as part of genesis config, see\\ndefault_simple_nightshade_shard_layout() Below is an overview for some important\\nfunctionalities of ShardLayout interface.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"V0\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"V0\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardLayoutV0\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"V1\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"V1\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardLayoutV1\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"V2\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"V2\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardLayoutV2\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"V3\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"V3\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardLayoutV3\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ShardLayout {
+    V0(ShardLayoutV0),
+    V1(ShardLayoutV1),
+    V2(ShardLayoutV2),
+    V3(ShardLayoutV3),
+}
+impl ::std::convert::From<&Self> for ShardLayout {
+    fn from(value: &ShardLayout) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<ShardLayoutV0> for ShardLayout {
+    fn from(value: ShardLayoutV0) -> Self {
+        Self::V0(value)
+    }
+}
+impl ::std::convert::From<ShardLayoutV1> for ShardLayout {
+    fn from(value: ShardLayoutV1) -> Self {
+        Self::V1(value)
+    }
+}
+impl ::std::convert::From<ShardLayoutV2> for ShardLayout {
+    fn from(value: ShardLayoutV2) -> Self {
+        Self::V2(value)
+    }
+}
+impl ::std::convert::From<ShardLayoutV3> for ShardLayout {
+    fn from(value: ShardLayoutV3) -> Self {
+        Self::V3(value)
+    }
+}
+#[doc = "A shard layout that maps accounts evenly across all shards -- by calculate the hash of account\nid and mod number of shards. This is added to capture the old `account_id_to_shard_id` algorithm,\nto keep backward compatibility for some existing tests.\n`parent_shards` for `ShardLayoutV1` is always `None`, meaning it can only be the first shard layout\na chain uses."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"A shard layout that maps accounts evenly across all shards -- by calculate the hash of account\\nid and mod number of shards. This is added to capture the old `account_id_to_shard_id` algorithm,\\nto keep backward compatibility for some existing tests.\\n`parent_shards` for `ShardLayoutV1` is always `None`, meaning it can only be the first shard layout\\na chain uses.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"num_shards\","]
+#[doc = "    \"version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"num_shards\": {"]
+#[doc = "      \"description\": \"Map accounts evenly across all shards\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"description\": \"Version of the shard layout, this is useful for uniquely identify the shard layout\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ShardLayoutV0 {
+    #[doc = "Map accounts evenly across all shards"]
+    pub num_shards: u64,
+    #[doc = "Version of the shard layout, this is useful for uniquely identify the shard layout"]
+    pub version: u32,
+}
+impl limits::FieldLimits for ShardLayoutV0 {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("num_shards", "uint64", Some(0), None),
+        limits::FieldLimit::integer("version", "uint32", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&ShardLayoutV0> for ShardLayoutV0 {
+    fn from(value: &ShardLayoutV0) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ShardLayoutV1`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"boundary_accounts\","]
+#[doc = "    \"version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"boundary_accounts\": {"]
+#[doc = "      \"description\": \"The boundary accounts are the accounts on boundaries between shards.\\nEach shard contains a range of accounts from one boundary account to\\nanother - or the smallest or largest account possible. The total\\nnumber of shards is equal to the number of boundary accounts plus 1.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shards_split_map\": {"]
+#[doc = "      \"description\": \"Maps shards from the last shard layout to shards that it splits to in this shard layout,\\nUseful for constructing states for the shards.\\nNone for the genesis shard layout\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"array\","]
+#[doc = "        \"items\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"to_parent_shard_map\": {"]
+#[doc = "      \"description\": \"Maps shard in this shard layout to their parent shard\\nSince shard_ids always range from 0 to num_shards - 1, we use vec instead of a hashmap\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"description\": \"Version of the shard layout, this is useful for uniquely identify the shard layout\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ShardLayoutV1 {
+    #[doc = "The boundary accounts are the accounts on boundaries between shards.\nEach shard contains a range of accounts from one boundary account to\nanother - or the smallest or largest account possible. The total\nnumber of shards is equal to the number of boundary accounts plus 1."]
+    pub boundary_accounts: ::std::vec::Vec<AccountId>,
+    #[doc = "Maps shards from the last shard layout to shards that it splits to in this shard layout,\nUseful for constructing states for the shards.\nNone for the genesis shard layout"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub shards_split_map: ::std::option::Option<::std::vec::Vec<::std::vec::Vec<ShardId>>>,
+    #[doc = "Maps shard in this shard layout to their parent shard\nSince shard_ids always range from 0 to num_shards - 1, we use vec instead of a hashmap"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub to_parent_shard_map: ::std::option::Option<::std::vec::Vec<ShardId>>,
+    #[doc = "Version of the shard layout, this is useful for uniquely identify the shard layout"]
+    pub version: u32,
+}
+impl limits::FieldLimits for ShardLayoutV1 {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "version",
+        "uint32",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&ShardLayoutV1> for ShardLayoutV1 {
+    fn from(value: &ShardLayoutV1) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Counterpart to `ShardLayoutV2` composed of maps with string keys to aid\nserde serialization."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Counterpart to `ShardLayoutV2` composed of maps with string keys to aid\\nserde serialization.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"boundary_accounts\","]
+#[doc = "    \"id_to_index_map\","]
+#[doc = "    \"index_to_id_map\","]
+#[doc = "    \"shard_ids\","]
+#[doc = "    \"version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"boundary_accounts\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"id_to_index_map\": {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"index_to_id_map\": {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shard_ids\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shards_parent_map\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"object\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shards_split_map\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"object\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"type\": \"array\","]
+#[doc = "        \"items\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ShardLayoutV2 {
+    pub boundary_accounts: ::std::vec::Vec<AccountId>,
+    pub id_to_index_map: OrderedMap<::std::string::String, u32>,
+    pub index_to_id_map: OrderedMap<::std::string::String, ShardId>,
+    pub shard_ids: ::std::vec::Vec<ShardId>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub shards_parent_map: ::std::option::Option<OrderedMap<::std::string::String, ShardId>>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub shards_split_map:
+        ::std::option::Option<OrderedMap<::std::string::String, ::std::vec::Vec<ShardId>>>,
+    pub version: u32,
+}
+impl limits::FieldLimits for ShardLayoutV2 {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "version",
+        "uint32",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&ShardLayoutV2> for ShardLayoutV2 {
+    fn from(value: &ShardLayoutV2) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Counterpart to `ShardLayoutV3` composed of maps with string keys to aid\nserde serialization."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Counterpart to `ShardLayoutV3` composed of maps with string keys to aid\\nserde serialization.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"boundary_accounts\","]
+#[doc = "    \"id_to_index_map\","]
+#[doc = "    \"last_split\","]
+#[doc = "    \"shard_ids\","]
+#[doc = "    \"shards_split_map\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"boundary_accounts\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"id_to_index_map\": {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"last_split\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "    },"]
+#[doc = "    \"shard_ids\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shards_split_map\": {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"type\": \"array\","]
+#[doc = "        \"items\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ShardLayoutV3 {
+    pub boundary_accounts: ::std::vec::Vec<AccountId>,
+    pub id_to_index_map: OrderedMap<::std::string::String, u32>,
+    pub last_split: ShardId,
+    pub shard_ids: ::std::vec::Vec<ShardId>,
+    pub shards_split_map: OrderedMap<::std::string::String, ::std::vec::Vec<ShardId>>,
+}
+impl ::std::convert::From<&ShardLayoutV3> for ShardLayoutV3 {
+    fn from(value: &ShardLayoutV3) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ShardUId` is a unique representation for shards from different shard layouts.\n\nComparing to `ShardId`, which is just an ordinal number ranging from 0 to NUM_SHARDS-1,\n`ShardUId` provides a way to unique identify shards when shard layouts may change across epochs.\nThis is important because we store states indexed by shards in our database, so we need a\nway to unique identify shard even when shards change across epochs.\nAnother difference between `ShardUId` and `ShardId` is that `ShardUId` should only exist in\na node's internal state while `ShardId` can be exposed to outside APIs and used in protocol\nlevel information (for example, `ShardChunkHeader` contains `ShardId` instead of `ShardUId`)"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"`ShardUId` is a unique representation for shards from different shard layouts.\\n\\nComparing to `ShardId`, which is just an ordinal number ranging from 0 to NUM_SHARDS-1,\\n`ShardUId` provides a way to unique identify shards when shard layouts may change across epochs.\\nThis is important because we store states indexed by shards in our database, so we need a\\nway to unique identify shard even when shards change across epochs.\\nAnother difference between `ShardUId` and `ShardId` is that `ShardUId` should only exist in\\na node's internal state while `ShardId` can be exposed to outside APIs and used in protocol\\nlevel information (for example, `ShardChunkHeader` contains `ShardId` instead of `ShardUId`)\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"shard_id\","]
+#[doc = "    \"version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"shard_id\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ShardUId {
+    pub shard_id: u32,
+    pub version: u32,
+}
+impl limits::FieldLimits for ShardUId {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("shard_id", "uint32", Some(0), None),
+        limits::FieldLimit::integer("version", "uint32", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&ShardUId> for ShardUId {
+    fn from(value: &ShardUId) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StateSyncConfig`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"concurrency\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/SyncConcurrency\""]
+#[doc = "    },"]
+#[doc = "    \"dump\": {"]
+#[doc = "      \"description\": \"`none` value disables state dump to external storage.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DumpConfig\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"parts_compression_lvl\": {"]
+#[doc = "      \"description\": \"Zstd compression level for state parts.\","]
+#[doc = "      \"default\": 1,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"int32\""]
+#[doc = "    },"]
+#[doc = "    \"sync\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/SyncConfig\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StateSyncConfig {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub concurrency: ::std::option::Option<SyncConcurrency>,
+    #[doc = "`none` value disables state dump to external storage."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub dump: ::std::option::Option<DumpConfig>,
+    #[doc = "Zstd compression level for state parts."]
+    #[serde(default = "defaults::default_u64::<i32, 1>")]
+    pub parts_compression_lvl: i32,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub sync: ::std::option::Option<SyncConfig>,
+}
+impl ::std::convert::From<&StateSyncConfig> for StateSyncConfig {
+    fn from(value: &StateSyncConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for StateSyncConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: Default::default(),
+            dump: Default::default(),
+            parts_compression_lvl: defaults::default_u64::<i32, 1>(),
+            sync: Default::default(),
+        }
+    }
+}
+#[doc = "This enum represents if a storage_get call will be performed through flat storage or trie"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"This enum represents if a storage_get call will be performed through flat storage or trie\","]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"FlatStorage\","]
+#[doc = "    \"Trie\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StorageGetMode {
+    FlatStorage,
+    Trie,
+}
+impl ::std::convert::From<&Self> for StorageGetMode {
+    fn from(value: &StorageGetMode) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for StorageGetMode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::FlatStorage => f.write_str("FlatStorage"),
+            Self::Trie => f.write_str("Trie"),
+        }
+    }
+}
+impl ::std::str::FromStr for StorageGetMode {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "FlatStorage" => Ok(Self::FlatStorage),
+            "Trie" => Ok(Self::Trie),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for StorageGetMode {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for StorageGetMode {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for StorageGetMode {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Describes cost of storage per block"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes cost of storage per block\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"num_bytes_account\": {"]
+#[doc = "      \"description\": \"Number of bytes for an account record, including rounding up for account id.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_extra_bytes_record\": {"]
+#[doc = "      \"description\": \"Additional number of bytes for a k/v record\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StorageUsageConfigView {
+    #[doc = "Number of bytes for an account record, including rounding up for account id."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub num_bytes_account: ::std::option::Option<u64>,
+    #[doc = "Additional number of bytes for a k/v record"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub num_extra_bytes_record: ::std::option::Option<u64>,
+}
+impl limits::FieldLimits for StorageUsageConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("num_bytes_account", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_extra_bytes_record", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&StorageUsageConfigView> for StorageUsageConfigView {
+    fn from(value: &StorageUsageConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for StorageUsageConfigView {
+    fn default() -> Self {
+        Self {
+            num_bytes_account: Default::default(),
+            num_extra_bytes_record: Default::default(),
+        }
+    }
+}
+#[doc = "`SyncConcurrency`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"apply\": {"]
+#[doc = "      \"description\": \"Maximum number of \\\"apply parts\\\" tasks that can be performed in parallel.\\nThis is a very disk-heavy task and therefore we set this to a low limit,\\nor else the rocksdb contention makes the whole server freeze up.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"apply_during_catchup\": {"]
+#[doc = "      \"description\": \"Maximum number of \\\"apply parts\\\" tasks that can be performed in parallel\\nduring catchup. We set this to a very low value to avoid overloading the\\nnode while it is still performing normal tasks.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"peer_downloads\": {"]
+#[doc = "      \"description\": \"Maximum number of outstanding requests for decentralized state sync.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"per_shard\": {"]
+#[doc = "      \"description\": \"The maximum parallelism to use per shard. This is mostly for fairness, because\\nthe actual rate limiting is done by the TaskTrackers, but this is useful for\\nbalancing the shards a little.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint8\","]
+#[doc = "      \"maximum\": 255.0,"]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SyncConcurrency {
+    #[doc = "Maximum number of \"apply parts\" tasks that can be performed in parallel.\nThis is a very disk-heavy task and therefore we set this to a low limit,\nor else the rocksdb contention makes the whole server freeze up."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub apply: ::std::option::Option<u8>,
+    #[doc = "Maximum number of \"apply parts\" tasks that can be performed in parallel\nduring catchup. We set this to a very low value to avoid overloading the\nnode while it is still performing normal tasks."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub apply_during_catchup: ::std::option::Option<u8>,
+    #[doc = "Maximum number of outstanding requests for decentralized state sync."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub peer_downloads: ::std::option::Option<u8>,
+    #[doc = "The maximum parallelism to use per shard. This is mostly for fairness, because\nthe actual rate limiting is done by the TaskTrackers, but this is useful for\nbalancing the shards a little."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub per_shard: ::std::option::Option<u8>,
+}
+impl limits::FieldLimits for SyncConcurrency {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("apply", "uint8", Some(0), Some(255)),
+        limits::FieldLimit::integer("apply_during_catchup", "uint8", Some(0), Some(255)),
+        limits::FieldLimit::integer("peer_downloads", "uint8", Some(0), Some(255)),
+        limits::FieldLimit::integer("per_shard", "uint8", Some(0), Some(255)),
+    ];
+}
+impl ::std::convert::From<&SyncConcurrency> for SyncConcurrency {
+    fn from(value: &SyncConcurrency) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for SyncConcurrency {
+    fn default() -> Self {
+        Self {
+            apply: Default::default(),
+            apply_during_catchup: Default::default(),
+            peer_downloads: Default::default(),
+            per_shard: Default::default(),
+        }
+    }
+}
+#[doc = "Configures how to fetch state parts during state sync."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configures how to fetch state parts during state sync.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Syncs state from the peers without reading anything from external storage.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Peers\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Expects parts to be available in external storage.\\n\\nUsually as a fallback after some number of attempts to use peers.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"ExternalStorage\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"ExternalStorage\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExternalStorageConfig\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SyncConfig {
+    #[doc = "Syncs state from the peers without reading anything from external storage."]
+    Peers,
+    #[doc = "Expects parts to be available in external storage.\n\nUsually as a fallback after some number of attempts to use peers."]
+    ExternalStorage(ExternalStorageConfig),
+}
+impl ::std::convert::From<&Self> for SyncConfig {
+    fn from(value: &SyncConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<ExternalStorageConfig> for SyncConfig {
+    fn from(value: ExternalStorageConfig) -> Self {
+        Self::ExternalStorage(value)
+    }
+}
+#[doc = "Describes the expected behavior of the node regarding shard tracking.\nIf the node is an active validator, it will also track the shards it is responsible for as a validator."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes the expected behavior of the node regarding shard tracking.\\nIf the node is an active validator, it will also track the shards it is responsible for as a validator.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Tracks no shards (light client).\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"NoShards\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Tracks arbitrary shards.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Shards\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Shards\": {"]
+#[doc = "          \"type\": \"array\","]
+#[doc = "          \"items\": {"]
+#[doc = "            \"$ref\": \"#/components/schemas/ShardUId\""]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Tracks all shards.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"AllShards\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Tracks shards that are assigned to given validator account.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"ShadowValidator\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"ShadowValidator\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Rotate between these sets of tracked shards.\\nUsed to simulate the behavior of chunk only producers without staking tokens.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Schedule\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Schedule\": {"]
+#[doc = "          \"type\": \"array\","]
+#[doc = "          \"items\": {"]
+#[doc = "            \"type\": \"array\","]
+#[doc = "            \"items\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Tracks shards that contain one of the given account.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Accounts\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Accounts\": {"]
+#[doc = "          \"type\": \"array\","]
+#[doc = "          \"items\": {"]
+#[doc = "            \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TrackedShardsConfig {
+    #[doc = "Tracks no shards (light client)."]
+    NoShards,
+    #[doc = "Tracks arbitrary shards."]
+    Shards(::std::vec::Vec<ShardUId>),
+    #[doc = "Tracks all shards."]
+    AllShards,
+    #[doc = "Tracks shards that are assigned to given validator account."]
+    ShadowValidator(AccountId),
+    #[doc = "Rotate between these sets of tracked shards.\nUsed to simulate the behavior of chunk only producers without staking tokens."]
+    Schedule(::std::vec::Vec<::std::vec::Vec<ShardId>>),
+    #[doc = "Tracks shards that contain one of the given account."]
+    Accounts(::std::vec::Vec<AccountId>),
+}
+impl ::std::convert::From<&Self> for TrackedShardsConfig {
+    fn from(value: &TrackedShardsConfig) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<::std::vec::Vec<ShardUId>> for TrackedShardsConfig {
+    fn from(value: ::std::vec::Vec<ShardUId>) -> Self {
+        Self::Shards(value)
+    }
+}
+impl ::std::convert::From<AccountId> for TrackedShardsConfig {
+    fn from(value: AccountId) -> Self {
+        Self::ShadowValidator(value)
+    }
+}
+impl ::std::convert::From<::std::vec::Vec<::std::vec::Vec<ShardId>>> for TrackedShardsConfig {
+    fn from(value: ::std::vec::Vec<::std::vec::Vec<ShardId>>) -> Self {
+        Self::Schedule(value)
+    }
+}
+impl ::std::convert::From<::std::vec::Vec<AccountId>> for TrackedShardsConfig {
+    fn from(value: ::std::vec::Vec<AccountId>) -> Self {
+        Self::Accounts(value)
+    }
+}
+#[doc = "`VmConfigView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"deterministic_account_ids\": {"]
+#[doc = "      \"description\": \"See [VMConfig::deterministic_account_ids](crate::vm::Config::deterministic_account_ids).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"discard_custom_sections\": {"]
+#[doc = "      \"description\": \"See [VMConfig::discard_custom_sections](crate::vm::Config::discard_custom_sections).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"eth_implicit_accounts\": {"]
+#[doc = "      \"description\": \"See [VMConfig::eth_implicit_accounts](crate::vm::Config::eth_implicit_accounts).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"ext_costs\": {"]
+#[doc = "      \"description\": \"Costs for runtime externals\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExtCostsConfigView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"fix_contract_loading_cost\": {"]
+#[doc = "      \"description\": \"See [VMConfig::fix_contract_loading_cost](crate::vm::Config::fix_contract_loading_cost).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"global_contract_host_fns\": {"]
+#[doc = "      \"description\": \"See [VMConfig::global_contract_host_fns](crate::vm::Config::global_contract_host_fns).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"grow_mem_cost\": {"]
+#[doc = "      \"description\": \"Gas cost of a growing memory by single page.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"implicit_account_creation\": {"]
+#[doc = "      \"description\": \"Deprecated\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"limit_config\": {"]
+#[doc = "      \"description\": \"Describes limits for VM and Runtime.\\n\\nTODO: Consider changing this to `VMLimitConfigView` to avoid dependency\\non runtime.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/LimitConfig\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"linear_op_base_cost\": {"]
+#[doc = "      \"description\": \"Base gas cost of a linear operation\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"linear_op_unit_cost\": {"]
+#[doc = "      \"description\": \"Unit gas cost of a linear operation\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"reftypes_bulk_memory\": {"]
+#[doc = "      \"description\": \"See [VMConfig::reftypes_bulk_memory](crate::vm::Config::reftypes_bulk_memory).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"regular_op_cost\": {"]
+#[doc = "      \"description\": \"Gas cost of a regular operation.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"saturating_float_to_int\": {"]
+#[doc = "      \"description\": \"See [VMConfig::saturating_float_to_int](crate::vm::Config::saturating_float_to_int).\","]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"storage_get_mode\": {"]
+#[doc = "      \"description\": \"See [VMConfig::storage_get_mode](crate::vm::Config::storage_get_mode).\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/StorageGetMode\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"vm_kind\": {"]
+#[doc = "      \"description\": \"See [VMConfig::vm_kind](crate::vm::Config::vm_kind).\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/VMKind\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct VmConfigView {
+    #[doc = "See [VMConfig::deterministic_account_ids](crate::vm::Config::deterministic_account_ids)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub deterministic_account_ids: ::std::option::Option<bool>,
+    #[doc = "See [VMConfig::discard_custom_sections](crate::vm::Config::discard_custom_sections)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub discard_custom_sections: ::std::option::Option<bool>,
+    #[doc = "See [VMConfig::eth_implicit_accounts](crate::vm::Config::eth_implicit_accounts)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub eth_implicit_accounts: ::std::option::Option<bool>,
+    #[doc = "Costs for runtime externals"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub ext_costs: ::std::option::Option<ExtCostsConfigView>,
+    #[doc = "See [VMConfig::fix_contract_loading_cost](crate::vm::Config::fix_contract_loading_cost)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub fix_contract_loading_cost: ::std::option::Option<bool>,
+    #[doc = "See [VMConfig::global_contract_host_fns](crate::vm::Config::global_contract_host_fns)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub global_contract_host_fns: ::std::option::Option<bool>,
+    #[doc = "Gas cost of a growing memory by single page."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub grow_mem_cost: ::std::option::Option<u32>,
+    #[doc = "Deprecated"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub implicit_account_creation: ::std::option::Option<bool>,
+    #[doc = "Describes limits for VM and Runtime.\n\nTODO: Consider changing this to `VMLimitConfigView` to avoid dependency\non runtime."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub limit_config: ::std::option::Option<LimitConfig>,
+    #[doc = "Base gas cost of a linear operation"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub linear_op_base_cost: ::std::option::Option<u64>,
+    #[doc = "Unit gas cost of a linear operation"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub linear_op_unit_cost: ::std::option::Option<u64>,
+    #[doc = "See [VMConfig::reftypes_bulk_memory](crate::vm::Config::reftypes_bulk_memory)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub reftypes_bulk_memory: ::std::option::Option<bool>,
+    #[doc = "Gas cost of a regular operation."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub regular_op_cost: ::std::option::Option<u32>,
+    #[doc = "See [VMConfig::saturating_float_to_int](crate::vm::Config::saturating_float_to_int)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub saturating_float_to_int: ::std::option::Option<bool>,
+    #[doc = "See [VMConfig::storage_get_mode](crate::vm::Config::storage_get_mode)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub storage_get_mode: ::std::option::Option<StorageGetMode>,
+    #[doc = "See [VMConfig::vm_kind](crate::vm::Config::vm_kind)."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub vm_kind: ::std::option::Option<VmKind>,
+}
+impl limits::FieldLimits for VmConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("grow_mem_cost", "uint32", Some(0), None),
+        limits::FieldLimit::integer("linear_op_base_cost", "uint64", Some(0), None),
+        limits::FieldLimit::integer("linear_op_unit_cost", "uint64", Some(0), None),
+        limits::FieldLimit::integer("regular_op_cost", "uint32", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&VmConfigView> for VmConfigView {
+    fn from(value: &VmConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for VmConfigView {
+    fn default() -> Self {
+        Self {
+            deterministic_account_ids: Default::default(),
+            discard_custom_sections: Default::default(),
+            eth_implicit_accounts: Default::default(),
+            ext_costs: Default::default(),
+            fix_contract_loading_cost: Default::default(),
+            global_contract_host_fns: Default::default(),
+            grow_mem_cost: Default::default(),
+            implicit_account_creation: Default::default(),
+            limit_config: Default::default(),
+            linear_op_base_cost: Default::default(),
+            linear_op_unit_cost: Default::default(),
+            reftypes_bulk_memory: Default::default(),
+            regular_op_cost: Default::default(),
+            saturating_float_to_int: Default::default(),
+            storage_get_mode: Default::default(),
+            vm_kind: Default::default(),
+        }
+    }
+}
+#[doc = "`VmKind`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Wasmer 0.17.x VM. Gone now.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Wasmer0\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Wasmtime VM.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Wasmtime\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Wasmer 2.x VM.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Wasmer2\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"NearVM.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"NearVm\""]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum VmKind {
+    #[doc = "Wasmer 0.17.x VM. Gone now."]
+    Wasmer0,
+    #[doc = "Wasmtime VM."]
+    Wasmtime,
+    #[doc = "Wasmer 2.x VM."]
+    Wasmer2,
+    #[doc = "NearVM."]
+    NearVm,
+}
+impl ::std::convert::From<&Self> for VmKind {
+    fn from(value: &VmKind) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for VmKind {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Wasmer0 => f.write_str("Wasmer0"),
+            Self::Wasmtime => f.write_str("Wasmtime"),
+            Self::Wasmer2 => f.write_str("Wasmer2"),
+            Self::NearVm => f.write_str("NearVm"),
+        }
+    }
+}
+impl ::std::str::FromStr for VmKind {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "Wasmer0" => Ok(Self::Wasmer0),
+            "Wasmtime" => Ok(Self::Wasmtime),
+            "Wasmer2" => Ok(Self::Wasmer2),
+            "NearVm" => Ok(Self::NearVm),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for VmKind {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for VmKind {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for VmKind {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Configuration specific to ChunkStateWitness."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Configuration specific to ChunkStateWitness.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"combined_transactions_size_limit\": {"]
+#[doc = "      \"description\": \"Maximum size of transactions contained inside ChunkStateWitness.\\n\\nA witness contains transactions from both the previous chunk and the current one.\\nThis parameter limits the sum of sizes of transactions from both of those chunks.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"main_storage_proof_size_soft_limit\": {"]
+#[doc = "      \"description\": \"Size limit for storage proof generated while executing receipts in a chunk.\\nAfter this limit is reached we defer execution of any new receipts.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"new_transactions_validation_state_size_soft_limit\": {"]
+#[doc = "      \"description\": \"Soft size limit of storage proof used to validate new transactions in ChunkStateWitness.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct WitnessConfigView {
+    #[doc = "Maximum size of transactions contained inside ChunkStateWitness.\n\nA witness contains transactions from both the previous chunk and the current one.\nThis parameter limits the sum of sizes of transactions from both of those chunks."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub combined_transactions_size_limit: ::std::option::Option<u32>,
+    #[doc = "Size limit for storage proof generated while executing receipts in a chunk.\nAfter this limit is reached we defer execution of any new receipts."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub main_storage_proof_size_soft_limit: ::std::option::Option<u64>,
+    #[doc = "Soft size limit of storage proof used to validate new transactions in ChunkStateWitness."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub new_transactions_validation_state_size_soft_limit: ::std::option::Option<u64>,
+}
+impl limits::FieldLimits for WitnessConfigView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("combined_transactions_size_limit", "uint", Some(0), None),
+        limits::FieldLimit::integer(
+            "main_storage_proof_size_soft_limit",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer(
+            "new_transactions_validation_state_size_soft_limit",
+            "uint64",
+            Some(0),
+            None,
+        ),
+    ];
+}
+impl ::std::convert::From<&WitnessConfigView> for WitnessConfigView {
+    fn from(value: &WitnessConfigView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for WitnessConfigView {
+    fn default() -> Self {
+        Self {
+            combined_transactions_size_limit: Default::default(),
+            main_storage_proof_size_soft_limit: Default::default(),
+            new_transactions_validation_state_size_soft_limit: Default::default(),
+        }
+    }
+}
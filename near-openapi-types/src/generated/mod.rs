@@ -0,0 +1,22 @@
+//! Types generated from the OpenAPI spec, split by domain. Every type is
+//! also re-exported at the crate root, e.g. [`block::BlockHeaderView`] as
+//! `near_openapi_types::BlockHeaderView`.
+#[cfg(feature = "block")]
+pub mod block;
+#[cfg(feature = "changes")]
+pub mod changes;
+#[cfg(feature = "block")]
+pub mod chunk;
+pub mod common;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod errors;
+#[cfg(feature = "full")]
+pub mod jsonrpc;
+#[cfg(feature = "debug")]
+pub mod network;
+pub mod query;
+#[cfg(feature = "tx")]
+pub mod tx;
+#[cfg(feature = "validators")]
+pub mod validators;
@@ -0,0 +1,1249 @@
+//! Node status, network info and maintenance windows.
+use crate::*;
+
+#[doc = "AccountData is a piece of global state that a validator\nsigns and broadcasts to the network.\n\nIt is essentially the data that a validator wants to share with the network.\nAll the nodes in the network are collecting the account data\nbroadcasted by the validators.\nSince the number of the validators is bounded and their\nidentity is known (and the maximal size of allowed AccountData is bounded)\nthe global state that is distributed in the form of AccountData is bounded\nas well.\nFind more information in the docs [here](https://github.com/near/nearcore/blob/560f7fc8f4b3106e0d5d46050688610b1f104ac6/chain/client/src/client.rs#L2232)"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"AccountData is a piece of global state that a validator\\nsigns and broadcasts to the network.\\n\\nIt is essentially the data that a validator wants to share with the network.\\nAll the nodes in the network are collecting the account data\\nbroadcasted by the validators.\\nSince the number of the validators is bounded and their\\nidentity is known (and the maximal size of allowed AccountData is bounded)\\nthe global state that is distributed in the form of AccountData is bounded\\nas well.\\nFind more information in the docs [here](https://github.com/near/nearcore/blob/560f7fc8f4b3106e0d5d46050688610b1f104ac6/chain/client/src/client.rs#L2232)\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_key\","]
+#[doc = "    \"peer_id\","]
+#[doc = "    \"proxies\","]
+#[doc = "    \"timestamp\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_key\": {"]
+#[doc = "      \"description\": \"Account key of the validator signing this AccountData.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"peer_id\": {"]
+#[doc = "      \"description\": \"ID of the node that handles the account key (aka validator key).\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"proxies\": {"]
+#[doc = "      \"description\": \"Proxy nodes that are directly connected to the validator node\\n(this list may include the validator node itself).\\nTIER1 nodes should connect to one of the proxies to sent TIER1\\nmessages to the validator.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/Tier1ProxyView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"timestamp\": {"]
+#[doc = "      \"description\": \"UTC timestamp of when the AccountData has been signed.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AccountDataView {
+    #[doc = "Account key of the validator signing this AccountData."]
+    pub account_key: PublicKey,
+    #[doc = "ID of the node that handles the account key (aka validator key)."]
+    pub peer_id: PublicKey,
+    #[doc = "Proxy nodes that are directly connected to the validator node\n(this list may include the validator node itself).\nTIER1 nodes should connect to one of the proxies to sent TIER1\nmessages to the validator."]
+    pub proxies: ::std::vec::Vec<Tier1ProxyView>,
+    #[doc = "UTC timestamp of when the AccountData has been signed."]
+    pub timestamp: ::std::string::String,
+}
+impl ::std::convert::From<&AccountDataView> for AccountDataView {
+    fn from(value: &AccountDataView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Status of the [catchup](https://near.github.io/nearcore/architecture/how/sync.html#catchup) process"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Status of the [catchup](https://near.github.io/nearcore/architecture/how/sync.html#catchup) process\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"blocks_to_catchup\","]
+#[doc = "    \"shard_sync_status\","]
+#[doc = "    \"sync_block_hash\","]
+#[doc = "    \"sync_block_height\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"blocks_to_catchup\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/BlockStatusView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shard_sync_status\": {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    \"sync_block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"sync_block_height\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CatchupStatusView {
+    pub blocks_to_catchup: ::std::vec::Vec<BlockStatusView>,
+    pub shard_sync_status: CatchupStatusViewShardSyncStatus,
+    pub sync_block_hash: CryptoHash,
+    pub sync_block_height: u64,
+}
+impl limits::FieldLimits for CatchupStatusView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "sync_block_height",
+        "uint64",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&CatchupStatusView> for CatchupStatusView {
+    fn from(value: &CatchupStatusView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`CatchupStatusViewShardSyncStatus`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"additionalProperties\": false"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(deny_unknown_fields)]
+pub struct CatchupStatusViewShardSyncStatus {}
+impl ::std::convert::From<&CatchupStatusViewShardSyncStatus> for CatchupStatusViewShardSyncStatus {
+    fn from(value: &CatchupStatusViewShardSyncStatus) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for CatchupStatusViewShardSyncStatus {
+    fn default() -> Self {
+        Self {}
+    }
+}
+#[doc = "`DetailedDebugStatus`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_production_delay_millis\","]
+#[doc = "    \"catchup_status\","]
+#[doc = "    \"current_head_status\","]
+#[doc = "    \"current_header_head_status\","]
+#[doc = "    \"network_info\","]
+#[doc = "    \"sync_status\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_production_delay_millis\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"catchup_status\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/CatchupStatusView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"current_head_status\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/BlockStatusView\""]
+#[doc = "    },"]
+#[doc = "    \"current_header_head_status\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/BlockStatusView\""]
+#[doc = "    },"]
+#[doc = "    \"network_info\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NetworkInfoView\""]
+#[doc = "    },"]
+#[doc = "    \"sync_status\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DetailedDebugStatus {
+    pub block_production_delay_millis: u64,
+    pub catchup_status: ::std::vec::Vec<CatchupStatusView>,
+    pub current_head_status: BlockStatusView,
+    pub current_header_head_status: BlockStatusView,
+    pub network_info: NetworkInfoView,
+    pub sync_status: ::std::string::String,
+}
+impl limits::FieldLimits for DetailedDebugStatus {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "block_production_delay_millis",
+        "uint64",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&DetailedDebugStatus> for DetailedDebugStatus {
+    fn from(value: &DetailedDebugStatus) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`NetworkInfoView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"connected_peers\","]
+#[doc = "    \"known_producers\","]
+#[doc = "    \"num_connected_peers\","]
+#[doc = "    \"peer_max_count\","]
+#[doc = "    \"tier1_accounts_data\","]
+#[doc = "    \"tier1_accounts_keys\","]
+#[doc = "    \"tier1_connections\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"connected_peers\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/PeerInfoView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"known_producers\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/KnownProducerView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_connected_peers\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"peer_max_count\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"tier1_accounts_data\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/AccountDataView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"tier1_accounts_keys\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"tier1_connections\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/PeerInfoView\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NetworkInfoView {
+    pub connected_peers: ::std::vec::Vec<PeerInfoView>,
+    pub known_producers: ::std::vec::Vec<KnownProducerView>,
+    pub num_connected_peers: u32,
+    pub peer_max_count: u32,
+    pub tier1_accounts_data: ::std::vec::Vec<AccountDataView>,
+    pub tier1_accounts_keys: ::std::vec::Vec<PublicKey>,
+    pub tier1_connections: ::std::vec::Vec<PeerInfoView>,
+}
+impl limits::FieldLimits for NetworkInfoView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("num_connected_peers", "uint", Some(0), None),
+        limits::FieldLimit::integer("peer_max_count", "uint32", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&NetworkInfoView> for NetworkInfoView {
+    fn from(value: &NetworkInfoView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`PeerInfoView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"addr\","]
+#[doc = "    \"archival\","]
+#[doc = "    \"connection_established_time_millis\","]
+#[doc = "    \"is_highest_block_invalid\","]
+#[doc = "    \"is_outbound_peer\","]
+#[doc = "    \"last_time_peer_requested_millis\","]
+#[doc = "    \"last_time_received_message_millis\","]
+#[doc = "    \"nonce\","]
+#[doc = "    \"peer_id\","]
+#[doc = "    \"received_bytes_per_sec\","]
+#[doc = "    \"sent_bytes_per_sec\","]
+#[doc = "    \"tracked_shards\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"addr\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"archival\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"block_hash\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"connection_established_time_millis\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"is_highest_block_invalid\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"is_outbound_peer\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"last_time_peer_requested_millis\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"last_time_received_message_millis\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"nonce\": {"]
+#[doc = "      \"description\": \"Connection nonce.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"peer_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"received_bytes_per_sec\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"sent_bytes_per_sec\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"tracked_shards\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct PeerInfoView {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub account_id: ::std::option::Option<AccountId>,
+    pub addr: ::std::string::String,
+    pub archival: bool,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_hash: ::std::option::Option<CryptoHash>,
+    pub connection_established_time_millis: u64,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub height: ::std::option::Option<u64>,
+    pub is_highest_block_invalid: bool,
+    pub is_outbound_peer: bool,
+    pub last_time_peer_requested_millis: u64,
+    pub last_time_received_message_millis: u64,
+    #[doc = "Connection nonce."]
+    pub nonce: u64,
+    pub peer_id: PublicKey,
+    pub received_bytes_per_sec: u64,
+    pub sent_bytes_per_sec: u64,
+    pub tracked_shards: ::std::vec::Vec<ShardId>,
+}
+impl limits::FieldLimits for PeerInfoView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer(
+            "connection_established_time_millis",
+            "uint64",
+            Some(0),
+            None,
+        ),
+        limits::FieldLimit::integer("height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("last_time_peer_requested_millis", "uint64", Some(0), None),
+        limits::FieldLimit::integer("last_time_received_message_millis", "uint64", Some(0), None),
+        limits::FieldLimit::integer("nonce", "uint64", Some(0), None),
+        limits::FieldLimit::integer("received_bytes_per_sec", "uint64", Some(0), None),
+        limits::FieldLimit::integer("sent_bytes_per_sec", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&PeerInfoView> for PeerInfoView {
+    fn from(value: &PeerInfoView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RangeOfUint64`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"end\","]
+#[doc = "    \"start\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"end\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"start\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RangeOfUint64 {
+    pub end: u64,
+    pub start: u64,
+}
+impl limits::FieldLimits for RangeOfUint64 {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("end", "uint64", Some(0), None),
+        limits::FieldLimit::integer("start", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RangeOfUint64> for RangeOfUint64 {
+    fn from(value: &RangeOfUint64) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcHealthRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcHealthRequest\","]
+#[doc = "  \"type\": \"null\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct RpcHealthRequest(pub ());
+impl ::std::ops::Deref for RpcHealthRequest {
+    type Target = ();
+    fn deref(&self) -> &() {
+        &self.0
+    }
+}
+impl ::std::convert::From<RpcHealthRequest> for () {
+    fn from(value: RpcHealthRequest) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&RpcHealthRequest> for RpcHealthRequest {
+    fn from(value: &RpcHealthRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<()> for RpcHealthRequest {
+    fn from(value: ()) -> Self {
+        Self(value)
+    }
+}
+#[doc = "`RpcHealthResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"null\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct RpcHealthResponse(pub ());
+impl ::std::ops::Deref for RpcHealthResponse {
+    type Target = ();
+    fn deref(&self) -> &() {
+        &self.0
+    }
+}
+impl ::std::convert::From<RpcHealthResponse> for () {
+    fn from(value: RpcHealthResponse) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&RpcHealthResponse> for RpcHealthResponse {
+    fn from(value: &RpcHealthResponse) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<()> for RpcHealthResponse {
+    fn from(value: ()) -> Self {
+        Self(value)
+    }
+}
+#[doc = "`RpcMaintenanceWindowsRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcMaintenanceWindowsRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcMaintenanceWindowsRequest {
+    pub account_id: AccountId,
+}
+impl ::std::convert::From<&RpcMaintenanceWindowsRequest> for RpcMaintenanceWindowsRequest {
+    fn from(value: &RpcMaintenanceWindowsRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcNetworkInfoRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcNetworkInfoRequest\","]
+#[doc = "  \"type\": \"null\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct RpcNetworkInfoRequest(pub ());
+impl ::std::ops::Deref for RpcNetworkInfoRequest {
+    type Target = ();
+    fn deref(&self) -> &() {
+        &self.0
+    }
+}
+impl ::std::convert::From<RpcNetworkInfoRequest> for () {
+    fn from(value: RpcNetworkInfoRequest) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&RpcNetworkInfoRequest> for RpcNetworkInfoRequest {
+    fn from(value: &RpcNetworkInfoRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<()> for RpcNetworkInfoRequest {
+    fn from(value: ()) -> Self {
+        Self(value)
+    }
+}
+#[doc = "`RpcNetworkInfoResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"active_peers\","]
+#[doc = "    \"known_producers\","]
+#[doc = "    \"num_active_peers\","]
+#[doc = "    \"peer_max_count\","]
+#[doc = "    \"received_bytes_per_sec\","]
+#[doc = "    \"sent_bytes_per_sec\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"active_peers\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/RpcPeerInfo\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"known_producers\": {"]
+#[doc = "      \"description\": \"Accounts of known block and chunk producers from routing table.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/RpcKnownProducer\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_active_peers\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"peer_max_count\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"received_bytes_per_sec\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"sent_bytes_per_sec\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcNetworkInfoResponse {
+    pub active_peers: ::std::vec::Vec<RpcPeerInfo>,
+    #[doc = "Accounts of known block and chunk producers from routing table."]
+    pub known_producers: ::std::vec::Vec<RpcKnownProducer>,
+    pub num_active_peers: u32,
+    pub peer_max_count: u32,
+    pub received_bytes_per_sec: u64,
+    pub sent_bytes_per_sec: u64,
+}
+impl limits::FieldLimits for RpcNetworkInfoResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("num_active_peers", "uint", Some(0), None),
+        limits::FieldLimit::integer("peer_max_count", "uint32", Some(0), None),
+        limits::FieldLimit::integer("received_bytes_per_sec", "uint64", Some(0), None),
+        limits::FieldLimit::integer("sent_bytes_per_sec", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RpcNetworkInfoResponse> for RpcNetworkInfoResponse {
+    fn from(value: &RpcNetworkInfoResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcPeerInfo`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"addr\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PeerId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcPeerInfo {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub account_id: ::std::option::Option<AccountId>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub addr: ::std::option::Option<::std::string::String>,
+    pub id: PeerId,
+}
+impl ::std::convert::From<&RpcPeerInfo> for RpcPeerInfo {
+    fn from(value: &RpcPeerInfo) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcSplitStorageInfoRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcSplitStorageInfoRequest\","]
+#[doc = "  \"type\": \"object\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct RpcSplitStorageInfoRequest(
+    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+);
+impl ::std::ops::Deref for RpcSplitStorageInfoRequest {
+    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+        &self.0
+    }
+}
+impl ::std::convert::From<RpcSplitStorageInfoRequest>
+    for ::serde_json::Map<::std::string::String, ::serde_json::Value>
+{
+    fn from(value: RpcSplitStorageInfoRequest) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&RpcSplitStorageInfoRequest> for RpcSplitStorageInfoRequest {
+    fn from(value: &RpcSplitStorageInfoRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
+    for RpcSplitStorageInfoRequest
+{
+    fn from(value: ::serde_json::Map<::std::string::String, ::serde_json::Value>) -> Self {
+        Self(value)
+    }
+}
+#[doc = "Contains the split storage information."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Contains the split storage information.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"cold_head_height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"final_head_height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"head_height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"hot_db_kind\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcSplitStorageInfoResponse {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub cold_head_height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub final_head_height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub head_height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub hot_db_kind: ::std::option::Option<::std::string::String>,
+}
+impl limits::FieldLimits for RpcSplitStorageInfoResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("cold_head_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("final_head_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("head_height", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RpcSplitStorageInfoResponse> for RpcSplitStorageInfoResponse {
+    fn from(value: &RpcSplitStorageInfoResponse) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RpcSplitStorageInfoResponse {
+    fn default() -> Self {
+        Self {
+            cold_head_height: Default::default(),
+            final_head_height: Default::default(),
+            head_height: Default::default(),
+            hot_db_kind: Default::default(),
+        }
+    }
+}
+#[doc = "`RpcStatusRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcStatusRequest\","]
+#[doc = "  \"type\": \"null\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct RpcStatusRequest(pub ());
+impl ::std::ops::Deref for RpcStatusRequest {
+    type Target = ();
+    fn deref(&self) -> &() {
+        &self.0
+    }
+}
+impl ::std::convert::From<RpcStatusRequest> for () {
+    fn from(value: RpcStatusRequest) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&RpcStatusRequest> for RpcStatusRequest {
+    fn from(value: &RpcStatusRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<()> for RpcStatusRequest {
+    fn from(value: ()) -> Self {
+        Self(value)
+    }
+}
+#[doc = "`RpcStatusResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"chain_id\","]
+#[doc = "    \"genesis_hash\","]
+#[doc = "    \"latest_protocol_version\","]
+#[doc = "    \"node_public_key\","]
+#[doc = "    \"protocol_version\","]
+#[doc = "    \"sync_info\","]
+#[doc = "    \"uptime_sec\","]
+#[doc = "    \"validators\","]
+#[doc = "    \"version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"chain_id\": {"]
+#[doc = "      \"description\": \"Unique chain id.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"detailed_debug_status\": {"]
+#[doc = "      \"description\": \"Information about last blocks, network, epoch and chain & chunk info.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DetailedDebugStatus\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"genesis_hash\": {"]
+#[doc = "      \"description\": \"Genesis hash of the chain.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"latest_protocol_version\": {"]
+#[doc = "      \"description\": \"Latest protocol version that this client supports.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"node_key\": {"]
+#[doc = "      \"description\": \"Deprecated; same as `validator_public_key` which you should use instead.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"node_public_key\": {"]
+#[doc = "      \"description\": \"Public key of the node.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"protocol_version\": {"]
+#[doc = "      \"description\": \"Currently active protocol version.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"rpc_addr\": {"]
+#[doc = "      \"description\": \"Address for RPC server.  None if node doesn't have RPC endpoint enabled.\","]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"sync_info\": {"]
+#[doc = "      \"description\": \"Sync status of the node.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/StatusSyncInfo\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"uptime_sec\": {"]
+#[doc = "      \"description\": \"Uptime of the node.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"int64\""]
+#[doc = "    },"]
+#[doc = "    \"validator_account_id\": {"]
+#[doc = "      \"description\": \"Validator id of the node\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"validator_public_key\": {"]
+#[doc = "      \"description\": \"Public key of the validator.\","]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"validators\": {"]
+#[doc = "      \"description\": \"Current epoch validators.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorInfo\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"description\": \"Binary version.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/Version\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcStatusResponse {
+    #[doc = "Unique chain id."]
+    pub chain_id: ::std::string::String,
+    #[doc = "Information about last blocks, network, epoch and chain & chunk info."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub detailed_debug_status: ::std::option::Option<DetailedDebugStatus>,
+    #[doc = "Genesis hash of the chain."]
+    pub genesis_hash: CryptoHash,
+    #[doc = "Latest protocol version that this client supports."]
+    pub latest_protocol_version: u32,
+    #[doc = "Deprecated; same as `validator_public_key` which you should use instead."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub node_key: ::std::option::Option<PublicKey>,
+    #[doc = "Public key of the node."]
+    pub node_public_key: PublicKey,
+    #[doc = "Currently active protocol version."]
+    pub protocol_version: u32,
+    #[doc = "Address for RPC server.  None if node doesn't have RPC endpoint enabled."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub rpc_addr: ::std::option::Option<::std::string::String>,
+    #[doc = "Sync status of the node."]
+    pub sync_info: StatusSyncInfo,
+    #[doc = "Uptime of the node."]
+    pub uptime_sec: i64,
+    #[doc = "Validator id of the node"]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub validator_account_id: ::std::option::Option<AccountId>,
+    #[doc = "Public key of the validator."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub validator_public_key: ::std::option::Option<PublicKey>,
+    #[doc = "Current epoch validators."]
+    pub validators: ::std::vec::Vec<ValidatorInfo>,
+    #[doc = "Binary version."]
+    pub version: Version,
+}
+impl limits::FieldLimits for RpcStatusResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("latest_protocol_version", "uint32", Some(0), None),
+        limits::FieldLimit::integer("protocol_version", "uint32", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RpcStatusResponse> for RpcStatusResponse {
+    fn from(value: &RpcStatusResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`StatusSyncInfo`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"latest_block_hash\","]
+#[doc = "    \"latest_block_height\","]
+#[doc = "    \"latest_block_time\","]
+#[doc = "    \"latest_state_root\","]
+#[doc = "    \"syncing\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"earliest_block_hash\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"earliest_block_height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"earliest_block_time\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"epoch_id\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/EpochId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"epoch_start_height\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"integer\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"latest_block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"latest_block_height\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"latest_block_time\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"latest_state_root\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"syncing\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StatusSyncInfo {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub earliest_block_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub earliest_block_height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub earliest_block_time: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_id: ::std::option::Option<EpochId>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub epoch_start_height: ::std::option::Option<u64>,
+    pub latest_block_hash: CryptoHash,
+    pub latest_block_height: u64,
+    pub latest_block_time: ::std::string::String,
+    pub latest_state_root: CryptoHash,
+    pub syncing: bool,
+}
+impl limits::FieldLimits for StatusSyncInfo {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("earliest_block_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("epoch_start_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("latest_block_height", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&StatusSyncInfo> for StatusSyncInfo {
+    fn from(value: &StatusSyncInfo) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`Tier1ProxyView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"addr\","]
+#[doc = "    \"peer_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"addr\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"peer_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Tier1ProxyView {
+    pub addr: ::std::string::String,
+    pub peer_id: PublicKey,
+}
+impl ::std::convert::From<&Tier1ProxyView> for Tier1ProxyView {
+    fn from(value: &Tier1ProxyView) -> Self {
+        value.clone()
+    }
+}
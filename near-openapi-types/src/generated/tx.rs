@@ -0,0 +1,2991 @@
+//! Transactions, actions, receipts and their outcomes.
+use crate::*;
+
+#[doc = "`ActionView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"CreateAccount\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeployContract\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeployContract\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"code\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"code\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"format\": \"bytes\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"FunctionCall\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"FunctionCall\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"args\","]
+#[doc = "            \"deposit\","]
+#[doc = "            \"gas\","]
+#[doc = "            \"method_name\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"args\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/FunctionArgs\""]
+#[doc = "            },"]
+#[doc = "            \"deposit\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            },"]
+#[doc = "            \"gas\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "            },"]
+#[doc = "            \"method_name\": {"]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Transfer\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Transfer\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"deposit\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"deposit\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Stake\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Stake\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"public_key\","]
+#[doc = "            \"stake\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            },"]
+#[doc = "            \"stake\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"AddKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"AddKey\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"access_key\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"access_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccessKeyView\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeleteKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeleteKey\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeleteAccount\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeleteAccount\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"beneficiary_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"beneficiary_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Delegate\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Delegate\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"delegate_action\","]
+#[doc = "            \"signature\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"delegate_action\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/DelegateAction\""]
+#[doc = "            },"]
+#[doc = "            \"signature\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeployGlobalContract\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeployGlobalContract\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"code\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"code\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"format\": \"bytes\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeployGlobalContractByAccountId\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeployGlobalContractByAccountId\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"code\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"code\": {"]
+#[doc = "              \"type\": \"string\","]
+#[doc = "              \"format\": \"bytes\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"UseGlobalContract\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"UseGlobalContract\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"code_hash\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"code_hash\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"UseGlobalContractByAccountId\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"UseGlobalContractByAccountId\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"account_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"account_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeterministicStateInit\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeterministicStateInit\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"code\","]
+#[doc = "            \"data\","]
+#[doc = "            \"deposit\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"code\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/GlobalContractIdentifierView\""]
+#[doc = "            },"]
+#[doc = "            \"data\": {"]
+#[doc = "              \"type\": \"object\","]
+#[doc = "              \"additionalProperties\": {"]
+#[doc = "                \"type\": \"string\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"deposit\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"AddGasKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"AddGasKey\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"num_nonces\","]
+#[doc = "            \"permission\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"num_nonces\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint32\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"permission\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccessKeyPermissionView\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeleteGasKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeleteGasKey\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"TransferToGasKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"TransferToGasKey\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"amount\","]
+#[doc = "            \"public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"amount\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            },"]
+#[doc = "            \"public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ActionView {
+    CreateAccount,
+    DeployContract {
+        code: ::std::string::String,
+    },
+    FunctionCall {
+        args: FunctionArgs,
+        deposit: NearToken,
+        gas: NearGas,
+        method_name: ::std::string::String,
+    },
+    Transfer {
+        deposit: NearToken,
+    },
+    Stake {
+        public_key: PublicKey,
+        stake: NearToken,
+    },
+    AddKey {
+        access_key: AccessKeyView,
+        public_key: PublicKey,
+    },
+    DeleteKey {
+        public_key: PublicKey,
+    },
+    DeleteAccount {
+        beneficiary_id: AccountId,
+    },
+    Delegate {
+        delegate_action: DelegateAction,
+        signature: Signature,
+    },
+    DeployGlobalContract {
+        code: ::std::string::String,
+    },
+    DeployGlobalContractByAccountId {
+        code: ::std::string::String,
+    },
+    UseGlobalContract {
+        code_hash: CryptoHash,
+    },
+    UseGlobalContractByAccountId {
+        account_id: AccountId,
+    },
+    DeterministicStateInit {
+        code: GlobalContractIdentifierView,
+        data: OrderedMap<::std::string::String, ::std::string::String>,
+        deposit: NearToken,
+    },
+    AddGasKey {
+        num_nonces: u32,
+        permission: AccessKeyPermissionView,
+        public_key: PublicKey,
+    },
+    DeleteGasKey {
+        public_key: PublicKey,
+    },
+    TransferToGasKey {
+        amount: NearToken,
+        public_key: PublicKey,
+    },
+    #[doc = "Variant missing from this version of the crate, only decoded inside `lenient::decode_leniently`"]
+    #[serde(untagged)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    Unknown(
+        #[serde(deserialize_with = "lenient::deserialize_unknown::<ActionView, _>")]
+        lenient::UnknownValue,
+    ),
+}
+impl lenient::OpenEnum for ActionView {
+    const KNOWN_VARIANTS: &'static [&'static str] = &[
+        "CreateAccount",
+        "DeployContract",
+        "FunctionCall",
+        "Transfer",
+        "Stake",
+        "AddKey",
+        "DeleteKey",
+        "DeleteAccount",
+        "Delegate",
+        "DeployGlobalContract",
+        "DeployGlobalContractByAccountId",
+        "UseGlobalContract",
+        "UseGlobalContractByAccountId",
+        "DeterministicStateInit",
+        "AddGasKey",
+        "DeleteGasKey",
+        "TransferToGasKey",
+    ];
+}
+impl ::std::convert::From<&Self> for ActionView {
+    fn from(value: &ActionView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`AddGasKeyAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"num_nonces\","]
+#[doc = "    \"permission\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"num_nonces\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"permission\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccessKeyPermission\""]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AddGasKeyAction {
+    pub num_nonces: u32,
+    pub permission: AccessKeyPermission,
+    pub public_key: PublicKey,
+}
+impl limits::FieldLimits for AddGasKeyAction {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "num_nonces",
+        "uint32",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&AddGasKeyAction> for AddGasKeyAction {
+    fn from(value: &AddGasKeyAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "An action that adds key with public key associated"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"An action that adds key with public key associated\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"access_key\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"access_key\": {"]
+#[doc = "      \"description\": \"An access key with the permission\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccessKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"description\": \"A public key which will be associated with an access_key\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AddKeyAction {
+    #[doc = "An access key with the permission"]
+    pub access_key: AccessKey,
+    #[doc = "A public key which will be associated with an access_key"]
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&AddKeyAction> for AddKeyAction {
+    fn from(value: &AddKeyAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Shows gas profile. More info [here](https://near.github.io/nearcore/architecture/gas/gas_profile.html?highlight=WASM_HOST_COST#example-transaction-gas-profile)."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Shows gas profile. More info [here](https://near.github.io/nearcore/architecture/gas/gas_profile.html?highlight=WASM_HOST_COST#example-transaction-gas-profile).\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"cost\","]
+#[doc = "    \"cost_category\","]
+#[doc = "    \"gas_used\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"cost\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"cost_category\": {"]
+#[doc = "      \"description\": \"Either ACTION_COST or WASM_HOST_COST.\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"gas_used\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CostGasUsed {
+    pub cost: ::std::string::String,
+    #[doc = "Either ACTION_COST or WASM_HOST_COST."]
+    pub cost_category: ::std::string::String,
+    pub gas_used: ::std::string::String,
+}
+impl ::std::convert::From<&CostGasUsed> for CostGasUsed {
+    fn from(value: &CostGasUsed) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Create account action"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Create account action\","]
+#[doc = "  \"type\": \"object\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct CreateAccountAction(pub ::serde_json::Map<::std::string::String, ::serde_json::Value>);
+impl ::std::ops::Deref for CreateAccountAction {
+    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+        &self.0
+    }
+}
+impl ::std::convert::From<CreateAccountAction>
+    for ::serde_json::Map<::std::string::String, ::serde_json::Value>
+{
+    fn from(value: CreateAccountAction) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&CreateAccountAction> for CreateAccountAction {
+    fn from(value: &CreateAccountAction) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
+    for CreateAccountAction
+{
+    fn from(value: ::serde_json::Map<::std::string::String, ::serde_json::Value>) -> Self {
+        Self(value)
+    }
+}
+#[doc = "`DataReceiverView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"data_id\","]
+#[doc = "    \"receiver_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"data_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"receiver_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DataReceiverView {
+    pub data_id: CryptoHash,
+    pub receiver_id: AccountId,
+}
+impl ::std::convert::From<&DataReceiverView> for DataReceiverView {
+    fn from(value: &DataReceiverView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "This action allows to execute the inner actions behalf of the defined sender."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"This action allows to execute the inner actions behalf of the defined sender.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"actions\","]
+#[doc = "    \"max_block_height\","]
+#[doc = "    \"nonce\","]
+#[doc = "    \"public_key\","]
+#[doc = "    \"receiver_id\","]
+#[doc = "    \"sender_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"actions\": {"]
+#[doc = "      \"description\": \"List of actions to be executed.\\n\\nWith the meta transactions MVP defined in NEP-366, nested\\nDelegateActions are not allowed. A separate type is used to enforce it.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/NonDelegateAction\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"max_block_height\": {"]
+#[doc = "      \"description\": \"The maximal height of the block in the blockchain below which the given DelegateAction is valid.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"nonce\": {"]
+#[doc = "      \"description\": \"Nonce to ensure that the same delegate action is not sent twice by a\\nrelayer and should match for given account's `public_key`.\\nAfter this action is processed it will increment.\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"description\": \"Public key used to sign this delegated action.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"receiver_id\": {"]
+#[doc = "      \"description\": \"Receiver of the delegated actions.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"sender_id\": {"]
+#[doc = "      \"description\": \"Signer of the delegated actions\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DelegateAction {
+    #[doc = "List of actions to be executed.\n\nWith the meta transactions MVP defined in NEP-366, nested\nDelegateActions are not allowed. A separate type is used to enforce it."]
+    pub actions: ::std::vec::Vec<NonDelegateAction>,
+    #[doc = "The maximal height of the block in the blockchain below which the given DelegateAction is valid."]
+    pub max_block_height: u64,
+    #[doc = "Nonce to ensure that the same delegate action is not sent twice by a\nrelayer and should match for given account's `public_key`.\nAfter this action is processed it will increment."]
+    pub nonce: u64,
+    #[doc = "Public key used to sign this delegated action."]
+    pub public_key: PublicKey,
+    #[doc = "Receiver of the delegated actions."]
+    pub receiver_id: AccountId,
+    #[doc = "Signer of the delegated actions"]
+    pub sender_id: AccountId,
+}
+impl limits::FieldLimits for DelegateAction {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("max_block_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("nonce", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&DelegateAction> for DelegateAction {
+    fn from(value: &DelegateAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`DeleteAccountAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"beneficiary_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"beneficiary_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeleteAccountAction {
+    pub beneficiary_id: AccountId,
+}
+impl ::std::convert::From<&DeleteAccountAction> for DeleteAccountAction {
+    fn from(value: &DeleteAccountAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`DeleteGasKeyAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeleteGasKeyAction {
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&DeleteGasKeyAction> for DeleteGasKeyAction {
+    fn from(value: &DeleteGasKeyAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`DeleteKeyAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"description\": \"A public key associated with the access_key to be deleted.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeleteKeyAction {
+    #[doc = "A public key associated with the access_key to be deleted."]
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&DeleteKeyAction> for DeleteKeyAction {
+    fn from(value: &DeleteKeyAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Deploy contract action"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Deploy contract action\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"code\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"code\": {"]
+#[doc = "      \"description\": \"WebAssembly binary\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeployContractAction {
+    #[doc = "WebAssembly binary"]
+    pub code: ::std::string::String,
+}
+impl ::std::convert::From<&DeployContractAction> for DeployContractAction {
+    fn from(value: &DeployContractAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Deploy global contract action"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Deploy global contract action\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"code\","]
+#[doc = "    \"deploy_mode\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"code\": {"]
+#[doc = "      \"description\": \"WebAssembly binary\","]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"deploy_mode\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/GlobalContractDeployMode\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeployGlobalContractAction {
+    #[doc = "WebAssembly binary"]
+    pub code: ::std::string::String,
+    pub deploy_mode: GlobalContractDeployMode,
+}
+impl ::std::convert::From<&DeployGlobalContractAction> for DeployGlobalContractAction {
+    fn from(value: &DeployGlobalContractAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`DeterministicAccountStateInit`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"V1\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"V1\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeterministicAccountStateInitV1\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DeterministicAccountStateInit {
+    V1(DeterministicAccountStateInitV1),
+}
+impl ::std::convert::From<&Self> for DeterministicAccountStateInit {
+    fn from(value: &DeterministicAccountStateInit) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<DeterministicAccountStateInitV1> for DeterministicAccountStateInit {
+    fn from(value: DeterministicAccountStateInitV1) -> Self {
+        Self::V1(value)
+    }
+}
+#[doc = "`DeterministicAccountStateInitV1`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"code\","]
+#[doc = "    \"data\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"code\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/GlobalContractIdentifier\""]
+#[doc = "    },"]
+#[doc = "    \"data\": {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"additionalProperties\": {"]
+#[doc = "        \"type\": \"string\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeterministicAccountStateInitV1 {
+    pub code: GlobalContractIdentifier,
+    pub data: OrderedMap<::std::string::String, ::std::string::String>,
+}
+impl ::std::convert::From<&DeterministicAccountStateInitV1> for DeterministicAccountStateInitV1 {
+    fn from(value: &DeterministicAccountStateInitV1) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`DeterministicStateInitAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"deposit\","]
+#[doc = "    \"state_init\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"deposit\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"state_init\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/DeterministicAccountStateInit\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeterministicStateInitAction {
+    pub deposit: NearToken,
+    pub state_init: DeterministicAccountStateInit,
+}
+impl ::std::convert::From<&DeterministicStateInitAction> for DeterministicStateInitAction {
+    fn from(value: &DeterministicStateInitAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ExecutionMetadataView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"gas_profile\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/CostGasUsed\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"version\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint32\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExecutionMetadataView {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub gas_profile: ::std::option::Option<::std::vec::Vec<CostGasUsed>>,
+    pub version: u32,
+}
+impl limits::FieldLimits for ExecutionMetadataView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "version",
+        "uint32",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&ExecutionMetadataView> for ExecutionMetadataView {
+    fn from(value: &ExecutionMetadataView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ExecutionOutcomeView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"executor_id\","]
+#[doc = "    \"gas_burnt\","]
+#[doc = "    \"logs\","]
+#[doc = "    \"receipt_ids\","]
+#[doc = "    \"status\","]
+#[doc = "    \"tokens_burnt\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"executor_id\": {"]
+#[doc = "      \"description\": \"The id of the account on which the execution happens. For transaction this is signer_id,\\nfor receipt this is receiver_id.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"gas_burnt\": {"]
+#[doc = "      \"description\": \"The amount of the gas burnt by the given transaction or receipt.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"logs\": {"]
+#[doc = "      \"description\": \"Logs from this transaction or receipt.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"string\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"metadata\": {"]
+#[doc = "      \"description\": \"Execution metadata, versioned\","]
+#[doc = "      \"default\": {"]
+#[doc = "        \"version\": 1"]
+#[doc = "      },"]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExecutionMetadataView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"receipt_ids\": {"]
+#[doc = "      \"description\": \"Receipt IDs generated by this transaction or receipt.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"status\": {"]
+#[doc = "      \"description\": \"Execution status. Contains the result in case of successful execution.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExecutionStatusView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"tokens_burnt\": {"]
+#[doc = "      \"description\": \"The amount of tokens burnt corresponding to the burnt gas amount.\\nThis value doesn't always equal to the `gas_burnt` multiplied by the gas price, because\\nthe prepaid gas price might be lower than the actual gas price and it creates a deficit.\\n`tokens_burnt` also contains the penalty subtracted from refunds, while\\n`gas_burnt` only contains the gas that we actually burn for the execution.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExecutionOutcomeView {
+    #[doc = "The id of the account on which the execution happens. For transaction this is signer_id,\nfor receipt this is receiver_id."]
+    pub executor_id: AccountId,
+    #[doc = "The amount of the gas burnt by the given transaction or receipt."]
+    pub gas_burnt: NearGas,
+    #[doc = "Logs from this transaction or receipt."]
+    pub logs: ::std::vec::Vec<::std::string::String>,
+    #[doc = "Execution metadata, versioned"]
+    #[serde(default = "defaults::execution_outcome_view_metadata")]
+    pub metadata: ExecutionMetadataView,
+    #[doc = "Receipt IDs generated by this transaction or receipt."]
+    pub receipt_ids: ::std::vec::Vec<CryptoHash>,
+    #[doc = "Execution status. Contains the result in case of successful execution."]
+    pub status: ExecutionStatusView,
+    #[doc = "The amount of tokens burnt corresponding to the burnt gas amount.\nThis value doesn't always equal to the `gas_burnt` multiplied by the gas price, because\nthe prepaid gas price might be lower than the actual gas price and it creates a deficit.\n`tokens_burnt` also contains the penalty subtracted from refunds, while\n`gas_burnt` only contains the gas that we actually burn for the execution."]
+    pub tokens_burnt: NearToken,
+}
+impl ::std::convert::From<&ExecutionOutcomeView> for ExecutionOutcomeView {
+    fn from(value: &ExecutionOutcomeView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ExecutionOutcomeWithIdView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"block_hash\","]
+#[doc = "    \"id\","]
+#[doc = "    \"outcome\","]
+#[doc = "    \"proof\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"outcome\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ExecutionOutcomeView\""]
+#[doc = "    },"]
+#[doc = "    \"proof\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/MerklePathItem\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExecutionOutcomeWithIdView {
+    pub block_hash: CryptoHash,
+    pub id: CryptoHash,
+    pub outcome: ExecutionOutcomeView,
+    pub proof: ::std::vec::Vec<MerklePathItem>,
+}
+impl ::std::convert::From<&ExecutionOutcomeWithIdView> for ExecutionOutcomeWithIdView {
+    fn from(value: &ExecutionOutcomeWithIdView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ExecutionStatusView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The execution is pending or unknown.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Unknown\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The execution has failed.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Failure\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Failure\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/TxExecutionError\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The final action succeeded and returned some value or an empty vec encoded in base64.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"SuccessValue\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"SuccessValue\": {"]
+#[doc = "          \"type\": \"string\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The final action of the receipt returned a promise or the signed transaction was converted\\nto a receipt. Contains the receipt_id of the generated receipt.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"SuccessReceiptId\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"SuccessReceiptId\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ExecutionStatusView {
+    #[doc = "The execution is pending or unknown."]
+    Unknown,
+    #[doc = "The execution has failed."]
+    Failure(TxExecutionError),
+    #[doc = "The final action succeeded and returned some value or an empty vec encoded in base64."]
+    SuccessValue(::std::string::String),
+    #[doc = "The final action of the receipt returned a promise or the signed transaction was converted\nto a receipt. Contains the receipt_id of the generated receipt."]
+    SuccessReceiptId(CryptoHash),
+}
+impl ::std::convert::From<&Self> for ExecutionStatusView {
+    fn from(value: &ExecutionStatusView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<TxExecutionError> for ExecutionStatusView {
+    fn from(value: TxExecutionError) -> Self {
+        Self::Failure(value)
+    }
+}
+impl ::std::convert::From<CryptoHash> for ExecutionStatusView {
+    fn from(value: CryptoHash) -> Self {
+        Self::SuccessReceiptId(value)
+    }
+}
+#[doc = "Execution outcome of the transaction and all the subsequent receipts.\nCould be not finalized yet"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Execution outcome of the transaction and all the subsequent receipts.\\nCould be not finalized yet\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"receipts_outcome\","]
+#[doc = "    \"status\","]
+#[doc = "    \"transaction\","]
+#[doc = "    \"transaction_outcome\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"receipts_outcome\": {"]
+#[doc = "      \"description\": \"The execution outcome of receipts.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ExecutionOutcomeWithIdView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"status\": {"]
+#[doc = "      \"description\": \"Execution status defined by chain.rs:get_final_transaction_result\\nFinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet\\nFinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution\\nFinalExecutionStatus::Failure - the result of the first leaf receipt_id\\nFinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/FinalExecutionStatus\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transaction\": {"]
+#[doc = "      \"description\": \"Signed Transaction\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/SignedTransactionView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transaction_outcome\": {"]
+#[doc = "      \"description\": \"The execution outcome of the signed transaction.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExecutionOutcomeWithIdView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FinalExecutionOutcomeView {
+    #[doc = "The execution outcome of receipts."]
+    pub receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+    #[doc = "Execution status defined by chain.rs:get_final_transaction_result\nFinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet\nFinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution\nFinalExecutionStatus::Failure - the result of the first leaf receipt_id\nFinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id"]
+    pub status: FinalExecutionStatus,
+    #[doc = "Signed Transaction"]
+    pub transaction: SignedTransactionView,
+    #[doc = "The execution outcome of the signed transaction."]
+    pub transaction_outcome: ExecutionOutcomeWithIdView,
+}
+impl ::std::convert::From<&FinalExecutionOutcomeView> for FinalExecutionOutcomeView {
+    fn from(value: &FinalExecutionOutcomeView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Final execution outcome of the transaction and all of subsequent the receipts. Also includes\nthe generated receipt."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Final execution outcome of the transaction and all of subsequent the receipts. Also includes\\nthe generated receipt.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"receipts\","]
+#[doc = "    \"receipts_outcome\","]
+#[doc = "    \"status\","]
+#[doc = "    \"transaction\","]
+#[doc = "    \"transaction_outcome\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"receipts\": {"]
+#[doc = "      \"description\": \"Receipts generated from the transaction\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ReceiptView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"receipts_outcome\": {"]
+#[doc = "      \"description\": \"The execution outcome of receipts.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ExecutionOutcomeWithIdView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"status\": {"]
+#[doc = "      \"description\": \"Execution status defined by chain.rs:get_final_transaction_result\\nFinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet\\nFinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution\\nFinalExecutionStatus::Failure - the result of the first leaf receipt_id\\nFinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/FinalExecutionStatus\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transaction\": {"]
+#[doc = "      \"description\": \"Signed Transaction\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/SignedTransactionView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"transaction_outcome\": {"]
+#[doc = "      \"description\": \"The execution outcome of the signed transaction.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/ExecutionOutcomeWithIdView\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FinalExecutionOutcomeWithReceiptView {
+    #[doc = "Receipts generated from the transaction"]
+    pub receipts: ::std::vec::Vec<ReceiptView>,
+    #[doc = "The execution outcome of receipts."]
+    pub receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+    #[doc = "Execution status defined by chain.rs:get_final_transaction_result\nFinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet\nFinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution\nFinalExecutionStatus::Failure - the result of the first leaf receipt_id\nFinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id"]
+    pub status: FinalExecutionStatus,
+    #[doc = "Signed Transaction"]
+    pub transaction: SignedTransactionView,
+    #[doc = "The execution outcome of the signed transaction."]
+    pub transaction_outcome: ExecutionOutcomeWithIdView,
+}
+impl ::std::convert::From<&FinalExecutionOutcomeWithReceiptView>
+    for FinalExecutionOutcomeWithReceiptView
+{
+    fn from(value: &FinalExecutionOutcomeWithReceiptView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`FinalExecutionStatus`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The execution has not yet started.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"NotStarted\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The execution has started and still going.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Started\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The execution has failed with the given error.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Failure\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Failure\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/TxExecutionError\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"The execution has succeeded and returned some value or an empty vec encoded in base64.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"SuccessValue\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"SuccessValue\": {"]
+#[doc = "          \"type\": \"string\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum FinalExecutionStatus {
+    #[doc = "The execution has not yet started."]
+    NotStarted,
+    #[doc = "The execution has started and still going."]
+    Started,
+    #[doc = "The execution has failed with the given error."]
+    Failure(TxExecutionError),
+    #[doc = "The execution has succeeded and returned some value or an empty vec encoded in base64."]
+    SuccessValue(::std::string::String),
+}
+impl ::std::convert::From<&Self> for FinalExecutionStatus {
+    fn from(value: &FinalExecutionStatus) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<TxExecutionError> for FinalExecutionStatus {
+    fn from(value: TxExecutionError) -> Self {
+        Self::Failure(value)
+    }
+}
+#[doc = "`FunctionCallAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"args\","]
+#[doc = "    \"deposit\","]
+#[doc = "    \"gas\","]
+#[doc = "    \"method_name\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"args\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    },"]
+#[doc = "    \"deposit\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"gas\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearGas\""]
+#[doc = "    },"]
+#[doc = "    \"method_name\": {"]
+#[doc = "      \"type\": \"string\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FunctionCallAction {
+    pub args: ::std::string::String,
+    pub deposit: NearToken,
+    pub gas: NearGas,
+    pub method_name: ::std::string::String,
+}
+impl ::std::convert::From<&FunctionCallAction> for FunctionCallAction {
+    fn from(value: &FunctionCallAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`GlobalContractDeployMode`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Contract is deployed under its code hash.\\nUsers will be able reference it by that hash.\\nThis effectively makes the contract immutable.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"CodeHash\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Contract is deployed under the owner account id.\\nUsers will be able reference it by that account id.\\nThis allows the owner to update the contract for all its users.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"AccountId\""]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum GlobalContractDeployMode {
+    #[doc = "Contract is deployed under its code hash.\nUsers will be able reference it by that hash.\nThis effectively makes the contract immutable."]
+    CodeHash,
+    #[doc = "Contract is deployed under the owner account id.\nUsers will be able reference it by that account id.\nThis allows the owner to update the contract for all its users."]
+    AccountId,
+}
+impl ::std::convert::From<&Self> for GlobalContractDeployMode {
+    fn from(value: &GlobalContractDeployMode) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for GlobalContractDeployMode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::CodeHash => f.write_str("CodeHash"),
+            Self::AccountId => f.write_str("AccountId"),
+        }
+    }
+}
+impl ::std::str::FromStr for GlobalContractDeployMode {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "CodeHash" => Ok(Self::CodeHash),
+            "AccountId" => Ok(Self::AccountId),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for GlobalContractDeployMode {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for GlobalContractDeployMode {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for GlobalContractDeployMode {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`GlobalContractIdentifierView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum GlobalContractIdentifierView {
+    CryptoHash(CryptoHash),
+    AccountId(AccountId),
+}
+impl ::std::convert::From<&Self> for GlobalContractIdentifierView {
+    fn from(value: &GlobalContractIdentifierView) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for GlobalContractIdentifierView {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::CryptoHash(x) => x.fmt(f),
+            Self::AccountId(x) => x.fmt(f),
+        }
+    }
+}
+impl ::std::convert::From<CryptoHash> for GlobalContractIdentifierView {
+    fn from(value: CryptoHash) -> Self {
+        Self::CryptoHash(value)
+    }
+}
+impl ::std::convert::From<AccountId> for GlobalContractIdentifierView {
+    fn from(value: AccountId) -> Self {
+        Self::AccountId(value)
+    }
+}
+#[doc = "An Action that can be included in a transaction or receipt, excluding delegate actions. This type represents all possible action types except DelegateAction to prevent infinite recursion in meta-transactions."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"An Action that can be included in a transaction or receipt, excluding delegate actions. This type represents all possible action types except DelegateAction to prevent infinite recursion in meta-transactions.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Create an (sub)account using a transaction `receiver_id` as an ID for\\na new account ID must pass validation rules described here\\n<https://nomicon.io/DataStructures/Account>.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"CreateAccount\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"CreateAccount\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CreateAccountAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Sets a Wasm code to a receiver_id\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeployContract\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeployContract\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeployContractAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"FunctionCall\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"FunctionCall\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/FunctionCallAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Transfer\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Transfer\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/TransferAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Stake\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Stake\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/StakeAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"AddKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"AddKey\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AddKeyAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeleteKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeleteKey\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeleteKeyAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeleteAccount\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeleteAccount\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeleteAccountAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeployGlobalContract\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeployGlobalContract\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeployGlobalContractAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"UseGlobalContract\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"UseGlobalContract\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/UseGlobalContractAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeterministicStateInit\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeterministicStateInit\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeterministicStateInitAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"AddGasKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"AddGasKey\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AddGasKeyAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"DeleteGasKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"DeleteGasKey\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/DeleteGasKeyAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"TransferToGasKey\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"TransferToGasKey\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/TransferToGasKeyAction\""]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum NonDelegateAction {
+    #[doc = "Create an (sub)account using a transaction `receiver_id` as an ID for\na new account ID must pass validation rules described here\n<https://nomicon.io/DataStructures/Account>."]
+    CreateAccount(CreateAccountAction),
+    #[doc = "Sets a Wasm code to a receiver_id"]
+    DeployContract(DeployContractAction),
+    FunctionCall(FunctionCallAction),
+    Transfer(TransferAction),
+    Stake(StakeAction),
+    AddKey(AddKeyAction),
+    DeleteKey(DeleteKeyAction),
+    DeleteAccount(DeleteAccountAction),
+    DeployGlobalContract(DeployGlobalContractAction),
+    UseGlobalContract(UseGlobalContractAction),
+    DeterministicStateInit(DeterministicStateInitAction),
+    AddGasKey(AddGasKeyAction),
+    DeleteGasKey(DeleteGasKeyAction),
+    TransferToGasKey(TransferToGasKeyAction),
+}
+impl ::std::convert::From<&Self> for NonDelegateAction {
+    fn from(value: &NonDelegateAction) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<CreateAccountAction> for NonDelegateAction {
+    fn from(value: CreateAccountAction) -> Self {
+        Self::CreateAccount(value)
+    }
+}
+impl ::std::convert::From<DeployContractAction> for NonDelegateAction {
+    fn from(value: DeployContractAction) -> Self {
+        Self::DeployContract(value)
+    }
+}
+impl ::std::convert::From<FunctionCallAction> for NonDelegateAction {
+    fn from(value: FunctionCallAction) -> Self {
+        Self::FunctionCall(value)
+    }
+}
+impl ::std::convert::From<TransferAction> for NonDelegateAction {
+    fn from(value: TransferAction) -> Self {
+        Self::Transfer(value)
+    }
+}
+impl ::std::convert::From<StakeAction> for NonDelegateAction {
+    fn from(value: StakeAction) -> Self {
+        Self::Stake(value)
+    }
+}
+impl ::std::convert::From<AddKeyAction> for NonDelegateAction {
+    fn from(value: AddKeyAction) -> Self {
+        Self::AddKey(value)
+    }
+}
+impl ::std::convert::From<DeleteKeyAction> for NonDelegateAction {
+    fn from(value: DeleteKeyAction) -> Self {
+        Self::DeleteKey(value)
+    }
+}
+impl ::std::convert::From<DeleteAccountAction> for NonDelegateAction {
+    fn from(value: DeleteAccountAction) -> Self {
+        Self::DeleteAccount(value)
+    }
+}
+impl ::std::convert::From<DeployGlobalContractAction> for NonDelegateAction {
+    fn from(value: DeployGlobalContractAction) -> Self {
+        Self::DeployGlobalContract(value)
+    }
+}
+impl ::std::convert::From<UseGlobalContractAction> for NonDelegateAction {
+    fn from(value: UseGlobalContractAction) -> Self {
+        Self::UseGlobalContract(value)
+    }
+}
+impl ::std::convert::From<DeterministicStateInitAction> for NonDelegateAction {
+    fn from(value: DeterministicStateInitAction) -> Self {
+        Self::DeterministicStateInit(value)
+    }
+}
+impl ::std::convert::From<AddGasKeyAction> for NonDelegateAction {
+    fn from(value: AddGasKeyAction) -> Self {
+        Self::AddGasKey(value)
+    }
+}
+impl ::std::convert::From<DeleteGasKeyAction> for NonDelegateAction {
+    fn from(value: DeleteGasKeyAction) -> Self {
+        Self::DeleteGasKey(value)
+    }
+}
+impl ::std::convert::From<TransferToGasKeyAction> for NonDelegateAction {
+    fn from(value: TransferToGasKeyAction) -> Self {
+        Self::TransferToGasKey(value)
+    }
+}
+#[doc = "`ReceiptEnumView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Action\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Action\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"actions\","]
+#[doc = "            \"gas_price\","]
+#[doc = "            \"input_data_ids\","]
+#[doc = "            \"output_data_receivers\","]
+#[doc = "            \"signer_id\","]
+#[doc = "            \"signer_public_key\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"actions\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/ActionView\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"gas_price\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            },"]
+#[doc = "            \"input_data_ids\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"is_promise_yield\": {"]
+#[doc = "              \"default\": false,"]
+#[doc = "              \"type\": \"boolean\""]
+#[doc = "            },"]
+#[doc = "            \"output_data_receivers\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/DataReceiverView\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"refund_to\": {"]
+#[doc = "              \"anyOf\": ["]
+#[doc = "                {"]
+#[doc = "                  \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "                },"]
+#[doc = "                {"]
+#[doc = "                  \"type\": \"null\""]
+#[doc = "                }"]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"signer_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "            },"]
+#[doc = "            \"signer_public_key\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"Data\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"Data\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"data_id\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"data\": {"]
+#[doc = "              \"type\": ["]
+#[doc = "                \"string\","]
+#[doc = "                \"null\""]
+#[doc = "              ]"]
+#[doc = "            },"]
+#[doc = "            \"data_id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "            },"]
+#[doc = "            \"is_promise_resume\": {"]
+#[doc = "              \"default\": false,"]
+#[doc = "              \"type\": \"boolean\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"GlobalContractDistribution\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"GlobalContractDistribution\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"already_delivered_shards\","]
+#[doc = "            \"code\","]
+#[doc = "            \"id\","]
+#[doc = "            \"target_shard\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"already_delivered_shards\": {"]
+#[doc = "              \"type\": \"array\","]
+#[doc = "              \"items\": {"]
+#[doc = "                \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "              }"]
+#[doc = "            },"]
+#[doc = "            \"code\": {"]
+#[doc = "              \"type\": \"string\""]
+#[doc = "            },"]
+#[doc = "            \"id\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/GlobalContractIdentifier\""]
+#[doc = "            },"]
+#[doc = "            \"target_shard\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ReceiptEnumView {
+    Action {
+        actions: ::std::vec::Vec<ActionView>,
+        gas_price: NearToken,
+        input_data_ids: ::std::vec::Vec<CryptoHash>,
+        #[serde(default)]
+        is_promise_yield: bool,
+        output_data_receivers: ::std::vec::Vec<DataReceiverView>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        refund_to: ::std::option::Option<AccountId>,
+        signer_id: AccountId,
+        signer_public_key: PublicKey,
+    },
+    Data {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        data: ::std::option::Option<::std::string::String>,
+        data_id: CryptoHash,
+        #[serde(default)]
+        is_promise_resume: bool,
+    },
+    GlobalContractDistribution {
+        already_delivered_shards: ::std::vec::Vec<ShardId>,
+        code: ::std::string::String,
+        id: GlobalContractIdentifier,
+        target_shard: ShardId,
+    },
+    #[doc = "Variant missing from this version of the crate, only decoded inside `lenient::decode_leniently`"]
+    #[serde(untagged)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    Unknown(
+        #[serde(deserialize_with = "lenient::deserialize_unknown::<ReceiptEnumView, _>")]
+        lenient::UnknownValue,
+    ),
+}
+impl lenient::OpenEnum for ReceiptEnumView {
+    const KNOWN_VARIANTS: &'static [&'static str] =
+        &["Action", "Data", "GlobalContractDistribution"];
+}
+impl ::std::convert::From<&Self> for ReceiptEnumView {
+    fn from(value: &ReceiptEnumView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ReceiptView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"predecessor_id\","]
+#[doc = "    \"receipt\","]
+#[doc = "    \"receipt_id\","]
+#[doc = "    \"receiver_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"predecessor_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"priority\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"receipt\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ReceiptEnumView\""]
+#[doc = "    },"]
+#[doc = "    \"receipt_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"receiver_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ReceiptView {
+    pub predecessor_id: AccountId,
+    #[serde(default)]
+    pub priority: u64,
+    pub receipt: ReceiptEnumView,
+    pub receipt_id: CryptoHash,
+    pub receiver_id: AccountId,
+}
+impl limits::FieldLimits for ReceiptView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "priority",
+        "uint64",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&ReceiptView> for ReceiptView {
+    fn from(value: &ReceiptView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcReceiptRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcReceiptRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"receipt_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"receipt_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcReceiptRequest {
+    pub receipt_id: CryptoHash,
+}
+impl ::std::convert::From<&RpcReceiptRequest> for RpcReceiptRequest {
+    fn from(value: &RpcReceiptRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcReceiptResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"predecessor_id\","]
+#[doc = "    \"receipt\","]
+#[doc = "    \"receipt_id\","]
+#[doc = "    \"receiver_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"predecessor_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"priority\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"receipt\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ReceiptEnumView\""]
+#[doc = "    },"]
+#[doc = "    \"receipt_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"receiver_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcReceiptResponse {
+    pub predecessor_id: AccountId,
+    #[serde(default)]
+    pub priority: u64,
+    pub receipt: ReceiptEnumView,
+    pub receipt_id: CryptoHash,
+    pub receiver_id: AccountId,
+}
+impl limits::FieldLimits for RpcReceiptResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[limits::FieldLimit::integer(
+        "priority",
+        "uint64",
+        Some(0),
+        None,
+    )];
+}
+impl ::std::convert::From<&RpcReceiptResponse> for RpcReceiptResponse {
+    fn from(value: &RpcReceiptResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcSendTransactionRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcSendTransactionRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"signed_tx_base64\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"signed_tx_base64\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/SignedTransaction\""]
+#[doc = "    },"]
+#[doc = "    \"wait_until\": {"]
+#[doc = "      \"default\": \"EXECUTED_OPTIMISTIC\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/TxExecutionStatus\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcSendTransactionRequest {
+    pub signed_tx_base64: SignedTransaction,
+    #[serde(default = "defaults::rpc_send_transaction_request_wait_until")]
+    pub wait_until: TxExecutionStatus,
+}
+impl ::std::convert::From<&RpcSendTransactionRequest> for RpcSendTransactionRequest {
+    fn from(value: &RpcSendTransactionRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcTransactionResponse`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"anyOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"$ref\": \"#/components/schemas/FinalExecutionOutcomeWithReceiptView\""]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"$ref\": \"#/components/schemas/FinalExecutionOutcomeView\""]
+#[doc = "    }"]
+#[doc = "  ],"]
+#[doc = "  \"required\": ["]
+#[doc = "    \"final_execution_status\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"final_execution_status\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/TxExecutionStatus\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum RpcTransactionResponse {
+    Variant0 {
+        final_execution_status: TxExecutionStatus,
+        #[doc = "Receipts generated from the transaction"]
+        receipts: ::std::vec::Vec<ReceiptView>,
+        #[doc = "The execution outcome of receipts."]
+        receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+        #[doc = "Execution status defined by chain.rs:get_final_transaction_result\nFinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet\nFinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution\nFinalExecutionStatus::Failure - the result of the first leaf receipt_id\nFinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id"]
+        status: FinalExecutionStatus,
+        #[doc = "Signed Transaction"]
+        transaction: SignedTransactionView,
+        #[doc = "The execution outcome of the signed transaction."]
+        transaction_outcome: ExecutionOutcomeWithIdView,
+    },
+    Variant1 {
+        final_execution_status: TxExecutionStatus,
+        #[doc = "The execution outcome of receipts."]
+        receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+        #[doc = "Execution status defined by chain.rs:get_final_transaction_result\nFinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet\nFinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution\nFinalExecutionStatus::Failure - the result of the first leaf receipt_id\nFinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id"]
+        status: FinalExecutionStatus,
+        #[doc = "Signed Transaction"]
+        transaction: SignedTransactionView,
+        #[doc = "The execution outcome of the signed transaction."]
+        transaction_outcome: ExecutionOutcomeWithIdView,
+    },
+}
+impl ::std::convert::From<&Self> for RpcTransactionResponse {
+    fn from(value: &RpcTransactionResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcTransactionStatusRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcTransactionStatusRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"anyOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"signed_tx_base64\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"signed_tx_base64\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/SignedTransaction\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"sender_account_id\","]
+#[doc = "        \"tx_hash\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"sender_account_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "        },"]
+#[doc = "        \"tx_hash\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"wait_until\": {"]
+#[doc = "      \"default\": \"EXECUTED_OPTIMISTIC\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/TxExecutionStatus\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum RpcTransactionStatusRequest {
+    Variant0 {
+        signed_tx_base64: SignedTransaction,
+        #[serde(default = "defaults::rpc_transaction_status_request_variant0_wait_until")]
+        wait_until: TxExecutionStatus,
+    },
+    Variant1 {
+        sender_account_id: AccountId,
+        tx_hash: CryptoHash,
+        #[serde(default = "defaults::rpc_transaction_status_request_variant1_wait_until")]
+        wait_until: TxExecutionStatus,
+    },
+}
+impl ::std::convert::From<&Self> for RpcTransactionStatusRequest {
+    fn from(value: &RpcTransactionStatusRequest) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`SignedDelegateAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"delegate_action\","]
+#[doc = "    \"signature\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"delegate_action\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/DelegateAction\""]
+#[doc = "    },"]
+#[doc = "    \"signature\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SignedDelegateAction {
+    pub delegate_action: DelegateAction,
+    pub signature: Signature,
+}
+impl ::std::convert::From<&SignedDelegateAction> for SignedDelegateAction {
+    fn from(value: &SignedDelegateAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`SignedTransaction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"format\": \"byte\""]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct SignedTransaction(pub ::std::string::String);
+impl ::std::ops::Deref for SignedTransaction {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<SignedTransaction> for ::std::string::String {
+    fn from(value: SignedTransaction) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&SignedTransaction> for SignedTransaction {
+    fn from(value: &SignedTransaction) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<::std::string::String> for SignedTransaction {
+    fn from(value: ::std::string::String) -> Self {
+        Self(value)
+    }
+}
+impl ::std::str::FromStr for SignedTransaction {
+    type Err = ::std::convert::Infallible;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::fmt::Display for SignedTransaction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+#[doc = "`SignedTransactionView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"actions\","]
+#[doc = "    \"hash\","]
+#[doc = "    \"nonce\","]
+#[doc = "    \"public_key\","]
+#[doc = "    \"receiver_id\","]
+#[doc = "    \"signature\","]
+#[doc = "    \"signer_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"actions\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ActionView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"hash\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/CryptoHash\""]
+#[doc = "    },"]
+#[doc = "    \"nonce\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"priority_fee\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"receiver_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"signature\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/Signature\""]
+#[doc = "    },"]
+#[doc = "    \"signer_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SignedTransactionView {
+    pub actions: ::std::vec::Vec<ActionView>,
+    pub hash: CryptoHash,
+    pub nonce: u64,
+    #[serde(default)]
+    pub priority_fee: u64,
+    pub public_key: PublicKey,
+    pub receiver_id: AccountId,
+    pub signature: Signature,
+    pub signer_id: AccountId,
+}
+impl limits::FieldLimits for SignedTransactionView {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("nonce", "uint64", Some(0), None),
+        limits::FieldLimit::integer("priority_fee", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&SignedTransactionView> for SignedTransactionView {
+    fn from(value: &SignedTransactionView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "An action which stakes signer_id tokens and setup's validator public key"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"An action which stakes signer_id tokens and setup's validator public key\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"public_key\","]
+#[doc = "    \"stake\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"description\": \"Validator key which will be used to sign transactions on behalf of signer_id\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"stake\": {"]
+#[doc = "      \"description\": \"Amount of tokens to stake.\","]
+#[doc = "      \"allOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StakeAction {
+    #[doc = "Validator key which will be used to sign transactions on behalf of signer_id"]
+    pub public_key: PublicKey,
+    #[doc = "Amount of tokens to stake."]
+    pub stake: NearToken,
+}
+impl ::std::convert::From<&StakeAction> for StakeAction {
+    fn from(value: &StakeAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`TransferAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"deposit\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"deposit\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TransferAction {
+    pub deposit: NearToken,
+}
+impl ::std::convert::From<&TransferAction> for TransferAction {
+    fn from(value: &TransferAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`TransferToGasKeyAction`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"deposit\","]
+#[doc = "    \"public_key\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"deposit\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TransferToGasKeyAction {
+    pub deposit: NearToken,
+    pub public_key: PublicKey,
+}
+impl ::std::convert::From<&TransferToGasKeyAction> for TransferToGasKeyAction {
+    fn from(value: &TransferToGasKeyAction) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`TxExecutionStatus`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Transaction is waiting to be included into the block\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"NONE\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Transaction is included into the block. The block may be not finalized yet\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"INCLUDED\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Transaction is included into the block +\\nAll non-refund transaction receipts finished their execution.\\nThe corresponding blocks for tx and each receipt may be not finalized yet\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"EXECUTED_OPTIMISTIC\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Transaction is included into finalized block\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"INCLUDED_FINAL\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Transaction is included into finalized block +\\nAll non-refund transaction receipts finished their execution.\\nThe corresponding blocks for each receipt may be not finalized yet\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"EXECUTED\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Transaction is included into finalized block +\\nExecution of all transaction receipts is finalized, including refund receipts\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"FINAL\""]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TxExecutionStatus {
+    #[doc = "Transaction is waiting to be included into the block"]
+    #[serde(rename = "NONE")]
+    None,
+    #[doc = "Transaction is included into the block. The block may be not finalized yet"]
+    #[serde(rename = "INCLUDED")]
+    Included,
+    #[doc = "Transaction is included into the block +\nAll non-refund transaction receipts finished their execution.\nThe corresponding blocks for tx and each receipt may be not finalized yet"]
+    #[serde(rename = "EXECUTED_OPTIMISTIC")]
+    ExecutedOptimistic,
+    #[doc = "Transaction is included into finalized block"]
+    #[serde(rename = "INCLUDED_FINAL")]
+    IncludedFinal,
+    #[doc = "Transaction is included into finalized block +\nAll non-refund transaction receipts finished their execution.\nThe corresponding blocks for each receipt may be not finalized yet"]
+    #[serde(rename = "EXECUTED")]
+    Executed,
+    #[doc = "Transaction is included into finalized block +\nExecution of all transaction receipts is finalized, including refund receipts"]
+    #[serde(rename = "FINAL")]
+    Final,
+}
+impl ::std::convert::From<&Self> for TxExecutionStatus {
+    fn from(value: &TxExecutionStatus) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for TxExecutionStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::None => f.write_str("NONE"),
+            Self::Included => f.write_str("INCLUDED"),
+            Self::ExecutedOptimistic => f.write_str("EXECUTED_OPTIMISTIC"),
+            Self::IncludedFinal => f.write_str("INCLUDED_FINAL"),
+            Self::Executed => f.write_str("EXECUTED"),
+            Self::Final => f.write_str("FINAL"),
+        }
+    }
+}
+impl ::std::str::FromStr for TxExecutionStatus {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "NONE" => Ok(Self::None),
+            "INCLUDED" => Ok(Self::Included),
+            "EXECUTED_OPTIMISTIC" => Ok(Self::ExecutedOptimistic),
+            "INCLUDED_FINAL" => Ok(Self::IncludedFinal),
+            "EXECUTED" => Ok(Self::Executed),
+            "FINAL" => Ok(Self::Final),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for TxExecutionStatus {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String> for TxExecutionStatus {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for TxExecutionStatus {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Use global contract action"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Use global contract action\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"contract_identifier\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"contract_identifier\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/GlobalContractIdentifier\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct UseGlobalContractAction {
+    pub contract_identifier: GlobalContractIdentifier,
+}
+impl ::std::convert::From<&UseGlobalContractAction> for UseGlobalContractAction {
+    fn from(value: &UseGlobalContractAction) -> Self {
+        value.clone()
+    }
+}
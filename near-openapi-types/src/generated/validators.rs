@@ -0,0 +1,983 @@
+//! Validators, their stakes and kickouts.
+use crate::*;
+
+#[doc = "Describes information about the current epoch validator"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Describes information about the current epoch validator\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"is_slashed\","]
+#[doc = "    \"num_expected_blocks\","]
+#[doc = "    \"num_produced_blocks\","]
+#[doc = "    \"public_key\","]
+#[doc = "    \"shards\","]
+#[doc = "    \"stake\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"is_slashed\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    },"]
+#[doc = "    \"num_expected_blocks\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_expected_chunks\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_expected_chunks_per_shard\": {"]
+#[doc = "      \"description\": \"Number of chunks this validator was expected to produce in each shard.\\nEach entry in the array corresponds to the shard in the `shards_produced` array.\","]
+#[doc = "      \"default\": [],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_expected_endorsements\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_expected_endorsements_per_shard\": {"]
+#[doc = "      \"description\": \"Number of chunks this validator was expected to validate and endorse in each shard.\\nEach entry in the array corresponds to the shard in the `shards_endorsed` array.\","]
+#[doc = "      \"default\": [],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_produced_blocks\": {"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_produced_chunks\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_produced_chunks_per_shard\": {"]
+#[doc = "      \"default\": [],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"num_produced_endorsements\": {"]
+#[doc = "      \"default\": 0,"]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"num_produced_endorsements_per_shard\": {"]
+#[doc = "      \"default\": [],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"type\": \"integer\","]
+#[doc = "        \"format\": \"uint64\","]
+#[doc = "        \"minimum\": 0.0"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"shards\": {"]
+#[doc = "      \"description\": \"Shards this validator is assigned to as chunk producer in the current epoch.\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"shards_endorsed\": {"]
+#[doc = "      \"description\": \"Shards this validator is assigned to as chunk validator in the current epoch.\","]
+#[doc = "      \"default\": [],"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"stake\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CurrentEpochValidatorInfo {
+    pub account_id: AccountId,
+    pub is_slashed: bool,
+    pub num_expected_blocks: u64,
+    #[serde(default)]
+    pub num_expected_chunks: u64,
+    #[doc = "Number of chunks this validator was expected to produce in each shard.\nEach entry in the array corresponds to the shard in the `shards_produced` array."]
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub num_expected_chunks_per_shard: ::std::vec::Vec<u64>,
+    #[serde(default)]
+    pub num_expected_endorsements: u64,
+    #[doc = "Number of chunks this validator was expected to validate and endorse in each shard.\nEach entry in the array corresponds to the shard in the `shards_endorsed` array."]
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub num_expected_endorsements_per_shard: ::std::vec::Vec<u64>,
+    pub num_produced_blocks: u64,
+    #[serde(default)]
+    pub num_produced_chunks: u64,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub num_produced_chunks_per_shard: ::std::vec::Vec<u64>,
+    #[serde(default)]
+    pub num_produced_endorsements: u64,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub num_produced_endorsements_per_shard: ::std::vec::Vec<u64>,
+    pub public_key: PublicKey,
+    #[doc = "Shards this validator is assigned to as chunk producer in the current epoch."]
+    pub shards: ::std::vec::Vec<ShardId>,
+    #[doc = "Shards this validator is assigned to as chunk validator in the current epoch."]
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub shards_endorsed: ::std::vec::Vec<ShardId>,
+    pub stake: NearToken,
+}
+impl limits::FieldLimits for CurrentEpochValidatorInfo {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("num_expected_blocks", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_expected_chunks", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_expected_endorsements", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_produced_blocks", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_produced_chunks", "uint64", Some(0), None),
+        limits::FieldLimit::integer("num_produced_endorsements", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&CurrentEpochValidatorInfo> for CurrentEpochValidatorInfo {
+    fn from(value: &CurrentEpochValidatorInfo) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Information about a Producer: its account name, peer_id and a list of connected peers that\nthe node can use to send message for this producer."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Information about a Producer: its account name, peer_id and a list of connected peers that\\nthe node can use to send message for this producer.\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"peer_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"next_hops\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"array\","]
+#[doc = "        \"null\""]
+#[doc = "      ],"]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"peer_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct KnownProducerView {
+    pub account_id: AccountId,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub next_hops: ::std::option::Option<::std::vec::Vec<PublicKey>>,
+    pub peer_id: PublicKey,
+}
+impl ::std::convert::From<&KnownProducerView> for KnownProducerView {
+    fn from(value: &KnownProducerView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`NextEpochValidatorInfo`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"public_key\","]
+#[doc = "    \"shards\","]
+#[doc = "    \"stake\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"shards\": {"]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ShardId\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"stake\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NextEpochValidatorInfo {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+    pub shards: ::std::vec::Vec<ShardId>,
+    pub stake: NearToken,
+}
+impl ::std::convert::From<&NextEpochValidatorInfo> for NextEpochValidatorInfo {
+    fn from(value: &NextEpochValidatorInfo) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcKnownProducer`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"peer_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"addr\": {"]
+#[doc = "      \"type\": ["]
+#[doc = "        \"string\","]
+#[doc = "        \"null\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    \"peer_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PeerId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcKnownProducer {
+    pub account_id: AccountId,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub addr: ::std::option::Option<::std::string::String>,
+    pub peer_id: PeerId,
+}
+impl ::std::convert::From<&RpcKnownProducer> for RpcKnownProducer {
+    fn from(value: &RpcKnownProducer) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcValidatorRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcValidatorRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"latest\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"epoch_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"epoch_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/EpochId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"block_id\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"block_id\": {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        }"]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RpcValidatorRequest {
+    #[serde(rename = "latest")]
+    Latest,
+    #[serde(rename = "epoch_id")]
+    EpochId(EpochId),
+    #[serde(rename = "block_id")]
+    BlockId(BlockId),
+}
+impl ::std::convert::From<&Self> for RpcValidatorRequest {
+    fn from(value: &RpcValidatorRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::convert::From<EpochId> for RpcValidatorRequest {
+    fn from(value: EpochId) -> Self {
+        Self::EpochId(value)
+    }
+}
+impl ::std::convert::From<BlockId> for RpcValidatorRequest {
+    fn from(value: BlockId) -> Self {
+        Self::BlockId(value)
+    }
+}
+#[doc = "Information about this epoch validators and next epoch validators"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Information about this epoch validators and next epoch validators\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"current_fishermen\","]
+#[doc = "    \"current_proposals\","]
+#[doc = "    \"current_validators\","]
+#[doc = "    \"epoch_height\","]
+#[doc = "    \"epoch_start_height\","]
+#[doc = "    \"next_fishermen\","]
+#[doc = "    \"next_validators\","]
+#[doc = "    \"prev_epoch_kickout\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"current_fishermen\": {"]
+#[doc = "      \"description\": \"Fishermen for the current epoch\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorStakeView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"current_proposals\": {"]
+#[doc = "      \"description\": \"Proposals in the current epoch\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorStakeView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"current_validators\": {"]
+#[doc = "      \"description\": \"Validators for the current epoch\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/CurrentEpochValidatorInfo\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"epoch_height\": {"]
+#[doc = "      \"description\": \"Epoch height\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"epoch_start_height\": {"]
+#[doc = "      \"description\": \"Epoch start block height\","]
+#[doc = "      \"type\": \"integer\","]
+#[doc = "      \"format\": \"uint64\","]
+#[doc = "      \"minimum\": 0.0"]
+#[doc = "    },"]
+#[doc = "    \"next_fishermen\": {"]
+#[doc = "      \"description\": \"Fishermen for the next epoch\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorStakeView\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"next_validators\": {"]
+#[doc = "      \"description\": \"Validators for the next epoch\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/NextEpochValidatorInfo\""]
+#[doc = "      }"]
+#[doc = "    },"]
+#[doc = "    \"prev_epoch_kickout\": {"]
+#[doc = "      \"description\": \"Kickout in the previous epoch\","]
+#[doc = "      \"type\": \"array\","]
+#[doc = "      \"items\": {"]
+#[doc = "        \"$ref\": \"#/components/schemas/ValidatorKickoutView\""]
+#[doc = "      }"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcValidatorResponse {
+    #[doc = "Fishermen for the current epoch"]
+    pub current_fishermen: ::std::vec::Vec<ValidatorStakeView>,
+    #[doc = "Proposals in the current epoch"]
+    pub current_proposals: ::std::vec::Vec<ValidatorStakeView>,
+    #[doc = "Validators for the current epoch"]
+    pub current_validators: ::std::vec::Vec<CurrentEpochValidatorInfo>,
+    #[doc = "Epoch height"]
+    pub epoch_height: u64,
+    #[doc = "Epoch start block height"]
+    pub epoch_start_height: u64,
+    #[doc = "Fishermen for the next epoch"]
+    pub next_fishermen: ::std::vec::Vec<ValidatorStakeView>,
+    #[doc = "Validators for the next epoch"]
+    pub next_validators: ::std::vec::Vec<NextEpochValidatorInfo>,
+    #[doc = "Kickout in the previous epoch"]
+    pub prev_epoch_kickout: ::std::vec::Vec<ValidatorKickoutView>,
+}
+impl limits::FieldLimits for RpcValidatorResponse {
+    const FIELD_LIMITS: &'static [limits::FieldLimit] = &[
+        limits::FieldLimit::integer("epoch_height", "uint64", Some(0), None),
+        limits::FieldLimit::integer("epoch_start_height", "uint64", Some(0), None),
+    ];
+}
+impl ::std::convert::From<&RpcValidatorResponse> for RpcValidatorResponse {
+    fn from(value: &RpcValidatorResponse) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`RpcValidatorsOrderedRequest`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"title\": \"RpcValidatorsOrderedRequest\","]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"block_id\": {"]
+#[doc = "      \"anyOf\": ["]
+#[doc = "        {"]
+#[doc = "          \"$ref\": \"#/components/schemas/BlockId\""]
+#[doc = "        },"]
+#[doc = "        {"]
+#[doc = "          \"type\": \"null\""]
+#[doc = "        }"]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RpcValidatorsOrderedRequest {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub block_id: ::std::option::Option<BlockId>,
+}
+impl ::std::convert::From<&RpcValidatorsOrderedRequest> for RpcValidatorsOrderedRequest {
+    fn from(value: &RpcValidatorsOrderedRequest) -> Self {
+        value.clone()
+    }
+}
+impl ::std::default::Default for RpcValidatorsOrderedRequest {
+    fn default() -> Self {
+        Self {
+            block_id: Default::default(),
+        }
+    }
+}
+#[doc = "`SlashedValidator`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"is_double_sign\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"is_double_sign\": {"]
+#[doc = "      \"type\": \"boolean\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SlashedValidator {
+    pub account_id: AccountId,
+    pub is_double_sign: bool,
+}
+impl ::std::convert::From<&SlashedValidator> for SlashedValidator {
+    fn from(value: &SlashedValidator) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ValidatorInfo`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ValidatorInfo {
+    pub account_id: AccountId,
+}
+impl ::std::convert::From<&ValidatorInfo> for ValidatorInfo {
+    fn from(value: &ValidatorInfo) -> Self {
+        value.clone()
+    }
+}
+#[doc = "Reasons for removing a validator from the validator set."]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"description\": \"Reasons for removing a validator from the validator set.\","]
+#[doc = "  \"oneOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Deprecated\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"_UnusedSlashed\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Validator didn't produce enough blocks.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"NotEnoughBlocks\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"NotEnoughBlocks\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"expected\","]
+#[doc = "            \"produced\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"expected\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"produced\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Validator didn't produce enough chunks.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"NotEnoughChunks\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"NotEnoughChunks\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"expected\","]
+#[doc = "            \"produced\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"expected\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"produced\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Validator unstaked themselves.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"Unstaked\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Validator stake is now below threshold\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"NotEnoughStake\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"NotEnoughStake\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"stake_u128\","]
+#[doc = "            \"threshold_u128\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"stake_u128\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            },"]
+#[doc = "            \"threshold_u128\": {"]
+#[doc = "              \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Enough stake but is not chosen because of seat limits.\","]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"DidNotGetASeat\""]
+#[doc = "      ]"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Validator didn't produce enough chunk endorsements.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"NotEnoughChunkEndorsements\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"NotEnoughChunkEndorsements\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"expected\","]
+#[doc = "            \"produced\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"expected\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"produced\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint64\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    },"]
+#[doc = "    {"]
+#[doc = "      \"description\": \"Validator's last block proposal was for a protocol version older than\\nthe network's voted protocol version.\","]
+#[doc = "      \"type\": \"object\","]
+#[doc = "      \"required\": ["]
+#[doc = "        \"ProtocolVersionTooOld\""]
+#[doc = "      ],"]
+#[doc = "      \"properties\": {"]
+#[doc = "        \"ProtocolVersionTooOld\": {"]
+#[doc = "          \"type\": \"object\","]
+#[doc = "          \"required\": ["]
+#[doc = "            \"network_version\","]
+#[doc = "            \"version\""]
+#[doc = "          ],"]
+#[doc = "          \"properties\": {"]
+#[doc = "            \"network_version\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint32\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            },"]
+#[doc = "            \"version\": {"]
+#[doc = "              \"type\": \"integer\","]
+#[doc = "              \"format\": \"uint32\","]
+#[doc = "              \"minimum\": 0.0"]
+#[doc = "            }"]
+#[doc = "          }"]
+#[doc = "        }"]
+#[doc = "      },"]
+#[doc = "      \"additionalProperties\": false"]
+#[doc = "    }"]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ValidatorKickoutReason {
+    #[doc = "Deprecated"]
+    #[serde(rename = "_UnusedSlashed")]
+    UnusedSlashed,
+    #[doc = "Validator didn't produce enough blocks."]
+    NotEnoughBlocks { expected: u64, produced: u64 },
+    #[doc = "Validator didn't produce enough chunks."]
+    NotEnoughChunks { expected: u64, produced: u64 },
+    #[doc = "Validator unstaked themselves."]
+    Unstaked,
+    #[doc = "Validator stake is now below threshold"]
+    NotEnoughStake {
+        stake_u128: NearToken,
+        threshold_u128: NearToken,
+    },
+    #[doc = "Enough stake but is not chosen because of seat limits."]
+    DidNotGetASeat,
+    #[doc = "Validator didn't produce enough chunk endorsements."]
+    NotEnoughChunkEndorsements { expected: u64, produced: u64 },
+    #[doc = "Validator's last block proposal was for a protocol version older than\nthe network's voted protocol version."]
+    ProtocolVersionTooOld { network_version: u32, version: u32 },
+}
+impl ::std::convert::From<&Self> for ValidatorKickoutReason {
+    fn from(value: &ValidatorKickoutReason) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ValidatorKickoutView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"reason\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"reason\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ValidatorKickoutReason\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ValidatorKickoutView {
+    pub account_id: AccountId,
+    pub reason: ValidatorKickoutReason,
+}
+impl ::std::convert::From<&ValidatorKickoutView> for ValidatorKickoutView {
+    fn from(value: &ValidatorKickoutView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ValidatorStakeView`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"allOf\": ["]
+#[doc = "    {"]
+#[doc = "      \"$ref\": \"#/components/schemas/ValidatorStakeViewV1\""]
+#[doc = "    }"]
+#[doc = "  ],"]
+#[doc = "  \"required\": ["]
+#[doc = "    \"validator_stake_struct_version\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"validator_stake_struct_version\": {"]
+#[doc = "      \"type\": \"string\","]
+#[doc = "      \"enum\": ["]
+#[doc = "        \"V1\""]
+#[doc = "      ]"]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ValidatorStakeView {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+    pub stake: NearToken,
+    pub validator_stake_struct_version: ValidatorStakeViewValidatorStakeStructVersion,
+}
+impl ::std::convert::From<&ValidatorStakeView> for ValidatorStakeView {
+    fn from(value: &ValidatorStakeView) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ValidatorStakeViewV1`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"object\","]
+#[doc = "  \"required\": ["]
+#[doc = "    \"account_id\","]
+#[doc = "    \"public_key\","]
+#[doc = "    \"stake\""]
+#[doc = "  ],"]
+#[doc = "  \"properties\": {"]
+#[doc = "    \"account_id\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/AccountId\""]
+#[doc = "    },"]
+#[doc = "    \"public_key\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/PublicKey\""]
+#[doc = "    },"]
+#[doc = "    \"stake\": {"]
+#[doc = "      \"$ref\": \"#/components/schemas/NearToken\""]
+#[doc = "    }"]
+#[doc = "  }"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ValidatorStakeViewV1 {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+    pub stake: NearToken,
+}
+impl ::std::convert::From<&ValidatorStakeViewV1> for ValidatorStakeViewV1 {
+    fn from(value: &ValidatorStakeViewV1) -> Self {
+        value.clone()
+    }
+}
+#[doc = "`ValidatorStakeViewValidatorStakeStructVersion`"]
+#[doc = r""]
+#[doc = r" <details><summary>JSON schema</summary>"]
+#[doc = r""]
+#[doc = r" ```json"]
+#[doc = "{"]
+#[doc = "  \"type\": \"string\","]
+#[doc = "  \"enum\": ["]
+#[doc = "    \"V1\""]
+#[doc = "  ]"]
+#[doc = "}"]
+#[doc = r" ```"]
+#[doc = r" </details>"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ValidatorStakeViewValidatorStakeStructVersion {
+    V1,
+}
+impl ::std::convert::From<&Self> for ValidatorStakeViewValidatorStakeStructVersion {
+    fn from(value: &ValidatorStakeViewValidatorStakeStructVersion) -> Self {
+        value.clone()
+    }
+}
+impl ::std::fmt::Display for ValidatorStakeViewValidatorStakeStructVersion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::V1 => f.write_str("V1"),
+        }
+    }
+}
+impl ::std::str::FromStr for ValidatorStakeViewValidatorStakeStructVersion {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "V1" => Ok(Self::V1),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for ValidatorStakeViewValidatorStakeStructVersion {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<&::std::string::String>
+    for ValidatorStakeViewValidatorStakeStructVersion
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: &::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String>
+    for ValidatorStakeViewValidatorStakeStructVersion
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
@@ -0,0 +1,103 @@
+//! Well-known contract accounts of mainnet and testnet, so code can refer to
+//! e.g. `known::mainnet::WRAP_NEAR` instead of `"wrap.near"`.
+//!
+//! ```
+//! # use near_openapi_types::{known::{self, KnownAccounts}, AccountId, ChainId};
+//! let wrap_near: AccountId = known::mainnet::WRAP_NEAR.to_owned();
+//! assert_eq!(wrap_near.as_str(), "wrap.near");
+//!
+//! let accounts = KnownAccounts::for_chain(&ChainId::Testnet).unwrap();
+//! assert_eq!(accounts.wrap_near, known::testnet::WRAP_NEAR);
+//! ```
+use near_account_id::AccountIdRef;
+
+use crate::ChainId;
+
+pub mod mainnet {
+    use near_account_id::AccountIdRef;
+
+    /// Wrapped NEAR, the NEP-141 token of NEAR.
+    pub const WRAP_NEAR: &AccountIdRef = AccountIdRef::new_or_panic("wrap.near");
+    /// USDC issued natively by Circle.
+    pub const USDC: &AccountIdRef = AccountIdRef::new_or_panic(
+        "17208628f84f5d6ad33f0da3bbbeb27ffcb398eac501a31bd6ad2011e36133a1",
+    );
+    /// USDt issued natively by Tether.
+    pub const USDT: &AccountIdRef = AccountIdRef::new_or_panic("usdt.tether-token.near");
+    /// Factory deploying the `*.poolv1.near` staking pools.
+    pub const STAKING_POOL_FACTORY: &AccountIdRef = AccountIdRef::new_or_panic("poolv1.near");
+    /// Linkdrop contract creating `*.near` accounts.
+    pub const LINKDROP: &AccountIdRef = AccountIdRef::new_or_panic("near");
+    /// MPC contract of chain signatures.
+    pub const MPC_SIGNER: &AccountIdRef = AccountIdRef::new_or_panic("v1.signer");
+    /// Social DB contract.
+    pub const SOCIAL_DB: &AccountIdRef = AccountIdRef::new_or_panic("social.near");
+}
+
+pub mod testnet {
+    use near_account_id::AccountIdRef;
+
+    /// Wrapped NEAR, the NEP-141 token of NEAR.
+    pub const WRAP_NEAR: &AccountIdRef = AccountIdRef::new_or_panic("wrap.testnet");
+    /// Test USDC issued by Circle.
+    pub const USDC: &AccountIdRef = AccountIdRef::new_or_panic(
+        "3e2210e1184b45b64c8a434c0a7e7b23cc04ea7eb7a6c3c32520d03d4afcb8af",
+    );
+    /// Test token standing in for USDt, which Tether does not issue on
+    /// testnet.
+    pub const USDT: &AccountIdRef = AccountIdRef::new_or_panic("usdt.fakes.testnet");
+    /// Factory deploying the `*.pool.f863973.m0` staking pools.
+    pub const STAKING_POOL_FACTORY: &AccountIdRef = AccountIdRef::new_or_panic("pool.f863973.m0");
+    /// Linkdrop contract creating `*.testnet` accounts.
+    pub const LINKDROP: &AccountIdRef = AccountIdRef::new_or_panic("testnet");
+    /// MPC contract of chain signatures.
+    pub const MPC_SIGNER: &AccountIdRef = AccountIdRef::new_or_panic("v1.signer-prod.testnet");
+    /// Social DB contract.
+    pub const SOCIAL_DB: &AccountIdRef = AccountIdRef::new_or_panic("v1.social08.testnet");
+}
+
+/// The well-known accounts of a network, for code choosing them by
+/// [`ChainId`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KnownAccounts {
+    pub wrap_near: &'static AccountIdRef,
+    pub usdc: &'static AccountIdRef,
+    pub usdt: &'static AccountIdRef,
+    pub staking_pool_factory: &'static AccountIdRef,
+    pub linkdrop: &'static AccountIdRef,
+    pub mpc_signer: &'static AccountIdRef,
+    pub social_db: &'static AccountIdRef,
+}
+
+/// The accounts of [`mainnet`].
+pub const MAINNET: KnownAccounts = KnownAccounts {
+    wrap_near: mainnet::WRAP_NEAR,
+    usdc: mainnet::USDC,
+    usdt: mainnet::USDT,
+    staking_pool_factory: mainnet::STAKING_POOL_FACTORY,
+    linkdrop: mainnet::LINKDROP,
+    mpc_signer: mainnet::MPC_SIGNER,
+    social_db: mainnet::SOCIAL_DB,
+};
+
+/// The accounts of [`testnet`].
+pub const TESTNET: KnownAccounts = KnownAccounts {
+    wrap_near: testnet::WRAP_NEAR,
+    usdc: testnet::USDC,
+    usdt: testnet::USDT,
+    staking_pool_factory: testnet::STAKING_POOL_FACTORY,
+    linkdrop: testnet::LINKDROP,
+    mpc_signer: testnet::MPC_SIGNER,
+    social_db: testnet::SOCIAL_DB,
+};
+
+impl KnownAccounts {
+    /// The accounts of `chain_id`, if it is mainnet or testnet.
+    pub fn for_chain(chain_id: &ChainId) -> Option<&'static Self> {
+        match chain_id {
+            ChainId::Mainnet => Some(&MAINNET),
+            ChainId::Testnet => Some(&TESTNET),
+            ChainId::Custom(_) => None,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Fields the node still sends but no longer fills.
+//!
+//! Blocks used to carry challenges against invalid chunks, with the
+//! validators they slashed in `BlockHeaderView::challenges_result` and the
+//! root of the challenges in `challenges_root`. Since stateless validation
+//! (protocol version 69) no challenges are made: the node sends an empty list
+//! and a zero root, or may leave them out. `challenges_root` is therefore an
+//! `Option`, and both default when missing:
+//!
+//! ```
+//! # use near_openapi_types::legacy;
+//! let field = legacy::field("BlockHeaderView", "challenges_root").unwrap();
+//! assert_eq!(field.unused_since, 69);
+//! ```
+use crate::{BlockHeaderView, CryptoHash};
+
+/// A field kept for compatibility that carries no information on current
+/// protocol versions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LegacyField {
+    pub type_name: &'static str,
+    pub field: &'static str,
+    /// First protocol version on which the field is always empty.
+    pub unused_since: u32,
+    pub reason: &'static str,
+}
+
+/// All legacy fields of the generated types.
+pub const LEGACY_FIELDS: &[LegacyField] = &[
+    LegacyField {
+        type_name: "BlockHeaderView",
+        field: "challenges_result",
+        unused_since: 69,
+        reason: "challenges were removed with stateless validation",
+    },
+    LegacyField {
+        type_name: "BlockHeaderView",
+        field: "challenges_root",
+        unused_since: 69,
+        reason: "challenges were removed with stateless validation",
+    },
+];
+
+/// The legacy field `field` of `type_name`, if it is one.
+pub fn field(type_name: &str, field: &str) -> Option<&'static LegacyField> {
+    LEGACY_FIELDS
+        .iter()
+        .find(|legacy| legacy.type_name == type_name && legacy.field == field)
+}
+
+impl BlockHeaderView {
+    /// Whether the block carries challenges, which only blocks before
+    /// stateless validation may do.
+    pub fn has_challenges(&self) -> bool {
+        !self.challenges_result.is_empty()
+            || self
+                .challenges_root
+                .as_ref()
+                .is_some_and(|root| *root != CryptoHash::default())
+    }
+}
@@ -0,0 +1,98 @@
+//! Opt-in decoding of enum variants this version of the crate does not know.
+//!
+//! Enums that nearcore regularly extends (e.g. [`ActionView`](crate::ActionView)
+//! or [`ActionErrorKind`](crate::ActionErrorKind)) have an extra
+//! `Unknown(UnknownValue)` variant. By default it never deserializes, so
+//! payloads with new variants fail to decode as before. Inside
+//! [`decode_leniently`] such payloads are kept as raw JSON instead and their
+//! discriminants are reported back to the caller:
+//!
+//! ```
+//! # use near_openapi_types::{ActionView, lenient};
+//! let (action, unknown) = lenient::decode_leniently(|| {
+//!     serde_json::from_str::<ActionView>(r#"{"NewAction": {"amount": "1"}}"#)
+//! });
+//! assert!(matches!(action, Ok(ActionView::Unknown(_))));
+//! assert_eq!(unknown, vec!["NewAction".to_string()]);
+//! ```
+use std::cell::RefCell;
+
+use serde::Deserialize;
+
+thread_local! {
+    static SEEN: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Raw payload of an enum variant that is missing from the generated types.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, serde::Serialize)]
+#[serde(transparent)]
+pub struct UnknownValue(pub serde_json::Value);
+
+impl UnknownValue {
+    /// Name of the variant: the string of a unit variant, the single key of an
+    /// externally tagged variant, or the `type` member of an internally tagged
+    /// one.
+    pub fn discriminant(&self) -> Option<&str> {
+        match &self.0 {
+            serde_json::Value::String(variant) => Some(variant),
+            serde_json::Value::Object(object) if object.len() == 1 => {
+                object.keys().next().map(String::as_str)
+            }
+            serde_json::Value::Object(object) => object.get("type")?.as_str(),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for UnknownValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Self(serde_json::Value::deserialize(deserializer)?);
+        SEEN.with_borrow_mut(|seen| match seen {
+            Some(seen) => {
+                seen.push(value.discriminant().unwrap_or("<unknown>").to_string());
+                Ok(value)
+            }
+            None => Err(serde::de::Error::custom(
+                "unknown enum variant (use `lenient::decode_leniently` to accept it)",
+            )),
+        })
+    }
+}
+
+/// Enum with an `Unknown` variant, listing the discriminants of its other
+/// variants.
+pub trait OpenEnum {
+    const KNOWN_VARIANTS: &'static [&'static str];
+}
+
+/// Deserializes the `Unknown` variant of `E`. Payloads of known variants that
+/// failed to decode as them are rejected instead, so they are not mistaken for
+/// new variants and decode the same after a round trip.
+pub fn deserialize_unknown<'de, E, D>(deserializer: D) -> Result<UnknownValue, D::Error>
+where
+    E: OpenEnum,
+    D: serde::Deserializer<'de>,
+{
+    let value = UnknownValue(serde_json::Value::deserialize(deserializer)?);
+    if let Some(variant) = value.discriminant()
+        && E::KNOWN_VARIANTS.contains(&variant)
+    {
+        return Err(serde::de::Error::custom(format!(
+            "invalid {} variant",
+            variant
+        )));
+    }
+    UnknownValue::deserialize(value.0).map_err(serde::de::Error::custom)
+}
+
+/// Runs `decode` with unknown enum variants accepted, returning its result
+/// together with the discriminants of all unknown variants that were decoded.
+pub fn decode_leniently<T>(decode: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let outer = SEEN.replace(Some(Vec::new()));
+    let result = decode();
+    let seen = SEEN.replace(outer).unwrap_or_default();
+    (result, seen)
+}
@@ -0,0 +1,197 @@
+//! Bounds the schema puts on numeric and array fields, e.g. `uint16` with a
+//! maximum of 65535 for `CongestionInfoView::allowed_shard`, so values
+//! computed in wider types can be checked before they are converted and
+//! sent instead of being rejected by the node.
+//!
+//! Every generated struct with bounded fields implements [`FieldLimits`],
+//! listing them under the JSON names of the fields.
+//!
+//! ```
+//! # use near_openapi_types::limits::FieldLimits;
+//! # use near_openapi_types::CongestionInfoView;
+//! let allowed_shard = CongestionInfoView::field_limit("allowed_shard").unwrap();
+//! assert_eq!(allowed_shard.maximum, Some(65535));
+//! assert!(allowed_shard.check(70_000).is_err());
+//! ```
+use serde_json::Value;
+
+/// Bounds of a field in the schema.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FieldLimit {
+    /// Name of the field in JSON.
+    pub field: &'static str,
+    /// Format of an integer field in the schema, e.g. `uint16`, empty for
+    /// arrays.
+    pub format: &'static str,
+    pub minimum: Option<i128>,
+    pub maximum: Option<i128>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+}
+
+/// A value out of the bounds of its field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LimitError {
+    OutOfRange {
+        field: &'static str,
+        value: i128,
+        minimum: Option<i128>,
+        maximum: Option<i128>,
+    },
+    ItemCount {
+        field: &'static str,
+        len: usize,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+    },
+    /// The value does not have the type of the field, e.g. a string for an
+    /// integer field.
+    InvalidType { field: &'static str },
+}
+
+impl std::fmt::Display for LimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange {
+                field,
+                value,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "{} is {}, out of [{}, {}]",
+                field,
+                value,
+                bound(minimum),
+                bound(maximum)
+            ),
+            Self::ItemCount {
+                field,
+                len,
+                min_items,
+                max_items,
+            } => write!(
+                f,
+                "{} has {} items, out of [{}, {}]",
+                field,
+                len,
+                bound(min_items),
+                bound(max_items)
+            ),
+            Self::InvalidType { field } => write!(f, "{} has an invalid type", field),
+        }
+    }
+}
+
+impl std::error::Error for LimitError {}
+
+fn bound<T: std::fmt::Display>(bound: &Option<T>) -> String {
+    bound
+        .as_ref()
+        .map_or_else(|| "..".to_string(), ToString::to_string)
+}
+
+impl FieldLimit {
+    pub const fn integer(
+        field: &'static str,
+        format: &'static str,
+        minimum: Option<i128>,
+        maximum: Option<i128>,
+    ) -> Self {
+        Self {
+            field,
+            format,
+            minimum,
+            maximum,
+            min_items: None,
+            max_items: None,
+        }
+    }
+
+    pub const fn items(
+        field: &'static str,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+    ) -> Self {
+        Self {
+            field,
+            format: "",
+            minimum: None,
+            maximum: None,
+            min_items,
+            max_items,
+        }
+    }
+
+    /// Checks `value` against the minimum and maximum of the field.
+    pub fn check(&self, value: i128) -> Result<(), LimitError> {
+        if self.minimum.is_some_and(|minimum| value < minimum)
+            || self.maximum.is_some_and(|maximum| value > maximum)
+        {
+            return Err(LimitError::OutOfRange {
+                field: self.field,
+                value,
+                minimum: self.minimum,
+                maximum: self.maximum,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks an array of `len` items against the bounds of the field.
+    pub fn check_len(&self, len: usize) -> Result<(), LimitError> {
+        if self.min_items.is_some_and(|min_items| len < min_items)
+            || self.max_items.is_some_and(|max_items| len > max_items)
+        {
+            return Err(LimitError::ItemCount {
+                field: self.field,
+                len,
+                min_items: self.min_items,
+                max_items: self.max_items,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks the JSON value of the field. `null`, i.e. an unset optional
+    /// field, is always accepted.
+    pub fn check_json(&self, value: &Value) -> Result<(), LimitError> {
+        let invalid_type = LimitError::InvalidType { field: self.field };
+        match value {
+            Value::Null => Ok(()),
+            Value::Array(items) if self.min_items.is_some() || self.max_items.is_some() => {
+                self.check_len(items.len())
+            }
+            Value::Number(number) => {
+                let value = number
+                    .as_i64()
+                    .map(i128::from)
+                    .or_else(|| number.as_u64().map(i128::from))
+                    .ok_or(invalid_type)?;
+                self.check(value)
+            }
+            _ => Err(invalid_type),
+        }
+    }
+}
+
+/// Bounded fields of a generated type.
+pub trait FieldLimits: serde::Serialize {
+    const FIELD_LIMITS: &'static [FieldLimit];
+
+    /// Bounds of the field with the JSON name `field`, if it has any.
+    fn field_limit(field: &str) -> Option<&'static FieldLimit> {
+        Self::FIELD_LIMITS.iter().find(|limit| limit.field == field)
+    }
+
+    /// Checks every bounded field of the value.
+    fn validate(&self) -> Result<(), LimitError> {
+        let value = serde_json::to_value(self).expect("generated types serialize to JSON");
+        for limit in Self::FIELD_LIMITS {
+            if let Some(field) = value.get(limit.field) {
+                limit.check_json(field)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//! Merkle proofs of nearcore: checking that an item, e.g. an execution
+//! outcome, is part of a root such as the `outcome_root` of a block.
+//!
+//! Nodes are combined as the SHA-256 hash of the left hash followed by the
+//! right one. A path lists the siblings from the leaf up to the root, each
+//! with the side it is on. Leaves are the hashes of the borsh encoding of the
+//! items, see [`ExecutionOutcomeWithIdView::leaf_hash`] for outcomes.
+//!
+//! ```
+//! # use near_openapi_types::{merkle, CryptoHash};
+//! let leaves = [CryptoHash([1; 32]), CryptoHash([2; 32]), CryptoHash([3; 32])];
+//! let (root, paths) = merkle::merklize(&leaves);
+//! assert!(merkle::verify_path(&root, &paths[2], &leaves[2]));
+//! ```
+use base64::Engine;
+
+#[cfg(feature = "block")]
+use crate::RpcLightClientExecutionProofResponse;
+use crate::error::ConversionError;
+use crate::tx::sha256;
+use crate::{
+    CryptoHash, Direction, ExecutionOutcomeView, ExecutionOutcomeWithIdView, ExecutionStatusView,
+    MerklePathItem,
+};
+
+/// Parent of the nodes `left` and `right`.
+pub fn combine_hash(left: &CryptoHash, right: &CryptoHash) -> CryptoHash {
+    let mut data = [0; 64];
+    data[..32].copy_from_slice(&left.0);
+    data[32..].copy_from_slice(&right.0);
+    sha256(&data)
+}
+
+/// Root reached from the leaf `item_hash` by following `path`.
+pub fn compute_root(item_hash: &CryptoHash, path: &[MerklePathItem]) -> CryptoHash {
+    path.iter()
+        .fold(*item_hash, |hash, item| item.combine(&hash))
+}
+
+/// Whether `path` leads from the leaf `item_hash` to `root`.
+pub fn verify_path(root: &CryptoHash, path: &[MerklePathItem], item_hash: &CryptoHash) -> bool {
+    compute_root(item_hash, path) == *root
+}
+
+/// Root of `leaves` and the path of each of them, the way nearcore builds
+/// them: a node without a sibling is moved up unchanged, and the root of no
+/// leaves is the default hash.
+pub fn merklize(leaves: &[CryptoHash]) -> (CryptoHash, Vec<Vec<MerklePathItem>>) {
+    let mut paths = vec![Vec::new(); leaves.len()];
+    let mut level = leaves.to_vec();
+    // Leaves under each node of `level`.
+    let mut width = 1;
+    while level.len() > 1 {
+        for (i, hash) in level.iter().enumerate() {
+            let sibling = i ^ 1;
+            let direction = if i % 2 == 0 {
+                Direction::Left
+            } else {
+                Direction::Right
+            };
+            let start = sibling * width;
+            for path in paths.iter_mut().skip(start).take(width) {
+                path.push(MerklePathItem {
+                    direction,
+                    hash: *hash,
+                });
+            }
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => combine_hash(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        width *= 2;
+    }
+    (level.pop().unwrap_or_default(), paths)
+}
+
+impl MerklePathItem {
+    /// Parent of `hash` and this sibling.
+    pub fn combine(&self, hash: &CryptoHash) -> CryptoHash {
+        match self.direction {
+            Direction::Left => combine_hash(&self.hash, hash),
+            Direction::Right => combine_hash(hash, &self.hash),
+        }
+    }
+}
+
+impl ExecutionOutcomeWithIdView {
+    /// Hashes committed to for the outcome: its id, the hash of the outcome
+    /// without logs and metadata, and the hash of each log.
+    pub fn to_hashes(&self) -> Result<Vec<CryptoHash>, ConversionError> {
+        let mut hashes = vec![self.id, sha256(&partial_outcome(&self.outcome)?)];
+        hashes.extend(self.outcome.logs.iter().map(|log| sha256(log.as_bytes())));
+        Ok(hashes)
+    }
+
+    /// Leaf of the outcome in the outcome root of its chunk.
+    pub fn leaf_hash(&self) -> Result<CryptoHash, ConversionError> {
+        let hashes = self.to_hashes()?;
+        Ok(sha256(
+            &borsh::to_vec(&hashes).expect("writing to a Vec cannot fail"),
+        ))
+    }
+
+    /// Outcome root of the chunk the outcome is part of, computed with
+    /// `proof`.
+    pub fn chunk_outcome_root(&self) -> Result<CryptoHash, ConversionError> {
+        Ok(compute_root(&self.leaf_hash()?, &self.proof))
+    }
+}
+
+#[cfg(feature = "block")]
+impl RpcLightClientExecutionProofResponse {
+    /// Outcome root of the block, computed from the outcome and the proofs.
+    pub fn outcome_root(&self) -> Result<CryptoHash, ConversionError> {
+        let chunk_outcome_root = self.outcome_proof.chunk_outcome_root()?;
+        Ok(compute_root(
+            &sha256(&chunk_outcome_root.0),
+            &self.outcome_root_proof,
+        ))
+    }
+
+    /// Whether the outcome is part of the outcome root of `block_header_lite`.
+    pub fn verify_outcome(&self) -> Result<bool, ConversionError> {
+        Ok(self.outcome_root()? == self.block_header_lite.inner_lite.outcome_root)
+    }
+}
+
+/// Borsh encoding of nearcore's `PartialExecutionOutcome`.
+fn partial_outcome(outcome: &ExecutionOutcomeView) -> Result<Vec<u8>, ConversionError> {
+    let mut data = borsh::to_vec(&(
+        &outcome.receipt_ids,
+        outcome.gas_burnt.as_gas(),
+        outcome.tokens_burnt.as_yoctonear(),
+        outcome.executor_id.as_str(),
+    ))
+    .expect("writing to a Vec cannot fail");
+    match &outcome.status {
+        ExecutionStatusView::Unknown => data.push(0),
+        // The error is not committed to.
+        ExecutionStatusView::Failure(_) => data.push(1),
+        ExecutionStatusView::SuccessValue(value) => {
+            let value = base64::engine::general_purpose::STANDARD.decode(value)?;
+            data.push(2);
+            data.extend((value.len() as u32).to_le_bytes());
+            data.extend(value);
+        }
+        ExecutionStatusView::SuccessReceiptId(receipt_id) => {
+            data.push(3);
+            data.extend(receipt_id.0);
+        }
+    }
+    Ok(data)
+}
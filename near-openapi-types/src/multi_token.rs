@@ -0,0 +1,194 @@
+//! NEP-245 multi-token contracts: arguments of their methods and their
+//! events.
+//!
+//! Token amounts are `u128` here and are encoded as the decimal strings the
+//! standard requires.
+//!
+//! ```
+//! # use near_openapi_types::multi_token::MtEvent;
+//! let log = r#"EVENT_JSON:{"standard":"nep245","version":"1.0.0","event":"mt_mint","data":[{"owner_id":"alice.near","token_ids":["sword"],"amounts":["1"]}]}"#;
+//! let Some(MtEvent::MtMint(mints)) = MtEvent::from_log(log) else { panic!() };
+//! assert_eq!(mints[0].amounts, vec![1]);
+//! ```
+use crate::standards::NEP245;
+use crate::{AccountId, NearToken};
+
+/// One yoctoNEAR, the deposit that transfer methods must be called with.
+pub const TRANSFER_DEPOSIT: NearToken = NearToken::from_yoctonear(1);
+
+/// Approval of a transfer by an approved account: the owner and the approval
+/// id.
+pub type Approval = (AccountId, u64);
+
+/// Arguments of `mt_balance_of`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtBalanceOfArgs {
+    pub account_id: AccountId,
+    pub token_id: String,
+}
+
+/// Arguments of `mt_batch_balance_of`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtBatchBalanceOfArgs {
+    pub account_id: AccountId,
+    pub token_ids: Vec<String>,
+}
+
+/// Arguments of `mt_transfer`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtTransferArgs {
+    pub receiver_id: AccountId,
+    pub token_id: String,
+    #[serde(with = "amount")]
+    pub amount: u128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval: Option<Approval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// Arguments of `mt_batch_transfer`. `token_ids`, `amounts` and, if given,
+/// `approvals` have one entry per token.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtBatchTransferArgs {
+    pub receiver_id: AccountId,
+    pub token_ids: Vec<String>,
+    #[serde(with = "amounts")]
+    pub amounts: Vec<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approvals: Option<Vec<Option<Approval>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+impl MtBatchTransferArgs {
+    /// Transfers `amount` of each token in `tokens` to `receiver_id`.
+    pub fn new(receiver_id: AccountId, tokens: impl IntoIterator<Item = (String, u128)>) -> Self {
+        let (token_ids, amounts) = tokens.into_iter().unzip();
+        Self {
+            receiver_id,
+            token_ids,
+            amounts,
+            approvals: None,
+            memo: None,
+        }
+    }
+}
+
+/// Event emitted by a multi-token contract.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum MtEvent {
+    MtMint(Vec<MtMintLog>),
+    MtTransfer(Vec<MtTransferLog>),
+    MtBurn(Vec<MtBurnLog>),
+}
+
+impl MtEvent {
+    /// Parses a NEP-297 log line (`EVENT_JSON:{...}`), returning `None` for
+    /// logs that aren't NEP-245 events.
+    pub fn from_log(log: &str) -> Option<Self> {
+        #[derive(serde::Deserialize)]
+        struct Envelope {
+            standard: String,
+            #[serde(flatten)]
+            event: MtEvent,
+        }
+
+        let envelope: Envelope = serde_json::from_str(log.strip_prefix("EVENT_JSON:")?).ok()?;
+        (envelope.standard == NEP245.name).then_some(envelope.event)
+    }
+
+    /// Formats the event as a NEP-297 log line.
+    pub fn to_log(&self) -> String {
+        #[derive(serde::Serialize)]
+        struct Envelope<'a> {
+            standard: &'a str,
+            version: &'a str,
+            #[serde(flatten)]
+            event: &'a MtEvent,
+        }
+
+        let envelope = Envelope {
+            standard: NEP245.name,
+            version: NEP245.version,
+            event: self,
+        };
+        format!(
+            "EVENT_JSON:{}",
+            serde_json::to_string(&envelope).expect("events serialize to JSON")
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtMintLog {
+    pub owner_id: AccountId,
+    pub token_ids: Vec<String>,
+    #[serde(with = "amounts")]
+    pub amounts: Vec<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtTransferLog {
+    /// Approved account that made the transfer on behalf of the owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<AccountId>,
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub token_ids: Vec<String>,
+    #[serde(with = "amounts")]
+    pub amounts: Vec<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MtBurnLog {
+    pub owner_id: AccountId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<AccountId>,
+    pub token_ids: Vec<String>,
+    #[serde(with = "amounts")]
+    pub amounts: Vec<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// A `u128` encoded as a decimal string.
+mod amount {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(amount: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A list of `u128` encoded as decimal strings.
+mod amounts {
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(amounts: &[u128], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(amounts.len()))?;
+        for amount in amounts {
+            seq.serialize_element(&amount.to_string())?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u128>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|amount| amount.parse().map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
@@ -0,0 +1,34 @@
+//! Maps of the generated types.
+//!
+//! Objects with arbitrary keys in the spec, such as the `data` of
+//! `DeterministicStateInit` actions or the shard maps of `ShardLayoutV2`,
+//! decode into an [`OrderedMap`], which encodes its entries in a
+//! deterministic order instead of the random order of a `HashMap`:
+//!
+//! - by default, a `BTreeMap` sorted by key, so equal values always encode
+//!   to the same JSON;
+//! - with the `preserve-order` feature, an `indexmap::IndexMap` keeping the
+//!   order of the decoded JSON, so re-encoding a response gives back the
+//!   same bytes even where the node does not sort keys as strings, e.g. the
+//!   base64 keys of state it sorts by their bytes.
+//!
+//! ```
+//! # use near_openapi_types::ActionView;
+//! let json = r#"{"DeterministicStateInit":{"code":"token.near","data":{"b2s=":"","YQ==":"Yg=="},"deposit":"0"}}"#;
+//! let action: ActionView = serde_json::from_str(json).unwrap();
+//! let encoded = serde_json::to_string(&action).unwrap();
+//! # #[cfg(not(feature = "preserve-order"))]
+//! assert!(encoded.contains(r#""data":{"YQ==":"Yg==","b2s=":""}"#));
+//! # #[cfg(feature = "preserve-order")]
+//! assert_eq!(encoded, json);
+//! ```
+
+/// Map of generated types, see the [module docs](self). The types holding
+/// one do not implement `Hash`, which `IndexMap` lacks.
+#[cfg(not(feature = "preserve-order"))]
+pub type OrderedMap<K, V> = std::collections::BTreeMap<K, V>;
+
+/// Map of generated types, see the [module docs](self). The types holding
+/// one do not implement `Hash`, which `IndexMap` lacks.
+#[cfg(feature = "preserve-order")]
+pub type OrderedMap<K, V> = indexmap::IndexMap<K, V>;
@@ -0,0 +1,141 @@
+//! Protocol features of nearcore and the protocol version enabling them,
+//! e.g. to gate what a client sends on the version of the node, or to
+//! explain an [`ActionsValidationError::UnsupportedProtocolFeature`].
+//!
+//! The table lists the features changing what transactions and RPC
+//! responses can contain, under the names nearcore uses in its errors.
+//!
+//! ```
+//! # use near_openapi_types::protocol_features::{protocol_features, ProtocolFeature};
+//! for feature in protocol_features(70) {
+//!     println!("{}", feature);
+//! }
+//! let global_contracts = ProtocolFeature::by_name("GlobalContracts").unwrap();
+//! assert!(!global_contracts.is_enabled(70));
+//! ```
+use crate::ActionsValidationError;
+use crate::gas_refund::REFUND_PENALTY_PROTOCOL_VERSION;
+
+/// A protocol feature and the version enabling it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ProtocolFeature {
+    /// Name of the feature in nearcore, e.g. in `UnsupportedProtocolFeature`
+    /// errors.
+    pub name: &'static str,
+    /// First protocol version with the feature.
+    pub protocol_version: u32,
+    /// Number of the NEP specifying the feature.
+    pub nep: Option<u32>,
+    pub description: &'static str,
+}
+
+/// Known protocol features, by protocol version.
+pub const PROTOCOL_FEATURES: &[ProtocolFeature] = &[
+    ProtocolFeature {
+        name: "DelegateAction",
+        protocol_version: 59,
+        nep: Some(366),
+        description: "meta transactions: actions signed by one account and relayed by another",
+    },
+    ProtocolFeature {
+        name: "ZeroBalanceAccount",
+        protocol_version: 59,
+        nep: Some(448),
+        description: "accounts using little storage need no balance to cover it",
+    },
+    ProtocolFeature {
+        name: "YieldExecution",
+        protocol_version: 67,
+        nep: Some(519),
+        description: "contracts can yield a call and resume it with data from a later transaction",
+    },
+    ProtocolFeature {
+        name: "CongestionControl",
+        protocol_version: 68,
+        nep: Some(539),
+        description: "shards reject transactions and receipts while congested",
+    },
+    ProtocolFeature {
+        name: "StatelessValidation",
+        protocol_version: 69,
+        nep: Some(509),
+        description: "chunks are validated from state witnesses instead of tracked shards",
+    },
+    ProtocolFeature {
+        name: "EthImplicitAccounts",
+        protocol_version: 70,
+        nep: Some(518),
+        description: "implicit accounts derived from Ethereum addresses, e.g. `0x…`",
+    },
+    ProtocolFeature {
+        name: "GlobalContracts",
+        protocol_version: 77,
+        nep: Some(591),
+        description: "contract code deployed once and used by many accounts",
+    },
+    ProtocolFeature {
+        name: "ReducedGasRefunds",
+        protocol_version: REFUND_PENALTY_PROTOCOL_VERSION,
+        nep: Some(536),
+        description: "part of the unused prepaid gas is burnt instead of refunded",
+    },
+];
+
+/// Features enabled at `protocol_version`.
+pub fn protocol_features(protocol_version: u32) -> Vec<ProtocolFeature> {
+    PROTOCOL_FEATURES
+        .iter()
+        .filter(|feature| feature.is_enabled(protocol_version))
+        .copied()
+        .collect()
+}
+
+impl ProtocolFeature {
+    /// The feature named `name` in [`PROTOCOL_FEATURES`].
+    pub fn by_name(name: &str) -> Option<&'static Self> {
+        PROTOCOL_FEATURES
+            .iter()
+            .find(|feature| feature.name == name)
+    }
+
+    pub fn is_enabled(&self, protocol_version: u32) -> bool {
+        protocol_version >= self.protocol_version
+    }
+
+    /// Link to the NEP specifying the feature.
+    pub fn docs_url(&self) -> Option<String> {
+        self.nep.map(|nep| {
+            format!(
+                "https://github.com/near/NEPs/blob/master/neps/nep-{:04}.md",
+                nep
+            )
+        })
+    }
+}
+
+impl std::fmt::Display for ProtocolFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (protocol version {}): {}",
+            self.name, self.protocol_version, self.description
+        )?;
+        if let Some(url) = self.docs_url() {
+            write!(f, ", see {}", url)?;
+        }
+        Ok(())
+    }
+}
+
+impl ActionsValidationError {
+    /// The feature an `UnsupportedProtocolFeature` error is about, if it is
+    /// in [`PROTOCOL_FEATURES`].
+    pub fn unsupported_protocol_feature(&self) -> Option<&'static ProtocolFeature> {
+        match self {
+            Self::UnsupportedProtocolFeature {
+                protocol_feature, ..
+            } => ProtocolFeature::by_name(protocol_feature),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! Public keys of accounts and validators.
+//!
+//! Nodes send keys as `ed25519:<base58>` or `secp256k1:<base58>` strings.
+//! [`PublicKey`] holds the raw bytes of the key instead, checked for their
+//! curve and length when the string is parsed, and turns back into the same
+//! string when serialized:
+//!
+//! ```
+//! # use near_openapi_types::{KeyType, PublicKey};
+//! let key: PublicKey = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap();
+//! assert_eq!(key.key_type(), KeyType::Ed25519);
+//! assert_eq!(key.key_data().len(), 32);
+//! assert_eq!(key.to_string(), "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp");
+//! ```
+//!
+//! With the `crypto` feature, [`PublicKey::verify`] checks signatures made
+//! with the key.
+use alloc::format;
+
+#[cfg(feature = "crypto")]
+use crate::Signature;
+use crate::error::ConversionError;
+
+/// Curve of a [`PublicKey`], numbered as in the borsh encoding of keys.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum KeyType {
+    Ed25519 = 0,
+    Secp256k1 = 1,
+}
+
+impl KeyType {
+    /// Prefix of keys of this type, without the `:`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ed25519 => "ed25519",
+            Self::Secp256k1 => "secp256k1",
+        }
+    }
+}
+
+impl core::fmt::Display for KeyType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for KeyType {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ed25519" => Ok(Self::Ed25519),
+            "secp256k1" => Ok(Self::Secp256k1),
+            _ => Err(format!("unknown key type {}", s).into()),
+        }
+    }
+}
+
+/// Public key of an access key or a validator.
+///
+/// Secp256k1 keys are the 64 bytes of the uncompressed point, without the
+/// leading `0x04`.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum PublicKey {
+    Ed25519([u8; 32]),
+    Secp256k1([u8; 64]),
+}
+
+/// Length of the longest string form of a key or a signature: `secp256k1:`
+/// and the base58 encoding of 65 bytes.
+pub(crate) const ENCODED_LEN: usize = 10 + 89;
+
+/// One byte more than the longest signature.
+pub(crate) const DECODED_LEN: usize = 66;
+
+impl PublicKey {
+    /// Key of type `key_type` with the raw bytes `data`.
+    pub fn from_parts(key_type: KeyType, data: &[u8]) -> Result<Self, ConversionError> {
+        let key = match key_type {
+            KeyType::Ed25519 => data.try_into().ok().map(Self::Ed25519),
+            KeyType::Secp256k1 => data.try_into().ok().map(Self::Secp256k1),
+        };
+        key.ok_or_else(|| length_error(key_type))
+    }
+
+    pub const fn key_type(&self) -> KeyType {
+        match self {
+            Self::Ed25519(_) => KeyType::Ed25519,
+            Self::Secp256k1(_) => KeyType::Secp256k1,
+        }
+    }
+
+    /// The raw bytes of the key, 32 for ed25519 and 64 for secp256k1.
+    pub fn key_data(&self) -> &[u8] {
+        match self {
+            Self::Ed25519(data) => data,
+            Self::Secp256k1(data) => data,
+        }
+    }
+
+    /// Whether `signature` is a signature of `message` by this key, as
+    /// nearcore checks it. Secp256k1 keys sign 32-byte hashes, so for them
+    /// `message` has to be one, e.g. the hash of a transaction; signatures
+    /// of another key type never verify.
+    #[cfg(feature = "crypto")]
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        match (self, signature) {
+            (Self::Ed25519(key), Signature::Ed25519(signature)) => {
+                use ed25519_dalek::Verifier;
+
+                let Ok(key) = ed25519_dalek::VerifyingKey::from_bytes(key) else {
+                    return false;
+                };
+                key.verify(message, &ed25519_dalek::Signature::from_bytes(signature))
+                    .is_ok()
+            }
+            (Self::Secp256k1(key), Signature::Secp256k1(data)) => {
+                use k256::ecdsa::{RecoveryId, VerifyingKey};
+
+                let (Ok(signature), Some(recovery_id)) = (
+                    k256::ecdsa::Signature::from_slice(&data[..64]),
+                    RecoveryId::from_byte(data[64]),
+                ) else {
+                    return false;
+                };
+                if message.len() != 32 {
+                    return false;
+                }
+                VerifyingKey::recover_from_prehash(message, &signature, recovery_id).is_ok_and(
+                    |recovered| recovered.to_encoded_point(false).as_bytes()[1..] == key[..],
+                )
+            }
+            _ => false,
+        }
+    }
+
+    fn encode_into<'a>(&self, buf: &'a mut [u8; ENCODED_LEN]) -> &'a str {
+        encode(self.key_type(), self.key_data(), buf)
+    }
+}
+
+/// Writes `key_type:base58` of `data`, a key or a signature, into `buf`
+/// without allocating.
+pub(crate) fn encode<'a>(
+    key_type: KeyType,
+    data: &[u8],
+    buf: &'a mut [u8; ENCODED_LEN],
+) -> &'a str {
+    let prefix = key_type.as_str();
+    buf[..prefix.len()].copy_from_slice(prefix.as_bytes());
+    buf[prefix.len()] = b':';
+    let len = bs58::encode(data)
+        .onto(&mut buf[prefix.len() + 1..])
+        .expect("65 bytes fit in 89 base58 digits");
+    core::str::from_utf8(&buf[..prefix.len() + 1 + len]).expect("base58 is ASCII")
+}
+
+/// Splits `type:base58` into the key type and the decoded bytes; strings
+/// without a type are ed25519, as nearcore reads them. Data longer than any
+/// key or signature decodes to all of `buf`, a length of no key type.
+pub(crate) fn decode<'a>(
+    s: &str,
+    buf: &'a mut [u8; DECODED_LEN],
+) -> Result<(KeyType, &'a [u8]), ConversionError> {
+    let (key_type, data) = match s.split_once(':') {
+        Some((key_type, data)) => (key_type.parse()?, data),
+        None => (KeyType::Ed25519, s),
+    };
+    let len = match bs58::decode(data).onto(&mut buf[..]) {
+        Ok(len) => len,
+        Err(bs58::decode::Error::BufferTooSmall) => buf.len(),
+        Err(err) => return Err(err.into()),
+    };
+    Ok((key_type, &buf[..len]))
+}
+
+fn length_error(key_type: KeyType) -> ConversionError {
+    let len = match key_type {
+        KeyType::Ed25519 => 32,
+        KeyType::Secp256k1 => 64,
+    };
+    format!("{} public key is not {} bytes long", key_type, len).into()
+}
+
+impl core::str::FromStr for PublicKey {
+    type Err = ConversionError;
+
+    /// Parses `type:base58`; keys without a type are ed25519 keys, as
+    /// nearcore reads them.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0; DECODED_LEN];
+        let (key_type, data) = decode(s, &mut buf)?;
+        Self::from_parts(key_type, data)
+    }
+}
+
+impl TryFrom<&str> for PublicKey {
+    type Error = ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<&PublicKey> for PublicKey {
+    fn from(value: &PublicKey) -> Self {
+        *value
+    }
+}
+
+impl From<PublicKey> for alloc::string::String {
+    fn from(key: PublicKey) -> Self {
+        key.encode_into(&mut [0; ENCODED_LEN]).into()
+    }
+}
+
+impl core::fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.encode_into(&mut [0; ENCODED_LEN]), f)
+    }
+}
+
+impl core::fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PublicKey")
+            .field(&self.encode_into(&mut [0; ENCODED_LEN]))
+            .finish()
+    }
+}
+
+impl serde::Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.encode_into(&mut [0; ENCODED_LEN]))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PublicKeyVisitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PublicKey {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "PublicKey".into()
+    }
+
+    /// A `type:base58` string, as in the OpenAPI spec.
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string" })
+    }
+}
+
+struct PublicKeyVisitor;
+
+impl serde::de::Visitor<'_> for PublicKeyVisitor {
+    type Value = PublicKey;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("an `ed25519:...` or `secp256k1:...` public key")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<PublicKey, E> {
+        s.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{BlockId, BlockReference, CryptoHash, RpcQueryRequest, RpcQueryResponse};
+
+impl RpcQueryRequest {
+    /// The same query at `block_id`, whatever block it referenced.
+    pub fn with_block_id(&self, block_id: BlockId) -> Self {
+        self.with_block(BlockReference::BlockId(block_id))
+    }
+
+    /// The same query at `block`, whatever block it referenced.
+    pub fn with_block(&self, block: BlockReference) -> Self {
+        // Every query has a by-finality, a by-sync-checkpoint and a by-block-id
+        // variant with otherwise identical fields.
+        let mut value = serde_json::to_value(self).expect("query is always serializable");
+        let fields = value.as_object_mut().expect("query is a JSON object");
+        fields.remove("block_id");
+        fields.remove("finality");
+        fields.remove("sync_checkpoint");
+        let serde_json::Value::Object(block) =
+            serde_json::to_value(block).expect("block reference is always serializable")
+        else {
+            unreachable!("block reference is a JSON object")
+        };
+        fields.extend(block);
+        serde_json::from_value(value).expect("query at another block has the same fields")
+    }
+}
+
+impl RpcQueryResponse {
+    /// Hash of the block the query was answered at.
+    pub fn block_hash(&self) -> &CryptoHash {
+        match self {
+            Self::Variant0 { block_hash, .. }
+            | Self::Variant1 { block_hash, .. }
+            | Self::Variant2 { block_hash, .. }
+            | Self::Variant3 { block_hash, .. }
+            | Self::Variant4 { block_hash, .. }
+            | Self::Variant5 { block_hash, .. }
+            | Self::Variant6 { block_hash, .. }
+            | Self::Variant7 { block_hash, .. } => block_hash,
+        }
+    }
+
+    /// Height of the block the query was answered at.
+    pub fn block_height(&self) -> u64 {
+        match self {
+            Self::Variant0 { block_height, .. }
+            | Self::Variant1 { block_height, .. }
+            | Self::Variant2 { block_height, .. }
+            | Self::Variant3 { block_height, .. }
+            | Self::Variant4 { block_height, .. }
+            | Self::Variant5 { block_height, .. }
+            | Self::Variant6 { block_height, .. }
+            | Self::Variant7 { block_height, .. } => *block_height,
+        }
+    }
+}
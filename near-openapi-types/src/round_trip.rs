@@ -0,0 +1,181 @@
+//! Checking that decoded JSON encodes back to JSON that decodes to the same
+//! value.
+//!
+//! Untagged unions such as [`RpcQueryResponse`](crate::RpcQueryResponse) are
+//! decoded as their first matching variant, so a variant whose JSON also
+//! matches an earlier one, or a field lost or renamed on the way back, would
+//! decode differently after a round trip. [`check_json`] is the property the
+//! fuzz targets in `fuzz/` check, and can be used the same way in fuzzers of
+//! other types.
+//!
+//! ```
+//! # use near_openapi_types::round_trip::check_json;
+//! # use near_openapi_types::ActionErrorKind;
+//! let json = br#"{"AccountAlreadyExists":{"account_id":"alice.near"}}"#;
+//! assert!(check_json::<ActionErrorKind>(json).unwrap().is_some());
+//! assert!(check_json::<ActionErrorKind>(b"{}").unwrap().is_none());
+//! ```
+//!
+//! [`canonical_json`] encodes a value with sorted keys and no whitespace,
+//! the same for equal values whatever the order of the JSON they were
+//! decoded from, so responses can be hashed or compared byte for byte; the
+//! major views implement it as [`CanonicalJson::canonical_json`].
+//! [`check_canonical_json`] checks that the canonical JSON of decoded JSON
+//! decodes back to the same value and encodes to the same canonical JSON.
+//!
+//! ```
+//! # use near_openapi_types::round_trip::CanonicalJson;
+//! # use near_openapi_types::AccessKeyView;
+//! let key: AccessKeyView =
+//!     serde_json::from_str(r#"{"permission": "FullAccess", "nonce": 7}"#).unwrap();
+//! assert_eq!(key.canonical_json().unwrap(), r#"{"nonce":7,"permission":"FullAccess"}"#);
+//! ```
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// A value that does not survive a round trip, with its JSON encoding.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundTripError {
+    /// The encoding of the value does not decode.
+    Undecodable { json: String, error: String },
+    /// The encoding of the value decodes to a different value, e.g. another
+    /// variant of an untagged union.
+    Changed { json: String },
+}
+
+impl std::fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Undecodable { json, error } => {
+                write!(f, "{} does not decode: {}", json, error)
+            }
+            Self::Changed { json } => write!(f, "{} decodes to a different value", json),
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// Decodes `data` as a `T` and checks that its encoding decodes to the same
+/// value. Returns `None` if `data` is not the JSON of a `T`.
+pub fn check_json<T>(data: &[u8]) -> Result<Option<T>, RoundTripError>
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    let Ok(value) = serde_json::from_slice::<T>(data) else {
+        return Ok(None);
+    };
+    let json = serde_json::to_string(&value).expect("decoded values encode to JSON");
+    match serde_json::from_str::<T>(&json) {
+        Ok(decoded) if decoded == value => Ok(Some(value)),
+        Ok(_) => Err(RoundTripError::Changed { json }),
+        Err(err) => Err(RoundTripError::Undecodable {
+            json,
+            error: err.to_string(),
+        }),
+    }
+}
+
+/// Encodes `value` as canonical JSON: object keys sorted by their bytes, no
+/// whitespace, integers in decimal and floats in their shortest form that
+/// decodes to the same float. Fails like `serde_json::to_value`, e.g. for
+/// maps with keys that are not strings.
+pub fn canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let mut json = String::new();
+    write_canonical(&serde_json::to_value(value)?, &mut json);
+    Ok(json)
+}
+
+fn write_canonical(value: &Value, json: &mut String) {
+    match value {
+        Value::Object(map) => {
+            // Sorted here rather than by the map, which keeps the order of
+            // the JSON when `serde_json/preserve_order` is enabled.
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by_key(|(key, _)| *key);
+            json.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                json.push_str(&Value::from(key.as_str()).to_string());
+                json.push(':');
+                write_canonical(value, json);
+            }
+            json.push('}');
+        }
+        Value::Array(values) => {
+            json.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write_canonical(value, json);
+            }
+            json.push(']');
+        }
+        value => json.push_str(&value.to_string()),
+    }
+}
+
+/// A type of the RPC responses worth hashing, see [`canonical_json`].
+pub trait CanonicalJson: Serialize {
+    /// The canonical JSON of the value, see [`canonical_json`].
+    fn canonical_json(&self) -> Result<String, serde_json::Error> {
+        canonical_json(self)
+    }
+}
+
+macro_rules! canonical_json {
+    ($($ty:ident),+) => {
+        $(impl CanonicalJson for crate::$ty {})+
+    };
+    ($feature:literal: $($ty:ident),+) => {
+        $(#[cfg(feature = $feature)] impl CanonicalJson for crate::$ty {})+
+    };
+}
+
+canonical_json!(
+    AccessKeyView,
+    AccountView,
+    ContractCodeView,
+    RpcQueryResponse
+);
+canonical_json!("block": BlockHeaderView, ChunkHeaderView, RpcBlockResponse, RpcChunkResponse);
+canonical_json!("changes": RpcStateChangesInBlockResponse, StateChangeWithCauseView);
+canonical_json!("config": RpcProtocolConfigResponse);
+canonical_json!(
+    "tx": ExecutionOutcomeWithIdView,
+    ReceiptView,
+    RpcReceiptResponse,
+    RpcTransactionResponse,
+    SignedTransactionView
+);
+canonical_json!("validators": RpcValidatorResponse);
+
+/// Decodes `data` as a `T` and checks that its canonical JSON decodes to
+/// the same value and encodes to the same canonical JSON again, returning
+/// the canonical JSON. Returns `None` if `data` is not the JSON of a `T`.
+pub fn check_canonical_json<T>(data: &[u8]) -> Result<Option<String>, RoundTripError>
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    let Ok(value) = serde_json::from_slice::<T>(data) else {
+        return Ok(None);
+    };
+    let json = canonical_json(&value).expect("decoded values encode to JSON");
+    match serde_json::from_str::<T>(&json) {
+        Ok(decoded)
+            if decoded == value
+                && canonical_json(&decoded).expect("decoded values encode to JSON") == json =>
+        {
+            Ok(Some(json))
+        }
+        Ok(_) => Err(RoundTripError::Changed { json }),
+        Err(err) => Err(RoundTripError::Undecodable {
+            json,
+            error: err.to_string(),
+        }),
+    }
+}
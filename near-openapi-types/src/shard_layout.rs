@@ -0,0 +1,119 @@
+//! Assignment of accounts to shards.
+//!
+//! The `shard_layout` of `EXPERIMENTAL_protocol_config` and of the genesis
+//! config tells which shard holds each account, the same way nearcore does:
+//! version 0 layouts split accounts by the hash of their id, later versions
+//! by ranges between `boundary_accounts`, so indexers can partition their
+//! work by shard:
+//!
+//! ```
+//! # use near_openapi_types::{AccountIdRef, ShardId, ShardLayout, ShardLayoutV1};
+//! let layout = ShardLayout::V1(ShardLayoutV1 {
+//!     boundary_accounts: vec!["aurora".parse().unwrap(), "sweat".parse().unwrap()],
+//!     shards_split_map: None,
+//!     to_parent_shard_map: None,
+//!     version: 1,
+//! });
+//! assert_eq!(layout.shard_count(), 3);
+//! assert_eq!(layout.account_to_shard(AccountIdRef::new_or_panic("alice.near")), ShardId(0));
+//! assert_eq!(layout.account_to_shard(AccountIdRef::new_or_panic("relay.aurora")), ShardId(1));
+//! assert_eq!(layout.account_to_shard(AccountIdRef::new_or_panic("token.sweat")), ShardId(2));
+//! assert!(layout.validate_shard_id(&ShardId(3)).is_err());
+//! ```
+use sha2::{Digest, Sha256};
+
+use crate::{AccountIdRef, ShardId, ShardLayout};
+
+/// A shard id that is not part of a [`ShardLayout`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownShardId {
+    pub shard_id: ShardId,
+    /// The shard ids of the layout.
+    pub shard_ids: Vec<ShardId>,
+}
+
+impl std::fmt::Display for UnknownShardId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shard_ids: Vec<_> = self.shard_ids.iter().map(|id| id.0.to_string()).collect();
+        write!(
+            f,
+            "shard {} is not in the shard layout, which has shards [{}]",
+            self.shard_id.0,
+            shard_ids.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownShardId {}
+
+impl ShardLayout {
+    /// Number of shards of the layout.
+    pub fn shard_count(&self) -> usize {
+        match self {
+            Self::V0(layout) => layout.num_shards as usize,
+            Self::V1(layout) => layout.boundary_accounts.len() + 1,
+            Self::V2(layout) => layout.shard_ids.len(),
+            Self::V3(layout) => layout.shard_ids.len(),
+        }
+    }
+
+    /// Ids of the shards, in the order of the account ranges they hold.
+    /// Shards of version 0 and 1 layouts are numbered from 0, later
+    /// versions keep the ids of shards that were not split.
+    pub fn shard_ids(&self) -> Vec<ShardId> {
+        match self {
+            Self::V0(_) | Self::V1(_) => (0..self.shard_count() as u64).map(ShardId).collect(),
+            Self::V2(layout) => layout.shard_ids.clone(),
+            Self::V3(layout) => layout.shard_ids.clone(),
+        }
+    }
+
+    /// Shard holding the state of `account_id`.
+    pub fn account_to_shard(&self, account_id: &AccountIdRef) -> ShardId {
+        let shard_index = |boundary_accounts: &[crate::AccountId]| {
+            boundary_accounts.partition_point(|boundary| boundary.as_str() <= account_id.as_str())
+        };
+        match self {
+            Self::V0(layout) => {
+                let hash = Sha256::digest(account_id.as_bytes());
+                let prefix = u64::from_le_bytes(hash[..8].try_into().expect("8 bytes"));
+                // A layout without shards is malformed; its accounts go to 0.
+                ShardId(prefix % layout.num_shards.max(1))
+            }
+            Self::V1(layout) => ShardId(shard_index(&layout.boundary_accounts) as u64),
+            Self::V2(layout) => shard_at(&layout.shard_ids, shard_index(&layout.boundary_accounts)),
+            Self::V3(layout) => shard_at(&layout.shard_ids, shard_index(&layout.boundary_accounts)),
+        }
+    }
+
+    /// Position of `shard_id` among the shards of the layout, e.g. to index
+    /// per-shard arrays such as the chunks of a block.
+    pub fn shard_index(&self, shard_id: &ShardId) -> Option<usize> {
+        match self {
+            Self::V0(_) | Self::V1(_) => {
+                Some(shard_id.0 as usize).filter(|&index| index < self.shard_count())
+            }
+            Self::V2(layout) => layout.shard_ids.iter().position(|id| id == shard_id),
+            Self::V3(layout) => layout.shard_ids.iter().position(|id| id == shard_id),
+        }
+    }
+
+    /// Checks that `shard_id` is a shard of the layout, returning its index.
+    pub fn validate_shard_id(&self, shard_id: &ShardId) -> Result<usize, UnknownShardId> {
+        self.shard_index(shard_id).ok_or_else(|| UnknownShardId {
+            shard_id: shard_id.clone(),
+            shard_ids: self.shard_ids(),
+        })
+    }
+}
+
+/// Shard at `index` of `shard_ids`. Layouts list one more shard than they
+/// have boundary accounts; if one lists fewer, the accounts past its last
+/// shard go to that shard.
+fn shard_at(shard_ids: &[ShardId], index: usize) -> ShardId {
+    shard_ids
+        .get(index)
+        .or(shard_ids.last())
+        .cloned()
+        .unwrap_or(ShardId(0))
+}
@@ -0,0 +1,164 @@
+//! Signatures of transactions, delegate actions and blocks.
+//!
+//! Like [`PublicKey`](crate::PublicKey), a [`Signature`] is sent as `ed25519:<base58>` or
+//! `secp256k1:<base58>` and holds the raw bytes, checked for their length
+//! when parsed. With the `crypto` feature, [`Signature::verify`] checks it
+//! against a key:
+//!
+//! ```
+//! # use near_openapi_types::{KeyType, Signature};
+//! let signature: Signature = "ed25519:3jHUhecrkNYoTDrQ8jARWco8vCNCdrwPewZrv1HQeTE5tQj78fjAYkJbgeFo8wcyi6maTFFYRNdHQ8nrmFa1Pjc".parse().unwrap();
+//! assert_eq!(signature.key_type(), KeyType::Ed25519);
+//! assert_eq!(signature.to_bytes().len(), 64);
+//! ```
+use alloc::format;
+
+#[cfg(feature = "crypto")]
+use crate::PublicKey;
+use crate::error::ConversionError;
+use crate::public_key::{DECODED_LEN, ENCODED_LEN, KeyType, decode, encode};
+
+/// Signature made with an ed25519 or a secp256k1 key.
+///
+/// Secp256k1 signatures are `r`, `s` and the recovery id.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Signature {
+    Ed25519([u8; 64]),
+    Secp256k1([u8; 65]),
+}
+
+impl Signature {
+    /// Signature of type `key_type` with the raw bytes `data`.
+    pub fn from_parts(key_type: KeyType, data: &[u8]) -> Result<Self, ConversionError> {
+        let signature = match key_type {
+            KeyType::Ed25519 => data.try_into().ok().map(Self::Ed25519),
+            KeyType::Secp256k1 => data.try_into().ok().map(Self::Secp256k1),
+        };
+        signature.ok_or_else(|| {
+            let len = match key_type {
+                KeyType::Ed25519 => 64,
+                KeyType::Secp256k1 => 65,
+            };
+            format!("{} signature is not {} bytes long", key_type, len).into()
+        })
+    }
+
+    pub const fn key_type(&self) -> KeyType {
+        match self {
+            Self::Ed25519(_) => KeyType::Ed25519,
+            Self::Secp256k1(_) => KeyType::Secp256k1,
+        }
+    }
+
+    /// The raw bytes of the signature, 64 for ed25519 and 65 for secp256k1.
+    pub fn to_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.data().to_vec()
+    }
+
+    /// Whether this is a signature of `message` by `public_key`, see
+    /// [`PublicKey::verify`].
+    #[cfg(feature = "crypto")]
+    pub fn verify(&self, message: &[u8], public_key: &PublicKey) -> bool {
+        public_key.verify(message, self)
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        match self {
+            Self::Ed25519(data) => data,
+            Self::Secp256k1(data) => data,
+        }
+    }
+
+    fn encode_into<'a>(&self, buf: &'a mut [u8; ENCODED_LEN]) -> &'a str {
+        encode(self.key_type(), self.data(), buf)
+    }
+}
+
+impl core::str::FromStr for Signature {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0; DECODED_LEN];
+        let (key_type, data) = decode(s, &mut buf)?;
+        Self::from_parts(key_type, data)
+    }
+}
+
+impl TryFrom<&str> for Signature {
+    type Error = ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<&Signature> for Signature {
+    fn from(value: &Signature) -> Self {
+        *value
+    }
+}
+
+impl From<Signature> for alloc::string::String {
+    fn from(signature: Signature) -> Self {
+        signature.encode_into(&mut [0; ENCODED_LEN]).into()
+    }
+}
+
+impl core::fmt::Display for Signature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.encode_into(&mut [0; ENCODED_LEN]), f)
+    }
+}
+
+impl core::fmt::Debug for Signature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Signature")
+            .field(&self.encode_into(&mut [0; ENCODED_LEN]))
+            .finish()
+    }
+}
+
+impl serde::Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.encode_into(&mut [0; ENCODED_LEN]))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(SignatureVisitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Signature {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "Signature".into()
+    }
+
+    /// A `type:base58` string, as in the OpenAPI spec.
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string" })
+    }
+}
+
+struct SignatureVisitor;
+
+impl serde::de::Visitor<'_> for SignatureVisitor {
+    type Value = Signature;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("an `ed25519:...` or `secp256k1:...` signature")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Signature, E> {
+        s.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,258 @@
+//! Well-known contract standards: their NEP-297 event names and the methods
+//! they define.
+//!
+//! ```
+//! # use near_openapi_types::standards;
+//! let ft = standards::standard("nep141").unwrap();
+//! assert!(ft.has_event("ft_transfer"));
+//! assert_eq!(ft.method("ft_transfer").unwrap().deposit, standards::Deposit::OneYocto);
+//! ```
+
+/// A contract standard.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Standard {
+    /// Name used in the `standard` field of NEP-297 events, e.g. `nep141`.
+    pub name: &'static str,
+    pub title: &'static str,
+    /// Latest version of the event format.
+    pub version: &'static str,
+    /// Values of the `event` field of the standard's events.
+    pub events: &'static [&'static str],
+    pub methods: &'static [Method],
+}
+
+impl Standard {
+    pub fn has_event(&self, event: &str) -> bool {
+        self.events.contains(&event)
+    }
+
+    pub fn method(&self, name: &str) -> Option<&'static Method> {
+        self.methods.iter().find(|method| method.name == name)
+    }
+}
+
+/// A method of a standard.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Method {
+    pub name: &'static str,
+    pub kind: MethodKind,
+    pub deposit: Deposit,
+    /// JSON arguments, in the order the standard lists them.
+    pub args: &'static [Arg],
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MethodKind {
+    /// Read-only, callable with the `call_function` query.
+    View,
+    /// Changes state, called with a `FunctionCall` action.
+    Call,
+}
+
+/// Deposit a call method must be called with.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Deposit {
+    None,
+    /// Exactly one yoctoNEAR, which requires a full access key.
+    OneYocto,
+    /// A deposit covering storage costs, refunded in part if too large.
+    Storage,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Arg {
+    pub name: &'static str,
+    pub optional: bool,
+}
+
+const fn arg(name: &'static str) -> Arg {
+    Arg {
+        name,
+        optional: false,
+    }
+}
+
+const fn optional(name: &'static str) -> Arg {
+    Arg {
+        name,
+        optional: true,
+    }
+}
+
+const fn view(name: &'static str, args: &'static [Arg]) -> Method {
+    Method {
+        name,
+        kind: MethodKind::View,
+        deposit: Deposit::None,
+        args,
+    }
+}
+
+const fn call(name: &'static str, deposit: Deposit, args: &'static [Arg]) -> Method {
+    Method {
+        name,
+        kind: MethodKind::Call,
+        deposit,
+        args,
+    }
+}
+
+/// Fungible tokens.
+pub const NEP141: Standard = Standard {
+    name: "nep141",
+    title: "Fungible Token",
+    version: "1.0.0",
+    events: &["ft_mint", "ft_transfer", "ft_burn"],
+    methods: &[
+        call(
+            "ft_transfer",
+            Deposit::OneYocto,
+            &[arg("receiver_id"), arg("amount"), optional("memo")],
+        ),
+        call(
+            "ft_transfer_call",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("amount"),
+                optional("memo"),
+                arg("msg"),
+            ],
+        ),
+        view("ft_total_supply", &[]),
+        view("ft_balance_of", &[arg("account_id")]),
+    ],
+};
+
+/// Storage management.
+pub const NEP145: Standard = Standard {
+    name: "nep145",
+    title: "Storage Management",
+    version: "1.0.0",
+    events: &[],
+    methods: &[
+        call(
+            "storage_deposit",
+            Deposit::Storage,
+            &[optional("account_id"), optional("registration_only")],
+        ),
+        call("storage_withdraw", Deposit::OneYocto, &[optional("amount")]),
+        call(
+            "storage_unregister",
+            Deposit::OneYocto,
+            &[optional("force")],
+        ),
+        view("storage_balance_bounds", &[]),
+        view("storage_balance_of", &[arg("account_id")]),
+    ],
+};
+
+/// Non-fungible tokens.
+pub const NEP171: Standard = Standard {
+    name: "nep171",
+    title: "Non-Fungible Token",
+    version: "1.2.0",
+    events: &[
+        "nft_mint",
+        "nft_transfer",
+        "nft_burn",
+        "nft_metadata_update",
+        "contract_metadata_update",
+    ],
+    methods: &[
+        call(
+            "nft_transfer",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("token_id"),
+                optional("approval_id"),
+                optional("memo"),
+            ],
+        ),
+        call(
+            "nft_transfer_call",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("token_id"),
+                optional("approval_id"),
+                optional("memo"),
+                arg("msg"),
+            ],
+        ),
+        view("nft_token", &[arg("token_id")]),
+    ],
+};
+
+/// Multi tokens.
+pub const NEP245: Standard = Standard {
+    name: "nep245",
+    title: "Multi Token",
+    version: "1.0.0",
+    events: &["mt_mint", "mt_transfer", "mt_burn"],
+    methods: &[
+        call(
+            "mt_transfer",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("token_id"),
+                arg("amount"),
+                optional("approval"),
+                optional("memo"),
+            ],
+        ),
+        call(
+            "mt_batch_transfer",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("token_ids"),
+                arg("amounts"),
+                optional("approvals"),
+                optional("memo"),
+            ],
+        ),
+        call(
+            "mt_transfer_call",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("token_id"),
+                arg("amount"),
+                optional("approval"),
+                optional("memo"),
+                arg("msg"),
+            ],
+        ),
+        call(
+            "mt_batch_transfer_call",
+            Deposit::OneYocto,
+            &[
+                arg("receiver_id"),
+                arg("token_ids"),
+                arg("amounts"),
+                optional("approvals"),
+                optional("memo"),
+                arg("msg"),
+            ],
+        ),
+        view("mt_token", &[arg("token_ids")]),
+        view("mt_balance_of", &[arg("account_id"), arg("token_id")]),
+        view(
+            "mt_batch_balance_of",
+            &[arg("account_id"), arg("token_ids")],
+        ),
+        view("mt_supply", &[arg("token_id")]),
+        view("mt_batch_supply", &[arg("token_ids")]),
+    ],
+};
+
+/// All standards known to this crate.
+pub const STANDARDS: &[Standard] = &[NEP141, NEP145, NEP171, NEP245];
+
+/// Looks up a standard by its NEP-297 name, e.g. `nep171`.
+pub fn standard(name: &str) -> Option<&'static Standard> {
+    STANDARDS.iter().find(|standard| standard.name == name)
+}
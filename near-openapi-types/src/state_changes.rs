@@ -0,0 +1,215 @@
+use crate::{
+    AccountChangesType, AccountId, AccountWithPublicKey, AllAccessKeyChangesType,
+    AllGasKeyChangesType, BlockId, BlockReference, ContractCodeChangesType, DataChangesType,
+    Finality, RpcStateChangesInBlockByTypeRequest, SingleAccessKeyChangesType,
+    SingleGasKeyChangesType, StoreKey, SyncCheckpoint,
+};
+
+/// Builder for [`RpcStateChangesInBlockByTypeRequest`].
+///
+/// Picks the variant matching the block reference and the requested change
+/// type, so callers don't have to spell out the 21 generated variants:
+///
+/// ```
+/// # use near_openapi_types::{Finality, state_changes::StateChangesRequestBuilder};
+/// let request = StateChangesRequestBuilder::new()
+///     .finality(Finality::Final)
+///     .account_changes(vec!["alice.near".parse().unwrap()]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StateChangesRequestBuilder {
+    block_reference: BlockReference,
+}
+
+impl Default for StateChangesRequestBuilder {
+    fn default() -> Self {
+        Self {
+            block_reference: BlockReference::Finality(Finality::Final),
+        }
+    }
+}
+
+macro_rules! by_reference {
+    (
+        $reference:expr,
+        ($block_id:ident, $finality:ident, $sync_checkpoint:ident),
+        $changes_type:ident::$variant:ident
+        { $($field:ident: $value:expr),* }
+    ) => {
+        match $reference {
+            BlockReference::BlockId(block_id) => RpcStateChangesInBlockByTypeRequest::$block_id {
+                block_id,
+                changes_type: $changes_type::$variant,
+                $($field: $value),*
+            },
+            BlockReference::Finality(finality) => RpcStateChangesInBlockByTypeRequest::$finality {
+                finality,
+                changes_type: $changes_type::$variant,
+                $($field: $value),*
+            },
+            BlockReference::SyncCheckpoint(sync_checkpoint) => {
+                RpcStateChangesInBlockByTypeRequest::$sync_checkpoint {
+                    sync_checkpoint,
+                    changes_type: $changes_type::$variant,
+                    $($field: $value),*
+                }
+            }
+        }
+    };
+}
+
+impl StateChangesRequestBuilder {
+    /// Creates a builder querying changes at `Finality::Final`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queries changes in the block with the given height or hash.
+    pub fn block(mut self, block_id: BlockId) -> Self {
+        self.block_reference = BlockReference::BlockId(block_id);
+        self
+    }
+
+    /// Queries changes in the latest block with the given finality.
+    pub fn finality(mut self, finality: Finality) -> Self {
+        self.block_reference = BlockReference::Finality(finality);
+        self
+    }
+
+    /// Queries changes at the given sync checkpoint.
+    pub fn sync_checkpoint(mut self, sync_checkpoint: SyncCheckpoint) -> Self {
+        self.block_reference = BlockReference::SyncCheckpoint(sync_checkpoint);
+        self
+    }
+
+    /// Queries changes in the referenced block.
+    pub fn block_reference(mut self, block_reference: BlockReference) -> Self {
+        self.block_reference = block_reference;
+        self
+    }
+
+    /// Changes of the accounts themselves (balance, storage, code hash).
+    pub fn account_changes(
+        self,
+        account_ids: Vec<AccountId>,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                AccountChangesByBlockId,
+                AccountChangesByFinality,
+                AccountChangesBySyncCheckpoint
+            ),
+            AccountChangesType::AccountChanges {
+                account_ids: account_ids
+            }
+        )
+    }
+
+    /// Changes of specific access keys.
+    pub fn single_access_key_changes(
+        self,
+        keys: Vec<AccountWithPublicKey>,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                SingleAccessKeyChangesByBlockId,
+                SingleAccessKeyChangesByFinality,
+                SingleAccessKeyChangesBySyncCheckpoint
+            ),
+            SingleAccessKeyChangesType::SingleAccessKeyChanges { keys: keys }
+        )
+    }
+
+    /// Changes of all access keys of the given accounts.
+    pub fn all_access_key_changes(
+        self,
+        account_ids: Vec<AccountId>,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                AllAccessKeyChangesByBlockId,
+                AllAccessKeyChangesByFinality,
+                AllAccessKeyChangesBySyncCheckpoint
+            ),
+            AllAccessKeyChangesType::AllAccessKeyChanges {
+                account_ids: account_ids
+            }
+        )
+    }
+
+    /// Changes of specific gas keys.
+    pub fn single_gas_key_changes(
+        self,
+        keys: Vec<AccountWithPublicKey>,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                SingleGasKeyChangesByBlockId,
+                SingleGasKeyChangesByFinality,
+                SingleGasKeyChangesBySyncCheckpoint
+            ),
+            SingleGasKeyChangesType::SingleGasKeyChanges { keys: keys }
+        )
+    }
+
+    /// Changes of all gas keys of the given accounts.
+    pub fn all_gas_key_changes(
+        self,
+        account_ids: Vec<AccountId>,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                AllGasKeyChangesByBlockId,
+                AllGasKeyChangesByFinality,
+                AllGasKeyChangesBySyncCheckpoint
+            ),
+            AllGasKeyChangesType::AllGasKeyChanges {
+                account_ids: account_ids
+            }
+        )
+    }
+
+    /// Contract code deployments of the given accounts.
+    pub fn contract_code_changes(
+        self,
+        account_ids: Vec<AccountId>,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                ContractCodeChangesByBlockId,
+                ContractCodeChangesByFinality,
+                ContractCodeChangesBySyncCheckpoint
+            ),
+            ContractCodeChangesType::ContractCodeChanges {
+                account_ids: account_ids
+            }
+        )
+    }
+
+    /// Contract storage changes of the given accounts under keys starting with
+    /// `key_prefix` (base64-encoded; an empty prefix matches every key).
+    pub fn data_changes(
+        self,
+        account_ids: Vec<AccountId>,
+        key_prefix: StoreKey,
+    ) -> RpcStateChangesInBlockByTypeRequest {
+        by_reference!(
+            self.block_reference,
+            (
+                DataChangesByBlockId,
+                DataChangesByFinality,
+                DataChangesBySyncCheckpoint
+            ),
+            DataChangesType::DataChanges {
+                account_ids: account_ids,
+                key_prefix_base64: key_prefix
+            }
+        )
+    }
+}
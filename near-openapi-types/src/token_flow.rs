@@ -0,0 +1,112 @@
+//! Classifying the NEAR moved by a receipt, e.g. for explorers and
+//! accounting.
+//!
+//! Refunds are transfers from the `system` account. nearcore signs gas
+//! refunds with the account and key that paid for the gas, and refunds of
+//! deposits of failed receipts with `system` itself, which tells the two
+//! apart. Older protocol versions sent gas refunds like deposit refunds, so
+//! on old blocks they show up as [`TokenFlowKind::DepositRefund`].
+//!
+//! ```
+//! # use near_openapi_types::{token_flow::TokenFlowKind, ReceiptView};
+//! # fn example(receipt: &ReceiptView) {
+//! for flow in receipt.token_flows() {
+//!     if flow.kind != TokenFlowKind::GasRefund {
+//!         println!("{} -> {}: {}", flow.from, flow.to, flow.amount);
+//!     }
+//! }
+//! # }
+//! ```
+use crate::{AccountId, ActionView, NearToken, ReceiptEnumView, ReceiptView};
+
+/// Account sending refunds.
+pub const SYSTEM_ACCOUNT: &str = "system";
+
+/// Methods of staking pool contracts that stake their attached deposit.
+pub const STAKING_POOL_DEPOSIT_METHODS: &[&str] = &["deposit", "deposit_and_stake"];
+
+/// NEAR moved by an action of a receipt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFlow {
+    pub from: AccountId,
+    pub to: AccountId,
+    pub amount: NearToken,
+    pub kind: TokenFlowKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenFlowKind {
+    /// A `Transfer` action of a user or contract, including the balance of a
+    /// deleted account sent to its beneficiary.
+    Transfer,
+    /// Deposit attached to a function call.
+    FunctionCallDeposit { method_name: String },
+    /// Deposit attached to a call of a staking pool that stakes it.
+    StakingPoolDeposit { method_name: String },
+    /// A `Stake` action of a validator. `amount` is the total stake it locks,
+    /// not a change of it, and `from` and `to` are the validator.
+    Stake,
+    /// Prepaid gas that was not burnt, returned by `system`.
+    GasRefund,
+    /// Deposit of a failed receipt, returned by `system`.
+    DepositRefund,
+}
+
+impl ReceiptView {
+    /// NEAR moved by the actions of the receipt, in action order. Transfers
+    /// and calls without a deposit are left out; `Stake` actions are kept
+    /// even with a zero stake, which unstakes everything.
+    pub fn token_flows(&self) -> Vec<TokenFlow> {
+        let ReceiptEnumView::Action {
+            actions, signer_id, ..
+        } = &self.receipt
+        else {
+            return Vec::new();
+        };
+        let is_refund = self.predecessor_id.as_str() == SYSTEM_ACCOUNT;
+        actions
+            .iter()
+            .filter_map(|action| {
+                let (amount, kind) = match action {
+                    ActionView::Transfer { deposit } if is_refund => {
+                        let kind = if signer_id.as_str() == SYSTEM_ACCOUNT {
+                            TokenFlowKind::DepositRefund
+                        } else {
+                            TokenFlowKind::GasRefund
+                        };
+                        (*deposit, kind)
+                    }
+                    ActionView::Transfer { deposit } => (*deposit, TokenFlowKind::Transfer),
+                    ActionView::FunctionCall {
+                        deposit,
+                        method_name,
+                        ..
+                    } => {
+                        let method_name = method_name.clone();
+                        let kind = if STAKING_POOL_DEPOSIT_METHODS.contains(&method_name.as_str()) {
+                            TokenFlowKind::StakingPoolDeposit { method_name }
+                        } else {
+                            TokenFlowKind::FunctionCallDeposit { method_name }
+                        };
+                        (*deposit, kind)
+                    }
+                    ActionView::Stake { stake, .. } => {
+                        return Some(TokenFlow {
+                            from: self.receiver_id.clone(),
+                            to: self.receiver_id.clone(),
+                            amount: *stake,
+                            kind: TokenFlowKind::Stake,
+                        });
+                    }
+                    _ => return None,
+                };
+                (!amount.is_zero()).then(|| TokenFlow {
+                    from: self.predecessor_id.clone(),
+                    to: self.receiver_id.clone(),
+                    amount,
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,698 @@
+//! Building, encoding and signing transactions.
+//!
+//! Transactions are borsh-encoded the way nearcore expects them, hashed with
+//! SHA-256 and signed over that hash. Signing with ed25519 and secp256k1 keys
+//! requires the `signing` feature; signatures made elsewhere can be attached
+//! with [`Transaction::with_signature`].
+//!
+//! ```
+//! # use near_openapi_types::{tx::TransactionBuilder, CryptoHash, NearToken};
+//! let tx = TransactionBuilder::new(
+//!     "alice.near".parse().unwrap(),
+//!     "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap(),
+//!     "bob.near".parse().unwrap(),
+//! )
+//! .transfer(NearToken::from_near(1))
+//! .build(7, CryptoHash::default());
+//! println!("{}", tx.hash().unwrap());
+//! ```
+use std::io::{self, Read, Write};
+
+use base64::Engine;
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    AccessKey, AccessKeyPermission, AccountId, AddKeyAction, CreateAccountAction, CryptoHash,
+    DelegateAction, DeleteAccountAction, DeleteKeyAction, DeployContractAction,
+    DeployGlobalContractAction, FunctionArgs, FunctionCallAction, FunctionCallPermission,
+    GlobalContractDeployMode, GlobalContractIdentifier, NearGas, NearToken, NonDelegateAction,
+    PublicKey, Signature, SignedDelegateAction, StakeAction, TransferAction,
+    UseGlobalContractAction,
+};
+
+/// An action of a [`Transaction`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    NonDelegate(NonDelegateAction),
+    /// Actions of another account, relayed by the signer of the transaction.
+    Delegate(Box<SignedDelegateAction>),
+}
+
+impl From<NonDelegateAction> for Action {
+    fn from(value: NonDelegateAction) -> Self {
+        Self::NonDelegate(value)
+    }
+}
+
+impl From<SignedDelegateAction> for Action {
+    fn from(value: SignedDelegateAction) -> Self {
+        Self::Delegate(Box::new(value))
+    }
+}
+
+/// A transaction to be signed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transaction {
+    pub signer_id: AccountId,
+    pub public_key: PublicKey,
+    /// Must be higher than the nonce of the access key `public_key`.
+    pub nonce: u64,
+    pub receiver_id: AccountId,
+    /// Hash of a recent block; the transaction expires some blocks after it.
+    pub block_hash: CryptoHash,
+    pub actions: Vec<Action>,
+}
+
+impl Transaction {
+    /// Borsh encoding of the transaction.
+    pub fn encode(&self) -> io::Result<Vec<u8>> {
+        borsh::to_vec(self)
+    }
+
+    /// Reads a transaction from its borsh encoding.
+    pub fn decode(data: &[u8]) -> io::Result<Self> {
+        borsh::from_slice(data)
+    }
+
+    /// Hash of the transaction: its id on chain, and what its signer signs.
+    pub fn hash(&self) -> io::Result<CryptoHash> {
+        Ok(sha256(&self.encode()?))
+    }
+
+    /// Attaches a signature of [`hash`](Self::hash) made elsewhere, e.g. by
+    /// a hardware wallet.
+    pub fn with_signature(self, signature: Signature) -> SignedTransaction {
+        SignedTransaction {
+            transaction: self,
+            signature,
+        }
+    }
+
+    /// Signs the transaction with `secret_key`, which must belong to
+    /// `public_key`.
+    #[cfg(feature = "signing")]
+    pub fn sign(self, secret_key: &SecretKey) -> io::Result<SignedTransaction> {
+        let signature = secret_key.sign(&self.hash()?);
+        Ok(self.with_signature(signature))
+    }
+}
+
+/// A transaction together with the signature of its hash.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedTransaction {
+    pub transaction: Transaction,
+    pub signature: Signature,
+}
+
+impl SignedTransaction {
+    /// Borsh encoding of the signed transaction.
+    pub fn encode(&self) -> io::Result<Vec<u8>> {
+        borsh::to_vec(self)
+    }
+
+    /// Reads a signed transaction from its borsh encoding.
+    pub fn decode(data: &[u8]) -> io::Result<Self> {
+        borsh::from_slice(data)
+    }
+
+    pub fn hash(&self) -> io::Result<CryptoHash> {
+        self.transaction.hash()
+    }
+
+    /// Whether the signature is a signature of the hash by the `public_key`
+    /// of the transaction.
+    #[cfg(feature = "crypto")]
+    pub fn verify(&self) -> io::Result<bool> {
+        let hash = self.hash()?;
+        Ok(self.transaction.public_key.verify(&hash.0, &self.signature))
+    }
+
+    /// Encoding accepted by `send_tx` and the `broadcast_tx_*` methods.
+    pub fn to_base64(&self) -> io::Result<crate::SignedTransaction> {
+        Ok(crate::SignedTransaction(
+            base64::engine::general_purpose::STANDARD.encode(self.encode()?),
+        ))
+    }
+
+    /// Id of the receipt the transaction is converted to when its chunk is
+    /// applied in the block at `block_height`.
+    pub fn receipt_id(&self, block_height: u64) -> io::Result<CryptoHash> {
+        Ok(hash_index(&self.hash()?, block_height, 0))
+    }
+}
+
+/// Id of the `index`th receipt created by the receipt `receipt_id` when it is
+/// executed in the block at `block_height`.
+pub fn child_receipt_id(receipt_id: &CryptoHash, block_height: u64, index: u64) -> CryptoHash {
+    hash_index(receipt_id, block_height, index)
+}
+
+/// `create_hash_index` of nearcore, from which receipt ids are derived.
+fn hash_index(base: &CryptoHash, block_height: u64, salt: u64) -> CryptoHash {
+    let mut data = base.0.to_vec();
+    data.extend(block_height.to_le_bytes());
+    data.extend(salt.to_le_bytes());
+    sha256(&data)
+}
+
+/// Prefix of the borsh encoding of a delegate action when it is signed,
+/// which sets it apart from transactions (NEP-461).
+const DELEGATE_ACTION_PREFIX: u32 = (1 << 30) + 366;
+
+impl DelegateAction {
+    /// Hash of the delegate action, which its sender signs.
+    pub fn hash(&self) -> io::Result<CryptoHash> {
+        let mut data = DELEGATE_ACTION_PREFIX.to_le_bytes().to_vec();
+        self.serialize(&mut data)?;
+        Ok(sha256(&data))
+    }
+}
+
+impl SignedDelegateAction {
+    /// Whether the signature is a signature of the hash of the delegate
+    /// action by its `public_key`, as the relayer's node checks it.
+    #[cfg(feature = "crypto")]
+    pub fn verify(&self) -> io::Result<bool> {
+        let hash = self.delegate_action.hash()?;
+        Ok(self
+            .delegate_action
+            .public_key
+            .verify(&hash.0, &self.signature))
+    }
+}
+
+/// Builder of a [`Transaction`], which gets its nonce and block hash last as
+/// they are usually fetched right before sending it.
+#[derive(Clone, Debug)]
+pub struct TransactionBuilder {
+    signer_id: AccountId,
+    public_key: PublicKey,
+    receiver_id: AccountId,
+    actions: Vec<Action>,
+}
+
+impl TransactionBuilder {
+    pub fn new(signer_id: AccountId, public_key: PublicKey, receiver_id: AccountId) -> Self {
+        Self {
+            signer_id,
+            public_key,
+            receiver_id,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn action(mut self, action: impl Into<Action>) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    pub fn create_account(self) -> Self {
+        self.action(NonDelegateAction::CreateAccount(CreateAccountAction(
+            Default::default(),
+        )))
+    }
+
+    pub fn deploy_contract(self, code: &[u8]) -> Self {
+        self.action(NonDelegateAction::DeployContract(DeployContractAction {
+            code: base64::engine::general_purpose::STANDARD.encode(code),
+        }))
+    }
+
+    pub fn function_call(
+        self,
+        method_name: impl Into<String>,
+        args: FunctionArgs,
+        gas: NearGas,
+        deposit: NearToken,
+    ) -> Self {
+        self.action(NonDelegateAction::FunctionCall(FunctionCallAction {
+            args: args.0,
+            deposit,
+            gas,
+            method_name: method_name.into(),
+        }))
+    }
+
+    pub fn transfer(self, deposit: NearToken) -> Self {
+        self.action(NonDelegateAction::Transfer(TransferAction { deposit }))
+    }
+
+    pub fn add_key(self, public_key: PublicKey, permission: AccessKeyPermission) -> Self {
+        self.action(NonDelegateAction::AddKey(AddKeyAction {
+            access_key: AccessKey {
+                nonce: 0,
+                permission,
+            },
+            public_key,
+        }))
+    }
+
+    pub fn delete_key(self, public_key: PublicKey) -> Self {
+        self.action(NonDelegateAction::DeleteKey(DeleteKeyAction { public_key }))
+    }
+
+    pub fn delete_account(self, beneficiary_id: AccountId) -> Self {
+        self.action(NonDelegateAction::DeleteAccount(DeleteAccountAction {
+            beneficiary_id,
+        }))
+    }
+
+    /// Builds the transaction with the next `nonce` of the access key and the
+    /// hash of a recent block.
+    pub fn build(self, nonce: u64, block_hash: CryptoHash) -> Transaction {
+        Transaction {
+            signer_id: self.signer_id,
+            public_key: self.public_key,
+            nonce,
+            receiver_id: self.receiver_id,
+            block_hash,
+            actions: self.actions,
+        }
+    }
+}
+
+/// Secret key to sign transactions with, parsed from its `ed25519:...` or
+/// `secp256k1:...` string form.
+#[cfg(feature = "signing")]
+#[derive(Clone)]
+pub enum SecretKey {
+    Ed25519(ed25519_dalek::SigningKey),
+    Secp256k1(k256::ecdsa::SigningKey),
+}
+
+#[cfg(feature = "signing")]
+impl SecretKey {
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Ed25519(key) => PublicKey::Ed25519(key.verifying_key().to_bytes()),
+            // Uncompressed point without the leading 0x04.
+            Self::Secp256k1(key) => PublicKey::Secp256k1(
+                key.verifying_key().to_encoded_point(false).as_bytes()[1..]
+                    .try_into()
+                    .expect("uncompressed point is 65 bytes long"),
+            ),
+        }
+    }
+
+    /// Signs `hash`, e.g. the hash of a transaction.
+    pub fn sign(&self, hash: &CryptoHash) -> Signature {
+        match self {
+            Self::Ed25519(key) => {
+                use ed25519_dalek::Signer;
+                Signature::Ed25519(key.sign(&hash.0).to_bytes())
+            }
+            Self::Secp256k1(key) => {
+                let (signature, recovery_id) = key
+                    .sign_prehash_recoverable(&hash.0)
+                    .expect("32-byte prehash is always signable");
+                let mut data = [0; 65];
+                data[..64].copy_from_slice(&signature.to_bytes());
+                data[64] = recovery_id.to_byte();
+                Signature::Secp256k1(data)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+impl core::str::FromStr for SecretKey {
+    type Err = crate::error::ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key_type, data) = s.split_once(':').ok_or("secret key has no key type")?;
+        let data = bs58::decode(data).into_vec()?;
+        match key_type {
+            // Either the 32-byte seed or the seed followed by the public key.
+            "ed25519" => {
+                let seed = data
+                    .get(..32)
+                    .filter(|_| data.len() == 32 || data.len() == 64)
+                    .ok_or("ed25519 secret key is not 32 or 64 bytes long")?;
+                Ok(Self::Ed25519(ed25519_dalek::SigningKey::from_bytes(
+                    seed.try_into().unwrap(),
+                )))
+            }
+            "secp256k1" => k256::ecdsa::SigningKey::from_slice(&data)
+                .map(Self::Secp256k1)
+                .map_err(|_| "invalid secp256k1 secret key".into()),
+            _ => Err(format!("unknown key type {}", key_type).into()),
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+impl core::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SecretKey")
+            .field(&self.public_key())
+            .finish()
+    }
+}
+
+pub(crate) fn sha256(data: &[u8]) -> CryptoHash {
+    CryptoHash(Sha256::digest(data).into())
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn decode_base64(data: &str) -> io::Result<Vec<u8>> {
+    base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map_err(|err| invalid_data(format!("invalid base64: {}", err)))
+}
+
+fn read_account_id<R: Read>(reader: &mut R) -> io::Result<AccountId> {
+    String::deserialize_reader(reader)?
+        .parse()
+        .map_err(|err| invalid_data(format!("invalid account id: {}", err)))
+}
+
+fn read_base64<R: Read>(reader: &mut R) -> io::Result<String> {
+    Ok(base64::engine::general_purpose::STANDARD.encode(Vec::<u8>::deserialize_reader(reader)?))
+}
+
+impl BorshSerialize for PublicKey {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.key_type() as u8).serialize(writer)?;
+        writer.write_all(self.key_data())
+    }
+}
+
+impl BorshSerialize for Signature {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.key_type() as u8).serialize(writer)?;
+        writer.write_all(self.data())
+    }
+}
+
+impl BorshSerialize for CryptoHash {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl BorshSerialize for Transaction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        // `TransactionV0`, which nearcore encodes without a version tag.
+        self.signer_id.as_str().serialize(writer)?;
+        self.public_key.serialize(writer)?;
+        self.nonce.serialize(writer)?;
+        self.receiver_id.as_str().serialize(writer)?;
+        self.block_hash.serialize(writer)?;
+        self.actions.serialize(writer)
+    }
+}
+
+impl BorshSerialize for SignedTransaction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.transaction.serialize(writer)?;
+        self.signature.serialize(writer)
+    }
+}
+
+impl BorshSerialize for Action {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Self::NonDelegate(action) => action.serialize(writer),
+            Self::Delegate(action) => {
+                8u8.serialize(writer)?;
+                action.serialize(writer)
+            }
+        }
+    }
+}
+
+impl BorshSerialize for NonDelegateAction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Self::CreateAccount(_) => 0u8.serialize(writer),
+            Self::DeployContract(action) => {
+                1u8.serialize(writer)?;
+                decode_base64(&action.code)?.serialize(writer)
+            }
+            Self::FunctionCall(action) => {
+                2u8.serialize(writer)?;
+                action.method_name.serialize(writer)?;
+                decode_base64(&action.args)?.serialize(writer)?;
+                action.gas.as_gas().serialize(writer)?;
+                action.deposit.as_yoctonear().serialize(writer)
+            }
+            Self::Transfer(action) => {
+                3u8.serialize(writer)?;
+                action.deposit.as_yoctonear().serialize(writer)
+            }
+            Self::Stake(action) => {
+                4u8.serialize(writer)?;
+                action.stake.as_yoctonear().serialize(writer)?;
+                action.public_key.serialize(writer)
+            }
+            Self::AddKey(action) => {
+                5u8.serialize(writer)?;
+                action.public_key.serialize(writer)?;
+                action.access_key.serialize(writer)
+            }
+            Self::DeleteKey(action) => {
+                6u8.serialize(writer)?;
+                action.public_key.serialize(writer)
+            }
+            Self::DeleteAccount(action) => {
+                7u8.serialize(writer)?;
+                action.beneficiary_id.as_str().serialize(writer)
+            }
+            Self::DeployGlobalContract(action) => {
+                9u8.serialize(writer)?;
+                decode_base64(&action.code)?.serialize(writer)?;
+                match action.deploy_mode {
+                    GlobalContractDeployMode::CodeHash => 0u8,
+                    GlobalContractDeployMode::AccountId => 1u8,
+                }
+                .serialize(writer)
+            }
+            Self::UseGlobalContract(action) => {
+                10u8.serialize(writer)?;
+                match &action.contract_identifier {
+                    GlobalContractIdentifier::CodeHash(hash) => {
+                        0u8.serialize(writer)?;
+                        hash.serialize(writer)
+                    }
+                    GlobalContractIdentifier::AccountId(account_id) => {
+                        1u8.serialize(writer)?;
+                        account_id.as_str().serialize(writer)
+                    }
+                }
+            }
+            Self::DeterministicStateInit(_)
+            | Self::AddGasKey(_)
+            | Self::DeleteGasKey(_)
+            | Self::TransferToGasKey(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "encoding this action is not supported yet",
+            )),
+        }
+    }
+}
+
+impl BorshSerialize for AccessKey {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.nonce.serialize(writer)?;
+        match &self.permission {
+            AccessKeyPermission::FunctionCall(permission) => {
+                0u8.serialize(writer)?;
+                permission
+                    .allowance
+                    .map(|allowance| allowance.as_yoctonear())
+                    .serialize(writer)?;
+                permission.receiver_id.serialize(writer)?;
+                permission.method_names.serialize(writer)
+            }
+            AccessKeyPermission::FullAccess => 1u8.serialize(writer),
+        }
+    }
+}
+
+impl BorshSerialize for SignedDelegateAction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.delegate_action.serialize(writer)?;
+        self.signature.serialize(writer)
+    }
+}
+
+impl BorshSerialize for DelegateAction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.sender_id.as_str().serialize(writer)?;
+        self.receiver_id.as_str().serialize(writer)?;
+        self.actions.serialize(writer)?;
+        self.nonce.serialize(writer)?;
+        self.max_block_height.serialize(writer)?;
+        self.public_key.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for PublicKey {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => <[u8; 32]>::deserialize_reader(reader).map(Self::Ed25519),
+            1 => <[u8; 64]>::deserialize_reader(reader).map(Self::Secp256k1),
+            tag => Err(invalid_data(format!("unknown key type {}", tag))),
+        }
+    }
+}
+
+impl BorshDeserialize for Signature {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => <[u8; 64]>::deserialize_reader(reader).map(Self::Ed25519),
+            1 => <[u8; 65]>::deserialize_reader(reader).map(Self::Secp256k1),
+            tag => Err(invalid_data(format!("unknown key type {}", tag))),
+        }
+    }
+}
+
+impl BorshDeserialize for CryptoHash {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        <[u8; 32]>::deserialize_reader(reader).map(Self)
+    }
+}
+
+impl BorshDeserialize for Transaction {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            signer_id: read_account_id(reader)?,
+            public_key: PublicKey::deserialize_reader(reader)?,
+            nonce: u64::deserialize_reader(reader)?,
+            receiver_id: read_account_id(reader)?,
+            block_hash: CryptoHash::deserialize_reader(reader)?,
+            actions: Vec::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl BorshDeserialize for SignedTransaction {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            transaction: Transaction::deserialize_reader(reader)?,
+            signature: Signature::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl BorshDeserialize for Action {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            8 => Ok(Self::Delegate(Box::new(
+                SignedDelegateAction::deserialize_reader(reader)?,
+            ))),
+            tag => read_non_delegate_action(tag, reader).map(Self::NonDelegate),
+        }
+    }
+}
+
+impl BorshDeserialize for NonDelegateAction {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let tag = u8::deserialize_reader(reader)?;
+        read_non_delegate_action(tag, reader)
+    }
+}
+
+/// Reads the action following its enum `tag`, the inverse of
+/// `NonDelegateAction::serialize`.
+fn read_non_delegate_action<R: Read>(tag: u8, reader: &mut R) -> io::Result<NonDelegateAction> {
+    Ok(match tag {
+        0 => NonDelegateAction::CreateAccount(CreateAccountAction(Default::default())),
+        1 => NonDelegateAction::DeployContract(DeployContractAction {
+            code: read_base64(reader)?,
+        }),
+        2 => NonDelegateAction::FunctionCall(FunctionCallAction {
+            method_name: String::deserialize_reader(reader)?,
+            args: read_base64(reader)?,
+            gas: NearGas::from_gas(u64::deserialize_reader(reader)?),
+            deposit: NearToken::from_yoctonear(u128::deserialize_reader(reader)?),
+        }),
+        3 => NonDelegateAction::Transfer(TransferAction {
+            deposit: NearToken::from_yoctonear(u128::deserialize_reader(reader)?),
+        }),
+        4 => NonDelegateAction::Stake(StakeAction {
+            stake: NearToken::from_yoctonear(u128::deserialize_reader(reader)?),
+            public_key: PublicKey::deserialize_reader(reader)?,
+        }),
+        5 => NonDelegateAction::AddKey(AddKeyAction {
+            public_key: PublicKey::deserialize_reader(reader)?,
+            access_key: AccessKey::deserialize_reader(reader)?,
+        }),
+        6 => NonDelegateAction::DeleteKey(DeleteKeyAction {
+            public_key: PublicKey::deserialize_reader(reader)?,
+        }),
+        7 => NonDelegateAction::DeleteAccount(DeleteAccountAction {
+            beneficiary_id: read_account_id(reader)?,
+        }),
+        8 => return Err(invalid_data("delegate actions can't be nested".to_string())),
+        9 => NonDelegateAction::DeployGlobalContract(DeployGlobalContractAction {
+            code: read_base64(reader)?,
+            deploy_mode: match u8::deserialize_reader(reader)? {
+                0 => GlobalContractDeployMode::CodeHash,
+                1 => GlobalContractDeployMode::AccountId,
+                mode => return Err(invalid_data(format!("unknown deploy mode {}", mode))),
+            },
+        }),
+        10 => NonDelegateAction::UseGlobalContract(UseGlobalContractAction {
+            contract_identifier: match u8::deserialize_reader(reader)? {
+                0 => GlobalContractIdentifier::CodeHash(CryptoHash::deserialize_reader(reader)?),
+                1 => GlobalContractIdentifier::AccountId(read_account_id(reader)?),
+                kind => {
+                    return Err(invalid_data(format!(
+                        "unknown global contract identifier {}",
+                        kind
+                    )));
+                }
+            },
+        }),
+        tag => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("decoding action {} is not supported", tag),
+            ));
+        }
+    })
+}
+
+impl BorshDeserialize for AccessKey {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let nonce = u64::deserialize_reader(reader)?;
+        let permission = match u8::deserialize_reader(reader)? {
+            0 => AccessKeyPermission::FunctionCall(FunctionCallPermission {
+                allowance: Option::<u128>::deserialize_reader(reader)?
+                    .map(NearToken::from_yoctonear),
+                receiver_id: String::deserialize_reader(reader)?,
+                method_names: Vec::deserialize_reader(reader)?,
+            }),
+            1 => AccessKeyPermission::FullAccess,
+            tag => return Err(invalid_data(format!("unknown permission {}", tag))),
+        };
+        Ok(Self { nonce, permission })
+    }
+}
+
+impl BorshDeserialize for SignedDelegateAction {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            delegate_action: DelegateAction::deserialize_reader(reader)?,
+            signature: Signature::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl BorshDeserialize for DelegateAction {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            sender_id: read_account_id(reader)?,
+            receiver_id: read_account_id(reader)?,
+            actions: Vec::deserialize_reader(reader)?,
+            nonce: u64::deserialize_reader(reader)?,
+            max_block_height: u64::deserialize_reader(reader)?,
+            public_key: PublicKey::deserialize_reader(reader)?,
+        })
+    }
+}
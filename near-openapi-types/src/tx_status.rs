@@ -0,0 +1,102 @@
+use crate::{
+    AccountId, CryptoHash, ExecutionOutcomeWithIdView, RpcTransactionResponse,
+    RpcTransactionStatusRequest, SignedTransaction, SignedTransactionView, TxExecutionStatus,
+};
+
+impl RpcTransactionStatusRequest {
+    /// Looks up a transaction by its hash and signer.
+    pub fn by_hash(
+        tx_hash: CryptoHash,
+        sender_account_id: AccountId,
+        wait_until: TxExecutionStatus,
+    ) -> Self {
+        Self::Variant1 {
+            sender_account_id,
+            tx_hash,
+            wait_until,
+        }
+    }
+
+    /// Looks up a transaction by the signed transaction itself, which saves
+    /// computing its hash locally, e.g. right after submitting it.
+    pub fn by_signed_transaction(
+        signed_tx_base64: SignedTransaction,
+        wait_until: TxExecutionStatus,
+    ) -> Self {
+        Self::Variant0 {
+            signed_tx_base64,
+            wait_until,
+        }
+    }
+}
+
+impl TxExecutionStatus {
+    /// Whether a transaction at this status is at least at `wanted`. The
+    /// statuses are not totally ordered: an `IncludedFinal` transaction need
+    /// not be `ExecutedOptimistic` yet, nor the other way round.
+    pub fn satisfies(self, wanted: TxExecutionStatus) -> bool {
+        match wanted {
+            Self::None => true,
+            Self::Included => self != Self::None,
+            Self::ExecutedOptimistic => {
+                matches!(
+                    self,
+                    Self::ExecutedOptimistic | Self::Executed | Self::Final
+                )
+            }
+            Self::IncludedFinal => {
+                matches!(self, Self::IncludedFinal | Self::Executed | Self::Final)
+            }
+            Self::Executed => matches!(self, Self::Executed | Self::Final),
+            Self::Final => self == Self::Final,
+        }
+    }
+}
+
+impl RpcTransactionResponse {
+    /// Status the transaction had reached when the node answered.
+    pub fn final_execution_status(&self) -> TxExecutionStatus {
+        match self {
+            Self::Variant0 {
+                final_execution_status,
+                ..
+            }
+            | Self::Variant1 {
+                final_execution_status,
+                ..
+            } => *final_execution_status,
+        }
+    }
+
+    pub fn transaction(&self) -> &SignedTransactionView {
+        match self {
+            Self::Variant0 { transaction, .. } | Self::Variant1 { transaction, .. } => transaction,
+        }
+    }
+
+    /// Outcome of converting the transaction into a receipt.
+    pub fn transaction_outcome(&self) -> &ExecutionOutcomeWithIdView {
+        match self {
+            Self::Variant0 {
+                transaction_outcome,
+                ..
+            }
+            | Self::Variant1 {
+                transaction_outcome,
+                ..
+            } => transaction_outcome,
+        }
+    }
+
+    /// Outcomes of the receipts executed so far.
+    pub fn receipts_outcome(&self) -> &[ExecutionOutcomeWithIdView] {
+        match self {
+            Self::Variant0 {
+                receipts_outcome, ..
+            }
+            | Self::Variant1 {
+                receipts_outcome, ..
+            } => receipts_outcome,
+        }
+    }
+}
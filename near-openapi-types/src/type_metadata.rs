@@ -0,0 +1,219 @@
+//! Shapes of the generated types, for diffing two versions of this crate.
+//!
+//! Every release embeds the definitions of its generated types (field and
+//! variant names with their Rust types). Save [`TypeMetadata::current`] as JSON
+//! with the version you depend on, and after upgrading compare it with the new
+//! one to get a machine-readable list of what changed:
+//!
+//! ```
+//! # use near_openapi_types::type_metadata::TypeMetadata;
+//! # let saved = serde_json::to_string(&TypeMetadata::current()).unwrap();
+//! let old: TypeMetadata = serde_json::from_str(&saved).unwrap();
+//! let report = old.diff(&TypeMetadata::current());
+//! assert!(report.changes.is_empty());
+//! ```
+use std::collections::BTreeMap;
+
+/// Whether a type is a struct or an enum.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeKind {
+    Struct,
+    Enum,
+}
+
+/// Definition of a single type.
+///
+/// `members` maps field names (`"0"`, `"1"`, ... for tuple structs) to their
+/// types, and variant names to their payload, which is `None` for unit
+/// variants.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TypeDefinition {
+    pub kind: TypeKind,
+    pub members: BTreeMap<String, Option<String>>,
+}
+
+/// Definitions of all generated types of one crate version.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TypeMetadata {
+    pub version: String,
+    pub types: BTreeMap<String, TypeDefinition>,
+}
+
+impl TypeMetadata {
+    /// Metadata of this version of the crate.
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            types: serde_json::from_str(include_str!("type_metadata.json"))
+                .expect("embedded type metadata is valid"),
+        }
+    }
+
+    /// Lists the changes from `self` to `new`, sorted by type name.
+    pub fn diff(&self, new: &TypeMetadata) -> ChangeReport {
+        let mut changes = Vec::new();
+        for (type_name, old_type) in &self.types {
+            let Some(new_type) = new.types.get(type_name) else {
+                changes.push(TypeChange::TypeRemoved {
+                    type_name: type_name.clone(),
+                });
+                continue;
+            };
+            if old_type.kind != new_type.kind {
+                changes.push(TypeChange::KindChanged {
+                    type_name: type_name.clone(),
+                    old_kind: old_type.kind,
+                    new_kind: new_type.kind,
+                });
+                continue;
+            }
+            diff_members(type_name, old_type, new_type, &mut changes);
+        }
+        for (type_name, new_type) in &new.types {
+            if !self.types.contains_key(type_name) {
+                changes.push(TypeChange::TypeAdded {
+                    type_name: type_name.clone(),
+                    kind: new_type.kind,
+                });
+            }
+        }
+        changes.sort_by(|a, b| a.type_name().cmp(b.type_name()));
+        ChangeReport {
+            old_version: self.version.clone(),
+            new_version: new.version.clone(),
+            changes,
+        }
+    }
+}
+
+fn diff_members(
+    type_name: &str,
+    old_type: &TypeDefinition,
+    new_type: &TypeDefinition,
+    changes: &mut Vec<TypeChange>,
+) {
+    let is_enum = old_type.kind == TypeKind::Enum;
+    for (member, old_member_type) in &old_type.members {
+        match new_type.members.get(member) {
+            None if is_enum => changes.push(TypeChange::VariantRemoved {
+                type_name: type_name.to_string(),
+                variant: member.clone(),
+            }),
+            None => changes.push(TypeChange::FieldRemoved {
+                type_name: type_name.to_string(),
+                field: member.clone(),
+            }),
+            Some(new_member_type) if new_member_type != old_member_type => {
+                changes.push(if is_enum {
+                    TypeChange::VariantPayloadChanged {
+                        type_name: type_name.to_string(),
+                        variant: member.clone(),
+                        old_payload: old_member_type.clone(),
+                        new_payload: new_member_type.clone(),
+                    }
+                } else {
+                    TypeChange::FieldTypeChanged {
+                        type_name: type_name.to_string(),
+                        field: member.clone(),
+                        old_type: old_member_type.clone().unwrap_or_default(),
+                        new_type: new_member_type.clone().unwrap_or_default(),
+                    }
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (member, new_member_type) in &new_type.members {
+        if old_type.members.contains_key(member) {
+            continue;
+        }
+        changes.push(if is_enum {
+            TypeChange::VariantAdded {
+                type_name: type_name.to_string(),
+                variant: member.clone(),
+                payload: new_member_type.clone(),
+            }
+        } else {
+            TypeChange::FieldAdded {
+                type_name: type_name.to_string(),
+                field: member.clone(),
+                field_type: new_member_type.clone().unwrap_or_default(),
+            }
+        });
+    }
+}
+
+/// A single difference between two versions of the generated types.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum TypeChange {
+    TypeAdded {
+        type_name: String,
+        kind: TypeKind,
+    },
+    TypeRemoved {
+        type_name: String,
+    },
+    KindChanged {
+        type_name: String,
+        old_kind: TypeKind,
+        new_kind: TypeKind,
+    },
+    FieldAdded {
+        type_name: String,
+        field: String,
+        field_type: String,
+    },
+    FieldRemoved {
+        type_name: String,
+        field: String,
+    },
+    FieldTypeChanged {
+        type_name: String,
+        field: String,
+        old_type: String,
+        new_type: String,
+    },
+    VariantAdded {
+        type_name: String,
+        variant: String,
+        payload: Option<String>,
+    },
+    VariantRemoved {
+        type_name: String,
+        variant: String,
+    },
+    VariantPayloadChanged {
+        type_name: String,
+        variant: String,
+        old_payload: Option<String>,
+        new_payload: Option<String>,
+    },
+}
+
+impl TypeChange {
+    /// Name of the type the change applies to.
+    pub fn type_name(&self) -> &str {
+        match self {
+            Self::TypeAdded { type_name, .. }
+            | Self::TypeRemoved { type_name }
+            | Self::KindChanged { type_name, .. }
+            | Self::FieldAdded { type_name, .. }
+            | Self::FieldRemoved { type_name, .. }
+            | Self::FieldTypeChanged { type_name, .. }
+            | Self::VariantAdded { type_name, .. }
+            | Self::VariantRemoved { type_name, .. }
+            | Self::VariantPayloadChanged { type_name, .. } => type_name,
+        }
+    }
+}
+
+/// Changes between two versions of the generated types, as returned by
+/// [`TypeMetadata::diff`].
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ChangeReport {
+    pub old_version: String,
+    pub new_version: String,
+    pub changes: Vec<TypeChange>,
+}
@@ -10,33 +10,82 @@ use crate::error;
 #[doc = "}"]
 #[doc = r" ```"]
 #[doc = r" </details>"]
-#[derive(
-    Clone,
-    Debug,
-    Eq,
-    Hash,
-    Ord,
-    PartialEq,
-    PartialOrd,
-)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CryptoHash(pub [u8; 32]);
-impl ::std::ops::Deref for CryptoHash {
+
+/// Length of the longest base58 encoding of 32 bytes.
+const BASE58_LEN: usize = 44;
+
+impl CryptoHash {
+    /// The hash of all zeros, e.g. the `prev_hash` of the genesis block.
+    pub const ZERO: CryptoHash = CryptoHash([0; 32]);
+
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < 32 {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Writes the base58 encoding into `buf` without allocating.
+    fn encode<'a>(&self, buf: &'a mut [u8; BASE58_LEN]) -> &'a str {
+        let len = bs58::encode(self.0)
+            .onto(&mut buf[..])
+            .expect("32 bytes fit in 44 base58 digits");
+        core::str::from_utf8(&buf[..len]).expect("base58 is ASCII")
+    }
+}
+impl From<[u8; 32]> for CryptoHash {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+impl From<CryptoHash> for [u8; 32] {
+    fn from(hash: CryptoHash) -> Self {
+        hash.0
+    }
+}
+impl AsRef<[u8]> for CryptoHash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl ::core::ops::Deref for CryptoHash {
     type Target = [u8; 32];
     fn deref(&self) -> &[u8; 32] {
         &self.0
     }
 }
-impl ::std::convert::From<&CryptoHash> for CryptoHash {
+impl ::core::convert::From<&CryptoHash> for CryptoHash {
     fn from(value: &CryptoHash) -> Self {
-        value.clone()
+        *value
     }
 }
-impl std::str::FromStr for CryptoHash {
+impl core::str::FromStr for CryptoHash {
     type Err = self::error::ConversionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes = bs58::decode(s).into_vec()?;
-        Self::try_from(bytes)
+        let mut bytes = [0; 32];
+        match bs58::decode(s).onto(&mut bytes) {
+            Ok(32) => Ok(Self(bytes)),
+            Ok(_) | Err(bs58::decode::Error::BufferTooSmall) => {
+                Err("length for CryptoHash is not 32".into())
+            }
+            Err(err) => Err(err.into()),
+        }
     }
 }
 impl TryFrom<&[u8]> for CryptoHash {
@@ -52,16 +101,16 @@ impl TryFrom<&[u8]> for CryptoHash {
     }
 }
 
-impl TryFrom<Vec<u8>> for CryptoHash {
+impl TryFrom<alloc::vec::Vec<u8>> for CryptoHash {
     type Error = self::error::ConversionError;
 
-    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+    fn try_from(v: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
         <Self as TryFrom<&[u8]>>::try_from(v.as_ref())
     }
 }
-impl std::fmt::Display for CryptoHash {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::std::fmt::Display::fmt(&bs58::encode(self.0).into_string(), f)
+impl core::fmt::Display for CryptoHash {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self.encode(&mut [0; BASE58_LEN]), f)
     }
 }
 
@@ -70,7 +119,7 @@ impl serde::Serialize for CryptoHash {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        serializer.serialize_str(self.encode(&mut [0; BASE58_LEN]))
     }
 }
 
@@ -79,7 +128,33 @@ impl<'de> serde::Deserialize<'de> for CryptoHash {
     where
         D: serde::Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        <Self as std::str::FromStr>::from_str(&s).map_err(serde::de::Error::custom)
+        deserializer.deserialize_str(CryptoHashVisitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CryptoHash {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "CryptoHash".into()
+    }
+
+    /// A base58 string, as in the OpenAPI spec.
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string" })
+    }
+}
+
+/// Decodes borrowed and owned strings alike, without copying them.
+struct CryptoHashVisitor;
+
+impl serde::de::Visitor<'_> for CryptoHashVisitor {
+    type Value = CryptoHash;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a base58-encoded 32-byte hash")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<CryptoHash, E> {
+        <CryptoHash as core::str::FromStr>::from_str(s).map_err(E::custom)
     }
 }
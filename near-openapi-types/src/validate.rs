@@ -0,0 +1,233 @@
+//! Checks of request params before they are sent.
+//!
+//! Which fields go together is enforced by the generated types, e.g. only
+//! the `DataChanges*` variants of [`RpcStateChangesInBlockByTypeRequest`]
+//! have a key prefix. [`Validate`] checks what the types cannot: lists that
+//! must not be empty, strings that must not be empty and base64 fields that
+//! must decode. It is implemented by the params of every JSON-RPC method.
+//!
+//! ```
+//! # use near_openapi_types::state_changes::StateChangesRequestBuilder;
+//! # use near_openapi_types::validate::{Validate, ValidationError};
+//! let request = StateChangesRequestBuilder::new().account_changes(vec![]);
+//! assert_eq!(
+//!     request.validate(),
+//!     Err(ValidationError::EmptyList { field: "account_ids" })
+//! );
+//! ```
+use base64::Engine;
+
+use crate::RpcQueryRequest;
+#[cfg(feature = "config")]
+use crate::{GenesisConfigRequest, RpcClientConfigRequest, RpcProtocolConfigRequest};
+#[cfg(feature = "block")]
+use crate::{
+    RpcBlockRequest, RpcChunkRequest, RpcCongestionLevelRequest, RpcGasPriceRequest,
+    RpcLightClientBlockProofRequest, RpcLightClientExecutionProofRequest,
+    RpcLightClientNextBlockRequest,
+};
+#[cfg(feature = "debug")]
+use crate::{
+    RpcHealthRequest, RpcMaintenanceWindowsRequest, RpcNetworkInfoRequest,
+    RpcSplitStorageInfoRequest, RpcStatusRequest,
+};
+#[cfg(feature = "tx")]
+use crate::{
+    RpcReceiptRequest, RpcSendTransactionRequest, RpcTransactionStatusRequest, SignedTransaction,
+};
+#[cfg(feature = "changes")]
+use crate::{RpcStateChangesInBlockByTypeRequest, RpcStateChangesInBlockRequest};
+#[cfg(feature = "validators")]
+use crate::{RpcValidatorRequest, RpcValidatorsOrderedRequest};
+
+/// Why request params are invalid, with the JSON name of the offending field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// A list that must name at least one item is empty, e.g. the
+    /// `account_ids` of a changes request.
+    EmptyList { field: &'static str },
+    /// A string that must not be empty is, e.g. the `method_name` of a
+    /// function call.
+    EmptyString { field: &'static str },
+    /// A base64 field does not decode.
+    InvalidBase64 { field: &'static str },
+    /// A signed transaction does not decode.
+    InvalidTransaction { field: &'static str, reason: String },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyList { field } => write!(f, "{} must not be empty", field),
+            Self::EmptyString { field } => write!(f, "{} must not be empty", field),
+            Self::InvalidBase64 { field } => write!(f, "{} is not valid base64", field),
+            Self::InvalidTransaction { field, reason } => {
+                write!(f, "{} is not a signed transaction: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Params that can be checked before they are sent.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+#[cfg(feature = "changes")]
+fn non_empty<T>(field: &'static str, items: &[T]) -> Result<(), ValidationError> {
+    if items.is_empty() {
+        return Err(ValidationError::EmptyList { field });
+    }
+    Ok(())
+}
+
+fn base64(field: &'static str, data: &str) -> Result<(), ValidationError> {
+    base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map(drop)
+        .map_err(|_| ValidationError::InvalidBase64 { field })
+}
+
+#[cfg(feature = "tx")]
+fn signed_transaction(
+    field: &'static str,
+    signed_tx: &SignedTransaction,
+) -> Result<(), ValidationError> {
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(&signed_tx.0)
+        .map_err(|_| ValidationError::InvalidBase64 { field })?;
+    crate::tx::SignedTransaction::decode(&data)
+        .map(drop)
+        .map_err(|err| ValidationError::InvalidTransaction {
+            field,
+            reason: err.to_string(),
+        })
+}
+
+/// Params without anything to check beyond their types.
+macro_rules! always_valid {
+    ($($feature:literal: $($params:ty),*;)*) => {
+        $($(
+            #[cfg(feature = $feature)]
+            impl Validate for $params {
+                fn validate(&self) -> Result<(), ValidationError> {
+                    Ok(())
+                }
+            }
+        )*)*
+    };
+}
+
+always_valid! {
+    "block": RpcBlockRequest, RpcChunkRequest, RpcCongestionLevelRequest, RpcGasPriceRequest,
+        RpcLightClientBlockProofRequest, RpcLightClientExecutionProofRequest,
+        RpcLightClientNextBlockRequest;
+    "changes": RpcStateChangesInBlockRequest;
+    "config": GenesisConfigRequest, RpcClientConfigRequest, RpcProtocolConfigRequest;
+    "debug": RpcHealthRequest, RpcMaintenanceWindowsRequest, RpcNetworkInfoRequest,
+        RpcSplitStorageInfoRequest, RpcStatusRequest;
+    "tx": RpcReceiptRequest;
+    "validators": RpcValidatorRequest, RpcValidatorsOrderedRequest;
+}
+
+impl Validate for RpcQueryRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::CallFunctionByBlockId {
+                method_name,
+                args_base64,
+                ..
+            }
+            | Self::CallFunctionByFinality {
+                method_name,
+                args_base64,
+                ..
+            }
+            | Self::CallFunctionBySyncCheckpoint {
+                method_name,
+                args_base64,
+                ..
+            } => {
+                if method_name.is_empty() {
+                    return Err(ValidationError::EmptyString {
+                        field: "method_name",
+                    });
+                }
+                base64("args_base64", &args_base64.0)
+            }
+            Self::ViewStateByBlockId { prefix_base64, .. }
+            | Self::ViewStateByFinality { prefix_base64, .. }
+            | Self::ViewStateBySyncCheckpoint { prefix_base64, .. } => {
+                base64("prefix_base64", &prefix_base64.0)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "changes")]
+impl Validate for RpcStateChangesInBlockByTypeRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::DataChangesByBlockId {
+                account_ids,
+                key_prefix_base64,
+                ..
+            }
+            | Self::DataChangesByFinality {
+                account_ids,
+                key_prefix_base64,
+                ..
+            }
+            | Self::DataChangesBySyncCheckpoint {
+                account_ids,
+                key_prefix_base64,
+                ..
+            } => {
+                non_empty("account_ids", account_ids)?;
+                base64("key_prefix_base64", &key_prefix_base64.0)
+            }
+            Self::AccountChangesByBlockId { account_ids, .. }
+            | Self::AccountChangesByFinality { account_ids, .. }
+            | Self::AccountChangesBySyncCheckpoint { account_ids, .. }
+            | Self::AllAccessKeyChangesByBlockId { account_ids, .. }
+            | Self::AllAccessKeyChangesByFinality { account_ids, .. }
+            | Self::AllAccessKeyChangesBySyncCheckpoint { account_ids, .. }
+            | Self::AllGasKeyChangesByBlockId { account_ids, .. }
+            | Self::AllGasKeyChangesByFinality { account_ids, .. }
+            | Self::AllGasKeyChangesBySyncCheckpoint { account_ids, .. }
+            | Self::ContractCodeChangesByBlockId { account_ids, .. }
+            | Self::ContractCodeChangesByFinality { account_ids, .. }
+            | Self::ContractCodeChangesBySyncCheckpoint { account_ids, .. } => {
+                non_empty("account_ids", account_ids)
+            }
+            Self::SingleAccessKeyChangesByBlockId { keys, .. }
+            | Self::SingleAccessKeyChangesByFinality { keys, .. }
+            | Self::SingleAccessKeyChangesBySyncCheckpoint { keys, .. }
+            | Self::SingleGasKeyChangesByBlockId { keys, .. }
+            | Self::SingleGasKeyChangesByFinality { keys, .. }
+            | Self::SingleGasKeyChangesBySyncCheckpoint { keys, .. } => non_empty("keys", keys),
+        }
+    }
+}
+
+#[cfg(feature = "tx")]
+impl Validate for RpcSendTransactionRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        signed_transaction("signed_tx_base64", &self.signed_tx_base64)
+    }
+}
+
+#[cfg(feature = "tx")]
+impl Validate for RpcTransactionStatusRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::Variant0 {
+                signed_tx_base64, ..
+            } => signed_transaction("signed_tx_base64", signed_tx_base64),
+            Self::Variant1 { .. } => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Stake distribution and production of the validators of an epoch.
+//!
+//! A [`ValidatorSet`] holds the validators of an `RpcValidatorResponse`
+//! sorted by stake, largest first, and indexed by account id:
+//!
+//! ```
+//! # use near_openapi_types::{validator_set::ValidatorSet, NearToken, NextEpochValidatorInfo};
+//! # let validator = |account_id: &str, stake: u128| NextEpochValidatorInfo {
+//! #     account_id: account_id.parse().unwrap(),
+//! #     public_key: "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap(),
+//! #     shards: vec![],
+//! #     stake: NearToken::from_near(stake),
+//! # };
+//! let set: ValidatorSet<_> = vec![validator("b.near", 10), validator("a.near", 30)].into_iter().collect();
+//! assert_eq!(set.total_stake(), NearToken::from_near(40));
+//! assert_eq!(set.iter().next().unwrap().account_id.as_str(), "a.near");
+//! assert_eq!(set.stake_share(&"b.near".parse().unwrap()), Some(0.25));
+//! ```
+//!
+//! Validators producing less than the kickout thresholds of the epoch config
+//! (`block_producer_kickout_threshold` and the like, in percent) are kicked
+//! out at the end of the epoch; [`CurrentEpochValidatorInfo::is_at_risk`]
+//! tells whether a validator is below a threshold so far.
+use std::collections::HashMap;
+
+use crate::{
+    AccountId, CurrentEpochValidatorInfo, NearToken, NextEpochValidatorInfo, RpcValidatorResponse,
+    ValidatorKickoutReason, ValidatorStakeView,
+};
+
+/// A validator, proposal or fisherman with its stake.
+pub trait StakedValidator {
+    fn account_id(&self) -> &AccountId;
+    fn stake(&self) -> NearToken;
+}
+
+impl StakedValidator for CurrentEpochValidatorInfo {
+    fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    fn stake(&self) -> NearToken {
+        self.stake
+    }
+}
+
+impl StakedValidator for NextEpochValidatorInfo {
+    fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    fn stake(&self) -> NearToken {
+        self.stake
+    }
+}
+
+impl StakedValidator for ValidatorStakeView {
+    fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    fn stake(&self) -> NearToken {
+        self.stake
+    }
+}
+
+/// Validators sorted by stake, largest first, then by account id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatorSet<V> {
+    validators: Vec<V>,
+    index: HashMap<AccountId, usize>,
+}
+
+impl<V: StakedValidator> ValidatorSet<V> {
+    pub fn new(mut validators: Vec<V>) -> Self {
+        validators.sort_by(|a, b| {
+            b.stake()
+                .cmp(&a.stake())
+                .then_with(|| a.account_id().cmp(b.account_id()))
+        });
+        let index = validators
+            .iter()
+            .enumerate()
+            .map(|(position, validator)| (validator.account_id().clone(), position))
+            .collect();
+        Self { validators, index }
+    }
+
+    pub fn get(&self, account_id: &AccountId) -> Option<&V> {
+        self.index
+            .get(account_id)
+            .map(|&position| &self.validators[position])
+    }
+
+    /// Position of `account_id` by stake, 0 for the largest validator.
+    pub fn rank(&self, account_id: &AccountId) -> Option<usize> {
+        self.index.get(account_id).copied()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, V> {
+        self.validators.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    pub fn total_stake(&self) -> NearToken {
+        NearToken::from_yoctonear(
+            self.validators
+                .iter()
+                .map(|validator| validator.stake().as_yoctonear())
+                .sum(),
+        )
+    }
+
+    /// Share of the total stake held by `account_id`, from 0 to 1.
+    pub fn stake_share(&self, account_id: &AccountId) -> Option<f64> {
+        let total = self.total_stake().as_yoctonear();
+        self.get(account_id).map(|validator| match total {
+            0 => 0.0,
+            total => validator.stake().as_yoctonear() as f64 / total as f64,
+        })
+    }
+
+    /// Fewest validators holding more than a third of the stake together,
+    /// enough to stop blocks from being finalized.
+    pub fn nakamoto_coefficient(&self) -> usize {
+        let total = self.total_stake().as_yoctonear();
+        let mut held = 0u128;
+        for (count, validator) in self.validators.iter().enumerate() {
+            held += validator.stake().as_yoctonear();
+            if held > total / 3 {
+                return count + 1;
+            }
+        }
+        self.validators.len()
+    }
+
+    pub fn into_vec(self) -> Vec<V> {
+        self.validators
+    }
+}
+
+impl ValidatorSet<CurrentEpochValidatorInfo> {
+    /// Validators producing less than `threshold` percent of their expected
+    /// blocks, chunks or endorsements so far, see
+    /// [`CurrentEpochValidatorInfo::is_at_risk`].
+    pub fn at_risk(&self, threshold: u8) -> impl Iterator<Item = &CurrentEpochValidatorInfo> {
+        self.validators
+            .iter()
+            .filter(move |validator| validator.is_at_risk(threshold))
+    }
+}
+
+impl<V: StakedValidator> FromIterator<V> for ValidatorSet<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<'a, V> IntoIterator for &'a ValidatorSet<V> {
+    type Item = &'a V;
+    type IntoIter = std::slice::Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.validators.iter()
+    }
+}
+
+impl CurrentEpochValidatorInfo {
+    /// Share of its expected blocks and chunks the validator produced, from
+    /// 0 to 1, as explorers show its uptime. `None` when it was expected to
+    /// produce neither.
+    pub fn production_rate(&self) -> Option<f64> {
+        rate(
+            self.num_produced_blocks + self.num_produced_chunks,
+            self.num_expected_blocks + self.num_expected_chunks,
+        )
+    }
+
+    pub fn block_production_rate(&self) -> Option<f64> {
+        rate(self.num_produced_blocks, self.num_expected_blocks)
+    }
+
+    pub fn chunk_production_rate(&self) -> Option<f64> {
+        rate(self.num_produced_chunks, self.num_expected_chunks)
+    }
+
+    pub fn endorsement_rate(&self) -> Option<f64> {
+        rate(
+            self.num_produced_endorsements,
+            self.num_expected_endorsements,
+        )
+    }
+
+    /// Whether the validator produced less than `threshold` percent of its
+    /// expected blocks, chunks or chunk endorsements so far, for which it
+    /// would be kicked out at the end of the epoch with a threshold of the
+    /// epoch config.
+    pub fn is_at_risk(&self, threshold: u8) -> bool {
+        let below = |produced: u64, expected: u64| {
+            u128::from(produced) * 100 < u128::from(threshold) * u128::from(expected)
+        };
+        below(self.num_produced_blocks, self.num_expected_blocks)
+            || below(self.num_produced_chunks, self.num_expected_chunks)
+            || below(
+                self.num_produced_endorsements,
+                self.num_expected_endorsements,
+            )
+    }
+}
+
+fn rate(produced: u64, expected: u64) -> Option<f64> {
+    (expected > 0).then(|| produced as f64 / expected as f64)
+}
+
+impl RpcValidatorResponse {
+    pub fn current_validator_set(&self) -> ValidatorSet<CurrentEpochValidatorInfo> {
+        self.current_validators.iter().cloned().collect()
+    }
+
+    pub fn next_validator_set(&self) -> ValidatorSet<NextEpochValidatorInfo> {
+        self.next_validators.iter().cloned().collect()
+    }
+
+    /// Why `account_id` was kicked out at the end of the previous epoch, if
+    /// it was.
+    pub fn kickout_reason(&self, account_id: &AccountId) -> Option<&ValidatorKickoutReason> {
+        self.prev_epoch_kickout
+            .iter()
+            .find(|kickout| &kickout.account_id == account_id)
+            .map(|kickout| &kickout.reason)
+    }
+}
@@ -3,6 +3,7 @@ import re
 import itertools
 import sys
 import os
+import shutil
 
 def reconstructAllOfOneOf(schema):
     # return
@@ -49,6 +50,566 @@ def iterate_nested_json_for_loop(json_obj):
         for item in json_obj:
             iterate_nested_json_for_loop(item)
 
+# Fields missing from responses for blocks produced by older protocol versions.
+# With the `historical-compat` feature they fall back to their `Default` value.
+HISTORICAL_COMPAT_FIELDS = {
+    'BlockHeaderView': ['block_merkle_root', 'last_ds_final_block', 'latest_protocol_version', 'timestamp_nanosec'],
+    'CongestionInfoView': ['allowed_shard', 'buffered_receipts_gas', 'delayed_receipts_gas', 'receipt_bytes'],
+}
+
+def add_historical_compat(types):
+    for type_name, fields in HISTORICAL_COMPAT_FIELDS.items():
+        start = types.find('pub struct ' + type_name + ' {')
+        end = types.find('\n}\n', start)
+        body = types[start:end]
+        for field in fields:
+            body = body.replace('\n    pub ' + field + ':', '\n    #[cfg_attr(feature = "historical-compat", serde(default))]\n    pub ' + field + ':', 1)
+        types = types[:start] + body + types[end:]
+    return types
+
+# Fields nearcore still sends but no longer fills since challenges were
+# removed; see near-openapi-types/src/legacy.rs
+LEGACY_FIELDS = {
+    'BlockHeaderView': {
+        'challenges_result': ('#[serde(default)]', None),
+        'challenges_root': ('#[serde(default, skip_serializing_if = "::std::option::Option::is_none")]',
+                            '::std::option::Option<CryptoHash>'),
+    },
+}
+
+def model_legacy_fields(types):
+    for type_name, fields in LEGACY_FIELDS.items():
+        start = types.find('pub struct ' + type_name + ' {')
+        end = types.find('\n}\n', start)
+        body = types[start:end]
+        for field, (attribute, field_type) in fields.items():
+            m = re.search(r'\n    pub ' + field + r': (.*),', body)
+            doc = '#[doc = "Legacy field, see [`crate::legacy`]."]'
+            body = (body[:m.start()] + '\n    ' + doc + '\n    ' + attribute + '\n    pub ' + field + ': '
+                    + (field_type or m.group(1)) + ',' + body[m.end():])
+        types = types[:start] + body + types[end:]
+    return types
+
+# Enums nearcore keeps extending; see near-openapi-types/src/lenient.rs
+OPEN_ENUMS = ['ActionErrorKind', 'ActionView', 'HostError', 'InvalidTxError', 'ReceiptEnumView', 'StateChangeCauseView']
+
+def known_variants(body):
+    # Serde names of the variants of a formatted enum body: the variant name
+    # unless renamed
+    variants = []
+    rename = None
+    for line in body.split('\n'):
+        m = re.match(r'    #\[serde\(rename = "([^"]+)"\)\]$', line)
+        if m:
+            rename = m.group(1)
+            continue
+        m = re.match(r'    ([A-Z]\w*)\b', line)
+        if m:
+            if m.group(1) != 'Unknown':
+                variants.append(rename or m.group(1))
+            rename = None
+    return variants
+
+def add_unknown_variants(types):
+    for type_name in OPEN_ENUMS:
+        start = types.find('\npub enum ' + type_name + ' {')
+        end = types.find('\n}\n', start)
+        variants = ''.join('        "' + variant + '",\n' for variant in known_variants(types[start:end]))
+        types = (types[:end] + '\n    #[doc = "Variant missing from this version of the crate, only decoded inside `lenient::decode_leniently`"]\n    #[serde(untagged)]\n    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]\n    Unknown(#[serde(deserialize_with = "lenient::deserialize_unknown::<' + type_name + ', _>")] lenient::UnknownValue),\n}\n'
+                 + 'impl lenient::OpenEnum for ' + type_name + ' {\n    const KNOWN_VARIANTS: &\'static [&\'static str] = &[\n' + variants + '    ];\n}'
+                 + types[end + 2:])
+    return types
+
+def fix_nullable_responses(types):
+    # Untagged envelopes of methods with a nullable result: `Variant0` would also
+    # match error responses, whose missing `result` decodes as `None`, so the
+    # error variant is tried first
+    for m in list(re.finditer(r'\npub enum (JsonRpcResponseForNullable\w+) \{\n', types)):
+        start = m.end()
+        end = types.find('\n}\n', start)
+        split = types.find('    Variant1 {', start)
+        types = types[:start] + types[split:end] + '\n' + types[start:split].rstrip('\n') + types[end:]
+    return types
+
+ARBITRARY_DERIVE = '#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]'
+
+def add_arbitrary_derives(types):
+    # Derive Arbitrary on every generated type behind the `arbitrary` feature.
+    # JSON objects have no Arbitrary impl: newtypes of them implement it in
+    # near-openapi-types/src/arbitrary_impls.rs and variants holding them use
+    # a helper from there
+    types = re.sub(r'(#\[derive\([^)]*\)\])((?:\n#\[(?!derive)[^\n]*)*\npub (?:struct|enum) (?!\w+\(\s*pub ::serde_json::Map<))',
+                   r'\1\n' + ARBITRARY_DERIVE + r'\2', types)
+    return types.replace('(::serde_json::Map<',
+                         '(#[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary_impls::json_map))] ::serde_json::Map<')
+
+SCHEMARS_DERIVE = '#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]'
+
+def add_schemars_derives(types):
+    # Derive JsonSchema on every generated type behind the `schemars` feature.
+    # The schemas follow the serde attributes, so they describe the same JSON
+    # as the OpenAPI spec; `Unknown` variants of open enums are left out as
+    # they only exist inside `lenient::decode_leniently`
+    types = re.sub(r'(#\[derive\([^)]*\)\])((?:\n#\[(?!derive)[^\n]*)*\npub (?:struct|enum) )',
+                   r'\1\n' + SCHEMARS_DERIVE + r'\2', types)
+    return types.replace('    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]\n    Unknown(',
+                         '    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]\n    #[cfg_attr(feature = "schemars", schemars(skip))]\n    Unknown(')
+
+# Requests by block id, finality and sync checkpoint each come with their own
+# single-value marker enum, e.g. `AccountChangesByBlockIdChangesType`. They
+# are merged into one enum per kind of changes (`AccountChangesType`), with
+# the per-request names kept as aliases of it
+CHANGES_TYPE_REFERENCES = ['ByBlockId', 'ByFinality', 'BySyncCheckpoint']
+
+def changes_type_item(types, type_name):
+    # Span of the definition of `type_name` and of the impls following it
+    start = types.find('#[doc = "`' + type_name + '`"]')
+    end = types.find('\n}\n', types.find('\npub enum ' + type_name + ' {', start)) + 3
+    while types.startswith('impl', end) and re.match(r'impl[^{]*\b' + type_name + r'\b', types[end:]):
+        end = types.find('\n}\n', end) + 3
+    return start, end
+
+def collapse_changes_types(types):
+    for kind in re.findall(r'\npub enum (\w+)ByBlockIdChangesType \{', types):
+        shared = kind + 'Type'
+        names = [kind + reference + 'ChangesType' for reference in CHANGES_TYPE_REFERENCES]
+        start, end = changes_type_item(types, names[0])
+        item = re.sub(r'\b' + names[0] + r'\b', shared, types[start:end])
+        item = item.replace('`"]\n', '`"]\n#[doc = r""]\n#[doc = "Marker of the `' + kind + '` requests by block id, finality and sync checkpoint."]\n', 1)
+        aliases = ''.join('#[doc = "Alias of [`' + shared + '`]."]\npub type ' + name + ' = ' + shared + ';\n' for name in names)
+        types = types[:start] + item + aliases + types[end:]
+        for name in names[1:]:
+            start, end = changes_type_item(types, name)
+            types = types[:start] + types[end:]
+        # Fields of the requests refer to the shared enum; the JSON schemas in
+        # the docs keep the names of the spec
+        alias_use = re.compile(r'(?<!pub type )\b(?:' + '|'.join(names) + r')\b')
+        types = '\n'.join(line if line.startswith('#[doc') else alias_use.sub(shared, line) for line in types.split('\n'))
+    return types
+
+# Bounds of the schema on numeric and array fields, exposed through
+# `limits::FieldLimits` so values can be checked before they are sent
+LIMIT_KEYWORDS = ('minimum', 'maximum', 'minItems', 'maxItems')
+
+def schema_of_item(doc_lines):
+    return json.loads('\n'.join(json.loads('"' + line[len('#[doc = "'):-len('"]')] + '"') for line in doc_lines))
+
+def field_limit(name, schema):
+    def bound(keyword):
+        return 'Some(' + str(int(schema[keyword])) + ')' if keyword in schema else 'None'
+    if 'minItems' in schema or 'maxItems' in schema:
+        return 'limits::FieldLimit::items("' + name + '", ' + bound('minItems') + ', ' + bound('maxItems') + '),'
+    return 'limits::FieldLimit::integer("' + name + '", "' + schema.get('format', '') + '", ' + bound('minimum') + ', ' + bound('maximum') + '),'
+
+def add_field_limits(types):
+    item_re = re.compile(r'#\[doc = r" ```json"\]\n((?:#\[doc = ".*"\]\n)+)#\[doc = r" ```"\]\n(?:#\[.*\]\n)*pub struct (\w+) \{\n(?:.*\n)*?\}\n')
+    def add(m):
+        schema = schema_of_item(m.group(1).rstrip('\n').split('\n'))
+        limits = [field_limit(name, field) for name, field in sorted(schema.get('properties', {}).items())
+                  if isinstance(field, dict) and any(keyword in field for keyword in LIMIT_KEYWORDS)]
+        if not limits:
+            return m.group(0)
+        return (m.group(0) + 'impl limits::FieldLimits for ' + m.group(2) + ' {\n'
+                + '    const FIELD_LIMITS: &\'static [limits::FieldLimit] = &[\n'
+                + ''.join('        ' + limit + '\n' for limit in limits) + '    ];\n}\n')
+    return item_re.sub(add, types)
+
+# Modules of near-openapi-types/src/generated, each with the pattern of the
+# type names it holds. The first matching pattern wins; types matching none go
+# to `common`
+TYPE_DOMAINS = [
+    ('common', r'^(BlockId|BlockReference|ChunkHash|Direction|EpochId|Finality|GlobalContractIdentifier|MerklePathItem|PeerId|ShardId|SyncCheckpoint|Version)$'),
+    ('jsonrpc', r'^JsonRpc(Request|Response)For'),
+    ('errors', r'Error(Kind)?$|^ErrorWrapperFor|^(HostError|MissingTrieValue\w*|WasmTrap)$'),
+    ('changes', r'StateChange|Changes'),
+    ('validators', r'Validator|KnownProducer'),
+    ('config', r'Config|^Shard|^(AccountIdValidityRulesVersion|AccountInfo|ChunkDistributionUris|DurationAsStdSchemaProvider|ExternalStorageLocation|Fee|LogSummaryStyle|StorageGetMode|SyncConcurrency|VmKind)$'),
+    ('query', r'^RpcQuery|^(View|CallFunction)\w+RequestType$|^(AccessKey|GasKey)|^(AccountView|CallResult|ContractCodeView|FunctionArgs|FunctionCallPermission|StateItem|StoreKey|StoreValue|ViewStateResult)$'),
+    ('chunk', r'Chunk|Congestion|^Bandwidth'),
+    ('block', r'Block|LightClient|GasPrice'),
+    ('tx', r'Transaction|Tx|Action|Receipt|Execution|GlobalContract|StateInit|^(CostGasUsed|DataReceiverView)$'),
+    ('network', r'Network|Peer|Status|Health|Maintenance|SplitStorage|^(AccountDataView|RangeOfUint64|Tier1ProxyView)$'),
+]
+
+DOMAIN_DOCS = {
+    'block': 'Blocks, their headers and light client proofs.',
+    'changes': 'State changes and the requests listing them.',
+    'chunk': 'Chunks, shards and congestion.',
+    'common': 'Keys, signatures and references to blocks, chunks and shards used across\n//! the other modules.',
+    'config': 'Genesis, protocol, runtime and node configuration.',
+    'errors': 'Errors of RPC methods and of transaction execution.',
+    'jsonrpc': 'JSON-RPC request and response envelopes of each method.',
+    'network': 'Node status, network info and maintenance windows.',
+    'query': 'Queries of accounts, access keys, contract code and state.',
+    'tx': 'Transactions, actions, receipts and their outcomes.',
+    'validators': 'Validators, their stakes and kickouts.',
+}
+
+# Cargo features of near-openapi-types compiling the modules; the others are
+# always compiled
+DOMAIN_FEATURES = {
+    'block': 'block',
+    'changes': 'changes',
+    'chunk': 'block',
+    'config': 'config',
+    'jsonrpc': 'full',
+    'network': 'debug',
+    'tx': 'tx',
+    'validators': 'validators',
+}
+
+def domain_cfg(domain):
+    if domain not in DOMAIN_FEATURES:
+        return ''
+    return '#[cfg(feature = "' + DOMAIN_FEATURES[domain] + '")]\n'
+
+def gate_defaults(defaults, modules):
+    # Compiles the default value of a field only with the feature of the
+    # modules using it, so that it is never unused
+    def gate(m):
+        features = set()
+        for domain, items in modules.items():
+            if re.search(r'defaults::' + m.group(2) + r'\b', '\n'.join(items)):
+                if domain not in DOMAIN_FEATURES:
+                    return m.group(0)
+                features.add(DOMAIN_FEATURES[domain])
+        if len(features) == 1:
+            cfg = 'feature = "' + features.pop() + '"'
+        else:
+            cfg = 'any(' + ', '.join('feature = "' + feature + '"' for feature in sorted(features)) + ')'
+        return m.group(1) + '#[cfg(' + cfg + ')]\n' + m.group(0)
+    return re.sub(r'(?m)^( +)pub\(super\) fn (\w+)', gate, defaults)
+
+def type_domain(type_name):
+    for domain, pattern in TYPE_DOMAINS:
+        if re.search(pattern, type_name):
+            return domain
+    return 'common'
+
+def split_top_level_items(types):
+    # Items of the formatted code start and end at column 0: attributes, a
+    # head line, and a body closed by `}` or `);`
+    items = []
+    item = []
+    in_body = False
+    for line in types.split('\n'):
+        if not line:
+            continue
+        item.append(line)
+        if in_body:
+            in_body = line not in ('}', ');', '};')
+        elif line.startswith('#[') and not line.endswith(']'):
+            in_body = True
+        elif not line.startswith('#['):
+            in_body = not line.endswith(';')
+        if not in_body and not line.startswith('#['):
+            items.append('\n'.join(item))
+            item = []
+    return items
+
+def split_domains(types):
+    # Splits the generated types into the modules of TYPE_DOMAINS, returning
+    # the items staying in lib.rs and the source of each module. Impls go
+    # along with the type they are for, or else with the type they follow
+    root = []
+    modules = {}
+    domains = {name: type_domain(name) for name in re.findall(r'(?m)^pub (?:struct|enum|type) (\w+)', types)}
+    current = None
+    for item in split_top_level_items(types):
+        head = re.sub(r'(?m)^#\[.*\n(?:(?!#\[|pub |impl).*\n)*', '', item + '\n')
+        if head.startswith(('pub use ', 'pub mod ')):
+            root.append(item)
+            continue
+        m = re.match(r'pub (?:struct|enum|type) (\w+)', head)
+        if m:
+            current = domains[m.group(1)]
+            domain = current
+        else:
+            header = head.split('{', 1)[0]
+            m = re.search(r'\bfor (?:::)?(?:\w+::)*(\w+)', header) or re.match(r'impl(?:<[^>]*>)? (?:::)?(?:\w+::)*(\w+)', header)
+            domain = domains.get(m.group(1), current)
+        modules.setdefault(domain, []).append(item)
+    sources = {'mod': '//! Types generated from the OpenAPI spec, split by domain. Every type is\n//! also re-exported at the crate root, e.g. [`block::BlockHeaderView`] as\n//! `near_openapi_types::BlockHeaderView`.\n'
+               + ''.join(domain_cfg(domain) + 'pub mod ' + domain + ';\n' for domain in sorted(modules))}
+    for domain, items in modules.items():
+        sources[domain] = '//! ' + DOMAIN_DOCS[domain] + '\nuse crate::*;\n\n' + '\n'.join(items) + '\n'
+    root = [gate_defaults(item, modules) if item.startswith('#[doc = r" Generation of default values') else item for item in root]
+    return '\n'.join(root) + '\n', sources
+
+def use_ordered_maps(types):
+    # Maps of the spec (`additionalProperties`) encode their entries in a
+    # deterministic order, see ordered_map.rs
+    return types.replace('::std::collections::HashMap<', 'OrderedMap<')
+
+# Field types that rule out deriving Eq (and therefore Hash) or only Hash
+NOT_EQ_TYPES = {'f32', 'f64'}
+NOT_HASH_TYPES = {'HashMap', 'HashSet', 'OrderedMap'}
+
+def format_derive(derives):
+    line = '#[derive(' + ', '.join(derives) + ')]'
+    if len(line) <= 100:
+        return line
+    return '#[derive(\n' + ''.join('    ' + derive + ',\n' for derive in derives) + ')]'
+
+def add_comparison_derives(types):
+    # Derive PartialEq on every generated type, plus Eq and Hash where no field
+    # (transitively) is a float or a map
+    item_re = re.compile(r'#\[derive\(([^)]*)\)\]((?:(?!#\[derive\().)*?)\npub (?:struct|enum) (\w+)', re.S)
+    items = {}
+    for m in item_re.finditer(types):
+        start = m.end()
+        if types.startswith('(', start):
+            end = types.find(');\n', start)
+        else:
+            end = types.find('\n}\n', start)
+        body = types[start:end]
+        body = re.sub(r'r"[^"]*"', '', body)
+        body = re.sub(r'"(?:\\.|[^"\\])*"', '', body)
+        items[m.group(3)] = set(re.findall(r'[A-Za-z_]\w*', body))
+
+    not_eq = {name for name, idents in items.items() if idents & NOT_EQ_TYPES}
+    not_hash = {name for name, idents in items.items() if idents & NOT_HASH_TYPES}
+    changed = True
+    while changed:
+        changed = False
+        for name, idents in items.items():
+            if name not in not_eq and idents & not_eq:
+                not_eq.add(name)
+                changed = True
+            if name not in not_hash and idents & (not_hash | not_eq):
+                not_hash.add(name)
+                changed = True
+
+    def add_derives(m):
+        name = m.group(3)
+        derives = [derive.strip() for derive in m.group(1).split(',') if derive.strip()]
+        wanted = {'PartialEq'}
+        if name not in not_eq:
+            wanted.add('Eq')
+            if name not in not_hash:
+                wanted.add('Hash')
+        serde = [derive for derive in derives if 'serde' in derive]
+        paths = [derive for derive in derives if '::' in derive and 'serde' not in derive]
+        plain = sorted(wanted | {derive for derive in derives if '::' not in derive})
+        return format_derive(serde + plain + paths) + m.group(2) + '\npub ' + m.group(0).rsplit('\npub ', 1)[1]
+
+    return item_re.sub(add_derives, types)
+
+def split_top_level(text, separator=','):
+    parts, depth, current = [], 0, ''
+    for char in text:
+        if char in '<({[':
+            depth += 1
+        elif char in '>)}]':
+            depth -= 1
+        if char == separator and depth == 0:
+            parts.append(current.strip())
+            current = ''
+        else:
+            current += char
+    if current.strip():
+        parts.append(current.strip())
+    return parts
+
+def strip_attributes(text):
+    result, i = '', 0
+    while i < len(text):
+        if text.startswith('#[', i):
+            depth = 0
+            while i < len(text):
+                if text[i] == '"':
+                    i = text.find('"', i + 1)
+                    while text[i - 1] == '\\':
+                        i = text.find('"', i + 1)
+                elif text[i] == '[':
+                    depth += 1
+                elif text[i] == ']':
+                    depth -= 1
+                    if depth == 0:
+                        break
+                i += 1
+        else:
+            result += text[i]
+        i += 1
+    return result
+
+def short_type(rust_type):
+    rust_type = re.sub(r'\s+', ' ', rust_type).strip()
+    rust_type = re.sub(r'(?:::)?std::(?:\w+::)+', '', rust_type)
+    rust_type = re.sub(r',\s*\}', ' }', re.sub(r',\s*\)', ')', rust_type))
+    return rust_type.replace('< ', '<').replace(' >', '>').replace(' ,', ',')
+
+def type_metadata(types):
+    # Shape of every public type, embedded in near-openapi-types to diff the
+    # definitions of two crate versions
+    metadata = {}
+    for m in re.finditer(r'\npub (struct|enum) (\w+)', types):
+        kind, name = m.group(1), m.group(2)
+        start = m.end()
+        if types.startswith('(', start):
+            body = strip_attributes(types[start + 1:types.find(');\n', start)])
+            members = {str(index): short_type(field.replace('pub ', '', 1))
+                       for index, field in enumerate(split_top_level(body))}
+        else:
+            body = strip_attributes(types[start + 2:types.find('\n}\n', start)])
+            members = {}
+            for member in split_top_level(body):
+                if kind == 'struct':
+                    field, field_type = member.replace('pub ', '', 1).split(':', 1)
+                    members[field.strip()] = short_type(field_type)
+                else:
+                    variant = re.match(r'(\w+)\s*(.*)', member, re.S)
+                    payload = variant.group(2).strip()
+                    members[variant.group(1)] = short_type(payload.replace('pub ', '')) if payload else None
+        metadata[name] = {'kind': kind, 'members': members}
+    for alias, target in re.findall(r'\npub type (\w+) = (\w+);', types):
+        metadata[alias] = metadata[target]
+    return metadata
+
+def rust_string(literal):
+    # Contents of a non-raw Rust string literal as emitted by progenitor
+    literal = re.sub(r'\\u\{([0-9a-fA-F]+)\}', lambda m: '\\u%04x' % int(m.group(1), 16), literal)
+    return json.loads('"' + literal.replace("\\'", "'") + '"')
+
+def type_descriptions(types, spec):
+    # Descriptions of the spec kept in the docs of the generated types, by
+    # type and member name, embedded in near-openapi-types. Docs added by
+    # this script are left out by keeping only text found in the spec
+    spec_texts = set()
+    def collect(value):
+        if isinstance(value, dict):
+            for key, item in value.items():
+                if key == 'description' and isinstance(item, str):
+                    spec_texts.add(item)
+                else:
+                    collect(item)
+        elif isinstance(value, list):
+            for item in value:
+                collect(item)
+    collect(spec)
+
+    doc = re.compile(r'#\[doc = "(.*)"\]$')
+    descriptions = {}
+    for m in re.finditer(r'\npub (struct|enum) (\w+)', types):
+        name = m.group(2)
+        attributes = []
+        for line in reversed(types[:m.start()].split('\n')):
+            if not line.startswith(('#[', ' ', ')]')):
+                break
+            attributes.insert(0, line)
+        lines = []
+        for line in attributes:
+            if line.startswith('#[doc = r'):
+                break
+            match = doc.match(line)
+            if match:
+                lines.append(rust_string(match.group(1)))
+        entry = {'description': None, 'members': {}}
+        if lines and '\n'.join(lines) in spec_texts:
+            entry['description'] = '\n'.join(lines)
+        if not types.startswith('(', m.end()):
+            body = types[m.end() + 2:types.find('\n}\n', m.end())]
+            pending, variant = [], None
+            for line in body.split('\n'):
+                indent = len(line) - len(line.lstrip(' '))
+                stripped = line.strip()
+                match = doc.match(stripped)
+                if match:
+                    pending.append(rust_string(match.group(1)))
+                    continue
+                if stripped.startswith('#[') or indent not in (4, 8):
+                    continue
+                member = re.match(r'(?:pub )?(\w+)\s*(?:[:({,]|$)', stripped)
+                if not member:
+                    continue
+                key = member.group(1)
+                if indent == 4:
+                    variant = key
+                elif variant is not None:
+                    key = variant + '.' + key
+                text = '\n'.join(pending)
+                pending = []
+                if text and text in spec_texts:
+                    entry['members'][key] = text
+        if entry['description'] is not None or entry['members']:
+            descriptions[name] = entry
+    for alias, target in re.findall(r'\npub type (\w+) = (\w+);', types):
+        if target in descriptions:
+            descriptions[alias] = descriptions[target]
+    return descriptions
+
+def rust_type_name(schema_name):
+    return ''.join(part.capitalize() if part.isupper() or part.islower() else part[0].upper() + part[1:]
+                   for part in schema_name.split('_'))
+
+def rpc_methods(spec, metadata):
+    # A marker type implementing RpcMethod and a typed method on RpcClient
+    # per JSON-RPC method of the spec
+    def qualify(rust_type):
+        return re.sub(r'\b(?!Option\b|Vec\b|String\b)([A-Z]\w*)', r'types::\1', rust_type)
+
+    markers = ''
+    methods = ''
+    for path, item in spec['paths'].items():
+        operation = item['post']
+        content = operation['requestBody']['content']['application/json']
+        request = metadata[rust_type_name(content['schema']['$ref'].split('/')[-1])]
+        content = operation['responses']['200']['content']['application/json']
+        response = metadata[rust_type_name(content['schema']['$ref'].split('/')[-1])]
+        params = qualify(request['members']['params'])
+        result = re.search(r'result: (.*) \}', response['members']['Variant0']).group(1)
+        # Nullable results of unit type, e.g. of `health`, are `null` on
+        # success: return the unit type instead of an always `None` option
+        nullable = re.fullmatch(r'Option<(\w+)>', result)
+        if nullable and metadata[nullable.group(1)]['members'] == {'0': '()'}:
+            result = nullable.group(1)
+        result = qualify(result)
+        error = qualify(re.search(r'error: (\w+)', response['members']['Variant1']).group(1))
+        method = operation['operationId']
+        marker = ''.join(word.capitalize() for word in method.split('_'))
+        description = json.dumps(operation['description'])
+        markers += f"""
+#[doc = {description}]
+#[derive(Clone, Copy, Debug)]
+pub struct {marker};
+
+impl RpcMethod for {marker} {{
+    const NAME: &'static str = "{method}";
+    type Params = {params};
+    type Response = {result};
+    type Error = {error};
+}}
+"""
+        methods += f"""
+    #[doc = {description}]
+    pub async fn {method.lower()}(
+        &self,
+        params: &{params},
+    ) -> Result<{result}, RpcError<{error}>> {{
+        self.request::<{marker}>(params).await
+    }}
+"""
+    return f"""//! Typed JSON-RPC methods: a marker type implementing [`RpcMethod`] for
+//! each method of the spec, and the matching methods of [`RpcClient`].
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::jsonrpc::RpcError;
+use crate::rpc::RpcClient;
+use crate::types;
+use crate::types::validate::Validate;
+
+/// A JSON-RPC method together with the types of its params, result and error.
+pub trait RpcMethod {{
+    const NAME: &'static str;
+    type Params: Serialize + Validate;
+    type Response: DeserializeOwned;
+    type Error: DeserializeOwned;
+}}
+{markers}
+impl RpcClient {{{methods}}}
+"""
+
 filename = 'openapi.json'
 
 f = open(filename, 'r')
@@ -84,7 +645,9 @@ pub mod types {"""
     types = lib_rs[types_index:client_index]
     client = lib_rs[client_index:]
 
-    types = 'pub use near_account_id::AccountId;\npub use near_gas::NearGas;\npub use near_token::NearToken;\n' + types[len(types_start):-2]
+    types = '#[cfg(feature = "std")]\npub use near_account_id::{AccountId, AccountIdRef};\n#[cfg(feature = "std")]\npub use near_gas::NearGas;\n#[cfg(feature = "std")]\npub use near_token::NearToken;\n' + types[len(types_start):-2]
+    # Items were nested in `mod types`; dedent them so they can be matched at the start of a line
+    types = re.sub(r'(?m)^    ', '', types)
     types = types.replace('super::NearToken("0".to_string())', 'super::NearToken::from_yoctonear(0)')
     account_id_start = types.find('#[doc = "NEAR Account Identifier')
     account_id_validity_start = types.find('#[doc = "`AccountIdValidityRulesVersion`"]')
@@ -103,6 +666,29 @@ pub mod types {"""
     current_epoch_start = types.find('#[doc = "Describes information about the current epoch validator"]')
     types = types[:crypto_hash_start] + types[current_epoch_start:]
 
+    # Remove PublicKey definition, replaced by the parsed key of public_key.rs
+    public_key_start = types.find('#[doc = "`PublicKey`"]')
+    public_key_display = 'impl ::std::fmt::Display for PublicKey {\n    fn fmt(&self, f: &mut ::std::fmt::Formatter<\'_>) -> ::std::fmt::Result {\n        self.0.fmt(f)\n    }\n}\n'
+    public_key_end = types.find(public_key_display, public_key_start) + len(public_key_display)
+    types = types[:public_key_start] + types[public_key_end:]
+
+    # Remove Signature definition, replaced by the parsed signature of signature.rs
+    signature_start = types.find('#[doc = "`Signature`"]')
+    signature_display = public_key_display.replace('PublicKey', 'Signature')
+    signature_end = types.find(signature_display, signature_start) + len(signature_display)
+    types = types[:signature_start] + types[signature_end:]
+
+    types = add_historical_compat(types)
+    types = model_legacy_fields(types)
+    types = add_unknown_variants(types)
+    types = fix_nullable_responses(types)
+    types = use_ordered_maps(types)
+    types = add_comparison_derives(types)
+    types = add_arbitrary_derives(types)
+    types = add_schemars_derives(types)
+    types = collapse_changes_types(types)
+    types = add_field_limits(types)
+
     # Add thiserror::Error and strum_macros::Display derives for error types
     # Match RpcRequestValidationErrorKind and types ending with Error (but not JsonRpcResponseFor*)
 
@@ -127,16 +713,131 @@ pub mod types {"""
         types
     )
 
+    types_root, generated_modules = split_domains(types)
+    # Only the hand-written primitives build without std
+    generated_uses = ''.join((domain_cfg(domain) or '#[cfg(feature = "std")]\n') + 'pub use generated::' + domain + '::*;\n' for domain in sorted(generated_modules) if domain != 'mod')
+    types_root = types_root.replace('#[doc = r" Generation of default values for serde."]\npub mod defaults {', '#[cfg(feature = "std")]\n#[doc = r" Generation of default values for serde."]\npub mod defaults {')
+
     types_lib_rs = """//! This crate provides types for the Near OpenAPI specification.
 //!
 //! Used in [near-openapi-client](https://docs.rs/near-openapi-client/latest/near_openapi_client/)
+//!
+//! ## Wire format of enums
+//!
+//! Enums without a `#[serde(tag = ...)]` or `#[serde(untagged)]` attribute use the
+//! externally tagged representation, same as nearcore: variants without data are
+//! bare strings and variants with data are objects with a single key. For example
+//! [`AccessKeyPermissionView`] is either `"FullAccess"` or
+//! `{"FunctionCall": {"allowance": "1", "method_names": [], "receiver_id": "app.near"}}`,
+//! and [`ActionErrorKind`] is either `"DelegateActionExpired"` or e.g.
+//! `{"AccountDoesNotExist": {"account_id": "alice.near"}}`. Values deserialized from
+//! node responses serialize back to identical JSON.
+//!
+//! ## Modules
+//!
+//! The generated types are grouped by domain in the modules of [`generated`],
+//! e.g. blocks in [`generated::block`] and errors in [`generated::errors`],
+//! and re-exported at the crate root.
+//!
+//! ## `no_std`
+//!
+//! Without the default `std` feature the crate is `no_std` and only has the
+//! primitives [`CryptoHash`], [`PublicKey`], [`Signature`], [`ChainId`] and
+//! [`error::ConversionError`], with their serde and borsh encodings, for
+//! signers and other targets with `alloc` only. Every other feature
+//! enables `std`, including `tx`: actions and the borsh encoding of
+//! transactions are built on `AccountId`, `NearToken` and `NearGas`, whose
+//! crates require `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+#[cfg(feature = "std")]
+mod access_key;
+#[cfg(feature = "std")]
+mod action_error;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "std")]
+pub mod balance;
+#[cfg(feature = "std")]
+mod block_reference;
+#[cfg(feature = "zero-copy")]
+pub mod borrowed;
+#[cfg(feature = "std")]
+mod bytes;
+#[cfg(feature = "std")]
+mod call_function;
+mod chain_id;
+#[cfg(feature = "tx")]
+pub mod chain_signatures;
+#[cfg(feature = "tx")]
+pub mod compact;
+#[cfg(feature = "config")]
+pub mod config_diff;
+#[cfg(feature = "tx")]
+pub mod data_flow;
+#[cfg(feature = "std")]
+pub mod descriptions;
 pub mod error;
+#[cfg(feature = "std")]
+pub mod eth_implicit;
+#[cfg(all(feature = "config", feature = "tx"))]
+pub mod fees;
+#[cfg(feature = "std")]
+pub mod gas_refund;
+#[cfg(feature = "std")]
+pub mod generated;
+#[cfg(feature = "std")]
+pub mod known;
+#[cfg(feature = "block")]
+pub mod legacy;
+#[cfg(feature = "std")]
+pub mod lenient;
+#[cfg(feature = "std")]
+pub mod limits;
+#[cfg(feature = "tx")]
+pub mod merkle;
+#[cfg(feature = "std")]
+pub mod multi_token;
+#[cfg(feature = "std")]
+pub mod ordered_map;
+#[cfg(feature = "std")]
+pub mod protocol_features;
+mod public_key;
+#[cfg(feature = "std")]
+mod query;
+#[cfg(feature = "std")]
+pub mod round_trip;
+#[cfg(feature = "config")]
+pub mod shard_layout;
+mod signature;
+#[cfg(feature = "std")]
+pub mod standards;
+#[cfg(feature = "changes")]
+pub mod state_changes;
+#[cfg(feature = "tx")]
+pub mod token_flow;
+#[cfg(feature = "tx")]
+pub mod tx;
+#[cfg(feature = "tx")]
+mod tx_status;
+#[cfg(feature = "std")]
+pub mod type_metadata;
 mod util;
+#[cfg(feature = "std")]
+pub mod validate;
+#[cfg(feature = "validators")]
+pub mod validator_set;
+pub use chain_id::ChainId;
+""" + generated_uses + """#[cfg(feature = "std")]
+pub use ordered_map::OrderedMap;
+pub use public_key::{KeyType, PublicKey};
+pub use signature::Signature;
 pub use util::CryptoHash;
-""" + types
+""" + types_root
 
     client_lib_rs = dependencies + client
-    client_lib_rs = 'pub use near_openapi_types as types;\n' + client_lib_rs
+    client_modules = 'pub mod account;\npub mod airdrop;\npub mod batch;\npub mod block;\npub mod builder;\npub mod cache;\npub mod chain_signatures;\npub mod contract;\npub mod error;\npub mod fees;\npub mod final_outcome;\npub mod finality;\npub mod gas_price;\npub mod genesis;\npub mod inclusion;\npub mod jsonrpc;\npub mod maintenance;\npub mod methods;\npub mod metrics;\npub mod multi_token;\npub mod near_client;\npub mod network;\npub mod nonce;\npub mod replay;\npub mod request_id;\npub mod resubmit;\npub mod retry;\npub mod rpc;\npub mod snapshot;\npub mod socialdb;\npub mod stake_events;\npub mod state;\npub mod state_changes;\npub mod storage;\n#[cfg(feature = "testing")]\npub mod testing;\nmod time;\n#[cfg(feature = "tracing")]\nmod trace;\npub mod transport;\npub mod tx_wait;\npub mod validators;\npub mod view;\npub mod wallet;\n#[cfg(feature = "ws")]\npub mod ws;\n'
+    client_lib_rs = 'pub use near_openapi_types as types;\n' + client_modules + client_lib_rs
     client_lib_rs = re.sub('"{}/\w*', '"{}/', client_lib_rs)
     
     readme_md = open('./README.md', 'r')
@@ -150,12 +851,35 @@ pub use util::CryptoHash;
     client_lib_rs_file = open('./near-openapi-client/src/lib.rs', 'w')
     client_lib_rs_file.write(client_lib_rs)
     client_lib_rs_file.close()
+
+    methods_file = open('./near-openapi-client/src/methods.rs', 'w')
+    methods_file.write(rpc_methods(spec, type_metadata(types)))
+    methods_file.close()
     
     if not os.path.isdir('./near-openapi-types/src'):
         os.makedirs('./near-openapi-types/src')
     types_lib_rs_file = open('./near-openapi-types/src/lib.rs', 'w')
     types_lib_rs_file.write(types_lib_rs)
     types_lib_rs_file.close()
+
+    generated_dir = './near-openapi-types/src/generated'
+    if os.path.isdir(generated_dir):
+        shutil.rmtree(generated_dir)
+    os.makedirs(generated_dir)
+    for module, source in generated_modules.items():
+        module_file = open(os.path.join(generated_dir, module + '.rs'), 'w')
+        module_file.write(source)
+        module_file.close()
+
+    type_metadata_file = open('./near-openapi-types/src/type_metadata.json', 'w')
+    json.dump(type_metadata(types), type_metadata_file, indent=2, sort_keys=True)
+    type_metadata_file.write('\n')
+    type_metadata_file.close()
+
+    descriptions_file = open('./near-openapi-types/src/descriptions.json', 'w')
+    json.dump(type_descriptions(types, spec), descriptions_file, indent=2, sort_keys=True)
+    descriptions_file.write('\n')
+    descriptions_file.close()
     
     all_cargo_toml_file = open('./near-openapi/Cargo.toml', 'r')
     cargo_toml = all_cargo_toml_file.read()
@@ -170,7 +894,9 @@ license.workspace = true
 repository.workspace = true
 description = "Progenitor-generated client of NEAR JSON RPC API"
 """, client_cargo_toml)
-    client_cargo_toml += 'near-openapi-types.workspace = true\n'
+    client_cargo_toml += 'near-openapi-types = { workspace = true, features = ["full"] }\nbase64 = "0.22"\nborsh = "1"\nfastrand = "2.3"\nfutures-util = { version = "0.3", default-features = false, features = ["alloc"] }\ntokio-tungstenite = { version = "0.26", optional = true, default-features = false, features = ["connect"] }\ntracing = { version = "0.1", optional = true, default-features = false, features = ["std"] }\nprometheus = { version = "0.14", optional = true, default-features = false }\n\n[target.\'cfg(not(target_arch = "wasm32"))\'.dependencies]\ntokio = { version = "1", features = ["time"] }\n\n[target.\'cfg(target_arch = "wasm32")\'.dependencies]\ngloo-timers = { version = "0.3", optional = true, features = ["futures"] }\nweb-time = { version = "1", optional = true }\n'
+    client_cargo_toml += '\n[dev-dependencies]\ntokio = { version = "1", features = ["macros", "rt-multi-thread"] }\n\n[[example]]\nname = "ingestion"\nrequired-features = ["bench"]\n'
+    client_cargo_toml += '\n[features]\narbitrary = ["near-openapi-types/arbitrary"]\nbench = []\ncrypto = ["near-openapi-types/crypto"]\nhistorical-compat = ["near-openapi-types/historical-compat"]\nmetrics = ["dep:prometheus"]\npreserve-order = ["near-openapi-types/preserve-order"]\nschemars = ["near-openapi-types/schemars"]\nsigning = ["near-openapi-types/signing"]\ntesting = []\ntracing = ["dep:tracing"]\nwasm = ["dep:gloo-timers", "dep:web-time", "fastrand/js", "near-openapi-types/wasm"]\nws = ["dep:tokio-tungstenite", "futures-util/sink", "tokio/sync"]\nzero-copy = ["near-openapi-types/zero-copy"]\n'
     types_cargo_toml = re.sub('near-openapi', 'near-openapi-types', cargo_toml)
     types_cargo_toml = re.sub('version = "0.0.0"\nedition = "2021"\nlicense = "SPECIFY A LICENSE BEFORE PUBLISHING"', """version.workspace = true
 edition.workspace = true
@@ -184,7 +910,12 @@ description = "Types for progenitor-generated client of NEAR JSON RPC API"
     types_cargo_toml = re.sub(r'progenitor-client = "[^"]+"\n', '', types_cargo_toml)
     types_cargo_toml = re.sub(r'reqwest = \{[^}]+\}\n', '', types_cargo_toml)
     types_cargo_toml = re.sub(r'serde_urlencoded = "[^"]+"\n', '', types_cargo_toml)
-    types_cargo_toml += 'near-account-id = { version = "2.0", features = ["serde"] }\nnear-gas = { version = "0.3.2", features = ["serde"] }\nnear-token = { version = "0.3.1", features = ["serde"] }\nthiserror = "2.0.17"\nstrum_macros = "0.27.2"\nbs58 = "0.5.1"\n'
+    # Numbers must decode to the value they were encoded from.
+    types_cargo_toml = re.sub(r'serde_json = "([^"]+)"\n', r'serde_json = { version = "\1", default-features = false, features = ["alloc", "float_roundtrip"] }\n', types_cargo_toml)
+    # The primitives build without std, see the `std` feature.
+    types_cargo_toml = re.sub(r'serde = \{ version = "([^"]+)", features = \["derive"\] \}\n', r'serde = { version = "\1", default-features = false, features = ["alloc", "derive"] }\n', types_cargo_toml)
+    types_cargo_toml += 'near-account-id = { version = "2.0", optional = true, features = ["serde"] }\nnear-gas = { version = "0.3.2", optional = true, features = ["serde"] }\nnear-token = { version = "0.3.1", optional = true, features = ["serde"] }\nthiserror = { version = "2.0.17", default-features = false }\nstrum_macros = "0.27.2"\nbs58 = { version = "0.5.1", default-features = false, features = ["alloc"] }\nbase64 = { version = "0.22", default-features = false, features = ["alloc"] }\nborsh = { version = "1", default-features = false }\nsha2 = { version = "0.10", default-features = false }\nsha3 = { version = "0.10", default-features = false }\ned25519-dalek = { version = "2", optional = true }\nk256 = { version = "0.13", optional = true, features = ["ecdsa"] }\narbitrary = { version = "1.4", optional = true, features = ["derive"] }\nschemars = { version = "1", optional = true, features = ["chrono04"] }\nindexmap = { version = "2", optional = true, features = ["serde"] }\n\n[target.\'cfg(target_arch = "wasm32")\'.dependencies]\ngetrandom = { version = "0.2", optional = true, features = ["js"] }\n'
+    types_cargo_toml += '\n[features]\ndefault = ["std", "full"]\narbitrary = ["std", "dep:arbitrary", "chrono/arbitrary", "chrono/std", "near-account-id/arbitrary", "near-gas/arbitrary", "near-token/arbitrary", "indexmap?/arbitrary"]\nblock = ["tx", "validators"]\nchanges = ["std"]\nconfig = ["std"]\ncrypto = ["std", "dep:ed25519-dalek", "dep:k256"]\ndebug = ["block"]\nfull = ["block", "changes", "config", "debug", "tx", "validators"]\nhistorical-compat = ["std"]\npreserve-order = ["std", "dep:indexmap", "schemars?/indexmap2"]\nschemars = ["std", "dep:schemars", "near-account-id/schemars-v1", "near-gas/schemars-v1", "near-token/schemars-v1"]\nsigning = ["crypto", "tx"]\nstd = ["dep:near-account-id", "dep:near-gas", "dep:near-token", "base64/std", "borsh/std", "bs58/std", "serde/std", "serde_json/std", "sha2/std", "sha3/std", "thiserror/std"]\ntx = ["std"]\nvalidators = ["std"]\nwasm = ["std", "dep:getrandom"]\nzero-copy = ["block", "serde_json/raw_value"]\n'
     
     client_cargo_toml_file = open('./near-openapi-client/Cargo.toml', 'w')
     client_cargo_toml_file.write(client_cargo_toml)